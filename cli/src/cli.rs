@@ -1,23 +1,29 @@
 use {
     crate::{
         command::{Command, CommandError},
-        helper::CliHelper,
-        print::{Print, PrintOption},
+        dump::autocommit,
+        helper::{Catalog, CliHelper, is_complete, split_statements},
+        print::{OutputMode, Print, PrintOption},
+        transfer::{export_query, import_file},
     },
     edit::{Builder, edit_file, edit_with_builder},
     gluesql_core::{
         prelude::Glue,
         store::{GStore, GStoreMut, Planner},
     },
-    rustyline::{Editor, error::ReadlineError},
+    rustyline::{CompletionType, Config, Editor, error::ReadlineError},
     std::{
         error::Error,
-        fs::File,
+        fs::{self, File},
         io::{Read, Result, Write},
-        path::Path,
+        path::{Path, PathBuf},
+        time::Instant,
     },
 };
 
+/// Entries kept in the history file.
+const HISTORY_SIZE: usize = 1000;
+
 pub struct Cli<T, W>
 where
     T: GStore + GStoreMut + Planner,
@@ -25,6 +31,7 @@ where
 {
     glue: Glue<T>,
     print: Print<W>,
+    history_path: Option<PathBuf>,
 }
 
 impl<T, W> Cli<T, W>
@@ -36,7 +43,20 @@ where
         let glue = Glue::new(storage);
         let print = Print::new(output, None, PrintOption::default());
 
-        Self { glue, print }
+        Self {
+            glue,
+            print,
+            history_path: None,
+        }
+    }
+
+    /// Loads the prompt history from `path` and saves it back on exit.
+    pub fn set_history_path(&mut self, path: PathBuf) {
+        self.history_path = Some(path);
+    }
+
+    pub fn set_output_mode(&mut self, mode: OutputMode) {
+        self.print.option.mode(mode);
     }
 
     pub fn run(&mut self) -> std::result::Result<(), Box<dyn Error>> {
@@ -46,10 +66,22 @@ where
 
         self.print.help()?;
 
-        let mut rl = Editor::<CliHelper>::new();
-        rl.set_helper(Some(CliHelper));
+        let config = Config::builder()
+            .completion_type(CompletionType::List)
+            .max_history_size(HISTORY_SIZE)
+            .build();
+        let mut rl = Editor::<CliHelper>::with_config(config);
+        rl.set_helper(Some(CliHelper::default()));
+        if let Some(path) = &self.history_path {
+            // a missing history file just means a first run
+            let _ = rl.load_history(path);
+        }
 
         loop {
+            if let Some(helper) = rl.helper_mut() {
+                helper.catalog = self.catalog();
+            }
+
             let line = match rl.readline("gluesql> ") {
                 Ok(line) => line,
                 Err(ReadlineError::Interrupted) => {
@@ -73,59 +105,17 @@ where
 
             let command = match Command::parse(line, &self.print.option) {
                 Ok(command) => command,
-                Err(CommandError::LackOfTable) => {
-                    println!("[error] should specify table. eg: .columns TableName\n");
-                    continue;
-                }
-                Err(CommandError::LackOfFile) => {
-                    println!("[error] should specify file path.\n");
-                    continue;
-                }
-                Err(CommandError::NotSupported) => {
-                    println!("[error] command not supported: {}", line);
-                    println!("\n  type .help to list all available commands.\n");
-                    continue;
-                }
-                Err(CommandError::LackOfOption) => {
-                    println!("[error] should specify option.\n");
-                    continue;
-                }
-                Err(CommandError::LackOfValue(usage)) => {
-                    println!("[error] should specify value.\n{usage}\n");
-                    continue;
-                }
-                Err(CommandError::WrongOption(e)) => {
-                    println!("[error] cannot support option: {e}\n");
-                    continue;
-                }
-                Err(CommandError::LackOfSQLHistory) => {
-                    println!("[error] Nothing in SQL history to run.\n");
+                Err(e) => {
+                    self.command_error(line, e)?;
                     continue;
                 }
             };
 
             match command {
-                Command::Help => {
-                    self.print.help()?;
-                }
                 Command::Quit => {
                     println!("bye\n");
                     break;
                 }
-                Command::Execute(sql) => self.execute(sql)?,
-                Command::ExecuteFromFile(filename) => {
-                    if let Err(e) = self.load(&filename) {
-                        println!("[error] {}\n", e);
-                    }
-                }
-                Command::SpoolOn(path) => {
-                    self.print.spool_on(path)?;
-                }
-                Command::SpoolOff => {
-                    self.print.spool_off();
-                }
-                Command::Set(option) => self.print.set_option(option),
-                Command::Show(option) => self.print.show_option(option)?,
                 Command::Edit(file_name) => {
                     if let Some(file_name) = file_name {
                         let file = Path::new(&file_name);
@@ -150,36 +140,269 @@ where
                         }
                     }
                 }
+                command => self.run_command(command)?,
+            }
+        }
+
+        if let Some(path) = &self.history_path {
+            if let Some(directory) = path.parent() {
+                fs::create_dir_all(directory)?;
+            }
+
+            rl.save_history(path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs the commands that do not need the line editor.
+    fn run_command(&mut self, command: Command) -> Result<()> {
+        match command {
+            Command::Help => self.print.help()?,
+            Command::Execute(sql) => {
+                self.execute(sql)?;
+            }
+            Command::ExecuteFromFile(filename) => {
+                if let Err(e) = self.load(&filename) {
+                    writeln!(self.print.output, "[error] {e}\n")?;
+                }
+            }
+            Command::SpoolOn(path) => self.print.spool_on(path)?,
+            Command::SpoolOff => self.print.spool_off(),
+            Command::Import {
+                path,
+                table_name,
+                create,
+            } => match import_file(&mut self.glue, Path::new(&path), &table_name, create) {
+                Ok(count) => writeln!(
+                    self.print.output,
+                    "{count} rows imported into {table_name}\n"
+                )?,
+                Err(e) => writeln!(self.print.output, "[error] {e}\n")?,
+            },
+            Command::Export { query, path } => {
+                match export_query(&mut self.glue, &query, Path::new(&path)) {
+                    Ok(count) => writeln!(self.print.output, "{count} rows exported to {path}\n")?,
+                    Err(e) => writeln!(self.print.output, "[error] {e}\n")?,
+                }
+            }
+            Command::Set(option) => self.print.set_option(option),
+            Command::Show(option) => self.print.show_option(option)?,
+            Command::Quit | Command::Edit(_) | Command::Run => {
+                writeln!(self.print.output, "[error] command not supported here\n")?;
             }
         }
 
         Ok(())
     }
 
-    fn execute(&mut self, sql: impl AsRef<str>) -> Result<()> {
-        match self.glue.execute(sql) {
-            Ok(payloads) => self.print.payloads(&payloads)?,
+    fn command_error(&mut self, line: &str, error: CommandError) -> Result<()> {
+        let output = &mut self.print.output;
+
+        match error {
+            CommandError::LackOfTable => {
+                writeln!(
+                    output,
+                    "[error] should specify table. eg: .columns TableName\n"
+                )
+            }
+            CommandError::LackOfFile => writeln!(output, "[error] should specify file path.\n"),
+            CommandError::LackOfQuery => writeln!(
+                output,
+                "[error] should specify query. eg: .export SELECT * FROM TableName out.csv\n"
+            ),
+            CommandError::NotSupported => {
+                writeln!(output, "[error] command not supported: {line}")?;
+                writeln!(output, "\n  type .help to list all available commands.\n")
+            }
+            CommandError::LackOfOption => writeln!(output, "[error] should specify option.\n"),
+            CommandError::LackOfValue(usage) => {
+                writeln!(output, "[error] should specify value.\n{usage}\n")
+            }
+            CommandError::WrongOption(e) => {
+                writeln!(output, "[error] cannot support option: {e}\n")
+            }
+            CommandError::LackOfSQLHistory => {
+                writeln!(output, "[error] Nothing in SQL history to run.\n")
+            }
+        }
+    }
+
+    /// Reads the names offered by tab completion, leaving the list empty when
+    /// the storage cannot provide them.
+    fn catalog(&mut self) -> Catalog {
+        autocommit(&mut self.glue.storage, |storage| {
+            let tables = storage
+                .fetch_all_schemas()?
+                .into_iter()
+                .map(|schema| {
+                    let columns = schema
+                        .column_defs
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|column_def| column_def.name)
+                        .collect();
+
+                    (schema.table_name, columns)
+                })
+                .collect();
+            let functions = storage
+                .fetch_all_functions()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|function| function.func_name.clone())
+                .collect();
+
+            Ok(Catalog { tables, functions })
+        })
+        .unwrap_or_default()
+    }
+
+    /// Runs each statement of `sql`, printing its result and elapsed time.
+    /// Returns `false` once a statement fails; the rest are skipped.
+    fn execute(&mut self, sql: impl AsRef<str>) -> Result<bool> {
+        let statements = match self.glue.plan(sql) {
+            Ok(statements) => statements,
             Err(e) => {
                 println!("[error] {e}\n");
+                return Ok(false);
+            }
+        };
+
+        for statement in &statements {
+            let started = Instant::now();
+
+            match self.glue.execute_stmt(statement) {
+                Ok(payload) => {
+                    self.print.payload(&payload)?;
+                    self.print.elapsed(started.elapsed())?;
+                }
+                Err(e) => {
+                    println!("[error] {e}\n");
+                    return Ok(false);
+                }
             }
         }
 
-        Ok(())
+        Ok(true)
     }
 
     pub fn load<P: AsRef<Path>>(&mut self, filename: P) -> Result<()> {
         let mut sqls = String::new();
         File::open(filename)?.read_to_string(&mut sqls)?;
-        for sql in sqls.split(';').filter(|sql| !sql.trim().is_empty()) {
-            match self.glue.execute(sql) {
-                Ok(payloads) => self.print.payloads(&payloads)?,
-                Err(e) => {
-                    println!("[error] {e}\n");
-                    break;
+        for sql in split_statements(&sqls) {
+            if !self.execute(sql)? {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs the dot commands and SQL statements of a startup file such as
+    /// `~/.gluesqlrc`. Dot commands take one line each, while SQL statements
+    /// may span lines until their terminating semicolon.
+    pub fn run_rc_file<P: AsRef<Path>>(&mut self, filename: P) -> Result<()> {
+        let mut lines = String::new();
+        File::open(filename)?.read_to_string(&mut lines)?;
+
+        let mut sql = String::new();
+        for line in lines.lines() {
+            if sql.trim().is_empty() && (line.trim().is_empty() || line.starts_with("--")) {
+                continue;
+            }
+
+            if sql.trim().is_empty() && line.trim_start().starts_with('.') {
+                match Command::parse(line.trim(), &self.print.option) {
+                    Ok(command) => self.run_command(command)?,
+                    Err(e) => self.command_error(line.trim(), e)?,
                 }
+
+                continue;
+            }
+
+            sql.push_str(line);
+            sql.push('\n');
+
+            if is_complete(&sql) {
+                self.execute(&sql)?;
+                sql.clear();
             }
         }
 
+        if !sql.trim().is_empty() {
+            self.execute(&sql)?;
+        }
+
+        Ok(())
+    }
+
+    /// Executes a SQL file without the prompt, stopping at the first failing statement.
+    pub fn execute_script<P: AsRef<Path>>(&mut self, filename: P) -> anyhow::Result<()> {
+        let mut sqls = String::new();
+        File::open(filename)?.read_to_string(&mut sqls)?;
+        for sql in split_statements(&sqls) {
+            let payloads = self.glue.execute(sql)?;
+            self.print.payloads(&payloads)?;
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::Cli, gluesql_memory_storage::MemoryStorage, std::fs};
+
+    #[test]
+    fn run_rc_file() {
+        let path = "tmp/gluesqlrc";
+        fs::create_dir_all("tmp").unwrap();
+        fs::write(
+            path,
+            "-- startup\n\
+             .set footer ON\n\
+             CREATE TABLE Foo (id INTEGER);\n\
+             INSERT INTO Foo\n\
+             \x20 VALUES (1), (2);\n\
+             .unknown\n\
+             SELECT * FROM Foo",
+        )
+        .unwrap();
+
+        let mut cli = Cli::new(MemoryStorage::default(), Vec::new());
+        cli.run_rc_file(path).unwrap();
+
+        let output = String::from_utf8(cli.print.output).unwrap();
+        assert!(output.contains("Table created"), "{output}");
+        assert!(output.contains("2 rows inserted"), "{output}");
+        assert!(output.contains("[error] command not supported: .unknown"));
+        assert!(output.contains("(2 rows)"), "{output}");
+    }
+
+    #[test]
+    fn execute_script() {
+        let path = "tmp/trigger_script.sql";
+        fs::create_dir_all("tmp").unwrap();
+        fs::write(
+            path,
+            "CREATE TABLE Foo (id INTEGER);\n\
+             CREATE TABLE Log (note TEXT);\n\
+             CREATE TRIGGER foo_log AFTER INSERT ON Foo FOR EACH ROW\n\
+             BEGIN\n\
+             \x20   INSERT INTO Log VALUES ('a;b');\n\
+             \x20   INSERT INTO Log VALUES ('c');\n\
+             END;\n\
+             INSERT INTO Foo VALUES (1);\n\
+             SELECT note FROM Log;\n",
+        )
+        .unwrap();
+
+        let mut cli = Cli::new(MemoryStorage::default(), Vec::new());
+        cli.execute_script(path).unwrap();
+
+        let output = String::from_utf8(cli.print.output).unwrap();
+        assert!(output.contains("| a;b  |"), "{output}");
+        assert!(output.contains("| c    |"), "{output}");
+    }
+}
@@ -1,4 +1,9 @@
-use {crate::print::PrintOption, std::fmt::Debug, thiserror::Error as ThisError};
+use {
+    crate::print::{OutputMode, PrintOption},
+    clap::ValueEnum,
+    std::fmt::Debug,
+    thiserror::Error as ThisError,
+};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum Command {
@@ -8,6 +13,15 @@ pub enum Command {
     ExecuteFromFile(String),
     SpoolOn(String),
     SpoolOff,
+    Import {
+        path: String,
+        table_name: String,
+        create: bool,
+    },
+    Export {
+        query: String,
+        path: String,
+    },
     Set(SetOption),
     Show(ShowOption),
     Edit(Option<String>),
@@ -20,6 +34,8 @@ pub enum CommandError {
     LackOfTable,
     #[error("should specify file path")]
     LackOfFile,
+    #[error("should specify query")]
+    LackOfQuery,
     #[error("should specify value for option")]
     LackOfValue(String),
     #[error("should specify option")]
@@ -35,9 +51,13 @@ pub enum CommandError {
 #[derive(Eq, Debug, PartialEq)]
 pub enum SetOption {
     Tabular(bool),
+    Mode(OutputMode),
+    Expanded(bool),
     Colsep(String),
     Colwrap(String),
     Heading(bool),
+    Timer(bool),
+    Footer(bool),
 }
 
 impl SetOption {
@@ -50,6 +70,10 @@ impl SetOption {
             }
         }
 
+        fn mode_from(value: &str) -> Result<OutputMode, CommandError> {
+            OutputMode::from_str(value, true).map_err(|_| CommandError::WrongOption(value.into()))
+        }
+
         if let Some(value) = value {
             let value = match *value {
                 "\"\"" => "",
@@ -57,12 +81,18 @@ impl SetOption {
             }
             .to_owned();
 
-            let set_option = match (key.to_lowercase().as_str(), &option.tabular) {
+            let set_option = match (key.to_lowercase().as_str(), option.mode) {
                 ("tabular", _) => Self::Tabular(bool_from(value)?),
-                ("colsep", false) => Self::Colsep(value),
-                ("colwrap", false) => Self::Colwrap(value),
-                ("heading", false) => Self::Heading(bool_from(value)?),
-                (_, true) => return Err(CommandError::WrongOption("run .set tabular OFF".into())),
+                ("mode", _) => Self::Mode(mode_from(&value)?),
+                ("expanded", _) => Self::Expanded(bool_from(value)?),
+                ("timer", _) => Self::Timer(bool_from(value)?),
+                ("footer", _) => Self::Footer(bool_from(value)?),
+                ("colsep", OutputMode::List) => Self::Colsep(value),
+                ("colwrap", OutputMode::List) => Self::Colwrap(value),
+                ("heading", mode) if mode != OutputMode::Table => Self::Heading(bool_from(value)?),
+                ("colsep" | "colwrap" | "heading", _) => {
+                    return Err(CommandError::WrongOption("run .set tabular OFF".into()));
+                }
 
                 _ => return Err(CommandError::WrongOption(key.into())),
             };
@@ -71,9 +101,13 @@ impl SetOption {
         } else {
             let payload = match key.to_lowercase().as_str() {
                 "tabular" => "Usage: .set tabular {ON|OFF}",
+                "mode" => "Usage: .set mode {table|list|csv|json|jsonl|markdown|html|line}",
+                "expanded" => "Usage: .set expanded {ON|OFF}",
                 "colsep" => "Usage: .set colsep {\"\"|TEXT}",
                 "colwrap" => "Usage: .set colwrap {\"\"|TEXT}",
                 "heading" => "Usage: .set heading {ON|OFF}",
+                "timer" => "Usage: .set timer {ON|OFF}",
+                "footer" => "Usage: .set footer {ON|OFF}",
 
                 _ => return Err(CommandError::WrongOption(key.into())),
             };
@@ -86,9 +120,13 @@ impl SetOption {
 #[derive(Eq, Debug, PartialEq, Copy, Clone)]
 pub enum ShowOption {
     Tabular,
+    Mode,
+    Expanded,
     Colsep,
     Colwrap,
     Heading,
+    Timer,
+    Footer,
     All,
 }
 
@@ -96,9 +134,13 @@ impl ShowOption {
     fn parse(key: &str) -> Result<Self, CommandError> {
         let show_option = match key.to_lowercase().as_str() {
             "tabular" => Self::Tabular,
+            "mode" => Self::Mode,
+            "expanded" => Self::Expanded,
             "colsep" => Self::Colsep,
             "colwrap" => Self::Colwrap,
             "heading" => Self::Heading,
+            "timer" => Self::Timer,
+            "footer" => Self::Footer,
             "all" => Self::All,
             _ => return Err(CommandError::WrongOption(key.into())),
         };
@@ -131,6 +173,31 @@ impl Command {
                     Some(path) => Ok(Self::SpoolOn((*path).to_owned())),
                     None => Err(CommandError::LackOfFile),
                 },
+                ".import" => match (params.get(1), params.get(2), params.get(3)) {
+                    (None, ..) => Err(CommandError::LackOfFile),
+                    (Some(_), None, _) => Err(CommandError::LackOfTable),
+                    (Some(path), Some(table_name), create) => Ok(Self::Import {
+                        path: (*path).to_owned(),
+                        table_name: (*table_name).to_owned(),
+                        create: match create {
+                            None => false,
+                            Some(&"--create") => true,
+                            Some(option) => {
+                                return Err(CommandError::WrongOption((*option).into()));
+                            }
+                        },
+                    }),
+                },
+                ".export" => match line[".export".len()..]
+                    .trim()
+                    .rsplit_once(char::is_whitespace)
+                {
+                    Some((query, path)) => Ok(Self::Export {
+                        query: query.trim_end().to_owned(),
+                        path: path.to_owned(),
+                    }),
+                    None => Err(CommandError::LackOfQuery),
+                },
                 ".set" => match (params.get(1), params.get(2)) {
                     (Some(key), value) => Ok(Self::Set(SetOption::parse(key, value, option)?)),
                     (None, _) => Err(CommandError::LackOfOption),
@@ -139,6 +206,15 @@ impl Command {
                     Some(key) => Ok(Self::Show(ShowOption::parse(key)?)),
                     None => Err(CommandError::LackOfOption),
                 },
+                ".mode" => match params.get(1) {
+                    Some(key) => Ok(Self::Set(SetOption::parse("mode", Some(key), option)?)),
+                    None => Ok(Self::Show(ShowOption::Mode)),
+                },
+                ".x" => Ok(Self::Set(SetOption::Expanded(!option.expanded))),
+                ".timer" => match params.get(1) {
+                    Some(value) => Ok(Self::Set(SetOption::parse("timer", Some(value), option)?)),
+                    None => Ok(Self::Show(ShowOption::Timer)),
+                },
                 ".edit" => Ok(Self::Edit(params.get(1).map(|&v| v.to_owned()))),
                 ".run" => Ok(Self::Run),
 
@@ -152,7 +228,10 @@ impl Command {
 
 #[cfg(test)]
 mod tests {
-    use crate::{command::CommandError, print::PrintOption};
+    use crate::{
+        command::CommandError,
+        print::{OutputMode, PrintOption},
+    };
 
     #[test]
     fn parse_command() {
@@ -199,6 +278,37 @@ mod tests {
         );
         assert_eq!(parse(".spool off"), Ok(Command::SpoolOff));
         assert_eq!(parse(".spool"), Err(CommandError::LackOfFile));
+        assert_eq!(
+            parse(".import data/foo.csv Foo"),
+            Ok(Command::Import {
+                path: "data/foo.csv".into(),
+                table_name: "Foo".into(),
+                create: false,
+            })
+        );
+        assert_eq!(
+            parse(".import foo.parquet Foo --create"),
+            Ok(Command::Import {
+                path: "foo.parquet".into(),
+                table_name: "Foo".into(),
+                create: true,
+            })
+        );
+        assert_eq!(parse(".import"), Err(CommandError::LackOfFile));
+        assert_eq!(parse(".import foo.csv"), Err(CommandError::LackOfTable));
+        assert_eq!(
+            parse(".import foo.csv Foo --force"),
+            Err(CommandError::WrongOption("--force".into()))
+        );
+        assert_eq!(
+            parse(".export SELECT id, name  FROM Foo WHERE id > 1 out.jsonl;"),
+            Ok(Command::Export {
+                query: "SELECT id, name  FROM Foo WHERE id > 1".into(),
+                path: "out.jsonl".into(),
+            })
+        );
+        assert_eq!(parse(".export"), Err(CommandError::LackOfQuery));
+        assert_eq!(parse(".export out.csv"), Err(CommandError::LackOfQuery));
         assert_eq!(
             parse(".set colsep ,"),
             Err(CommandError::WrongOption("run .set tabular OFF".into()))
@@ -281,5 +391,56 @@ mod tests {
             Err(CommandError::WrongOption("abc".to_owned()))
         );
         assert_eq!(parse(".show"), Err(CommandError::LackOfOption));
+        assert_eq!(parse(".show mode"), Ok(Command::Show(ShowOption::Mode)));
+        assert_eq!(
+            parse(".show expanded"),
+            Ok(Command::Show(ShowOption::Expanded))
+        );
+        assert_eq!(
+            parse(".mode csv"),
+            Ok(Command::Set(SetOption::Mode(OutputMode::Csv)))
+        );
+        assert_eq!(
+            parse(".mode JSONL"),
+            Ok(Command::Set(SetOption::Mode(OutputMode::Jsonl)))
+        );
+        assert_eq!(parse(".mode"), Ok(Command::Show(ShowOption::Mode)));
+        assert_eq!(
+            parse(".mode xml"),
+            Err(CommandError::WrongOption("xml".to_owned()))
+        );
+        assert_eq!(
+            parse(".set mode html"),
+            Ok(Command::Set(SetOption::Mode(OutputMode::Html)))
+        );
+        assert_eq!(
+            parse(".set expanded on"),
+            Ok(Command::Set(SetOption::Expanded(true)))
+        );
+        assert_eq!(parse(".x"), Ok(Command::Set(SetOption::Expanded(true))));
+        assert_eq!(parse(".timer on"), Ok(Command::Set(SetOption::Timer(true))));
+        assert_eq!(parse(".timer"), Ok(Command::Show(ShowOption::Timer)));
+        assert_eq!(
+            parse(".timer maybe"),
+            Err(CommandError::WrongOption("maybe".into()))
+        );
+        assert_eq!(
+            parse(".set footer ON"),
+            Ok(Command::Set(SetOption::Footer(true)))
+        );
+        assert_eq!(parse(".show footer"), Ok(Command::Show(ShowOption::Footer)));
+
+        let mut option = PrintOption::default();
+        option.mode(OutputMode::Csv);
+        let parse = |command| Command::parse(command, &option);
+
+        assert_eq!(
+            parse(".set heading off"),
+            Ok(Command::Set(SetOption::Heading(false)))
+        );
+        assert_eq!(
+            parse(".set colsep :"),
+            Err(CommandError::WrongOption("run .set tabular OFF".into()))
+        );
     }
 }
@@ -0,0 +1,230 @@
+use {
+    anyhow::{Result, bail},
+    gluesql_core::{
+        ast::{Expr, ToSql},
+        data::{CustomFunction as StructCustomFunction, Schema, Value},
+        executor::{Payload, execute},
+        prelude::Glue,
+        query_builder::{Build, table},
+        store::{GStore, GStoreMut, Planner},
+    },
+    std::{
+        fs::File,
+        io::{Read, Write},
+        path::{Path, PathBuf},
+    },
+};
+
+pub(crate) const ROWS_PER_INSERT: usize = 100;
+
+/// Narrows down what [`dump_database`] writes.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DumpOptions {
+    /// Tables to dump, every table when empty
+    pub tables: Vec<String>,
+    /// `WHERE` condition applied to the rows of every dumped table
+    pub filter: Option<String>,
+}
+
+/// Writes the database as a SQL script that [`restore_database`] can replay.
+///
+/// Custom functions come first, then each table with its rows and indexes,
+/// ordered so that referenced tables are created and filled before the tables
+/// whose foreign keys point at them. Triggers come last, so restoring the rows
+/// does not fire them.
+pub fn dump_database<T: GStore + GStoreMut + Planner>(
+    storage: &mut T,
+    dump_path: PathBuf,
+    DumpOptions { tables, filter }: &DumpOptions,
+) -> Result<()> {
+    let (schemas, functions, triggers) = autocommit(storage, |storage| {
+        let schemas = storage.fetch_all_schemas()?;
+        // storages without custom function support cannot hold any to dump
+        let functions = storage
+            .fetch_all_functions()
+            .unwrap_or_default()
+            .into_iter()
+            .map(StructCustomFunction::to_ddl)
+            .collect::<Vec<_>>();
+        let triggers = storage.fetch_all_triggers()?;
+
+        Ok((schemas, functions, triggers))
+    })?;
+    let schemas = sort_by_foreign_keys(select_tables(schemas, tables)?);
+    let file = File::create(dump_path)?;
+
+    for function in &functions {
+        writeln!(&file, "{function}")?;
+    }
+
+    if !functions.is_empty() {
+        writeln!(&file)?;
+    }
+
+    for schema in &schemas {
+        let table_name = &schema.table_name;
+        writeln!(&file, "{}", schema.to_ddl())?;
+
+        match filter {
+            Some(filter) => {
+                let rows = select_rows(storage, table_name, filter)?;
+
+                write_inserts(&file, table_name, rows.into_iter().map(Ok))?;
+            }
+            None => autocommit(storage, |storage| {
+                let rows = storage
+                    .scan_data(table_name)?
+                    .map(|result| result.map(|(_, row)| row));
+
+                write_inserts(&file, table_name, rows)
+            })?,
+        }
+
+        writeln!(&file)?;
+    }
+
+    let triggers = triggers.into_iter().filter(|trigger| {
+        schemas
+            .iter()
+            .any(|schema| schema.table_name == trigger.table_name)
+    });
+
+    for trigger in triggers {
+        writeln!(&file, "{}", trigger.to_ddl())?;
+    }
+
+    Ok(())
+}
+
+/// Executes a script written by [`dump_database`] and returns the number of statements run.
+pub fn restore_database<T: GStore + GStoreMut + Planner>(
+    glue: &mut Glue<T>,
+    dump_path: &Path,
+) -> Result<usize> {
+    let mut sqls = String::new();
+    File::open(dump_path)?.read_to_string(&mut sqls)?;
+
+    let payloads = glue.execute(&sqls)?;
+
+    Ok(payloads.len())
+}
+
+pub(crate) fn autocommit<T: GStoreMut, U>(
+    storage: &mut T,
+    read: impl FnOnce(&mut T) -> Result<U>,
+) -> Result<U> {
+    let autocommit = storage.begin(true)?;
+    let result = read(storage);
+
+    if !autocommit {
+        return result;
+    }
+
+    match result {
+        Ok(value) => {
+            storage.commit()?;
+
+            Ok(value)
+        }
+        Err(error) => {
+            storage.rollback()?;
+
+            Err(error)
+        }
+    }
+}
+
+fn select_tables(schemas: Vec<Schema>, tables: &[String]) -> Result<Vec<Schema>> {
+    if tables.is_empty() {
+        return Ok(schemas);
+    }
+
+    if let Some(table_name) = tables.iter().find(|table_name| {
+        !schemas
+            .iter()
+            .any(|schema| &&schema.table_name == table_name)
+    }) {
+        bail!("table not found: {table_name}");
+    }
+
+    Ok(schemas
+        .into_iter()
+        .filter(|schema| tables.contains(&schema.table_name))
+        .collect())
+}
+
+/// Moves every table behind the dumped tables it references, keeping the
+/// original order otherwise. Tables left in a reference cycle keep their order.
+fn sort_by_foreign_keys(mut pending: Vec<Schema>) -> Vec<Schema> {
+    let mut sorted = Vec::with_capacity(pending.len());
+
+    while !pending.is_empty() {
+        let ready = pending
+            .iter()
+            .position(|schema| {
+                schema.foreign_keys.iter().all(|foreign_key| {
+                    let referenced = &foreign_key.referenced_table_name;
+
+                    referenced == &schema.table_name
+                        || !pending
+                            .iter()
+                            .any(|pending| &pending.table_name == referenced)
+                })
+            })
+            .unwrap_or(0);
+
+        sorted.push(pending.remove(ready));
+    }
+
+    sorted
+}
+
+fn select_rows<T: GStore + GStoreMut + Planner>(
+    storage: &mut T,
+    table_name: &str,
+    filter: &str,
+) -> Result<Vec<Vec<Value>>> {
+    let statement = table(table_name).select().filter(filter).build()?;
+    let statement = storage.plan(statement)?;
+
+    match execute(storage, &statement)? {
+        Payload::Select { rows, .. } => Ok(rows),
+        Payload::SelectMap(rows) => Ok(rows.into_iter().map(|row| vec![Value::Map(row)]).collect()),
+        payload => bail!("unexpected payload while dumping {table_name}: {payload:?}"),
+    }
+}
+
+fn write_inserts(
+    mut file: &File,
+    table_name: &str,
+    mut rows: impl Iterator<Item = gluesql_core::error::Result<Vec<Value>>>,
+) -> Result<()> {
+    loop {
+        let exprs_list = rows
+            .by_ref()
+            .take(ROWS_PER_INSERT)
+            .map(|result| result.map(|row| row.into_iter().map(Expr::Value).collect::<Vec<_>>()))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        if exprs_list.is_empty() {
+            break;
+        }
+
+        let values = exprs_list
+            .into_iter()
+            .map(|exprs| {
+                let row = exprs
+                    .into_iter()
+                    .map(|expr| expr.to_sql())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("({row})")
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        writeln!(file, r#"INSERT INTO "{table_name}" VALUES {values};"#)?;
+    }
+
+    Ok(())
+}
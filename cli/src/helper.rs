@@ -1,22 +1,634 @@
 use {
+    gluesql_core::ast::{AggregateFunction, Function},
     rustyline::{
-        Result,
+        Context, Result,
+        completion::{Completer, Pair},
+        highlight::Highlighter,
         validate::{ValidationContext, ValidationResult, Validator},
     },
-    rustyline_derive::{Completer, Helper, Highlighter, Hinter},
+    rustyline_derive::{Helper, Hinter},
+    std::borrow::Cow,
 };
 
-#[derive(Completer, Helper, Highlighter, Hinter)]
-pub struct CliHelper;
+const KEYWORDS: &[&str] = &[
+    "ADD",
+    "AFTER",
+    "ALL",
+    "ALTER",
+    "AND",
+    "AS",
+    "ASC",
+    "BEFORE",
+    "BEGIN",
+    "BETWEEN",
+    "BY",
+    "CASE",
+    "COLUMN",
+    "COMMIT",
+    "CREATE",
+    "CROSS",
+    "DEFAULT",
+    "DELETE",
+    "DESC",
+    "DISTINCT",
+    "DROP",
+    "EACH",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "EXISTS",
+    "EXPLAIN",
+    "FALSE",
+    "FETCH",
+    "FOR",
+    "FOREIGN",
+    "FROM",
+    "FULL",
+    "FUNCTION",
+    "GROUP",
+    "HAVING",
+    "IF",
+    "ILIKE",
+    "IN",
+    "INDEX",
+    "INNER",
+    "INSERT",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "JOIN",
+    "KEY",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "NOT",
+    "NULL",
+    "NULLS",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "PRIMARY",
+    "REFERENCES",
+    "RENAME",
+    "RETURN",
+    "RIGHT",
+    "ROLLBACK",
+    "ROW",
+    "SELECT",
+    "SET",
+    "SHOW",
+    "TABLE",
+    "TABLES",
+    "THEN",
+    "TO",
+    "TRANSACTION",
+    "TRIGGER",
+    "TRUE",
+    "TRUNCATE",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USING",
+    "VALUES",
+    "VERSION",
+    "VIEW",
+    "WHEN",
+    "WHERE",
+    "WITH",
+    "FUNCTIONS",
+    "COLUMNS",
+];
+
+const DOT_COMMANDS: &[&str] = &[
+    ".help",
+    ".quit",
+    ".tables",
+    ".functions",
+    ".columns",
+    ".version",
+    ".execute",
+    ".spool",
+    ".import",
+    ".export",
+    ".show",
+    ".set",
+    ".mode",
+    ".x",
+    ".edit",
+    ".run",
+];
+
+const PRINT_OPTIONS: &[&str] = &[
+    "tabular", "mode", "colsep", "colwrap", "heading", "expanded",
+];
+
+const OUTPUT_MODES: &[&str] = &[
+    "table", "list", "csv", "json", "jsonl", "markdown", "html", "line",
+];
+
+/// Keywords after which only a table name can follow.
+const TABLE_KEYWORDS: &[&str] = &["FROM", "JOIN", "INTO", "UPDATE", "TABLE", "TRUNCATE"];
+
+const KEYWORD_STYLE: &str = "\x1b[1;34m";
+const STRING_STYLE: &str = "\x1b[32m";
+const NUMBER_STYLE: &str = "\x1b[33m";
+const COMMENT_STYLE: &str = "\x1b[90m";
+const BRACKET_STYLE: &str = "\x1b[1;4;35m";
+const RESET_STYLE: &str = "\x1b[0m";
+
+/// Tables, columns and custom functions of the storage, refreshed before
+/// each prompt so that completion sees the latest schema.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Catalog {
+    /// Table names with their column names
+    pub tables: Vec<(String, Vec<String>)>,
+    pub functions: Vec<String>,
+}
+
+#[derive(Helper, Hinter, Default)]
+pub struct CliHelper {
+    pub catalog: Catalog,
+}
+
+impl CliHelper {
+    fn candidates(&self, line: &str, pos: usize) -> (usize, Vec<String>) {
+        let before = &line[..pos];
+        let start = before
+            .rfind(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+            .map_or(0, |index| index + 1);
+        let word = &before[start..];
+        let mut tokens = before[..start].split_whitespace();
+
+        if before.trim_start().starts_with('.') {
+            let candidates: Vec<&str> = match tokens.next() {
+                None => DOT_COMMANDS.to_vec(),
+                Some(".columns") => self.table_names().collect(),
+                Some(".set" | ".show") if tokens.next().is_none() => PRINT_OPTIONS.to_vec(),
+                Some(".mode") => OUTPUT_MODES.to_vec(),
+                Some(".set") if before.contains(" mode ") => OUTPUT_MODES.to_vec(),
+                Some(_) => Vec::new(),
+            };
+
+            return (start, matching(&candidates, word, false));
+        }
+
+        if let Some((table_name, prefix)) = word.rsplit_once('.') {
+            let columns = self
+                .catalog
+                .tables
+                .iter()
+                .filter(|(name, _)| name.eq_ignore_ascii_case(table_name))
+                .flat_map(|(_, columns)| columns.iter().map(String::as_str))
+                .collect::<Vec<_>>();
+
+            return (
+                start + table_name.len() + 1,
+                matching(&columns, prefix, false),
+            );
+        }
+
+        let previous = before[..start].split_whitespace().next_back();
+        if previous.is_some_and(|previous| {
+            TABLE_KEYWORDS
+                .iter()
+                .any(|keyword| keyword.eq_ignore_ascii_case(previous))
+        }) {
+            let tables = self.table_names().collect::<Vec<_>>();
+
+            return (start, matching(&tables, word, false));
+        }
+
+        if word.is_empty() {
+            return (start, Vec::new());
+        }
+
+        let upper = word.chars().next().is_some_and(char::is_uppercase);
+        let mut candidates = self.columns_in_scope(line);
+        candidates.extend(self.table_names());
+        candidates.extend(self.catalog.functions.iter().map(String::as_str));
+        let mut candidates = matching(&candidates, word, false);
+        candidates.extend(matching(KEYWORDS, word, !upper));
+        candidates.extend(matching(Function::NAMES, word, !upper));
+        candidates.extend(matching(AggregateFunction::NAMES, word, !upper));
+        candidates.sort();
+        candidates.dedup();
+
+        (start, candidates)
+    }
+
+    fn table_names(&self) -> impl Iterator<Item = &str> {
+        self.catalog.tables.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Columns of the tables mentioned anywhere in the line.
+    fn columns_in_scope(&self, line: &str) -> Vec<&str> {
+        let words = line
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .collect::<Vec<_>>();
+
+        self.catalog
+            .tables
+            .iter()
+            .filter(|(name, _)| words.iter().any(|word| word.eq_ignore_ascii_case(name)))
+            .flat_map(|(_, columns)| columns.iter().map(String::as_str))
+            .collect()
+    }
+}
+
+fn matching(candidates: &[&str], word: &str, lowercase: bool) -> Vec<String> {
+    let word = word.to_lowercase();
+
+    candidates
+        .iter()
+        .filter(|candidate| candidate.to_lowercase().starts_with(&word))
+        .map(|candidate| {
+            if lowercase {
+                candidate.to_lowercase()
+            } else {
+                (*candidate).to_owned()
+            }
+        })
+        .collect()
+}
+
+impl Completer for CliHelper {
+    type Candidate = Pair;
+
+    fn complete(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> Result<(usize, Vec<Pair>)> {
+        let (start, candidates) = self.candidates(line, pos);
+        let candidates = candidates
+            .into_iter()
+            .map(|candidate| Pair {
+                display: candidate.clone(),
+                replacement: candidate,
+            })
+            .collect();
+
+        Ok((start, candidates))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Keyword,
+    String,
+    /// String literal missing its closing quote
+    OpenString,
+    Number,
+    Comment,
+    Bracket,
+    Other,
+}
+
+/// Splits input into `(start, end, kind)` spans; an unterminated string runs to
+/// the end of the input and a comment to the end of its line.
+fn tokenize(line: &str) -> Vec<(usize, usize, TokenKind)> {
+    let mut tokens = Vec::new();
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let kind = match c {
+            '\'' | '"' => {
+                let mut kind = TokenKind::OpenString;
+                while let Some((_, next)) = chars.next() {
+                    if next == c && chars.next_if(|(_, next)| *next == c).is_none() {
+                        kind = TokenKind::String;
+                        break;
+                    }
+                }
+
+                kind
+            }
+            '-' if chars.peek().is_some_and(|(_, next)| *next == '-') => {
+                while chars.next_if(|(_, next)| *next != '\n').is_some() {}
+
+                TokenKind::Comment
+            }
+            '(' | ')' | '[' | ']' => TokenKind::Bracket,
+            c if c.is_ascii_digit() => {
+                while chars
+                    .next_if(|(_, next)| next.is_ascii_alphanumeric() || *next == '.')
+                    .is_some()
+                {}
+
+                TokenKind::Number
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                while chars
+                    .next_if(|(_, next)| next.is_alphanumeric() || *next == '_')
+                    .is_some()
+                {}
+
+                let end = chars.peek().map_or(line.len(), |(index, _)| *index);
+                let word = &line[start..end];
+
+                if KEYWORDS
+                    .iter()
+                    .any(|keyword| keyword.eq_ignore_ascii_case(word))
+                {
+                    TokenKind::Keyword
+                } else {
+                    TokenKind::Other
+                }
+            }
+            _ => TokenKind::Other,
+        };
+
+        let end = chars.peek().map_or(line.len(), |(index, _)| *index);
+        tokens.push((start, end, kind));
+    }
+
+    tokens
+}
+
+/// Finds the bracket matching the one at or right before the cursor.
+fn matching_brackets(line: &str, pos: usize) -> Option<(usize, usize)> {
+    let brackets = tokenize(line)
+        .into_iter()
+        .filter(|(_, _, kind)| *kind == TokenKind::Bracket)
+        .map(|(start, _, _)| (start, line.as_bytes()[start]))
+        .collect::<Vec<_>>();
+
+    let cursor = brackets
+        .iter()
+        .position(|(index, _)| *index == pos)
+        .or_else(|| brackets.iter().position(|(index, _)| *index + 1 == pos))?;
+    let (index, bracket) = brackets[cursor];
+    let (pair, forward) = match bracket {
+        b'(' => (b')', true),
+        b'[' => (b']', true),
+        b')' => (b'(', false),
+        _ => (b'[', false),
+    };
+
+    let others: Box<dyn Iterator<Item = &(usize, u8)>> = if forward {
+        Box::new(brackets[cursor + 1..].iter())
+    } else {
+        Box::new(brackets[..cursor].iter().rev())
+    };
+
+    let mut depth = 0;
+    for &(other, c) in others {
+        if c == bracket {
+            depth += 1;
+        } else if c == pair {
+            if depth == 0 {
+                return Some((index, other));
+            }
+
+            depth -= 1;
+        }
+    }
+
+    None
+}
+
+impl Highlighter for CliHelper {
+    fn highlight<'l>(&self, line: &'l str, pos: usize) -> Cow<'l, str> {
+        if line.trim_start().starts_with('.') {
+            return Cow::Borrowed(line);
+        }
+
+        let brackets = matching_brackets(line, pos);
+        let mut highlighted = String::with_capacity(line.len() * 2);
+
+        for (start, end, kind) in tokenize(line) {
+            let style = match kind {
+                TokenKind::Keyword => KEYWORD_STYLE,
+                TokenKind::String | TokenKind::OpenString => STRING_STYLE,
+                TokenKind::Number => NUMBER_STYLE,
+                TokenKind::Comment => COMMENT_STYLE,
+                TokenKind::Bracket
+                    if brackets.is_some_and(|(left, right)| start == left || start == right) =>
+                {
+                    BRACKET_STYLE
+                }
+                TokenKind::Bracket | TokenKind::Other => {
+                    highlighted.push_str(&line[start..end]);
+                    continue;
+                }
+            };
+
+            highlighted.push_str(style);
+            highlighted.push_str(&line[start..end]);
+            highlighted.push_str(RESET_STYLE);
+        }
+
+        Cow::Owned(highlighted)
+    }
+
+    fn highlight_char(&self, line: &str, _pos: usize) -> bool {
+        !line.is_empty()
+    }
+}
 
 impl Validator for CliHelper {
     fn validate(&self, ctx: &mut ValidationContext<'_>) -> Result<ValidationResult> {
-        let input = ctx.input().trim();
+        let input = ctx.input();
 
-        if input.ends_with(';') || input.starts_with('.') {
+        if input.trim_start().starts_with('.') || is_complete(input) {
             Ok(ValidationResult::Valid(None))
         } else {
             Ok(ValidationResult::Incomplete)
         }
     }
 }
+
+/// Input is complete once it ends with a semicolon outside of any string,
+/// comment, bracket, `CASE ... END` or trigger `BEGIN ... END` block.
+pub(crate) fn is_complete(input: &str) -> bool {
+    let tokens = significant_tokens(input);
+    if tokens
+        .iter()
+        .any(|(_, _, kind)| *kind == TokenKind::OpenString)
+    {
+        return false;
+    }
+
+    statement_ends(input, &tokens)
+        .last()
+        .is_some_and(|end| *end + 1 == tokens.len())
+}
+
+/// Splits a script into its statements, each ending with the semicolon that
+/// completes it in the sense of [`is_complete`]. Chunks holding nothing but
+/// comments are dropped.
+pub(crate) fn split_statements(input: &str) -> Vec<&str> {
+    let tokens = significant_tokens(input);
+    let mut statements = Vec::new();
+    let mut first = 0;
+
+    for end in statement_ends(input, &tokens) {
+        if end > first {
+            statements.push(&input[tokens[first].0..tokens[end].1]);
+        }
+
+        first = end + 1;
+    }
+
+    if let (Some((start, _, _)), Some((_, end, _))) = (tokens.get(first), tokens.last()) {
+        statements.push(&input[*start..*end]);
+    }
+
+    statements
+}
+
+fn significant_tokens(input: &str) -> Vec<(usize, usize, TokenKind)> {
+    tokenize(input)
+        .into_iter()
+        .filter(|(start, end, kind)| {
+            *kind != TokenKind::Comment && !input[*start..*end].trim().is_empty()
+        })
+        .collect()
+}
+
+/// Indexes of the `;` tokens that end a statement.
+fn statement_ends(input: &str, tokens: &[(usize, usize, TokenKind)]) -> Vec<usize> {
+    let text = |(start, end, _): &(usize, usize, TokenKind)| &input[*start..*end];
+
+    let mut ends = Vec::new();
+    let mut depth = 0;
+    for (index, token) in tokens.iter().enumerate() {
+        let (_, _, kind) = token;
+        let keyword =
+            |name: &str| *kind == TokenKind::Keyword && text(token).eq_ignore_ascii_case(name);
+
+        match kind {
+            TokenKind::Bracket if matches!(text(token), "(" | "[") => depth += 1,
+            TokenKind::Bracket => depth -= 1,
+            _ if keyword("CASE") => depth += 1,
+            _ if keyword("END") => depth -= 1,
+            _ if keyword("BEGIN") => {
+                let transaction = tokens
+                    .get(index + 1)
+                    .map(text)
+                    .is_none_or(|next| next == ";" || next.eq_ignore_ascii_case("TRANSACTION"));
+
+                if !transaction {
+                    depth += 1;
+                }
+            }
+            _ if text(token) == ";" && depth <= 0 => {
+                ends.push(index);
+                depth = 0;
+            }
+            _ => {}
+        }
+    }
+
+    ends
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{Catalog, CliHelper, is_complete, matching_brackets, split_statements},
+        rustyline::highlight::Highlighter,
+    };
+
+    fn helper() -> CliHelper {
+        CliHelper {
+            catalog: Catalog {
+                tables: vec![
+                    ("Item".to_owned(), vec!["id".to_owned(), "name".to_owned()]),
+                    ("Order".to_owned(), vec!["item_id".to_owned()]),
+                ],
+                functions: vec!["add_tax".to_owned()],
+            },
+        }
+    }
+
+    #[test]
+    fn complete() {
+        let complete = |line: &str| helper().candidates(line, line.len());
+        let owned = |candidates: &[&str]| {
+            candidates
+                .iter()
+                .map(|candidate| (*candidate).to_owned())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(complete(".ta"), (0, owned(&[".tables"])));
+        assert_eq!(complete(".columns O"), (9, owned(&["Order"])));
+        assert_eq!(complete(".set hea"), (5, owned(&["heading"])));
+        assert_eq!(complete(".mode js"), (6, owned(&["json", "jsonl"])));
+        assert_eq!(complete("SELECT * FROM "), (14, owned(&["Item", "Order"])));
+        assert_eq!(complete("select * from it"), (14, owned(&["Item"])));
+        assert_eq!(complete("SELECT Item.n"), (12, owned(&["name"])));
+        assert_eq!(
+            helper().candidates("SELECT na FROM Item", 9),
+            (7, owned(&["name"]))
+        );
+        assert_eq!(complete("SELECT na"), (7, Vec::new()));
+        assert_eq!(
+            complete("SELECT add"),
+            (7, owned(&["add", "add_month", "add_tax"]))
+        );
+        assert_eq!(complete("sel"), (0, owned(&["select"])));
+        assert_eq!(complete("SELECT STRI"), (7, owned(&["STRING_AGG"])));
+        assert_eq!(complete("SELECT "), (7, Vec::new()));
+    }
+
+    #[test]
+    fn brackets() {
+        let line = "SELECT (1 + (2)) * [3]";
+
+        assert_eq!(matching_brackets(line, 7), Some((7, 15)));
+        assert_eq!(matching_brackets(line, 16), Some((15, 7)));
+        assert_eq!(matching_brackets(line, 12), Some((12, 14)));
+        assert_eq!(matching_brackets(line, 19), Some((19, 21)));
+        assert_eq!(matching_brackets(line, 2), None);
+        assert_eq!(matching_brackets("SELECT (1", 7), None);
+    }
+
+    #[test]
+    fn highlight() {
+        assert_eq!(
+            helper().highlight("select (1), 'a' -- b", 7),
+            "\x1b[1;34mselect\x1b[0m \x1b[1;4;35m(\x1b[0m\x1b[33m1\x1b[0m\x1b[1;4;35m)\x1b[0m, \x1b[32m'a'\x1b[0m \x1b[90m-- b\x1b[0m"
+        );
+        assert_eq!(helper().highlight(".tables", 0), ".tables");
+    }
+
+    #[test]
+    fn validate() {
+        assert!(is_complete("SELECT 1;"));
+        assert!(is_complete("SELECT 1; -- done"));
+        assert!(is_complete("SELECT\n  'a;b'\nFROM Foo;"));
+        assert!(is_complete("SELECT 'it''s';"));
+        assert!(!is_complete("SELECT 1"));
+        assert!(!is_complete("SELECT 'a;"));
+        assert!(!is_complete("SELECT 1 -- ;"));
+        assert!(!is_complete("CREATE FUNCTION f() RETURN (1;"));
+        assert!(!is_complete(""));
+        assert!(is_complete("SELECT CASE WHEN TRUE THEN 1 END;"));
+        assert!(is_complete("BEGIN;"));
+        assert!(!is_complete(
+            "CREATE TRIGGER t AFTER INSERT ON Foo FOR EACH ROW\nBEGIN\n    INSERT INTO Log VALUES (1);"
+        ));
+        assert!(is_complete(
+            "CREATE TRIGGER t AFTER INSERT ON Foo FOR EACH ROW\nBEGIN\n    INSERT INTO Log VALUES (1);\nEND;"
+        ));
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(
+            split_statements("SELECT 1;\n-- note\nSELECT 'a;b'; ;\nSELECT 2"),
+            vec!["SELECT 1;", "SELECT 'a;b';", "SELECT 2"]
+        );
+        assert_eq!(
+            split_statements(
+                "BEGIN;\nCREATE TRIGGER t AFTER INSERT ON Foo FOR EACH ROW\nBEGIN\n    INSERT INTO Log VALUES (1);\nEND;\nCOMMIT;\n-- done\n"
+            ),
+            vec![
+                "BEGIN;",
+                "CREATE TRIGGER t AFTER INSERT ON Foo FOR EACH ROW\nBEGIN\n    INSERT INTO Log VALUES (1);\nEND;",
+                "COMMIT;",
+            ]
+        );
+        assert!(split_statements("-- only a comment\n").is_empty());
+    }
+}
@@ -2,17 +2,32 @@
 
 mod cli;
 mod command;
+mod dump;
 mod helper;
 mod print;
+mod storage;
+mod transfer;
 mod upgrade;
 
+pub use {
+    dump::{DumpOptions, dump_database, restore_database},
+    transfer::{export_query, import_file},
+};
+
 use {
-    crate::cli::Cli,
+    crate::{
+        cli::Cli,
+        print::OutputMode,
+        storage::{
+            DEFAULT_MONGO_URL, DEFAULT_REDIS_URL, GitRemote, GitType, open_composite, open_git,
+            open_mongo, open_redis, push_git,
+        },
+    },
     anyhow::Result,
     clap::Parser,
     gluesql_core::{
-        ast::{Expr, ToSql},
-        store::{GStore, GStoreMut, Planner, Store, Transaction},
+        prelude::Glue,
+        store::{GStore, GStoreMut, Planner},
     },
     gluesql_csv_storage::CsvStorage,
     gluesql_file_storage::FileStorage,
@@ -21,7 +36,7 @@ use {
     gluesql_parquet_storage::ParquetStorage,
     gluesql_redb_storage::RedbStorage,
     gluesql_sled_storage::SledStorage,
-    std::{fmt::Debug, fs::File, io::Write, path::PathBuf},
+    std::{fmt::Debug, path::PathBuf},
 };
 
 #[derive(Parser, Debug)]
@@ -31,28 +46,69 @@ struct Args {
     #[clap(short, long, value_parser)]
     execute: Option<PathBuf>,
 
+    /// Print the results of --execute in this format and exit without the prompt
+    #[clap(long, value_parser, requires = "execute")]
+    output_format: Option<OutputMode>,
+
     /// PATH to dump whole database
     #[clap(short, long, value_parser)]
     dump: Option<PathBuf>,
 
+    /// PATH of a dump file to restore into the storage
+    #[clap(short, long, value_parser, conflicts_with_all = &["execute", "dump"])]
+    restore: Option<PathBuf>,
+
+    /// Tables to dump, separated by commas; default is every table
+    #[clap(long, value_parser, value_delimiter = ',', requires = "dump")]
+    tables: Vec<String>,
+
+    /// Dump only the rows matching this condition, e.g. "id > 100"
+    #[clap(long = "where", value_parser, requires = "dump")]
+    filter: Option<String>,
+
     /// Storage type to store data, default is memory
     #[clap(short, long, value_parser)]
     storage: Option<Storage>,
 
-    /// Storage path to load
+    /// Storage path to load, or the config file of composite storage
     #[clap(short, long, value_parser)]
     path: Option<PathBuf>,
 
+    /// Connection string of mongo or redis storage
+    #[clap(long, value_parser)]
+    url: Option<String>,
+
+    /// Database of mongo storage or key namespace of redis storage, default is gluesql
+    #[clap(long, value_parser)]
+    database: Option<String>,
+
+    /// Format of the files in git storage, default is file
+    #[clap(long, value_parser)]
+    git_type: Option<GitType>,
+
+    /// Git remote to pull from before the session and push to after it
+    #[clap(long, value_parser)]
+    git_remote: Option<String>,
+
+    /// Branch of --git-remote, default is main
+    #[clap(long, value_parser, requires = "git-remote")]
+    git_branch: Option<String>,
+
+    /// Startup file of dot commands and SQL run before the prompt, default is ~/.gluesqlrc
+    #[clap(long, value_parser, conflicts_with_all = &["dump", "restore", "output-format"])]
+    rc: Option<PathBuf>,
+
     /// Upgrade storage data format to the latest version
     #[clap(
         long,
         requires_all = &["storage", "path"],
-        conflicts_with_all = &["execute", "dump"]
+        conflicts_with_all = &["execute", "dump", "restore"]
     )]
     upgrade: bool,
 }
 
-#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(clap::ValueEnum, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
 enum Storage {
     Memory,
     Sled,
@@ -61,156 +117,250 @@ enum Storage {
     Csv,
     Parquet,
     File,
+    Git,
+    Mongo,
+    Redis,
+    Composite,
+}
+
+enum Mode {
+    Console {
+        execute: Option<PathBuf>,
+        rc: Option<PathBuf>,
+    },
+    Script {
+        execute: PathBuf,
+        output_format: OutputMode,
+    },
+    Dump {
+        dump_path: PathBuf,
+        options: DumpOptions,
+    },
+    Restore {
+        restore_path: PathBuf,
+    },
 }
 
 pub fn run() -> Result<()> {
-    fn run<T: GStore + GStoreMut + Planner>(storage: T, input: Option<PathBuf>) {
-        let output = std::io::stdout();
-        let mut cli = Cli::new(storage, output);
-
-        if let Some(path) = input
-            && let Err(e) = cli.load(path.as_path())
-        {
-            println!("[error] {e}\n");
+    fn run<T: GStore + GStoreMut + Planner>(mut storage: T, mode: Mode) -> Result<()> {
+        match mode {
+            Mode::Console { execute, rc } => {
+                let output = std::io::stdout();
+                let mut cli = Cli::new(storage, output);
+
+                if let Some(path) = dirs_next::config_dir() {
+                    cli.set_history_path(path.join("gluesql").join("history"));
+                }
+
+                // the default startup file is optional, an explicit one is not
+                let rc = rc.or_else(|| {
+                    dirs_next::home_dir()
+                        .map(|path| path.join(".gluesqlrc"))
+                        .filter(|path| path.is_file())
+                });
+                if let Some(path) = rc
+                    && let Err(e) = cli.run_rc_file(&path)
+                {
+                    println!("[error] {}: {e}\n", path.display());
+                }
+
+                if let Some(path) = execute
+                    && let Err(e) = cli.load(path.as_path())
+                {
+                    println!("[error] {e}\n");
+                }
+
+                if let Err(e) = cli.run() {
+                    eprintln!("{e}");
+                }
+            }
+            Mode::Script {
+                execute,
+                output_format,
+            } => {
+                let mut cli = Cli::new(storage, std::io::stdout());
+                cli.set_output_mode(output_format);
+
+                if let Err(e) = cli.execute_script(execute) {
+                    eprintln!("[error] {e}");
+                    std::process::exit(1);
+                }
+            }
+            Mode::Dump { dump_path, options } => {
+                dump_database(&mut storage, dump_path, &options)?;
+            }
+            Mode::Restore { restore_path } => {
+                let mut glue = Glue::new(storage);
+                let count = restore_database(&mut glue, &restore_path)?;
+
+                println!(
+                    "[restore] {count} statements executed from {}",
+                    restore_path.display()
+                );
+            }
         }
 
-        if let Err(e) = cli.run() {
-            eprintln!("{e}");
-        }
+        Ok(())
     }
 
     let Args {
         execute,
+        output_format,
         dump,
+        restore,
+        tables,
+        filter,
         storage,
         path,
+        url,
+        database,
+        git_type,
+        git_remote,
+        git_branch,
+        rc,
         upgrade,
     } = Args::parse();
 
     if upgrade {
-        return upgrade::run_upgrade(path.as_deref(), storage, execute.is_some(), dump.is_some());
+        return upgrade::run_upgrade(
+            path.as_deref(),
+            storage,
+            execute.is_some(),
+            dump.is_some() || restore.is_some(),
+        );
     }
 
+    let mode = match (dump, restore) {
+        (Some(dump_path), _) => Mode::Dump {
+            dump_path,
+            options: DumpOptions { tables, filter },
+        },
+        (None, Some(restore_path)) => Mode::Restore { restore_path },
+        (None, None) => match (execute, output_format) {
+            (Some(execute), Some(output_format)) => Mode::Script {
+                execute,
+                output_format,
+            },
+            (execute, _) => Mode::Console { execute, rc },
+        },
+    };
     let path = path.as_deref();
+    let url = url.as_deref();
+    let database = database.as_deref();
 
-    match (path, storage, dump) {
-        (None, None | Some(Storage::Memory), _) => {
-            println!("[memory-storage] initialized");
+    match (path, storage, mode) {
+        (None, None | Some(Storage::Memory), mode) => {
+            eprintln!("[memory-storage] initialized");
 
-            run(MemoryStorage::default(), execute);
+            run(MemoryStorage::default(), mode)
         }
         (Some(_), Some(Storage::Memory), _) => {
             panic!("failed to load memory-storage: it should be without path");
         }
-        (Some(path), Some(Storage::Sled), _) => {
-            println!("[sled-storage] connected to {}", path.display());
+        (Some(path), Some(Storage::Sled), mode) => {
+            eprintln!("[sled-storage] connected to {}", path.display());
 
             run(
                 SledStorage::new(path).expect("failed to load sled-storage"),
-                execute,
-            );
+                mode,
+            )
         }
-        (Some(path), Some(Storage::Redb), _) => {
-            println!("[redb-storage] connected to {}", path.display());
+        (Some(path), Some(Storage::Redb), mode) => {
+            eprintln!("[redb-storage] connected to {}", path.display());
 
             run(
                 RedbStorage::new(path).expect("failed to load redb-storage"),
-                execute,
-            );
+                mode,
+            )
         }
-        (Some(path), Some(Storage::Json), _) => {
-            println!("[json-storage] connected to {}", path.display());
+        (Some(path), Some(Storage::Json), mode) => {
+            eprintln!("[json-storage] connected to {}", path.display());
 
             run(
                 JsonStorage::new(path).expect("failed to load json-storage"),
-                execute,
-            );
+                mode,
+            )
         }
-        (Some(path), Some(Storage::Csv), _) => {
-            println!("[csv-storage] connected to {}", path.display());
+        (Some(path), Some(Storage::Csv), mode) => {
+            eprintln!("[csv-storage] connected to {}", path.display());
 
             run(
                 CsvStorage::new(path).expect("failed to load csv-storage"),
-                execute,
-            );
+                mode,
+            )
         }
-        (Some(path), Some(Storage::Parquet), _) => {
-            println!("[parquet-storage] connected to {}", path.display());
+        (Some(path), Some(Storage::Parquet), mode) => {
+            eprintln!("[parquet-storage] connected to {}", path.display());
 
             run(
                 ParquetStorage::new(path).expect("failed to load parquet-storage"),
-                execute,
-            );
+                mode,
+            )
         }
-        (Some(path), Some(Storage::File), _) => {
-            println!("[file-storage] connected to {}", path.display());
+        (Some(path), Some(Storage::File), mode) => {
+            eprintln!("[file-storage] connected to {}", path.display());
 
             run(
                 FileStorage::new(path).expect("failed to load file-storage"),
-                execute,
+                mode,
+            )
+        }
+        (Some(path), Some(Storage::Git), mode) => {
+            let git_type = git_type.unwrap_or_default();
+            let remote = git_remote.map(|remote| GitRemote {
+                remote,
+                branch: git_branch,
+            });
+            let storage =
+                open_git(path, git_type, remote.as_ref()).expect("failed to load git-storage");
+            eprintln!("[git-storage] connected to {}", path.display());
+
+            run(storage, mode)?;
+
+            match remote {
+                Some(remote) => push_git(path, git_type, &remote),
+                None => Ok(()),
+            }
+        }
+        (None, Some(Storage::Mongo), mode) => {
+            let storage = open_mongo(url, database).expect("failed to load mongo-storage");
+            eprintln!(
+                "[mongo-storage] connected to {}",
+                url.unwrap_or(DEFAULT_MONGO_URL)
             );
+
+            run(storage, mode)
         }
-        (Some(path), None, Some(dump_path)) => {
-            let mut storage = SledStorage::new(path).expect("failed to load sled-storage");
+        (None, Some(Storage::Redis), mode) => {
+            let storage = open_redis(url, database).expect("failed to load redis-storage");
+            eprintln!(
+                "[redis-storage] connected to {}",
+                url.unwrap_or(DEFAULT_REDIS_URL)
+            );
 
-            dump_database(&mut storage, dump_path)?;
+            run(storage, mode)
         }
-        (None, Some(_), _) | (Some(_), None, None) => {
-            panic!("both path and storage should be specified");
+        (Some(_), Some(Storage::Mongo | Storage::Redis), _) => {
+            panic!("failed to load storage: mongo and redis connect with --url, not --path");
         }
-    }
-
-    Ok(())
-}
-
-pub fn dump_database(storage: &mut SledStorage, dump_path: PathBuf) -> Result<()> {
-    let file = File::create(dump_path)?;
-
-    storage.begin(true)?;
-    let schemas = storage.fetch_all_schemas()?;
-    for schema in schemas {
-        writeln!(&file, "{}", schema.to_ddl())?;
-
-        let mut rows = storage
-            .scan_data(&schema.table_name)?
-            .map(|result| result.map(|(_, row)| row));
-
-        loop {
-            let exprs_list = rows
-                .by_ref()
-                .take(100)
-                .map(|result| {
-                    result.map(|row| row.into_iter().map(Expr::Value).collect::<Vec<_>>())
-                })
-                .collect::<std::result::Result<Vec<_>, _>>()?;
-
-            if exprs_list.is_empty() {
-                break;
-            }
+        (Some(path), Some(Storage::Composite), mode) => {
+            let storage = open_composite(path)?;
+            eprintln!(
+                "[composite-storage] mounted {} from {}",
+                storage.storages.len(),
+                path.display()
+            );
 
-            let values = exprs_list
-                .into_iter()
-                .map(|exprs| {
-                    let row = exprs
-                        .into_iter()
-                        .map(|expr| expr.to_sql())
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    format!("({row})")
-                })
-                .collect::<Vec<_>>()
-                .join(", ");
-
-            let insert_statement =
-                format!(r#"INSERT INTO "{}" VALUES {values};"#, schema.table_name);
-
-            writeln!(&file, "{insert_statement}")?;
+            run(storage, mode)
+        }
+        (Some(path), None, mode @ (Mode::Dump { .. } | Mode::Restore { .. })) => run(
+            SledStorage::new(path).expect("failed to load sled-storage"),
+            mode,
+        ),
+        (None, Some(_), _) | (Some(_), None, Mode::Console { .. } | Mode::Script { .. }) => {
+            panic!("both path and storage should be specified");
         }
-
-        writeln!(&file)?;
     }
-
-    Ok(())
 }
 
 #[cfg(test)]
@@ -252,4 +402,83 @@ mod tests {
         ]);
         assert!(args.is_err());
     }
+
+    #[test]
+    fn parse_dump_filters_require_dump() {
+        let args = Args::try_parse_from(["gluesql", "--tables", "Foo,Bar"]);
+        assert!(args.is_err());
+
+        let args = Args::try_parse_from(["gluesql", "--where", "id > 1"]);
+        assert!(args.is_err());
+
+        let args = Args::try_parse_from([
+            "gluesql", "--path", "./tmp", "--dump", "dump.sql", "--tables", "Foo,Bar", "--where",
+            "id > 1",
+        ])
+        .expect("dump options should parse");
+        assert_eq!(args.tables, vec!["Foo".to_owned(), "Bar".to_owned()]);
+        assert_eq!(args.filter.as_deref(), Some("id > 1"));
+    }
+
+    #[test]
+    fn parse_rc_rejects_dump() {
+        let args =
+            Args::try_parse_from(["gluesql", "--rc", "init.sql"]).expect("rc file should parse");
+        assert_eq!(args.rc, Some("init.sql".into()));
+
+        let args = Args::try_parse_from([
+            "gluesql", "--path", "./tmp", "--rc", "init.sql", "--dump", "dump.sql",
+        ]);
+        assert!(args.is_err());
+    }
+
+    #[test]
+    fn parse_storage_connections() {
+        let args = Args::try_parse_from([
+            "gluesql",
+            "--storage",
+            "redis",
+            "--url",
+            "redis://localhost:6379",
+            "--database",
+            "app",
+        ])
+        .expect("redis options should parse");
+        assert_eq!(args.url.as_deref(), Some("redis://localhost:6379"));
+        assert_eq!(args.database.as_deref(), Some("app"));
+
+        let args = Args::try_parse_from(["gluesql", "--git-branch", "dev"]);
+        assert!(args.is_err());
+
+        let args = Args::try_parse_from([
+            "gluesql",
+            "--storage",
+            "git",
+            "--path",
+            "./tmp",
+            "--git-type",
+            "json",
+            "--git-remote",
+            "origin",
+            "--git-branch",
+            "dev",
+        ])
+        .expect("git options should parse");
+        assert_eq!(args.git_type, Some(super::GitType::Json));
+        assert_eq!(args.git_branch.as_deref(), Some("dev"));
+    }
+
+    #[test]
+    fn parse_restore_rejects_dump() {
+        let args = Args::try_parse_from([
+            "gluesql",
+            "--path",
+            "./tmp",
+            "--restore",
+            "dump.sql",
+            "--dump",
+            "dump.sql",
+        ]);
+        assert!(args.is_err());
+    }
 }
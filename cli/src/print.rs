@@ -1,12 +1,14 @@
 use {
     crate::command::{SetOption, ShowOption},
-    gluesql_core::prelude::{Payload, PayloadVariable},
+    gluesql_core::prelude::{Payload, PayloadVariable, Value},
+    serde_json::Value as JsonValue,
     std::{
         collections::{BTreeMap, HashSet},
         fmt::Display,
         fs::File,
         io::{Result as IOResult, Write},
         path::Path,
+        time::Duration,
     },
     strum_macros::Display,
     tabled::{Style, Table, builder::Builder},
@@ -18,23 +20,62 @@ pub struct Print<W: Write> {
     pub option: PrintOption,
 }
 
+/// How result sets are written, selected with `.mode` or `--output-format`.
+#[derive(clap::ValueEnum, Display, Debug, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")]
+pub enum OutputMode {
+    /// Aligned table, the default
+    Table,
+    /// Values joined by `colsep` and wrapped in `colwrap`
+    List,
+    Csv,
+    /// One JSON array of row objects per result
+    Json,
+    /// One JSON object per row
+    Jsonl,
+    Markdown,
+    Html,
+    /// One `column = value` line per column
+    Line,
+}
+
+impl OutputMode {
+    /// Machine readable modes print only result sets, so the output can be piped
+    /// into other tools.
+    fn prints_messages(self) -> bool {
+        !matches!(self, Self::Csv | Self::Json | Self::Jsonl)
+    }
+}
+
+// each flag is an independent `.set` toggle
+#[allow(clippy::struct_excessive_bools)]
 pub struct PrintOption {
-    pub tabular: bool,
+    pub mode: OutputMode,
     colsep: String,
     colwrap: String,
     heading: bool,
+    pub expanded: bool,
+    timer: bool,
+    footer: bool,
 }
 
 impl PrintOption {
     pub fn tabular(&mut self, tabular: bool) {
         if tabular {
-            self.tabular = tabular;
+            self.mode(OutputMode::Table);
+        } else {
+            self.mode = OutputMode::List;
+        }
+    }
+
+    pub fn mode(&mut self, mode: OutputMode) {
+        if mode == OutputMode::Table {
             self.colsep("|".into());
             self.colwrap(String::new());
             self.heading(true);
-        } else {
-            self.tabular = tabular;
         }
+
+        self.mode = mode;
     }
 
     fn colsep(&mut self, colsep: String) {
@@ -49,21 +90,43 @@ impl PrintOption {
         self.heading = heading;
     }
 
+    fn expanded(&mut self, expanded: bool) {
+        self.expanded = expanded;
+    }
+
+    fn timer(&mut self, timer: bool) {
+        self.timer = timer;
+    }
+
+    fn footer(&mut self, footer: bool) {
+        self.footer = footer;
+    }
+
     fn format(&self, option: ShowOption) -> String {
         fn string_from(value: bool) -> String {
             if value { "ON".into() } else { "OFF".into() }
         }
         match option {
-            ShowOption::Tabular => format!("tabular {}", string_from(self.tabular)),
+            ShowOption::Tabular => {
+                format!("tabular {}", string_from(self.mode == OutputMode::Table))
+            }
+            ShowOption::Mode => format!("mode {}", self.mode),
             ShowOption::Colsep => format!("colsep \"{}\"", self.colsep),
             ShowOption::Colwrap => format!("colwrap \"{}\"", self.colwrap),
             ShowOption::Heading => format!("heading {}", string_from(self.heading)),
+            ShowOption::Expanded => format!("expanded {}", string_from(self.expanded)),
+            ShowOption::Timer => format!("timer {}", string_from(self.timer)),
+            ShowOption::Footer => format!("footer {}", string_from(self.footer)),
             ShowOption::All => format!(
-                "{}\n{}\n{}\n{}",
+                "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
                 self.format(ShowOption::Tabular),
+                self.format(ShowOption::Mode),
                 self.format(ShowOption::Colsep),
                 self.format(ShowOption::Colwrap),
                 self.format(ShowOption::Heading),
+                self.format(ShowOption::Expanded),
+                self.format(ShowOption::Timer),
+                self.format(ShowOption::Footer),
             ),
         }
     }
@@ -72,10 +135,13 @@ impl PrintOption {
 impl Default for PrintOption {
     fn default() -> Self {
         Self {
-            tabular: true,
+            mode: OutputMode::Table,
             colsep: "|".into(),
             colwrap: String::new(),
             heading: true,
+            expanded: false,
+            timer: false,
+            footer: false,
         }
     }
 }
@@ -102,6 +168,20 @@ impl<'a, W: Write> Print<W> {
         }
         use Target::*;
 
+        if !self.option.mode.prints_messages()
+            && !matches!(
+                payload,
+                Payload::Select { .. }
+                    | Payload::SelectMap(_)
+                    | Payload::ShowColumns(_)
+                    | Payload::ShowVariable(
+                        PayloadVariable::Tables(_) | PayloadVariable::Functions(_)
+                    )
+            )
+        {
+            return Ok(());
+        }
+
         let mut affected = |n: usize, target: Target, msg: &str| -> IOResult<()> {
             let payload = format!("{n} {target}{} {msg}", if n > 1 { "s" } else { "" });
             self.writeln(payload)
@@ -110,6 +190,7 @@ impl<'a, W: Write> Print<W> {
             Payload::Create => self.writeln("Table created")?,
             Payload::DropTable(n) => affected(*n, Table, "dropped")?,
             Payload::DropFunction => self.writeln("Function dropped")?,
+            Payload::DropTrigger => self.writeln("Trigger dropped")?,
             Payload::AlterTable => self.writeln("Table altered")?,
             Payload::CreateIndex => self.writeln("Index created")?,
             Payload::DropIndex => self.writeln("Index dropped")?,
@@ -121,47 +202,43 @@ impl<'a, W: Write> Print<W> {
             Payload::Update(n) => affected(*n, Row, "updated")?,
             Payload::ShowVariable(PayloadVariable::Version(v)) => self.writeln(format!("v{v}"))?,
             Payload::ShowVariable(PayloadVariable::Tables(names)) => {
-                let mut table = Self::get_table(["tables"]);
-                for name in names {
-                    table.add_record([name]);
-                }
-                let table = Self::build_table(table);
-                self.writeln(table)?;
+                let rows = names
+                    .iter()
+                    .map(|name| vec![Value::Str(name.to_owned())])
+                    .collect::<Vec<_>>();
+                let rows = rows.iter().map(|row| row.iter().map(Some).collect());
+
+                self.write_result(&["tables"], rows)?;
             }
             Payload::ShowVariable(PayloadVariable::Functions(names)) => {
-                let mut table = Self::get_table(["functions"]);
-                for name in names {
-                    table.add_record([name]);
-                }
-                let table = Self::build_table(table);
-                self.writeln(table)?;
+                let rows = names
+                    .iter()
+                    .map(|name| vec![Value::Str(name.to_owned())])
+                    .collect::<Vec<_>>();
+                let rows = rows.iter().map(|row| row.iter().map(Some).collect());
+
+                self.write_result(&["functions"], rows)?;
             }
             Payload::ShowColumns(columns) => {
-                let mut table = Self::get_table(vec!["Field", "Type"]);
-                for (field, field_type) in columns {
-                    table.add_record([field, &field_type.to_string()]);
-                }
-                let table = Self::build_table(table);
-                self.writeln(table)?;
+                let rows = columns
+                    .iter()
+                    .map(|(field, field_type)| {
+                        vec![
+                            Value::Str(field.to_owned()),
+                            Value::Str(field_type.to_string()),
+                        ]
+                    })
+                    .collect::<Vec<_>>();
+                let rows = rows.iter().map(|row| row.iter().map(Some).collect());
+
+                self.write_result(&["Field", "Type"], rows)?;
+            }
+            Payload::Select { labels, rows } => {
+                let labels = labels.iter().map(String::as_str).collect::<Vec<_>>();
+                let rows = rows.iter().map(|row| row.iter().map(Some).collect());
+
+                self.write_result(&labels, rows)?;
             }
-            Payload::Select { labels, rows } => match &self.option.tabular {
-                true => {
-                    let labels = labels.iter().map(AsRef::as_ref);
-                    let mut table = Self::get_table(labels);
-                    for row in rows {
-                        let row: Vec<String> = row.iter().map(Into::into).collect();
-
-                        table.add_record(row);
-                    }
-                    let table = Self::build_table(table);
-                    self.writeln(table)?;
-                }
-                false => {
-                    self.write_header(labels.iter().map(String::as_str))?;
-                    let rows = rows.iter().map(|row| row.iter().map(String::from));
-                    self.write_rows(rows)?;
-                }
-            },
             Payload::SelectMap(rows) => {
                 let mut labels = rows
                     .iter()
@@ -172,35 +249,212 @@ impl<'a, W: Write> Print<W> {
                     .collect::<Vec<_>>();
                 labels.sort_unstable();
 
-                match &self.option.tabular {
-                    true => {
-                        let mut table = Self::get_table(labels.clone());
-                        for row in rows {
-                            let row = labels
-                                .iter()
-                                .map(|label| row.get(*label).map(Into::into).unwrap_or_default())
-                                .collect::<Vec<String>>();
-
-                            table.add_record(row);
-                        }
-                        let table = Self::build_table(table);
-                        self.writeln(table)?;
+                let rows = rows
+                    .iter()
+                    .map(|row| labels.iter().map(|label| row.get(*label)).collect());
+
+                self.write_result(&labels, rows)?;
+            }
+        }
+
+        let returned = match payload {
+            Payload::Select { rows, .. } => rows.len(),
+            Payload::SelectMap(rows) => rows.len(),
+            Payload::ShowColumns(columns) => columns.len(),
+            Payload::ShowVariable(
+                PayloadVariable::Tables(names) | PayloadVariable::Functions(names),
+            ) => names.len(),
+            _ => return Ok(()),
+        };
+
+        if self.option.footer && self.option.mode.prints_messages() {
+            let plural = if returned == 1 { "" } else { "s" };
+            self.writeln(format!("({returned} row{plural})"))?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes how long a statement took when `.timer` is on.
+    pub fn elapsed(&mut self, elapsed: Duration) -> IOResult<()> {
+        if !self.option.timer || !self.option.mode.prints_messages() {
+            return Ok(());
+        }
+
+        self.writeln(format!("Time: {:.3} ms", elapsed.as_secs_f64() * 1000.0))
+    }
+
+    /// Writes one result set in the current [`OutputMode`].
+    ///
+    /// `None` marks a column missing from a schemaless row, which is left out
+    /// of JSON objects and printed empty elsewhere.
+    fn write_result<'b>(
+        &mut self,
+        labels: &[&str],
+        rows: impl Iterator<Item = Vec<Option<&'b Value>>>,
+    ) -> IOResult<()> {
+        let text = |value: Option<&Value>| value.map(String::from).unwrap_or_default();
+
+        match self.option.mode {
+            OutputMode::Table if self.option.expanded => self.write_expanded(labels, rows),
+            OutputMode::Table => {
+                let mut table = Self::get_table(labels.iter().copied());
+                for row in rows {
+                    table.add_record(row.into_iter().map(text));
+                }
+                let table = Self::build_table(table);
+
+                self.writeln(table)
+            }
+            OutputMode::List => {
+                self.write_header(labels.iter().copied())?;
+                self.write_rows(rows.map(|row| row.into_iter().map(text)))
+            }
+            OutputMode::Csv => {
+                if self.option.heading {
+                    self.write(
+                        labels
+                            .iter()
+                            .map(|label| escape_csv(label))
+                            .collect::<Vec<_>>()
+                            .join(","),
+                    )?;
+                }
+
+                for row in rows {
+                    let row = row
+                        .into_iter()
+                        .map(|value| match value {
+                            None | Some(Value::Null) => String::new(),
+                            Some(value) => escape_csv(&String::from(value)),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",");
+
+                    self.write(row)?;
+                }
+
+                Ok(())
+            }
+            OutputMode::Json => {
+                let objects = rows
+                    .map(|row| json_object(labels, row))
+                    .collect::<Vec<_>>()
+                    .join(",\n");
+
+                self.write(format!("[{objects}]"))
+            }
+            OutputMode::Jsonl => {
+                for row in rows {
+                    self.write(json_object(labels, row))?;
+                }
+
+                Ok(())
+            }
+            OutputMode::Markdown => {
+                let line = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+
+                self.write(line(
+                    labels.iter().map(|label| escape_markdown(label)).collect(),
+                ))?;
+                self.write(line(labels.iter().map(|_| "---".to_owned()).collect()))?;
+                for row in rows {
+                    self.write(line(
+                        row.into_iter()
+                            .map(|value| escape_markdown(&text(value)))
+                            .collect(),
+                    ))?;
+                }
+
+                self.writeln("")
+            }
+            OutputMode::Html => {
+                let line = |tag: &str, cells: Vec<String>| {
+                    let mut row = String::from("<tr>");
+                    for cell in cells {
+                        row += &["<", tag, ">", &cell, "</", tag, ">"].concat();
                     }
-                    false => {
-                        self.write_header(labels.iter().map(AsRef::as_ref))?;
-
-                        let rows = rows.iter().map(|row| {
-                            labels
-                                .iter()
-                                .map(|label| row.get(*label).map(String::from).unwrap_or_default())
-                        });
-                        self.write_rows(rows)?;
+
+                    row + "</tr>"
+                };
+
+                self.write("<table>")?;
+                if self.option.heading {
+                    self.write(line(
+                        "th",
+                        labels.iter().map(|label| escape_html(label)).collect(),
+                    ))?;
+                }
+                for row in rows {
+                    self.write(line(
+                        "td",
+                        row.into_iter()
+                            .map(|value| escape_html(&text(value)))
+                            .collect(),
+                    ))?;
+                }
+
+                self.writeln("</table>")
+            }
+            OutputMode::Line => {
+                let width = labels
+                    .iter()
+                    .map(|label| label.chars().count())
+                    .max()
+                    .unwrap_or(0);
+
+                for row in rows {
+                    for (label, value) in labels.iter().zip(row) {
+                        self.write(format!("{label:>width$} = {}", text(value)))?;
                     }
+
+                    self.write("")?;
                 }
+
+                Ok(())
             }
         }
+    }
 
-        Ok(())
+    /// Prints every row as a `column | value` block like `psql` does with `\x`.
+    fn write_expanded<'b>(
+        &mut self,
+        labels: &[&str],
+        rows: impl Iterator<Item = Vec<Option<&'b Value>>>,
+    ) -> IOResult<()> {
+        let label_width = labels
+            .iter()
+            .map(|label| label.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut printed = 0;
+
+        for (i, row) in rows.enumerate() {
+            let values = row
+                .into_iter()
+                .map(|value| value.map(String::from).unwrap_or_default())
+                .collect::<Vec<_>>();
+            let value_width = values
+                .iter()
+                .map(|value| value.chars().count())
+                .max()
+                .unwrap_or(0);
+            let header = format!("-[ RECORD {} ]", i + 1);
+            let width = (label_width + 3 + value_width).max(header.chars().count());
+
+            self.write(format!("{header:-<width$}"))?;
+            for (label, value) in labels.iter().zip(values) {
+                self.write(format!("{label:<label_width$} | {value}"))?;
+            }
+
+            printed += 1;
+        }
+
+        if printed == 0 {
+            self.write("(0 rows)")?;
+        }
+
+        self.writeln("")
     }
 
     fn write_rows(
@@ -257,7 +511,7 @@ impl<'a, W: Write> Print<W> {
 
     pub fn help(&mut self) -> IOResult<()> {
         const HEADER: [&str; 2] = ["command", "description"];
-        const CONTENT: [[&str; 2]; 12] = [
+        const CONTENT: [[&str; 2]; 17] = [
             [".help", "show help"],
             [".quit", "quit program"],
             [".tables", "show table names"],
@@ -266,8 +520,13 @@ impl<'a, W: Write> Print<W> {
             [".version", "show version"],
             [".execute PATH", "execute SQL from PATH"],
             [".spool PATH|off", "spool to PATH or off"],
+            [".import FILE TABLE", "import FILE into TABLE [--create]"],
+            [".export QUERY FILE", "export result of QUERY to FILE"],
             [".show OPTION", "show print option eg).show all"],
             [".set OPTION", "set print option eg).set tabular off"],
+            [".mode MODE", "set output mode eg).mode csv"],
+            [".x", "toggle expanded display"],
+            [".timer ON|OFF", "turn on/off statement timing"],
             [".edit [PATH]", "open editor with last command or PATH"],
             [".run ", "execute last command"],
         ];
@@ -306,9 +565,13 @@ impl<'a, W: Write> Print<W> {
     pub fn set_option(&mut self, option: SetOption) {
         match option {
             SetOption::Tabular(value) => self.option.tabular(value),
+            SetOption::Mode(value) => self.option.mode(value),
+            SetOption::Expanded(value) => self.option.expanded(value),
             SetOption::Colsep(value) => self.option.colsep(value),
             SetOption::Colwrap(value) => self.option.colwrap(value),
             SetOption::Heading(value) => self.option.heading(value),
+            SetOption::Timer(value) => self.option.timer(value),
+            SetOption::Footer(value) => self.option.footer(value),
         }
     }
 
@@ -320,6 +583,52 @@ impl<'a, W: Write> Print<W> {
     }
 }
 
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+fn escape_markdown(cell: &str) -> String {
+    cell.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+fn escape_html(cell: &str) -> String {
+    cell.chars()
+        .map(|c| match c {
+            '&' => "&amp;".to_owned(),
+            '<' => "&lt;".to_owned(),
+            '>' => "&gt;".to_owned(),
+            '"' => "&quot;".to_owned(),
+            '\'' => "&#39;".to_owned(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// Builds the object by hand to keep the column order of the result.
+fn json_object(labels: &[&str], row: Vec<Option<&Value>>) -> String {
+    let fields = labels
+        .iter()
+        .zip(row)
+        .filter_map(|(label, value)| {
+            let value = value?;
+            let value = JsonValue::try_from(value.clone())
+                .unwrap_or_else(|_| JsonValue::String(String::from(value)));
+
+            Some(format!("{}:{value}", JsonValue::from(*label)))
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!("{{{fields}}}")
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -338,20 +647,25 @@ mod tests {
             String::from_utf8(print.output).unwrap()
         };
         let expected = "
-| command         | description                           |
-|-----------------|---------------------------------------|
-| .help           | show help                             |
-| .quit           | quit program                          |
-| .tables         | show table names                      |
-| .functions      | show function names                   |
-| .columns TABLE  | show columns from TABLE               |
-| .version        | show version                          |
-| .execute PATH   | execute SQL from PATH                 |
-| .spool PATH|off | spool to PATH or off                  |
-| .show OPTION    | show print option eg).show all        |
-| .set OPTION     | set print option eg).set tabular off  |
-| .edit [PATH]    | open editor with last command or PATH |
-| .run            | execute last command                  |";
+| command            | description                           |
+|--------------------|---------------------------------------|
+| .help              | show help                             |
+| .quit              | quit program                          |
+| .tables            | show table names                      |
+| .functions         | show function names                   |
+| .columns TABLE     | show columns from TABLE               |
+| .version           | show version                          |
+| .execute PATH      | execute SQL from PATH                 |
+| .spool PATH|off    | spool to PATH or off                  |
+| .import FILE TABLE | import FILE into TABLE [--create]     |
+| .export QUERY FILE | export result of QUERY to FILE        |
+| .show OPTION       | show print option eg).show all        |
+| .set OPTION        | set print option eg).set tabular off  |
+| .mode MODE         | set output mode eg).mode csv          |
+| .x                 | toggle expanded display               |
+| .timer ON|OFF      | turn on/off statement timing          |
+| .edit [PATH]       | open editor with last command or PATH |
+| .run               | execute last command                  |";
 
         assert_eq!(
             actual.as_str().trim_matches('\n'),
@@ -390,6 +704,7 @@ mod tests {
         test!(Payload::CreateIndex, "Index created");
         test!(Payload::DropIndex, "Index dropped");
         test!(Payload::DropFunction, "Function dropped");
+        test!(Payload::DropTrigger, "Trigger dropped");
         test!(Payload::Commit, "Commit completed");
         test!(Payload::Rollback, "Rollback completed");
         test!(Payload::StartTransaction, "Transaction started");
@@ -759,13 +1074,182 @@ id,title,valid
             print.option.format(ShowOption::All),
             "
 tabular ON
+mode table
 colsep \"|\"
 colwrap \"\"
-heading ON"
+heading ON
+expanded OFF
+timer OFF
+footer OFF"
                 .trim_matches('\n')
         );
     }
 
+    #[test]
+    fn print_output_modes() {
+        use {
+            super::OutputMode,
+            gluesql_core::prelude::{Payload, Value},
+        };
+
+        let mut print = Print::new(Vec::new(), None, PrintOption::default());
+        let select = Payload::Select {
+            labels: vec!["id".to_owned(), "note".to_owned()],
+            rows: vec![
+                vec![Value::I64(1), Value::Str("a,\"b\"|<c>".to_owned())],
+                vec![Value::I64(2), Value::Null],
+            ],
+        };
+        let select_map = Payload::SelectMap(vec![
+            [("id".to_owned(), Value::I64(1))].into_iter().collect(),
+            [("name".to_owned(), Value::Str("x".to_owned()))]
+                .into_iter()
+                .collect(),
+        ]);
+
+        macro_rules! test {
+            ($mode: expr, $payload: expr, $expected: literal) => {
+                print.set_option(SetOption::Mode($mode));
+                print.payloads(std::slice::from_ref(&$payload)).unwrap();
+
+                assert_eq!(
+                    String::from_utf8(print.output.clone())
+                        .unwrap()
+                        .trim_matches('\n'),
+                    $expected.trim_matches('\n')
+                );
+
+                print.output.clear();
+            };
+        }
+
+        test!(
+            OutputMode::Csv,
+            select,
+            r#"
+id,note
+1,"a,""b""|<c>"
+2,"#
+        );
+        test!(
+            OutputMode::Json,
+            select,
+            r#"
+[{"id":1,"note":"a,\"b\"|<c>"},
+{"id":2,"note":null}]"#
+        );
+        test!(
+            OutputMode::Jsonl,
+            select_map,
+            r#"
+{"id":1}
+{"name":"x"}"#
+        );
+        test!(OutputMode::Json, Payload::Insert(1), "");
+        test!(
+            OutputMode::Markdown,
+            select,
+            r#"
+| id | note |
+| --- | --- |
+| 1 | a,"b"\|<c> |
+| 2 | NULL |"#
+        );
+        test!(
+            OutputMode::Html,
+            select,
+            "
+<table>
+<tr><th>id</th><th>note</th></tr>
+<tr><td>1</td><td>a,&quot;b&quot;|&lt;c&gt;</td></tr>
+<tr><td>2</td><td>NULL</td></tr>
+</table>"
+        );
+        test!(
+            OutputMode::Line,
+            select,
+            r#"
+  id = 1
+note = a,"b"|<c>
+
+  id = 2
+note = NULL"#
+        );
+
+        print.set_option(SetOption::Mode(OutputMode::Table));
+        print.set_option(SetOption::Expanded(true));
+        assert_eq!(print.option.format(ShowOption::Expanded), "expanded ON");
+        test!(
+            OutputMode::Table,
+            select,
+            r#"
+-[ RECORD 1 ]---
+id   | 1
+note | a,"b"|<c>
+-[ RECORD 2 ]
+id   | 2
+note | NULL"#
+        );
+        test!(
+            OutputMode::Table,
+            Payload::Select {
+                labels: vec!["id".to_owned()],
+                rows: Vec::new(),
+            },
+            "(0 rows)"
+        );
+        test!(OutputMode::Table, Payload::Insert(1), "1 row inserted");
+    }
+
+    #[test]
+    fn print_footer_and_timer() {
+        use {
+            super::OutputMode,
+            gluesql_core::prelude::{Payload, PayloadVariable, Value},
+            std::time::Duration,
+        };
+
+        let mut print = Print::new(Vec::new(), None, PrintOption::default());
+        let select = Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![vec![Value::I64(1)], vec![Value::I64(2)]],
+        };
+        let output = |print: &mut Print<Vec<u8>>| {
+            let output = String::from_utf8(print.output.clone()).unwrap();
+            print.output.clear();
+
+            output
+        };
+
+        print.payload(&select).unwrap();
+        print.elapsed(Duration::from_micros(1500)).unwrap();
+        assert_eq!(output(&mut print), "| id |\n|----|\n| 1  |\n| 2  |\n\n");
+
+        print.set_option(SetOption::Footer(true));
+        print.set_option(SetOption::Timer(true));
+        print.payload(&select).unwrap();
+        print.elapsed(Duration::from_micros(1500)).unwrap();
+        assert_eq!(
+            output(&mut print),
+            "| id |\n|----|\n| 1  |\n| 2  |\n\n(2 rows)\n\nTime: 1.500 ms\n\n"
+        );
+
+        print
+            .payload(&Payload::ShowVariable(PayloadVariable::Tables(vec![
+                "Foo".to_owned(),
+            ])))
+            .unwrap();
+        assert!(output(&mut print).ends_with("(1 row)\n\n"));
+
+        print.payload(&Payload::Insert(3)).unwrap();
+        assert_eq!(output(&mut print), "3 rows inserted\n\n");
+
+        print.set_option(SetOption::Mode(OutputMode::Csv));
+        print.payload(&select).unwrap();
+        print.elapsed(Duration::from_micros(1500)).unwrap();
+        assert_eq!(output(&mut print), "id\n1\n2\n");
+    }
+
     #[test]
     fn print_spool() {
         use std::fs;
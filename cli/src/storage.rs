@@ -0,0 +1,300 @@
+use {
+    crate::Storage,
+    anyhow::{Context, Result, bail},
+    gluesql_composite_storage::CompositeStorage,
+    gluesql_csv_storage::CsvStorage,
+    gluesql_file_storage::FileStorage,
+    gluesql_git_storage::{GitStorage, StorageType},
+    gluesql_json_storage::JsonStorage,
+    gluesql_memory_storage::MemoryStorage,
+    gluesql_mongo_storage::MongoStorage,
+    gluesql_parquet_storage::ParquetStorage,
+    gluesql_redb_storage::RedbStorage,
+    gluesql_redis_storage::RedisStorage,
+    gluesql_sled_storage::SledStorage,
+    serde::Deserialize,
+    std::{
+        collections::BTreeMap,
+        fs,
+        path::{Path, PathBuf},
+    },
+};
+
+pub(crate) const DEFAULT_MONGO_URL: &str = "mongodb://localhost:27017";
+pub(crate) const DEFAULT_REDIS_URL: &str = "redis://localhost:6379";
+pub(crate) const DEFAULT_DATABASE: &str = "gluesql";
+const DEFAULT_REDIS_PORT: u16 = 6379;
+
+/// Format of the files a git storage keeps under version control.
+#[derive(clap::ValueEnum, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum GitType {
+    #[default]
+    File,
+    Csv,
+    Json,
+}
+
+impl From<GitType> for StorageType {
+    fn from(git_type: GitType) -> Self {
+        match git_type {
+            GitType::File => StorageType::File,
+            GitType::Csv => StorageType::Csv,
+            GitType::Json => StorageType::Json,
+        }
+    }
+}
+
+/// Remote and branch a git storage pulls from before the session and pushes
+/// to after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GitRemote {
+    pub remote: String,
+    pub branch: Option<String>,
+}
+
+/// Opens the git repository at `path`, creating it when missing, and pulls
+/// from `remote` when given.
+pub(crate) fn open_git(
+    path: &Path,
+    git_type: GitType,
+    remote: Option<&GitRemote>,
+) -> Result<GitStorage> {
+    let mut storage = if path.join(".git").exists() {
+        GitStorage::open(path, git_type.into())?
+    } else {
+        GitStorage::init(path, git_type.into())?
+    };
+
+    if let Some(GitRemote { remote, branch }) = remote {
+        storage.set_remote(remote.clone());
+        if let Some(branch) = branch {
+            storage.set_branch(branch.clone());
+        }
+
+        storage.pull()?;
+    }
+
+    Ok(storage)
+}
+
+/// Pushes the commits made during the session back to `remote`.
+pub(crate) fn push_git(path: &Path, git_type: GitType, remote: &GitRemote) -> Result<()> {
+    let mut storage = GitStorage::open(path, git_type.into())?;
+    storage.set_remote(remote.remote.clone());
+    if let Some(branch) = &remote.branch {
+        storage.set_branch(branch.clone());
+    }
+
+    Ok(storage.push()?)
+}
+
+pub(crate) fn open_mongo(url: Option<&str>, database: Option<&str>) -> Result<MongoStorage> {
+    let url = url.unwrap_or(DEFAULT_MONGO_URL);
+    let database = database.unwrap_or(DEFAULT_DATABASE);
+
+    Ok(MongoStorage::new(url, database)?)
+}
+
+pub(crate) fn open_redis(url: Option<&str>, database: Option<&str>) -> Result<RedisStorage> {
+    let (host, port) = redis_address(url.unwrap_or(DEFAULT_REDIS_URL))?;
+
+    Ok(RedisStorage::new(
+        database.unwrap_or(DEFAULT_DATABASE),
+        host,
+        port,
+    ))
+}
+
+/// Splits `redis://host:port` into the host and port `RedisStorage` connects to.
+fn redis_address(url: &str) -> Result<(&str, u16)> {
+    let address = url.strip_prefix("redis://").unwrap_or(url);
+    let address = address.trim_end_matches('/');
+
+    match address.rsplit_once(':') {
+        Some((host, port)) => {
+            let port = port
+                .parse()
+                .with_context(|| format!("invalid redis port: {url}"))?;
+
+            Ok((host, port))
+        }
+        None if !address.is_empty() => Ok((address, DEFAULT_REDIS_PORT)),
+        None => bail!("invalid redis url: {url}"),
+    }
+}
+
+/// Storages mounted by a composite storage config file.
+///
+/// ```toml
+/// default = "main"
+///
+/// [engines.main]
+/// storage = "sled"
+/// path = "data/sled"
+///
+/// [engines.files]
+/// storage = "json"
+/// path = "data/json"
+/// ```
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct CompositeConfig {
+    default: Option<String>,
+    engines: BTreeMap<String, EngineConfig>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct EngineConfig {
+    storage: Storage,
+    path: Option<PathBuf>,
+    url: Option<String>,
+    database: Option<String>,
+    git_type: Option<GitType>,
+}
+
+/// Mounts every engine listed in the config file at `config_path` into a
+/// `CompositeStorage`. Relative paths are resolved from the directory of the
+/// config file.
+pub(crate) fn open_composite(config_path: &Path) -> Result<CompositeStorage> {
+    let config = fs::read_to_string(config_path)
+        .with_context(|| format!("failed to read {}", config_path.display()))?;
+    let CompositeConfig { default, engines } = toml::from_str(&config)
+        .with_context(|| format!("invalid config file: {}", config_path.display()))?;
+    let base = config_path.parent().unwrap_or_else(|| Path::new("."));
+
+    if engines.is_empty() {
+        bail!("no engines in config file: {}", config_path.display());
+    }
+
+    let mut composite = CompositeStorage::new();
+    for (name, engine) in engines {
+        let EngineConfig {
+            storage,
+            path,
+            url,
+            database,
+            git_type,
+        } = engine;
+        let path = path.map(|path| base.join(path));
+        let path = || {
+            path.as_deref()
+                .with_context(|| format!("engine {name}: path is required"))
+        };
+        let url = url.as_deref();
+        let database = database.as_deref();
+
+        match storage {
+            Storage::Memory => composite.push(&name, MemoryStorage::default()),
+            Storage::Sled => composite.push(&name, SledStorage::new(path()?)?),
+            Storage::Redb => composite.push(&name, RedbStorage::new(path()?)?),
+            Storage::Json => composite.push(&name, JsonStorage::new(path()?)?),
+            Storage::Csv => composite.push(&name, CsvStorage::new(path()?)?),
+            Storage::Parquet => composite.push(&name, ParquetStorage::new(path()?)?),
+            Storage::File => composite.push(&name, FileStorage::new(path()?)?),
+            Storage::Git => {
+                let storage = open_git(path()?, git_type.unwrap_or_default(), None)?;
+
+                composite.push(&name, storage);
+            }
+            Storage::Mongo => composite.push(&name, open_mongo(url, database)?),
+            Storage::Redis => composite.push(&name, open_redis(url, database)?),
+            Storage::Composite => bail!("engine {name}: composite storage cannot be nested"),
+        }
+    }
+
+    if let Some(default) = default {
+        if !composite.storages.contains_key(&default) {
+            bail!("default engine not found: {default}");
+        }
+
+        composite.set_default(default);
+    }
+
+    Ok(composite)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{open_composite, redis_address},
+        gluesql_core::prelude::{Glue, Payload, Value},
+        std::{fs, path::Path},
+    };
+
+    #[test]
+    fn parse_redis_address() {
+        assert_eq!(
+            redis_address("redis://localhost:6379").unwrap(),
+            ("localhost", 6379)
+        );
+        assert_eq!(redis_address("10.0.0.1:7000").unwrap(), ("10.0.0.1", 7000));
+        assert_eq!(redis_address("redis://cache").unwrap(), ("cache", 6379));
+        assert_eq!(
+            redis_address("redis://cache:port").unwrap_err().to_string(),
+            "invalid redis port: redis://cache:port"
+        );
+    }
+
+    #[test]
+    fn mount_composite_engines() {
+        let dir = Path::new("tmp/composite_config");
+        fs::remove_dir_all(dir).unwrap_or(());
+        fs::create_dir_all(dir.join("json")).unwrap();
+        fs::write(
+            dir.join("json/Item.jsonl"),
+            "{\"id\": 1, \"name\": \"Glue\"}\n{\"id\": 2, \"name\": \"SQL\"}\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("gluesql.toml"),
+            "default = \"main\"\n\n\
+             [engines.main]\nstorage = \"memory\"\n\n\
+             [engines.files]\nstorage = \"json\"\npath = \"json\"\n",
+        )
+        .unwrap();
+
+        let mut glue = Glue::new(open_composite(&dir.join("gluesql.toml")).unwrap());
+        glue.execute("CREATE TABLE Stock (item_id INTEGER, count INTEGER);")
+            .unwrap();
+        glue.execute("INSERT INTO Stock VALUES (2, 10);").unwrap();
+
+        let actual = glue
+            .execute(
+                "SELECT Item.name, Stock.count FROM Item JOIN Stock ON Item.id = Stock.item_id;",
+            )
+            .unwrap();
+        assert_eq!(
+            actual,
+            vec![Payload::Select {
+                labels: vec!["name".to_owned(), "count".to_owned()],
+                rows: vec![vec![Value::Str("SQL".to_owned()), Value::I64(10)]],
+            }]
+        );
+
+        fs::write(
+            dir.join("nested.toml"),
+            "[engines.inner]\nstorage = \"composite\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            open_composite(&dir.join("nested.toml"))
+                .err()
+                .map(|e| e.to_string()),
+            Some("engine inner: composite storage cannot be nested".to_owned())
+        );
+
+        fs::write(
+            dir.join("no_path.toml"),
+            "[engines.db]\nstorage = \"sled\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            open_composite(&dir.join("no_path.toml"))
+                .err()
+                .map(|e| e.to_string()),
+            Some("engine db: path is required".to_owned())
+        );
+    }
+}
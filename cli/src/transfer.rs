@@ -0,0 +1,405 @@
+use {
+    crate::dump::{ROWS_PER_INSERT, autocommit},
+    anyhow::{Result, bail},
+    gluesql_core::{
+        ast::{ColumnDef, DataType, ToSql},
+        data::{Schema, Value},
+        executor::Payload,
+        prelude::Glue,
+        store::{GStore, GStoreMut, Planner, Store},
+    },
+    gluesql_csv_storage::CsvStorage,
+    gluesql_json_storage::JsonStorage,
+    gluesql_parquet_storage::ParquetStorage,
+    serde_json::{Map, Value as JsonValue},
+    std::{
+        collections::BTreeMap,
+        fs::{self, File},
+        io::{BufWriter, Write},
+        path::Path,
+    },
+};
+
+/// Rows of a CSV file read to infer the type of each column.
+const INFER_SAMPLE_ROWS: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileFormat {
+    Csv,
+    Json,
+    Jsonl,
+    Parquet,
+}
+
+impl FileFormat {
+    fn from_path(path: &Path) -> Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_lowercase);
+
+        match extension.as_deref() {
+            Some("csv") => Ok(Self::Csv),
+            Some("json") => Ok(Self::Json),
+            Some("jsonl") => Ok(Self::Jsonl),
+            Some("parquet") => Ok(Self::Parquet),
+            _ => bail!(
+                "unsupported file: {}, expected .csv, .json, .jsonl or .parquet",
+                path.display()
+            ),
+        }
+    }
+}
+
+/// Column names, types and values of the rows moved by an import or export.
+struct Rows {
+    column_defs: Vec<ColumnDef>,
+    rows: Vec<Vec<Value>>,
+}
+
+impl Rows {
+    /// Flattens schemaless rows into columns ordered by first appearance.
+    fn from_maps(maps: Vec<BTreeMap<String, Value>>) -> Self {
+        let mut labels: Vec<String> = Vec::new();
+        for key in maps.iter().flat_map(BTreeMap::keys) {
+            if !labels.contains(key) {
+                labels.push(key.clone());
+            }
+        }
+
+        let rows = maps
+            .into_iter()
+            .map(|mut map| {
+                labels
+                    .iter()
+                    .map(|label| map.remove(label).unwrap_or(Value::Null))
+                    .collect()
+            })
+            .collect();
+
+        Self::infer(labels, rows)
+    }
+
+    /// Picks for every column the type shared by all of its values.
+    fn infer(labels: Vec<String>, rows: Vec<Vec<Value>>) -> Self {
+        let column_defs = labels
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| {
+                let values = rows.iter().filter_map(|row| row.get(index));
+
+                ColumnDef {
+                    name,
+                    data_type: infer_data_type(values),
+                    nullable: true,
+                    default: None,
+                    unique: None,
+                    comment: None,
+                }
+            })
+            .collect();
+
+        Self { column_defs, rows }
+    }
+}
+
+fn infer_data_type<'a>(values: impl Iterator<Item = &'a Value>) -> DataType {
+    let mut data_types = Vec::new();
+    for data_type in values.filter_map(Value::get_type) {
+        if !data_types.contains(&data_type) {
+            data_types.push(data_type);
+        }
+    }
+
+    match data_types.as_slice() {
+        [] => DataType::Text,
+        [data_type] => data_type.clone(),
+        data_types if data_types.iter().all(is_integer) => DataType::Int,
+        data_types
+            if data_types
+                .iter()
+                .all(|data_type| is_integer(data_type) || is_float(data_type)) =>
+        {
+            DataType::Float
+        }
+        _ => DataType::Text,
+    }
+}
+
+fn is_integer(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int
+            | DataType::Int128
+            | DataType::Uint8
+            | DataType::Uint16
+            | DataType::Uint32
+            | DataType::Uint64
+            | DataType::Uint128
+    )
+}
+
+fn is_float(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Float32 | DataType::Float | DataType::Decimal
+    )
+}
+
+/// Loads the rows of a CSV, JSON or Parquet file into `table_name` and returns
+/// the number of rows inserted.
+///
+/// Columns are matched by name. With `create`, the table is created first,
+/// using the schema of the file or the types inferred from its values.
+pub fn import_file<T: GStore + GStoreMut + Planner>(
+    glue: &mut Glue<T>,
+    path: &Path,
+    table_name: &str,
+    create: bool,
+) -> Result<usize> {
+    let format = FileFormat::from_path(path)?;
+    if !path.is_file() {
+        bail!("file not found: {}", path.display());
+    }
+
+    let (directory, name) = split_path(path)?;
+    let Rows { column_defs, rows } = match format {
+        FileFormat::Csv => {
+            let storage = CsvStorage::builder(directory)
+                .infer_types(INFER_SAMPLE_ROWS)
+                .build()?;
+
+            read_rows(&storage, name)?
+        }
+        FileFormat::Json | FileFormat::Jsonl => read_rows(&JsonStorage::new(directory)?, name)?,
+        FileFormat::Parquet => read_rows(&ParquetStorage::new(directory)?, name)?,
+    };
+
+    let schema = autocommit(&mut glue.storage, |storage| {
+        Ok(storage.fetch_schema(table_name)?)
+    })?;
+    let schema = match (schema, create) {
+        (Some(_), true) => bail!("table already exists: {table_name}"),
+        (None, false) => bail!("table not found: {table_name}, use --create to create it"),
+        (Some(schema), false) => schema,
+        (None, true) => {
+            let schema = Schema {
+                table_name: table_name.to_owned(),
+                column_defs: Some(column_defs.clone()),
+                indexes: Vec::new(),
+                engine: None,
+                foreign_keys: Vec::new(),
+                comment: None,
+                ttl_column: None,
+            };
+            glue.execute(schema.to_ddl())?;
+
+            schema
+        }
+    };
+
+    let count = rows.len();
+    let inserts = if let Some(target_defs) = &schema.column_defs {
+        let columns = column_defs
+            .iter()
+            .map(|column_def| {
+                target_defs
+                    .iter()
+                    .find(|target| target.name == column_def.name)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("column not found in {table_name}: {}", column_def.name)
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let names = columns
+            .iter()
+            .map(|column_def| format!(r#""{}""#, column_def.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let rows = rows
+            .into_iter()
+            .map(|row| cast_row(row, &columns))
+            .collect::<Result<Vec<_>>>()?;
+
+        insert_statements(&format!(r#""{table_name}" ({names})"#), &rows)
+    } else {
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                let map = column_defs
+                    .iter()
+                    .zip(row)
+                    .filter(|(_, value)| !matches!(value, Value::Null))
+                    .map(|(column_def, value)| (column_def.name.clone(), value))
+                    .collect();
+
+                vec![Value::Map(map)]
+            })
+            .collect::<Vec<_>>();
+
+        insert_statements(&format!(r#""{table_name}""#), &rows)
+    };
+
+    for insert in inserts {
+        glue.execute(insert)?;
+    }
+
+    Ok(count)
+}
+
+/// Runs `query` and writes its result set to a CSV, JSONL or Parquet file,
+/// replacing the file when it exists. Returns the number of rows written.
+///
+/// Only the target file is written; no schema file is created next to it.
+pub fn export_query<T: GStore + GStoreMut + Planner>(
+    glue: &mut Glue<T>,
+    query: &str,
+    path: &Path,
+) -> Result<usize> {
+    let format = FileFormat::from_path(path)?;
+    if format == FileFormat::Json {
+        bail!("export to .json is not supported, use .jsonl instead");
+    }
+
+    let Rows { column_defs, rows } = match glue.execute(query)?.pop() {
+        Some(Payload::Select { labels, rows }) => Rows::infer(labels, rows),
+        Some(Payload::SelectMap(rows)) => Rows::from_maps(rows),
+        _ => bail!("query does not return rows: {query}"),
+    };
+    let rows = rows
+        .into_iter()
+        .map(|row| cast_row(row, &column_defs.iter().collect::<Vec<_>>()))
+        .collect::<Result<Vec<_>>>()?;
+
+    let (directory, name) = split_path(path)?;
+    fs::create_dir_all(directory)?;
+
+    let count = rows.len();
+    match format {
+        FileFormat::Csv => write_csv(path, &column_defs, rows)?,
+        FileFormat::Jsonl | FileFormat::Json => write_jsonl(path, &column_defs, rows)?,
+        FileFormat::Parquet => {
+            let schema = Schema {
+                table_name: name.to_owned(),
+                column_defs: Some(column_defs),
+                indexes: Vec::new(),
+                engine: None,
+                foreign_keys: Vec::new(),
+                comment: None,
+                ttl_column: None,
+            };
+
+            ParquetStorage::write_file(path, &schema, &rows)?;
+        }
+    }
+
+    Ok(count)
+}
+
+/// Splits a data file path into the directory opened as storage and the
+/// table name the storage maps to the file.
+fn split_path(path: &Path) -> Result<(&Path, &str)> {
+    let Some(name) = path.file_stem().and_then(|name| name.to_str()) else {
+        bail!("invalid file name: {}", path.display());
+    };
+    let directory = match path.parent() {
+        Some(directory) if !directory.as_os_str().is_empty() => directory,
+        _ => Path::new("."),
+    };
+
+    Ok((directory, name))
+}
+
+fn read_rows<S: Store>(storage: &S, name: &str) -> Result<Rows> {
+    let Some(schema) = storage.fetch_schema(name)? else {
+        bail!("file not found: {name}");
+    };
+    let rows = storage
+        .scan_data(name)?
+        .map(|result| result.map(|(_, row)| row))
+        .collect::<gluesql_core::error::Result<Vec<_>>>()?;
+
+    if let Some(column_defs) = schema.column_defs {
+        Ok(Rows { column_defs, rows })
+    } else {
+        let maps = rows
+            .into_iter()
+            .filter_map(|row| match row.into_iter().next() {
+                Some(Value::Map(map)) => Some(map),
+                _ => None,
+            })
+            .collect();
+
+        Ok(Rows::from_maps(maps))
+    }
+}
+
+/// Writes a header and one record per row, with `NULL` for missing values as
+/// `CsvStorage` reads them back.
+fn write_csv(path: &Path, column_defs: &[ColumnDef], rows: Vec<Vec<Value>>) -> Result<()> {
+    let mut writer = csv::Writer::from_path(path)?;
+    writer.write_record(column_defs.iter().map(|column_def| &column_def.name))?;
+
+    for row in rows {
+        writer.write_record(row.into_iter().map(|value| match value {
+            Value::Null => "NULL".to_owned(),
+            value => String::from(value),
+        }))?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Writes one JSON object per line, keyed by column name.
+fn write_jsonl(path: &Path, column_defs: &[ColumnDef], rows: Vec<Vec<Value>>) -> Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+
+    for row in rows {
+        let object = column_defs
+            .iter()
+            .zip(row)
+            .map(|(column_def, value)| Ok((column_def.name.clone(), JsonValue::try_from(value)?)))
+            .collect::<Result<Map<_, _>>>()?;
+
+        serde_json::to_writer(&mut file, &JsonValue::Object(object))?;
+        writeln!(file)?;
+    }
+
+    file.flush()?;
+
+    Ok(())
+}
+
+fn cast_row(row: Vec<Value>, column_defs: &[&ColumnDef]) -> Result<Vec<Value>> {
+    row.into_iter()
+        .zip(column_defs)
+        .map(|(value, column_def)| match value.get_type() {
+            Some(data_type) if data_type != column_def.data_type => {
+                Ok(value.cast(&column_def.data_type)?)
+            }
+            _ => Ok(value),
+        })
+        .collect()
+}
+
+fn insert_statements(target: &str, rows: &[Vec<Value>]) -> Vec<String> {
+    rows.chunks(ROWS_PER_INSERT)
+        .map(|rows| {
+            let values = rows
+                .iter()
+                .map(|row| {
+                    let row = row.iter().map(ToSql::to_sql).collect::<Vec<_>>().join(", ");
+                    format!("({row})")
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("INSERT INTO {target} VALUES {values};")
+        })
+        .collect()
+}
@@ -11,10 +11,10 @@ pub(super) fn run_upgrade(
     path: Option<&Path>,
     storage: Option<Storage>,
     has_execute: bool,
-    has_dump: bool,
+    has_dump_or_restore: bool,
 ) -> Result<()> {
-    if has_execute || has_dump {
-        bail!("--upgrade cannot be used with --execute or --dump");
+    if has_execute || has_dump_or_restore {
+        bail!("--upgrade cannot be used with --execute, --dump or --restore");
     }
 
     let (Some(path), Some(storage)) = (path, storage) else {
@@ -113,7 +113,7 @@ mod tests {
     #[test]
     fn upgrade_rejects_execute_or_dump() {
         let actual = run_upgrade(Some(Path::new("./tmp")), Some(Storage::Sled), true, false);
-        let expected = "--upgrade cannot be used with --execute or --dump";
+        let expected = "--upgrade cannot be used with --execute, --dump or --restore";
 
         assert_eq!(
             actual.expect_err("execute should conflict").to_string(),
@@ -1,8 +1,14 @@
 use {
-    gluesql_cli::dump_database,
-    gluesql_core::prelude::Glue,
+    gluesql_cli::{DumpOptions, dump_database, restore_database},
+    gluesql_core::prelude::{Glue, Payload, Value},
+    gluesql_json_storage::JsonStorage,
+    gluesql_memory_storage::MemoryStorage,
     gluesql_sled_storage::{SledStorage, sled},
-    std::{fs::File, io::Read, path::PathBuf},
+    std::{
+        fs::{self, File},
+        io::Read,
+        path::PathBuf,
+    },
 };
 
 #[test]
@@ -69,7 +75,12 @@ fn dump_and_import() {
         source_glue.execute(sql).unwrap();
     }
 
-    dump_database(&mut source_glue.storage, dump_path.clone()).unwrap();
+    dump_database(
+        &mut source_glue.storage,
+        dump_path.clone(),
+        &DumpOptions::default(),
+    )
+    .unwrap();
 
     let data_path = "tmp/target";
     let config = sled::Config::default().path(data_path).temporary(true);
@@ -77,7 +88,7 @@ fn dump_and_import() {
     let mut target_glue = Glue::new(target_storage);
 
     let mut sqls = String::new();
-    File::open(dump_path)
+    File::open(&dump_path)
         .unwrap()
         .read_to_string(&mut sqls)
         .unwrap();
@@ -90,9 +101,9 @@ fn dump_and_import() {
     assert!(sqls.contains(r#"CREATE TABLE "Empty" ("id" INT NULL);"#));
     assert!(!sqls.contains(r#"INSERT INTO "Empty""#));
 
-    for sql in sqls.split(';').filter(|sql| !sql.trim().is_empty()) {
-        target_glue.execute(sql).unwrap();
-    }
+    assert!(sqls.contains(r#"CREATE INDEX "Foo_int" ON "Foo" ("int");"#));
+
+    restore_database(&mut target_glue, &dump_path).unwrap();
 
     // schemas should be identical
     let sql = "SELECT OBJECT_TYPE, OBJECT_NAME FROM GLUE_OBJECTS";
@@ -111,3 +122,126 @@ fn dump_and_import() {
     let target_data = target_glue.execute(sql).unwrap();
     assert_eq!(source_data, target_data);
 }
+
+#[test]
+fn dump_functions_triggers_and_foreign_keys() {
+    let dump_path = PathBuf::from("tmp/dump_objects.sql");
+    let mut source_glue = Glue::new(MemoryStorage::default());
+
+    let sqls = [
+        "CREATE FUNCTION add_tax (price INT, rate INT DEFAULT 10) RETURN price + price * rate / 100;",
+        "CREATE TABLE Parent (id INTEGER PRIMARY KEY);",
+        "CREATE TABLE Child (
+            id INTEGER PRIMARY KEY,
+            parent_id INTEGER,
+            FOREIGN KEY (parent_id) REFERENCES Parent (id)
+        );",
+        "CREATE TABLE Log (child_id INTEGER);",
+        "INSERT INTO Parent VALUES (1), (2);",
+        "INSERT INTO Child VALUES (10, 1), (20, 2);",
+        "CREATE TRIGGER child_insert AFTER INSERT ON Child FOR EACH ROW
+         BEGIN
+             INSERT INTO Log VALUES (NEW.id);
+         END;",
+    ];
+
+    for sql in sqls {
+        source_glue.execute(sql).unwrap();
+    }
+
+    dump_database(
+        &mut source_glue.storage,
+        dump_path.clone(),
+        &DumpOptions::default(),
+    )
+    .unwrap();
+
+    let dump = fs::read_to_string(&dump_path).unwrap();
+    let position = |needle: &str| {
+        dump.find(needle)
+            .unwrap_or_else(|| panic!("{needle} not found in dump:\n{dump}"))
+    };
+    assert!(position("CREATE FUNCTION") < position(r#"CREATE TABLE "Child""#));
+    assert!(position(r#"INSERT INTO "Parent""#) < position(r#"CREATE TABLE "Child""#));
+    assert!(position(r#"INSERT INTO "Child""#) < position("CREATE TRIGGER"));
+
+    let mut target_glue = Glue::new(MemoryStorage::default());
+    let count = restore_database(&mut target_glue, &dump_path).unwrap();
+    assert_eq!(count, 7);
+
+    for sql in [
+        "SELECT * FROM Child",
+        "SELECT * FROM Log",
+        "SELECT add_tax(100) AS price",
+    ] {
+        assert_eq!(source_glue.execute(sql), target_glue.execute(sql));
+    }
+
+    target_glue
+        .execute("INSERT INTO Child VALUES (30, 1);")
+        .unwrap();
+    assert_eq!(
+        target_glue.execute("SELECT * FROM Log"),
+        Ok(vec![Payload::Select {
+            labels: vec!["child_id".to_owned()],
+            rows: vec![vec![Value::I64(30)]],
+        }])
+    );
+}
+
+#[test]
+fn dump_selected_tables_with_filter() {
+    let dump_path = PathBuf::from("tmp/dump_filtered.sql");
+    let mut source_glue = Glue::new(MemoryStorage::default());
+
+    source_glue
+        .execute(
+            "
+            CREATE TABLE Foo (id INTEGER, name TEXT);
+            CREATE TABLE Bar (id INTEGER);
+            CREATE TABLE Baz;
+            INSERT INTO Foo VALUES (1, 'a'), (2, 'b'), (3, 'c');
+            INSERT INTO Bar VALUES (1), (5);
+            INSERT INTO Baz VALUES ('{\"id\": 1}'), ('{\"id\": 9}');
+            ",
+        )
+        .unwrap();
+
+    let options = DumpOptions {
+        tables: vec!["Foo".to_owned(), "Baz".to_owned()],
+        filter: Some("id >= 2".to_owned()),
+    };
+    dump_database(&mut source_glue.storage, dump_path.clone(), &options).unwrap();
+
+    let dump = fs::read_to_string(&dump_path).unwrap();
+    assert!(!dump.contains(r#""Bar""#));
+
+    let target_path = "tmp/dump_filtered_target";
+    let _ = fs::remove_dir_all(target_path);
+    let mut target_glue = Glue::new(JsonStorage::new(target_path).unwrap());
+    restore_database(&mut target_glue, &dump_path).unwrap();
+
+    assert_eq!(
+        target_glue.execute("SELECT id, name FROM Foo"),
+        Ok(vec![Payload::Select {
+            labels: vec!["id".to_owned(), "name".to_owned()],
+            rows: vec![
+                vec![Value::I64(2), Value::Str("b".to_owned())],
+                vec![Value::I64(3), Value::Str("c".to_owned())],
+            ],
+        }])
+    );
+    assert_eq!(
+        target_glue.execute("SELECT id FROM Baz"),
+        source_glue.execute("SELECT id FROM Baz WHERE id >= 2"),
+    );
+
+    let options = DumpOptions {
+        tables: vec!["Missing".to_owned()],
+        filter: None,
+    };
+    let error = dump_database(&mut source_glue.storage, dump_path, &options).unwrap_err();
+    assert_eq!(error.to_string(), "table not found: Missing");
+
+    fs::remove_dir_all(target_path).unwrap();
+}
@@ -0,0 +1,212 @@
+use {
+    gluesql_cli::{export_query, import_file},
+    gluesql_core::prelude::{Glue, Payload, Value},
+    gluesql_memory_storage::MemoryStorage,
+    std::{fs, path::Path},
+};
+
+#[test]
+fn import_csv_with_inferred_schema() {
+    let dir = Path::new("tmp/transfer_csv");
+    fs::create_dir_all(dir).unwrap();
+    let path = dir.join("people.csv");
+    fs::write(&path, "id,name,score\n1,Alice,9.5\n2,Bob,NULL\n").unwrap();
+
+    let mut glue = Glue::new(MemoryStorage::default());
+    assert_eq!(
+        import_file(&mut glue, &path, "People", false)
+            .unwrap_err()
+            .to_string(),
+        "table not found: People, use --create to create it"
+    );
+    assert_eq!(import_file(&mut glue, &path, "People", true).unwrap(), 2);
+    assert_eq!(
+        import_file(&mut glue, &path, "People", true)
+            .unwrap_err()
+            .to_string(),
+        "table already exists: People"
+    );
+
+    assert_eq!(
+        glue.execute("SELECT * FROM People"),
+        Ok(vec![Payload::Select {
+            labels: vec!["id".to_owned(), "name".to_owned(), "score".to_owned()],
+            rows: vec![
+                vec![
+                    Value::I64(1),
+                    Value::Str("Alice".to_owned()),
+                    Value::F64(9.5)
+                ],
+                vec![Value::I64(2), Value::Str("Bob".to_owned()), Value::Null],
+            ],
+        }])
+    );
+
+    // columns are matched by name and cast to the types of an existing table
+    glue.execute("CREATE TABLE Scores (score FLOAT, id INT8, note TEXT DEFAULT 'none');")
+        .unwrap();
+    fs::write(dir.join("scores.csv"), "id,score\n3,1.5\n").unwrap();
+    assert_eq!(
+        import_file(&mut glue, &dir.join("scores.csv"), "Scores", false).unwrap(),
+        1
+    );
+    assert_eq!(
+        glue.execute("SELECT * FROM Scores"),
+        Ok(vec![Payload::Select {
+            labels: vec!["score".to_owned(), "id".to_owned(), "note".to_owned()],
+            rows: vec![vec![
+                Value::F64(1.5),
+                Value::I8(3),
+                Value::Str("none".to_owned())
+            ]],
+        }])
+    );
+
+    assert_eq!(
+        import_file(&mut glue, &path, "Scores", false)
+            .unwrap_err()
+            .to_string(),
+        "column not found in Scores: name"
+    );
+    assert_eq!(
+        import_file(&mut glue, &dir.join("missing.csv"), "People", false)
+            .unwrap_err()
+            .to_string(),
+        "file not found: tmp/transfer_csv/missing.csv"
+    );
+    assert_eq!(
+        import_file(&mut glue, &dir.join("people.txt"), "People", false)
+            .unwrap_err()
+            .to_string(),
+        "unsupported file: tmp/transfer_csv/people.txt, expected .csv, .json, .jsonl or .parquet"
+    );
+}
+
+#[test]
+fn import_schemaless_json() {
+    let dir = Path::new("tmp/transfer_json");
+    fs::create_dir_all(dir).unwrap();
+    let path = dir.join("events.jsonl");
+    fs::write(
+        &path,
+        "{\"id\": 1, \"kind\": \"click\"}\n{\"id\": 2, \"amount\": 1.5}\n{\"id\": 3, \"amount\": 2}\n",
+    )
+    .unwrap();
+
+    let mut glue = Glue::new(MemoryStorage::default());
+    assert_eq!(import_file(&mut glue, &path, "Events", true).unwrap(), 3);
+    assert_eq!(
+        glue.execute("SELECT id, amount, kind FROM Events"),
+        Ok(vec![Payload::Select {
+            labels: vec!["id".to_owned(), "amount".to_owned(), "kind".to_owned()],
+            rows: vec![
+                vec![Value::I64(1), Value::Null, Value::Str("click".to_owned())],
+                vec![Value::I64(2), Value::F64(1.5), Value::Null],
+                vec![Value::I64(3), Value::F64(2.0), Value::Null],
+            ],
+        }])
+    );
+
+    glue.execute("CREATE TABLE Raw;").unwrap();
+    assert_eq!(import_file(&mut glue, &path, "Raw", false).unwrap(), 3);
+    assert_eq!(
+        glue.execute("SELECT COUNT(*) AS count FROM Raw WHERE amount IS NOT NULL"),
+        Ok(vec![Payload::Select {
+            labels: vec!["count".to_owned()],
+            rows: vec![vec![Value::I64(2)]],
+        }])
+    );
+}
+
+#[test]
+fn export_and_import_round_trip() {
+    let dir = Path::new("tmp/transfer_export");
+    let _ = fs::remove_dir_all(dir);
+
+    let mut glue = Glue::new(MemoryStorage::default());
+    glue.execute(
+        "
+        CREATE TABLE Item (id INTEGER, name TEXT, price FLOAT, tags LIST);
+        INSERT INTO Item VALUES
+            (1, 'apple', 1.5, '[\"red\"]'),
+            (2, 'kiwi', NULL, '[]'),
+            (3, 'plum', 3.0, '[\"purple\", \"sour\"]');
+        ",
+    )
+    .unwrap();
+
+    let query = "SELECT id, name, price FROM Item WHERE id > 1";
+    for file in ["items.csv", "items.jsonl", "items.parquet"] {
+        let path = dir.join(file);
+        assert_eq!(export_query(&mut glue, query, &path).unwrap(), 2);
+        // exporting again replaces the file
+        assert_eq!(export_query(&mut glue, query, &path).unwrap(), 2);
+
+        // only parquet keeps the column types, CSV and JSONL go into a typed table
+        let table_name = format!("Copy_{}", file.replace('.', "_"));
+        let create = file.ends_with(".parquet");
+        if !create {
+            glue.execute(format!(
+                "CREATE TABLE {table_name} (id INTEGER, name TEXT, price FLOAT);"
+            ))
+            .unwrap();
+        }
+        assert_eq!(
+            import_file(&mut glue, &path, &table_name, create).unwrap(),
+            2,
+            "{file}"
+        );
+        assert_eq!(
+            glue.execute(format!("SELECT * FROM {table_name}")),
+            glue.execute(query),
+            "{file}"
+        );
+    }
+
+    let csv = fs::read_to_string(dir.join("items.csv")).unwrap();
+    assert_eq!(csv, "id,name,price\n2,kiwi,NULL\n3,plum,3\n");
+
+    assert_eq!(
+        export_query(&mut glue, "SELECT * FROM Item", &dir.join("items.json"))
+            .unwrap_err()
+            .to_string(),
+        "export to .json is not supported, use .jsonl instead"
+    );
+    assert_eq!(
+        export_query(&mut glue, "DELETE FROM Item", &dir.join("deleted.csv"))
+            .unwrap_err()
+            .to_string(),
+        "query does not return rows: DELETE FROM Item"
+    );
+}
+
+#[test]
+fn export_writes_only_target_file() {
+    let mut glue = Glue::new(MemoryStorage::default());
+    glue.execute(
+        "CREATE TABLE Item (id INTEGER, name TEXT); INSERT INTO Item VALUES (1, 'apple');",
+    )
+    .unwrap();
+
+    for file in ["items.csv", "items.jsonl", "items.parquet"] {
+        let dir = Path::new("tmp/transfer_export_only").join(file.replace('.', "_"));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join(file);
+        assert_eq!(
+            export_query(&mut glue, "SELECT * FROM Item", &path).unwrap(),
+            1
+        );
+        assert_eq!(
+            export_query(&mut glue, "SELECT * FROM Item", &path).unwrap(),
+            1
+        );
+
+        let files = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect::<Vec<_>>();
+        assert_eq!(files, vec![file], "{file}");
+    }
+}
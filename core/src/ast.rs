@@ -88,6 +88,8 @@ pub enum Statement {
         engine: Option<String>,
         foreign_keys: Vec<ForeignKey>,
         comment: Option<String>,
+        /// `WITH (ttl_column = ...)` table option
+        ttl_column: Option<String>,
     },
     /// CREATE FUNCTION
     CreateFunction {
@@ -119,6 +121,22 @@ pub enum Statement {
         /// One or more objects to drop. (ANSI SQL requires exactly one.)
         names: Vec<String>,
     },
+    /// CREATE TRIGGER
+    CreateTrigger {
+        or_replace: bool,
+        name: String,
+        table_name: String,
+        timing: TriggerTiming,
+        events: Vec<TriggerEvent>,
+        /// SQL text of each statement between `BEGIN` and `END`
+        body: Vec<String>,
+    },
+    /// DROP TRIGGER
+    DropTrigger {
+        if_exists: bool,
+        name: String,
+        table_name: String,
+    },
     /// CREATE INDEX
     CreateIndex {
         name: String,
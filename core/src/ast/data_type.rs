@@ -31,4 +31,9 @@ pub enum DataType {
     List,
     Decimal,
     Point,
+    #[strum(to_string = "LINESTRING")]
+    LineString,
+    Polygon,
+    #[strum(to_string = "MULTIPOINT")]
+    MultiPoint,
 }
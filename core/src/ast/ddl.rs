@@ -2,6 +2,7 @@ use {
     super::{DataType, Expr},
     crate::ast::ToSql,
     serde::{Deserialize, Serialize},
+    strum_macros::Display,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -47,6 +48,21 @@ pub struct OperateFunctionArg {
     pub default: Option<Expr>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum TriggerTiming {
+    Before,
+    After,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum TriggerEvent {
+    Insert,
+    Update,
+    Delete,
+}
+
 impl ToSql for ColumnDef {
     fn to_sql(&self) -> String {
         let ColumnDef {
@@ -462,6 +462,7 @@ mod tests {
                         },
                         selection: None,
                         group_by: Vec::new(),
+                        grouping_sets: None,
                         having: None,
                     })),
                     order_by: Vec::new(),
@@ -490,6 +491,7 @@ mod tests {
                         },
                         selection: None,
                         group_by: Vec::new(),
+                        grouping_sets: None,
                         having: None,
                     })),
                     order_by: Vec::new(),
@@ -517,6 +519,7 @@ mod tests {
                         },
                         selection: None,
                         group_by: Vec::new(),
+                        grouping_sets: None,
                         having: None,
                     })),
                     order_by: Vec::new(),
@@ -544,6 +547,7 @@ mod tests {
                         },
                         selection: None,
                         group_by: Vec::new(),
+                        grouping_sets: None,
                         having: None,
                     })),
                     order_by: Vec::new(),
@@ -570,6 +574,7 @@ mod tests {
                     },
                     selection: None,
                     group_by: Vec::new(),
+                    grouping_sets: None,
                     having: None,
                 })),
                 order_by: Vec::new(),
@@ -1,5 +1,5 @@
 use {
-    super::{DataType, DateTimeField, Expr, literal::TrimWhereField},
+    super::{DataType, DateTimeField, Expr, OrderByExpr, literal::TrimWhereField},
     crate::ast::ToSql,
     serde::{Deserialize, Serialize},
     strum_macros::Display,
@@ -118,6 +118,37 @@ pub enum Function {
     CurrentTimestamp(),
     Pi(),
     LastDay(Expr),
+    DateTrunc {
+        field: Expr,
+        expr: Expr,
+    },
+    DateBin {
+        stride: Expr,
+        expr: Expr,
+        origin: Option<Expr>,
+    },
+    DateDiff {
+        field: Expr,
+        start: Expr,
+        end: Expr,
+    },
+    Age {
+        left: Expr,
+        right: Option<Expr>,
+    },
+    MakeDate {
+        year: Expr,
+        month: Expr,
+        day: Expr,
+    },
+    MakeTimestamp {
+        year: Expr,
+        month: Expr,
+        day: Expr,
+        hour: Expr,
+        minute: Expr,
+        second: Expr,
+    },
     Ltrim {
         expr: Expr,
         chars: Option<Expr>,
@@ -168,6 +199,32 @@ pub enum Function {
         sub_expr: Expr,
         start: Option<Expr>,
     },
+    RegexpLike {
+        expr: Expr,
+        pattern: Expr,
+        flags: Option<Expr>,
+    },
+    RegexpReplace {
+        expr: Expr,
+        pattern: Expr,
+        replacement: Expr,
+        flags: Option<Expr>,
+    },
+    RegexpSubstr {
+        expr: Expr,
+        pattern: Expr,
+        flags: Option<Expr>,
+    },
+    RegexpMatches {
+        expr: Expr,
+        pattern: Expr,
+        flags: Option<Expr>,
+    },
+    RegexpSplitToArray {
+        expr: Expr,
+        pattern: Expr,
+        flags: Option<Expr>,
+    },
     Ascii(Expr),
     Chr(Expr),
     Md5(Expr),
@@ -207,6 +264,24 @@ pub enum Function {
         geometry1: Expr,
         geometry2: Expr,
     },
+    StContains {
+        geometry1: Expr,
+        geometry2: Expr,
+    },
+    StIntersects {
+        geometry1: Expr,
+        geometry2: Expr,
+    },
+    StWithin {
+        geometry1: Expr,
+        geometry2: Expr,
+    },
+    StArea(Expr),
+    StLength(Expr),
+    StDistanceSphere {
+        geometry1: Expr,
+        geometry2: Expr,
+    },
     IsEmpty(Expr),
     Length(Expr),
     Entries(Expr),
@@ -221,6 +296,113 @@ pub enum Function {
     Dedup(Expr),
 }
 
+impl Function {
+    /// Names of the built-in functions, as written in SQL.
+    pub const NAMES: &[&str] = &[
+        "ABS",
+        "ACOS",
+        "ADD_MONTH",
+        "AGE",
+        "APPEND",
+        "ASCII",
+        "ASIN",
+        "ATAN",
+        "CALC_DISTANCE",
+        "CAST",
+        "CEIL",
+        "CHR",
+        "COALESCE",
+        "CONCAT",
+        "CONCAT_WS",
+        "COS",
+        "CURRENT_DATE",
+        "CURRENT_TIME",
+        "CURRENT_TIMESTAMP",
+        "DATE_BIN",
+        "DATE_DIFF",
+        "DATE_TRUNC",
+        "DEDUP",
+        "DEGREES",
+        "DIV",
+        "ENTRIES",
+        "EXP",
+        "EXTRACT",
+        "FIND_IDX",
+        "FLOOR",
+        "FORMAT",
+        "GCD",
+        "GENERATE_UUID",
+        "GET_X",
+        "GET_Y",
+        "GREATEST",
+        "HEX",
+        "IFNULL",
+        "INITCAP",
+        "IS_EMPTY",
+        "KEYS",
+        "LAST_DAY",
+        "LCM",
+        "LEFT",
+        "LENGTH",
+        "LN",
+        "LOG",
+        "LOG10",
+        "LOG2",
+        "LOWER",
+        "LPAD",
+        "LTRIM",
+        "MAKE_DATE",
+        "MAKE_TIMESTAMP",
+        "MD5",
+        "MOD",
+        "NOW",
+        "NULLIF",
+        "PI",
+        "POINT",
+        "POSITION",
+        "POWER",
+        "PREPEND",
+        "RADIANS",
+        "RAND",
+        "REGEXP_LIKE",
+        "REGEXP_MATCHES",
+        "REGEXP_REPLACE",
+        "REGEXP_SPLIT_TO_ARRAY",
+        "REGEXP_SUBSTR",
+        "REPEAT",
+        "REPLACE",
+        "REVERSE",
+        "RIGHT",
+        "ROUND",
+        "RPAD",
+        "RTRIM",
+        "SIGN",
+        "SIN",
+        "SKIP",
+        "SLICE",
+        "SORT",
+        "SPLICE",
+        "SQRT",
+        "ST_AREA",
+        "ST_CONTAINS",
+        "ST_DISTANCE_SPHERE",
+        "ST_INTERSECTS",
+        "ST_LENGTH",
+        "ST_WITHIN",
+        "SUBSTR",
+        "TAKE",
+        "TAN",
+        "TO_DATE",
+        "TO_TIME",
+        "TO_TIMESTAMP",
+        "TRIM",
+        "TRUNC",
+        "UNWRAP",
+        "UPPER",
+        "VALUES",
+    ];
+}
+
 impl ToSql for Function {
     fn to_sql(&self) -> String {
         match self {
@@ -355,6 +537,54 @@ impl ToSql for Function {
             Function::CurrentTimestamp() => "CURRENT_TIMESTAMP()".to_owned(),
             Function::Pi() => "PI()".to_owned(),
             Function::LastDay(expr) => format!("LAST_DAY({})", expr.to_sql()),
+            Function::DateTrunc { field, expr } => {
+                format!("DATE_TRUNC({}, {})", field.to_sql(), expr.to_sql())
+            }
+            Function::DateBin {
+                stride,
+                expr,
+                origin,
+            } => match origin {
+                None => format!("DATE_BIN({}, {})", stride.to_sql(), expr.to_sql()),
+                Some(origin) => format!(
+                    "DATE_BIN({}, {}, {})",
+                    stride.to_sql(),
+                    expr.to_sql(),
+                    origin.to_sql()
+                ),
+            },
+            Function::DateDiff { field, start, end } => format!(
+                "DATE_DIFF({}, {}, {})",
+                field.to_sql(),
+                start.to_sql(),
+                end.to_sql()
+            ),
+            Function::Age { left, right } => match right {
+                None => format!("AGE({})", left.to_sql()),
+                Some(right) => format!("AGE({}, {})", left.to_sql(), right.to_sql()),
+            },
+            Function::MakeDate { year, month, day } => format!(
+                "MAKE_DATE({}, {}, {})",
+                year.to_sql(),
+                month.to_sql(),
+                day.to_sql()
+            ),
+            Function::MakeTimestamp {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            } => format!(
+                "MAKE_TIMESTAMP({}, {}, {}, {}, {}, {})",
+                year.to_sql(),
+                month.to_sql(),
+                day.to_sql(),
+                hour.to_sql(),
+                minute.to_sql(),
+                second.to_sql()
+            ),
             Function::Ltrim { expr, chars } => match chars {
                 None => format!("LTRIM({})", expr.to_sql()),
                 Some(chars) => format!("LTRIM({}, {})", expr.to_sql(), chars.to_sql()),
@@ -425,6 +655,54 @@ impl ToSql for Function {
                     start_expr.to_sql()
                 ),
             },
+            Function::RegexpLike {
+                expr,
+                pattern,
+                flags,
+            }
+            | Function::RegexpSubstr {
+                expr,
+                pattern,
+                flags,
+            }
+            | Function::RegexpMatches {
+                expr,
+                pattern,
+                flags,
+            }
+            | Function::RegexpSplitToArray {
+                expr,
+                pattern,
+                flags,
+            } => match flags {
+                None => format!("{self}({}, {})", expr.to_sql(), pattern.to_sql()),
+                Some(flags) => format!(
+                    "{self}({}, {}, {})",
+                    expr.to_sql(),
+                    pattern.to_sql(),
+                    flags.to_sql()
+                ),
+            },
+            Function::RegexpReplace {
+                expr,
+                pattern,
+                replacement,
+                flags,
+            } => match flags {
+                None => format!(
+                    "REGEXP_REPLACE({}, {}, {})",
+                    expr.to_sql(),
+                    pattern.to_sql(),
+                    replacement.to_sql()
+                ),
+                Some(flags) => format!(
+                    "REGEXP_REPLACE({}, {}, {}, {})",
+                    expr.to_sql(),
+                    pattern.to_sql(),
+                    replacement.to_sql(),
+                    flags.to_sql()
+                ),
+            },
             Function::Extract { field, expr } => {
                 format!("EXTRACT({field} FROM {})", expr.to_sql())
             }
@@ -483,6 +761,36 @@ impl ToSql for Function {
                     geometry2.to_sql()
                 )
             }
+            Function::StContains {
+                geometry1,
+                geometry2,
+            } => format!(
+                "ST_CONTAINS({}, {})",
+                geometry1.to_sql(),
+                geometry2.to_sql()
+            ),
+            Function::StIntersects {
+                geometry1,
+                geometry2,
+            } => format!(
+                "ST_INTERSECTS({}, {})",
+                geometry1.to_sql(),
+                geometry2.to_sql()
+            ),
+            Function::StWithin {
+                geometry1,
+                geometry2,
+            } => format!("ST_WITHIN({}, {})", geometry1.to_sql(), geometry2.to_sql()),
+            Function::StArea(e) => format!("ST_AREA({})", e.to_sql()),
+            Function::StLength(e) => format!("ST_LENGTH({})", e.to_sql()),
+            Function::StDistanceSphere {
+                geometry1,
+                geometry2,
+            } => format!(
+                "ST_DISTANCE_SPHERE({}, {})",
+                geometry1.to_sql(),
+                geometry2.to_sql()
+            ),
             Function::IsEmpty(e) => format!("IS_EMPTY({})", e.to_sql()),
             Function::Length(e) => format!("LENGTH({})", e.to_sql()),
             Function::Entries(e) => format!("ENTRIES({})", e.to_sql()),
@@ -522,17 +830,49 @@ pub enum AggregateFunction {
     Avg(Expr),
     Variance(Expr),
     Stdev(Expr),
+    StringAgg {
+        expr: Expr,
+        separator: Expr,
+        order_by: Vec<OrderByExpr>,
+    },
+    ArrayAgg {
+        expr: Expr,
+        order_by: Vec<OrderByExpr>,
+    },
+    BoolAnd(Expr),
+    BoolOr(Expr),
+    Median(Expr),
+    PercentileCont {
+        fraction: Expr,
+        order_by: OrderByExpr,
+    },
+    PercentileDisc {
+        fraction: Expr,
+        order_by: OrderByExpr,
+    },
+    Mode(Expr),
+    Grouping(Vec<Expr>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Aggregate {
     pub func: AggregateFunction,
     pub distinct: bool,
+    pub filter: Option<Expr>,
 }
 
 impl Aggregate {
     pub fn new(func: AggregateFunction, distinct: bool) -> Self {
-        Self { func, distinct }
+        Self {
+            func,
+            distinct,
+            filter: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_filter(self, filter: Option<Expr>) -> Self {
+        Self { filter, ..self }
     }
 
     pub fn count(expr: CountArgExpr, distinct: bool) -> Self {
@@ -562,30 +902,128 @@ impl Aggregate {
     pub fn stdev(expr: Expr, distinct: bool) -> Self {
         Self::new(AggregateFunction::Stdev(expr), distinct)
     }
+
+    pub fn bool_and(expr: Expr, distinct: bool) -> Self {
+        Self::new(AggregateFunction::BoolAnd(expr), distinct)
+    }
+
+    pub fn bool_or(expr: Expr, distinct: bool) -> Self {
+        Self::new(AggregateFunction::BoolOr(expr), distinct)
+    }
+
+    pub fn median(expr: Expr, distinct: bool) -> Self {
+        Self::new(AggregateFunction::Median(expr), distinct)
+    }
+
+    pub fn mode(expr: Expr, distinct: bool) -> Self {
+        Self::new(AggregateFunction::Mode(expr), distinct)
+    }
 }
 
 impl AggregateFunction {
+    /// Names of the aggregate functions, as written in SQL.
+    pub const NAMES: &[&str] = &[
+        "COUNT",
+        "SUM",
+        "MIN",
+        "MAX",
+        "AVG",
+        "VARIANCE",
+        "STDEV",
+        "BOOL_AND",
+        "BOOL_OR",
+        "MEDIAN",
+        "MODE",
+        "GROUPING",
+        "STRING_AGG",
+        "ARRAY_AGG",
+        "PERCENTILE_CONT",
+        "PERCENTILE_DISC",
+    ];
+
     fn to_sql_with_distinct(&self, distinct: bool) -> String {
-        let (name, arg) = match self {
-            AggregateFunction::Count(expr) => ("COUNT", expr.to_sql()),
-            AggregateFunction::Sum(expr) => ("SUM", expr.to_sql()),
-            AggregateFunction::Max(expr) => ("MAX", expr.to_sql()),
-            AggregateFunction::Min(expr) => ("MIN", expr.to_sql()),
-            AggregateFunction::Avg(expr) => ("AVG", expr.to_sql()),
-            AggregateFunction::Variance(expr) => ("VARIANCE", expr.to_sql()),
-            AggregateFunction::Stdev(expr) => ("STDEV", expr.to_sql()),
+        let order_by_sql = |order_by: &[OrderByExpr]| {
+            if order_by.is_empty() {
+                String::new()
+            } else {
+                let order_by = order_by
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(" ORDER BY {order_by}")
+            }
+        };
+
+        let (name, arg, within_group) = match self {
+            AggregateFunction::Count(expr) => ("COUNT", expr.to_sql(), None),
+            AggregateFunction::Sum(expr) => ("SUM", expr.to_sql(), None),
+            AggregateFunction::Max(expr) => ("MAX", expr.to_sql(), None),
+            AggregateFunction::Min(expr) => ("MIN", expr.to_sql(), None),
+            AggregateFunction::Avg(expr) => ("AVG", expr.to_sql(), None),
+            AggregateFunction::Variance(expr) => ("VARIANCE", expr.to_sql(), None),
+            AggregateFunction::Stdev(expr) => ("STDEV", expr.to_sql(), None),
+            AggregateFunction::StringAgg {
+                expr,
+                separator,
+                order_by,
+            } => (
+                "STRING_AGG",
+                format!(
+                    "{}, {}{}",
+                    expr.to_sql(),
+                    separator.to_sql(),
+                    order_by_sql(order_by)
+                ),
+                None,
+            ),
+            AggregateFunction::ArrayAgg { expr, order_by } => (
+                "ARRAY_AGG",
+                format!("{}{}", expr.to_sql(), order_by_sql(order_by)),
+                None,
+            ),
+            AggregateFunction::BoolAnd(expr) => ("BOOL_AND", expr.to_sql(), None),
+            AggregateFunction::BoolOr(expr) => ("BOOL_OR", expr.to_sql(), None),
+            AggregateFunction::Median(expr) => ("MEDIAN", expr.to_sql(), None),
+            AggregateFunction::PercentileCont { fraction, order_by } => {
+                ("PERCENTILE_CONT", fraction.to_sql(), Some(order_by))
+            }
+            AggregateFunction::PercentileDisc { fraction, order_by } => {
+                ("PERCENTILE_DISC", fraction.to_sql(), Some(order_by))
+            }
+            AggregateFunction::Mode(expr) => ("MODE", expr.to_sql(), None),
+            AggregateFunction::Grouping(exprs) => (
+                "GROUPING",
+                exprs
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                None,
+            ),
         };
-        if distinct {
+        let sql = if distinct {
             format!("{name}(DISTINCT {arg})")
         } else {
             format!("{name}({arg})")
+        };
+
+        match within_group {
+            Some(order_by) => format!("{sql} WITHIN GROUP (ORDER BY {})", order_by.to_sql()),
+            None => sql,
         }
     }
 }
 
 impl ToSql for Aggregate {
     fn to_sql(&self) -> String {
-        self.func.to_sql_with_distinct(self.distinct)
+        let sql = self.func.to_sql_with_distinct(self.distinct);
+
+        match &self.filter {
+            Some(filter) => format!("{sql} FILTER (WHERE {})", filter.to_sql()),
+            None => sql,
+        }
     }
 }
 
@@ -609,8 +1047,8 @@ mod tests {
     use {
         crate::{
             ast::{
-                Aggregate, CountArgExpr, DataType, DateTimeField, Expr, Function, Literal, ToSql,
-                TrimWhereField,
+                Aggregate, AggregateFunction, BinaryOperator, CountArgExpr, DataType,
+                DateTimeField, Expr, Function, Literal, OrderByExpr, ToSql, TrimWhereField,
             },
             data::Value,
         },
@@ -1205,6 +1643,67 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            "DATE_TRUNC('month', \"ts\")",
+            &Expr::Function(Box::new(Function::DateTrunc {
+                field: Expr::Literal(Literal::QuotedString("month".to_owned())),
+                expr: Expr::Identifier("ts".to_owned())
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "DATE_BIN(\"stride\", \"ts\", \"origin\")",
+            &Expr::Function(Box::new(Function::DateBin {
+                stride: Expr::Identifier("stride".to_owned()),
+                expr: Expr::Identifier("ts".to_owned()),
+                origin: Some(Expr::Identifier("origin".to_owned()))
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "DATE_DIFF('day', \"a\", \"b\")",
+            &Expr::Function(Box::new(Function::DateDiff {
+                field: Expr::Literal(Literal::QuotedString("day".to_owned())),
+                start: Expr::Identifier("a".to_owned()),
+                end: Expr::Identifier("b".to_owned())
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "AGE(\"born\")",
+            &Expr::Function(Box::new(Function::Age {
+                left: Expr::Identifier("born".to_owned()),
+                right: None
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "MAKE_DATE(\"y\", \"m\", \"d\")",
+            &Expr::Function(Box::new(Function::MakeDate {
+                year: Expr::Identifier("y".to_owned()),
+                month: Expr::Identifier("m".to_owned()),
+                day: Expr::Identifier("d".to_owned())
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "MAKE_TIMESTAMP(\"y\", \"m\", \"d\", \"h\", \"mi\", \"s\")",
+            &Expr::Function(Box::new(Function::MakeTimestamp {
+                year: Expr::Identifier("y".to_owned()),
+                month: Expr::Identifier("m".to_owned()),
+                day: Expr::Identifier("d".to_owned()),
+                hour: Expr::Identifier("h".to_owned()),
+                minute: Expr::Identifier("mi".to_owned()),
+                second: Expr::Identifier("s".to_owned())
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             "TO_DATE('2022-10-12', '%Y-%m-%d')",
             &Expr::Function(Box::new(Function::ToDate {
@@ -1416,6 +1915,58 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#"ST_CONTAINS("area", "spot")"#,
+            &Expr::Function(Box::new(Function::StContains {
+                geometry1: Expr::Identifier("area".to_owned()),
+                geometry2: Expr::Identifier("spot".to_owned())
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ST_INTERSECTS("area", "spot")"#,
+            &Expr::Function(Box::new(Function::StIntersects {
+                geometry1: Expr::Identifier("area".to_owned()),
+                geometry2: Expr::Identifier("spot".to_owned())
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ST_WITHIN("spot", "area")"#,
+            &Expr::Function(Box::new(Function::StWithin {
+                geometry1: Expr::Identifier("spot".to_owned()),
+                geometry2: Expr::Identifier("area".to_owned())
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ST_AREA("area")"#,
+            &Expr::Function(Box::new(Function::StArea(Expr::Identifier(
+                "area".to_owned()
+            ))))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ST_LENGTH("route")"#,
+            &Expr::Function(Box::new(Function::StLength(Expr::Identifier(
+                "route".to_owned()
+            ))))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ST_DISTANCE_SPHERE("from", "to")"#,
+            &Expr::Function(Box::new(Function::StDistanceSphere {
+                geometry1: Expr::Identifier("from".to_owned()),
+                geometry2: Expr::Identifier("to".to_owned())
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             r#"IS_EMPTY("list")"#,
             &Expr::Function(Box::new(Function::IsEmpty(Expr::Identifier(
@@ -1475,6 +2026,37 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#"REGEXP_LIKE("name", '^G')"#,
+            &Expr::Function(Box::new(Function::RegexpLike {
+                expr: Expr::Identifier("name".to_owned()),
+                pattern: Expr::Literal(Literal::QuotedString("^G".to_owned())),
+                flags: None,
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"REGEXP_MATCHES("name", '(\d+)', 'i')"#,
+            &Expr::Function(Box::new(Function::RegexpMatches {
+                expr: Expr::Identifier("name".to_owned()),
+                pattern: Expr::Literal(Literal::QuotedString(r"(\d+)".to_owned())),
+                flags: Some(Expr::Literal(Literal::QuotedString("i".to_owned()))),
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"REGEXP_REPLACE("name", 'a', 'b', 'g')"#,
+            &Expr::Function(Box::new(Function::RegexpReplace {
+                expr: Expr::Identifier("name".to_owned()),
+                pattern: Expr::Literal(Literal::QuotedString("a".to_owned())),
+                replacement: Expr::Literal(Literal::QuotedString("b".to_owned())),
+                flags: Some(Expr::Literal(Literal::QuotedString("g".to_owned()))),
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             r#"DEDUP("list")"#,
             &Expr::Function(Box::new(Function::Dedup(Expr::Identifier(
@@ -1612,5 +2194,85 @@ mod tests {
             )))
             .to_sql()
         );
+
+        assert_eq!(
+            r#"STRING_AGG("name", ', ' ORDER BY "id" DESC)"#,
+            Expr::Aggregate(Box::new(Aggregate::new(
+                AggregateFunction::StringAgg {
+                    expr: Expr::Identifier("name".to_owned()),
+                    separator: Expr::Literal(Literal::QuotedString(", ".to_owned())),
+                    order_by: vec![OrderByExpr {
+                        expr: Expr::Identifier("id".to_owned()),
+                        asc: Some(false),
+                    }],
+                },
+                false
+            )))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ARRAY_AGG(DISTINCT "name")"#,
+            Expr::Aggregate(Box::new(Aggregate::new(
+                AggregateFunction::ArrayAgg {
+                    expr: Expr::Identifier("name".to_owned()),
+                    order_by: Vec::new(),
+                },
+                true
+            )))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"BOOL_AND("done") FILTER (WHERE "id" > 1)"#,
+            Expr::Aggregate(Box::new(
+                Aggregate::bool_and(Expr::Identifier("done".to_owned()), false).with_filter(Some(
+                    Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier("id".to_owned())),
+                        op: BinaryOperator::Gt,
+                        right: Box::new(Expr::Literal(Literal::Number(
+                            BigDecimal::from_str("1").unwrap()
+                        ))),
+                    }
+                ))
+            ))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY "total")"#,
+            Expr::Aggregate(Box::new(Aggregate::new(
+                AggregateFunction::PercentileCont {
+                    fraction: Expr::Literal(Literal::Number(BigDecimal::from_str("0.5").unwrap())),
+                    order_by: OrderByExpr {
+                        expr: Expr::Identifier("total".to_owned()),
+                        asc: None,
+                    },
+                },
+                false
+            )))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"MEDIAN("total")"#,
+            Expr::Aggregate(Box::new(Aggregate::median(
+                Expr::Identifier("total".to_owned()),
+                false
+            )))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"GROUPING("region", "product")"#,
+            Expr::Aggregate(Box::new(Aggregate::new(
+                AggregateFunction::Grouping(vec![
+                    Expr::Identifier("region".to_owned()),
+                    Expr::Identifier("product".to_owned()),
+                ]),
+                false
+            )))
+            .to_sql()
+        );
     }
 }
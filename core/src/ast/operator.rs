@@ -45,6 +45,10 @@ pub enum BinaryOperator {
     BitwiseShiftLeft,
     BitwiseShiftRight,
     Arrow,
+    RegexMatch,
+    RegexIMatch,
+    RegexNotMatch,
+    RegexNotIMatch,
 }
 
 impl ToSql for BinaryOperator {
@@ -54,7 +58,8 @@ impl ToSql for BinaryOperator {
             BinaryOperator::Multiply => "*".to_owned(),
             BinaryOperator::Divide => "/".to_owned(),
             BinaryOperator::Modulo => "%".to_owned(),
-            BinaryOperator::Plus | BinaryOperator::StringConcat => "+".to_owned(),
+            BinaryOperator::Plus => "+".to_owned(),
+            BinaryOperator::StringConcat => "||".to_owned(),
             BinaryOperator::Gt => ">".to_owned(),
             BinaryOperator::Lt => "<".to_owned(),
             BinaryOperator::GtEq => ">=".to_owned(),
@@ -68,6 +73,10 @@ impl ToSql for BinaryOperator {
             BinaryOperator::BitwiseShiftLeft => "<<".to_owned(),
             BinaryOperator::BitwiseShiftRight => ">>".to_owned(),
             BinaryOperator::Arrow => "->".to_owned(),
+            BinaryOperator::RegexMatch => "~".to_owned(),
+            BinaryOperator::RegexIMatch => "~*".to_owned(),
+            BinaryOperator::RegexNotMatch => "!~".to_owned(),
+            BinaryOperator::RegexNotIMatch => "!~*".to_owned(),
         }
     }
 }
@@ -79,6 +88,8 @@ pub enum IndexOperator {
     GtEq,
     LtEq,
     Eq,
+    /// Bounding boxes overlap; only spatial indexes answer it, as a prefilter.
+    Intersects,
 }
 
 impl IndexOperator {
@@ -92,18 +103,18 @@ impl IndexOperator {
             GtEq => LtEq,
             LtEq => GtEq,
             Eq => Eq,
+            Intersects => Intersects,
         }
     }
-}
 
-impl From<IndexOperator> for BinaryOperator {
-    fn from(index_op: IndexOperator) -> Self {
-        match index_op {
-            IndexOperator::Gt => BinaryOperator::Gt,
-            IndexOperator::Lt => BinaryOperator::Lt,
-            IndexOperator::GtEq => BinaryOperator::GtEq,
-            IndexOperator::LtEq => BinaryOperator::LtEq,
-            IndexOperator::Eq => BinaryOperator::Eq,
+    pub fn from_binary_operator(op: &BinaryOperator) -> Option<Self> {
+        match op {
+            BinaryOperator::Gt => Some(IndexOperator::Gt),
+            BinaryOperator::Lt => Some(IndexOperator::Lt),
+            BinaryOperator::GtEq => Some(IndexOperator::GtEq),
+            BinaryOperator::LtEq => Some(IndexOperator::LtEq),
+            BinaryOperator::Eq => Some(IndexOperator::Eq),
+            _ => None,
         }
     }
 }
@@ -167,7 +178,7 @@ mod tests {
         );
 
         assert_eq!(
-            "'Glue' + 'SQL'",
+            "'Glue' || 'SQL'",
             &Expr::BinaryOp {
                 left: Box::new(Expr::Literal(Literal::QuotedString("Glue".to_owned()))),
                 op: BinaryOperator::StringConcat,
@@ -310,6 +321,16 @@ mod tests {
             .to_sql(),
         );
 
+        assert_eq!(
+            r#""name" ~* '^glue'"#,
+            &Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("name".to_owned())),
+                op: BinaryOperator::RegexIMatch,
+                right: Box::new(Expr::Literal(Literal::QuotedString("^glue".to_owned())))
+            }
+            .to_sql()
+        );
+
         assert_eq!(
             "29 & 15",
             &Expr::BinaryOp {
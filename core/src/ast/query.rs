@@ -18,6 +18,13 @@ pub struct Query {
 pub enum SetExpr {
     Select(Box<Select>),
     Values(Values),
+    /// `left UNION [ALL] right`, where `ORDER BY`, `LIMIT` and `OFFSET` of the
+    /// enclosing [`Query`] apply to the combined rows.
+    Union {
+        left: Box<Query>,
+        right: Box<Query>,
+        all: bool,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -33,6 +40,9 @@ pub struct Select {
     /// WHERE
     pub selection: Option<Expr>,
     pub group_by: Vec<Expr>,
+    /// `GROUPING SETS`, `ROLLUP` and `CUBE` expanded into sets of indexes into `group_by`.
+    /// `None` groups by every `group_by` expression at once.
+    pub grouping_sets: Option<Vec<Vec<usize>>>,
     pub having: Option<Expr>,
 }
 
@@ -66,6 +76,12 @@ pub enum TableFactor {
         alias: TableAlias,
         size: Expr,
     },
+    GenerateSeries {
+        alias: TableAlias,
+        start: Expr,
+        stop: Expr,
+        step: Option<Expr>,
+    },
     Dictionary {
         dict: Dictionary,
         alias: TableAlias,
@@ -197,6 +213,30 @@ impl SetExpr {
             (SetExpr::Select(select), false) => select.to_sql_unquoted(),
             (SetExpr::Values(values), true) => format!("VALUES {}", values.to_sql()),
             (SetExpr::Values(values), false) => format!("VALUES {}", values.to_sql_unquoted()),
+            (SetExpr::Union { left, right, all }, _) => {
+                let operand = |query: &Query, nested: bool| {
+                    let Query {
+                        body,
+                        order_by,
+                        limit,
+                        offset,
+                    } = query;
+                    let sql = query.to_sql_with(quoted);
+                    let bare = order_by.is_empty()
+                        && limit.is_none()
+                        && offset.is_none()
+                        && !(nested && matches!(body, SetExpr::Union { .. }));
+
+                    if bare { sql } else { format!("({sql})") }
+                };
+                let all = if *all { " ALL" } else { "" };
+
+                format!(
+                    "{} UNION{all} {}",
+                    operand(left, false),
+                    operand(right, true)
+                )
+            }
         }
     }
 }
@@ -229,6 +269,7 @@ impl Select {
             from,
             selection,
             group_by,
+            grouping_sets,
             having,
         } = self;
         let projection = match projection {
@@ -242,10 +283,21 @@ impl Select {
             None => String::new(),
         };
 
-        let group_by = if group_by.is_empty() {
-            String::new()
-        } else {
-            format!("GROUP BY {}", group_by.iter().map(to_sql).join(", "))
+        let group_by = match grouping_sets {
+            Some(grouping_sets) => {
+                let grouping_sets = grouping_sets
+                    .iter()
+                    .map(|set| {
+                        let exprs = set.iter().map(|index| to_sql(&group_by[*index])).join(", ");
+
+                        format!("({exprs})")
+                    })
+                    .join(", ");
+
+                format!("GROUP BY GROUPING SETS ({grouping_sets})")
+            }
+            None if group_by.is_empty() => String::new(),
+            None => format!("GROUP BY {}", group_by.iter().map(to_sql).join(", ")),
         };
 
         let having = match having {
@@ -388,6 +440,27 @@ impl TableFactor {
             (TableFactor::Series { alias, size }, _) => {
                 format!("SERIES({}) {}", to_sql(size), alias.to_sql_with(quoted))
             }
+            (
+                TableFactor::GenerateSeries {
+                    alias,
+                    start,
+                    stop,
+                    step,
+                },
+                _,
+            ) => {
+                let step = step
+                    .as_ref()
+                    .map(|step| format!(", {}", to_sql(step)))
+                    .unwrap_or_default();
+
+                format!(
+                    "GENERATE_SERIES({}, {}{step}) {}",
+                    to_sql(start),
+                    to_sql(stop),
+                    alias.to_sql_with(quoted)
+                )
+            }
             (TableFactor::Dictionary { dict, alias }, true) => {
                 format!(r#""{dict}" {}"#, alias.to_sql_with(quoted))
             }
@@ -557,8 +630,8 @@ mod tests {
                 OrderByExpr, Projection, Query, Select, SelectItem, SetExpr, TableAlias,
                 TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, Values,
             },
-            parse_sql::parse_expr,
-            translate::{NO_PARAMS, translate_expr},
+            parse_sql::{parse_expr, parse_query},
+            translate::{NO_PARAMS, translate_expr, translate_query},
         },
         bigdecimal::BigDecimal,
         std::str::FromStr,
@@ -594,6 +667,7 @@ mod tests {
                 },
                 selection: None,
                 group_by: Vec::new(),
+                grouping_sets: None,
                 having: None,
             })),
             order_by,
@@ -631,6 +705,7 @@ mod tests {
                 },
                 selection: None,
                 group_by: Vec::new(),
+                grouping_sets: None,
                 having: None,
             })),
             order_by,
@@ -645,6 +720,25 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn to_sql_union() {
+        let query = |sql: &str| translate_query(&parse_query(sql).expect(sql), NO_PARAMS).unwrap();
+
+        let actual =
+            query("SELECT * FROM Foo UNION ALL VALUES (1) UNION SELECT * FROM Bar LIMIT 1");
+        let expected =
+            r#"SELECT * FROM "Foo" UNION ALL VALUES (1) UNION SELECT * FROM "Bar" LIMIT 1"#;
+        assert_eq!(actual.to_sql(), expected);
+        assert_eq!(query(expected), actual);
+
+        let actual = query(
+            "(SELECT * FROM Foo ORDER BY id LIMIT 1) UNION (SELECT * FROM Bar UNION SELECT * FROM Baz)",
+        );
+        let expected = "(SELECT * FROM Foo ORDER BY id LIMIT 1) UNION (SELECT * FROM Bar UNION SELECT * FROM Baz)";
+        assert_eq!(actual.to_sql_unquoted(), expected);
+        assert_eq!(query(expected), actual);
+    }
+
     #[test]
     fn to_sql_set_expr() {
         let actual = r#"SELECT * FROM "FOO" AS "F" INNER JOIN "PlayerItem""#.to_owned();
@@ -669,6 +763,7 @@ mod tests {
             },
             selection: None,
             group_by: Vec::new(),
+            grouping_sets: None,
             having: None,
         }))
         .to_sql();
@@ -715,6 +810,7 @@ mod tests {
             },
             selection: None,
             group_by: Vec::new(),
+            grouping_sets: None,
             having: None,
         }))
         .to_sql_unquoted();
@@ -770,6 +866,7 @@ mod tests {
             },
             selection: None,
             group_by: vec![Expr::Identifier("name".to_owned())],
+            grouping_sets: None,
             having: Some(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("name".to_owned())),
                 op: BinaryOperator::Eq,
@@ -796,6 +893,30 @@ mod tests {
                 right: Box::new(Expr::Literal(Literal::QuotedString("glue".to_owned()))),
             }),
             group_by: Vec::new(),
+            grouping_sets: None,
+            having: None,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual =
+            r#"SELECT * FROM "FOO" GROUP BY GROUPING SETS (("a", "b"), ("a"), ())"#.to_owned();
+        let expected = Select {
+            distinct: false,
+            projection: Projection::SelectItems(vec![SelectItem::Wildcard]),
+            from: TableWithJoins {
+                relation: TableFactor::Table {
+                    name: "FOO".to_owned(),
+                    alias: None,
+                },
+                joins: Vec::new(),
+            },
+            selection: None,
+            group_by: vec![
+                Expr::Identifier("a".to_owned()),
+                Expr::Identifier("b".to_owned()),
+            ],
+            grouping_sets: Some(vec![vec![0, 1], vec![0], Vec::new()]),
             having: None,
         }
         .to_sql();
@@ -820,6 +941,7 @@ mod tests {
             },
             selection: None,
             group_by: vec![Expr::Identifier("name".to_owned())],
+            grouping_sets: None,
             having: Some(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("name".to_owned())),
                 op: BinaryOperator::Eq,
@@ -846,6 +968,7 @@ mod tests {
                 right: Box::new(Expr::Literal(Literal::QuotedString("glue".to_owned()))),
             }),
             group_by: Vec::new(),
+            grouping_sets: None,
             having: None,
         }
         .to_sql_unquoted();
@@ -948,6 +1071,7 @@ mod tests {
                     },
                     selection: None,
                     group_by: Vec::new(),
+                    grouping_sets: None,
                     having: None,
                 })),
                 order_by: Vec::new(),
@@ -973,6 +1097,21 @@ mod tests {
         .to_sql();
         assert_eq!(actual, expected);
 
+        let actual = r#"GENERATE_SERIES(1, 10, 2) AS "S""#;
+        let expected = TableFactor::GenerateSeries {
+            alias: TableAlias {
+                name: "S".to_owned(),
+                columns: Vec::new(),
+            },
+            start: Expr::Literal(Literal::Number(BigDecimal::from_str("1").unwrap())),
+            stop: Expr::Literal(Literal::Number(BigDecimal::from_str("10").unwrap())),
+            step: Some(Expr::Literal(Literal::Number(
+                BigDecimal::from_str("2").unwrap(),
+            ))),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
         let actual = r#""GLUE_TABLES" AS "glue""#;
         let expected = TableFactor::Dictionary {
             dict: Dictionary::GlueTables,
@@ -1013,6 +1152,7 @@ mod tests {
                     },
                     selection: None,
                     group_by: Vec::new(),
+                    grouping_sets: None,
                     having: None,
                 })),
                 order_by: Vec::new(),
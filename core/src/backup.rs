@@ -0,0 +1,273 @@
+//! Storage-agnostic backups.
+//!
+//! [`write_backup`] streams the schemas, rows, custom functions and triggers
+//! of a snapshot as JSON lines, and [`restore_backup`] replays such a stream into
+//! any storage, which does not have to be the engine the backup came from.
+
+use {
+    crate::{
+        ast::{ColumnDef, ColumnUniqueOption, OrderByExpr},
+        data::{
+            CustomFunction as StructCustomFunction, Key, Schema, SchemaIndex, SchemaIndexOrd,
+            Trigger as StructTrigger, Value,
+        },
+        result::{Error, Result},
+        store::{CustomFunction, GStore, GStoreMut, Store, Trigger},
+    },
+    serde::{Deserialize, Serialize},
+    std::io::{BufRead, Write},
+    thiserror::Error as ThisError,
+};
+
+/// Version written in the header of every backup.
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+const ROWS_PER_RECORD: usize = 1000;
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum BackupError {
+    #[error("failed to read or write backup: {0}")]
+    Io(String),
+
+    #[error("invalid backup record at line {line}: {message}")]
+    InvalidRecord { line: usize, message: String },
+
+    #[error("backup header is missing")]
+    HeaderNotFound,
+
+    #[error("backup header found after the first line")]
+    UnexpectedHeader,
+
+    #[error("unsupported backup format version: {0}")]
+    UnsupportedVersion(u32),
+
+    #[error("table already exists in restore target: {0}")]
+    TableAlreadyExists(String),
+
+    #[error("rows found for table without schema: {0}")]
+    TableNotFound(String),
+}
+
+#[derive(Serialize, Deserialize)]
+enum BackupRecord {
+    Header {
+        version: u32,
+    },
+    Schema(Schema),
+    Rows {
+        table_name: String,
+        rows: Vec<Vec<Value>>,
+    },
+    Function(StructCustomFunction),
+    Trigger(StructTrigger),
+}
+
+/// Writes every schema, row, custom function and trigger of `snapshot` to `writer`.
+///
+/// Pass a [`crate::store::Backup::snapshot`] so that the backup reflects a
+/// single point in time even when other connections keep writing.
+pub fn write_backup<S, W>(snapshot: &S, mut writer: W) -> Result<()>
+where
+    S: Store + CustomFunction + Trigger,
+    W: Write,
+{
+    let mut write = |record: &BackupRecord| -> Result<()> {
+        serde_json::to_writer(&mut writer, record).map_err(|e| BackupError::Io(e.to_string()))?;
+        writer
+            .write_all(b"\n")
+            .map_err(|e| BackupError::Io(e.to_string()).into())
+    };
+
+    write(&BackupRecord::Header {
+        version: BACKUP_FORMAT_VERSION,
+    })?;
+
+    for schema in snapshot.fetch_all_schemas()? {
+        let table_name = schema.table_name.clone();
+        write(&BackupRecord::Schema(schema))?;
+
+        let mut rows = Vec::with_capacity(ROWS_PER_RECORD);
+        for item in snapshot.scan_data(&table_name)? {
+            rows.push(item?.1);
+
+            if rows.len() == ROWS_PER_RECORD {
+                write(&BackupRecord::Rows {
+                    table_name: table_name.clone(),
+                    rows: std::mem::take(&mut rows),
+                })?;
+            }
+        }
+
+        if !rows.is_empty() {
+            write(&BackupRecord::Rows { table_name, rows })?;
+        }
+    }
+
+    for function in snapshot.fetch_all_functions()? {
+        write(&BackupRecord::Function(function.clone()))?;
+    }
+
+    for trigger in snapshot.fetch_all_triggers()? {
+        write(&BackupRecord::Trigger(trigger))?;
+    }
+
+    writer
+        .flush()
+        .map_err(|e| BackupError::Io(e.to_string()).into())
+}
+
+/// Restores a backup written by [`write_backup`] into `storage`.
+///
+/// Tables are created without their indexes, filled, and then indexed with
+/// `IndexMut::create_index`, so the target engine builds its own index data.
+/// Rows of tables with a primary key are keyed by that column; other rows are
+/// appended and get new keys from the target. The whole restore runs in one
+/// transaction on storages that support it.
+pub fn restore_backup<T, R>(storage: &mut T, reader: R) -> Result<()>
+where
+    T: GStore + GStoreMut,
+    R: BufRead,
+{
+    let autocommit = storage.begin(true)?;
+    let result = restore(storage, reader);
+
+    match (result, autocommit) {
+        (Ok(()), true) => storage.commit(),
+        (Ok(()), false) => Ok(()),
+        (Err(error), true) => {
+            storage.rollback()?;
+
+            Err(error)
+        }
+        (Err(error), false) => Err(error),
+    }
+}
+
+struct RestoredTable {
+    table_name: String,
+    primary_key: Option<usize>,
+}
+
+fn restore<T: GStore + GStoreMut, R: BufRead>(storage: &mut T, reader: R) -> Result<()> {
+    let mut lines = reader.lines().enumerate().map(|(i, line)| {
+        let line = line.map_err(|e| BackupError::Io(e.to_string()))?;
+        let record = serde_json::from_str::<BackupRecord>(&line).map_err(|e| {
+            BackupError::InvalidRecord {
+                line: i + 1,
+                message: e.to_string(),
+            }
+        })?;
+
+        Ok::<_, Error>(record)
+    });
+
+    match lines.next().transpose()? {
+        Some(BackupRecord::Header {
+            version: BACKUP_FORMAT_VERSION,
+        }) => {}
+        Some(BackupRecord::Header { version }) => {
+            return Err(BackupError::UnsupportedVersion(version).into());
+        }
+        _ => return Err(BackupError::HeaderNotFound.into()),
+    }
+
+    let mut tables: Vec<RestoredTable> = Vec::new();
+    let mut indexes: Vec<(String, SchemaIndex)> = Vec::new();
+
+    for record in lines {
+        match record? {
+            BackupRecord::Header { .. } => return Err(BackupError::UnexpectedHeader.into()),
+            BackupRecord::Schema(mut schema) => {
+                if storage.fetch_schema(&schema.table_name)?.is_some() {
+                    return Err(BackupError::TableAlreadyExists(schema.table_name).into());
+                }
+
+                let primary_key = schema.column_defs.as_ref().and_then(|column_defs| {
+                    column_defs.iter().position(|ColumnDef { unique, .. }| {
+                        unique == &Some(ColumnUniqueOption { is_primary: true })
+                    })
+                });
+
+                indexes.extend(
+                    std::mem::take(&mut schema.indexes)
+                        .into_iter()
+                        .map(|index| (schema.table_name.clone(), index)),
+                );
+                storage.insert_schema(&schema)?;
+                tables.push(RestoredTable {
+                    table_name: schema.table_name,
+                    primary_key,
+                });
+            }
+            BackupRecord::Rows { table_name, rows } => {
+                let table = tables
+                    .iter()
+                    .find(|table| table.table_name == table_name)
+                    .ok_or_else(|| BackupError::TableNotFound(table_name.clone()))?;
+
+                match table.primary_key {
+                    Some(i) => {
+                        let rows = rows
+                            .into_iter()
+                            .filter_map(|row| {
+                                row.get(i)
+                                    .map(Key::try_from)
+                                    .map(|result| result.map(|key| (key, row)))
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+
+                        storage.insert_data(&table_name, rows)?;
+                    }
+                    None => storage.append_data(&table_name, rows)?,
+                }
+            }
+            BackupRecord::Function(function) => storage.insert_function(function)?,
+            BackupRecord::Trigger(trigger) => storage.insert_trigger(trigger)?,
+        }
+    }
+
+    for (table_name, index) in indexes {
+        let asc = match index.order {
+            SchemaIndexOrd::Asc => Some(true),
+            SchemaIndexOrd::Desc => Some(false),
+            SchemaIndexOrd::Both => None,
+        };
+        let column = OrderByExpr {
+            expr: index.expr,
+            asc,
+        };
+
+        storage.create_index(&table_name, &index.name, &column)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{BackupError, restore_backup},
+        crate::{mock::MockStorage, prelude::Error},
+    };
+
+    #[test]
+    fn restore_rejects_invalid_input() {
+        let mut storage = MockStorage::default();
+
+        assert_eq!(
+            restore_backup(&mut storage, &b""[..]),
+            Err(BackupError::HeaderNotFound.into())
+        );
+        assert_eq!(
+            restore_backup(&mut storage, &b"{\"Header\":{\"version\":99}}\n"[..]),
+            Err(BackupError::UnsupportedVersion(99).into())
+        );
+        assert!(matches!(
+            restore_backup(
+                &mut storage,
+                &b"{\"Header\":{\"version\":1}}\nnot json\n"[..]
+            ),
+            Err(Error::Backup(BackupError::InvalidRecord { line: 2, .. }))
+        ));
+    }
+}
@@ -1,5 +1,6 @@
 mod bigdecimal_ext;
 mod function;
+mod geometry;
 mod interval;
 mod key;
 mod point;
@@ -7,6 +8,7 @@ mod row;
 mod string_ext;
 mod table;
 mod tribool;
+mod trigger;
 
 pub(crate) const SCHEMALESS_DOC_COLUMN: &str = "_doc";
 
@@ -16,13 +18,15 @@ pub mod value;
 pub use {
     bigdecimal_ext::BigDecimalExt,
     function::CustomFunction,
+    geometry::{BoundingBox, Geometry, LineString, MultiPoint, Polygon},
     interval::{Interval, IntervalError},
     key::{Key, KeyError},
     point::Point,
     row::Row,
-    schema::{Schema, SchemaIndex, SchemaIndexOrd, SchemaParseError},
-    string_ext::{StringExt, StringExtError},
+    schema::{RowExpiry, Schema, SchemaIndex, SchemaIndexOrd, SchemaParseError},
+    string_ext::{StringExt, StringExtError, compile_regex},
     table::{TableError, get_alias},
     tribool::Tribool,
+    trigger::Trigger,
     value::{BTreeMapJsonExt, NumericBinaryOperator, Value, ValueError},
 };
@@ -1,5 +1,5 @@
 use {
-    crate::ast::{Expr, OperateFunctionArg},
+    crate::ast::{Expr, OperateFunctionArg, ToSql},
     serde::{Deserialize, Serialize},
 };
 
@@ -21,4 +21,34 @@ impl CustomFunction {
             .join(", ");
         format!("{name}({args})")
     }
+
+    pub fn to_ddl(&self) -> String {
+        let CustomFunction {
+            func_name,
+            args,
+            body,
+        } = self;
+
+        let args = args
+            .iter()
+            .map(
+                |OperateFunctionArg {
+                     name,
+                     data_type,
+                     default,
+                 }| {
+                    let default = default
+                        .as_ref()
+                        .map(|default| format!(" DEFAULT {}", default.to_sql()))
+                        .unwrap_or_default();
+
+                    format!(r#""{name}" {data_type}{default}"#)
+                },
+            )
+            .collect::<Vec<String>>()
+            .join(", ");
+        let body = body.to_sql();
+
+        format!(r#"CREATE FUNCTION "{func_name}"({args}) RETURN {body};"#)
+    }
 }
@@ -0,0 +1,727 @@
+use {
+    super::{Point, ValueError},
+    crate::result::Result,
+    serde::{Deserialize, Serialize},
+    std::fmt,
+};
+
+const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LineString {
+    pub points: Vec<Point>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Polygon {
+    /// The exterior ring followed by the rings of its holes.
+    pub rings: Vec<Vec<Point>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MultiPoint {
+    pub points: Vec<Point>,
+}
+
+/// Any of the spatial values, used by the `ST_*` functions and spatial indexes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    Point(Point),
+    LineString(LineString),
+    Polygon(Polygon),
+    MultiPoint(MultiPoint),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl LineString {
+    pub fn new(points: Vec<Point>) -> Result<Self> {
+        if points.len() < 2 {
+            return Err(ValueError::FailedToParseLineString(format_points(&points)).into());
+        }
+
+        Ok(Self { points })
+    }
+
+    pub fn from_wkt(v: &str) -> Result<Self> {
+        let fail = || ValueError::FailedToParseLineString(v.to_owned());
+
+        strip_tag(v, "LINESTRING")
+            .and_then(parse_points)
+            .ok_or_else(fail)
+            .and_then(|points| Self::new(points).map_err(|_| fail()))
+            .map_err(Into::into)
+    }
+
+    pub fn length(&self) -> f64 {
+        segments(&self.points)
+            .map(|(a, b)| a.calc_distance(&b))
+            .sum()
+    }
+}
+
+impl Polygon {
+    pub fn new(rings: Vec<Vec<Point>>) -> Result<Self> {
+        let is_ring = |ring: &Vec<Point>| ring.len() >= 4 && ring.first() == ring.last();
+
+        if rings.is_empty() || !rings.iter().all(is_ring) {
+            let exterior = rings.first().map(|ring| format_points(ring));
+
+            return Err(ValueError::FailedToParsePolygon(exterior.unwrap_or_default()).into());
+        }
+
+        Ok(Self { rings })
+    }
+
+    pub fn exterior(&self) -> &[Point] {
+        &self.rings[0]
+    }
+
+    pub fn interiors(&self) -> &[Vec<Point>] {
+        &self.rings[1..]
+    }
+
+    pub fn from_wkt(v: &str) -> Result<Self> {
+        let fail = || ValueError::FailedToParsePolygon(v.to_owned());
+        let rings = strip_tag(v, "POLYGON")
+            .and_then(split_groups)
+            .ok_or_else(fail)?
+            .into_iter()
+            .map(parse_points)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(fail)?;
+
+        Self::new(rings).map_err(|_| fail().into())
+    }
+
+    pub fn area(&self) -> f64 {
+        let holes: f64 = self.interiors().iter().map(|ring| ring_area(ring)).sum();
+
+        ring_area(self.exterior()) - holes
+    }
+
+    fn rings(&self) -> impl Iterator<Item = &Vec<Point>> {
+        self.rings.iter()
+    }
+
+    fn locate(&self, point: &Point) -> Location {
+        if self
+            .rings()
+            .any(|ring| segments(ring).any(|(a, b)| on_segment(point, &a, &b)))
+        {
+            return Location::Boundary;
+        }
+
+        if in_ring(point, self.exterior()) && !self.interiors().iter().any(|r| in_ring(point, r)) {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+}
+
+impl MultiPoint {
+    pub fn from_wkt(v: &str) -> Result<Self> {
+        let fail = || ValueError::FailedToParseMultiPoint(v.to_owned());
+        let inner = strip_tag(v, "MULTIPOINT").ok_or_else(fail)?;
+        let points = match split_groups(inner) {
+            Some(groups) if !groups.is_empty() => groups
+                .into_iter()
+                .map(|group| parse_points(group).filter(|points| points.len() == 1))
+                .collect::<Option<Vec<_>>>()
+                .map(|points| points.into_iter().flatten().collect()),
+            _ => parse_points(inner),
+        };
+
+        points
+            .map(|points| Self { points })
+            .ok_or_else(|| fail().into())
+    }
+}
+
+impl Geometry {
+    /// Parses a well-known binary geometry in either byte order.
+    pub fn from_wkb(bytes: &[u8]) -> Option<Self> {
+        let mut reader = WkbReader { bytes };
+        let geometry = reader.read_geometry()?;
+
+        reader.bytes.is_empty().then_some(geometry)
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox {
+        self.vertices().fold(
+            BoundingBox {
+                min_x: f64::INFINITY,
+                min_y: f64::INFINITY,
+                max_x: f64::NEG_INFINITY,
+                max_y: f64::NEG_INFINITY,
+            },
+            |bbox, point| BoundingBox {
+                min_x: bbox.min_x.min(point.x),
+                min_y: bbox.min_y.min(point.y),
+                max_x: bbox.max_x.max(point.x),
+                max_y: bbox.max_y.max(point.y),
+            },
+        )
+    }
+
+    pub fn area(&self) -> f64 {
+        match self {
+            Self::Polygon(polygon) => polygon.area(),
+            _ => 0.0,
+        }
+    }
+
+    pub fn length(&self) -> f64 {
+        match self {
+            Self::LineString(line) => line.length(),
+            _ => 0.0,
+        }
+    }
+
+    /// Returns true when the two geometries share at least one point.
+    pub fn intersects(&self, other: &Self) -> bool {
+        let crossing = self.edges().any(|(a, b)| {
+            other
+                .edges()
+                .any(|(c, d)| segments_intersect(&a, &b, &c, &d))
+        });
+        let covered_by = |polygon: &Self, geometry: &Self| match polygon {
+            Self::Polygon(polygon) => geometry
+                .vertices()
+                .any(|point| polygon.locate(point) != Location::Outside),
+            _ => false,
+        };
+
+        crossing || covered_by(self, other) || covered_by(other, self)
+    }
+
+    /// Returns true when no point of `other` lies outside `self` and the interiors
+    /// of both geometries share at least one point.
+    pub fn contains(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Point(_) | Self::MultiPoint(_), Self::Point(_) | Self::MultiPoint(_)) => other
+                .vertices()
+                .all(|point| self.vertices().any(|vertex| vertex == point)),
+            (Self::LineString(line), Self::Point(_) | Self::MultiPoint(_)) => {
+                let closed = line.points.first() == line.points.last();
+                let is_endpoint = |point: &Point| {
+                    !closed
+                        && (line.points.first() == Some(point) || line.points.last() == Some(point))
+                };
+                let on_line =
+                    |point: &Point| segments(&line.points).any(|(a, b)| on_segment(point, &a, &b));
+
+                other.vertices().all(on_line) && other.vertices().any(|point| !is_endpoint(point))
+            }
+            (Self::LineString(line), Self::LineString(_)) => {
+                let on_line =
+                    |point: &Point| segments(&line.points).any(|(a, b)| on_segment(point, &a, &b));
+
+                other
+                    .edges()
+                    .all(|(a, b)| split_points(&a, &b, &line.points, self).iter().all(on_line))
+            }
+            (Self::Polygon(polygon), Self::Point(_) | Self::MultiPoint(_)) => {
+                other
+                    .vertices()
+                    .all(|point| polygon.locate(point) != Location::Outside)
+                    && other
+                        .vertices()
+                        .any(|point| polygon.locate(point) == Location::Inside)
+            }
+            (Self::Polygon(polygon), Self::LineString(_) | Self::Polygon(_)) => {
+                let vertices = polygon.rings().flatten().copied().collect::<Vec<_>>();
+                let locations = other
+                    .edges()
+                    .flat_map(|(a, b)| split_points(&a, &b, &vertices, self))
+                    .map(|point| polygon.locate(&point))
+                    .collect::<Vec<_>>();
+
+                !locations.contains(&Location::Outside)
+                    && (matches!(other, Self::Polygon(_)) || locations.contains(&Location::Inside))
+            }
+            _ => false,
+        }
+    }
+
+    pub fn within(&self, other: &Self) -> bool {
+        other.contains(self)
+    }
+
+    fn vertices(&self) -> Box<dyn Iterator<Item = &Point> + '_> {
+        match self {
+            Self::Point(point) => Box::new(std::iter::once(point)),
+            Self::LineString(LineString { points }) | Self::MultiPoint(MultiPoint { points }) => {
+                Box::new(points.iter())
+            }
+            Self::Polygon(polygon) => Box::new(polygon.rings().flatten()),
+        }
+    }
+
+    /// Segments making up the geometry; points become zero-length segments.
+    fn edges(&self) -> Box<dyn Iterator<Item = (Point, Point)> + '_> {
+        match self {
+            Self::Point(point) => Box::new(std::iter::once((*point, *point))),
+            Self::MultiPoint(MultiPoint { points }) => {
+                Box::new(points.iter().map(|point| (*point, *point)))
+            }
+            Self::LineString(LineString { points }) => Box::new(segments(points)),
+            Self::Polygon(polygon) => Box::new(polygon.rings().flat_map(|ring| segments(ring))),
+        }
+    }
+}
+
+impl BoundingBox {
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+
+    /// Encodes the box so that byte order follows `min_x`, which lets sorted
+    /// storages seek past every box that starts to the right of a search area.
+    pub fn to_cmp_be_bytes(&self) -> Vec<u8> {
+        [self.min_x, self.max_x, self.min_y, self.max_y]
+            .into_iter()
+            .flat_map(|v| cmp_f64_bits(v).to_be_bytes())
+            .collect()
+    }
+
+    pub fn from_cmp_be_bytes(bytes: &[u8]) -> Option<Self> {
+        let bytes: &[u8; 32] = bytes.try_into().ok()?;
+        let value = |i: usize| {
+            let bits = u64::from_be_bytes(bytes[i * 8..(i + 1) * 8].try_into().ok()?);
+
+            Some(f64::from_bits(if bits >> 63 == 1 {
+                bits ^ (1 << 63)
+            } else {
+                !bits
+            }))
+        };
+
+        Some(Self {
+            min_x: value(0)?,
+            max_x: value(1)?,
+            min_y: value(2)?,
+            max_y: value(3)?,
+        })
+    }
+}
+
+impl Point {
+    /// Great-circle distance in meters, reading `x` as longitude and `y` as
+    /// latitude in degrees.
+    pub fn calc_haversine_distance(&self, other: &Point) -> f64 {
+        let (lat1, lat2) = (self.y.to_radians(), other.y.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.x - self.x).to_radians();
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+    }
+}
+
+impl fmt::Display for LineString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LINESTRING({})", format_points(&self.points))
+    }
+}
+
+impl fmt::Display for Polygon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rings = self
+            .rings()
+            .map(|ring| format!("({})", format_points(ring)))
+            .collect::<Vec<_>>();
+
+        write!(f, "POLYGON({})", rings.join(", "))
+    }
+}
+
+impl fmt::Display for MultiPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MULTIPOINT({})", format_points(&self.points))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl WkbReader<'_> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let (head, tail) = self.bytes.split_at_checked(N)?;
+        self.bytes = tail;
+
+        head.try_into().ok()
+    }
+
+    fn read_u32(&mut self, little_endian: bool) -> Option<u32> {
+        let bytes = self.take::<4>()?;
+
+        Some(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_point(&mut self, little_endian: bool) -> Option<Point> {
+        let mut read_f64 = || {
+            let bytes = self.take::<8>()?;
+
+            Some(if little_endian {
+                f64::from_le_bytes(bytes)
+            } else {
+                f64::from_be_bytes(bytes)
+            })
+        };
+
+        Some(Point::new(read_f64()?, read_f64()?))
+    }
+
+    fn read_points(&mut self, little_endian: bool) -> Option<Vec<Point>> {
+        let len = self.read_u32(little_endian)?;
+
+        (0..len).map(|_| self.read_point(little_endian)).collect()
+    }
+
+    fn read_header(&mut self) -> Option<(bool, u32)> {
+        let little_endian = match self.take::<1>()? {
+            [0] => false,
+            [1] => true,
+            _ => return None,
+        };
+
+        Some((little_endian, self.read_u32(little_endian)?))
+    }
+
+    fn read_geometry(&mut self) -> Option<Geometry> {
+        let (little_endian, kind) = self.read_header()?;
+
+        match kind {
+            1 => self.read_point(little_endian).map(Geometry::Point),
+            2 => {
+                let points = self.read_points(little_endian)?;
+
+                LineString::new(points).ok().map(Geometry::LineString)
+            }
+            3 => {
+                let len = self.read_u32(little_endian)?;
+                let rings = (0..len)
+                    .map(|_| self.read_points(little_endian))
+                    .collect::<Option<Vec<_>>>()?;
+
+                Polygon::new(rings).ok().map(Geometry::Polygon)
+            }
+            4 => {
+                let len = self.read_u32(little_endian)?;
+                let points = (0..len)
+                    .map(|_| match self.read_header()? {
+                        (little_endian, 1) => self.read_point(little_endian),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+
+                Some(Geometry::MultiPoint(MultiPoint { points }))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn strip_tag<'a>(v: &'a str, tag: &str) -> Option<&'a str> {
+    let v = v.trim();
+    let (head, rest) = v.split_at_checked(tag.len())?;
+
+    if !head.eq_ignore_ascii_case(tag) {
+        return None;
+    }
+
+    rest.trim()
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+}
+
+/// Splits `(a), (b)` into `a` and `b`.
+fn split_groups(v: &str) -> Option<Vec<&str>> {
+    let mut groups = Vec::new();
+    let mut rest = v.trim();
+
+    while !rest.is_empty() {
+        let inner = rest.strip_prefix('(')?;
+        let end = inner.find(')')?;
+        groups.push(&inner[..end]);
+
+        rest = inner[end + 1..].trim_start();
+        rest = match rest.strip_prefix(',') {
+            Some(next) => next.trim_start(),
+            None if rest.is_empty() => rest,
+            None => return None,
+        };
+    }
+
+    Some(groups)
+}
+
+fn parse_points(v: &str) -> Option<Vec<Point>> {
+    v.split(',')
+        .map(|pair| {
+            let mut coordinates = pair.split_whitespace().map(str::parse::<f64>);
+
+            match (coordinates.next(), coordinates.next(), coordinates.next()) {
+                (Some(Ok(x)), Some(Ok(y)), None) => Some(Point::new(x, y)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn format_points(points: &[Point]) -> String {
+    points
+        .iter()
+        .map(|point| format!("{} {}", point.x, point.y))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn segments(points: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    points.windows(2).map(|pair| (pair[0], pair[1]))
+}
+
+fn ring_area(ring: &[Point]) -> f64 {
+    let twice_area: f64 = segments(ring).map(|(a, b)| a.x * b.y - b.x * a.y).sum();
+
+    twice_area.abs() / 2.0
+}
+
+fn cross(o: &Point, a: &Point, b: &Point) -> f64 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+fn within_extent(p: &Point, a: &Point, b: &Point) -> bool {
+    p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x) && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+}
+
+fn on_segment(p: &Point, a: &Point, b: &Point) -> bool {
+    cross(a, b, p) == 0.0 && within_extent(p, a, b)
+}
+
+fn segments_intersect(a: &Point, b: &Point, c: &Point, d: &Point) -> bool {
+    let d1 = cross(c, d, a);
+    let d2 = cross(c, d, b);
+    let d3 = cross(a, b, c);
+    let d4 = cross(a, b, d);
+
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+
+    on_segment(a, c, d) || on_segment(b, c, d) || on_segment(c, a, b) || on_segment(d, a, b)
+}
+
+fn in_ring(p: &Point, ring: &[Point]) -> bool {
+    segments(ring)
+        .filter(|(a, b)| {
+            (a.y > p.y) != (b.y > p.y) && p.x < (b.x - a.x) * (p.y - a.y) / (b.y - a.y) + a.x
+        })
+        .count()
+        % 2
+        == 1
+}
+
+/// Cuts the segment `a`-`b` wherever it meets `vertices` or the edges of
+/// `geometry`, and returns the midpoint of every piece along with both ends.
+fn split_points(a: &Point, b: &Point, vertices: &[Point], geometry: &Geometry) -> Vec<Point> {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let length = dx * dx + dy * dy;
+    let param = |p: &Point| {
+        if length == 0.0 {
+            0.0
+        } else {
+            ((p.x - a.x) * dx + (p.y - a.y) * dy) / length
+        }
+    };
+    let crossing = |c: &Point, d: &Point| {
+        let denominator = dx * (d.y - c.y) - dy * (d.x - c.x);
+
+        (denominator != 0.0)
+            .then(|| ((c.x - a.x) * (d.y - c.y) - (c.y - a.y) * (d.x - c.x)) / denominator)
+    };
+
+    let mut params = vec![0.0, 1.0];
+    params.extend(
+        vertices
+            .iter()
+            .filter(|vertex| on_segment(vertex, a, b))
+            .map(param),
+    );
+    params.extend(
+        geometry
+            .edges()
+            .filter_map(|(c, d)| crossing(&c, &d))
+            .filter(|t| (0.0..=1.0).contains(t)),
+    );
+    params.sort_by(f64::total_cmp);
+    params.dedup();
+
+    let at = |t: f64| Point::new(a.x + dx * t, a.y + dy * t);
+    let mut points = params.iter().copied().map(at).collect::<Vec<_>>();
+    points.extend(
+        params
+            .windows(2)
+            .map(|pair| at(f64::midpoint(pair[0], pair[1]))),
+    );
+    points
+}
+
+fn cmp_f64_bits(v: f64) -> u64 {
+    let bits = v.to_bits();
+
+    if bits >> 63 == 1 {
+        !bits
+    } else {
+        bits ^ (1 << 63)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{BoundingBox, Geometry, LineString, MultiPoint, Polygon},
+        crate::data::Point,
+    };
+
+    fn polygon(wkt: &str) -> Geometry {
+        Geometry::Polygon(Polygon::from_wkt(wkt).unwrap())
+    }
+
+    fn line(wkt: &str) -> Geometry {
+        Geometry::LineString(LineString::from_wkt(wkt).unwrap())
+    }
+
+    fn point(x: f64, y: f64) -> Geometry {
+        Geometry::Point(Point::new(x, y))
+    }
+
+    #[test]
+    fn from_wkt() {
+        assert_eq!(
+            LineString::from_wkt("LINESTRING(0 0, 1 1.5)")
+                .unwrap()
+                .to_string(),
+            "LINESTRING(0 0, 1 1.5)"
+        );
+        assert!(LineString::from_wkt("LINESTRING(0 0)").is_err());
+        assert_eq!(
+            Polygon::from_wkt("polygon ((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1))")
+                .unwrap()
+                .to_string(),
+            "POLYGON((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1))"
+        );
+        assert!(Polygon::from_wkt("POLYGON((0 0, 4 0, 4 4))").is_err());
+        assert_eq!(
+            MultiPoint::from_wkt("MULTIPOINT((0 0), (1 2))").unwrap(),
+            MultiPoint::from_wkt("MULTIPOINT(0 0, 1 2)").unwrap()
+        );
+        assert!(MultiPoint::from_wkt("MULTIPOINT(0 0, 1)").is_err());
+    }
+
+    #[test]
+    fn from_wkb() {
+        let le_point = [
+            1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64,
+        ];
+        assert_eq!(Geometry::from_wkb(&le_point), Some(point(1.0, 2.0)));
+
+        let be_line = [
+            0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 240, 0,
+            0, 0, 0, 0, 0, 63, 240, 0, 0, 0, 0, 0, 0,
+        ];
+        assert_eq!(
+            Geometry::from_wkb(&be_line),
+            Some(line("LINESTRING(0 0, 1 1)"))
+        );
+        assert_eq!(Geometry::from_wkb(&le_point[..20]), None);
+    }
+
+    #[test]
+    fn measures() {
+        let square = polygon("POLYGON((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 2, 1 1))");
+        assert!((square.area() - 15.0).abs() < f64::EPSILON);
+        assert!((line("LINESTRING(0 0, 3 4, 3 5)").length() - 6.0).abs() < f64::EPSILON);
+
+        let seoul = Point::new(126.978, 37.5665);
+        let busan = Point::new(129.0756, 35.1796);
+        let distance = seoul.calc_haversine_distance(&busan);
+        assert!((distance - 325_000.0).abs() < 1_000.0, "{distance}");
+    }
+
+    #[test]
+    fn predicates() {
+        let square = polygon("POLYGON((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 2, 1 1))");
+        let concave = polygon("POLYGON((0 0, 4 0, 4 4, 2 1, 0 4, 0 0))");
+
+        assert!(square.contains(&point(3.0, 3.0)));
+        assert!(!square.contains(&point(1.5, 1.5)));
+        assert!(!square.contains(&point(4.0, 2.0)));
+        assert!(square.intersects(&point(4.0, 2.0)));
+        assert!(point(3.0, 3.0).within(&square));
+
+        assert!(square.contains(&line("LINESTRING(3 0.5, 3 3.5)")));
+        assert!(!square.contains(&line("LINESTRING(0.5 0.5, 3 3)")));
+        assert!(!concave.contains(&line("LINESTRING(0.5 3, 3 3)")));
+        assert!(concave.intersects(&line("LINESTRING(0.5 3, 3 3)")));
+        assert!(!concave.intersects(&line("LINESTRING(1.9 3, 2.1 3)")));
+
+        assert!(square.contains(&polygon("POLYGON((3 3, 4 3, 4 4, 3 3))")));
+        assert!(!square.contains(&polygon("POLYGON((3 3, 5 3, 5 5, 3 3))")));
+        assert!(square.intersects(&polygon("POLYGON((3 3, 5 3, 5 5, 3 3))")));
+        assert!(!square.intersects(&polygon("POLYGON((5 5, 6 5, 6 6, 5 5))")));
+
+        let path = line("LINESTRING(0 0, 2 0, 2 2)");
+        assert!(path.contains(&line("LINESTRING(1 0, 2 0, 2 1)")));
+        assert!(!path.contains(&line("LINESTRING(1 0, 2 1)")));
+        assert!(path.contains(&point(2.0, 1.0)));
+        assert!(!path.contains(&point(0.0, 0.0)));
+    }
+
+    #[test]
+    fn bounding_box_bytes() {
+        let bbox = polygon("POLYGON((-1.5 -2, 3 -2, 3 4, -1.5 -2))").bounding_box();
+        assert_eq!(
+            bbox,
+            BoundingBox {
+                min_x: -1.5,
+                min_y: -2.0,
+                max_x: 3.0,
+                max_y: 4.0
+            }
+        );
+        assert_eq!(
+            BoundingBox::from_cmp_be_bytes(&bbox.to_cmp_be_bytes()),
+            Some(bbox)
+        );
+
+        let left = point(-3.0, 0.0).bounding_box().to_cmp_be_bytes();
+        let right = point(2.0, 0.0).bounding_box().to_cmp_be_bytes();
+        assert!(left < right);
+    }
+}
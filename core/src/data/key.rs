@@ -1,5 +1,6 @@
 use {
     crate::{
+        ast::DataType,
         data::{Interval, Value},
         result::{Error, Result},
     },
@@ -24,6 +25,9 @@ pub enum KeyError {
 
     #[error("POINT data type cannot be used as Key")]
     PointTypeKeyNotSupported,
+
+    #[error("{0} data type cannot be used as Key")]
+    GeometryTypeKeyNotSupported(DataType),
 }
 
 #[derive(PartialEq, Eq, Hash, Clone, Debug, Serialize, Deserialize)]
@@ -134,6 +138,11 @@ impl TryFrom<Value> for Key {
             Map(_) => Err(KeyError::MapTypeKeyNotSupported.into()),
             List(_) => Err(KeyError::ListTypeKeyNotSupported.into()),
             Point(_) => Err(KeyError::PointTypeKeyNotSupported.into()),
+            LineString(_) | Polygon(_) | MultiPoint(_) => {
+                let data_type = value.get_type().unwrap_or(DataType::Point);
+
+                Err(KeyError::GeometryTypeKeyNotSupported(data_type).into())
+            }
         }
     }
 }
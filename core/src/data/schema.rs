@@ -1,6 +1,7 @@
 use {
     crate::{
         ast::{ColumnDef, Expr, ForeignKey, OrderByExpr, Statement, ToSql},
+        data::Value,
         prelude::{parse, translate},
         result::Result,
     },
@@ -35,9 +36,40 @@ pub struct Schema {
     pub engine: Option<String>,
     pub foreign_keys: Vec<ForeignKey>,
     pub comment: Option<String>,
+    /// `TIMESTAMP` column after which a row is treated as expired.
+    #[serde(default)]
+    pub ttl_column: Option<String>,
+}
+
+/// Position of a table's `ttl_column` paired with the time rows are checked against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RowExpiry {
+    pub index: usize,
+    pub now: NaiveDateTime,
+}
+
+impl RowExpiry {
+    /// A row expires once its `ttl_column` is at or before `now`; `NULL` never expires.
+    pub fn is_expired(&self, values: &[Value]) -> bool {
+        matches!(values.get(self.index), Some(Value::Timestamp(expires_at)) if *expires_at <= self.now)
+    }
 }
 
 impl Schema {
+    /// Position of `ttl_column` among the column definitions.
+    pub fn ttl_index(&self) -> Option<usize> {
+        let ttl_column = self.ttl_column.as_ref()?;
+
+        self.column_defs
+            .as_ref()?
+            .iter()
+            .position(|column_def| &column_def.name == ttl_column)
+    }
+
+    pub fn row_expiry(&self, now: NaiveDateTime) -> Option<RowExpiry> {
+        self.ttl_index().map(|index| RowExpiry { index, now })
+    }
+
     pub fn to_ddl(&self) -> String {
         let Schema {
             table_name,
@@ -46,6 +78,7 @@ impl Schema {
             engine,
             foreign_keys,
             comment,
+            ttl_column,
         } = self;
 
         let columns = column_defs.as_ref().map(|column_defs| {
@@ -59,6 +92,9 @@ impl Schema {
 
             format!("({body})")
         });
+        let ttl_column = ttl_column
+            .as_ref()
+            .map(|ttl_column| format!(r#"WITH (ttl_column = "{ttl_column}")"#));
         let engine = engine.as_ref().map(|engine| format!(" ENGINE = {engine}"));
         let comment = comment
             .as_ref()
@@ -67,6 +103,7 @@ impl Schema {
         let create_table = vec![
             Some(format!(r#"CREATE TABLE "{table_name}""#)),
             columns,
+            ttl_column,
             engine,
             comment,
         ]
@@ -131,6 +168,7 @@ impl Schema {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
                 ..
             } => Ok(Schema {
                 table_name: name,
@@ -139,6 +177,7 @@ impl Schema {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
             }),
             _ => Err(SchemaParseError::CannotParseDDL.into()),
         }
@@ -157,8 +196,8 @@ mod tests {
         super::SchemaParseError,
         crate::{
             ast::{ColumnDef, ColumnUniqueOption, Expr, Literal},
-            chrono::Utc,
-            data::{Schema, SchemaIndex, SchemaIndexOrd},
+            chrono::{NaiveDate, TimeDelta, Utc},
+            data::{Schema, SchemaIndex, SchemaIndexOrd, Value},
             prelude::DataType,
         },
     };
@@ -171,6 +210,7 @@ mod tests {
             engine,
             foreign_keys,
             comment,
+            ttl_column,
         } = actual;
 
         let Schema {
@@ -180,6 +220,7 @@ mod tests {
             engine: engine_e,
             foreign_keys: foreign_keys_e,
             comment: comment_e,
+            ttl_column: ttl_column_e,
         } = expected;
 
         assert_eq!(table_name, table_name_e);
@@ -187,6 +228,7 @@ mod tests {
         assert_eq!(engine, engine_e);
         assert_eq!(foreign_keys, foreign_keys_e);
         assert_eq!(comment, comment_e);
+        assert_eq!(ttl_column, ttl_column_e);
         indexes
             .into_iter()
             .zip(indexes_e)
@@ -235,6 +277,7 @@ mod tests {
             engine: None,
             foreign_keys: Vec::new(),
             comment: None,
+            ttl_column: None,
         };
 
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NULL DEFAULT 'glue');"#;
@@ -250,6 +293,7 @@ mod tests {
             engine: None,
             foreign_keys: Vec::new(),
             comment: None,
+            ttl_column: None,
         };
         let ddl = r#"CREATE TABLE "Test";"#;
         assert_eq!(schema.to_ddl(), ddl);
@@ -274,6 +318,7 @@ mod tests {
             engine: None,
             foreign_keys: Vec::new(),
             comment: None,
+            ttl_column: None,
         };
 
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL PRIMARY KEY);"#;
@@ -283,6 +328,52 @@ mod tests {
         assert_schema(actual, schema);
     }
 
+    #[test]
+    fn table_with_ttl_column() {
+        let schema = Schema {
+            table_name: "Session".to_owned(),
+            column_defs: Some(vec![
+                ColumnDef {
+                    name: "id".to_owned(),
+                    data_type: DataType::Int,
+                    nullable: false,
+                    default: None,
+                    unique: None,
+                    comment: None,
+                },
+                ColumnDef {
+                    name: "expires_at".to_owned(),
+                    data_type: DataType::Timestamp,
+                    nullable: true,
+                    default: None,
+                    unique: None,
+                    comment: None,
+                },
+            ]),
+            indexes: Vec::new(),
+            engine: None,
+            foreign_keys: Vec::new(),
+            comment: None,
+            ttl_column: Some("expires_at".to_owned()),
+        };
+
+        let ddl = r#"CREATE TABLE "Session" ("id" INT NOT NULL, "expires_at" TIMESTAMP NULL) WITH (ttl_column = "expires_at");"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema.clone());
+
+        let now = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .unwrap();
+        let expiry = schema.row_expiry(now).unwrap();
+        let row = |expires_at| vec![Value::I32(1), expires_at];
+
+        assert!(expiry.is_expired(&row(Value::Timestamp(now))));
+        assert!(!expiry.is_expired(&row(Value::Timestamp(now + TimeDelta::seconds(1)))));
+        assert!(!expiry.is_expired(&row(Value::Null)));
+    }
+
     #[test]
     fn invalid_ddl() {
         // Only Statement::CreateTable is supported
@@ -330,6 +421,7 @@ mod tests {
             engine: None,
             foreign_keys: Vec::new(),
             comment: None,
+            ttl_column: None,
         };
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);
 CREATE INDEX "User_id" ON "User" ("id");
@@ -376,6 +468,7 @@ CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);"#;
             engine: None,
             foreign_keys: Vec::new(),
             comment: None,
+            ttl_column: None,
         };
         let ddl = r#"CREATE TABLE "1" ("2" INT NULL, ";" INT NULL);
 CREATE INDEX "." ON "1" (";");"#;
@@ -1,13 +1,35 @@
-use {crate::result::Result, regex::Regex, serde::Serialize, thiserror::Error};
+use {
+    crate::result::Result,
+    regex::{Regex, RegexBuilder},
+    serde::Serialize,
+    std::{cell::RefCell, collections::HashMap},
+    thiserror::Error,
+};
+
+const REGEX_CACHE_CAPACITY: usize = 64;
+
+thread_local! {
+    /// Compiled regexes by flags, then by pattern, so a lookup needs no allocation.
+    static REGEX_CACHE: RefCell<HashMap<String, HashMap<String, Regex>>> =
+        RefCell::new(HashMap::new());
+}
 
 #[derive(Error, Serialize, Debug, PartialEq, Eq)]
 pub enum StringExtError {
     #[error("unreachable literal unary operation")]
     UnreachablePatternParsing,
+
+    #[error("invalid regular expression: {0}")]
+    InvalidRegexPattern(String),
+
+    #[error("invalid regular expression flag: {0}")]
+    InvalidRegexFlag(char),
 }
 
 pub trait StringExt {
     fn like(&self, pattern: &str, case_sensitive: bool) -> Result<bool>;
+
+    fn regexp_like(&self, pattern: &str, flags: &str) -> Result<bool>;
 }
 
 impl StringExt for str {
@@ -30,4 +52,91 @@ impl StringExt for str {
         .map_err(|_| StringExtError::UnreachablePatternParsing)?
         .is_match(match_string.as_str()))
     }
+
+    fn regexp_like(&self, pattern: &str, flags: &str) -> Result<bool> {
+        compile_regex(pattern, flags).map(|regex| regex.is_match(self))
+    }
+}
+
+/// Compiles `pattern` with Postgres-style `flags`, reusing a previously
+/// compiled [`Regex`] when the same pattern and flags were seen before.
+///
+/// Supported flags are `i` (case-insensitive), `c` (case-sensitive),
+/// `m`/`n` (multi-line), `s` (`.` matches newline) and `x` (ignore
+/// whitespace). `g` is accepted and left to the caller to interpret.
+pub fn compile_regex(pattern: &str, flags: &str) -> Result<Regex> {
+    let cached = REGEX_CACHE.with(|cache| {
+        cache
+            .borrow()
+            .get(flags)
+            .and_then(|regexes| regexes.get(pattern))
+            .cloned()
+    });
+    if let Some(regex) = cached {
+        return Ok(regex);
+    }
+
+    let mut builder = RegexBuilder::new(pattern);
+    for flag in flags.chars() {
+        match flag {
+            'i' => builder.case_insensitive(true),
+            'c' => builder.case_insensitive(false),
+            'm' | 'n' => builder.multi_line(true),
+            's' => builder.dot_matches_new_line(true),
+            'x' => builder.ignore_whitespace(true),
+            'g' => &mut builder,
+            _ => return Err(StringExtError::InvalidRegexFlag(flag).into()),
+        };
+    }
+
+    let regex = builder
+        .build()
+        .map_err(|_| StringExtError::InvalidRegexPattern(pattern.to_owned()))?;
+
+    REGEX_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.values().map(HashMap::len).sum::<usize>() >= REGEX_CACHE_CAPACITY {
+            cache.clear();
+        }
+
+        cache
+            .entry(flags.to_owned())
+            .or_default()
+            .insert(pattern.to_owned(), regex.clone());
+    });
+
+    Ok(regex)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{StringExt, StringExtError, compile_regex},
+        crate::result::Error,
+    };
+
+    #[test]
+    fn regexp_like() {
+        assert_eq!("GlueSQL".regexp_like("^Glue", ""), Ok(true));
+        assert_eq!("GlueSQL".regexp_like("^glue", ""), Ok(false));
+        assert_eq!("GlueSQL".regexp_like("^glue", "i"), Ok(true));
+        assert_eq!("GlueSQL".regexp_like("^glue", "ic"), Ok(false));
+        assert_eq!("a\nb".regexp_like("^b$", ""), Ok(false));
+        assert_eq!("a\nb".regexp_like("^b$", "m"), Ok(true));
+        assert_eq!("a\nb".regexp_like("a.b", "s"), Ok(true));
+    }
+
+    #[test]
+    fn compile_regex_errors() {
+        assert_eq!(
+            compile_regex("(", "").map(|regex| regex.to_string()),
+            Err(Error::StringExt(StringExtError::InvalidRegexPattern(
+                "(".to_owned()
+            )))
+        );
+        assert_eq!(
+            compile_regex("a", "q").map(|regex| regex.to_string()),
+            Err(Error::StringExt(StringExtError::InvalidRegexFlag('q')))
+        );
+    }
 }
@@ -28,6 +28,10 @@ pub fn get_alias(table_factor: &TableFactor) -> &String {
             alias: TableAlias { name, .. },
             ..
         }
+        | TableFactor::GenerateSeries {
+            alias: TableAlias { name, .. },
+            ..
+        }
         | TableFactor::Dictionary {
             alias: TableAlias { name, .. },
             ..
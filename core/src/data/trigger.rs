@@ -0,0 +1,55 @@
+use {
+    crate::ast::{TriggerEvent, TriggerTiming},
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Trigger {
+    pub name: String,
+    pub table_name: String,
+    pub timing: TriggerTiming,
+    pub events: Vec<TriggerEvent>,
+    /// SQL text of each statement run for every affected row
+    pub body: Vec<String>,
+}
+
+impl Trigger {
+    pub fn to_str(&self) -> String {
+        let events = self
+            .events
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>()
+            .join(" OR ");
+
+        format!(
+            "{} {} {events} ON {}",
+            self.name, self.timing, self.table_name
+        )
+    }
+
+    pub fn to_ddl(&self) -> String {
+        let Trigger {
+            name,
+            table_name,
+            timing,
+            events,
+            body,
+        } = self;
+
+        let events = events
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>()
+            .join(" OR ");
+        let body = body
+            .iter()
+            .map(|statement| format!("    {statement};"))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            "CREATE TRIGGER \"{name}\" {timing} {events} ON \"{table_name}\" FOR EACH ROW\nBEGIN\n{body}\nEND;"
+        )
+    }
+}
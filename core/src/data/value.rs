@@ -2,7 +2,7 @@ use {
     super::{Interval, Key, StringExt},
     crate::{
         ast::{DataType, DateTimeField},
-        data::{Tribool, point::Point},
+        data::{BoundingBox, Geometry, LineString, MultiPoint, Polygon, Tribool, point::Point},
         result::Result,
     },
     binary_op::TryBinaryOperator,
@@ -66,6 +66,9 @@ pub enum Value {
     Map(BTreeMap<String, Value>),
     List(Vec<Value>),
     Point(Point),
+    LineString(LineString),
+    Polygon(Polygon),
+    MultiPoint(MultiPoint),
     Null,
 }
 
@@ -188,10 +191,27 @@ impl Value {
             Value::Map(_) => Some(DataType::Map),
             Value::List(_) => Some(DataType::List),
             Value::Point(_) => Some(DataType::Point),
+            Value::LineString(_) => Some(DataType::LineString),
+            Value::Polygon(_) => Some(DataType::Polygon),
+            Value::MultiPoint(_) => Some(DataType::MultiPoint),
             Value::Null => None,
         }
     }
 
+    pub fn geometry(&self) -> Option<Geometry> {
+        match self {
+            Value::Point(v) => Some(Geometry::Point(*v)),
+            Value::LineString(v) => Some(Geometry::LineString(v.clone())),
+            Value::Polygon(v) => Some(Geometry::Polygon(v.clone())),
+            Value::MultiPoint(v) => Some(Geometry::MultiPoint(v.clone())),
+            _ => None,
+        }
+    }
+
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        self.geometry().as_ref().map(Geometry::bounding_box)
+    }
+
     pub fn validate_type(&self, data_type: &DataType) -> Result<()> {
         let valid = self.get_type().is_none_or(|t| t == *data_type);
 
@@ -234,6 +254,9 @@ impl Value {
             | (DataType::Bytea, Value::Bytea(_))
             | (DataType::Inet, Value::Inet(_))
             | (DataType::Point, Value::Point(_))
+            | (DataType::LineString, Value::LineString(_))
+            | (DataType::Polygon, Value::Polygon(_))
+            | (DataType::MultiPoint, Value::MultiPoint(_))
             | (DataType::Date, Value::Date(_))
             | (DataType::Timestamp, Value::Timestamp(_))
             | (DataType::Time, Value::Time(_))
@@ -269,6 +292,9 @@ impl Value {
             (DataType::Uuid, value) => Ok(value.try_into().map(Value::Uuid)?),
             (DataType::Inet, value) => Ok(value.try_into().map(Value::Inet)?),
             (DataType::Point, value) => Ok(value.try_into().map(Value::Point)?),
+            (DataType::LineString, value) => Ok(value.try_into().map(Value::LineString)?),
+            (DataType::Polygon, value) => Ok(value.try_into().map(Value::Polygon)?),
+            (DataType::MultiPoint, value) => Ok(value.try_into().map(Value::MultiPoint)?),
 
             (DataType::Bytea, Value::Str(value)) => hex::decode(value)
                 .map_err(|_| ValueError::CastFromHexToByteaFailed(value.clone()).into())
@@ -758,6 +784,28 @@ impl Value {
         self.try_into().and_then(|key: Key| key.to_cmp_be_bytes())
     }
 
+    /// Index key for geometry values, sorting before `NULL` like
+    /// [`Value::to_cmp_be_bytes`] does.
+    /// The bounding box leads so that spatial scans can seek by area, and the
+    /// WKT text follows so that distinct geometries sharing a box stay apart.
+    pub fn to_spatial_cmp_be_bytes(&self) -> Option<Vec<u8>> {
+        let bbox = self.bounding_box()?;
+        let wkt = match self {
+            Value::Point(v) => v.to_string(),
+            Value::LineString(v) => v.to_string(),
+            Value::Polygon(v) => v.to_string(),
+            Value::MultiPoint(v) => v.to_string(),
+            _ => return None,
+        };
+
+        Some(
+            [0].into_iter()
+                .chain(bbox.to_cmp_be_bytes())
+                .chain(wkt.into_bytes())
+                .collect(),
+        )
+    }
+
     /// # Description
     /// The operation method differs depending on the argument.
     /// 1. If both arguments are String
@@ -839,6 +887,9 @@ impl PartialEq for Value {
             (Value::Map(a), Value::Map(b)) => a == b,
             (Value::List(a), Value::List(b)) => a == b,
             (Value::Point(a), Value::Point(b)) => a == b,
+            (Value::LineString(a), Value::LineString(b)) => a == b,
+            (Value::Polygon(a), Value::Polygon(b)) => a == b,
+            (Value::MultiPoint(a), Value::MultiPoint(b)) => a == b,
             (Value::Null, Value::Null) => true,
             _ => false,
         }
@@ -899,6 +950,9 @@ impl Hash for Value {
             }
             Value::List(list) => list.hash(state),
             Value::Point(p) => p.hash(state),
+            Value::LineString(v) => v.hash(state),
+            Value::Polygon(v) => v.hash(state),
+            Value::MultiPoint(v) => v.hash(state),
             Value::Null => {
                 // Null gets its own unique hash based on discriminant only
                 // No additional data needed since discriminant already makes it unique
@@ -4,7 +4,10 @@ use {
         date::{parse_date, parse_time, parse_timestamp},
         error::ValueError,
     },
-    crate::{ast::DataType, data::Point},
+    crate::{
+        ast::DataType,
+        data::{Geometry, LineString, MultiPoint, Point, Polygon},
+    },
     chrono::{NaiveDate, NaiveDateTime, NaiveTime},
     rust_decimal::prelude::{Decimal, FromPrimitive, FromStr, ToPrimitive},
     std::net::IpAddr,
@@ -59,6 +62,9 @@ impl From<&Value> for String {
                 .to_string(),
             Value::Decimal(value) => value.to_string(),
             Value::Point(value) => value.to_string(),
+            Value::LineString(value) => value.to_string(),
+            Value::Polygon(value) => value.to_string(),
+            Value::MultiPoint(value) => value.to_string(),
             Value::Null => "NULL".to_owned(),
         }
     }
@@ -160,6 +166,9 @@ impl TryFrom<&Value> for bool {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Inet(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
@@ -216,6 +225,9 @@ impl TryFrom<&Value> for i8 {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Inet(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
@@ -274,6 +286,9 @@ impl TryFrom<&Value> for i16 {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Inet(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
@@ -329,6 +344,9 @@ impl TryFrom<&Value> for i32 {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Inet(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
@@ -384,6 +402,9 @@ impl TryFrom<&Value> for i64 {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Inet(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
@@ -439,6 +460,9 @@ impl TryFrom<&Value> for i128 {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Inet(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
@@ -492,6 +516,9 @@ impl TryFrom<&Value> for u8 {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Inet(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
@@ -546,6 +573,9 @@ impl TryFrom<&Value> for u16 {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Inet(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
@@ -603,6 +633,9 @@ impl TryFrom<&Value> for u32 {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
                     value: v.clone(),
@@ -658,6 +691,9 @@ impl TryFrom<&Value> for u64 {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
                     value: v.clone(),
@@ -712,6 +748,9 @@ impl TryFrom<&Value> for u128 {
             | Value::Inet(IpAddr::V4(_))
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
                     value: v.clone(),
@@ -774,6 +813,9 @@ impl TryFrom<&Value> for f32 {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Inet(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
@@ -837,6 +879,9 @@ impl TryFrom<&Value> for f64 {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Inet(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
@@ -892,6 +937,9 @@ impl TryFrom<&Value> for usize {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Inet(_)
             | Value::Null => return Err(err()),
         })
@@ -948,6 +996,9 @@ impl TryFrom<&Value> for Decimal {
             | Value::List(_)
             | Value::Bytea(_)
             | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_)
             | Value::Inet(_)
             | Value::Null => {
                 return Err(ValueError::ConvertFailed {
@@ -1053,23 +1104,52 @@ impl TryFrom<&Value> for Point {
     type Error = ValueError;
 
     fn try_from(v: &Value) -> Result<Point> {
-        Ok(match v {
-            Value::Point(value) => *value,
-            Value::Str(value) => Point::from_wkt(value).map_err(|_| ValueError::ConvertFailed {
-                value: v.clone(),
-                data_type: DataType::Point,
-            })?,
+        let convert_failed = || ValueError::ConvertFailed {
+            value: v.clone(),
+            data_type: DataType::Point,
+        };
 
-            _ => {
-                return Err(ValueError::ConvertFailed {
+        match v {
+            Value::Point(value) => Ok(*value),
+            Value::Str(value) => Point::from_wkt(value).map_err(|_| convert_failed()),
+            Value::Bytea(value) => match Geometry::from_wkb(value) {
+                Some(Geometry::Point(point)) => Ok(point),
+                _ => Err(convert_failed()),
+            },
+            _ => Err(convert_failed()),
+        }
+    }
+}
+
+macro_rules! try_from_geometry {
+    ($type: ident) => {
+        impl TryFrom<&Value> for $type {
+            type Error = ValueError;
+
+            fn try_from(v: &Value) -> Result<$type> {
+                let convert_failed = || ValueError::ConvertFailed {
                     value: v.clone(),
-                    data_type: DataType::Point,
-                });
+                    data_type: DataType::$type,
+                };
+
+                match v {
+                    Value::$type(value) => Ok(value.clone()),
+                    Value::Str(value) => $type::from_wkt(value).map_err(|_| convert_failed()),
+                    Value::Bytea(value) => match Geometry::from_wkb(value) {
+                        Some(Geometry::$type(value)) => Ok(value),
+                        _ => Err(convert_failed()),
+                    },
+                    _ => Err(convert_failed()),
+                }
             }
-        })
-    }
+        }
+    };
 }
 
+try_from_geometry!(LineString);
+try_from_geometry!(Polygon);
+try_from_geometry!(MultiPoint);
+
 #[cfg(test)]
 mod tests {
     use {
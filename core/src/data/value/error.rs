@@ -29,6 +29,15 @@ pub enum ValueError {
     #[error("failed to parse point: {0}")]
     FailedToParsePoint(String),
 
+    #[error("failed to parse linestring: {0}")]
+    FailedToParseLineString(String),
+
+    #[error("failed to parse polygon: {0}")]
+    FailedToParsePolygon(String),
+
+    #[error("failed to parse multipoint: {0}")]
+    FailedToParseMultiPoint(String),
+
     #[error("non-numeric values {lhs:?} {operator} {rhs:?}")]
     NonNumericMathOperation {
         lhs: Value,
@@ -94,6 +94,9 @@ impl TryFrom<Value> for JsonValue {
                 .collect::<Result<Vec<JsonValue>>>()
                 .map(Into::into),
             Value::Point(v) => Ok(v.to_string().into()),
+            Value::LineString(v) => Ok(v.to_string().into()),
+            Value::Polygon(v) => Ok(v.to_string().into()),
+            Value::MultiPoint(v) => Ok(v.to_string().into()),
             Value::Null => Ok(JsonValue::Null),
         }
     }
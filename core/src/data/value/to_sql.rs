@@ -35,6 +35,9 @@ impl ToSql for Value {
                 format!("'{escaped}'")
             }
             Value::Point(p) => format!("POINT({} {})", p.x, p.y),
+            Value::LineString(v) => format!("CAST('{v}' AS LINESTRING)"),
+            Value::Polygon(v) => format!("CAST('{v}' AS POLYGON)"),
+            Value::MultiPoint(v) => format!("CAST('{v}' AS MULTIPOINT)"),
             Value::Null => "NULL".to_owned(),
         }
     }
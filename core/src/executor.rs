@@ -8,6 +8,7 @@ mod filter;
 mod insert;
 mod query;
 mod select;
+mod trigger;
 mod update;
 mod validate;
 
@@ -20,6 +21,7 @@ pub use {
     fetch::FetchError,
     insert::InsertError,
     query::QueryError,
+    trigger::TriggerError,
     update::UpdateError,
     validate::ValidateError,
 };
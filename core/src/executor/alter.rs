@@ -3,6 +3,7 @@ mod error;
 mod function;
 mod index;
 mod table;
+mod trigger;
 mod validate;
 
 use validate::{validate, validate_arg_names, validate_column_names, validate_default_args};
@@ -13,4 +14,5 @@ pub use {
     function::{delete_function, insert_function},
     index::create_index,
     table::{CreateTableOptions, Referencing, create_table, drop_table},
+    trigger::{CreateTriggerOptions, create_trigger, drop_trigger},
 };
@@ -21,6 +21,10 @@ pub fn alter_table<T: GStore + GStoreMut>(
     | AlterTableOperationPlan::DropColumn { column_name, .. } = operation
     {
         if let Some(schema) = storage.fetch_schema(table_name)? {
+            if schema.ttl_column.as_ref() == Some(column_name) {
+                return Err(AlterError::CannotAlterTtlColumn(column_name.to_owned()).into());
+            }
+
             let referencing_foreign_key = schema
                 .foreign_keys
                 .into_iter()
@@ -15,6 +15,12 @@ pub enum AlterError {
     #[error("function does not exist: {0}")]
     FunctionNotFound(String),
 
+    #[error("trigger already exists: {0}")]
+    TriggerAlreadyExists(String),
+
+    #[error("trigger does not exist: {0}")]
+    TriggerNotFound(String),
+
     // CREATE INDEX, DROP TABLE
     #[error("table does not exist: {0}")]
     TableNotFound(String),
@@ -46,6 +52,15 @@ pub enum AlterError {
     #[error("non-default argument should not follow the default argument")]
     NonDefaultArgumentFollowsDefaultArgument,
 
+    #[error("ttl column not found: {0}")]
+    TtlColumnNotFound(String),
+
+    #[error("ttl column '{column}' must be TIMESTAMP, found '{data_type}'")]
+    UnsupportedTtlColumnType { column: String, data_type: DataType },
+
+    #[error("cannot drop or rename ttl column: {0}")]
+    CannotAlterTtlColumn(String),
+
     #[error("foreign table not found: {0}")]
     ReferencedTableNotFound(String),
 
@@ -27,6 +27,7 @@ pub struct CreateTableOptions<'a> {
     pub engine: &'a Option<String>,
     pub foreign_keys: &'a Vec<ForeignKey>,
     pub comment: &'a Option<String>,
+    pub ttl_column: &'a Option<String>,
 }
 
 pub fn create_table<T: GStore + GStoreMut>(
@@ -39,6 +40,7 @@ pub fn create_table<T: GStore + GStoreMut>(
         engine,
         foreign_keys,
         comment,
+        ttl_column,
     }: CreateTableOptions<'_>,
 ) -> Result<()> {
     let mut selected_source_rows = None;
@@ -130,6 +132,25 @@ pub fn create_table<T: GStore + GStoreMut>(
         }
     }
 
+    if let Some(ttl_column) = ttl_column {
+        let column_def = target_columns_defs
+            .as_deref()
+            .and_then(|column_defs| {
+                column_defs
+                    .iter()
+                    .find(|column_def| &column_def.name == ttl_column)
+            })
+            .ok_or_else(|| AlterError::TtlColumnNotFound(ttl_column.to_owned()))?;
+
+        if column_def.data_type != DataType::Timestamp {
+            return Err(AlterError::UnsupportedTtlColumnType {
+                column: ttl_column.to_owned(),
+                data_type: column_def.data_type.clone(),
+            }
+            .into());
+        }
+    }
+
     for foreign_key in foreign_keys {
         let ForeignKey {
             referencing_column_name,
@@ -198,6 +219,7 @@ pub fn create_table<T: GStore + GStoreMut>(
             engine: engine.clone(),
             foreign_keys: foreign_keys.clone(),
             comment: comment.clone(),
+            ttl_column: ttl_column.clone(),
         };
 
         storage.insert_schema(&schema)?;
@@ -312,6 +334,9 @@ pub fn drop_table<T: GStore + GStoreMut>(
                 .retain(|foreign_key| foreign_key.name != name);
             storage.insert_schema(&schema)?;
         }
+        for trigger in storage.fetch_triggers(table_name)? {
+            storage.delete_trigger(table_name, &trigger.name)?;
+        }
         storage.delete_schema(table_name)?;
 
         n += 1;
@@ -0,0 +1,77 @@
+use {
+    super::AlterError,
+    crate::{
+        ast::{TriggerEvent, TriggerTiming},
+        data::Trigger,
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+};
+
+pub struct CreateTriggerOptions<'a> {
+    pub name: &'a str,
+    pub table_name: &'a str,
+    pub timing: TriggerTiming,
+    pub events: &'a [TriggerEvent],
+    pub body: &'a [String],
+    pub or_replace: bool,
+}
+
+pub fn create_trigger<T: GStore + GStoreMut>(
+    storage: &mut T,
+    CreateTriggerOptions {
+        name,
+        table_name,
+        timing,
+        events,
+        body,
+        or_replace,
+    }: CreateTriggerOptions,
+) -> Result<()> {
+    if storage.fetch_schema(table_name)?.is_none() {
+        return Err(AlterError::TableNotFound(table_name.to_owned()).into());
+    }
+
+    let exists = storage
+        .fetch_triggers(table_name)?
+        .iter()
+        .any(|trigger| trigger.name == name);
+
+    if exists && !or_replace {
+        return Err(AlterError::TriggerAlreadyExists(name.to_owned()).into());
+    }
+
+    if exists {
+        storage.delete_trigger(table_name, name)?;
+    }
+
+    storage.insert_trigger(Trigger {
+        name: name.to_owned(),
+        table_name: table_name.to_owned(),
+        timing,
+        events: events.to_owned(),
+        body: body.to_owned(),
+    })
+}
+
+pub fn drop_trigger<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    name: &str,
+    if_exists: bool,
+) -> Result<()> {
+    let exists = storage
+        .fetch_triggers(table_name)?
+        .iter()
+        .any(|trigger| trigger.name == name);
+
+    if !exists {
+        return if if_exists {
+            Ok(())
+        } else {
+            Err(AlterError::TriggerNotFound(name.to_owned()).into())
+        };
+    }
+
+    storage.delete_trigger(table_name, name)
+}
@@ -1,20 +1,37 @@
-use {super::RowContext, crate::data::Value, std::rc::Rc};
+use {
+    super::RowContext,
+    crate::{data::Value, plan::ExprPlan},
+    std::rc::Rc,
+};
 
 #[derive(Debug)]
 pub struct AggregateValues {
     values: Box<[Value]>,
+    rolled_up: Rc<[ExprPlan]>,
 }
 
 impl AggregateValues {
     pub fn new(values: Vec<Value>) -> Self {
         Self {
             values: values.into_boxed_slice(),
+            rolled_up: Rc::from([]),
         }
     }
 
+    /// Marks the `GROUP BY` expressions a grouping set leaves out, so they
+    /// evaluate to NULL in the subtotal rows of that set.
+    #[must_use]
+    pub fn with_rolled_up(self, rolled_up: Rc<[ExprPlan]>) -> Self {
+        Self { rolled_up, ..self }
+    }
+
     pub fn get(&self, slot: usize) -> Option<&Value> {
         self.values.get(slot)
     }
+
+    pub fn is_rolled_up(&self, expr: &ExprPlan) -> bool {
+        self.rolled_up.contains(expr)
+    }
 }
 
 #[derive(Debug)]
@@ -2,9 +2,10 @@ use {
     super::{
         Payload, Referencing,
         fetch::{fetch, fetch_columns},
+        trigger::RowTriggers,
     },
     crate::{
-        ast::{BinaryOperator, ForeignKey, ReferentialAction},
+        ast::{BinaryOperator, ForeignKey, ReferentialAction, TriggerEvent},
         plan::ExprPlan,
         result::Result,
         store::{GStore, GStoreMut},
@@ -38,8 +39,10 @@ pub fn delete<T: GStore + GStoreMut>(
                 .map(|columns| (referencing, columns))
         })
         .collect::<Result<Vec<_>>>()?;
+    let triggers = RowTriggers::fetch(storage, table_name, TriggerEvent::Delete)?;
 
     let mut keys = Vec::new();
+    let mut old_rows = Vec::new();
     for item in fetch(storage, table_name, columns, selection)? {
         let (key, row) = item?;
 
@@ -83,10 +86,21 @@ pub fn delete<T: GStore + GStoreMut>(
         }
 
         keys.push(key);
+        if !triggers.is_empty() {
+            old_rows.push(row);
+        }
     }
     let num_keys = keys.len();
 
-    storage
-        .delete_data(table_name, keys)
-        .map(|()| Payload::Delete(num_keys))
+    for row in &old_rows {
+        triggers.fire_before(storage, Some(row), None)?;
+    }
+
+    storage.delete_data(table_name, keys)?;
+
+    for row in &old_rows {
+        triggers.fire_after(storage, Some(row), None)?;
+    }
+
+    Ok(Payload::Delete(num_keys))
 }
@@ -57,6 +57,10 @@ where
 {
     let eval = |expr| evaluate_inner(storage, context, aggregated, expr);
 
+    if aggregated.is_some_and(|aggregated| aggregated.is_rolled_up(expr)) {
+        return Ok(Evaluated::Value(Cow::Owned(Value::Null)));
+    }
+
     match expr {
         ExprPlan::Literal(literal) => Ok(expr::literal(literal)),
         ExprPlan::Value(value) => Ok(Evaluated::Value(Cow::Borrowed(value))),
@@ -428,6 +432,62 @@ fn evaluate_function<'a, 'b: 'a, T: GStore>(
             let expr = eval(expr)?;
             f::last_day(&name, expr)
         }
+        FunctionExprPlan::DateTrunc { field, expr } => {
+            let field = eval(field)?;
+            let expr = eval(expr)?;
+            f::date_trunc(&name, field, expr)
+        }
+        FunctionExprPlan::DateBin {
+            stride,
+            expr,
+            origin,
+        } => {
+            let stride = eval(stride)?;
+            let expr = eval(expr)?;
+            let origin = match origin {
+                Some(v) => Some(eval(v)?),
+                None => None,
+            };
+
+            f::date_bin(&name, stride, expr, origin)
+        }
+        FunctionExprPlan::DateDiff { field, start, end } => {
+            let field = eval(field)?;
+            let start = eval(start)?;
+            let end = eval(end)?;
+            f::date_diff(&name, field, start, end)
+        }
+        FunctionExprPlan::Age { left, right } => {
+            let left = eval(left)?;
+            let right = match right {
+                Some(v) => Some(eval(v)?),
+                None => None,
+            };
+
+            f::age(&name, left, right)
+        }
+        FunctionExprPlan::MakeDate { year, month, day } => {
+            let year = eval(year)?;
+            let month = eval(month)?;
+            let day = eval(day)?;
+            f::make_date(&name, year, month, day)
+        }
+        FunctionExprPlan::MakeTimestamp {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        } => {
+            let year = eval(year)?;
+            let month = eval(month)?;
+            let day = eval(day)?;
+            let hour = eval(hour)?;
+            let minute = eval(minute)?;
+            let second = eval(second)?;
+            f::make_timestamp(&name, year, month, day, hour, minute, second)
+        }
         FunctionExprPlan::Trim {
             expr,
             filter_chars,
@@ -480,6 +540,78 @@ fn evaluate_function<'a, 'b: 'a, T: GStore>(
 
             return expr.substr(name, start, count);
         }
+        FunctionExprPlan::RegexpLike {
+            expr,
+            pattern,
+            flags,
+        } => {
+            let expr = eval(expr)?;
+            let pattern = eval(pattern)?;
+            let flags = match flags {
+                Some(v) => Some(eval(v)?),
+                None => None,
+            };
+
+            f::regexp_like(&name, expr, pattern, flags)
+        }
+        FunctionExprPlan::RegexpReplace {
+            expr,
+            pattern,
+            replacement,
+            flags,
+        } => {
+            let expr = eval(expr)?;
+            let pattern = eval(pattern)?;
+            let replacement = eval(replacement)?;
+            let flags = match flags {
+                Some(v) => Some(eval(v)?),
+                None => None,
+            };
+
+            f::regexp_replace(&name, expr, pattern, replacement, flags)
+        }
+        FunctionExprPlan::RegexpSubstr {
+            expr,
+            pattern,
+            flags,
+        } => {
+            let expr = eval(expr)?;
+            let pattern = eval(pattern)?;
+            let flags = match flags {
+                Some(v) => Some(eval(v)?),
+                None => None,
+            };
+
+            f::regexp_substr(&name, expr, pattern, flags)
+        }
+        FunctionExprPlan::RegexpMatches {
+            expr,
+            pattern,
+            flags,
+        } => {
+            let expr = eval(expr)?;
+            let pattern = eval(pattern)?;
+            let flags = match flags {
+                Some(v) => Some(eval(v)?),
+                None => None,
+            };
+
+            f::regexp_matches(&name, expr, pattern, flags)
+        }
+        FunctionExprPlan::RegexpSplitToArray {
+            expr,
+            pattern,
+            flags,
+        } => {
+            let expr = eval(expr)?;
+            let pattern = eval(pattern)?;
+            let flags = match flags {
+                Some(v) => Some(eval(v)?),
+                None => None,
+            };
+
+            f::regexp_split_to_array(&name, expr, pattern, flags)
+        }
         FunctionExprPlan::Ascii(expr) => f::ascii(&name, eval(expr)?),
         FunctionExprPlan::Chr(expr) => f::chr(&name, eval(expr)?),
         FunctionExprPlan::Md5(expr) => f::md5(&name, eval(expr)?),
@@ -575,6 +707,44 @@ fn evaluate_function<'a, 'b: 'a, T: GStore>(
 
             f::calc_distance(&name, geometry1, geometry2)
         }
+        FunctionExprPlan::StContains {
+            geometry1,
+            geometry2,
+        } => {
+            let geometry1 = eval(geometry1)?;
+            let geometry2 = eval(geometry2)?;
+
+            f::st_contains(&name, geometry1, geometry2)
+        }
+        FunctionExprPlan::StIntersects {
+            geometry1,
+            geometry2,
+        } => {
+            let geometry1 = eval(geometry1)?;
+            let geometry2 = eval(geometry2)?;
+
+            f::st_intersects(&name, geometry1, geometry2)
+        }
+        FunctionExprPlan::StWithin {
+            geometry1,
+            geometry2,
+        } => {
+            let geometry1 = eval(geometry1)?;
+            let geometry2 = eval(geometry2)?;
+
+            f::st_within(&name, geometry1, geometry2)
+        }
+        FunctionExprPlan::StArea(expr) => f::st_area(&name, eval(expr)?),
+        FunctionExprPlan::StLength(expr) => f::st_length(&name, eval(expr)?),
+        FunctionExprPlan::StDistanceSphere {
+            geometry1,
+            geometry2,
+        } => {
+            let geometry1 = eval(geometry1)?;
+            let geometry2 = eval(geometry2)?;
+
+            f::st_distance_sphere(&name, geometry1, geometry2)
+        }
 
         // --- etc ---
         FunctionExprPlan::Unwrap { expr, selector } => {
@@ -789,6 +959,7 @@ mod tests {
         let aggregate = AggregateExprPlan {
             func: AggregateFunctionPlan::Count(CountArgExprPlan::Wildcard),
             distinct: false,
+            filter: None,
             slot: Some(0),
         };
 
@@ -49,9 +49,27 @@ pub enum EvaluateError {
     #[error("function requires point value: {0}")]
     FunctionRequiresPointValue(String),
 
+    #[error("function requires geometry value: {0}")]
+    FunctionRequiresGeometryValue(String),
+
     #[error("function requires date or datetime value: {0}")]
     FunctionRequiresDateOrDateTimeValue(String),
 
+    #[error("function requires interval value: {0}")]
+    FunctionRequiresIntervalValue(String),
+
+    #[error("unsupported date or time unit: {0}")]
+    UnsupportedDateTimeUnit(String),
+
+    #[error("DATE_BIN stride must be a positive interval without months")]
+    DateBinRequiresPositiveTimeInterval,
+
+    #[error("invalid date or time parts: {0}")]
+    InvalidDateTimeParts(String),
+
+    #[error("date or time out of range: {0}")]
+    DateTimeOutOfRange(String),
+
     #[error("function requires one of string, list, map types: {0}")]
     FunctionRequiresStrOrListOrMapValue(String),
 
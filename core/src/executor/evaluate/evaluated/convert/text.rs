@@ -2,7 +2,7 @@ use {
     crate::{
         ast::DataType,
         data::{
-            Interval, Point, Value,
+            Interval, LineString, MultiPoint, Point, Polygon, Value,
             value::{parse_date, parse_time, parse_timestamp, parse_uuid},
         },
         executor::EvaluateError,
@@ -39,6 +39,15 @@ pub(crate) fn text_to_value(data_type: &DataType, value: &str) -> Result<Value>
         DataType::Point => Point::from_wkt(value)
             .map(Value::Point)
             .map_err(|_| parse_failed(value, data_type).into()),
+        DataType::LineString => LineString::from_wkt(value)
+            .map(Value::LineString)
+            .map_err(|_| parse_failed(value, data_type).into()),
+        DataType::Polygon => Polygon::from_wkt(value)
+            .map(Value::Polygon)
+            .map_err(|_| parse_failed(value, data_type).into()),
+        DataType::MultiPoint => MultiPoint::from_wkt(value)
+            .map(Value::MultiPoint)
+            .map_err(|_| parse_failed(value, data_type).into()),
         DataType::Date => parse_date(value)
             .map(Value::Date)
             .ok_or_else(|| parse_failed(value, data_type).into()),
@@ -0,0 +1,112 @@
+use {
+    super::Evaluated,
+    crate::{
+        ast::BinaryOperator,
+        data::{StringExt, Value},
+        executor::evaluate::error::EvaluateError,
+        result::{Error, Result},
+    },
+    std::borrow::Cow,
+};
+
+impl<'a> Evaluated<'a> {
+    /// Evaluates Postgres-style `~`, `~*`, `!~` and `!~*` operators.
+    pub fn regexp_match(
+        &self,
+        other: &Evaluated<'a>,
+        op: &BinaryOperator,
+    ) -> Result<Evaluated<'a>> {
+        let unsupported = || -> Error {
+            EvaluateError::UnsupportedBinaryOperation {
+                left: self.to_string(),
+                op: op.clone(),
+                right: other.to_string(),
+            }
+            .into()
+        };
+
+        let (flags, negated) = match op {
+            BinaryOperator::RegexMatch => ("", false),
+            BinaryOperator::RegexIMatch => ("i", false),
+            BinaryOperator::RegexNotMatch => ("", true),
+            BinaryOperator::RegexNotIMatch => ("i", true),
+            _ => return Err(unsupported()),
+        };
+
+        let (Some(target), Some(pattern)) = (self.as_str(), other.as_str()) else {
+            return Err(unsupported());
+        };
+
+        let matched = target.regexp_like(pattern, flags)?;
+
+        Ok(Evaluated::Value(Cow::Owned(Value::Bool(matched ^ negated))))
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Evaluated::Text(v) => Some(v.as_ref()),
+            Evaluated::StrSlice { source, range } => Some(&source[range.clone()]),
+            Evaluated::Value(v) => match v.as_ref() {
+                Value::Str(v) => Some(v.as_str()),
+                _ => None,
+            },
+            Evaluated::Number(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Evaluated,
+        crate::{ast::BinaryOperator, data::Value, executor::EvaluateError},
+        std::borrow::Cow,
+    };
+
+    #[test]
+    fn regexp_match() {
+        let text = |s: &str| Evaluated::Text(Cow::Owned(s.to_owned()));
+        let val_str = |s: &str| Evaluated::Value(Cow::Owned(Value::Str(s.to_owned())));
+        let bool = |v: bool| Ok(Evaluated::Value(Cow::Owned(Value::Bool(v))));
+
+        assert_eq!(
+            text("GlueSQL").regexp_match(&text("^Glue"), &BinaryOperator::RegexMatch),
+            bool(true)
+        );
+        assert_eq!(
+            val_str("GlueSQL").regexp_match(&text("^glue"), &BinaryOperator::RegexMatch),
+            bool(false)
+        );
+        assert_eq!(
+            val_str("GlueSQL").regexp_match(&val_str("^glue"), &BinaryOperator::RegexIMatch),
+            bool(true)
+        );
+        assert_eq!(
+            text("GlueSQL").regexp_match(&text("sql$"), &BinaryOperator::RegexNotMatch),
+            bool(true)
+        );
+        assert_eq!(
+            text("GlueSQL").regexp_match(&text("sql$"), &BinaryOperator::RegexNotIMatch),
+            bool(false)
+        );
+        assert_eq!(
+            Evaluated::Value(Cow::Owned(Value::I64(1)))
+                .regexp_match(&text("1"), &BinaryOperator::RegexMatch),
+            Err(EvaluateError::UnsupportedBinaryOperation {
+                left: "1".to_owned(),
+                op: BinaryOperator::RegexMatch,
+                right: "1".to_owned(),
+            }
+            .into())
+        );
+        assert_eq!(
+            text("GlueSQL").regexp_match(&text("Glue"), &BinaryOperator::Eq),
+            Err(EvaluateError::UnsupportedBinaryOperation {
+                left: "GlueSQL".to_owned(),
+                op: BinaryOperator::Eq,
+                right: "Glue".to_owned(),
+            }
+            .into())
+        );
+    }
+}
@@ -71,6 +71,10 @@ pub fn binary_op<'a>(
         BinaryOperator::BitwiseShiftLeft => l.bitwise_shift_left(&r),
         BinaryOperator::BitwiseShiftRight => l.bitwise_shift_right(&r),
         BinaryOperator::Arrow => l.arrow(&r),
+        BinaryOperator::RegexMatch
+        | BinaryOperator::RegexIMatch
+        | BinaryOperator::RegexNotMatch
+        | BinaryOperator::RegexNotIMatch => l.regexp_match(&r, op),
     }
 }
 
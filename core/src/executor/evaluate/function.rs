@@ -2,12 +2,15 @@ use {
     super::{EvaluateError, Evaluated},
     crate::{
         ast::DateTimeField,
-        data::{Key, Point, Value},
+        data::{Geometry, Interval, Key, Point, Value, compile_regex},
         result::{Error, Result},
     },
-    chrono::{Datelike, Duration, Months},
+    chrono::{
+        DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc,
+    },
     md5::{Digest, Md5},
     rand::{Rng, SeedableRng, rngs::StdRng},
+    regex::Regex,
     std::{
         borrow::Cow,
         fmt::Write,
@@ -140,6 +143,14 @@ fn eval_to_point(name: &str, evaluated: Evaluated<'_>) -> ControlFlow<Point> {
     }
 }
 
+fn eval_to_geometry(name: &str, evaluated: Evaluated<'_>) -> ControlFlow<Geometry> {
+    let value: Value = evaluated.try_into().break_if_null()?;
+
+    value
+        .geometry()
+        .continue_or_break(EvaluateError::FunctionRequiresGeometryValue(name.to_owned()).into())
+}
+
 // --- text ---
 pub fn concat(exprs: Vec<Evaluated<'_>>) -> ControlFlow<Evaluated<'_>> {
     let value = exprs
@@ -362,6 +373,147 @@ pub fn hex<'a>(name: &str, expr: Evaluated<'_>) -> ControlFlow<Evaluated<'a>> {
     }
 }
 
+// --- regex ---
+fn eval_to_regex(
+    name: &str,
+    pattern: Evaluated<'_>,
+    flags: Option<Evaluated<'_>>,
+) -> ControlFlow<(Regex, bool)> {
+    let pattern = eval_to_str(name, pattern)?;
+    let flags = match flags {
+        Some(flags) => eval_to_str(name, flags)?,
+        None => String::new(),
+    };
+    let regex = compile_regex(&pattern, &flags).into_control_flow()?;
+
+    Continue((regex, flags.contains('g')))
+}
+
+/// Converts Postgres-style replacement syntax (`\1`, `\&`) into the `regex` crate syntax.
+fn translate_regexp_replacement(replacement: &str) -> String {
+    let mut translated = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '$' => translated.push_str("$$"),
+            '\\' => match chars.next() {
+                Some(d @ '0'..='9') => {
+                    let _ = write!(translated, "${{{d}}}");
+                }
+                Some('&') => translated.push_str("${0}"),
+                Some('$') => translated.push_str("$$"),
+                Some(other) => translated.push(other),
+                None => translated.push('\\'),
+            },
+            _ => translated.push(c),
+        }
+    }
+
+    translated
+}
+
+pub fn regexp_like<'a>(
+    name: &str,
+    expr: Evaluated<'_>,
+    pattern: Evaluated<'_>,
+    flags: Option<Evaluated<'_>>,
+) -> ControlFlow<Evaluated<'a>> {
+    let expr = eval_to_str(name, expr)?;
+    let (regex, _) = eval_to_regex(name, pattern, flags)?;
+
+    Continue(Evaluated::Value(Cow::Owned(Value::Bool(
+        regex.is_match(&expr),
+    ))))
+}
+
+pub fn regexp_replace<'a>(
+    name: &str,
+    expr: Evaluated<'_>,
+    pattern: Evaluated<'_>,
+    replacement: Evaluated<'_>,
+    flags: Option<Evaluated<'_>>,
+) -> ControlFlow<Evaluated<'a>> {
+    let expr = eval_to_str(name, expr)?;
+    let (regex, global) = eval_to_regex(name, pattern, flags)?;
+    let replacement = translate_regexp_replacement(&eval_to_str(name, replacement)?);
+    let value = if global {
+        regex.replace_all(&expr, replacement.as_str())
+    } else {
+        regex.replace(&expr, replacement.as_str())
+    };
+
+    Continue(Evaluated::Value(Cow::Owned(Value::Str(value.into_owned()))))
+}
+
+pub fn regexp_substr<'a>(
+    name: &str,
+    expr: Evaluated<'_>,
+    pattern: Evaluated<'_>,
+    flags: Option<Evaluated<'_>>,
+) -> ControlFlow<Evaluated<'a>> {
+    let expr = eval_to_str(name, expr)?;
+    let (regex, _) = eval_to_regex(name, pattern, flags)?;
+
+    match regex.find(&expr) {
+        Some(found) => Continue(Evaluated::Value(Cow::Owned(Value::Str(
+            found.as_str().to_owned(),
+        )))),
+        None => Break(BreakCase::Null),
+    }
+}
+
+pub fn regexp_matches<'a>(
+    name: &str,
+    expr: Evaluated<'_>,
+    pattern: Evaluated<'_>,
+    flags: Option<Evaluated<'_>>,
+) -> ControlFlow<Evaluated<'a>> {
+    let expr = eval_to_str(name, expr)?;
+    let (regex, global) = eval_to_regex(name, pattern, flags)?;
+    let to_list = |captures: regex::Captures<'_>| {
+        let groups = captures
+            .iter()
+            .skip(usize::from(captures.len() > 1))
+            .map(|group| group.map_or(Value::Null, |m| Value::Str(m.as_str().to_owned())))
+            .collect();
+
+        Value::List(groups)
+    };
+
+    let value = if global {
+        let matches = regex.captures_iter(&expr).map(to_list).collect::<Vec<_>>();
+        if matches.is_empty() {
+            return Break(BreakCase::Null);
+        }
+
+        Value::List(matches)
+    } else {
+        match regex.captures(&expr) {
+            Some(captures) => to_list(captures),
+            None => return Break(BreakCase::Null),
+        }
+    };
+
+    Continue(Evaluated::Value(Cow::Owned(value)))
+}
+
+pub fn regexp_split_to_array<'a>(
+    name: &str,
+    expr: Evaluated<'_>,
+    pattern: Evaluated<'_>,
+    flags: Option<Evaluated<'_>>,
+) -> ControlFlow<Evaluated<'a>> {
+    let expr = eval_to_str(name, expr)?;
+    let (regex, _) = eval_to_regex(name, pattern, flags)?;
+    let items = regex
+        .split(&expr)
+        .map(|item| Value::Str(item.to_owned()))
+        .collect();
+
+    Continue(Evaluated::Value(Cow::Owned(Value::List(items))))
+}
+
 // --- float ---
 
 pub fn abs<'a>(name: &str, n: Evaluated<'_>) -> ControlFlow<Evaluated<'a>> {
@@ -843,6 +995,253 @@ pub fn to_timestamp<'a>(
     .into_control_flow()
 }
 
+#[derive(Clone, Copy)]
+enum DateUnit {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+fn eval_to_date_unit(name: &str, evaluated: Evaluated<'_>) -> ControlFlow<DateUnit> {
+    let unit = eval_to_str(name, evaluated)?;
+
+    match unit.to_lowercase().as_str() {
+        "year" => Continue(DateUnit::Year),
+        "quarter" => Continue(DateUnit::Quarter),
+        "month" => Continue(DateUnit::Month),
+        "week" => Continue(DateUnit::Week),
+        "day" => Continue(DateUnit::Day),
+        "hour" => Continue(DateUnit::Hour),
+        "minute" => Continue(DateUnit::Minute),
+        "second" => Continue(DateUnit::Second),
+        _ => Break(BreakCase::Err(
+            EvaluateError::UnsupportedDateTimeUnit(unit).into(),
+        )),
+    }
+}
+
+/// Reads a DATE or TIMESTAMP argument, treating a date as its midnight.
+fn eval_to_timestamp(name: &str, evaluated: Evaluated<'_>) -> ControlFlow<NaiveDateTime> {
+    match evaluated.try_into().break_if_null()? {
+        Value::Date(date) => Continue(date.and_time(NaiveTime::MIN)),
+        Value::Timestamp(timestamp) => Continue(timestamp),
+        _ => Break(BreakCase::Err(
+            EvaluateError::FunctionRequiresDateOrDateTimeValue(name.to_owned()).into(),
+        )),
+    }
+}
+
+fn truncate_timestamp(unit: DateUnit, timestamp: NaiveDateTime) -> NaiveDateTime {
+    let date = timestamp.date();
+    let seconds = i64::from(timestamp.num_seconds_from_midnight());
+    let truncate_time = |unit_seconds: i64| {
+        date.and_time(NaiveTime::MIN) + Duration::seconds(seconds - seconds % unit_seconds)
+    };
+
+    match unit {
+        DateUnit::Year => {
+            (date - Duration::days(i64::from(date.ordinal0()))).and_time(NaiveTime::MIN)
+        }
+        DateUnit::Quarter => {
+            let month = date - Duration::days(i64::from(date.day0()));
+
+            (month - Months::new(date.month0() % 3)).and_time(NaiveTime::MIN)
+        }
+        DateUnit::Month => (date - Duration::days(i64::from(date.day0()))).and_time(NaiveTime::MIN),
+        DateUnit::Week => {
+            let days = date.weekday().num_days_from_monday();
+
+            (date - Duration::days(i64::from(days))).and_time(NaiveTime::MIN)
+        }
+        DateUnit::Day => date.and_time(NaiveTime::MIN),
+        DateUnit::Hour => truncate_time(3600),
+        DateUnit::Minute => truncate_time(60),
+        DateUnit::Second => truncate_time(1),
+    }
+}
+
+pub fn date_trunc<'a>(
+    name: &str,
+    field: Evaluated<'_>,
+    expr: Evaluated<'_>,
+) -> ControlFlow<Evaluated<'a>> {
+    let unit = eval_to_date_unit(name, field)?;
+    let value = match expr.try_into().break_if_null()? {
+        Value::Date(date) => {
+            Value::Date(truncate_timestamp(unit, date.and_time(NaiveTime::MIN)).date())
+        }
+        Value::Timestamp(timestamp) => Value::Timestamp(truncate_timestamp(unit, timestamp)),
+        _ => {
+            return Err(EvaluateError::FunctionRequiresDateOrDateTimeValue(name.to_owned()).into())
+                .into_control_flow();
+        }
+    };
+
+    Continue(Evaluated::Value(Cow::Owned(value)))
+}
+
+pub fn date_bin<'a>(
+    name: &str,
+    stride: Evaluated<'_>,
+    expr: Evaluated<'_>,
+    origin: Option<Evaluated<'_>>,
+) -> ControlFlow<Evaluated<'a>> {
+    let stride = match stride.try_into().break_if_null()? {
+        Value::Interval(Interval::Microsecond(stride)) if stride > 0 => stride,
+        Value::Interval(_) => {
+            return Err(EvaluateError::DateBinRequiresPositiveTimeInterval.into())
+                .into_control_flow();
+        }
+        _ => {
+            return Err(EvaluateError::FunctionRequiresIntervalValue(name.to_owned()).into())
+                .into_control_flow();
+        }
+    };
+    let timestamp = eval_to_timestamp(name, expr)?;
+    let origin = match origin {
+        Some(origin) => eval_to_timestamp(name, origin)?,
+        None => DateTime::UNIX_EPOCH.naive_utc(),
+    };
+
+    let out_of_range = || EvaluateError::DateTimeOutOfRange(name.to_owned()).into();
+    let elapsed = (timestamp - origin)
+        .num_microseconds()
+        .continue_or_break(out_of_range())?;
+    let binned = origin
+        .checked_add_signed(Duration::microseconds(elapsed.div_euclid(stride) * stride))
+        .continue_or_break(out_of_range())?;
+
+    Continue(Evaluated::Value(Cow::Owned(Value::Timestamp(binned))))
+}
+
+pub fn date_diff<'a>(
+    name: &str,
+    field: Evaluated<'_>,
+    start: Evaluated<'_>,
+    end: Evaluated<'_>,
+) -> ControlFlow<Evaluated<'a>> {
+    let unit = eval_to_date_unit(name, field)?;
+    let start = eval_to_timestamp(name, start)?;
+    let end = eval_to_timestamp(name, end)?;
+
+    let months =
+        |timestamp: NaiveDateTime| i64::from(timestamp.year()) * 12 + i64::from(timestamp.month0());
+    let elapsed = || truncate_timestamp(unit, end) - truncate_timestamp(unit, start);
+    let diff = match unit {
+        DateUnit::Year => i64::from(end.year() - start.year()),
+        DateUnit::Quarter => months(end).div_euclid(3) - months(start).div_euclid(3),
+        DateUnit::Month => months(end) - months(start),
+        DateUnit::Week => elapsed().num_weeks(),
+        DateUnit::Day => elapsed().num_days(),
+        DateUnit::Hour => elapsed().num_hours(),
+        DateUnit::Minute => elapsed().num_minutes(),
+        DateUnit::Second => elapsed().num_seconds(),
+    };
+
+    Continue(Evaluated::Value(Cow::Owned(Value::I64(diff))))
+}
+
+/// Returns the whole months elapsed from `right` to `left` as a month interval. The
+/// remaining days and time are dropped, as an [`Interval`] cannot mix months with
+/// smaller units. With a single argument, the age is measured from the current date.
+pub fn age<'a>(
+    name: &str,
+    left: Evaluated<'_>,
+    right: Option<Evaluated<'_>>,
+) -> ControlFlow<Evaluated<'a>> {
+    let (end, start) = match right {
+        Some(right) => (
+            eval_to_timestamp(name, left)?,
+            eval_to_timestamp(name, right)?,
+        ),
+        None => (
+            Utc::now().date_naive().and_time(NaiveTime::MIN),
+            eval_to_timestamp(name, left)?,
+        ),
+    };
+
+    let months = |timestamp: NaiveDateTime| timestamp.year() * 12 + timestamp.month0() as i32;
+    let day_time = |timestamp: NaiveDateTime| (timestamp.day(), timestamp.time());
+    let months = match months(end) - months(start) {
+        months if months > 0 && day_time(end) < day_time(start) => months - 1,
+        months if months < 0 && day_time(end) > day_time(start) => months + 1,
+        months => months,
+    };
+
+    Continue(Evaluated::Value(Cow::Owned(Value::Interval(
+        Interval::Month(months),
+    ))))
+}
+
+pub fn make_date<'a>(
+    name: &str,
+    year: Evaluated<'_>,
+    month: Evaluated<'_>,
+    day: Evaluated<'_>,
+) -> ControlFlow<Evaluated<'a>> {
+    let year = eval_to_int(name, year)?;
+    let month = eval_to_int(name, month)?;
+    let day = eval_to_int(name, day)?;
+
+    let date = ymd_to_date(year, month, day).continue_or_break(
+        EvaluateError::InvalidDateTimeParts(format!("{year}-{month}-{day}")).into(),
+    )?;
+
+    Continue(Evaluated::Value(Cow::Owned(Value::Date(date))))
+}
+
+pub fn make_timestamp<'a>(
+    name: &str,
+    year: Evaluated<'_>,
+    month: Evaluated<'_>,
+    day: Evaluated<'_>,
+    hour: Evaluated<'_>,
+    minute: Evaluated<'_>,
+    second: Evaluated<'_>,
+) -> ControlFlow<Evaluated<'a>> {
+    let year = eval_to_int(name, year)?;
+    let month = eval_to_int(name, month)?;
+    let day = eval_to_int(name, day)?;
+    let hour = eval_to_int(name, hour)?;
+    let minute = eval_to_int(name, minute)?;
+    let second = eval_to_float(name, second)?;
+
+    let time = (0.0..60.0).contains(&second).then_some(()).and_then(|()| {
+        let micro = ((second - second.trunc()) * 1_000_000.0).round() as u32;
+
+        NaiveTime::from_hms_micro_opt(
+            u32::try_from(hour).ok()?,
+            u32::try_from(minute).ok()?,
+            second.trunc() as u32,
+            micro.min(999_999),
+        )
+    });
+    let timestamp = ymd_to_date(year, month, day)
+        .zip(time)
+        .map(|(date, time)| date.and_time(time))
+        .continue_or_break(
+            EvaluateError::InvalidDateTimeParts(format!(
+                "{year}-{month}-{day} {hour}:{minute}:{second}"
+            ))
+            .into(),
+        )?;
+
+    Continue(Evaluated::Value(Cow::Owned(Value::Timestamp(timestamp))))
+}
+
+fn ymd_to_date(year: i64, month: i64, day: i64) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(
+        i32::try_from(year).ok()?,
+        u32::try_from(month).ok()?,
+        u32::try_from(day).ok()?,
+    )
+}
+
 pub fn add_month<'a>(
     name: &str,
     expr: Evaluated<'_>,
@@ -970,6 +1369,70 @@ pub fn calc_distance<'a>(
     ))))
 }
 
+pub fn st_contains<'a>(
+    name: &str,
+    geometry1: Evaluated<'_>,
+    geometry2: Evaluated<'_>,
+) -> ControlFlow<Evaluated<'a>> {
+    let geometry1 = eval_to_geometry(name, geometry1)?;
+    let geometry2 = eval_to_geometry(name, geometry2)?;
+
+    Continue(Evaluated::Value(Cow::Owned(Value::Bool(
+        geometry1.contains(&geometry2),
+    ))))
+}
+
+pub fn st_intersects<'a>(
+    name: &str,
+    geometry1: Evaluated<'_>,
+    geometry2: Evaluated<'_>,
+) -> ControlFlow<Evaluated<'a>> {
+    let geometry1 = eval_to_geometry(name, geometry1)?;
+    let geometry2 = eval_to_geometry(name, geometry2)?;
+
+    Continue(Evaluated::Value(Cow::Owned(Value::Bool(
+        geometry1.intersects(&geometry2),
+    ))))
+}
+
+pub fn st_within<'a>(
+    name: &str,
+    geometry1: Evaluated<'_>,
+    geometry2: Evaluated<'_>,
+) -> ControlFlow<Evaluated<'a>> {
+    let geometry1 = eval_to_geometry(name, geometry1)?;
+    let geometry2 = eval_to_geometry(name, geometry2)?;
+
+    Continue(Evaluated::Value(Cow::Owned(Value::Bool(
+        geometry1.within(&geometry2),
+    ))))
+}
+
+pub fn st_area<'a>(name: &str, expr: Evaluated<'_>) -> ControlFlow<Evaluated<'a>> {
+    let geometry = eval_to_geometry(name, expr)?;
+
+    Continue(Evaluated::Value(Cow::Owned(Value::F64(geometry.area()))))
+}
+
+pub fn st_length<'a>(name: &str, expr: Evaluated<'_>) -> ControlFlow<Evaluated<'a>> {
+    let geometry = eval_to_geometry(name, expr)?;
+
+    Continue(Evaluated::Value(Cow::Owned(Value::F64(geometry.length()))))
+}
+
+pub fn st_distance_sphere<'a>(
+    name: &str,
+    x: Evaluated<'_>,
+    y: Evaluated<'_>,
+) -> ControlFlow<Evaluated<'a>> {
+    let x = eval_to_point(name, x)?;
+    let y = eval_to_point(name, y)?;
+
+    Continue(Evaluated::Value(Cow::Owned(Value::F64(
+        x.calc_haversine_distance(&y),
+    ))))
+}
+
 pub fn length<'a>(name: &str, expr: Evaluated<'_>) -> ControlFlow<Evaluated<'a>> {
     match expr.try_into().break_if_null()? {
         Value::Str(expr) => Ok(Evaluated::Value(Cow::Owned(Value::U64(
@@ -1,18 +1,19 @@
 use {
     super::{
         alter::{
-            CreateTableOptions, alter_table, create_index, create_table, delete_function,
-            drop_table, insert_function,
+            CreateTableOptions, CreateTriggerOptions, alter_table, create_index, create_table,
+            create_trigger, delete_function, drop_table, drop_trigger, insert_function,
         },
         delete::delete,
         fetch::fetch,
         insert::insert,
         query, select,
+        trigger::RowTriggers,
         update::Update,
         validate::{ColumnValidation, validate_unique},
     },
     crate::{
-        ast::{BinaryOperator, DataType, Dictionary, Literal, Variable},
+        ast::{BinaryOperator, DataType, Dictionary, Literal, TriggerEvent, Variable},
         data::{Key, Row, SCHEMALESS_DOC_COLUMN, Schema, Value},
         plan::{
             DictionarySourcePlan, ExprPlan, FilterInputPlan, FilterPlan, ProjectInputPlan,
@@ -55,6 +56,7 @@ pub enum Payload {
     Update(usize),
     DropTable(usize),
     DropFunction,
+    DropTrigger,
     AlterTable,
     CreateIndex,
     DropIndex,
@@ -132,7 +134,7 @@ pub fn execute<T: GStore + GStoreMut>(
     }
 }
 
-fn execute_inner<T: GStore + GStoreMut>(
+pub(super) fn execute_inner<T: GStore + GStoreMut>(
     storage: &mut T,
     statement: &StatementPlan,
 ) -> Result<Payload> {
@@ -147,6 +149,7 @@ fn execute_inner<T: GStore + GStoreMut>(
             engine,
             foreign_keys,
             comment,
+            ttl_column,
         } => {
             let options = CreateTableOptions {
                 target_table_name: name,
@@ -156,6 +159,7 @@ fn execute_inner<T: GStore + GStoreMut>(
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
             };
 
             create_table(storage, options).map(|()| Payload::Create)
@@ -209,13 +213,18 @@ fn execute_inner<T: GStore + GStoreMut>(
                 .map(|assignment| assignment.id.clone())
                 .collect();
 
+            let triggers = RowTriggers::fetch(storage, table_name, TriggerEvent::Update)?;
             let update = Update::new(storage, table_name, assignments, column_defs.as_deref())?;
 
             let foreign_keys = Rc::new(foreign_keys);
 
+            let mut old_rows = Vec::new();
             let rows = fetch(storage, table_name, all_columns, selection.as_ref())?
                 .map(|item| {
                     let (key, row) = item?;
+                    if !triggers.is_empty() {
+                        old_rows.push(row.clone());
+                    }
                     let row = update.apply(row, foreign_keys.as_ref())?;
 
                     Ok((key, row))
@@ -230,15 +239,28 @@ fn execute_inner<T: GStore + GStoreMut>(
                 validate_unique(storage, table_name, &column_validation, rows)?;
             }
 
+            let new_rows = if triggers.is_empty() {
+                Vec::new()
+            } else {
+                rows.iter().map(|(_, row)| row.clone()).collect()
+            };
+            for (old, new) in old_rows.iter().zip(&new_rows) {
+                triggers.fire_before(storage, Some(old), Some(new))?;
+            }
+
             let num_rows = rows.len();
             let rows = rows
                 .into_iter()
                 .map(|(key, row)| (key, row.into_values()))
                 .collect();
 
-            storage
-                .insert_data(table_name, rows)
-                .map(|()| Payload::Update(num_rows))
+            storage.insert_data(table_name, rows)?;
+
+            for (old, new) in old_rows.iter().zip(&new_rows) {
+                triggers.fire_after(storage, Some(old), Some(new))?;
+            }
+
+            Ok(Payload::Update(num_rows))
         }
         StatementPlan::Delete {
             table_name,
@@ -345,5 +367,29 @@ fn execute_inner<T: GStore + GStoreMut>(
         StatementPlan::DropFunction { if_exists, names } => {
             delete_function(storage, names, *if_exists).map(|()| Payload::DropFunction)
         }
+        StatementPlan::CreateTrigger {
+            or_replace,
+            name,
+            table_name,
+            timing,
+            events,
+            body,
+        } => {
+            let options = CreateTriggerOptions {
+                name,
+                table_name,
+                timing: *timing,
+                events,
+                body,
+                or_replace: *or_replace,
+            };
+
+            create_trigger(storage, options).map(|()| Payload::Create)
+        }
+        StatementPlan::DropTrigger {
+            if_exists,
+            name,
+            table_name,
+        } => drop_trigger(storage, table_name, name, *if_exists).map(|()| Payload::DropTrigger),
     }
 }
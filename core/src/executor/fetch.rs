@@ -1,11 +1,12 @@
 use {
     super::{context::RowContext, filter::check_expr},
     crate::{
-        data::{Key, Row, SCHEMALESS_DOC_COLUMN},
+        data::{Key, Row, RowExpiry, SCHEMALESS_DOC_COLUMN},
         plan::ExprPlan,
         result::Result,
-        store::GStore,
+        store::{GStore, Store},
     },
+    chrono::Utc,
     serde::Serialize,
     std::{borrow::Cow, fmt::Debug, rc::Rc},
     thiserror::Error as ThisError,
@@ -25,11 +26,15 @@ pub fn fetch<'a, T: GStore>(
     columns: Rc<[String]>,
     where_clause: Option<&'a ExprPlan>,
 ) -> Result<KeyedRows<'a>> {
+    let expiry = fetch_row_expiry(storage, table_name)?;
     let rows = storage.scan_data(table_name)?.filter_map(move |row| {
         let (key, values) = match row {
             Ok(row) => row,
             Err(error) => return Some(Err(error)),
         };
+        if expiry.is_some_and(|expiry| expiry.is_expired(&values)) {
+            return None;
+        }
         let row = Row {
             columns: Rc::clone(&columns),
             values,
@@ -52,6 +57,16 @@ pub fn fetch<'a, T: GStore>(
     Ok(Box::new(rows))
 }
 
+/// Returns how rows of `table_name` expire, checked against the current time.
+///
+/// Rows past their `ttl_column` stay in storage until purged, so every read
+/// path of the executor filters them out with this.
+pub fn fetch_row_expiry<T: Store>(storage: &T, table_name: &str) -> Result<Option<RowExpiry>> {
+    Ok(storage
+        .fetch_schema(table_name)?
+        .and_then(|schema| schema.row_expiry(Utc::now().naive_utc())))
+}
+
 pub fn fetch_columns<T: GStore>(storage: &T, table_name: &str) -> Result<Vec<String>> {
     let columns = storage
         .fetch_schema(table_name)?
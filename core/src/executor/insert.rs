@@ -3,13 +3,18 @@ mod schemaful;
 mod schemaless;
 mod values;
 
-use crate::{
-    data::{Key, Schema, Value},
-    plan::QueryPlan,
-    result::Result,
-    store::{GStore, GStoreMut},
-};
 pub use error::InsertError;
+use {
+    super::trigger::RowTriggers,
+    crate::{
+        ast::TriggerEvent,
+        data::{Key, Row, SCHEMALESS_DOC_COLUMN, Schema, Value},
+        plan::QueryPlan,
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+    std::rc::Rc,
+};
 
 enum RowsData {
     Append(Vec<Vec<Value>>),
@@ -30,6 +35,17 @@ pub fn insert<T: GStore + GStoreMut>(
         .fetch_schema(table_name)?
         .ok_or_else(|| InsertError::TableNotFound(table_name.to_owned()))?;
 
+    let triggers = RowTriggers::fetch(storage, table_name, TriggerEvent::Insert)?;
+    let labels: Rc<[String]> = column_defs.as_deref().map_or_else(
+        || Rc::from(vec![SCHEMALESS_DOC_COLUMN.to_owned()]),
+        |column_defs| {
+            column_defs
+                .iter()
+                .map(|col_def| col_def.name.clone())
+                .collect()
+        },
+    );
+
     let rows = match column_defs {
         Some(column_defs) => schemaful::fetch_rows(
             storage,
@@ -42,7 +58,28 @@ pub fn insert<T: GStore + GStoreMut>(
         None => schemaless::fetch_rows(storage, source).map(RowsData::Append),
     }?;
 
-    match rows {
+    let new_rows = if triggers.is_empty() {
+        Vec::new()
+    } else {
+        let values: Vec<&Vec<Value>> = match &rows {
+            RowsData::Append(rows) => rows.iter().collect(),
+            RowsData::Insert(rows) => rows.iter().map(|(_, values)| values).collect(),
+        };
+
+        values
+            .into_iter()
+            .map(|values| Row {
+                columns: Rc::clone(&labels),
+                values: values.clone(),
+            })
+            .collect()
+    };
+
+    for row in &new_rows {
+        triggers.fire_before(storage, None, Some(row))?;
+    }
+
+    let num_rows = match rows {
         RowsData::Append(rows) => {
             let num_rows = rows.len();
 
@@ -53,5 +90,11 @@ pub fn insert<T: GStore + GStoreMut>(
 
             storage.insert_data(table_name, rows).map(|()| num_rows)
         }
+    }?;
+
+    for row in &new_rows {
+        triggers.fire_after(storage, None, Some(row))?;
     }
+
+    Ok(num_rows)
 }
@@ -5,6 +5,7 @@ use {
         data::{Key, Value},
         executor::{
             evaluate::evaluate_stateless,
+            fetch::fetch_row_expiry,
             query,
             validate::{ColumnValidation, validate_unique},
         },
@@ -216,6 +217,7 @@ fn validate_foreign_key<T: GStore>(
             ..
         } = &foreign_key;
 
+        let expiry = fetch_row_expiry(storage, referenced_table_name)?;
         let target_index = column_defs
             .iter()
             .enumerate()
@@ -237,7 +239,7 @@ fn validate_foreign_key<T: GStore>(
 
             let no_referenced = storage
                 .fetch_data(referenced_table_name, &Key::try_from(value)?)?
-                .is_none();
+                .is_none_or(|values| expiry.is_some_and(|expiry| expiry.is_expired(&values)));
 
             if no_referenced {
                 return Err(InsertError::CannotFindReferencedValue {
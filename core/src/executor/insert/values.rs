@@ -19,7 +19,10 @@ where
     F: FnOnce(&'a ValuesPlan) -> EvaluatedRows<'a>,
 {
     match query {
-        QueryPlan::Project(_) | QueryPlan::SelectOrderBy(_) | QueryPlan::Distinct(_) => Ok(None),
+        QueryPlan::Project(_)
+        | QueryPlan::SelectOrderBy(_)
+        | QueryPlan::Distinct(_)
+        | QueryPlan::Union(_) => Ok(None),
         QueryPlan::Values(plan) => Ok(Some(rows(values(plan)))),
         QueryPlan::ValuesOrderBy(plan) => Ok(Some(execute_order_by(plan, values)?)),
         QueryPlan::Offset(plan) => execute_offset(plan, values),
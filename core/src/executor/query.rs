@@ -10,6 +10,7 @@ mod order_by;
 mod output;
 mod project;
 mod source;
+mod union;
 mod values;
 
 pub use error::QueryError;
@@ -93,5 +94,6 @@ where
         QueryPlan::Distinct(distinct) => distinct::execute(storage, distinct, filter_context),
         QueryPlan::Offset(offset) => offset::execute(storage, offset, filter_context),
         QueryPlan::Limit(limit) => limit::execute(storage, limit, filter_context),
+        QueryPlan::Union(union) => union::execute(storage, union, filter_context),
     }
 }
@@ -17,7 +17,7 @@ use {
         result::Result,
         store::GStore,
     },
-    std::rc::Rc,
+    std::{borrow::Cow, rc::Rc},
 };
 
 pub(super) struct AggregatedRows<'a> {
@@ -36,6 +36,7 @@ where
     let AggregationPlan {
         input,
         group_by,
+        grouping_sets,
         aggregate_slots,
     } = plan;
     let SelectedRows { sources, rows } = match input {
@@ -48,7 +49,11 @@ where
         }
         AggregationInputPlan::Filter(filter) => filter::execute(storage, filter, filter_context)?,
     };
-    let mut state = State::new(storage, aggregate_slots.len(), group_by.is_empty());
+    let grouping_sets = match grouping_sets {
+        Some(grouping_sets) => Cow::Borrowed(grouping_sets.as_slice()),
+        None => Cow::Owned(vec![(0..group_by.len()).collect()]),
+    };
+    let mut state = State::new(storage, aggregate_slots.len(), &grouping_sets);
 
     for context in rows {
         let context = context?;
@@ -59,18 +64,22 @@ where
             )),
             None => Rc::clone(&context),
         };
-        let group = group_by
+        let values = group_by
             .iter()
             .map(|expr| evaluate(storage, Some(&row_filter_context), None, expr)?.try_into())
             .collect::<Result<Vec<Value>>>()?;
-        let group_index = state.apply(group, Rc::clone(&context));
 
-        for (slot, aggregate) in aggregate_slots.iter().enumerate() {
-            state.accumulate(group_index, &row_filter_context, slot, aggregate)?;
+        for (set, indexes) in grouping_sets.iter().enumerate() {
+            let group = indexes.iter().map(|index| values[*index].clone()).collect();
+            let group_index = state.apply(set, group, Rc::clone(&context));
+
+            for (slot, aggregate) in aggregate_slots.iter().enumerate() {
+                state.accumulate(group_index, &row_filter_context, slot, aggregate)?;
+            }
         }
     }
 
-    let rows = state.export(aggregate_slots)?;
+    let rows = state.export(group_by, &grouping_sets, aggregate_slots)?;
 
     Ok(AggregatedRows { sources, rows })
 }
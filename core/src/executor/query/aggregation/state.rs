@@ -1,12 +1,16 @@
 use {
+    super::super::{QueryError, order_by::sort_by},
     crate::{
         ast::DataType,
-        data::Value,
+        data::{Key, Value},
         executor::{
             context::{AggregateContext, AggregateValues, RowContext},
             evaluate::evaluate,
+            filter::check_expr,
+        },
+        plan::{
+            AggregateExprPlan, AggregateFunctionPlan, CountArgExprPlan, ExprPlan, OrderByExprPlan,
         },
-        plan::{AggregateExprPlan, AggregateFunctionPlan, CountArgExprPlan},
         result::Result,
         store::GStore,
     },
@@ -53,6 +57,52 @@ enum AggrValue {
         count: i64,
         distinct_values: Option<HashSet<Value>>,
     },
+    StringAgg {
+        items: Vec<(SortKeys, String, String)>,
+        distinct_values: Option<HashSet<Value>>,
+    },
+    ArrayAgg {
+        items: Vec<(SortKeys, Value)>,
+        distinct_values: Option<HashSet<Value>>,
+    },
+    BoolAnd {
+        value: bool,
+        distinct_values: Option<HashSet<Value>>,
+    },
+    BoolOr {
+        value: bool,
+        distinct_values: Option<HashSet<Value>>,
+    },
+    Median {
+        values: Vec<f64>,
+        distinct_values: Option<HashSet<Value>>,
+    },
+    PercentileCont {
+        fraction: f64,
+        asc: bool,
+        values: Vec<f64>,
+        distinct_values: Option<HashSet<Value>>,
+    },
+    PercentileDisc {
+        fraction: f64,
+        asc: bool,
+        values: Vec<Value>,
+        distinct_values: Option<HashSet<Value>>,
+    },
+    Mode {
+        counts: HashMap<Value, i64>,
+        distinct_values: Option<HashSet<Value>>,
+    },
+}
+
+type SortKeys = Vec<(Key, Option<bool>)>;
+
+/// Per-row inputs an aggregate needs besides its main argument.
+#[derive(Default)]
+struct AggrArgs {
+    separator: Option<Value>,
+    fraction: Option<Value>,
+    sort_keys: SortKeys,
 }
 
 impl AggrValue {
@@ -70,7 +120,31 @@ impl AggrValue {
         }
     }
 
-    fn new(aggregate: &AggregateExprPlan, value: &Value) -> Result<Self> {
+    fn bool_value(name: &str, value: &Value) -> Result<bool> {
+        match value {
+            Value::Bool(value) => Ok(*value),
+            _ => Err(QueryError::AggregateRequiresBooleanValue(name.to_owned()).into()),
+        }
+    }
+
+    fn fraction_value(fraction: Option<&Value>) -> Result<f64> {
+        let fraction = fraction.map_or(Ok(f64::NAN), f64::try_from)?;
+
+        if (0.0..=1.0).contains(&fraction) {
+            Ok(fraction)
+        } else {
+            Err(QueryError::PercentileFractionOutOfRange(fraction.to_string()).into())
+        }
+    }
+
+    fn accumulated(mut self, value: &Value, args: AggrArgs) -> Result<Self> {
+        self.accumulate(value, args)?;
+
+        Ok(self)
+    }
+
+    fn new(aggregate: &AggregateExprPlan, value: &Value, args: AggrArgs) -> Result<Self> {
+        let empty_distinct_values = || aggregate.distinct.then(HashSet::new);
         let value = value.clone();
 
         Ok(match &aggregate.func {
@@ -175,10 +249,57 @@ impl AggrValue {
                     distinct_values,
                 }
             }
+            AggregateFunctionPlan::StringAgg { .. } => Self::StringAgg {
+                items: Vec::new(),
+                distinct_values: empty_distinct_values(),
+            }
+            .accumulated(&value, args)?,
+            AggregateFunctionPlan::ArrayAgg { .. } => Self::ArrayAgg {
+                items: Vec::new(),
+                distinct_values: empty_distinct_values(),
+            }
+            .accumulated(&value, args)?,
+            AggregateFunctionPlan::BoolAnd(_) => Self::BoolAnd {
+                value: true,
+                distinct_values: empty_distinct_values(),
+            }
+            .accumulated(&value, args)?,
+            AggregateFunctionPlan::BoolOr(_) => Self::BoolOr {
+                value: false,
+                distinct_values: empty_distinct_values(),
+            }
+            .accumulated(&value, args)?,
+            AggregateFunctionPlan::Median(_) => Self::Median {
+                values: Vec::new(),
+                distinct_values: empty_distinct_values(),
+            }
+            .accumulated(&value, args)?,
+            AggregateFunctionPlan::PercentileCont { order_by, .. } => Self::PercentileCont {
+                fraction: Self::fraction_value(args.fraction.as_ref())?,
+                asc: order_by.asc.unwrap_or(true),
+                values: Vec::new(),
+                distinct_values: empty_distinct_values(),
+            }
+            .accumulated(&value, args)?,
+            AggregateFunctionPlan::PercentileDisc { order_by, .. } => Self::PercentileDisc {
+                fraction: Self::fraction_value(args.fraction.as_ref())?,
+                asc: order_by.asc.unwrap_or(true),
+                values: Vec::new(),
+                distinct_values: empty_distinct_values(),
+            }
+            .accumulated(&value, args)?,
+            AggregateFunctionPlan::Mode(_) => Self::Mode {
+                counts: HashMap::new(),
+                distinct_values: empty_distinct_values(),
+            }
+            .accumulated(&value, args)?,
+            AggregateFunctionPlan::Grouping(_) => {
+                unreachable!("GROUPING is resolved from grouping sets on export")
+            }
         })
     }
 
-    fn accumulate(&mut self, new_value: &Value) -> Result<bool> {
+    fn accumulate(&mut self, new_value: &Value, args: AggrArgs) -> Result<bool> {
         match self {
             Self::Count {
                 wildcard,
@@ -269,6 +390,99 @@ impl AggrValue {
                 *count += 1;
                 Ok(true)
             }
+            Self::StringAgg {
+                items,
+                distinct_values,
+            } => {
+                if !Self::track_distinct(distinct_values, new_value) {
+                    return Ok(false);
+                }
+
+                let string_value = |value: Option<&Value>| match value {
+                    Some(Value::Str(value)) => Ok(value.clone()),
+                    None | Some(Value::Null) => Ok(String::new()),
+                    Some(_) => Err(QueryError::AggregateRequiresStringValue(
+                        "STRING_AGG".to_owned(),
+                    )),
+                };
+                let value = string_value(Some(new_value))?;
+                let separator = string_value(args.separator.as_ref())?;
+
+                items.push((args.sort_keys, separator, value));
+                Ok(true)
+            }
+            Self::ArrayAgg {
+                items,
+                distinct_values,
+            } => {
+                if !Self::track_distinct(distinct_values, new_value) {
+                    return Ok(false);
+                }
+
+                items.push((args.sort_keys, new_value.clone()));
+                Ok(true)
+            }
+            Self::BoolAnd {
+                value,
+                distinct_values,
+            } => {
+                if !Self::track_distinct(distinct_values, new_value) {
+                    return Ok(false);
+                }
+
+                *value &= Self::bool_value("BOOL_AND", new_value)?;
+                Ok(true)
+            }
+            Self::BoolOr {
+                value,
+                distinct_values,
+            } => {
+                if !Self::track_distinct(distinct_values, new_value) {
+                    return Ok(false);
+                }
+
+                *value |= Self::bool_value("BOOL_OR", new_value)?;
+                Ok(true)
+            }
+            Self::Median {
+                values,
+                distinct_values,
+            }
+            | Self::PercentileCont {
+                values,
+                distinct_values,
+                ..
+            } => {
+                if !Self::track_distinct(distinct_values, new_value) {
+                    return Ok(false);
+                }
+
+                values.push(f64::try_from(new_value)?);
+                Ok(true)
+            }
+            Self::PercentileDisc {
+                values,
+                distinct_values,
+                ..
+            } => {
+                if !Self::track_distinct(distinct_values, new_value) {
+                    return Ok(false);
+                }
+
+                values.push(new_value.clone());
+                Ok(true)
+            }
+            Self::Mode {
+                counts,
+                distinct_values,
+            } => {
+                if !Self::track_distinct(distinct_values, new_value) {
+                    return Ok(false);
+                }
+
+                *counts.entry(new_value.clone()).or_default() += 1;
+                Ok(true)
+            }
         }
     }
 
@@ -303,18 +517,84 @@ impl AggrValue {
                 count,
                 ..
             } => variance(sum_square, sum, count)?.sqrt(),
+            Self::StringAgg { mut items, .. } => {
+                items.sort_by(|(keys_a, ..), (keys_b, ..)| sort_by(keys_a, keys_b));
+
+                let mut items = items.into_iter();
+                let mut joined = items.next().map(|(_, _, value)| value).unwrap_or_default();
+                for (_, separator, value) in items {
+                    joined.push_str(&separator);
+                    joined.push_str(&value);
+                }
+
+                Ok(Value::Str(joined))
+            }
+            Self::ArrayAgg { mut items, .. } => {
+                items.sort_by(|(keys_a, _), (keys_b, _)| sort_by(keys_a, keys_b));
+
+                Ok(Value::List(
+                    items.into_iter().map(|(_, value)| value).collect(),
+                ))
+            }
+            Self::BoolAnd { value, .. } | Self::BoolOr { value, .. } => Ok(Value::Bool(value)),
+            Self::Median { values, .. } => Ok(percentile_cont(values, 0.5, true)),
+            Self::PercentileCont {
+                fraction,
+                asc,
+                values,
+                ..
+            } => Ok(percentile_cont(values, fraction, asc)),
+            Self::PercentileDisc {
+                fraction,
+                asc,
+                mut values,
+                ..
+            } => {
+                values.sort_by(|a, b| a.evaluate_cmp(b).unwrap_or(Ordering::Equal));
+                if !asc {
+                    values.reverse();
+                }
+
+                let index = ((fraction * values.len() as f64).ceil() as usize).saturating_sub(1);
+
+                Ok(values.swap_remove(index))
+            }
+            Self::Mode { counts, .. } => Ok(counts
+                .into_iter()
+                .max_by(|(value_a, count_a), (value_b, count_b)| {
+                    count_a
+                        .cmp(count_b)
+                        .then_with(|| value_b.evaluate_cmp(value_a).unwrap_or(Ordering::Equal))
+                })
+                .map_or(Value::Null, |(value, _)| value)),
         }
     }
 }
 
+/// Linearly interpolates the `fraction` percentile of non-empty `values`.
+fn percentile_cont(mut values: Vec<f64>, fraction: f64, asc: bool) -> Value {
+    values.sort_by(f64::total_cmp);
+    if !asc {
+        values.reverse();
+    }
+
+    let position = fraction * (values.len() - 1) as f64;
+    let lower = values[position.floor() as usize];
+    let upper = values[position.ceil() as usize];
+
+    Value::F64(lower + (upper - lower) * position.fract())
+}
+
 struct GroupState<'a> {
+    set: usize,
     representative: Option<Rc<RowContext<'a>>>,
     values: Vec<Option<AggrValue>>,
 }
 
 impl<'a> GroupState<'a> {
-    fn new(slot_count: usize, representative: Option<Rc<RowContext<'a>>>) -> Self {
+    fn new(set: usize, slot_count: usize, representative: Option<Rc<RowContext<'a>>>) -> Self {
         Self {
+            set,
             representative,
             values: vec![None; slot_count],
         }
@@ -325,17 +605,21 @@ pub struct State<'a, T: GStore> {
     storage: &'a T,
     slot_count: usize,
     groups: Vec<GroupState<'a>>,
-    group_indexes: HashMap<Vec<Value>, usize>,
+    group_indexes: HashMap<(usize, Vec<Value>), usize>,
 }
 
 impl<'a, T: GStore> State<'a, T> {
-    pub fn new(storage: &'a T, slot_count: usize, global: bool) -> Self {
+    /// Creates the aggregation state for the given grouping sets. Sets without any
+    /// expression always produce a row, even when the input is empty.
+    pub fn new(storage: &'a T, slot_count: usize, grouping_sets: &[Vec<usize>]) -> Self {
         let mut groups = Vec::new();
         let mut group_indexes = HashMap::new();
 
-        if global {
-            groups.push(GroupState::new(slot_count, None));
-            group_indexes.insert(Vec::new(), 0);
+        for (set, indexes) in grouping_sets.iter().enumerate() {
+            if indexes.is_empty() {
+                group_indexes.insert((set, Vec::new()), groups.len());
+                groups.push(GroupState::new(set, slot_count, None));
+            }
         }
 
         Self {
@@ -346,7 +630,8 @@ impl<'a, T: GStore> State<'a, T> {
         }
     }
 
-    pub fn apply(&mut self, group: Vec<Value>, context: Rc<RowContext<'a>>) -> usize {
+    pub fn apply(&mut self, set: usize, group: Vec<Value>, context: Rc<RowContext<'a>>) -> usize {
+        let group = (set, group);
         if let Some(index) = self.group_indexes.get(&group).copied() {
             if self.groups[index].representative.is_none() {
                 self.groups[index].representative = Some(context);
@@ -356,8 +641,11 @@ impl<'a, T: GStore> State<'a, T> {
         }
 
         let index = self.groups.len();
-        self.groups
-            .push(GroupState::new(self.slot_count, Some(Rc::clone(&context))));
+        self.groups.push(GroupState::new(
+            set,
+            self.slot_count,
+            Some(Rc::clone(&context)),
+        ));
         self.group_indexes.insert(group, index);
 
         index
@@ -370,15 +658,38 @@ impl<'a, T: GStore> State<'a, T> {
         slot: usize,
         aggregate: &AggregateExprPlan,
     ) -> Result<()> {
-        let value = match &aggregate.func {
+        if let Some(filter) = &aggregate.filter
+            && !check_expr(self.storage, Some(filter_context), None, filter)?
+        {
+            return Ok(());
+        }
+
+        let evaluate_value = |expr: &ExprPlan| -> Result<Value> {
+            evaluate(self.storage, Some(filter_context), None, expr)?.try_into()
+        };
+        let sort_keys = |order_by: &[OrderByExprPlan]| -> Result<SortKeys> {
+            order_by
+                .iter()
+                .map(|OrderByExprPlan { expr, asc }| {
+                    evaluate_value(expr)
+                        .and_then(Key::try_from)
+                        .map(|key| (key, *asc))
+                })
+                .collect()
+        };
+
+        let (value, args) = match &aggregate.func {
+            AggregateFunctionPlan::Grouping(_) => return Ok(()),
             AggregateFunctionPlan::Count(CountArgExprPlan::Wildcard) => {
-                if aggregate.distinct {
+                let value = if aggregate.distinct {
                     let entries = filter_context.get_all_entries();
                     let values: Vec<Value> = entries.into_iter().map(|(_, value)| value).collect();
                     Value::List(values)
                 } else {
                     Value::Null
-                }
+                };
+
+                (value, AggrArgs::default())
             }
             AggregateFunctionPlan::Count(CountArgExprPlan::Expr(expr))
             | AggregateFunctionPlan::Sum(expr)
@@ -386,15 +697,49 @@ impl<'a, T: GStore> State<'a, T> {
             | AggregateFunctionPlan::Max(expr)
             | AggregateFunctionPlan::Avg(expr)
             | AggregateFunctionPlan::Variance(expr)
-            | AggregateFunctionPlan::Stdev(expr) => {
-                evaluate(self.storage, Some(filter_context), None, expr)?.try_into()?
-            }
+            | AggregateFunctionPlan::Stdev(expr)
+            | AggregateFunctionPlan::BoolAnd(expr)
+            | AggregateFunctionPlan::BoolOr(expr)
+            | AggregateFunctionPlan::Median(expr)
+            | AggregateFunctionPlan::Mode(expr) => (evaluate_value(expr)?, AggrArgs::default()),
+            AggregateFunctionPlan::StringAgg {
+                expr,
+                separator,
+                order_by,
+            } => (
+                evaluate_value(expr)?,
+                AggrArgs {
+                    separator: Some(evaluate_value(separator)?),
+                    sort_keys: sort_keys(order_by)?,
+                    ..AggrArgs::default()
+                },
+            ),
+            AggregateFunctionPlan::ArrayAgg { expr, order_by } => (
+                evaluate_value(expr)?,
+                AggrArgs {
+                    sort_keys: sort_keys(order_by)?,
+                    ..AggrArgs::default()
+                },
+            ),
+            AggregateFunctionPlan::PercentileCont { fraction, order_by }
+            | AggregateFunctionPlan::PercentileDisc { fraction, order_by } => (
+                evaluate_value(&order_by.expr)?,
+                AggrArgs {
+                    fraction: Some(evaluate_value(fraction)?),
+                    ..AggrArgs::default()
+                },
+            ),
         };
 
         // SQL eliminates NULL before applying a set function, so every aggregate but
-        // COUNT ignores NULL inputs entirely. Groups that see nothing but NULL keep an
-        // empty slot and fall back to `empty_value` on export.
-        if value.is_null() && !matches!(aggregate.func, AggregateFunctionPlan::Count(_)) {
+        // COUNT and ARRAY_AGG ignores NULL inputs entirely. Groups that see nothing but
+        // NULL keep an empty slot and fall back to `empty_value` on export.
+        if value.is_null()
+            && !matches!(
+                aggregate.func,
+                AggregateFunctionPlan::Count(_) | AggregateFunctionPlan::ArrayAgg { .. }
+            )
+        {
             return Ok(());
         }
 
@@ -404,39 +749,61 @@ impl<'a, T: GStore> State<'a, T> {
             .expect("group index must exist");
         match group.values[slot].as_mut() {
             Some(aggr_value) => {
-                aggr_value.accumulate(&value)?;
+                aggr_value.accumulate(&value, args)?;
             }
             None => {
-                group.values[slot] = Some(AggrValue::new(aggregate, &value)?);
+                group.values[slot] = Some(AggrValue::new(aggregate, &value, args)?);
             }
         }
 
         Ok(())
     }
 
+    /// Exports one row per group, ordered by grouping set so detail rows come before
+    /// the subtotal rows of the sets that follow.
     pub fn export(
         self,
+        group_by: &[ExprPlan],
+        grouping_sets: &[Vec<usize>],
         aggregate_slots: &[AggregateExprPlan],
     ) -> Result<Vec<AggregateContext<'a>>> {
-        let groups = self.groups;
+        let mut groups = self.groups;
+        groups.sort_by_key(|group| group.set);
+
+        let rolled_up = grouping_sets
+            .iter()
+            .map(|indexes| {
+                group_by
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| !indexes.contains(index))
+                    .map(|(_, expr)| expr.clone())
+                    .collect::<Rc<[ExprPlan]>>()
+            })
+            .collect::<Vec<_>>();
 
         groups
             .into_iter()
             .map(|group| {
-                let values = if aggregate_slots.is_empty() {
+                let rolled_up = &rolled_up[group.set];
+                let values = if aggregate_slots.is_empty() && rolled_up.is_empty() {
                     None
                 } else {
                     let values = group
                         .values
                         .into_iter()
                         .zip(aggregate_slots.iter())
-                        .map(|(value, aggregate)| match value {
-                            Some(value) => value.export(),
-                            None => Ok(empty_value(aggregate)),
+                        .map(|(value, aggregate)| match (value, &aggregate.func) {
+                            (_, AggregateFunctionPlan::Grouping(exprs)) => {
+                                grouping_value(exprs, group_by, &grouping_sets[group.set])
+                            }
+                            (Some(value), _) => value.export(),
+                            (None, _) => Ok(empty_value(aggregate)),
                         })
                         .collect::<Result<Vec<_>>>()?;
+                    let values = AggregateValues::new(values).with_rolled_up(Rc::clone(rolled_up));
 
-                    Some(Rc::new(AggregateValues::new(values)))
+                    Some(Rc::new(values))
                 };
 
                 Ok(AggregateContext {
@@ -448,6 +815,22 @@ impl<'a, T: GStore> State<'a, T> {
     }
 }
 
+/// Computes `GROUPING(args...)`: a bit mask with one bit per argument, most significant
+/// first, set when the argument is rolled up in the current grouping set.
+fn grouping_value(exprs: &[ExprPlan], group_by: &[ExprPlan], set: &[usize]) -> Result<Value> {
+    exprs
+        .iter()
+        .try_fold(0_i64, |mask, expr| {
+            let index = group_by
+                .iter()
+                .position(|group_by| group_by == expr)
+                .ok_or(QueryError::GroupingArgumentNotInGroupBy)?;
+
+            Ok((mask << 1) | i64::from(!set.contains(&index)))
+        })
+        .map(Value::I64)
+}
+
 fn empty_value(aggregate: &AggregateExprPlan) -> Value {
     match &aggregate.func {
         AggregateFunctionPlan::Count(_) => Value::I64(0),
@@ -456,6 +839,15 @@ fn empty_value(aggregate: &AggregateExprPlan) -> Value {
         | AggregateFunctionPlan::Max(_)
         | AggregateFunctionPlan::Avg(_)
         | AggregateFunctionPlan::Variance(_)
-        | AggregateFunctionPlan::Stdev(_) => Value::Null,
+        | AggregateFunctionPlan::Stdev(_)
+        | AggregateFunctionPlan::StringAgg { .. }
+        | AggregateFunctionPlan::ArrayAgg { .. }
+        | AggregateFunctionPlan::BoolAnd(_)
+        | AggregateFunctionPlan::BoolOr(_)
+        | AggregateFunctionPlan::Median(_)
+        | AggregateFunctionPlan::PercentileCont { .. }
+        | AggregateFunctionPlan::PercentileDisc { .. }
+        | AggregateFunctionPlan::Mode(_)
+        | AggregateFunctionPlan::Grouping(_) => Value::Null,
     }
 }
@@ -17,6 +17,15 @@ pub enum QueryError {
     #[error("SERIES has invalid size: {0}")]
     InvalidSeriesSize(i64),
 
+    #[error("GENERATE_SERIES step must not be zero")]
+    GenerateSeriesZeroStep,
+
+    #[error("GENERATE_SERIES over dates or timestamps requires an interval step")]
+    GenerateSeriesIntervalStepRequired,
+
+    #[error("GENERATE_SERIES requires integer, date or timestamp bounds: {0}")]
+    GenerateSeriesUnsupportedBounds(String),
+
     #[error("table '{0}' has {1} columns available but {2} column aliases specified")]
     TooManyColumnAliases(String, usize, usize),
 
@@ -25,4 +34,19 @@ pub enum QueryError {
 
     #[error("unreachable - table schema missing after source preparation: {0}")]
     UnreachableTableSchemaMissingAfterPreparation(String),
+
+    #[error("{0} requires boolean values")]
+    AggregateRequiresBooleanValue(String),
+
+    #[error("{0} requires string values")]
+    AggregateRequiresStringValue(String),
+
+    #[error("percentile fraction must be between 0 and 1: {0}")]
+    PercentileFractionOutOfRange(String),
+
+    #[error("GROUPING arguments must be GROUP BY expressions")]
+    GroupingArgumentNotInGroupBy,
+
+    #[error("each UNION query must have the same number of columns: {0} and {1}")]
+    UnionColumnCountMismatch(usize, usize),
 }
@@ -47,7 +47,7 @@ where
     })
 }
 
-fn evaluate_count(expr: &ExprPlan) -> Result<usize> {
+pub(super) fn evaluate_count(expr: &ExprPlan) -> Result<usize> {
     let evaluated = evaluate_stateless(None, expr)?;
     let size: usize = Value::try_from(evaluated)?.try_into()?;
 
@@ -26,7 +26,10 @@ pub(crate) fn execute<'a>(
     })
 }
 
-fn sort(rows: Vec<Row>, order_by: &[OrderByExprPlan]) -> Result<Vec<Row>> {
+pub(in crate::executor::query) fn sort(
+    rows: Vec<Row>,
+    order_by: &[OrderByExprPlan],
+) -> Result<Vec<Row>> {
     let mut keyed_rows = Vec::with_capacity(rows.len());
     for row in rows {
         let keys = order_by
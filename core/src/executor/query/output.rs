@@ -24,6 +24,7 @@ pub(crate) fn body(query: &QueryPlan) -> OutputBody<'_> {
             LimitInputPlan::Distinct(distinct) => distinct_body(distinct),
             LimitInputPlan::Offset(offset) => offset_body(offset),
         },
+        QueryPlan::Union(union) => body(&union.left),
     }
 }
 
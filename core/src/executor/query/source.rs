@@ -1,5 +1,6 @@
 mod derived;
 mod dictionary;
+mod generate_series;
 mod series;
 mod table;
 
@@ -29,6 +30,7 @@ pub(super) fn execute<'a, T: GStore>(
         SourcePlan::Table(table) => table::execute(storage, table),
         SourcePlan::Derived(derived) => derived::execute(storage, derived),
         SourcePlan::Series(series) => Ok(series::execute(series)),
+        SourcePlan::GenerateSeries(series) => Ok(generate_series::execute(series)),
         SourcePlan::Dictionary(dictionary) => Ok(dictionary::execute(storage, dictionary)),
     }
 }
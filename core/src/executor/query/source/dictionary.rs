@@ -74,10 +74,21 @@ fn rows<'a, T: GStore>(
             let table_metas = storage
                 .scan_table_meta()?
                 .collect::<Result<BTreeMap<_, _>>>()?;
+            let triggers = storage.fetch_all_triggers()?;
             let rows = schemas.into_iter().flat_map({
                 let columns = Rc::clone(&columns);
 
                 move |schema| {
+                    let trigger_rows = triggers
+                        .iter()
+                        .filter(|trigger| trigger.table_name == schema.table_name)
+                        .map(|trigger| {
+                            BTreeMap::from([
+                                ("OBJECT_NAME".to_owned(), Value::Str(trigger.name.clone())),
+                                ("OBJECT_TYPE".to_owned(), Value::Str("TRIGGER".to_owned())),
+                            ])
+                        })
+                        .collect::<Vec<_>>();
                     let meta = table_metas
                         .iter()
                         .find_map(|(table_name, hash_map)| {
@@ -101,6 +112,7 @@ fn rows<'a, T: GStore>(
 
                     iter::once(table_rows)
                         .chain(index_rows)
+                        .chain(trigger_rows)
                         .map(move |mut hash_map| Row {
                             values: columns
                                 .iter()
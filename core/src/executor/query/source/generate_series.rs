@@ -0,0 +1,181 @@
+use {
+    super::{
+        super::{QueryError, SourceColumns},
+        PreparedSource, SourceRows,
+    },
+    crate::{
+        data::{Interval, Row, Value},
+        executor::{context::RowContext, evaluate::evaluate_stateless},
+        plan::{ExprPlan, GenerateSeriesSourcePlan},
+        result::Result,
+    },
+    chrono::{Duration, Months, NaiveDateTime, NaiveTime},
+    std::{iter, rc::Rc},
+};
+
+const MICROSECONDS_PER_DAY: i64 = 86_400_000_000;
+
+type Values<'a> = Box<dyn Iterator<Item = Value> + 'a>;
+
+pub(super) fn execute<'a>(series: &'a GenerateSeriesSourcePlan) -> PreparedSource<'a> {
+    let output = SourceColumns {
+        alias: &series.alias.name,
+        names: Rc::from(vec!["N".to_owned()]),
+    };
+    let source = SourceColumns {
+        alias: output.alias,
+        names: Rc::clone(&output.names),
+    };
+    let rows = Box::new(move |_: Option<Rc<RowContext<'a>>>| {
+        rows(
+            series,
+            SourceColumns {
+                alias: source.alias,
+                names: Rc::clone(&source.names),
+            },
+        )
+    });
+
+    PreparedSource { output, rows }
+}
+
+fn rows<'a>(
+    series: &'a GenerateSeriesSourcePlan,
+    source: SourceColumns<'a>,
+) -> Result<SourceRows<'a>> {
+    let columns = Rc::clone(&source.names);
+    let start = evaluate(&series.start)?;
+    let end = evaluate(&series.stop)?;
+    let step = series.step.as_ref().map(evaluate).transpose()?;
+
+    let values: Values<'a> = match (start, end, step) {
+        (Value::Null, _, _) | (_, Value::Null, _) | (_, _, Some(Value::Null)) => {
+            Box::new(iter::empty())
+        }
+        (start @ (Value::Date(_) | Value::Timestamp(_)), end, step) => {
+            let Some(Value::Interval(step)) = step else {
+                return Err(QueryError::GenerateSeriesIntervalStepRequired.into());
+            };
+
+            temporal_values(&start, &end, step)?
+        }
+        (start, end, step) => {
+            let step = step.map_or(Ok(1), integer)?;
+
+            integer_values(integer(start)?, integer(end)?, step)?
+        }
+    };
+
+    let rows = values.map(move |value| {
+        Ok(Row {
+            columns: Rc::clone(&columns),
+            values: vec![value],
+        })
+    });
+
+    Ok(SourceRows {
+        source,
+        rows: Box::new(rows),
+    })
+}
+
+fn evaluate(expr: &ExprPlan) -> Result<Value> {
+    evaluate_stateless(None, expr)?.try_into()
+}
+
+fn integer(value: Value) -> Result<i64> {
+    match value {
+        Value::I8(_)
+        | Value::I16(_)
+        | Value::I32(_)
+        | Value::I64(_)
+        | Value::I128(_)
+        | Value::U8(_)
+        | Value::U16(_)
+        | Value::U32(_)
+        | Value::U64(_)
+        | Value::U128(_) => Ok(value.try_into()?),
+        _ => Err(QueryError::GenerateSeriesUnsupportedBounds(format!("{value:?}")).into()),
+    }
+}
+
+fn timestamp(value: &Value) -> Result<NaiveDateTime> {
+    match value {
+        Value::Date(date) => Ok(date.and_time(NaiveTime::MIN)),
+        Value::Timestamp(timestamp) => Ok(*timestamp),
+        _ => Err(QueryError::GenerateSeriesUnsupportedBounds(format!("{value:?}")).into()),
+    }
+}
+
+fn integer_values<'a>(start: i64, end: i64, step: i64) -> Result<Values<'a>> {
+    if step == 0 {
+        return Err(QueryError::GenerateSeriesZeroStep.into());
+    }
+
+    let values = iter::successors(Some(start), move |value| value.checked_add(step))
+        .take_while(move |value| {
+            if step > 0 {
+                *value <= end
+            } else {
+                *value >= end
+            }
+        })
+        .map(Value::I64);
+
+    Ok(Box::new(values))
+}
+
+/// Produces `start + step * n` for `n = 0, 1, ..`, so month steps keep the
+/// original day of month instead of drifting after a clamped month end.
+/// Dates stay dates as long as the step is a whole number of days or months.
+fn temporal_values<'a>(start: &Value, end: &Value, step: Interval) -> Result<Values<'a>> {
+    let (forward, whole_days) = match step {
+        Interval::Month(0) | Interval::Microsecond(0) => {
+            return Err(QueryError::GenerateSeriesZeroStep.into());
+        }
+        Interval::Month(months) => (months > 0, true),
+        Interval::Microsecond(micros) => (micros > 0, micros % MICROSECONDS_PER_DAY == 0),
+    };
+    let as_date = whole_days && matches!(start, Value::Date(_));
+    let start = timestamp(start)?;
+    let end = timestamp(end)?;
+
+    let values = (0_i64..)
+        .map_while(move |n| nth_timestamp(start, step, n))
+        .take_while(move |value| {
+            if forward {
+                *value <= end
+            } else {
+                *value >= end
+            }
+        })
+        .map(move |value| {
+            if as_date {
+                Value::Date(value.date())
+            } else {
+                Value::Timestamp(value)
+            }
+        });
+
+    Ok(Box::new(values))
+}
+
+fn nth_timestamp(start: NaiveDateTime, step: Interval, n: i64) -> Option<NaiveDateTime> {
+    match step {
+        Interval::Month(months) => {
+            let months = i64::from(months).checked_mul(n)?;
+            let offset = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+
+            if months >= 0 {
+                start.checked_add_months(offset)
+            } else {
+                start.checked_sub_months(offset)
+            }
+        }
+        Interval::Microsecond(micros) => {
+            let micros = micros.checked_mul(n)?;
+
+            start.checked_add_signed(Duration::microseconds(micros))
+        }
+    }
+}
@@ -4,11 +4,15 @@ use {
         PreparedSource, SourceRows,
     },
     crate::{
-        data::{Key, Row},
-        executor::{context::RowContext, evaluate::evaluate, fetch::fetch_columns},
+        data::{Key, Row, RowExpiry},
+        executor::{
+            context::RowContext,
+            evaluate::evaluate,
+            fetch::{fetch_columns, fetch_row_expiry},
+        },
         plan::{TableAccessPlan, TableSourcePlan},
         result::Result,
-        store::GStore,
+        store::{GStore, ScanPredicate},
     },
     std::{iter, rc::Rc},
 };
@@ -18,6 +22,7 @@ pub(super) fn execute<'a, T: GStore>(
     table: &'a TableSourcePlan,
 ) -> Result<PreparedSource<'a>> {
     let names = fetch_columns(storage, &table.name)?;
+    let expiry = fetch_row_expiry(storage, &table.name)?;
     let names = match &table.alias {
         None => names,
         Some(alias) if alias.columns.len() > names.len() => {
@@ -56,6 +61,7 @@ pub(super) fn execute<'a, T: GStore>(
                 names: Rc::clone(&source.names),
             },
             evaluation_context.as_ref(),
+            expiry,
         )
     });
 
@@ -67,6 +73,7 @@ fn rows<'a, T: GStore>(
     table: &'a TableSourcePlan,
     source: SourceColumns<'a>,
     evaluation_context: Option<&Rc<RowContext<'a>>>,
+    expiry: Option<RowExpiry>,
 ) -> Result<SourceRows<'a>> {
     let columns = Rc::clone(&source.names);
     let rows = match &table.access {
@@ -139,6 +146,51 @@ fn rows<'a, T: GStore>(
 
             Box::new(rows)
         }
+        TableAccessPlan::Scan {
+            predicates,
+            columns: needed_columns,
+        } => {
+            let predicates = predicates
+                .iter()
+                .map(|predicate| {
+                    let evaluated = evaluate(storage, None, None, &predicate.expr)?;
+
+                    Ok(ScanPredicate {
+                        column: predicate.column,
+                        operator: predicate.operator.clone(),
+                        value: evaluated.try_into()?,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let needed_columns = needed_columns.as_ref().map(|needed_columns| {
+                needed_columns
+                    .iter()
+                    .copied()
+                    .chain(expiry.map(|expiry| expiry.index))
+                    .collect::<Vec<_>>()
+            });
+            let rows = storage
+                .scan_filtered_data(&table.name, &predicates, needed_columns.as_deref())?
+                .map({
+                    let columns = Rc::clone(&columns);
+
+                    move |row| {
+                        let (_, values) = row?;
+                        Ok(Row {
+                            columns: Rc::clone(&columns),
+                            values,
+                        })
+                    }
+                });
+
+            Box::new(rows)
+        }
+    };
+    let rows = match expiry {
+        Some(expiry) => Box::new(rows.filter(
+            move |row| !matches!(row, Ok(Row { values, .. }) if expiry.is_expired(values)),
+        )),
+        None => rows,
     };
 
     Ok(SourceRows { source, rows })
@@ -0,0 +1,60 @@
+use {
+    super::{LabeledRows, QueryError, QueryIter, execute_query, limit, offset, order_by},
+    crate::{
+        data::Row, executor::context::RowContext, plan::UnionPlan, result::Result, store::GStore,
+    },
+    std::{collections::HashSet, rc::Rc},
+};
+
+pub(super) fn execute<'a, T>(
+    storage: &'a T,
+    plan: &'a UnionPlan,
+    filter_context: Option<Rc<RowContext<'a>>>,
+) -> Result<LabeledRows<'a>>
+where
+    T: GStore,
+{
+    let LabeledRows { labels, rows: left } =
+        execute_query(storage, &plan.left, filter_context.as_ref().map(Rc::clone))?;
+    let LabeledRows {
+        labels: right_labels,
+        rows: right,
+    } = execute_query(storage, &plan.right, filter_context)?;
+
+    if labels.len() != right_labels.len() {
+        return Err(QueryError::UnionColumnCountMismatch(labels.len(), right_labels.len()).into());
+    }
+
+    let columns = Rc::from(labels.clone());
+    let rows = left.chain(right).map(move |row| {
+        row.map(|Row { values, .. }| Row {
+            columns: Rc::clone(&columns),
+            values,
+        })
+    });
+    let mut rows: QueryIter<'a> = if plan.all {
+        Box::new(rows)
+    } else {
+        let mut seen = HashSet::new();
+
+        Box::new(rows.filter(move |row| {
+            row.as_ref()
+                .map_or(true, |row| seen.insert(row.values.clone()))
+        }))
+    };
+
+    if !plan.order_by.is_empty() {
+        let sorted = order_by::values::sort(rows.collect::<Result<Vec<_>>>()?, &plan.order_by)?;
+        rows = Box::new(sorted.into_iter().map(Ok));
+    }
+
+    if let Some(count) = &plan.offset {
+        rows = Box::new(rows.skip(offset::evaluate_count(count)?));
+    }
+
+    if let Some(count) = &plan.limit {
+        rows = Box::new(rows.take(limit::evaluate_count(count)?));
+    }
+
+    Ok(LabeledRows { labels, rows })
+}
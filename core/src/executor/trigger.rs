@@ -0,0 +1,223 @@
+use {
+    super::execute::execute_inner,
+    crate::{
+        ast::{TriggerEvent, TriggerTiming},
+        data::{Row, SCHEMALESS_DOC_COLUMN, Trigger, Value},
+        parse_sql::{TriggerRow, parse_trigger_statement},
+        plan::StatementPlan,
+        planner::{
+            fetch_schema_map, plan_aggregate, plan_hash_join, plan_primary_key, plan_schemaless,
+            validate,
+        },
+        result::Result,
+        store::{GStore, GStoreMut},
+        translate::{IntoParamLiteral, ParamLiteral, translate_with_params},
+    },
+    serde::Serialize,
+    sqlparser::ast::Statement as SqlStatement,
+    std::cell::Cell,
+    thiserror::Error as ThisError,
+};
+
+/// How deeply trigger bodies may fire further triggers, so that a recursive
+/// trigger fails instead of overflowing the stack.
+pub const MAX_TRIGGER_DEPTH: usize = 32;
+
+thread_local! {
+    static TRIGGER_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum TriggerError {
+    #[error("{row} row is not available in {event} trigger: {trigger_name}")]
+    RowNotAvailable {
+        trigger_name: String,
+        event: TriggerEvent,
+        row: String,
+    },
+
+    #[error("column not found in {row} row of trigger {trigger_name}: {column}")]
+    ColumnNotFound {
+        trigger_name: String,
+        row: String,
+        column: String,
+    },
+
+    #[error("trigger {trigger_name} exceeded the nesting limit of {limit}")]
+    DepthLimitExceeded { trigger_name: String, limit: usize },
+}
+
+struct TriggerStatement {
+    trigger_name: String,
+    statement: SqlStatement,
+    references: Vec<(TriggerRow, String)>,
+}
+
+/// Row-level triggers of one table and event, parsed once per statement.
+pub struct RowTriggers {
+    event: TriggerEvent,
+    before: Vec<TriggerStatement>,
+    after: Vec<TriggerStatement>,
+}
+
+impl RowTriggers {
+    pub fn fetch<T: GStore>(storage: &T, table_name: &str, event: TriggerEvent) -> Result<Self> {
+        let mut triggers = storage
+            .fetch_triggers(table_name)?
+            .into_iter()
+            .filter(|trigger| trigger.events.contains(&event))
+            .collect::<Vec<_>>();
+        triggers.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut before = Vec::new();
+        let mut after = Vec::new();
+        for Trigger {
+            name, timing, body, ..
+        } in triggers
+        {
+            let statements = match timing {
+                TriggerTiming::Before => &mut before,
+                TriggerTiming::After => &mut after,
+            };
+
+            for sql in body {
+                let (statement, references) = parse_trigger_statement(sql)?;
+
+                statements.push(TriggerStatement {
+                    trigger_name: name.clone(),
+                    statement,
+                    references,
+                });
+            }
+        }
+
+        Ok(Self {
+            event,
+            before,
+            after,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.before.is_empty() && self.after.is_empty()
+    }
+
+    pub fn fire_before<T: GStore + GStoreMut>(
+        &self,
+        storage: &mut T,
+        old: Option<&Row>,
+        new: Option<&Row>,
+    ) -> Result<()> {
+        self.fire(storage, &self.before, old, new)
+    }
+
+    pub fn fire_after<T: GStore + GStoreMut>(
+        &self,
+        storage: &mut T,
+        old: Option<&Row>,
+        new: Option<&Row>,
+    ) -> Result<()> {
+        self.fire(storage, &self.after, old, new)
+    }
+
+    fn fire<T: GStore + GStoreMut>(
+        &self,
+        storage: &mut T,
+        statements: &[TriggerStatement],
+        old: Option<&Row>,
+        new: Option<&Row>,
+    ) -> Result<()> {
+        for TriggerStatement {
+            trigger_name,
+            statement,
+            references,
+        } in statements
+        {
+            let params = references
+                .iter()
+                .map(|(trigger_row, column)| {
+                    let (row, name) = match trigger_row {
+                        TriggerRow::New => (new, "NEW"),
+                        TriggerRow::Old => (old, "OLD"),
+                    };
+                    let row = row.ok_or_else(|| TriggerError::RowNotAvailable {
+                        trigger_name: trigger_name.clone(),
+                        event: self.event,
+                        row: name.to_owned(),
+                    })?;
+
+                    get_value(row, column)
+                        .map(IntoParamLiteral::into_param_literal)
+                        .ok_or_else(|| {
+                            TriggerError::ColumnNotFound {
+                                trigger_name: trigger_name.clone(),
+                                row: name.to_owned(),
+                                column: column.clone(),
+                            }
+                            .into()
+                        })
+                })
+                .collect::<Result<Vec<ParamLiteral>>>()?;
+
+            let statement = translate_with_params(statement, &params)?;
+            let statement = plan(storage, StatementPlan::from(statement))?;
+
+            let _depth = DepthGuard::enter(trigger_name)?;
+            execute_inner(storage, &statement)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Plans a trigger body statement with the steps of the default
+/// `Planner::plan`, which only need `Store`.
+fn plan<T: GStore>(storage: &T, statement: StatementPlan) -> Result<StatementPlan> {
+    let schema_map = fetch_schema_map(storage, &statement)?;
+    validate(&schema_map, &statement)?;
+
+    let statement = plan_schemaless(&schema_map, statement)?;
+    let statement = plan_primary_key(&schema_map, statement);
+    let statement = plan_hash_join(&schema_map, statement);
+    let statement = plan_aggregate(statement);
+
+    Ok(statement)
+}
+
+/// Counts the trigger bodies running on this thread while it is alive.
+struct DepthGuard;
+
+impl DepthGuard {
+    fn enter(trigger_name: &str) -> Result<Self> {
+        TRIGGER_DEPTH.with(|depth| {
+            if depth.get() >= MAX_TRIGGER_DEPTH {
+                return Err(TriggerError::DepthLimitExceeded {
+                    trigger_name: trigger_name.to_owned(),
+                    limit: MAX_TRIGGER_DEPTH,
+                }
+                .into());
+            }
+
+            depth.set(depth.get() + 1);
+
+            Ok(Self)
+        })
+    }
+}
+
+impl Drop for DepthGuard {
+    fn drop(&mut self) {
+        TRIGGER_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+fn get_value(row: &Row, column: &str) -> Option<Value> {
+    if let Some(value) = row.get_value(column) {
+        return Some(value.clone());
+    }
+
+    match row.get_value(SCHEMALESS_DOC_COLUMN) {
+        Some(Value::Map(values)) => Some(values.get(column).cloned().unwrap_or(Value::Null)),
+        _ => None,
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    super::{context::RowContext, evaluate::evaluate},
+    super::{context::RowContext, evaluate::evaluate, fetch::fetch_row_expiry},
     crate::{
         ast::{ColumnDef, ColumnUniqueOption, ForeignKey},
         data::{Key, Row, Value},
@@ -112,10 +112,13 @@ impl<'a, T: GStore> Update<'a, T> {
                         continue;
                     }
 
+                    let expiry = fetch_row_expiry(self.storage, referenced_table_name)?;
                     let no_referenced = self
                         .storage
                         .fetch_data(referenced_table_name, &Key::try_from(&value)?)?
-                        .is_none();
+                        .is_none_or(|values| {
+                            expiry.is_some_and(|expiry| expiry.is_expired(&values))
+                        });
 
                     if no_referenced {
                         return Err(UpdateError::CannotFindReferencedValue {
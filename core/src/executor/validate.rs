@@ -2,6 +2,7 @@ use {
     crate::{
         ast::{ColumnDef, ColumnUniqueOption},
         data::{Key, Value},
+        executor::fetch::fetch_row_expiry,
         result::Result,
         store::Store,
     },
@@ -115,6 +116,9 @@ pub fn validate_unique<'a, T: Store>(
         ),
     };
 
+    let expiry = fetch_row_expiry(storage, table_name)?;
+    let is_live = |values: &[Value]| !expiry.is_some_and(|expiry| expiry.is_expired(values));
+
     match columns {
         Columns::PrimaryKeyOnly(primary_key_index) => {
             for primary_key in
@@ -122,7 +126,10 @@ pub fn validate_unique<'a, T: Store>(
             {
                 let key = primary_key?;
 
-                if storage.fetch_data(table_name, &key)?.is_some() {
+                if storage
+                    .fetch_data(table_name, &key)?
+                    .is_some_and(|values| is_live(&values))
+                {
                     return Err(ValidateError::DuplicateEntryOnPrimaryKeyField(key).into());
                 }
             }
@@ -137,6 +144,10 @@ pub fn validate_unique<'a, T: Store>(
 
             for row in storage.scan_data(table_name)? {
                 let (_, values) = row?;
+                if !is_live(&values) {
+                    continue;
+                }
+
                 for constraint in &unique_constraints {
                     let col_idx = constraint.column_index;
                     let val = values
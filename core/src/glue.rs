@@ -1,10 +1,10 @@
 use crate::{
     executor::{Payload, execute},
-    parse_sql::parse,
+    parse_sql::parse_statements,
     plan::StatementPlan,
     result::Result,
     store::{GStore, GStoreMut, Planner},
-    translate::{IntoParamLiteral, ParamLiteral, translate_with_params},
+    translate::{IntoParamLiteral, ParamLiteral, translate_parsed_with_params},
 };
 
 #[derive(Debug)]
@@ -29,7 +29,7 @@ impl<T: GStore + GStoreMut + Planner> Glue<T> {
         I: IntoIterator<Item = P>,
         P: IntoParamLiteral,
     {
-        let parsed = parse(sql)?;
+        let parsed = parse_statements(sql)?;
         let params: Vec<ParamLiteral> = params
             .into_iter()
             .map(IntoParamLiteral::into_param_literal)
@@ -37,7 +37,7 @@ impl<T: GStore + GStoreMut + Planner> Glue<T> {
         parsed
             .into_iter()
             .map(|p| {
-                translate_with_params(&p, &params)
+                translate_parsed_with_params(&p, &params)
                     .and_then(|statement| self.storage.plan(statement.into()))
             })
             .collect()
@@ -1,13 +1,14 @@
 #![deny(clippy::str_to_string)]
 
 // re-export
-pub use {chrono, sqlparser};
+pub use {chrono, rust_decimal, sqlparser};
 
 mod glue;
 mod mock;
 mod result;
 
 pub mod ast;
+pub mod backup;
 pub mod data;
 pub mod executor;
 pub mod parse_sql;
@@ -5,7 +5,7 @@ use {
         result::{Error, Result},
         store::{
             AlterTable, CustomFunction, CustomFunctionMut, Index, IndexMut, Metadata, Planner,
-            RowIter, Store, StoreMut, Transaction,
+            RowIter, Store, StoreMut, Transaction, Trigger, TriggerMut,
         },
     },
     std::collections::HashMap,
@@ -35,6 +35,8 @@ pub struct MockStorage {
 impl CustomFunction for MockStorage {}
 
 impl CustomFunctionMut for MockStorage {}
+impl Trigger for MockStorage {}
+impl TriggerMut for MockStorage {}
 
 impl Store for MockStorage {
     fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
@@ -214,6 +216,7 @@ mod tests {
             engine: None,
             foreign_keys: Vec::new(),
             comment: None,
+            ttl_column: None,
         };
         storage.insert_schema(&schema).unwrap();
 
@@ -248,6 +251,7 @@ mod tests {
             engine: None,
             foreign_keys: Vec::new(),
             comment: None,
+            ttl_column: None,
         };
         storage.insert_schema(&schema).unwrap();
 
@@ -3,12 +3,15 @@ use {
     sqlparser::{
         ast::{
             Assignment as SqlAssignment, ColumnDef as SqlColumnDef, DataType as SqlDataType,
-            Expr as SqlExpr, Ident as SqlIdent, OrderByExpr as SqlOrderByExpr, Query as SqlQuery,
-            SelectItem as SqlSelectItem, Statement as SqlStatement,
+            Expr as SqlExpr, Ident as SqlIdent, ObjectName as SqlObjectName,
+            OrderByExpr as SqlOrderByExpr, Query as SqlQuery, SelectItem as SqlSelectItem,
+            Statement as SqlStatement, TriggerEvent as SqlTriggerEvent,
+            TriggerPeriod as SqlTriggerPeriod,
         },
         dialect::PostgreSqlDialect,
-        parser::Parser,
-        tokenizer::Tokenizer,
+        keywords::Keyword,
+        parser::{Parser, ParserError},
+        tokenizer::{Token, Tokenizer},
     },
 };
 
@@ -18,6 +21,179 @@ pub fn parse<Sql: AsRef<str>>(sql: Sql) -> Result<Vec<SqlStatement>> {
     Parser::parse_sql(&DIALECT, sql.as_ref()).map_err(|e| Error::Parser(format!("{e:#?}")))
 }
 
+/// Statement returned by [`parse_statements`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParsedStatement {
+    Sql(Box<SqlStatement>),
+    /// `sqlparser` only knows `EXECUTE FUNCTION` trigger bodies, so
+    /// `CREATE TRIGGER .. FOR EACH ROW BEGIN .. END` is parsed here.
+    CreateTrigger(SqlCreateTrigger),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqlCreateTrigger {
+    pub or_replace: bool,
+    pub name: SqlObjectName,
+    pub period: SqlTriggerPeriod,
+    pub events: Vec<SqlTriggerEvent>,
+    pub table_name: SqlObjectName,
+    pub body: Vec<SqlStatement>,
+}
+
+/// Row a trigger body refers to with `NEW.column` or `OLD.column`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerRow {
+    New,
+    Old,
+}
+
+/// Parses SQL like [`parse`], also accepting `CREATE TRIGGER` with a statement list body.
+pub fn parse_statements<Sql: AsRef<str>>(sql: Sql) -> Result<Vec<ParsedStatement>> {
+    let map_err = |e: ParserError| Error::Parser(format!("{e:#?}"));
+    let mut parser = Parser::new(&DIALECT)
+        .try_with_sql(sql.as_ref())
+        .map_err(map_err)?;
+    let mut statements = Vec::new();
+    let mut expecting_statement_delimiter = false;
+
+    loop {
+        while parser.consume_token(&Token::SemiColon) {
+            expecting_statement_delimiter = false;
+        }
+
+        if parser.peek_token().token == Token::EOF {
+            break;
+        }
+
+        if expecting_statement_delimiter {
+            return parser
+                .expected("end of statement", parser.peek_token())
+                .map_err(map_err);
+        }
+
+        let statement = if is_create_trigger(&parser) {
+            parse_create_trigger(&mut parser).map(ParsedStatement::CreateTrigger)
+        } else {
+            parser
+                .parse_statement()
+                .map(Box::new)
+                .map(ParsedStatement::Sql)
+        };
+
+        statements.push(statement.map_err(map_err)?);
+        expecting_statement_delimiter = true;
+    }
+
+    Ok(statements)
+}
+
+fn is_create_trigger(parser: &Parser) -> bool {
+    let keywords = parser.peek_tokens::<4>().map(|token| match token {
+        Token::Word(word) => word.keyword,
+        _ => Keyword::NoKeyword,
+    });
+
+    matches!(
+        keywords,
+        [Keyword::CREATE, Keyword::TRIGGER, ..]
+            | [
+                Keyword::CREATE,
+                Keyword::OR,
+                Keyword::REPLACE,
+                Keyword::TRIGGER
+            ]
+    )
+}
+
+fn parse_create_trigger(parser: &mut Parser) -> Result<SqlCreateTrigger, ParserError> {
+    parser.expect_keyword(Keyword::CREATE)?;
+    let or_replace = parser.parse_keywords(&[Keyword::OR, Keyword::REPLACE]);
+    parser.expect_keyword(Keyword::TRIGGER)?;
+    let name = parser.parse_object_name(false)?;
+    let period = parser.parse_trigger_period()?;
+    let events = parser.parse_keyword_separated(Keyword::OR, Parser::parse_trigger_event)?;
+    parser.expect_keyword(Keyword::ON)?;
+    let table_name = parser.parse_object_name(false)?;
+    parser.expect_keyword(Keyword::FOR)?;
+    let _ = parser.parse_keyword(Keyword::EACH);
+    parser.expect_keyword(Keyword::ROW)?;
+    parser.expect_keyword(Keyword::BEGIN)?;
+
+    let mut body = Vec::new();
+    loop {
+        while parser.consume_token(&Token::SemiColon) {}
+
+        if parser.parse_keyword(Keyword::END) {
+            break;
+        }
+
+        body.push(parser.parse_statement()?);
+
+        if !parser.consume_token(&Token::SemiColon) {
+            parser.expect_keyword(Keyword::END)?;
+            break;
+        }
+    }
+
+    Ok(SqlCreateTrigger {
+        or_replace,
+        name,
+        period,
+        events,
+        table_name,
+        body,
+    })
+}
+
+/// Parses a stored trigger body statement with every `NEW.column` and
+/// `OLD.column` replaced by a placeholder, returning the referenced columns in
+/// placeholder order.
+pub fn parse_trigger_statement<Sql: AsRef<str>>(
+    sql: Sql,
+) -> Result<(SqlStatement, Vec<(TriggerRow, String)>)> {
+    let tokens = Tokenizer::new(&DIALECT, sql.as_ref())
+        .tokenize()
+        .map_err(|e| Error::Parser(format!("{e:#?}")))?;
+
+    let mut references = Vec::new();
+    let mut rewritten = Vec::with_capacity(tokens.len());
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        let row = match &token {
+            Token::Word(word) if word.quote_style.is_none() => {
+                match word.value.to_uppercase().as_str() {
+                    "NEW" => Some(TriggerRow::New),
+                    "OLD" => Some(TriggerRow::Old),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        match row {
+            Some(row) if tokens.peek() == Some(&Token::Period) => {
+                tokens.next();
+                let Some(Token::Word(column)) = tokens.next() else {
+                    return Err(Error::Parser(format!(
+                        "expected column name after {token}."
+                    )));
+                };
+
+                references.push((row, column.value));
+                rewritten.push(Token::Placeholder(format!("${}", references.len())));
+            }
+            _ => rewritten.push(token),
+        }
+    }
+
+    let statement = Parser::new(&DIALECT)
+        .with_tokens(rewritten)
+        .parse_statement()
+        .map_err(|e| Error::Parser(format!("{e:#?}")))?;
+
+    Ok((statement, references))
+}
+
 macro_rules! generate_parse_fn {
     ($fn_name: ident, $output_type: ty) => {
         pub fn $fn_name<Sql: AsRef<str>>(sql_expr: Sql) -> Result<$output_type> {
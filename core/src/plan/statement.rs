@@ -12,13 +12,13 @@ pub use {
     projection::{ProjectionPlan, SelectItemPlan},
     query::{
         AggregationInputPlan, AggregationPlan, DerivedSourcePlan, DictionarySourcePlan,
-        DistinctInputPlan, DistinctPlan, FilterInputPlan, FilterPlan, HashJoinInputPlan,
-        HashJoinPlan, HavingPlan, IndexPredicatePlan, InnerJoinInputPlan, InnerJoinPlan,
-        JoinConditionInputPlan, JoinConditionPlan, LeftOuterJoinInputPlan, LeftOuterJoinPlan,
-        LimitInputPlan, LimitPlan, NestedLoopJoinInputPlan, NestedLoopJoinPlan, OffsetInputPlan,
-        OffsetPlan, OrderByExprPlan, ProjectInputPlan, ProjectPlan, QueryPlan, SelectOrderByPlan,
-        SeriesSourcePlan, SourcePlan, TableAccessPlan, TableAliasPlan, TableSourcePlan,
-        ValuesOrderByPlan, ValuesPlan,
+        DistinctInputPlan, DistinctPlan, FilterInputPlan, FilterPlan, GenerateSeriesSourcePlan,
+        HashJoinInputPlan, HashJoinPlan, HavingPlan, IndexPredicatePlan, InnerJoinInputPlan,
+        InnerJoinPlan, JoinConditionInputPlan, JoinConditionPlan, LeftOuterJoinInputPlan,
+        LeftOuterJoinPlan, LimitInputPlan, LimitPlan, NestedLoopJoinInputPlan, NestedLoopJoinPlan,
+        OffsetInputPlan, OffsetPlan, OrderByExprPlan, ProjectInputPlan, ProjectPlan, QueryPlan,
+        ScanPredicatePlan, SelectOrderByPlan, SeriesSourcePlan, SourcePlan, TableAccessPlan,
+        TableAliasPlan, TableSourcePlan, UnionPlan, ValuesOrderByPlan, ValuesPlan,
     },
 };
 
@@ -55,6 +55,7 @@ pub enum StatementPlan {
         engine: Option<String>,
         foreign_keys: Vec<ForeignKey>,
         comment: Option<String>,
+        ttl_column: Option<String>,
     },
     CreateFunction {
         or_replace: bool,
@@ -75,6 +76,19 @@ pub enum StatementPlan {
         if_exists: bool,
         names: Vec<String>,
     },
+    CreateTrigger {
+        or_replace: bool,
+        name: String,
+        table_name: String,
+        timing: ast::TriggerTiming,
+        events: Vec<ast::TriggerEvent>,
+        body: Vec<String>,
+    },
+    DropTrigger {
+        if_exists: bool,
+        name: String,
+        table_name: String,
+    },
     CreateIndex {
         name: String,
         table_name: String,
@@ -135,6 +149,7 @@ impl From<ast::Statement> for StatementPlan {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
             } => Self::CreateTable {
                 if_not_exists,
                 name,
@@ -143,6 +158,7 @@ impl From<ast::Statement> for StatementPlan {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
             },
             ast::Statement::CreateFunction {
                 or_replace,
@@ -171,6 +187,30 @@ impl From<ast::Statement> for StatementPlan {
             ast::Statement::DropFunction { if_exists, names } => {
                 Self::DropFunction { if_exists, names }
             }
+            ast::Statement::CreateTrigger {
+                or_replace,
+                name,
+                table_name,
+                timing,
+                events,
+                body,
+            } => Self::CreateTrigger {
+                or_replace,
+                name,
+                table_name,
+                timing,
+                events,
+                body,
+            },
+            ast::Statement::DropTrigger {
+                if_exists,
+                name,
+                table_name,
+            } => Self::DropTrigger {
+                if_exists,
+                name,
+                table_name,
+            },
             ast::Statement::CreateIndex {
                 name,
                 table_name,
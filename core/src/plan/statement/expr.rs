@@ -1,5 +1,5 @@
 use {
-    super::QueryPlan,
+    super::{OrderByExprPlan, QueryPlan},
     crate::{
         ast::{
             self, BinaryOperator, DataType, DateTimeField, Literal, TrimWhereField, UnaryOperator,
@@ -200,6 +200,37 @@ pub enum FunctionExprPlan {
     CurrentTimestamp(),
     Pi(),
     LastDay(ExprPlan),
+    DateTrunc {
+        field: ExprPlan,
+        expr: ExprPlan,
+    },
+    DateBin {
+        stride: ExprPlan,
+        expr: ExprPlan,
+        origin: Option<ExprPlan>,
+    },
+    DateDiff {
+        field: ExprPlan,
+        start: ExprPlan,
+        end: ExprPlan,
+    },
+    Age {
+        left: ExprPlan,
+        right: Option<ExprPlan>,
+    },
+    MakeDate {
+        year: ExprPlan,
+        month: ExprPlan,
+        day: ExprPlan,
+    },
+    MakeTimestamp {
+        year: ExprPlan,
+        month: ExprPlan,
+        day: ExprPlan,
+        hour: ExprPlan,
+        minute: ExprPlan,
+        second: ExprPlan,
+    },
     Ltrim {
         expr: ExprPlan,
         chars: Option<ExprPlan>,
@@ -250,6 +281,32 @@ pub enum FunctionExprPlan {
         sub_expr: ExprPlan,
         start: Option<ExprPlan>,
     },
+    RegexpLike {
+        expr: ExprPlan,
+        pattern: ExprPlan,
+        flags: Option<ExprPlan>,
+    },
+    RegexpReplace {
+        expr: ExprPlan,
+        pattern: ExprPlan,
+        replacement: ExprPlan,
+        flags: Option<ExprPlan>,
+    },
+    RegexpSubstr {
+        expr: ExprPlan,
+        pattern: ExprPlan,
+        flags: Option<ExprPlan>,
+    },
+    RegexpMatches {
+        expr: ExprPlan,
+        pattern: ExprPlan,
+        flags: Option<ExprPlan>,
+    },
+    RegexpSplitToArray {
+        expr: ExprPlan,
+        pattern: ExprPlan,
+        flags: Option<ExprPlan>,
+    },
     Ascii(ExprPlan),
     Chr(ExprPlan),
     Md5(ExprPlan),
@@ -289,6 +346,24 @@ pub enum FunctionExprPlan {
         geometry1: ExprPlan,
         geometry2: ExprPlan,
     },
+    StContains {
+        geometry1: ExprPlan,
+        geometry2: ExprPlan,
+    },
+    StIntersects {
+        geometry1: ExprPlan,
+        geometry2: ExprPlan,
+    },
+    StWithin {
+        geometry1: ExprPlan,
+        geometry2: ExprPlan,
+    },
+    StArea(ExprPlan),
+    StLength(ExprPlan),
+    StDistanceSphere {
+        geometry1: ExprPlan,
+        geometry2: ExprPlan,
+    },
     IsEmpty(ExprPlan),
     Length(ExprPlan),
     Entries(ExprPlan),
@@ -307,6 +382,7 @@ pub enum FunctionExprPlan {
 pub struct AggregateExprPlan {
     pub func: AggregateFunctionPlan,
     pub distinct: bool,
+    pub filter: Option<ExprPlan>,
     pub slot: Option<usize>,
 }
 
@@ -319,6 +395,28 @@ pub enum AggregateFunctionPlan {
     Avg(ExprPlan),
     Variance(ExprPlan),
     Stdev(ExprPlan),
+    StringAgg {
+        expr: ExprPlan,
+        separator: ExprPlan,
+        order_by: Vec<OrderByExprPlan>,
+    },
+    ArrayAgg {
+        expr: ExprPlan,
+        order_by: Vec<OrderByExprPlan>,
+    },
+    BoolAnd(ExprPlan),
+    BoolOr(ExprPlan),
+    Median(ExprPlan),
+    PercentileCont {
+        fraction: ExprPlan,
+        order_by: OrderByExprPlan,
+    },
+    PercentileDisc {
+        fraction: ExprPlan,
+        order_by: OrderByExprPlan,
+    },
+    Mode(ExprPlan),
+    Grouping(Vec<ExprPlan>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -560,6 +658,48 @@ impl From<ast::Function> for FunctionExprPlan {
             ast::Function::CurrentTimestamp() => Self::CurrentTimestamp(),
             ast::Function::Pi() => Self::Pi(),
             ast::Function::LastDay(expr) => Self::LastDay(expr.into()),
+            ast::Function::DateTrunc { field, expr } => Self::DateTrunc {
+                field: field.into(),
+                expr: expr.into(),
+            },
+            ast::Function::DateBin {
+                stride,
+                expr,
+                origin,
+            } => Self::DateBin {
+                stride: stride.into(),
+                expr: expr.into(),
+                origin: origin.map(Into::into),
+            },
+            ast::Function::DateDiff { field, start, end } => Self::DateDiff {
+                field: field.into(),
+                start: start.into(),
+                end: end.into(),
+            },
+            ast::Function::Age { left, right } => Self::Age {
+                left: left.into(),
+                right: right.map(Into::into),
+            },
+            ast::Function::MakeDate { year, month, day } => Self::MakeDate {
+                year: year.into(),
+                month: month.into(),
+                day: day.into(),
+            },
+            ast::Function::MakeTimestamp {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            } => Self::MakeTimestamp {
+                year: year.into(),
+                month: month.into(),
+                day: day.into(),
+                hour: hour.into(),
+                minute: minute.into(),
+                second: second.into(),
+            },
             ast::Function::Ltrim { expr, chars } => Self::Ltrim {
                 expr: expr.into(),
                 chars: chars.map(Into::into),
@@ -619,6 +759,53 @@ impl From<ast::Function> for FunctionExprPlan {
                 sub_expr: sub_expr.into(),
                 start: start.map(Into::into),
             },
+            ast::Function::RegexpLike {
+                expr,
+                pattern,
+                flags,
+            } => Self::RegexpLike {
+                expr: expr.into(),
+                pattern: pattern.into(),
+                flags: flags.map(Into::into),
+            },
+            ast::Function::RegexpReplace {
+                expr,
+                pattern,
+                replacement,
+                flags,
+            } => Self::RegexpReplace {
+                expr: expr.into(),
+                pattern: pattern.into(),
+                replacement: replacement.into(),
+                flags: flags.map(Into::into),
+            },
+            ast::Function::RegexpSubstr {
+                expr,
+                pattern,
+                flags,
+            } => Self::RegexpSubstr {
+                expr: expr.into(),
+                pattern: pattern.into(),
+                flags: flags.map(Into::into),
+            },
+            ast::Function::RegexpMatches {
+                expr,
+                pattern,
+                flags,
+            } => Self::RegexpMatches {
+                expr: expr.into(),
+                pattern: pattern.into(),
+                flags: flags.map(Into::into),
+            },
+            ast::Function::RegexpSplitToArray {
+                expr,
+                pattern,
+                flags,
+            } => Self::RegexpSplitToArray {
+                expr: expr.into(),
+                pattern: pattern.into(),
+                flags: flags.map(Into::into),
+            },
             ast::Function::Ascii(expr) => Self::Ascii(expr.into()),
             ast::Function::Chr(expr) => Self::Chr(expr.into()),
             ast::Function::Md5(expr) => Self::Md5(expr.into()),
@@ -665,6 +852,36 @@ impl From<ast::Function> for FunctionExprPlan {
                 geometry1: geometry1.into(),
                 geometry2: geometry2.into(),
             },
+            ast::Function::StContains {
+                geometry1,
+                geometry2,
+            } => Self::StContains {
+                geometry1: geometry1.into(),
+                geometry2: geometry2.into(),
+            },
+            ast::Function::StIntersects {
+                geometry1,
+                geometry2,
+            } => Self::StIntersects {
+                geometry1: geometry1.into(),
+                geometry2: geometry2.into(),
+            },
+            ast::Function::StWithin {
+                geometry1,
+                geometry2,
+            } => Self::StWithin {
+                geometry1: geometry1.into(),
+                geometry2: geometry2.into(),
+            },
+            ast::Function::StArea(expr) => Self::StArea(expr.into()),
+            ast::Function::StLength(expr) => Self::StLength(expr.into()),
+            ast::Function::StDistanceSphere {
+                geometry1,
+                geometry2,
+            } => Self::StDistanceSphere {
+                geometry1: geometry1.into(),
+                geometry2: geometry2.into(),
+            },
             ast::Function::IsEmpty(expr) => Self::IsEmpty(expr.into()),
             ast::Function::Length(expr) => Self::Length(expr.into()),
             ast::Function::Entries(expr) => Self::Entries(expr.into()),
@@ -688,11 +905,16 @@ impl From<ast::Function> for FunctionExprPlan {
 
 impl From<ast::Aggregate> for AggregateExprPlan {
     fn from(aggregate: ast::Aggregate) -> Self {
-        let ast::Aggregate { func, distinct } = aggregate;
+        let ast::Aggregate {
+            func,
+            distinct,
+            filter,
+        } = aggregate;
 
         Self {
             func: func.into(),
             distinct,
+            filter: filter.map(Into::into),
             slot: None,
         }
     }
@@ -708,6 +930,34 @@ impl From<ast::AggregateFunction> for AggregateFunctionPlan {
             ast::AggregateFunction::Avg(expr) => Self::Avg(expr.into()),
             ast::AggregateFunction::Variance(expr) => Self::Variance(expr.into()),
             ast::AggregateFunction::Stdev(expr) => Self::Stdev(expr.into()),
+            ast::AggregateFunction::StringAgg {
+                expr,
+                separator,
+                order_by,
+            } => Self::StringAgg {
+                expr: expr.into(),
+                separator: separator.into(),
+                order_by: order_by.into_iter().map(Into::into).collect(),
+            },
+            ast::AggregateFunction::ArrayAgg { expr, order_by } => Self::ArrayAgg {
+                expr: expr.into(),
+                order_by: order_by.into_iter().map(Into::into).collect(),
+            },
+            ast::AggregateFunction::BoolAnd(expr) => Self::BoolAnd(expr.into()),
+            ast::AggregateFunction::BoolOr(expr) => Self::BoolOr(expr.into()),
+            ast::AggregateFunction::Median(expr) => Self::Median(expr.into()),
+            ast::AggregateFunction::PercentileCont { fraction, order_by } => Self::PercentileCont {
+                fraction: fraction.into(),
+                order_by: order_by.into(),
+            },
+            ast::AggregateFunction::PercentileDisc { fraction, order_by } => Self::PercentileDisc {
+                fraction: fraction.into(),
+                order_by: order_by.into(),
+            },
+            ast::AggregateFunction::Mode(expr) => Self::Mode(expr.into()),
+            ast::AggregateFunction::Grouping(exprs) => {
+                Self::Grouping(exprs.into_iter().map(Into::into).collect())
+            }
         }
     }
 }
@@ -9,6 +9,7 @@ mod order_by_expr;
 mod project;
 mod select_order_by;
 mod source;
+mod union;
 mod values;
 
 pub use {
@@ -27,9 +28,11 @@ pub use {
     project::{ProjectInputPlan, ProjectPlan},
     select_order_by::SelectOrderByPlan,
     source::{
-        DerivedSourcePlan, DictionarySourcePlan, IndexPredicatePlan, SeriesSourcePlan, SourcePlan,
-        TableAccessPlan, TableAliasPlan, TableSourcePlan,
+        DerivedSourcePlan, DictionarySourcePlan, GenerateSeriesSourcePlan, IndexPredicatePlan,
+        ScanPredicatePlan, SeriesSourcePlan, SourcePlan, TableAccessPlan, TableAliasPlan,
+        TableSourcePlan,
     },
+    union::UnionPlan,
     values::{ValuesOrderByPlan, ValuesPlan},
 };
 
@@ -47,6 +50,7 @@ pub enum QueryPlan {
     Distinct(DistinctPlan),
     Offset(OffsetPlan),
     Limit(LimitPlan),
+    Union(UnionPlan),
 }
 
 impl QueryPlan {
@@ -58,6 +62,7 @@ impl QueryPlan {
             Self::Distinct(distinct) => Some(distinct.project()),
             Self::Offset(offset) => offset.project(),
             Self::Limit(limit) => limit.project(),
+            Self::Union(union) => union.left.project(),
         }
     }
 }
@@ -74,6 +79,16 @@ impl From<ast::Query> for QueryPlan {
         let order_by = order_by.into_iter().map(Into::into).collect::<Vec<_>>();
 
         let input = match body {
+            ast::SetExpr::Union { left, right, all } => {
+                return QueryPlan::Union(UnionPlan {
+                    left: Box::new((*left).into()),
+                    right: Box::new((*right).into()),
+                    all,
+                    order_by,
+                    offset: offset_expr.map(Into::into),
+                    limit: limit_expr.map(Into::into),
+                });
+            }
             ast::SetExpr::Select(select) => {
                 let ast::Select {
                     distinct: is_distinct,
@@ -81,11 +96,12 @@ impl From<ast::Query> for QueryPlan {
                     from,
                     selection,
                     group_by,
+                    grouping_sets,
                     having,
                 } = *select;
                 let input = joins(from);
                 let input = filter(input, selection);
-                let input = group_by_having(input, group_by, having);
+                let input = group_by_having(input, group_by, grouping_sets, having);
                 let input = ProjectPlan {
                     input,
                     projection: projection.into(),
@@ -191,6 +207,7 @@ fn filter(input: NestedLoopJoinInputPlan, selection: Option<ast::Expr>) -> Aggre
 fn group_by_having(
     input: AggregationInputPlan,
     group_by: Vec<ast::Expr>,
+    grouping_sets: Option<Vec<Vec<usize>>>,
     having: Option<ast::Expr>,
 ) -> ProjectInputPlan {
     let group_by = group_by.into_iter().map(Into::into).collect::<Vec<_>>();
@@ -200,11 +217,12 @@ fn group_by_having(
             input: AggregationPlan {
                 input,
                 group_by,
+                grouping_sets,
                 aggregate_slots: Vec::new(),
             },
             expr: having.into(),
         }),
-        None if group_by.is_empty() => match input {
+        None if group_by.is_empty() && grouping_sets.is_none() => match input {
             AggregationInputPlan::Source(source) => ProjectInputPlan::Source(source),
             AggregationInputPlan::InnerJoin(join) => ProjectInputPlan::InnerJoin(join),
             AggregationInputPlan::LeftOuterJoin(join) => ProjectInputPlan::LeftOuterJoin(join),
@@ -213,6 +231,7 @@ fn group_by_having(
         None => ProjectInputPlan::Aggregation(AggregationPlan {
             input,
             group_by,
+            grouping_sets,
             aggregate_slots: Vec::new(),
         }),
     }
@@ -330,6 +349,7 @@ mod tests {
             project_statement(ProjectInputPlan::Aggregation(AggregationPlan {
                 input: AggregationInputPlan::Source(relation_plan()),
                 group_by: vec![ExprPlan::Identifier("category".to_owned())],
+                grouping_sets: None,
                 aggregate_slots: Vec::new(),
             }))
         );
@@ -338,6 +358,7 @@ mod tests {
             project_statement(ProjectInputPlan::Aggregation(AggregationPlan {
                 input: AggregationInputPlan::Filter(filter_plan()),
                 group_by: vec![ExprPlan::Identifier("category".to_owned())],
+                grouping_sets: None,
                 aggregate_slots: Vec::new(),
             }))
         );
@@ -347,6 +368,7 @@ mod tests {
                 input: AggregationPlan {
                     input: AggregationInputPlan::Source(relation_plan()),
                     group_by: vec![ExprPlan::Identifier("category".to_owned())],
+                    grouping_sets: None,
                     aggregate_slots: Vec::new(),
                 },
                 expr: ExprPlan::Value(Value::Bool(true)),
@@ -358,6 +380,7 @@ mod tests {
                 input: AggregationPlan {
                     input: AggregationInputPlan::Filter(filter_plan()),
                     group_by: vec![ExprPlan::Identifier("category".to_owned())],
+                    grouping_sets: None,
                     aggregate_slots: Vec::new(),
                 },
                 expr: ExprPlan::Value(Value::Bool(true)),
@@ -369,6 +392,7 @@ mod tests {
                 input: AggregationPlan {
                     input: AggregationInputPlan::Source(relation_plan()),
                     group_by: Vec::new(),
+                    grouping_sets: None,
                     aggregate_slots: Vec::new(),
                 },
                 expr: ExprPlan::Value(Value::Bool(true)),
@@ -380,6 +404,7 @@ mod tests {
                 input: AggregationPlan {
                     input: AggregationInputPlan::Filter(filter_plan()),
                     group_by: Vec::new(),
+                    grouping_sets: None,
                     aggregate_slots: Vec::new(),
                 },
                 expr: ExprPlan::Value(Value::Bool(true)),
@@ -36,6 +36,7 @@ impl AggregationInputPlan {
 pub struct AggregationPlan {
     pub input: AggregationInputPlan,
     pub group_by: Vec<ExprPlan>,
+    pub grouping_sets: Option<Vec<Vec<usize>>>,
     pub aggregate_slots: Vec<AggregateExprPlan>,
 }
 
@@ -83,21 +84,25 @@ mod tests {
         let relation = AggregationPlan {
             input: AggregationInputPlan::Source(table("A")),
             group_by: Vec::new(),
+            grouping_sets: None,
             aggregate_slots: Vec::new(),
         };
         let inner = AggregationPlan {
             input: AggregationInputPlan::InnerJoin(Box::new(inner_join.clone())),
             group_by: Vec::new(),
+            grouping_sets: None,
             aggregate_slots: Vec::new(),
         };
         let left_outer = AggregationPlan {
             input: AggregationInputPlan::LeftOuterJoin(Box::new(left_outer_join.clone())),
             group_by: Vec::new(),
+            grouping_sets: None,
             aggregate_slots: Vec::new(),
         };
         let filtered = AggregationPlan {
             input: AggregationInputPlan::Filter(filter.clone()),
             group_by: Vec::new(),
+            grouping_sets: None,
             aggregate_slots: Vec::new(),
         };
 
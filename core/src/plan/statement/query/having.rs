@@ -33,6 +33,7 @@ mod tests {
                 access: TableAccessPlan::FullScan,
             })),
             group_by: Vec::new(),
+            grouping_sets: None,
             aggregate_slots: Vec::new(),
         };
         let expr = ExprPlan::Value(Value::Bool(true));
@@ -89,6 +89,7 @@ mod tests {
         let aggregation = AggregationPlan {
             input: AggregationInputPlan::Filter(filter.clone()),
             group_by: Vec::new(),
+            grouping_sets: None,
             aggregate_slots: Vec::new(),
         };
         let having_plan = HavingPlan {
@@ -1,5 +1,6 @@
 mod derived;
 mod dictionary;
+mod generate_series;
 mod series;
 mod table;
 mod table_access;
@@ -7,9 +8,10 @@ mod table_access;
 pub use {
     derived::DerivedSourcePlan,
     dictionary::DictionarySourcePlan,
+    generate_series::GenerateSeriesSourcePlan,
     series::SeriesSourcePlan,
     table::TableSourcePlan,
-    table_access::{IndexPredicatePlan, TableAccessPlan},
+    table_access::{IndexPredicatePlan, ScanPredicatePlan, TableAccessPlan},
 };
 
 use {
@@ -22,6 +24,7 @@ pub enum SourcePlan {
     Table(TableSourcePlan),
     Derived(DerivedSourcePlan),
     Series(SeriesSourcePlan),
+    GenerateSeries(GenerateSeriesSourcePlan),
     Dictionary(DictionarySourcePlan),
 }
 
@@ -45,6 +48,10 @@ impl SourcePlan {
                 alias: TableAliasPlan { name, .. },
                 ..
             })
+            | Self::GenerateSeries(GenerateSeriesSourcePlan {
+                alias: TableAliasPlan { name, .. },
+                ..
+            })
             | Self::Dictionary(DictionarySourcePlan {
                 alias: TableAliasPlan { name, .. },
                 ..
@@ -69,6 +76,17 @@ impl From<ast::TableFactor> for SourcePlan {
                 alias: alias.into(),
                 size: size.into(),
             }),
+            ast::TableFactor::GenerateSeries {
+                alias,
+                start,
+                stop,
+                step,
+            } => Self::GenerateSeries(GenerateSeriesSourcePlan {
+                alias: alias.into(),
+                start: start.into(),
+                stop: stop.into(),
+                step: step.map(Into::into),
+            }),
             ast::TableFactor::Dictionary { dict, alias } => {
                 Self::Dictionary(DictionarySourcePlan {
                     dictionary: dict,
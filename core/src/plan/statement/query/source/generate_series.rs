@@ -0,0 +1,13 @@
+use {
+    super::TableAliasPlan,
+    crate::plan::ExprPlan,
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GenerateSeriesSourcePlan {
+    pub alias: TableAliasPlan,
+    pub start: ExprPlan,
+    pub stop: ExprPlan,
+    pub step: Option<ExprPlan>,
+}
@@ -1,6 +1,7 @@
 mod index_predicate;
+mod scan_predicate;
 
-pub use index_predicate::IndexPredicatePlan;
+pub use {index_predicate::IndexPredicatePlan, scan_predicate::ScanPredicatePlan};
 
 use {
     crate::plan::ExprPlan,
@@ -18,4 +19,10 @@ pub enum TableAccessPlan {
         asc: Option<bool>,
         predicate: Option<IndexPredicatePlan>,
     },
+    /// Full scan that hands conjunctive predicates and the needed column
+    /// indexes to [`Store::scan_filtered_data`](crate::store::Store::scan_filtered_data).
+    Scan {
+        predicates: Vec<ScanPredicatePlan>,
+        columns: Option<Vec<usize>>,
+    },
 }
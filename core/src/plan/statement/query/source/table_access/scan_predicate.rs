@@ -0,0 +1,11 @@
+use {
+    crate::{ast, plan::ExprPlan},
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ScanPredicatePlan {
+    pub column: usize,
+    pub operator: ast::IndexOperator,
+    pub expr: ExprPlan,
+}
@@ -0,0 +1,32 @@
+use {
+    super::QueryPlan,
+    crate::plan::{ExprPlan, OrderByExprPlan},
+    serde::{Deserialize, Serialize},
+};
+
+/// `left UNION [ALL] right` with the `ORDER BY`, `OFFSET` and `LIMIT` of its
+/// query, which apply to the combined rows labeled after `left`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UnionPlan {
+    pub left: Box<QueryPlan>,
+    pub right: Box<QueryPlan>,
+    pub all: bool,
+    pub order_by: Vec<OrderByExprPlan>,
+    pub offset: Option<ExprPlan>,
+    pub limit: Option<ExprPlan>,
+}
+
+impl UnionPlan {
+    /// Rebuilds both operands with `f`, keeping the rest of the plan.
+    #[must_use]
+    pub fn map_queries<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(QueryPlan) -> QueryPlan,
+    {
+        Self {
+            left: Box::new(f(*self.left)),
+            right: Box::new(f(*self.right)),
+            ..self
+        }
+    }
+}
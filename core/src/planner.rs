@@ -5,6 +5,7 @@ mod expr;
 mod hash_join;
 mod index;
 mod primary_key;
+mod pushdown;
 mod query;
 mod schema;
 mod schemaless;
@@ -13,6 +14,6 @@ mod validate;
 pub use {
     self::validate::validate, aggregate::plan as plan_aggregate, error::*,
     hash_join::plan as plan_hash_join, index::plan as plan_index,
-    primary_key::plan as plan_primary_key, schema::fetch_schema_map,
-    schemaless::plan as plan_schemaless,
+    primary_key::plan as plan_primary_key, pushdown::plan as plan_pushdown,
+    schema::fetch_schema_map, schemaless::plan as plan_schemaless,
 };
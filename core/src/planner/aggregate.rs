@@ -7,7 +7,7 @@ use {
         LeftOuterJoinInputPlan, LeftOuterJoinPlan, LimitInputPlan, LimitPlan,
         NestedLoopJoinInputPlan, NestedLoopJoinPlan, OffsetInputPlan, OffsetPlan, OrderByExprPlan,
         ProjectInputPlan, ProjectPlan, ProjectionPlan, QueryPlan, SelectItemPlan,
-        SelectOrderByPlan, SourcePlan, StatementPlan, ValuesOrderByPlan, ValuesPlan,
+        SelectOrderByPlan, SourcePlan, StatementPlan, UnionPlan, ValuesOrderByPlan, ValuesPlan,
     },
     std::collections::HashMap,
 };
@@ -38,6 +38,7 @@ pub fn plan(statement: StatementPlan) -> StatementPlan {
             engine,
             foreign_keys,
             comment,
+            ttl_column,
         } => {
             if let Some(source) = source.as_mut() {
                 plan_query(source);
@@ -51,6 +52,7 @@ pub fn plan(statement: StatementPlan) -> StatementPlan {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
             }
         }
         StatementPlan::Update {
@@ -109,6 +111,27 @@ fn plan_query(query: &mut QueryPlan) {
 
             plan_expr(count);
         }
+        QueryPlan::Union(union) => plan_union(union),
+    }
+}
+
+fn plan_union(
+    UnionPlan {
+        left,
+        right,
+        order_by,
+        offset,
+        limit,
+        ..
+    }: &mut UnionPlan,
+) {
+    plan_query(left);
+    plan_query(right);
+    for order_by in order_by {
+        plan_expr(&mut order_by.expr);
+    }
+    for count in offset.iter_mut().chain(limit) {
+        plan_expr(count);
     }
 }
 
@@ -264,6 +287,14 @@ fn plan_source(source: &mut SourcePlan) {
         SourcePlan::Table(_) | SourcePlan::Dictionary(_) => {}
         SourcePlan::Derived(derived) => plan_query(&mut derived.query),
         SourcePlan::Series(series) => plan_expr(&mut series.size),
+        SourcePlan::GenerateSeries(series) => {
+            plan_expr(&mut series.start);
+            plan_expr(&mut series.stop);
+
+            if let Some(step) = &mut series.step {
+                plan_expr(step);
+            }
+        }
     }
 }
 
@@ -280,6 +311,7 @@ fn plan_expr(expr: &mut ExprPlan) {
 struct AggregateKey {
     func: AggregateFunctionPlan,
     distinct: bool,
+    filter: Option<ExprPlan>,
 }
 
 fn bind_project(
@@ -295,6 +327,7 @@ fn bind_project(
                 let key = AggregateKey {
                     func: aggregate.func.clone(),
                     distinct: aggregate.distinct,
+                    filter: aggregate.filter.clone(),
                 };
 
                 let slot = *slots.entry(key).or_insert_with(|| {
@@ -331,6 +364,7 @@ fn bind_project(
             *input = ProjectInputPlan::Aggregation(AggregationPlan {
                 input: AggregationInputPlan::Source(relation.clone()),
                 group_by: Vec::new(),
+                grouping_sets: None,
                 aggregate_slots: aggregates,
             });
         }
@@ -338,6 +372,7 @@ fn bind_project(
             *input = ProjectInputPlan::Aggregation(AggregationPlan {
                 input: AggregationInputPlan::InnerJoin(join.clone()),
                 group_by: Vec::new(),
+                grouping_sets: None,
                 aggregate_slots: aggregates,
             });
         }
@@ -345,6 +380,7 @@ fn bind_project(
             *input = ProjectInputPlan::Aggregation(AggregationPlan {
                 input: AggregationInputPlan::LeftOuterJoin(join.clone()),
                 group_by: Vec::new(),
+                grouping_sets: None,
                 aggregate_slots: aggregates,
             });
         }
@@ -352,6 +388,7 @@ fn bind_project(
             *input = ProjectInputPlan::Aggregation(AggregationPlan {
                 input: AggregationInputPlan::Filter(filter.clone()),
                 group_by: Vec::new(),
+                grouping_sets: None,
                 aggregate_slots: aggregates,
             });
         }
@@ -421,6 +458,7 @@ mod tests {
         AggregateExprPlan {
             func: AggregateFunctionPlan::Count(CountArgExprPlan::Wildcard),
             distinct: false,
+            filter: None,
             slot,
         }
     }
@@ -435,6 +473,7 @@ mod tests {
                 "id".to_owned(),
             ))),
             distinct: true,
+            filter: None,
             slot,
         }
     }
@@ -486,6 +525,7 @@ mod tests {
                     input: AggregationPlan {
                         input: AggregationInputPlan::Source(table_source("Item")),
                         group_by: Vec::new(),
+                        grouping_sets: None,
                         aggregate_slots: vec![count_wildcard(Some(0))],
                     },
                     expr: greater_than(
@@ -525,6 +565,7 @@ mod tests {
                     input: ProjectInputPlan::Aggregation(AggregationPlan {
                         input: AggregationInputPlan::Source(table_source("Item")),
                         group_by: Vec::new(),
+                        grouping_sets: None,
                         aggregate_slots: vec![count_distinct_id(Some(0))],
                     }),
                     projection: ProjectionPlan::SelectItems(vec![SelectItemPlan::Expr {
@@ -547,6 +588,7 @@ mod tests {
         let aggregation = AggregationPlan {
             input: AggregationInputPlan::Source(table_source("Item")),
             group_by: Vec::new(),
+            grouping_sets: None,
             aggregate_slots: Vec::new(),
         };
         let query = |slot: Option<usize>| {
@@ -592,6 +634,7 @@ mod tests {
                 alias: table_alias("sub"),
             })),
             group_by: Vec::new(),
+            grouping_sets: None,
             aggregate_slots: vec![count_wildcard(Some(0))],
         });
         assert_eq!(actual, Some(&expected));
@@ -618,6 +661,7 @@ mod tests {
             engine: None,
             foreign_keys: Vec::new(),
             comment: None,
+            ttl_column: None,
         };
         assert_eq!(actual, expected);
     }
@@ -742,6 +786,7 @@ mod tests {
                 subquery: Box::new(parse_and_plan_query("SELECT COUNT(*) FROM Source")),
                 negated: false,
             }],
+            grouping_sets: None,
             aggregate_slots: Vec::new(),
         });
 
@@ -784,6 +829,7 @@ mod tests {
         let expected = ProjectInputPlan::Aggregation(AggregationPlan {
             input: AggregationInputPlan::Source(table_source("Item")),
             group_by: Vec::new(),
+            grouping_sets: None,
             aggregate_slots: vec![count_wildcard(Some(0))],
         });
         assert_eq!(actual, Some(&expected));
@@ -793,6 +839,7 @@ mod tests {
         let expected = ProjectInputPlan::Aggregation(AggregationPlan {
             input: AggregationInputPlan::Source(table_source("Item")),
             group_by: Vec::new(),
+            grouping_sets: None,
             aggregate_slots: vec![count_wildcard(Some(0))],
         });
         assert_eq!(actual, Some(&expected));
@@ -806,6 +853,7 @@ mod tests {
             input: AggregationPlan {
                 input: AggregationInputPlan::Source(table_source("Item")),
                 group_by: Vec::new(),
+                grouping_sets: None,
                 aggregate_slots: vec![count_wildcard(Some(0))],
             },
             expr: greater_than(
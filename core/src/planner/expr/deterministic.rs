@@ -96,6 +96,8 @@ fn is_function_deterministic(function: &FunctionExprPlan) -> bool {
         | Md5(expr)
         | Hex(expr)
         | GetX(expr)
+        | StArea(expr)
+        | StLength(expr)
         | GetY(expr)
         | Dedup(expr)
         | Extract { expr, .. }
@@ -147,6 +149,22 @@ fn is_function_deterministic(function: &FunctionExprPlan) -> bool {
             geometry1: expr,
             geometry2: size,
         }
+        | StContains {
+            geometry1: expr,
+            geometry2: size,
+        }
+        | StIntersects {
+            geometry1: expr,
+            geometry2: size,
+        }
+        | StWithin {
+            geometry1: expr,
+            geometry2: size,
+        }
+        | StDistanceSphere {
+            geometry1: expr,
+            geometry2: size,
+        }
         | Sort {
             expr,
             order: Some(size),
@@ -177,6 +195,36 @@ fn is_function_deterministic(function: &FunctionExprPlan) -> bool {
                 && count.as_ref().is_none_or(is_deterministic)
         }
         Unwrap { expr, selector } => is_deterministic(expr) && is_deterministic(selector),
+        DateTrunc { field, expr } => is_deterministic(field) && is_deterministic(expr),
+        DateBin {
+            stride,
+            expr,
+            origin,
+        } => {
+            is_deterministic(stride)
+                && is_deterministic(expr)
+                && origin.as_ref().is_none_or(is_deterministic)
+        }
+        DateDiff { field, start, end } => {
+            is_deterministic(field) && is_deterministic(start) && is_deterministic(end)
+        }
+        // AGE with a single argument measures from the current date.
+        Age { left, right } => {
+            is_deterministic(left) && right.as_ref().is_some_and(is_deterministic)
+        }
+        MakeDate { year, month, day } => {
+            is_deterministic(year) && is_deterministic(month) && is_deterministic(day)
+        }
+        MakeTimestamp {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        } => [year, month, day, hour, minute, second]
+            .into_iter()
+            .all(is_deterministic),
         FindIdx {
             from_expr,
             sub_expr,
@@ -186,6 +234,41 @@ fn is_function_deterministic(function: &FunctionExprPlan) -> bool {
                 && is_deterministic(sub_expr)
                 && start.as_ref().is_none_or(is_deterministic)
         }
+        RegexpLike {
+            expr,
+            pattern,
+            flags,
+        }
+        | RegexpSubstr {
+            expr,
+            pattern,
+            flags,
+        }
+        | RegexpMatches {
+            expr,
+            pattern,
+            flags,
+        }
+        | RegexpSplitToArray {
+            expr,
+            pattern,
+            flags,
+        } => {
+            is_deterministic(expr)
+                && is_deterministic(pattern)
+                && flags.as_ref().is_none_or(is_deterministic)
+        }
+        RegexpReplace {
+            expr,
+            pattern,
+            replacement,
+            flags,
+        } => {
+            is_deterministic(expr)
+                && is_deterministic(pattern)
+                && is_deterministic(replacement)
+                && flags.as_ref().is_none_or(is_deterministic)
+        }
         Splice {
             list_data,
             begin_index,
@@ -7,8 +7,8 @@ use {
             JoinConditionInputPlan, JoinConditionPlan, LeftOuterJoinInputPlan, LeftOuterJoinPlan,
             LimitInputPlan, LimitPlan, NestedLoopJoinInputPlan, NestedLoopJoinPlan,
             OffsetInputPlan, OffsetPlan, ProjectInputPlan, ProjectPlan, ProjectionPlan, QueryPlan,
-            SelectItemPlan, SelectOrderByPlan, SourcePlan, TableAliasPlan, ValuesOrderByPlan,
-            ValuesPlan,
+            SelectItemPlan, SelectOrderByPlan, SourcePlan, TableAliasPlan, UnionPlan,
+            ValuesOrderByPlan, ValuesPlan,
         },
         planner::context::Context,
     },
@@ -64,9 +64,31 @@ fn check_query(context: Option<&Rc<Context<'_>>>, query: &QueryPlan) -> bool {
 
             input && check_expr(context.map(Rc::clone), count)
         }
+        QueryPlan::Union(union) => check_union(context, union),
     }
 }
 
+fn check_union(
+    context: Option<&Rc<Context<'_>>>,
+    UnionPlan {
+        left,
+        right,
+        order_by,
+        offset,
+        limit,
+        ..
+    }: &UnionPlan,
+) -> bool {
+    check_query(context, left)
+        && check_query(context, right)
+        && order_by
+            .iter()
+            .map(|order_by| &order_by.expr)
+            .chain(offset)
+            .chain(limit)
+            .all(|expr| check_expr(context.map(Rc::clone), expr))
+}
+
 fn check_offset(context: Option<&Rc<Context<'_>>>, plan: &OffsetPlan) -> bool {
     let input = match &plan.input {
         OffsetInputPlan::Project(project) => check_project(context, project),
@@ -240,6 +262,15 @@ fn check_source(context: Option<&Rc<Context<'_>>>, source: &SourcePlan) -> bool
         SourcePlan::Series(series) => {
             contains_alias(&series.alias.name) && check_expr(context.map(Rc::clone), &series.size)
         }
+        SourcePlan::GenerateSeries(series) => {
+            contains_alias(&series.alias.name)
+                && check_expr(context.map(Rc::clone), &series.start)
+                && check_expr(context.map(Rc::clone), &series.stop)
+                && series
+                    .step
+                    .as_ref()
+                    .is_none_or(|step| check_expr(context.map(Rc::clone), step))
+        }
         SourcePlan::Dictionary(dictionary) => contains_alias(&dictionary.alias.name),
     }
 }
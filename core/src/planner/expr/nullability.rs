@@ -60,7 +60,7 @@ fn function_may_return_null(function: &FunctionExprPlan) -> bool {
     match function {
         Coalesce(exprs) => exprs.iter().all(may_return_null),
         IfNull { expr, then } => may_return_null(expr) && may_return_null(then),
-        NullIf { .. } | Custom { .. } => true,
+        NullIf { .. } | Custom { .. } | RegexpSubstr { .. } | RegexpMatches { .. } => true,
         Now() | CurrentDate() | CurrentTime() | CurrentTimestamp() | Pi() | GenerateUuid()
         | Rand(_) => false,
         Cast { expr, .. }
@@ -98,6 +98,8 @@ fn function_may_return_null(function: &FunctionExprPlan) -> bool {
         | Md5(expr)
         | Hex(expr)
         | GetX(expr)
+        | StArea(expr)
+        | StLength(expr)
         | GetY(expr)
         | Dedup(expr)
         | Extract { expr, .. }
@@ -140,6 +142,22 @@ fn function_may_return_null(function: &FunctionExprPlan) -> bool {
             geometry1: expr,
             geometry2: size,
         }
+        | StContains {
+            geometry1: expr,
+            geometry2: size,
+        }
+        | StIntersects {
+            geometry1: expr,
+            geometry2: size,
+        }
+        | StWithin {
+            geometry1: expr,
+            geometry2: size,
+        }
+        | StDistanceSphere {
+            geometry1: expr,
+            geometry2: size,
+        }
         | Sort {
             expr,
             order: Some(size),
@@ -174,6 +192,33 @@ fn function_may_return_null(function: &FunctionExprPlan) -> bool {
                 || count.as_ref().is_some_and(may_return_null)
         }
         Unwrap { expr, selector } => may_return_null(expr) || may_return_null(selector),
+        DateTrunc { field, expr } => may_return_null(field) || may_return_null(expr),
+        DateBin {
+            stride,
+            expr,
+            origin,
+        } => {
+            may_return_null(stride)
+                || may_return_null(expr)
+                || origin.as_ref().is_some_and(may_return_null)
+        }
+        DateDiff { field, start, end } => {
+            may_return_null(field) || may_return_null(start) || may_return_null(end)
+        }
+        Age { left, right } => may_return_null(left) || right.as_ref().is_some_and(may_return_null),
+        MakeDate { year, month, day } => {
+            may_return_null(year) || may_return_null(month) || may_return_null(day)
+        }
+        MakeTimestamp {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        } => [year, month, day, hour, minute, second]
+            .into_iter()
+            .any(may_return_null),
         FindIdx {
             from_expr,
             sub_expr,
@@ -183,6 +228,31 @@ fn function_may_return_null(function: &FunctionExprPlan) -> bool {
                 || may_return_null(sub_expr)
                 || start.as_ref().is_some_and(may_return_null)
         }
+        RegexpLike {
+            expr,
+            pattern,
+            flags,
+        }
+        | RegexpSplitToArray {
+            expr,
+            pattern,
+            flags,
+        } => {
+            may_return_null(expr)
+                || may_return_null(pattern)
+                || flags.as_ref().is_some_and(may_return_null)
+        }
+        RegexpReplace {
+            expr,
+            pattern,
+            replacement,
+            flags,
+        } => {
+            may_return_null(expr)
+                || may_return_null(pattern)
+                || may_return_null(replacement)
+                || flags.as_ref().is_some_and(may_return_null)
+        }
         Splice {
             list_data,
             begin_index,
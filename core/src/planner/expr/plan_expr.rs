@@ -40,10 +40,15 @@ impl<'a> From<&'a ExprPlan> for PlanExpr<'a> {
             | ExprPlan::IsNull(expr)
             | ExprPlan::IsNotNull(expr)
             | ExprPlan::Interval { expr, .. } => PlanExpr::Expr(expr),
-            ExprPlan::Aggregate(aggregate) => match aggregate.as_expr() {
-                Some(expr) => PlanExpr::Expr(expr),
-                None => PlanExpr::None,
-            },
+            ExprPlan::Aggregate(aggregate) => {
+                let exprs = aggregate.as_exprs().collect::<Vec<_>>();
+
+                match exprs.as_slice() {
+                    [] => PlanExpr::None,
+                    [expr] => PlanExpr::Expr(expr),
+                    _ => PlanExpr::MultiExprs(exprs),
+                }
+            }
             ExprPlan::BinaryOp { left, right, .. } => PlanExpr::TwoExprs(left, right),
             ExprPlan::Like { expr, pattern, .. } | ExprPlan::ILike { expr, pattern, .. } => {
                 PlanExpr::TwoExprs(expr, pattern)
@@ -1,17 +1,45 @@
-use crate::plan::{AggregateExprPlan, AggregateFunctionPlan, CountArgExprPlan, ExprPlan};
+use crate::plan::{
+    AggregateExprPlan, AggregateFunctionPlan, CountArgExprPlan, ExprPlan, OrderByExprPlan,
+};
 
 impl AggregateExprPlan {
-    pub fn as_expr(&self) -> Option<&ExprPlan> {
-        match &self.func {
-            AggregateFunctionPlan::Count(CountArgExprPlan::Wildcard) => None,
+    pub fn as_exprs(&self) -> impl Iterator<Item = &ExprPlan> {
+        let exprs = match &self.func {
+            AggregateFunctionPlan::Count(CountArgExprPlan::Wildcard) => Vec::new(),
             AggregateFunctionPlan::Count(CountArgExprPlan::Expr(expr))
             | AggregateFunctionPlan::Sum(expr)
             | AggregateFunctionPlan::Max(expr)
             | AggregateFunctionPlan::Min(expr)
             | AggregateFunctionPlan::Avg(expr)
             | AggregateFunctionPlan::Variance(expr)
-            | AggregateFunctionPlan::Stdev(expr) => Some(expr),
-        }
+            | AggregateFunctionPlan::Stdev(expr)
+            | AggregateFunctionPlan::BoolAnd(expr)
+            | AggregateFunctionPlan::BoolOr(expr)
+            | AggregateFunctionPlan::Median(expr)
+            | AggregateFunctionPlan::Mode(expr) => vec![expr],
+            AggregateFunctionPlan::StringAgg {
+                expr,
+                separator,
+                order_by,
+            } => [expr, separator]
+                .into_iter()
+                .chain(order_by.iter().map(|OrderByExprPlan { expr, .. }| expr))
+                .collect(),
+            AggregateFunctionPlan::ArrayAgg { expr, order_by } => std::iter::once(expr)
+                .chain(order_by.iter().map(|OrderByExprPlan { expr, .. }| expr))
+                .collect(),
+            AggregateFunctionPlan::PercentileCont {
+                fraction,
+                order_by: OrderByExprPlan { expr, .. },
+            }
+            | AggregateFunctionPlan::PercentileDisc {
+                fraction,
+                order_by: OrderByExprPlan { expr, .. },
+            } => vec![fraction, expr],
+            AggregateFunctionPlan::Grouping(exprs) => exprs.iter().collect(),
+        };
+
+        exprs.into_iter().chain(self.filter.as_ref())
     }
 }
 
@@ -24,34 +52,48 @@ mod tests {
     };
 
     #[test]
-    fn as_expr() {
+    fn as_exprs() {
         macro_rules! test {
             ($input: literal, $expected: expr) => {
                 let parsed = parse_expr($input).expect($input);
                 let expr = ExprPlan::from(translate_expr(&parsed, NO_PARAMS).expect($input));
                 let actual = match expr {
-                    ExprPlan::Aggregate(aggregate) => Some(aggregate.as_expr().cloned()),
+                    ExprPlan::Aggregate(aggregate) => {
+                        Some(aggregate.as_exprs().cloned().collect::<Vec<_>>())
+                    }
                     _ => None,
                 };
-                let expected: Option<&str> = $expected;
-                let expected = expected.map(|expected| {
-                    let parsed = parse_expr(expected).expect(expected);
+                let expected: &[&str] = &$expected;
+                let expected = expected
+                    .iter()
+                    .map(|expected| {
+                        let parsed = parse_expr(expected).expect(expected);
 
-                    ExprPlan::from(translate_expr(&parsed, NO_PARAMS).expect(expected))
-                });
+                        ExprPlan::from(translate_expr(&parsed, NO_PARAMS).expect(expected))
+                    })
+                    .collect::<Vec<_>>();
                 let expected = Some(expected);
 
                 assert_eq!(actual, expected, "input: {}", $input);
             };
         }
 
-        test!("COUNT(*)", None);
-        test!("COUNT(id)", Some("id"));
-        test!("SUM(id)", Some("id"));
-        test!("MAX(id)", Some("id"));
-        test!("MIN(id)", Some("id"));
-        test!("AVG(id)", Some("id"));
-        test!("VARIANCE(id)", Some("id"));
-        test!("STDEV(id)", Some("id"));
+        test!("COUNT(*)", []);
+        test!("COUNT(id)", ["id"]);
+        test!("SUM(id)", ["id"]);
+        test!("MAX(id)", ["id"]);
+        test!("MIN(id)", ["id"]);
+        test!("AVG(id)", ["id"]);
+        test!("VARIANCE(id)", ["id"]);
+        test!("STDEV(id)", ["id"]);
+        test!("BOOL_OR(done)", ["done"]);
+        test!("MODE(id)", ["id"]);
+        test!("STRING_AGG(name, sep ORDER BY id)", ["name", "sep", "id"]);
+        test!("ARRAY_AGG(name ORDER BY id)", ["name", "id"]);
+        test!(
+            "PERCENTILE_DISC(0.5) WITHIN GROUP (ORDER BY id)",
+            ["0.5", "id"]
+        );
+        test!("COUNT(*) FILTER (WHERE id > 1)", ["id > 1"]);
     }
 }
@@ -6,7 +6,7 @@ use {
 impl FunctionExprPlan {
     pub fn as_exprs(&self) -> impl Iterator<Item = &ExprPlan> {
         #[derive(iter_enum::Iterator)]
-        enum Exprs<I0, I1, I2, I3, I4, I5, I6> {
+        enum Exprs<I0, I1, I2, I3, I4, I5, I6, I7> {
             Empty(I0),
             Single(I1),
             Double(I2),
@@ -14,6 +14,7 @@ impl FunctionExprPlan {
             VariableArgs(I4),
             VariableArgsWithSingle(I5),
             Quadruple(I6),
+            Sextuple(I7),
         }
 
         match self {
@@ -53,6 +54,10 @@ impl FunctionExprPlan {
             | Self::Md5(expr)
             | Self::Hex(expr)
             | Self::LastDay(expr)
+            | Self::Age {
+                left: expr,
+                right: None,
+            }
             | Self::Ltrim { expr, chars: None }
             | Self::Rtrim { expr, chars: None }
             | Self::Trim {
@@ -64,6 +69,8 @@ impl FunctionExprPlan {
             | Self::Cast { expr, .. }
             | Self::Extract { expr, .. }
             | Self::GetX(expr)
+            | Self::StArea(expr)
+            | Self::StLength(expr)
             | Self::GetY(expr)
             | Self::IsEmpty(expr)
             | Self::Sort { expr, order: None }
@@ -134,6 +141,19 @@ impl FunctionExprPlan {
                 chars: Some(expr2),
             }
             | Self::Repeat { expr, num: expr2 }
+            | Self::DateTrunc {
+                field: expr,
+                expr: expr2,
+            }
+            | Self::DateBin {
+                stride: expr,
+                expr: expr2,
+                origin: None,
+            }
+            | Self::Age {
+                left: expr,
+                right: Some(expr2),
+            }
             | Self::Substr {
                 expr,
                 start: expr2,
@@ -167,6 +187,42 @@ impl FunctionExprPlan {
                 geometry1: expr,
                 geometry2: expr2,
             }
+            | Self::StContains {
+                geometry1: expr,
+                geometry2: expr2,
+            }
+            | Self::StIntersects {
+                geometry1: expr,
+                geometry2: expr2,
+            }
+            | Self::StWithin {
+                geometry1: expr,
+                geometry2: expr2,
+            }
+            | Self::StDistanceSphere {
+                geometry1: expr,
+                geometry2: expr2,
+            }
+            | Self::RegexpLike {
+                expr,
+                pattern: expr2,
+                flags: None,
+            }
+            | Self::RegexpSubstr {
+                expr,
+                pattern: expr2,
+                flags: None,
+            }
+            | Self::RegexpMatches {
+                expr,
+                pattern: expr2,
+                flags: None,
+            }
+            | Self::RegexpSplitToArray {
+                expr,
+                pattern: expr2,
+                flags: None,
+            }
             | Self::AddMonth { expr, size: expr2 } => Exprs::Double([expr, expr2].into_iter()),
 
             Self::Lpad {
@@ -204,6 +260,47 @@ impl FunctionExprPlan {
                 begin_index: expr2,
                 end_index: expr3,
                 values: None,
+            }
+            | Self::RegexpLike {
+                expr,
+                pattern: expr2,
+                flags: Some(expr3),
+            }
+            | Self::RegexpSubstr {
+                expr,
+                pattern: expr2,
+                flags: Some(expr3),
+            }
+            | Self::RegexpMatches {
+                expr,
+                pattern: expr2,
+                flags: Some(expr3),
+            }
+            | Self::RegexpSplitToArray {
+                expr,
+                pattern: expr2,
+                flags: Some(expr3),
+            }
+            | Self::RegexpReplace {
+                expr,
+                pattern: expr2,
+                replacement: expr3,
+                flags: None,
+            }
+            | Self::DateBin {
+                stride: expr,
+                expr: expr2,
+                origin: Some(expr3),
+            }
+            | Self::DateDiff {
+                field: expr,
+                start: expr2,
+                end: expr3,
+            }
+            | Self::MakeDate {
+                year: expr,
+                month: expr2,
+                day: expr3,
             } => Exprs::Triple([expr, expr2, expr3].into_iter()),
             Self::Custom { name: _, exprs }
             | Self::Coalesce(exprs)
@@ -217,7 +314,21 @@ impl FunctionExprPlan {
                 begin_index: expr2,
                 end_index: expr3,
                 values: Some(expr4),
+            }
+            | Self::RegexpReplace {
+                expr,
+                pattern: expr2,
+                replacement: expr3,
+                flags: Some(expr4),
             } => Exprs::Quadruple([expr, expr2, expr3, expr4].into_iter()),
+            Self::MakeTimestamp {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            } => Exprs::Sextuple([year, month, day, hour, minute, second].into_iter()),
         }
     }
 }
@@ -314,6 +425,7 @@ mod tests {
         // Double
         test(r#"LEFT("hello", 2)"#, &[r#""hello""#, "2"]);
         test(r#"RIGHT("hello", 2)"#, &[r#""hello""#, "2"]);
+        test(r"REGEXP_SUBSTR(name, '\d+')", &["name", r"'\d+'"]);
         test(r#"FIND_IDX("Calzone", "zone")"#, &[r"Calzone", r"zone"]);
         test(r"TAKE(list, 3)", &[r"list", r"3"]);
         test(r"LPAD(value, 5)", &["value", "5"]);
@@ -349,10 +461,22 @@ mod tests {
             &[r#"'   >++++("<   '"#, "3", "11"],
         );
         test(r"SPLICE(list, 2, 4)", &["list", "2", "4"]);
+        test(r"REGEXP_LIKE(name, '^a', 'i')", &["name", "'^a'", "'i'"]);
+        test(r"REGEXP_REPLACE(name, 'a', 'b')", &["name", "'a'", "'b'"]);
 
         // Quadruple
         test(r"SPLICE(list, 3, 5, values)", &["list", "3", "5", "values"]);
 
+        // Sextuple
+        test(
+            r"MAKE_TIMESTAMP(y, mo, d, h, mi, s)",
+            &["y", "mo", "d", "h", "mi", "s"],
+        );
+        test(
+            r"REGEXP_REPLACE(name, 'a', 'b', 'g')",
+            &["name", "'a'", "'b'", "'g'"],
+        );
+
         //VariableArgs
         test(r#"COALESCE("test")"#, &[r#""test""#]);
 
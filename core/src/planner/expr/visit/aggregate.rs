@@ -1,7 +1,9 @@
 use {
     super::{try_visit_expr, visit_mut_expr},
     crate::{
-        plan::{AggregateExprPlan, AggregateFunctionPlan, CountArgExprPlan, ExprPlan},
+        plan::{
+            AggregateExprPlan, AggregateFunctionPlan, CountArgExprPlan, ExprPlan, OrderByExprPlan,
+        },
         planner::PlannerError,
     },
 };
@@ -31,8 +33,45 @@ macro_rules! visit_aggregate_children {
             | AggregateFunctionPlan::Max(expr)
             | AggregateFunctionPlan::Avg(expr)
             | AggregateFunctionPlan::Variance(expr)
-            | AggregateFunctionPlan::Stdev(expr) => {
+            | AggregateFunctionPlan::Stdev(expr)
+            | AggregateFunctionPlan::BoolAnd(expr)
+            | AggregateFunctionPlan::BoolOr(expr)
+            | AggregateFunctionPlan::Median(expr)
+            | AggregateFunctionPlan::Mode(expr) => {
+                $apply!($visit_expr(expr, $f));
+            }
+            AggregateFunctionPlan::StringAgg {
+                expr,
+                separator,
+                order_by,
+            } => {
                 $apply!($visit_expr(expr, $f));
+                $apply!($visit_expr(separator, $f));
+                for OrderByExprPlan { expr, .. } in order_by {
+                    $apply!($visit_expr(expr, $f));
+                }
+            }
+            AggregateFunctionPlan::ArrayAgg { expr, order_by } => {
+                $apply!($visit_expr(expr, $f));
+                for OrderByExprPlan { expr, .. } in order_by {
+                    $apply!($visit_expr(expr, $f));
+                }
+            }
+            AggregateFunctionPlan::PercentileCont {
+                fraction,
+                order_by: OrderByExprPlan { expr, .. },
+            }
+            | AggregateFunctionPlan::PercentileDisc {
+                fraction,
+                order_by: OrderByExprPlan { expr, .. },
+            } => {
+                $apply!($visit_expr(fraction, $f));
+                $apply!($visit_expr(expr, $f));
+            }
+            AggregateFunctionPlan::Grouping(exprs) => {
+                for expr in exprs {
+                    $apply!($visit_expr(expr, $f));
+                }
             }
         }
     };
@@ -43,6 +82,9 @@ where
     F: FnMut(&mut ExprPlan),
 {
     visit_aggregate_children!(&mut aggr.func, visit_mut_expr, f, apply_mut);
+    if let Some(filter) = &mut aggr.filter {
+        visit_mut_expr(filter, f);
+    }
 }
 
 pub fn try_visit_aggregate<F>(aggr: &AggregateExprPlan, f: &mut F) -> Result<(), PlannerError>
@@ -50,6 +92,9 @@ where
     F: FnMut(&ExprPlan) -> Result<(), PlannerError>,
 {
     visit_aggregate_children!(&aggr.func, try_visit_expr, f, apply_try);
+    if let Some(filter) = &aggr.filter {
+        try_visit_expr(filter, f)?;
+    }
     Ok(())
 }
 
@@ -89,6 +134,26 @@ mod tests {
         test("MIN(x)", "MIN(_x)");
         test("MAX(x)", "MAX(_x)");
         test("AVG(x)", "AVG(_x)");
+        test("BOOL_AND(x)", "BOOL_AND(_x)");
+        test("MEDIAN(x)", "MEDIAN(_x)");
+        test("MODE() WITHIN GROUP (ORDER BY x)", "MODE(_x)");
+        test(
+            "STRING_AGG(x, s ORDER BY y DESC)",
+            "STRING_AGG(_x, _s ORDER BY _y DESC)",
+        );
+        test("ARRAY_AGG(x ORDER BY y)", "ARRAY_AGG(_x ORDER BY _y)");
+        test(
+            "PERCENTILE_CONT(f) WITHIN GROUP (ORDER BY x)",
+            "PERCENTILE_CONT(_f) WITHIN GROUP (ORDER BY _x)",
+        );
+        test(
+            "PERCENTILE_DISC(f) WITHIN GROUP (ORDER BY x)",
+            "PERCENTILE_DISC(_f) WITHIN GROUP (ORDER BY _x)",
+        );
+        test(
+            "SUM(x) FILTER (WHERE y > 1)",
+            "SUM(_x) FILTER (WHERE _y > 1)",
+        );
     }
 
     #[test]
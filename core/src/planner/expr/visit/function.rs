@@ -56,6 +56,8 @@ macro_rules! visit_function_children {
             | FunctionExprPlan::Values(expr)
             | FunctionExprPlan::Dedup(expr)
             | FunctionExprPlan::GetX(expr)
+            | FunctionExprPlan::StArea(expr)
+            | FunctionExprPlan::StLength(expr)
             | FunctionExprPlan::GetY(expr) => $apply!($visit_expr(expr, $f)),
             FunctionExprPlan::AddMonth { expr, size }
             | FunctionExprPlan::Left { expr, size }
@@ -151,6 +153,52 @@ macro_rules! visit_function_children {
                 $apply!($visit_expr(expr, $f));
                 $apply!($visit_expr(selector, $f));
             }
+            FunctionExprPlan::DateTrunc { field, expr } => {
+                $apply!($visit_expr(field, $f));
+                $apply!($visit_expr(expr, $f));
+            }
+            FunctionExprPlan::DateBin {
+                stride,
+                expr,
+                origin,
+            } => {
+                $apply!($visit_expr(stride, $f));
+                $apply!($visit_expr(expr, $f));
+                if let Some(e) = origin {
+                    $apply!($visit_expr(e, $f));
+                }
+            }
+            FunctionExprPlan::DateDiff { field, start, end } => {
+                $apply!($visit_expr(field, $f));
+                $apply!($visit_expr(start, $f));
+                $apply!($visit_expr(end, $f));
+            }
+            FunctionExprPlan::Age { left, right } => {
+                $apply!($visit_expr(left, $f));
+                if let Some(e) = right {
+                    $apply!($visit_expr(e, $f));
+                }
+            }
+            FunctionExprPlan::MakeDate { year, month, day } => {
+                $apply!($visit_expr(year, $f));
+                $apply!($visit_expr(month, $f));
+                $apply!($visit_expr(day, $f));
+            }
+            FunctionExprPlan::MakeTimestamp {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            } => {
+                $apply!($visit_expr(year, $f));
+                $apply!($visit_expr(month, $f));
+                $apply!($visit_expr(day, $f));
+                $apply!($visit_expr(hour, $f));
+                $apply!($visit_expr(minute, $f));
+                $apply!($visit_expr(second, $f));
+            }
             FunctionExprPlan::Format { expr, format }
             | FunctionExprPlan::ToDate { expr, format }
             | FunctionExprPlan::ToTimestamp { expr, format }
@@ -176,6 +224,45 @@ macro_rules! visit_function_children {
                     $apply!($visit_expr(e, $f));
                 }
             }
+            FunctionExprPlan::RegexpLike {
+                expr,
+                pattern,
+                flags,
+            }
+            | FunctionExprPlan::RegexpSubstr {
+                expr,
+                pattern,
+                flags,
+            }
+            | FunctionExprPlan::RegexpMatches {
+                expr,
+                pattern,
+                flags,
+            }
+            | FunctionExprPlan::RegexpSplitToArray {
+                expr,
+                pattern,
+                flags,
+            } => {
+                $apply!($visit_expr(expr, $f));
+                $apply!($visit_expr(pattern, $f));
+                if let Some(e) = flags {
+                    $apply!($visit_expr(e, $f));
+                }
+            }
+            FunctionExprPlan::RegexpReplace {
+                expr,
+                pattern,
+                replacement,
+                flags,
+            } => {
+                $apply!($visit_expr(expr, $f));
+                $apply!($visit_expr(pattern, $f));
+                $apply!($visit_expr(replacement, $f));
+                if let Some(e) = flags {
+                    $apply!($visit_expr(e, $f));
+                }
+            }
             FunctionExprPlan::Append { expr, value }
             | FunctionExprPlan::Prepend { expr, value } => {
                 $apply!($visit_expr(expr, $f));
@@ -203,6 +290,22 @@ macro_rules! visit_function_children {
             FunctionExprPlan::CalcDistance {
                 geometry1,
                 geometry2,
+            }
+            | FunctionExprPlan::StContains {
+                geometry1,
+                geometry2,
+            }
+            | FunctionExprPlan::StIntersects {
+                geometry1,
+                geometry2,
+            }
+            | FunctionExprPlan::StWithin {
+                geometry1,
+                geometry2,
+            }
+            | FunctionExprPlan::StDistanceSphere {
+                geometry1,
+                geometry2,
             } => {
                 $apply!($visit_expr(geometry1, $f));
                 $apply!($visit_expr(geometry2, $f));
@@ -327,6 +430,17 @@ mod tests {
         test("CURRENT_TIMESTAMP()", "CURRENT_TIMESTAMP()");
         test("PI()", "PI()");
         test("LAST_DAY(d)", "LAST_DAY(_d)");
+        test("DATE_TRUNC(u, d)", "DATE_TRUNC(_u, _d)");
+        test("DATE_BIN(i, d)", "DATE_BIN(_i, _d)");
+        test("DATE_BIN(i, d, o)", "DATE_BIN(_i, _d, _o)");
+        test("DATE_DIFF(u, a, b)", "DATE_DIFF(_u, _a, _b)");
+        test("AGE(a)", "AGE(_a)");
+        test("AGE(a, b)", "AGE(_a, _b)");
+        test("MAKE_DATE(y, m, d)", "MAKE_DATE(_y, _m, _d)");
+        test(
+            "MAKE_TIMESTAMP(y, mo, d, h, mi, s)",
+            "MAKE_TIMESTAMP(_y, _mo, _d, _h, _mi, _s)",
+        );
         test("LTRIM(s)", "LTRIM(_s)");
         test("LTRIM(s, c)", "LTRIM(_s, _c)");
         test("RTRIM(s)", "RTRIM(_s)");
@@ -346,6 +460,19 @@ mod tests {
         test("POSITION(a IN b)", "POSITION(_a IN _b)");
         test("FIND_IDX(a, b)", "FIND_IDX(_a, _b)");
         test("FIND_IDX(a, b, n)", "FIND_IDX(_a, _b, _n)");
+        test("REGEXP_LIKE(s, p)", "REGEXP_LIKE(_s, _p)");
+        test("REGEXP_LIKE(s, p, f)", "REGEXP_LIKE(_s, _p, _f)");
+        test("REGEXP_REPLACE(s, p, r)", "REGEXP_REPLACE(_s, _p, _r)");
+        test(
+            "REGEXP_REPLACE(s, p, r, f)",
+            "REGEXP_REPLACE(_s, _p, _r, _f)",
+        );
+        test("REGEXP_SUBSTR(s, p)", "REGEXP_SUBSTR(_s, _p)");
+        test("REGEXP_MATCHES(s, p, f)", "REGEXP_MATCHES(_s, _p, _f)");
+        test(
+            "REGEXP_SPLIT_TO_ARRAY(s, p)",
+            "REGEXP_SPLIT_TO_ARRAY(_s, _p)",
+        );
         test("ASCII(s)", "ASCII(_s)");
         test("CHR(n)", "CHR(_n)");
         test("MD5(s)", "MD5(_s)");
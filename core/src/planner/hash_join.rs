@@ -110,6 +110,9 @@ impl<'a, S: BuildHasher> Planner<'a> for HashJoinPlanner<'a, S> {
 
                 QueryPlan::Limit(LimitPlan { input, count })
             }
+            QueryPlan::Union(union) => QueryPlan::Union(
+                union.map_queries(|query| self.query(outer_context.clone(), query)),
+            ),
         }
     }
 
@@ -9,9 +9,9 @@ use {
         data::{Schema, SchemaIndex, SchemaIndexOrd, Value},
         plan::{
             AggregationInputPlan, DistinctInputPlan, DistinctPlan, ExprPlan, FilterInputPlan,
-            FilterPlan, IndexPredicatePlan, LimitInputPlan, LimitPlan, OffsetInputPlan, OffsetPlan,
-            OrderByExprPlan, ProjectInputPlan, ProjectPlan, QueryPlan, SelectOrderByPlan,
-            SourcePlan, StatementPlan, TableAccessPlan, plan_scalar_expr,
+            FilterPlan, FunctionExprPlan, IndexPredicatePlan, LimitInputPlan, LimitPlan,
+            OffsetInputPlan, OffsetPlan, OrderByExprPlan, ProjectInputPlan, ProjectPlan, QueryPlan,
+            SelectOrderByPlan, SourcePlan, StatementPlan, TableAccessPlan, plan_scalar_expr,
         },
     },
     std::{collections::HashMap, hash::BuildHasher, rc::Rc},
@@ -129,6 +129,9 @@ impl<'a, S: BuildHasher> Planner<'a> for IndexPlanner<'a, S> {
 
                 QueryPlan::Limit(LimitPlan { input, count })
             }
+            QueryPlan::Union(union) => QueryPlan::Union(
+                union.map_queries(|query| self.query(outer_context.clone(), query)),
+            ),
         }
     }
 
@@ -427,29 +430,24 @@ impl<'a, S: BuildHasher> IndexPlanner<'a, S> {
             }
             ExprPlan::BinaryOp {
                 left,
-                op: BinaryOperator::Gt,
+                op:
+                    op @ (BinaryOperator::Gt
+                    | BinaryOperator::Lt
+                    | BinaryOperator::GtEq
+                    | BinaryOperator::LtEq
+                    | BinaryOperator::Eq),
                 right,
-            } => self.search_index_op(outer_context, indexes, IndexOperator::Gt, *left, *right),
-            ExprPlan::BinaryOp {
-                left,
-                op: BinaryOperator::Lt,
-                right,
-            } => self.search_index_op(outer_context, indexes, IndexOperator::Lt, *left, *right),
-            ExprPlan::BinaryOp {
-                left,
-                op: BinaryOperator::GtEq,
-                right,
-            } => self.search_index_op(outer_context, indexes, IndexOperator::GtEq, *left, *right),
-            ExprPlan::BinaryOp {
-                left,
-                op: BinaryOperator::LtEq,
-                right,
-            } => self.search_index_op(outer_context, indexes, IndexOperator::LtEq, *left, *right),
-            ExprPlan::BinaryOp {
-                left,
-                op: BinaryOperator::Eq,
-                right,
-            } => self.search_index_op(outer_context, indexes, IndexOperator::Eq, *left, *right),
+            } => self.search_index_op(outer_context, indexes, op, *left, *right),
+            ExprPlan::Function(function)
+                if matches!(
+                    *function,
+                    FunctionExprPlan::StContains { .. }
+                        | FunctionExprPlan::StIntersects { .. }
+                        | FunctionExprPlan::StWithin { .. }
+                ) =>
+            {
+                self.search_spatial_index(outer_context, indexes, *function)
+            }
             expr => {
                 let expr = self.subquery_expr(outer_context, expr);
 
@@ -504,13 +502,16 @@ impl<'a, S: BuildHasher> IndexPlanner<'a, S> {
         &self,
         outer_context: Option<Rc<Context<'a>>>,
         indexes: &Indexes<'a>,
-        index_op: IndexOperator,
+        op: BinaryOperator,
         left: ExprPlan,
         right: ExprPlan,
     ) -> Planned {
-        if let Some(index_name) = indexes
-            .find(&left)
-            .filter(|_| is_deterministic(&right) && !may_return_null(&right))
+        let index_op = IndexOperator::from_binary_operator(&op);
+
+        if let Some(index_op) = index_op.clone()
+            && let Some(index_name) = indexes
+                .find(&left)
+                .filter(|_| is_deterministic(&right) && !may_return_null(&right))
         {
             let value_expr = self.subquery_expr(outer_context.clone(), right);
 
@@ -522,9 +523,10 @@ impl<'a, S: BuildHasher> IndexPlanner<'a, S> {
             };
         }
 
-        if let Some(index_name) = indexes
-            .find(&right)
-            .filter(|_| is_deterministic(&left) && !may_return_null(&left))
+        if let Some(index_op) = index_op
+            && let Some(index_name) = indexes
+                .find(&right)
+                .filter(|_| is_deterministic(&left) && !may_return_null(&left))
         {
             let value_expr = self.subquery_expr(outer_context.clone(), left);
 
@@ -537,11 +539,11 @@ impl<'a, S: BuildHasher> IndexPlanner<'a, S> {
         }
 
         if let ExprPlan::Nested(left) = left {
-            return self.search_index_op(outer_context, indexes, index_op, *left, right);
+            return self.search_index_op(outer_context, indexes, op, *left, right);
         }
 
         if let ExprPlan::Nested(right) = right {
-            return self.search_index_op(outer_context, indexes, index_op, left, *right);
+            return self.search_index_op(outer_context, indexes, op, left, *right);
         }
 
         let left = self.subquery_expr(outer_context.clone(), left);
@@ -549,10 +551,56 @@ impl<'a, S: BuildHasher> IndexPlanner<'a, S> {
 
         Planned::Expr(ExprPlan::BinaryOp {
             left: Box::new(left),
-            op: index_op.into(),
+            op,
             right: Box::new(right),
         })
     }
+
+    /// Spatial predicates can only narrow the scan down to rows whose bounding
+    /// box overlaps the other side, so the predicate itself stays as residual.
+    fn search_spatial_index(
+        &self,
+        outer_context: Option<Rc<Context<'a>>>,
+        indexes: &Indexes<'a>,
+        function: FunctionExprPlan,
+    ) -> Planned {
+        let searched = match &function {
+            FunctionExprPlan::StContains {
+                geometry1,
+                geometry2,
+            }
+            | FunctionExprPlan::StIntersects {
+                geometry1,
+                geometry2,
+            }
+            | FunctionExprPlan::StWithin {
+                geometry1,
+                geometry2,
+            } => [(geometry1, geometry2), (geometry2, geometry1)]
+                .into_iter()
+                .find_map(|(indexed, other)| {
+                    indexes
+                        .find(indexed)
+                        .filter(|_| is_deterministic(other) && !may_return_null(other))
+                        .map(|index_name| (index_name, other.clone()))
+                }),
+            _ => None,
+        };
+        let expr = self.subquery_expr(
+            outer_context.clone(),
+            ExprPlan::Function(Box::new(function)),
+        );
+
+        match searched {
+            Some((index_name, value_expr)) => Planned::IndexedExpr {
+                index_name,
+                index_op: IndexOperator::Intersects,
+                index_value_expr: self.subquery_expr(outer_context, value_expr),
+                residual: Some(expr),
+            },
+            None => Planned::Expr(expr),
+        }
+    }
 }
 
 struct PlannedSchemaIndex<'a> {
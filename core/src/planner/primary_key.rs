@@ -114,6 +114,9 @@ impl<'a, S: BuildHasher> Planner<'a> for PrimaryKeyPlanner<'a, S> {
 
                 QueryPlan::Limit(LimitPlan { input, count })
             }
+            QueryPlan::Union(union) => QueryPlan::Union(
+                union.map_queries(|query| self.query(outer_context.clone(), query)),
+            ),
         }
     }
 
@@ -984,6 +987,7 @@ mod tests {
             },
             selection: Some(expr("Player.id = Badge.user_id")),
             group_by: Vec::new(),
+            grouping_sets: None,
             having: None,
         });
         assert_eq!(actual, expected, "join but no primary key:\n{sql}");
@@ -1448,6 +1452,7 @@ mod tests {
                     },
                     selection: None,
                     group_by: Vec::new(),
+                    grouping_sets: None,
                     having: None,
                 })),
                 limit: Some(expr("1")),
@@ -1471,6 +1476,7 @@ mod tests {
                     right: Box::new(Expr::Subquery(Box::new(subquery))),
                 }),
                 group_by: Vec::new(),
+                grouping_sets: None,
                 having: None,
             })
         };
@@ -1499,6 +1505,7 @@ mod tests {
                     },
                     selection: Some(expr("id = id")),
                     group_by: Vec::new(),
+                    grouping_sets: None,
                     having: None,
                 })),
                 limit: None,
@@ -1522,6 +1529,7 @@ mod tests {
                     negated: false,
                 }),
                 group_by: Vec::new(),
+                grouping_sets: None,
                 having: None,
             })
         };
@@ -1566,6 +1574,7 @@ mod tests {
             },
             selection: Some(Expr::Nested(Box::new(expr("name")))),
             group_by: Vec::new(),
+            grouping_sets: None,
             having: None,
         });
         assert_eq!(actual, expected, "nested:\n{sql}");
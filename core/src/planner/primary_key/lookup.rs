@@ -105,9 +105,10 @@ impl JoinedRelation {
                 .and_then(|schema| schema.column_defs.as_deref())
                 .and_then(|column_defs| effective_columns(column_defs, table.alias.as_ref()))
                 .map_or(RelationColumns::Unknown, RelationColumns::Known),
-            SourcePlan::Derived(_) | SourcePlan::Series(_) | SourcePlan::Dictionary(_) => {
-                RelationColumns::Unknown
-            }
+            SourcePlan::Derived(_)
+            | SourcePlan::Series(_)
+            | SourcePlan::GenerateSeries(_)
+            | SourcePlan::Dictionary(_) => RelationColumns::Unknown,
         };
 
         Self {
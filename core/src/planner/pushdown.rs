@@ -0,0 +1,523 @@
+use {
+    super::{
+        PlannerError,
+        context::Context,
+        expr::{deterministic::is_deterministic, nullability::may_return_null, try_visit_expr},
+        query::Planner,
+    },
+    crate::{
+        ast::{BinaryOperator, IndexOperator},
+        data::Schema,
+        plan::{
+            AggregateFunctionPlan, AggregationInputPlan, CountArgExprPlan, DistinctInputPlan,
+            DistinctPlan, ExprPlan, FilterInputPlan, FilterPlan, LimitInputPlan, LimitPlan,
+            OffsetInputPlan, OffsetPlan, OrderByExprPlan, ProjectInputPlan, ProjectPlan,
+            ProjectionPlan, QueryPlan, ScanPredicatePlan, SelectItemPlan, SelectOrderByPlan,
+            SourcePlan, StatementPlan, TableAccessPlan, TableSourcePlan,
+        },
+    },
+    std::{
+        collections::{HashMap, HashSet},
+        hash::BuildHasher,
+        rc::Rc,
+    },
+};
+
+/// Turns full scans of single table queries into [`TableAccessPlan::Scan`].
+///
+/// Simple `column <op> value` conjuncts of the `WHERE` clause and the columns
+/// the query reads are handed to the storage, which is free to use them while
+/// scanning. The filter itself is kept, so results never depend on it.
+pub fn plan<S: BuildHasher>(
+    schema_map: &HashMap<String, Schema, S>,
+    statement: StatementPlan,
+) -> StatementPlan {
+    let planner = PushdownPlanner { schema_map };
+
+    match statement {
+        StatementPlan::Query(query) => {
+            let query = planner.query(None, query);
+
+            StatementPlan::Query(query)
+        }
+        _ => statement,
+    }
+}
+
+struct PushdownPlanner<'a, S> {
+    schema_map: &'a HashMap<String, Schema, S>,
+}
+
+impl<'a, S: BuildHasher> Planner<'a> for PushdownPlanner<'a, S> {
+    fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: QueryPlan) -> QueryPlan {
+        match query {
+            QueryPlan::Project(project) => {
+                QueryPlan::Project(self.project(outer_context.as_ref(), project, &[]))
+            }
+            QueryPlan::Values(values) => QueryPlan::Values(values),
+            QueryPlan::SelectOrderBy(order_by) => {
+                QueryPlan::SelectOrderBy(self.select_order_by(outer_context.as_ref(), order_by))
+            }
+            QueryPlan::ValuesOrderBy(order_by) => QueryPlan::ValuesOrderBy(order_by),
+            QueryPlan::Distinct(distinct) => {
+                QueryPlan::Distinct(self.distinct(outer_context.as_ref(), distinct))
+            }
+            QueryPlan::Offset(offset) => {
+                QueryPlan::Offset(self.offset(outer_context.as_ref(), offset))
+            }
+            QueryPlan::Limit(LimitPlan { input, count }) => {
+                let input = match input {
+                    LimitInputPlan::Project(project) => {
+                        LimitInputPlan::Project(self.project(outer_context.as_ref(), project, &[]))
+                    }
+                    LimitInputPlan::Values(values) => LimitInputPlan::Values(values),
+                    LimitInputPlan::SelectOrderBy(order_by) => LimitInputPlan::SelectOrderBy(
+                        self.select_order_by(outer_context.as_ref(), order_by),
+                    ),
+                    LimitInputPlan::ValuesOrderBy(order_by) => {
+                        LimitInputPlan::ValuesOrderBy(order_by)
+                    }
+                    LimitInputPlan::Distinct(distinct) => {
+                        LimitInputPlan::Distinct(self.distinct(outer_context.as_ref(), distinct))
+                    }
+                    LimitInputPlan::Offset(offset) => {
+                        LimitInputPlan::Offset(self.offset(outer_context.as_ref(), offset))
+                    }
+                };
+
+                QueryPlan::Limit(LimitPlan { input, count })
+            }
+            QueryPlan::Union(union) => QueryPlan::Union(
+                union.map_queries(|query| self.query(outer_context.clone(), query)),
+            ),
+        }
+    }
+
+    fn get_schema(&self, name: &str) -> Option<&'a Schema> {
+        self.schema_map.get(name)
+    }
+}
+
+impl<'a, S: BuildHasher> PushdownPlanner<'a, S> {
+    fn offset(
+        &self,
+        outer_context: Option<&Rc<Context<'a>>>,
+        OffsetPlan { input, count }: OffsetPlan,
+    ) -> OffsetPlan {
+        let input = match input {
+            OffsetInputPlan::Project(project) => {
+                OffsetInputPlan::Project(self.project(outer_context, project, &[]))
+            }
+            OffsetInputPlan::Values(values) => OffsetInputPlan::Values(values),
+            OffsetInputPlan::SelectOrderBy(order_by) => {
+                OffsetInputPlan::SelectOrderBy(self.select_order_by(outer_context, order_by))
+            }
+            OffsetInputPlan::ValuesOrderBy(order_by) => OffsetInputPlan::ValuesOrderBy(order_by),
+            OffsetInputPlan::Distinct(distinct) => {
+                OffsetInputPlan::Distinct(self.distinct(outer_context, distinct))
+            }
+        };
+
+        OffsetPlan { input, count }
+    }
+
+    fn distinct(
+        &self,
+        outer_context: Option<&Rc<Context<'a>>>,
+        DistinctPlan { input }: DistinctPlan,
+    ) -> DistinctPlan {
+        let input = match input {
+            DistinctInputPlan::Project(project) => {
+                DistinctInputPlan::Project(self.project(outer_context, project, &[]))
+            }
+            DistinctInputPlan::SelectOrderBy(order_by) => {
+                DistinctInputPlan::SelectOrderBy(self.select_order_by(outer_context, order_by))
+            }
+        };
+
+        DistinctPlan { input }
+    }
+
+    fn select_order_by(
+        &self,
+        outer_context: Option<&Rc<Context<'a>>>,
+        SelectOrderByPlan { input, exprs }: SelectOrderByPlan,
+    ) -> SelectOrderByPlan {
+        let input = self.project(outer_context, input, &exprs);
+
+        SelectOrderByPlan { input, exprs }
+    }
+
+    fn project(
+        &self,
+        outer_context: Option<&Rc<Context<'a>>>,
+        mut project: ProjectPlan,
+        order_by: &[OrderByExprPlan],
+    ) -> ProjectPlan {
+        let columns = referenced_columns(&project, order_by);
+
+        project.input = match project.input {
+            ProjectInputPlan::Source(source) => {
+                ProjectInputPlan::Source(self.source(source, None, columns.as_ref()))
+            }
+            ProjectInputPlan::Filter(filter) => {
+                ProjectInputPlan::Filter(self.filter(outer_context, filter, columns.as_ref()))
+            }
+            ProjectInputPlan::Aggregation(mut aggregation) => {
+                aggregation.input =
+                    self.aggregation_input(outer_context, aggregation.input, columns.as_ref());
+                ProjectInputPlan::Aggregation(aggregation)
+            }
+            ProjectInputPlan::Having(mut having) => {
+                having.input.input =
+                    self.aggregation_input(outer_context, having.input.input, columns.as_ref());
+                ProjectInputPlan::Having(having)
+            }
+            input @ (ProjectInputPlan::InnerJoin(_) | ProjectInputPlan::LeftOuterJoin(_)) => input,
+        };
+
+        project
+    }
+
+    fn aggregation_input(
+        &self,
+        outer_context: Option<&Rc<Context<'a>>>,
+        input: AggregationInputPlan,
+        columns: Option<&HashSet<String>>,
+    ) -> AggregationInputPlan {
+        match input {
+            AggregationInputPlan::Source(source) => {
+                AggregationInputPlan::Source(self.source(source, None, columns))
+            }
+            AggregationInputPlan::Filter(filter) => {
+                AggregationInputPlan::Filter(self.filter(outer_context, filter, columns))
+            }
+            input @ (AggregationInputPlan::InnerJoin(_)
+            | AggregationInputPlan::LeftOuterJoin(_)) => input,
+        }
+    }
+
+    fn filter(
+        &self,
+        outer_context: Option<&Rc<Context<'a>>>,
+        FilterPlan { input, expr }: FilterPlan,
+        columns: Option<&HashSet<String>>,
+    ) -> FilterPlan {
+        let input = match input {
+            FilterInputPlan::Source(source) => {
+                FilterInputPlan::Source(self.source(source, Some(&expr), columns))
+            }
+            input @ (FilterInputPlan::InnerJoin(_) | FilterInputPlan::LeftOuterJoin(_)) => input,
+        };
+        let expr = self.subquery_expr(outer_context.map(Rc::clone), expr);
+
+        FilterPlan { input, expr }
+    }
+
+    fn source(
+        &self,
+        source: SourcePlan,
+        filter_expr: Option<&ExprPlan>,
+        columns: Option<&HashSet<String>>,
+    ) -> SourcePlan {
+        let SourcePlan::Table(mut table) = source else {
+            return source;
+        };
+
+        let Some(column_defs) = self
+            .schema_map
+            .get(&table.name)
+            .and_then(|schema| schema.column_defs.as_ref())
+            .filter(|_| table.access == TableAccessPlan::FullScan)
+        else {
+            return SourcePlan::Table(table);
+        };
+
+        let names = column_names(&table, column_defs.iter().map(|def| def.name.as_str()));
+        let scan_table = ScanTable {
+            alias: table
+                .alias
+                .as_ref()
+                .map_or(table.name.as_str(), |alias| alias.name.as_str()),
+            names: &names,
+        };
+
+        let mut predicates = Vec::new();
+        if let Some(expr) = filter_expr {
+            scan_table.predicates(expr, &mut predicates);
+        }
+
+        let columns = columns
+            .map(|columns| {
+                names
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, name)| columns.contains(*name))
+                    .map(|(index, _)| index)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|columns| columns.len() < names.len());
+
+        if !predicates.is_empty() || columns.is_some() {
+            table.access = TableAccessPlan::Scan {
+                predicates,
+                columns,
+            };
+        }
+
+        SourcePlan::Table(table)
+    }
+}
+
+fn column_names<'b>(table: &TableSourcePlan, names: impl Iterator<Item = &'b str>) -> Vec<String> {
+    let aliases = table
+        .alias
+        .as_ref()
+        .map_or(&[][..], |alias| alias.columns.as_slice());
+
+    names
+        .enumerate()
+        .map(|(index, name)| aliases.get(index).map_or(name, String::as_str).to_owned())
+        .collect()
+}
+
+struct ScanTable<'b> {
+    alias: &'b str,
+    names: &'b [String],
+}
+
+impl ScanTable<'_> {
+    fn predicates(&self, expr: &ExprPlan, predicates: &mut Vec<ScanPredicatePlan>) {
+        match expr {
+            ExprPlan::Nested(expr) => self.predicates(expr, predicates),
+            ExprPlan::BinaryOp {
+                left,
+                op: BinaryOperator::And,
+                right,
+            } => {
+                self.predicates(left, predicates);
+                self.predicates(right, predicates);
+            }
+            ExprPlan::BinaryOp { left, op, right } => {
+                let Some(operator) = IndexOperator::from_binary_operator(op) else {
+                    return;
+                };
+
+                let predicate = match (self.column(left), self.column(right)) {
+                    (Some(column), _) if is_value(right) => ScanPredicatePlan {
+                        column,
+                        operator,
+                        expr: *right.clone(),
+                    },
+                    (_, Some(column)) if is_value(left) => ScanPredicatePlan {
+                        column,
+                        operator: operator.reverse(),
+                        expr: *left.clone(),
+                    },
+                    _ => return,
+                };
+
+                predicates.push(predicate);
+            }
+            _ => {}
+        }
+    }
+
+    fn column(&self, expr: &ExprPlan) -> Option<usize> {
+        let name = match expr {
+            ExprPlan::Identifier(name) => name,
+            ExprPlan::CompoundIdentifier { alias, ident } if alias == self.alias => ident,
+            _ => return None,
+        };
+
+        self.names.iter().position(|column| column == name)
+    }
+}
+
+fn is_value(expr: &ExprPlan) -> bool {
+    is_deterministic(expr) && !may_return_null(expr)
+}
+
+/// Collects the column names a query reads, or `None` when it may read all of them.
+fn referenced_columns(
+    project: &ProjectPlan,
+    order_by: &[OrderByExprPlan],
+) -> Option<HashSet<String>> {
+    let mut exprs = Vec::new();
+
+    match &project.projection {
+        ProjectionPlan::SelectItems(items) => {
+            for item in items {
+                match item {
+                    SelectItemPlan::Expr { expr, .. } => exprs.push(expr),
+                    SelectItemPlan::QualifiedWildcard(_) | SelectItemPlan::Wildcard => {
+                        return None;
+                    }
+                }
+            }
+        }
+        ProjectionPlan::SchemalessMap => return None,
+    }
+
+    exprs.extend(order_by.iter().map(|order_by| &order_by.expr));
+
+    let aggregation = match &project.input {
+        ProjectInputPlan::Source(_) => None,
+        ProjectInputPlan::Filter(filter) => {
+            exprs.push(&filter.expr);
+            None
+        }
+        ProjectInputPlan::Aggregation(aggregation) => Some(aggregation),
+        ProjectInputPlan::Having(having) => {
+            exprs.push(&having.expr);
+            Some(&having.input)
+        }
+        ProjectInputPlan::InnerJoin(_) | ProjectInputPlan::LeftOuterJoin(_) => return None,
+    };
+
+    let aggregate_slots;
+    if let Some(aggregation) = aggregation {
+        exprs.extend(&aggregation.group_by);
+
+        if let AggregationInputPlan::Filter(filter) = &aggregation.input {
+            exprs.push(&filter.expr);
+        }
+
+        aggregate_slots = aggregation
+            .aggregate_slots
+            .iter()
+            .map(|slot| ExprPlan::Aggregate(Box::new(slot.clone())))
+            .collect::<Vec<_>>();
+        exprs.extend(&aggregate_slots);
+    }
+
+    let mut columns = HashSet::new();
+    for expr in exprs {
+        // A subquery may read any column of the outer row, and a wildcard
+        // aggregate such as `COUNT(DISTINCT *)` compares whole rows, so both
+        // stop the walk.
+        try_visit_expr(expr, &mut |expr| match expr {
+            ExprPlan::Identifier(name) | ExprPlan::CompoundIdentifier { ident: name, .. } => {
+                columns.insert(name.clone());
+                Ok(())
+            }
+            ExprPlan::Subquery(_) | ExprPlan::Exists { .. } | ExprPlan::InSubquery { .. } => {
+                Err(PlannerError::Unreachable)
+            }
+            ExprPlan::Aggregate(aggregate)
+                if matches!(
+                    aggregate.func,
+                    AggregateFunctionPlan::Count(CountArgExprPlan::Wildcard)
+                ) =>
+            {
+                Err(PlannerError::Unreachable)
+            }
+            _ => Ok(()),
+        })
+        .ok()?;
+    }
+
+    Some(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::plan as plan_pushdown,
+        crate::{
+            ast::{IndexOperator, Literal},
+            mock::{MockStorage, run},
+            parse_sql::parse,
+            plan::{ExprPlan, ScanPredicatePlan, SourcePlan, StatementPlan, TableAccessPlan},
+            planner::fetch_schema_map,
+            translate::translate,
+        },
+    };
+
+    fn access(storage: &MockStorage, sql: &str) -> TableAccessPlan {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+        let statement = StatementPlan::from(translate(&parsed).unwrap());
+        let schema_map = fetch_schema_map(storage, &statement).unwrap();
+        let StatementPlan::Query(query) = plan_pushdown(&schema_map, statement) else {
+            panic!("expected query:\n{sql}");
+        };
+        let Some(SourcePlan::Table(table)) = query.project().map(|p| p.input.base_source()) else {
+            panic!("expected table source:\n{sql}");
+        };
+
+        table.access.clone()
+    }
+
+    fn predicate(column: usize, operator: IndexOperator, value: i64) -> ScanPredicatePlan {
+        ScanPredicatePlan {
+            column,
+            operator,
+            expr: ExprPlan::Literal(Literal::Number(value.into())),
+        }
+    }
+
+    #[test]
+    fn pushdown() {
+        let storage = run("
+            CREATE TABLE Item (id INTEGER, name TEXT, price INTEGER, memo TEXT);
+            CREATE TABLE Other (id INTEGER);
+        ");
+
+        let sql = "SELECT * FROM Item";
+        assert_eq!(access(&storage, sql), TableAccessPlan::FullScan, "{sql}");
+
+        let sql = "SELECT id, name FROM Item";
+        let expected = TableAccessPlan::Scan {
+            predicates: Vec::new(),
+            columns: Some(vec![0, 1]),
+        };
+        assert_eq!(access(&storage, sql), expected, "{sql}");
+
+        let sql = "SELECT * FROM Item WHERE id > 1 AND 10 >= price AND name LIKE 'a%'";
+        let expected = TableAccessPlan::Scan {
+            predicates: vec![
+                predicate(0, IndexOperator::Gt, 1),
+                predicate(2, IndexOperator::LtEq, 10),
+            ],
+            columns: None,
+        };
+        assert_eq!(access(&storage, sql), expected, "{sql}");
+
+        let sql = "SELECT i.name FROM Item i WHERE i.id = 3 ORDER BY price";
+        let expected = TableAccessPlan::Scan {
+            predicates: vec![predicate(0, IndexOperator::Eq, 3)],
+            columns: Some(vec![0, 1, 2]),
+        };
+        assert_eq!(access(&storage, sql), expected, "{sql}");
+
+        let sql = "SELECT COUNT(price) FROM Item GROUP BY memo";
+        let expected = TableAccessPlan::Scan {
+            predicates: Vec::new(),
+            columns: Some(vec![2, 3]),
+        };
+        assert_eq!(access(&storage, sql), expected, "{sql}");
+
+        let sql = "SELECT COUNT(*) FROM Item GROUP BY memo";
+        assert_eq!(access(&storage, sql), TableAccessPlan::FullScan, "{sql}");
+
+        let sql = "SELECT COUNT(DISTINCT *) FROM Item WHERE id = 1";
+        let expected = TableAccessPlan::Scan {
+            predicates: vec![predicate(0, IndexOperator::Eq, 1)],
+            columns: None,
+        };
+        assert_eq!(access(&storage, sql), expected, "{sql}");
+
+        let sql = "SELECT name FROM Item WHERE id = (SELECT id FROM Other)";
+        assert_eq!(access(&storage, sql), TableAccessPlan::FullScan, "{sql}");
+
+        let sql = "SELECT name FROM Item WHERE id = price OR id = 1";
+        let expected = TableAccessPlan::Scan {
+            predicates: Vec::new(),
+            columns: Some(vec![0, 1, 2]),
+        };
+        assert_eq!(access(&storage, sql), expected, "{sql}");
+
+        let sql = "SELECT id FROM Item JOIN Other ON Item.id = Other.id WHERE Item.id = 1";
+        assert_eq!(access(&storage, sql), TableAccessPlan::FullScan, "{sql}");
+    }
+}
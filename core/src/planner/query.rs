@@ -210,7 +210,10 @@ pub trait Planner<'a> {
 
                 (&table.name, alias)
             }
-            SourcePlan::Derived(_) | SourcePlan::Series(_) | SourcePlan::Dictionary(_) => {
+            SourcePlan::Derived(_)
+            | SourcePlan::Series(_)
+            | SourcePlan::GenerateSeries(_)
+            | SourcePlan::Dictionary(_) => {
                 return next;
             }
         };
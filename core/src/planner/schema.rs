@@ -9,7 +9,7 @@ use {
             LimitInputPlan, LimitPlan, NestedLoopJoinInputPlan, NestedLoopJoinPlan,
             OffsetInputPlan, OffsetPlan, OrderByExprPlan, ProjectInputPlan, ProjectPlan,
             ProjectionPlan, QueryPlan, SelectItemPlan, SelectOrderByPlan, SourcePlan,
-            StatementPlan, ValuesOrderByPlan,
+            StatementPlan, UnionPlan, ValuesOrderByPlan,
         },
         result::Result,
         store::Store,
@@ -121,6 +121,7 @@ fn scan_query<T: Store + ?Sized>(
                 .chain(scan_expr(storage, count)?)
                 .collect())
         }
+        QueryPlan::Union(union) => scan_union(storage, union),
     }
 }
 
@@ -161,6 +162,34 @@ fn scan_select_order_by<T: Store + ?Sized>(
     scan_order_by_exprs(storage, schema_list, exprs)
 }
 
+fn scan_union<T: Store + ?Sized>(
+    storage: &T,
+    UnionPlan {
+        left,
+        right,
+        order_by,
+        offset,
+        limit,
+        ..
+    }: &UnionPlan,
+) -> Result<HashMap<String, Schema>> {
+    let schema_list = scan_query(storage, left)?
+        .into_iter()
+        .chain(scan_query(storage, right)?)
+        .collect();
+    let schema_list = scan_order_by_exprs(storage, schema_list, order_by)?;
+
+    offset
+        .iter()
+        .chain(limit)
+        .try_fold(schema_list, |schema_list, count| {
+            Ok(schema_list
+                .into_iter()
+                .chain(scan_expr(storage, count)?)
+                .collect())
+        })
+}
+
 fn scan_values_order_by<T: Store + ?Sized>(
     storage: &T,
     ValuesOrderByPlan { exprs, .. }: &ValuesOrderByPlan,
@@ -347,7 +376,9 @@ where
             Ok(schema_list)
         }
         SourcePlan::Derived(derived) => scan_query(storage, &derived.query),
-        SourcePlan::Series(_) | SourcePlan::Dictionary(_) => Ok(HashMap::new()),
+        SourcePlan::Series(_) | SourcePlan::GenerateSeries(_) | SourcePlan::Dictionary(_) => {
+            Ok(HashMap::new())
+        }
     }
 }
 
@@ -117,6 +117,7 @@ fn transform_statement<S: BuildHasher>(
             engine,
             foreign_keys,
             comment,
+            ttl_column,
         } => {
             if let Some(source) = source.as_mut() {
                 transform_query(schema_map, source);
@@ -129,6 +130,7 @@ fn transform_statement<S: BuildHasher>(
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
             }
         }
         _ => statement,
@@ -9,7 +9,7 @@ use {
             JoinConditionInputPlan, JoinConditionPlan, LeftOuterJoinInputPlan, LeftOuterJoinPlan,
             LimitInputPlan, LimitPlan, NestedLoopJoinInputPlan, NestedLoopJoinPlan,
             OffsetInputPlan, OffsetPlan, ProjectInputPlan, ProjectPlan, ProjectionPlan, QueryPlan,
-            SelectItemPlan, SelectOrderByPlan, SourcePlan, ValuesOrderByPlan,
+            SelectItemPlan, SelectOrderByPlan, SourcePlan, UnionPlan, ValuesOrderByPlan,
         },
     },
     std::{
@@ -60,6 +60,7 @@ pub(super) fn transform_query<S: BuildHasher>(
             };
             transform_query_expr(schema_map, count, &state);
         }
+        QueryPlan::Union(union) => transform_union(schema_map, union),
     }
 }
 
@@ -103,6 +104,29 @@ fn transform_select_order_by<S: BuildHasher>(
     state
 }
 
+fn transform_union<S: BuildHasher>(
+    schema_map: &HashMap<String, Schema, S>,
+    UnionPlan {
+        left,
+        right,
+        order_by,
+        offset,
+        limit,
+        ..
+    }: &mut UnionPlan,
+) {
+    transform_query(schema_map, left);
+    transform_query(schema_map, right);
+
+    let state = empty_rewrite_state();
+    for order_by in order_by {
+        transform_query_expr(schema_map, &mut order_by.expr, &state);
+    }
+    for count in offset.iter_mut().chain(limit) {
+        transform_query_expr(schema_map, count, &state);
+    }
+}
+
 fn transform_values_order_by<S: BuildHasher>(
     schema_map: &HashMap<String, Schema, S>,
     ValuesOrderByPlan { exprs, .. }: &mut ValuesOrderByPlan,
@@ -8,8 +8,8 @@ use {
             JoinConditionInputPlan, JoinConditionPlan, LeftOuterJoinInputPlan, LeftOuterJoinPlan,
             LimitInputPlan, LimitPlan, NestedLoopJoinInputPlan, NestedLoopJoinPlan,
             OffsetInputPlan, OffsetPlan, ProjectInputPlan, ProjectPlan, ProjectionPlan, QueryPlan,
-            SelectItemPlan, SelectOrderByPlan, SourcePlan, StatementPlan, ValuesOrderByPlan,
-            ValuesPlan,
+            SelectItemPlan, SelectOrderByPlan, SourcePlan, StatementPlan, UnionPlan,
+            ValuesOrderByPlan, ValuesPlan,
         },
         result::Result,
     },
@@ -93,6 +93,7 @@ fn validate_query(
 
             validate_expr(schema_map, count)
         }
+        QueryPlan::Union(union) => validate_union(schema_map, union),
     }
 }
 
@@ -139,6 +140,29 @@ fn validate_select_order_by(
     Ok(())
 }
 
+fn validate_union(
+    schema_map: &HashMap<String, Schema, impl BuildHasher>,
+    UnionPlan {
+        left,
+        right,
+        order_by,
+        offset,
+        limit,
+        ..
+    }: &UnionPlan,
+) -> ValidateResult {
+    validate_query(schema_map, left)?;
+    validate_query(schema_map, right)?;
+    for order_by in order_by {
+        validate_expr(schema_map, &order_by.expr)?;
+    }
+    for count in offset.iter().chain(limit) {
+        validate_expr(schema_map, count)?;
+    }
+
+    Ok(())
+}
+
 fn validate_values_order_by(
     schema_map: &HashMap<String, Schema, impl BuildHasher>,
     ValuesOrderByPlan { input, exprs }: &ValuesOrderByPlan,
@@ -139,7 +139,7 @@ fn contextualize_source<'a>(
             schema.map(|schema| Rc::from(Context::new(get_labels(schema), None)))
         }
         SourcePlan::Derived(derived) => contextualize_query(schema_map, &derived.query),
-        SourcePlan::Series(_) | SourcePlan::Dictionary(_) => None,
+        SourcePlan::Series(_) | SourcePlan::GenerateSeries(_) | SourcePlan::Dictionary(_) => None,
     }
 }
 
@@ -68,7 +68,8 @@ pub use {
         DistinctNode, FilterNode, GroupByNode, HavingNode, InnerHashJoinNode,
         InnerJoinConditionNode, InnerNestedLoopJoinNode, LeftOuterHashJoinNode,
         LeftOuterJoinConditionNode, LeftOuterNestedLoopJoinNode, LimitNode, OffsetLimitNode,
-        OffsetNode, ProjectNode, SelectNode, SelectOrderByNode, ValuesOrderByNode, select, values,
+        OffsetNode, ProjectNode, SelectNode, SelectOrderByNode, UnionNode, ValuesOrderByNode,
+        select, values,
     },
     select_item::SelectItemNode,
     select_item_list::SelectItemList,
@@ -55,6 +55,7 @@ impl Build for CreateTableNode {
             engine: None,
             foreign_keys: Vec::new(),
             comment: None,
+            ttl_column: None,
         }
         .into())
     }
@@ -121,6 +121,7 @@ impl AggregateNode<'_> {
         Ok(AggregateExprPlan {
             func,
             distinct,
+            filter: None,
             slot: None,
         })
     }
@@ -28,6 +28,34 @@ impl<'a> CaseNode<'a> {
             when_then: vec![(when.into(), then.into())],
         }
     }
+
+    pub fn when<W: Into<ExprNode<'a>>>(self, when: W) -> WhenNode<'a> {
+        WhenNode {
+            prev_node: self,
+            when_then: Vec::new(),
+            when: when.into(),
+        }
+    }
+}
+
+/// `WHEN` condition waiting for its `THEN` result.
+#[derive(Clone, Debug)]
+pub struct WhenNode<'a> {
+    prev_node: CaseNode<'a>,
+    when_then: Vec<(ExprNode<'a>, ExprNode<'a>)>,
+    when: ExprNode<'a>,
+}
+
+impl<'a> WhenNode<'a> {
+    pub fn then<T: Into<ExprNode<'a>>>(self, then: T) -> WhenThenNode<'a> {
+        let mut when_then = self.when_then;
+        when_then.push((self.when, then.into()));
+
+        WhenThenNode {
+            prev_node: self.prev_node,
+            when_then,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -46,6 +74,14 @@ impl<'a> WhenThenNode<'a> {
         self
     }
 
+    pub fn when<W: Into<ExprNode<'a>>>(self, when: W) -> WhenNode<'a> {
+        WhenNode {
+            prev_node: self.prev_node,
+            when_then: self.when_then,
+            when: when.into(),
+        }
+    }
+
     pub fn or_else<T: Into<ExprNode<'a>>>(self, else_result: T) -> ExprNode<'a> {
         ExprNode::Case {
             operand: self.prev_node.operand,
@@ -118,4 +154,27 @@ mod tests {
             ";
         test_expr(actual, expected);
     }
+
+    #[test]
+    fn when_then_separately() {
+        let actual = case()
+            .when(col("id").lt(10))
+            .then(text("low"))
+            .when_then(col("id").lt(100), text("mid"))
+            .when("id >= 100")
+            .then(text("high"))
+            .end();
+        let expected = "
+            CASE
+              WHEN id < 10 THEN 'low'
+              WHEN id < 100 THEN 'mid'
+              WHEN id >= 100 THEN 'high'
+            END
+            ";
+        test_expr(actual, expected);
+
+        let actual = col("id").case().when(1).then(text("a")).or_else(text("b"));
+        let expected = "CASE id WHEN 1 THEN 'a' ELSE 'b' END";
+        test_expr(actual, expected);
+    }
 }
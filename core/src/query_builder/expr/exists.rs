@@ -16,7 +16,7 @@ pub fn not_exists<'a, T: Into<QueryNode<'a>>>(query: T) -> ExprNode<'a> {
 
 #[cfg(test)]
 mod test {
-    use crate::query_builder::{Build, col, exists, not_exists, table, test, test_expr};
+    use crate::query_builder::{Build, QueryNode, col, exists, not_exists, table, test, test_expr};
 
     #[test]
     fn exist() {
@@ -66,5 +66,21 @@ mod test {
         let actual = not_exists("SELECT * FROM FOO");
         let expected = "NOT EXISTS (SELECT * FROM FOO)";
         test_expr(actual, expected);
+
+        let actual = table("FOO").select().filter(col("id").gt(2)).exists();
+        let expected = "EXISTS (SELECT * FROM FOO WHERE id > 2)";
+        test_expr(actual, expected);
+
+        let actual = table("FOO")
+            .select()
+            .join("BAR")
+            .on("FOO.id = BAR.id")
+            .not_exists();
+        let expected = "NOT EXISTS (SELECT * FROM FOO JOIN BAR ON FOO.id = BAR.id)";
+        test_expr(actual, expected);
+
+        let actual = QueryNode::from("SELECT * FROM FOO").exists();
+        let expected = "EXISTS (SELECT * FROM FOO)";
+        test_expr(actual, expected);
     }
 }
@@ -4,7 +4,8 @@ use {
         DistinctNode, FilterNode, GroupByNode, HavingNode, InnerHashJoinNode,
         InnerJoinConditionNode, InnerNestedLoopJoinNode, LeftOuterHashJoinNode,
         LeftOuterJoinConditionNode, LeftOuterNestedLoopJoinNode, LimitNode, OffsetLimitNode,
-        OffsetNode, ProjectNode, QueryNode, SelectNode, SelectOrderByNode, ValuesOrderByNode,
+        OffsetNode, ProjectNode, QueryNode, SelectNode, SelectOrderByNode, UnionNode,
+        ValuesOrderByNode,
     },
 };
 
@@ -60,6 +61,7 @@ impl_from_select_nodes!(ProjectNode<'a>);
 impl_from_select_nodes!(SelectOrderByNode<'a>);
 impl_from_select_nodes!(ValuesOrderByNode<'a>);
 impl_from_select_nodes!(DistinctNode<'a>);
+impl_from_select_nodes!(UnionNode<'a>);
 
 impl<'a> ExprNode<'a> {
     #[must_use]
@@ -79,6 +81,18 @@ impl<'a> ExprNode<'a> {
             negated: true,
         }
     }
+
+    /// Unlike `in_list`, accepts only a query, so a text argument is parsed
+    /// as a query rather than as a list of expressions.
+    #[must_use]
+    pub fn in_subquery<T: Into<QueryNode<'a>>>(self, query: T) -> Self {
+        self.in_list(InListNode::Query(Box::new(query.into())))
+    }
+
+    #[must_use]
+    pub fn not_in_subquery<T: Into<QueryNode<'a>>>(self, query: T) -> Self {
+        self.not_in_list(InListNode::Query(Box::new(query.into())))
+    }
 }
 
 #[cfg(test)]
@@ -103,6 +117,26 @@ mod test {
         test_expr(actual, expected);
     }
 
+    #[test]
+    fn in_subquery() {
+        let actual = col("id").in_subquery(table("FOO").select().project("id"));
+        let expected = "id IN (SELECT id FROM FOO)";
+        test_expr(actual, expected);
+
+        let actual = col("id").not_in_subquery("SELECT id FROM FOO");
+        let expected = "id NOT IN (SELECT id FROM FOO)";
+        test_expr(actual, expected);
+
+        let actual = col("id").in_subquery(
+            table("FOO")
+                .select()
+                .project("id")
+                .union(table("BAR").select().project("id")),
+        );
+        let expected = "id IN (SELECT id FROM FOO UNION SELECT id FROM BAR)";
+        test_expr(actual, expected);
+    }
+
     #[test]
     fn from_nodes() {
         // from Vec<ExprNode>
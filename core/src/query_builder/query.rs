@@ -1,9 +1,10 @@
 use {
     super::{
-        DistinctNode, ExprList, FilterNode, GroupByNode, HavingNode, InnerHashJoinNode,
+        DistinctNode, ExprList, ExprNode, FilterNode, GroupByNode, HavingNode, InnerHashJoinNode,
         InnerJoinConditionNode, InnerNestedLoopJoinNode, LeftOuterHashJoinNode,
         LeftOuterJoinConditionNode, LeftOuterNestedLoopJoinNode, LimitNode, OffsetLimitNode,
-        OffsetNode, ProjectNode, SelectNode, SelectOrderByNode, SourceNode, ValuesOrderByNode,
+        OffsetNode, ProjectNode, SelectNode, SelectOrderByNode, SourceNode, UnionNode,
+        ValuesOrderByNode,
         select::{BuildQuery, BuildQueryPlan, ValuesNode},
     },
     crate::{
@@ -37,6 +38,7 @@ pub enum QueryNode<'a> {
     SelectOrderByNode(SelectOrderByNode<'a>),
     ValuesOrderByNode(ValuesOrderByNode<'a>),
     DistinctNode(DistinctNode<'a>),
+    UnionNode(UnionNode<'a>),
 }
 
 impl<'a> QueryNode<'a> {
@@ -47,6 +49,28 @@ impl<'a> QueryNode<'a> {
         }
     }
 
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        UnionNode::new(self, query.into(), false)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        UnionNode::new(self, query.into(), true)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        ExprNode::Exists {
+            subquery: Box::new(self),
+            negated: false,
+        }
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        ExprNode::Exists {
+            subquery: Box::new(self),
+            negated: true,
+        }
+    }
+
     pub(super) fn build_query(self) -> Result<Query> {
         match self {
             QueryNode::Text(query_node) => {
@@ -83,6 +107,7 @@ impl<'a> QueryNode<'a> {
             QueryNode::SelectOrderByNode(node) => node.build_query(),
             QueryNode::ValuesOrderByNode(node) => node.build_query(),
             QueryNode::DistinctNode(node) => node.build_query(),
+            QueryNode::UnionNode(node) => node.build_query(),
         }
     }
 
@@ -116,6 +141,7 @@ impl<'a> QueryNode<'a> {
             QueryNode::SelectOrderByNode(node) => node.build_query_plan(),
             QueryNode::ValuesOrderByNode(node) => node.build_query_plan(),
             QueryNode::DistinctNode(node) => node.build_query_plan(),
+            QueryNode::UnionNode(node) => node.build_query_plan(),
         }
     }
 }
@@ -142,6 +168,7 @@ macro_rules! impl_from_select_nodes {
     };
 }
 
+impl_from_select_nodes!(ValuesNode);
 impl_from_select_nodes!(InnerNestedLoopJoinNode);
 impl_from_select_nodes!(LeftOuterNestedLoopJoinNode);
 impl_from_select_nodes!(InnerHashJoinNode);
@@ -158,6 +185,7 @@ impl_from_select_nodes!(ProjectNode);
 impl_from_select_nodes!(SelectOrderByNode);
 impl_from_select_nodes!(ValuesOrderByNode);
 impl_from_select_nodes!(DistinctNode);
+impl_from_select_nodes!(UnionNode);
 
 #[cfg(test)]
 mod test {
@@ -9,6 +9,7 @@ mod offset_limit;
 mod order_by;
 mod project;
 mod root;
+mod union;
 mod values;
 
 use {
@@ -38,6 +39,7 @@ pub use {
     order_by::{SelectOrderByNode, ValuesOrderByNode},
     project::ProjectNode,
     root::{SelectNode, select},
+    union::UnionNode,
     values::{ValuesNode, values},
 };
 
@@ -7,7 +7,7 @@ use {
             ExprNode, FilterNode, GroupByNode, HavingNode, InnerHashJoinNode,
             InnerJoinConditionNode, InnerNestedLoopJoinNode, LeftOuterHashJoinNode,
             LeftOuterJoinConditionNode, LeftOuterNestedLoopJoinNode, LimitNode, OffsetNode,
-            ProjectNode, QueryNode, SelectNode, SelectOrderByNode, SourceNode,
+            ProjectNode, QueryNode, SelectNode, SelectOrderByNode, SourceNode, UnionNode,
         },
         result::Result,
     },
@@ -157,6 +157,22 @@ impl<'a> DistinctNode<'a> {
         QueryNode::DistinctNode(self).alias_as(table_alias)
     }
 
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::DistinctNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::DistinctNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::DistinctNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::DistinctNode(self).not_exists()
+    }
+
     pub(super) fn build_distinct_plan(self) -> Result<DistinctPlan> {
         self.prev_node
             .build_distinct_input_plan()
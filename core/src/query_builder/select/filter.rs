@@ -10,7 +10,7 @@ use {
             ExprList, ExprNode, GroupByNode, HavingNode, InnerHashJoinNode, InnerJoinConditionNode,
             InnerNestedLoopJoinNode, LeftOuterHashJoinNode, LeftOuterJoinConditionNode,
             LeftOuterNestedLoopJoinNode, LimitNode, OffsetNode, OrderByExprList, ProjectNode,
-            QueryNode, SelectItemList, SelectNode, SelectOrderByNode, SourceNode,
+            QueryNode, SelectItemList, SelectNode, SelectOrderByNode, SourceNode, UnionNode,
         },
         result::Result,
     },
@@ -152,6 +152,22 @@ impl<'a> FilterNode<'a> {
     pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
         QueryNode::FilterNode(self).alias_as(table_alias)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::FilterNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::FilterNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::FilterNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::FilterNode(self).not_exists()
+    }
 }
 
 impl BuildFilterPlan for FilterNode<'_> {
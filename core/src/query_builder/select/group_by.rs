@@ -10,7 +10,7 @@ use {
             ExprList, ExprNode, FilterNode, HavingNode, InnerHashJoinNode, InnerJoinConditionNode,
             InnerNestedLoopJoinNode, LeftOuterHashJoinNode, LeftOuterJoinConditionNode,
             LeftOuterNestedLoopJoinNode, LimitNode, OffsetNode, OrderByExprList, ProjectNode,
-            QueryNode, SelectItemList, SelectNode, SelectOrderByNode, SourceNode,
+            QueryNode, SelectItemList, SelectNode, SelectOrderByNode, SourceNode, UnionNode,
         },
         result::Result,
     },
@@ -150,6 +150,22 @@ impl<'a> GroupByNode<'a> {
     pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
         QueryNode::GroupByNode(self).alias_as(table_alias)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::GroupByNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::GroupByNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::GroupByNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::GroupByNode(self).not_exists()
+    }
 }
 
 impl BuildAggregationPlan for GroupByNode<'_> {
@@ -157,6 +173,7 @@ impl BuildAggregationPlan for GroupByNode<'_> {
         Ok(AggregationPlan {
             input: self.prev_node.build_aggregation_input_plan()?,
             group_by: self.expr_list.build_exprs_plan()?,
+            grouping_sets: None,
             aggregate_slots: Vec::new(),
         })
     }
@@ -269,6 +286,7 @@ mod tests {
                 input: ProjectInputPlan::Aggregation(AggregationPlan {
                     input: AggregationInputPlan::InnerJoin(Box::new(join)),
                     group_by: vec![col("PlayerItem.category").build_expr_plan().unwrap()],
+                    grouping_sets: None,
                     aggregate_slots: Vec::new(),
                 }),
                 projection: ProjectionPlan::SelectItems(
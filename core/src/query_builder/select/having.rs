@@ -10,7 +10,7 @@ use {
             ExprNode, FilterNode, GroupByNode, InnerHashJoinNode, InnerJoinConditionNode,
             InnerNestedLoopJoinNode, LeftOuterHashJoinNode, LeftOuterJoinConditionNode,
             LeftOuterNestedLoopJoinNode, LimitNode, OffsetNode, OrderByExprList, ProjectNode,
-            QueryNode, SelectItemList, SelectNode, SelectOrderByNode, SourceNode,
+            QueryNode, SelectItemList, SelectNode, SelectOrderByNode, SourceNode, UnionNode,
         },
         result::Result,
     },
@@ -45,6 +45,7 @@ impl PrevNode<'_> {
         .map(|input| AggregationPlan {
             input,
             group_by: Vec::new(),
+            grouping_sets: None,
             aggregate_slots: Vec::new(),
         })
     }
@@ -157,6 +158,22 @@ impl<'a> HavingNode<'a> {
     pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
         QueryNode::HavingNode(self).alias_as(table_alias)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::HavingNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::HavingNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::HavingNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::HavingNode(self).not_exists()
+    }
 }
 
 impl BuildHavingPlan for HavingNode<'_> {
@@ -272,6 +289,7 @@ mod tests {
                         }),
                     })),
                     group_by: Vec::new(),
+                    grouping_sets: None,
                     aggregate_slots: Vec::new(),
                 },
                 expr: ExprPlan::Value(Value::Bool(true)),
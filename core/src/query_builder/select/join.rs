@@ -1,8 +1,3 @@
-use crate::{
-    ast::{TableAlias, TableFactor},
-    plan::{SourcePlan, TableAccessPlan, TableAliasPlan, TableSourcePlan},
-};
-
 mod inner_hash_join;
 mod inner_join_condition;
 mod inner_nested_loop_join;
@@ -16,24 +11,3 @@ pub use {
     left_outer_join_condition::LeftOuterJoinConditionNode,
     left_outer_nested_loop_join::LeftOuterNestedLoopJoinNode,
 };
-
-fn table_source_plan(name: String, alias: Option<String>) -> SourcePlan {
-    SourcePlan::Table(TableSourcePlan {
-        name,
-        alias: alias.map(|name| TableAliasPlan {
-            name,
-            columns: Vec::new(),
-        }),
-        access: TableAccessPlan::FullScan,
-    })
-}
-
-fn table_factor(name: String, alias: Option<String>) -> TableFactor {
-    TableFactor::Table {
-        name,
-        alias: alias.map(|name| TableAlias {
-            name,
-            columns: Vec::new(),
-        }),
-    }
-}
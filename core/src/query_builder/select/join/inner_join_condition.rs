@@ -8,10 +8,11 @@ use crate::{
         DistinctNode, ExprList, ExprNode, FilterNode, GroupByNode, HavingNode, InnerHashJoinNode,
         InnerNestedLoopJoinNode, LeftOuterNestedLoopJoinNode, LimitNode, OffsetNode,
         OrderByExprList, ProjectNode, QueryBuilderError, QueryNode, SelectItemList,
-        SelectOrderByNode, SourceNode,
+        SelectOrderByNode, SourceNode, UnionNode,
         select::{
             BuildAggregationInputPlan, BuildFilterInputPlan, BuildProjectInputPlan, BuildSelect,
         },
+        table,
     },
     result::Result,
 };
@@ -67,20 +68,20 @@ impl<'a> InnerJoinConditionNode<'a> {
         })
     }
 
-    pub fn join(self, table_name: &str) -> InnerNestedLoopJoinNode<'a> {
-        InnerNestedLoopJoinNode::new(self, table_name.to_owned(), None)
+    pub fn join<T: Into<SourceNode<'a>>>(self, source: T) -> InnerNestedLoopJoinNode<'a> {
+        InnerNestedLoopJoinNode::new(self, source.into())
     }
 
     pub fn join_as(self, table_name: &str, alias: &str) -> InnerNestedLoopJoinNode<'a> {
-        InnerNestedLoopJoinNode::new(self, table_name.to_owned(), Some(alias.to_owned()))
+        InnerNestedLoopJoinNode::new(self, table(table_name).alias_as(alias))
     }
 
-    pub fn left_join(self, table_name: &str) -> LeftOuterNestedLoopJoinNode<'a> {
-        LeftOuterNestedLoopJoinNode::new(self, table_name.to_owned(), None)
+    pub fn left_join<T: Into<SourceNode<'a>>>(self, source: T) -> LeftOuterNestedLoopJoinNode<'a> {
+        LeftOuterNestedLoopJoinNode::new(self, source.into())
     }
 
     pub fn left_join_as(self, table_name: &str, alias: &str) -> LeftOuterNestedLoopJoinNode<'a> {
-        LeftOuterNestedLoopJoinNode::new(self, table_name.to_owned(), Some(alias.to_owned()))
+        LeftOuterNestedLoopJoinNode::new(self, table(table_name).alias_as(alias))
     }
 
     pub fn project<T: Into<SelectItemList<'a>>>(self, select_items: T) -> ProjectNode<'a> {
@@ -121,6 +122,22 @@ impl<'a> InnerJoinConditionNode<'a> {
     pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
         QueryNode::InnerJoinConditionNode(self).alias_as(table_alias)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::InnerJoinConditionNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::InnerJoinConditionNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::InnerJoinConditionNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::InnerJoinConditionNode(self).not_exists()
+    }
 }
 
 impl BuildFilterInputPlan for InnerJoinConditionNode<'_> {
@@ -1,5 +1,5 @@
 use {
-    super::{inner_hash_join::InnerHashJoinNode, table_factor, table_source_plan},
+    super::inner_hash_join::InnerHashJoinNode,
     crate::{
         ast::{Expr, Join, JoinConstraint, JoinOperator, Select},
         plan::{
@@ -10,11 +10,12 @@ use {
             DistinctNode, ExprList, ExprNode, FilterNode, GroupByNode, HavingNode,
             InnerJoinConditionNode, LeftOuterHashJoinNode, LeftOuterJoinConditionNode,
             LeftOuterNestedLoopJoinNode, LimitNode, OffsetNode, OrderByExprList, ProjectNode,
-            QueryNode, SelectItemList, SelectNode, SelectOrderByNode, SourceNode,
+            QueryNode, SelectItemList, SelectNode, SelectOrderByNode, SourceNode, UnionNode,
             select::{
                 BuildAggregationInputPlan, BuildFilterInputPlan, BuildProjectInputPlan,
                 BuildSelect, BuildSourcePlan,
             },
+            table,
         },
         result::Result,
     },
@@ -124,29 +125,22 @@ impl<'a> From<LeftOuterJoinConditionNode<'a>> for PrevNode<'a> {
 #[derive(Clone, Debug)]
 pub struct InnerNestedLoopJoinNode<'a> {
     prev_node: PrevNode<'a>,
-    right_name: String,
-    right_alias: Option<String>,
+    right: SourceNode<'a>,
 }
 
 impl<'a> InnerNestedLoopJoinNode<'a> {
-    pub(super) fn new<N: Into<PrevNode<'a>>>(
-        prev_node: N,
-        name: String,
-        alias: Option<String>,
-    ) -> Self {
+    pub(super) fn new<N: Into<PrevNode<'a>>>(prev_node: N, right: SourceNode<'a>) -> Self {
         Self {
             prev_node: prev_node.into(),
-            right_name: name,
-            right_alias: alias,
+            right,
         }
     }
 
     pub(in crate::query_builder::select) fn from_select(
         prev_node: SelectNode<'a>,
-        name: String,
-        alias: Option<String>,
+        right: SourceNode<'a>,
     ) -> Self {
-        Self::new(prev_node, name, alias)
+        Self::new(prev_node, right)
     }
 
     pub fn on<T: Into<ExprNode<'a>>>(self, expr: T) -> InnerJoinConditionNode<'a> {
@@ -158,35 +152,27 @@ impl<'a> InnerNestedLoopJoinNode<'a> {
         right_key: T,
         input_key: U,
     ) -> InnerHashJoinNode<'a> {
-        let Self {
-            prev_node,
-            right_name,
-            right_alias,
-        } = self;
+        let Self { prev_node, right } = self;
         let right_key = right_key.into();
         let input_key = input_key.into();
 
         match prev_node {
-            PrevNode::Select(node) => {
-                InnerHashJoinNode::new(node, right_name, right_alias, right_key, input_key)
-            }
+            PrevNode::Select(node) => InnerHashJoinNode::new(node, right, right_key, input_key),
             PrevNode::InnerNestedLoop(node) => {
-                InnerHashJoinNode::new(*node, right_name, right_alias, right_key, input_key)
+                InnerHashJoinNode::new(*node, right, right_key, input_key)
             }
             PrevNode::LeftOuterNestedLoop(node) => {
-                InnerHashJoinNode::new(*node, right_name, right_alias, right_key, input_key)
-            }
-            PrevNode::InnerHash(node) => {
-                InnerHashJoinNode::new(*node, right_name, right_alias, right_key, input_key)
+                InnerHashJoinNode::new(*node, right, right_key, input_key)
             }
+            PrevNode::InnerHash(node) => InnerHashJoinNode::new(*node, right, right_key, input_key),
             PrevNode::LeftOuterHash(node) => {
-                InnerHashJoinNode::new(*node, right_name, right_alias, right_key, input_key)
+                InnerHashJoinNode::new(*node, right, right_key, input_key)
             }
             PrevNode::InnerCondition(node) => {
-                InnerHashJoinNode::new(*node, right_name, right_alias, right_key, input_key)
+                InnerHashJoinNode::new(*node, right, right_key, input_key)
             }
             PrevNode::LeftOuterCondition(node) => {
-                InnerHashJoinNode::new(*node, right_name, right_alias, right_key, input_key)
+                InnerHashJoinNode::new(*node, right, right_key, input_key)
             }
         }
     }
@@ -194,7 +180,7 @@ impl<'a> InnerNestedLoopJoinNode<'a> {
     pub(super) fn build_nested_loop_join_plan(self) -> Result<NestedLoopJoinPlan> {
         Ok(NestedLoopJoinPlan {
             input: self.prev_node.build_nested_loop_input_plan()?,
-            right: table_source_plan(self.right_name, self.right_alias),
+            right: self.right.build_source_plan()?,
         })
     }
 
@@ -205,7 +191,7 @@ impl<'a> InnerNestedLoopJoinNode<'a> {
     }
 
     pub(super) fn build_select_with_condition(self, expr: Option<Expr>) -> Result<Select> {
-        let relation = table_factor(self.right_name, self.right_alias);
+        let relation = self.right.build_table_factor()?;
         let mut select = self.prev_node.build_select()?;
         let constraint = expr.map_or(JoinConstraint::None, JoinConstraint::On);
         select.from.joins.push(Join {
@@ -217,23 +203,23 @@ impl<'a> InnerNestedLoopJoinNode<'a> {
     }
 
     #[must_use]
-    pub fn join(self, table_name: &str) -> InnerNestedLoopJoinNode<'a> {
-        InnerNestedLoopJoinNode::new(self, table_name.to_owned(), None)
+    pub fn join<T: Into<SourceNode<'a>>>(self, source: T) -> InnerNestedLoopJoinNode<'a> {
+        InnerNestedLoopJoinNode::new(self, source.into())
     }
 
     #[must_use]
     pub fn join_as(self, table_name: &str, alias: &str) -> InnerNestedLoopJoinNode<'a> {
-        InnerNestedLoopJoinNode::new(self, table_name.to_owned(), Some(alias.to_owned()))
+        InnerNestedLoopJoinNode::new(self, table(table_name).alias_as(alias))
     }
 
     #[must_use]
-    pub fn left_join(self, table_name: &str) -> LeftOuterNestedLoopJoinNode<'a> {
-        LeftOuterNestedLoopJoinNode::new(self, table_name.to_owned(), None)
+    pub fn left_join<T: Into<SourceNode<'a>>>(self, source: T) -> LeftOuterNestedLoopJoinNode<'a> {
+        LeftOuterNestedLoopJoinNode::new(self, source.into())
     }
 
     #[must_use]
     pub fn left_join_as(self, table_name: &str, alias: &str) -> LeftOuterNestedLoopJoinNode<'a> {
-        LeftOuterNestedLoopJoinNode::new(self, table_name.to_owned(), Some(alias.to_owned()))
+        LeftOuterNestedLoopJoinNode::new(self, table(table_name).alias_as(alias))
     }
 
     pub fn project<T: Into<SelectItemList<'a>>>(self, select_items: T) -> ProjectNode<'a> {
@@ -274,6 +260,22 @@ impl<'a> InnerNestedLoopJoinNode<'a> {
     pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
         QueryNode::InnerNestedLoopJoinNode(self).alias_as(table_alias)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::InnerNestedLoopJoinNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::InnerNestedLoopJoinNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::InnerNestedLoopJoinNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::InnerNestedLoopJoinNode(self).not_exists()
+    }
 }
 
 impl BuildFilterInputPlan for InnerNestedLoopJoinNode<'_> {
@@ -317,7 +319,7 @@ mod tests {
                 TableAccessPlan, TableAliasPlan, TableSourcePlan,
             },
             query_builder::{
-                QueryBuilderError, col, expr, select::BuildQuery, table, test_query_builder,
+                QueryBuilderError, col, expr, select::BuildQuery, series, table, test_query_builder,
             },
             result::Error,
         },
@@ -351,6 +353,24 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn derived() {
+        let actual = table("A")
+            .select()
+            .join(table("B").select().filter("id > 1").alias_as("Sub"))
+            .on("A.id = Sub.id");
+        let expected =
+            "SELECT * FROM A JOIN (SELECT * FROM B WHERE id > 1) AS Sub ON A.id = Sub.id";
+        test_query_builder(actual, expected);
+
+        let actual = table("A")
+            .select()
+            .join(table("B").alias_as("b"))
+            .join(series(3).select().alias_as("S"));
+        let expected = "SELECT * FROM A JOIN B AS b JOIN (SELECT * FROM SERIES(3)) AS S";
+        test_query_builder(actual, expected);
+    }
+
     #[test]
     fn successors() {
         let actual = table("A").select().join("B").join("C");
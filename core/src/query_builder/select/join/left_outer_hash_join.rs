@@ -1,5 +1,5 @@
 use {
-    super::{left_outer_nested_loop_join::LeftOuterNestedLoopJoinNode, table_source_plan},
+    super::left_outer_nested_loop_join::LeftOuterNestedLoopJoinNode,
     crate::{
         ast::Select,
         plan::{
@@ -11,11 +11,12 @@ use {
             InnerHashJoinNode, InnerJoinConditionNode, InnerNestedLoopJoinNode,
             LeftOuterJoinConditionNode, LimitNode, OffsetNode, OrderByExprList, ProjectNode,
             QueryBuilderError, QueryNode, SelectItemList, SelectNode, SelectOrderByNode,
-            SourceNode,
+            SourceNode, UnionNode,
             select::{
                 BuildAggregationInputPlan, BuildFilterInputPlan, BuildProjectInputPlan,
                 BuildSelect, BuildSourcePlan,
             },
+            table,
         },
         result::Result,
     },
@@ -109,8 +110,7 @@ impl<'a> From<LeftOuterJoinConditionNode<'a>> for PrevNode<'a> {
 #[derive(Clone, Debug)]
 pub struct LeftOuterHashJoinNode<'a> {
     prev_node: PrevNode<'a>,
-    right_name: String,
-    right_alias: Option<String>,
+    right: SourceNode<'a>,
     right_key: ExprNode<'a>,
     input_key: ExprNode<'a>,
     right_filter: Option<ExprNode<'a>>,
@@ -119,15 +119,13 @@ pub struct LeftOuterHashJoinNode<'a> {
 impl<'a> LeftOuterHashJoinNode<'a> {
     pub(super) fn new<N: Into<PrevNode<'a>>>(
         prev_node: N,
-        right_name: String,
-        right_alias: Option<String>,
+        right: SourceNode<'a>,
         right_key: ExprNode<'a>,
         input_key: ExprNode<'a>,
     ) -> Self {
         Self {
             prev_node: prev_node.into(),
-            right_name,
-            right_alias,
+            right,
             right_key,
             input_key,
             right_filter: None,
@@ -152,7 +150,7 @@ impl<'a> LeftOuterHashJoinNode<'a> {
     pub(super) fn build_hash_join_plan(self) -> Result<HashJoinPlan> {
         Ok(HashJoinPlan {
             input: self.prev_node.build_hash_join_input_plan()?,
-            right: table_source_plan(self.right_name, self.right_alias),
+            right: self.right.build_source_plan()?,
             input_key: self.input_key.build_expr_plan()?,
             right_key: self.right_key.build_expr_plan()?,
             right_filter: self
@@ -168,20 +166,20 @@ impl<'a> LeftOuterHashJoinNode<'a> {
             .map(|input| LeftOuterJoinPlan { input })
     }
 
-    pub fn join(self, table_name: &str) -> InnerNestedLoopJoinNode<'a> {
-        InnerNestedLoopJoinNode::new(self, table_name.to_owned(), None)
+    pub fn join<T: Into<SourceNode<'a>>>(self, source: T) -> InnerNestedLoopJoinNode<'a> {
+        InnerNestedLoopJoinNode::new(self, source.into())
     }
 
     pub fn join_as(self, table_name: &str, alias: &str) -> InnerNestedLoopJoinNode<'a> {
-        InnerNestedLoopJoinNode::new(self, table_name.to_owned(), Some(alias.to_owned()))
+        InnerNestedLoopJoinNode::new(self, table(table_name).alias_as(alias))
     }
 
-    pub fn left_join(self, table_name: &str) -> LeftOuterNestedLoopJoinNode<'a> {
-        LeftOuterNestedLoopJoinNode::new(self, table_name.to_owned(), None)
+    pub fn left_join<T: Into<SourceNode<'a>>>(self, source: T) -> LeftOuterNestedLoopJoinNode<'a> {
+        LeftOuterNestedLoopJoinNode::new(self, source.into())
     }
 
     pub fn left_join_as(self, table_name: &str, alias: &str) -> LeftOuterNestedLoopJoinNode<'a> {
-        LeftOuterNestedLoopJoinNode::new(self, table_name.to_owned(), Some(alias.to_owned()))
+        LeftOuterNestedLoopJoinNode::new(self, table(table_name).alias_as(alias))
     }
 
     pub fn project<T: Into<SelectItemList<'a>>>(self, select_items: T) -> ProjectNode<'a> {
@@ -222,6 +220,22 @@ impl<'a> LeftOuterHashJoinNode<'a> {
     pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
         QueryNode::LeftOuterHashJoinNode(self).alias_as(table_alias)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::LeftOuterHashJoinNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::LeftOuterHashJoinNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::LeftOuterHashJoinNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::LeftOuterHashJoinNode(self).not_exists()
+    }
 }
 
 impl BuildFilterInputPlan for LeftOuterHashJoinNode<'_> {
@@ -457,6 +471,7 @@ mod tests {
             input: ProjectInputPlan::Aggregation(AggregationPlan {
                 input: AggregationInputPlan::LeftOuterJoin(Box::new(join.clone())),
                 group_by: vec![col("A.id").build_expr_plan().unwrap()],
+                grouping_sets: None,
                 aggregate_slots: Vec::new(),
             }),
             projection: wildcard.clone(),
@@ -474,6 +489,7 @@ mod tests {
                 input: AggregationPlan {
                     input: AggregationInputPlan::LeftOuterJoin(Box::new(join.clone())),
                     group_by: Vec::new(),
+                    grouping_sets: None,
                     aggregate_slots: Vec::new(),
                 },
                 expr: expr("TRUE").build_expr_plan().unwrap(),
@@ -1,5 +1,5 @@
 use {
-    super::{left_outer_hash_join::LeftOuterHashJoinNode, table_factor, table_source_plan},
+    super::left_outer_hash_join::LeftOuterHashJoinNode,
     crate::{
         ast::{Expr, Join, JoinConstraint, JoinOperator, Select},
         plan::{
@@ -10,11 +10,12 @@ use {
             DistinctNode, ExprList, ExprNode, FilterNode, GroupByNode, HavingNode,
             InnerHashJoinNode, InnerJoinConditionNode, InnerNestedLoopJoinNode,
             LeftOuterJoinConditionNode, LimitNode, OffsetNode, OrderByExprList, ProjectNode,
-            QueryNode, SelectItemList, SelectNode, SelectOrderByNode, SourceNode,
+            QueryNode, SelectItemList, SelectNode, SelectOrderByNode, SourceNode, UnionNode,
             select::{
                 BuildAggregationInputPlan, BuildFilterInputPlan, BuildProjectInputPlan,
                 BuildSelect, BuildSourcePlan,
             },
+            table,
         },
         result::Result,
     },
@@ -124,29 +125,22 @@ impl<'a> From<LeftOuterJoinConditionNode<'a>> for PrevNode<'a> {
 #[derive(Clone, Debug)]
 pub struct LeftOuterNestedLoopJoinNode<'a> {
     prev_node: PrevNode<'a>,
-    right_name: String,
-    right_alias: Option<String>,
+    right: SourceNode<'a>,
 }
 
 impl<'a> LeftOuterNestedLoopJoinNode<'a> {
-    pub(super) fn new<N: Into<PrevNode<'a>>>(
-        prev_node: N,
-        name: String,
-        alias: Option<String>,
-    ) -> Self {
+    pub(super) fn new<N: Into<PrevNode<'a>>>(prev_node: N, right: SourceNode<'a>) -> Self {
         Self {
             prev_node: prev_node.into(),
-            right_name: name,
-            right_alias: alias,
+            right,
         }
     }
 
     pub(in crate::query_builder::select) fn from_select(
         prev_node: SelectNode<'a>,
-        name: String,
-        alias: Option<String>,
+        right: SourceNode<'a>,
     ) -> Self {
-        Self::new(prev_node, name, alias)
+        Self::new(prev_node, right)
     }
 
     pub fn on<T: Into<ExprNode<'a>>>(self, expr: T) -> LeftOuterJoinConditionNode<'a> {
@@ -158,35 +152,29 @@ impl<'a> LeftOuterNestedLoopJoinNode<'a> {
         right_key: T,
         input_key: U,
     ) -> LeftOuterHashJoinNode<'a> {
-        let Self {
-            prev_node,
-            right_name,
-            right_alias,
-        } = self;
+        let Self { prev_node, right } = self;
         let right_key = right_key.into();
         let input_key = input_key.into();
 
         match prev_node {
-            PrevNode::Select(node) => {
-                LeftOuterHashJoinNode::new(node, right_name, right_alias, right_key, input_key)
-            }
+            PrevNode::Select(node) => LeftOuterHashJoinNode::new(node, right, right_key, input_key),
             PrevNode::InnerNestedLoop(node) => {
-                LeftOuterHashJoinNode::new(*node, right_name, right_alias, right_key, input_key)
+                LeftOuterHashJoinNode::new(*node, right, right_key, input_key)
             }
             PrevNode::LeftOuterNestedLoop(node) => {
-                LeftOuterHashJoinNode::new(*node, right_name, right_alias, right_key, input_key)
+                LeftOuterHashJoinNode::new(*node, right, right_key, input_key)
             }
             PrevNode::InnerHash(node) => {
-                LeftOuterHashJoinNode::new(*node, right_name, right_alias, right_key, input_key)
+                LeftOuterHashJoinNode::new(*node, right, right_key, input_key)
             }
             PrevNode::LeftOuterHash(node) => {
-                LeftOuterHashJoinNode::new(*node, right_name, right_alias, right_key, input_key)
+                LeftOuterHashJoinNode::new(*node, right, right_key, input_key)
             }
             PrevNode::InnerCondition(node) => {
-                LeftOuterHashJoinNode::new(*node, right_name, right_alias, right_key, input_key)
+                LeftOuterHashJoinNode::new(*node, right, right_key, input_key)
             }
             PrevNode::LeftOuterCondition(node) => {
-                LeftOuterHashJoinNode::new(*node, right_name, right_alias, right_key, input_key)
+                LeftOuterHashJoinNode::new(*node, right, right_key, input_key)
             }
         }
     }
@@ -194,7 +182,7 @@ impl<'a> LeftOuterNestedLoopJoinNode<'a> {
     pub(super) fn build_nested_loop_join_plan(self) -> Result<NestedLoopJoinPlan> {
         Ok(NestedLoopJoinPlan {
             input: self.prev_node.build_nested_loop_input_plan()?,
-            right: table_source_plan(self.right_name, self.right_alias),
+            right: self.right.build_source_plan()?,
         })
     }
 
@@ -205,7 +193,7 @@ impl<'a> LeftOuterNestedLoopJoinNode<'a> {
     }
 
     pub(super) fn build_select_with_condition(self, expr: Option<Expr>) -> Result<Select> {
-        let relation = table_factor(self.right_name, self.right_alias);
+        let relation = self.right.build_table_factor()?;
         let mut select = self.prev_node.build_select()?;
         let constraint = expr.map_or(JoinConstraint::None, JoinConstraint::On);
         select.from.joins.push(Join {
@@ -217,23 +205,23 @@ impl<'a> LeftOuterNestedLoopJoinNode<'a> {
     }
 
     #[must_use]
-    pub fn join(self, table_name: &str) -> InnerNestedLoopJoinNode<'a> {
-        InnerNestedLoopJoinNode::new(self, table_name.to_owned(), None)
+    pub fn join<T: Into<SourceNode<'a>>>(self, source: T) -> InnerNestedLoopJoinNode<'a> {
+        InnerNestedLoopJoinNode::new(self, source.into())
     }
 
     #[must_use]
     pub fn join_as(self, table_name: &str, alias: &str) -> InnerNestedLoopJoinNode<'a> {
-        InnerNestedLoopJoinNode::new(self, table_name.to_owned(), Some(alias.to_owned()))
+        InnerNestedLoopJoinNode::new(self, table(table_name).alias_as(alias))
     }
 
     #[must_use]
-    pub fn left_join(self, table_name: &str) -> LeftOuterNestedLoopJoinNode<'a> {
-        LeftOuterNestedLoopJoinNode::new(self, table_name.to_owned(), None)
+    pub fn left_join<T: Into<SourceNode<'a>>>(self, source: T) -> LeftOuterNestedLoopJoinNode<'a> {
+        LeftOuterNestedLoopJoinNode::new(self, source.into())
     }
 
     #[must_use]
     pub fn left_join_as(self, table_name: &str, alias: &str) -> LeftOuterNestedLoopJoinNode<'a> {
-        LeftOuterNestedLoopJoinNode::new(self, table_name.to_owned(), Some(alias.to_owned()))
+        LeftOuterNestedLoopJoinNode::new(self, table(table_name).alias_as(alias))
     }
 
     pub fn project<T: Into<SelectItemList<'a>>>(self, select_items: T) -> ProjectNode<'a> {
@@ -274,6 +262,22 @@ impl<'a> LeftOuterNestedLoopJoinNode<'a> {
     pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
         QueryNode::LeftOuterNestedLoopJoinNode(self).alias_as(table_alias)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::LeftOuterNestedLoopJoinNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::LeftOuterNestedLoopJoinNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::LeftOuterNestedLoopJoinNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::LeftOuterNestedLoopJoinNode(self).not_exists()
+    }
 }
 
 impl BuildFilterInputPlan for LeftOuterNestedLoopJoinNode<'_> {
@@ -317,7 +321,7 @@ mod tests {
                 TableAccessPlan, TableAliasPlan, TableSourcePlan,
             },
             query_builder::{
-                QueryBuilderError, col, expr, select::BuildQuery, table, test_query_builder,
+                QueryBuilderError, col, expr, select::BuildQuery, series, table, test_query_builder,
             },
             result::Error,
         },
@@ -351,6 +355,24 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn derived() {
+        let actual = table("A")
+            .select()
+            .left_join(table("B").select().filter("id > 1").alias_as("Sub"))
+            .on("A.id = Sub.id");
+        let expected =
+            "SELECT * FROM A LEFT JOIN (SELECT * FROM B WHERE id > 1) AS Sub ON A.id = Sub.id";
+        test_query_builder(actual, expected);
+
+        let actual = table("A")
+            .select()
+            .left_join(table("B").alias_as("b"))
+            .left_join(series(3).select().alias_as("S"));
+        let expected = "SELECT * FROM A LEFT JOIN B AS b LEFT JOIN (SELECT * FROM SERIES(3)) AS S";
+        test_query_builder(actual, expected);
+    }
+
     #[test]
     fn successors() {
         let actual = table("A").select().left_join("B").join("C");
@@ -7,7 +7,7 @@ use {
             ExprNode, FilterNode, GroupByNode, HavingNode, InnerHashJoinNode,
             InnerJoinConditionNode, InnerNestedLoopJoinNode, LeftOuterHashJoinNode,
             LeftOuterJoinConditionNode, LeftOuterNestedLoopJoinNode, ProjectNode, QueryNode,
-            SelectNode, SelectOrderByNode, SourceNode, ValuesOrderByNode,
+            SelectNode, SelectOrderByNode, SourceNode, UnionNode, ValuesOrderByNode,
         },
         result::Result,
     },
@@ -191,6 +191,22 @@ impl<'a> LimitNode<'a> {
     pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
         QueryNode::LimitNode(self).alias_as(table_alias)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::LimitNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::LimitNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::LimitNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::LimitNode(self).not_exists()
+    }
 }
 
 impl BuildQueryPlan for LimitNode<'_> {
@@ -7,7 +7,7 @@ use {
             ExprNode, FilterNode, GroupByNode, HavingNode, InnerHashJoinNode,
             InnerJoinConditionNode, InnerNestedLoopJoinNode, LeftOuterHashJoinNode,
             LeftOuterJoinConditionNode, LeftOuterNestedLoopJoinNode, OffsetLimitNode, ProjectNode,
-            QueryNode, SelectNode, SelectOrderByNode, SourceNode, ValuesOrderByNode,
+            QueryNode, SelectNode, SelectOrderByNode, SourceNode, UnionNode, ValuesOrderByNode,
         },
         result::Result,
     },
@@ -196,6 +196,22 @@ impl<'a> OffsetNode<'a> {
         QueryNode::OffsetNode(self).alias_as(table_alias)
     }
 
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::OffsetNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::OffsetNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::OffsetNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::OffsetNode(self).not_exists()
+    }
+
     pub(super) fn build_offset_plan(self) -> Result<OffsetPlan> {
         let count = self.expr.build_expr_plan()?;
         let input = self.prev_node.build_offset_input_plan()?;
@@ -3,7 +3,7 @@ use {
     crate::{
         ast::Query,
         plan::{LimitInputPlan, LimitPlan, OffsetPlan, QueryPlan},
-        query_builder::{ExprNode, OffsetNode, QueryNode, SourceNode},
+        query_builder::{ExprNode, OffsetNode, QueryNode, SourceNode, UnionNode},
         result::Result,
     },
 };
@@ -52,6 +52,22 @@ impl<'a> OffsetLimitNode<'a> {
     pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
         QueryNode::OffsetLimitNode(self).alias_as(table_alias)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::OffsetLimitNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::OffsetLimitNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::OffsetLimitNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::OffsetLimitNode(self).not_exists()
+    }
 }
 
 impl BuildQueryPlan for OffsetLimitNode<'_> {
@@ -7,7 +7,7 @@ use {
             ExprNode, FilterNode, GroupByNode, HavingNode, InnerHashJoinNode,
             InnerJoinConditionNode, InnerNestedLoopJoinNode, LeftOuterHashJoinNode,
             LeftOuterJoinConditionNode, LeftOuterNestedLoopJoinNode, LimitNode, OffsetNode,
-            OrderByExprList, ProjectNode, QueryNode, SelectNode, SourceNode,
+            OrderByExprList, ProjectNode, QueryNode, SelectNode, SourceNode, UnionNode,
         },
         result::Result,
     },
@@ -147,6 +147,22 @@ impl<'a> SelectOrderByNode<'a> {
     pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
         QueryNode::SelectOrderByNode(self).alias_as(table_alias)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::SelectOrderByNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::SelectOrderByNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::SelectOrderByNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::SelectOrderByNode(self).not_exists()
+    }
 }
 
 impl SelectOrderByNode<'_> {
@@ -224,6 +240,22 @@ impl<'a> ValuesOrderByNode<'a> {
     pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
         QueryNode::ValuesOrderByNode(self).alias_as(table_alias)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::ValuesOrderByNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::ValuesOrderByNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::ValuesOrderByNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::ValuesOrderByNode(self).not_exists()
+    }
 }
 
 impl ValuesOrderByNode<'_> {
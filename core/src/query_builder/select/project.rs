@@ -8,6 +8,7 @@ use {
             InnerJoinConditionNode, InnerNestedLoopJoinNode, LeftOuterHashJoinNode,
             LeftOuterJoinConditionNode, LeftOuterNestedLoopJoinNode, LimitNode, OffsetNode,
             OrderByExprList, QueryNode, SelectItemList, SelectNode, SelectOrderByNode, SourceNode,
+            UnionNode,
         },
         result::Result,
     },
@@ -149,6 +150,22 @@ impl<'a> ProjectNode<'a> {
         QueryNode::ProjectNode(self).alias_as(table_alias)
     }
 
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::ProjectNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::ProjectNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::ProjectNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::ProjectNode(self).not_exists()
+    }
+
     pub fn order_by<T: Into<OrderByExprList<'a>>>(
         self,
         order_by_exprs: T,
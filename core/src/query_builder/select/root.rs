@@ -4,21 +4,14 @@ use {
         BuildSourcePlan, DistinctNode,
     },
     crate::{
-        ast::{
-            Expr, Literal, Projection, Select, SelectItem, TableAlias, TableFactor, TableWithJoins,
-        },
-        plan::{
-            AggregationInputPlan, DerivedSourcePlan, DictionarySourcePlan, FilterInputPlan,
-            ProjectInputPlan, SeriesSourcePlan, SourcePlan, TableAliasPlan, TableSourcePlan,
-        },
+        ast::{Expr, Literal, Projection, Select, SelectItem, TableWithJoins},
+        plan::{AggregationInputPlan, FilterInputPlan, ProjectInputPlan, SourcePlan},
         query_builder::{
             ExprList, ExprNode, FilterNode, GroupByNode, HavingNode, InnerNestedLoopJoinNode,
             LeftOuterNestedLoopJoinNode, LimitNode, OffsetNode, OrderByExprList, ProjectNode,
-            QueryBuilderError, QueryNode, SelectItemList, SelectOrderByNode, SourceNode,
-            TableAccessNode,
+            QueryNode, SelectItemList, SelectOrderByNode, SourceNode, UnionNode, table,
         },
         result::Result,
-        translate::alias_or_name,
     },
 };
 
@@ -67,70 +60,46 @@ impl<'a> SelectNode<'a> {
         SelectOrderByNode::new(self, order_by_exprs)
     }
 
-    pub fn join(self, table_name: &str) -> InnerNestedLoopJoinNode<'a> {
-        InnerNestedLoopJoinNode::from_select(self, table_name.to_owned(), None)
+    pub fn join<T: Into<SourceNode<'a>>>(self, source: T) -> InnerNestedLoopJoinNode<'a> {
+        InnerNestedLoopJoinNode::from_select(self, source.into())
     }
 
     pub fn join_as(self, table_name: &str, alias: &str) -> InnerNestedLoopJoinNode<'a> {
-        InnerNestedLoopJoinNode::from_select(self, table_name.to_owned(), Some(alias.to_owned()))
+        InnerNestedLoopJoinNode::from_select(self, table(table_name).alias_as(alias))
     }
 
-    pub fn left_join(self, table_name: &str) -> LeftOuterNestedLoopJoinNode<'a> {
-        LeftOuterNestedLoopJoinNode::from_select(self, table_name.to_owned(), None)
+    pub fn left_join<T: Into<SourceNode<'a>>>(self, source: T) -> LeftOuterNestedLoopJoinNode<'a> {
+        LeftOuterNestedLoopJoinNode::from_select(self, source.into())
     }
 
     pub fn left_join_as(self, table_name: &str, alias: &str) -> LeftOuterNestedLoopJoinNode<'a> {
-        LeftOuterNestedLoopJoinNode::from_select(
-            self,
-            table_name.to_owned(),
-            Some(alias.to_owned()),
-        )
+        LeftOuterNestedLoopJoinNode::from_select(self, table(table_name).alias_as(alias))
     }
 
     pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
         QueryNode::SelectNode(self).alias_as(table_alias)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::SelectNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::SelectNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::SelectNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::SelectNode(self).not_exists()
+    }
 }
 
 impl BuildSourcePlan for SelectNode<'_> {
     fn build_source_plan(self) -> Result<SourcePlan> {
-        match self.source_node {
-            SourceNode::Table {
-                name,
-                alias,
-                access,
-            } => Ok(SourcePlan::Table(TableSourcePlan {
-                name,
-                alias: alias.map(|name| TableAliasPlan {
-                    name,
-                    columns: Vec::new(),
-                }),
-                access: access.build_table_access_plan()?,
-            })),
-            SourceNode::Dictionary { dictionary, alias } => {
-                Ok(SourcePlan::Dictionary(DictionarySourcePlan {
-                    dictionary,
-                    alias: TableAliasPlan {
-                        name: alias,
-                        columns: Vec::new(),
-                    },
-                }))
-            }
-            SourceNode::Series { size, alias } => Ok(SourcePlan::Series(SeriesSourcePlan {
-                alias: TableAliasPlan {
-                    name: alias,
-                    columns: Vec::new(),
-                },
-                size: size.build_expr_plan()?,
-            })),
-            SourceNode::Derived { query, alias } => Ok(SourcePlan::Derived(DerivedSourcePlan {
-                query: Box::new(query.build_query_plan()?),
-                alias: TableAliasPlan {
-                    name: alias,
-                    columns: Vec::new(),
-                },
-            })),
-        }
+        self.source_node.build_source_plan()
     }
 }
 
@@ -154,37 +123,7 @@ impl BuildProjectInputPlan for SelectNode<'_> {
 
 impl BuildSelect for SelectNode<'_> {
     fn build_select(self) -> Result<Select> {
-        let relation = match self.source_node {
-            SourceNode::Table {
-                name,
-                alias,
-                access: TableAccessNode::FullScan,
-            } => TableFactor::Table {
-                name,
-                alias: alias.map(|name| TableAlias {
-                    name,
-                    columns: Vec::new(),
-                }),
-            },
-            SourceNode::Table { .. } => {
-                return Err(QueryBuilderError::IndexByRequiresPlan.into());
-            }
-            SourceNode::Dictionary { dictionary, alias } => TableFactor::Dictionary {
-                dict: dictionary,
-                alias: alias_or_name(None, alias),
-            },
-            SourceNode::Series { size, alias } => TableFactor::Series {
-                alias: alias_or_name(None, alias),
-                size: size.build_expr()?,
-            },
-            SourceNode::Derived { query, alias } => TableFactor::Derived {
-                subquery: query.build_query()?,
-                alias: TableAlias {
-                    name: alias,
-                    columns: Vec::new(),
-                },
-            },
-        };
+        let relation = self.source_node.build_table_factor()?;
 
         let from = TableWithJoins {
             relation,
@@ -197,6 +136,7 @@ impl BuildSelect for SelectNode<'_> {
             from,
             selection: None,
             group_by: Vec::new(),
+            grouping_sets: None,
             having: None,
         })
     }
@@ -0,0 +1,210 @@
+use {
+    super::{BuildQuery, BuildQueryPlan},
+    crate::{
+        ast::{Query, SetExpr},
+        plan::{QueryPlan, UnionPlan},
+        query_builder::{ExprNode, OrderByExprList, QueryNode, SourceNode},
+        result::Result,
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct UnionNode<'a> {
+    left: Box<QueryNode<'a>>,
+    right: Box<QueryNode<'a>>,
+    all: bool,
+    order_by: Option<OrderByExprList<'a>>,
+    offset: Option<ExprNode<'a>>,
+    limit: Option<ExprNode<'a>>,
+}
+
+impl<'a> UnionNode<'a> {
+    pub(in crate::query_builder) fn new(
+        left: QueryNode<'a>,
+        right: QueryNode<'a>,
+        all: bool,
+    ) -> Self {
+        Self {
+            left: Box::new(left),
+            right: Box::new(right),
+            all,
+            order_by: None,
+            offset: None,
+            limit: None,
+        }
+    }
+
+    #[must_use]
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::UnionNode(self).union(query)
+    }
+
+    #[must_use]
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::UnionNode(self).union_all(query)
+    }
+
+    /// Orders the combined rows of both queries.
+    #[must_use]
+    pub fn order_by<T: Into<OrderByExprList<'a>>>(mut self, order_by_exprs: T) -> Self {
+        self.order_by = Some(order_by_exprs.into());
+        self
+    }
+
+    #[must_use]
+    pub fn offset<T: Into<ExprNode<'a>>>(mut self, expr: T) -> Self {
+        self.offset = Some(expr.into());
+        self
+    }
+
+    #[must_use]
+    pub fn limit<T: Into<ExprNode<'a>>>(mut self, expr: T) -> Self {
+        self.limit = Some(expr.into());
+        self
+    }
+
+    pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
+        QueryNode::UnionNode(self).alias_as(table_alias)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::UnionNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::UnionNode(self).not_exists()
+    }
+}
+
+impl BuildQueryPlan for UnionNode<'_> {
+    fn build_query_plan(self) -> Result<QueryPlan> {
+        Ok(QueryPlan::Union(UnionPlan {
+            left: Box::new(self.left.build_query_plan()?),
+            right: Box::new(self.right.build_query_plan()?),
+            all: self.all,
+            order_by: self
+                .order_by
+                .map(OrderByExprList::build_order_by_exprs_plan)
+                .transpose()?
+                .unwrap_or_default(),
+            offset: self.offset.map(ExprNode::build_expr_plan).transpose()?,
+            limit: self.limit.map(ExprNode::build_expr_plan).transpose()?,
+        }))
+    }
+}
+
+impl BuildQuery for UnionNode<'_> {
+    fn build_query(self) -> Result<Query> {
+        Ok(Query {
+            body: SetExpr::Union {
+                left: Box::new(self.left.build_query()?),
+                right: Box::new(self.right.build_query()?),
+                all: self.all,
+            },
+            order_by: self
+                .order_by
+                .map(OrderByExprList::build_order_by_exprs)
+                .transpose()?
+                .unwrap_or_default(),
+            limit: self.limit.map(ExprNode::build_expr).transpose()?,
+            offset: self.offset.map(ExprNode::build_expr).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{
+            ast::ToSql,
+            parse_sql::parse_query,
+            query_builder::{
+                QueryNode, col, num, select::BuildQuery, table, test_query_builder, values,
+            },
+            translate::{NO_PARAMS, translate_query},
+        },
+        pretty_assertions::assert_eq,
+    };
+
+    #[test]
+    fn union() {
+        // select node -> union node -> build
+        let actual = table("Foo").select().union(table("Bar").select());
+        let expected = "SELECT * FROM Foo UNION SELECT * FROM Bar";
+        test_query_builder(actual, expected);
+
+        // project node -> union all node -> build
+        let actual = table("Foo")
+            .select()
+            .project("id")
+            .union_all(table("Bar").select().project("id"));
+        let expected = "SELECT id FROM Foo UNION ALL SELECT id FROM Bar";
+        test_query_builder(actual, expected);
+
+        // union node -> union node -> build
+        let actual = table("Foo")
+            .select()
+            .filter(col("id").gt(1))
+            .union(values(vec!["1"]))
+            .union_all("SELECT id FROM Bar");
+        let expected =
+            "SELECT * FROM Foo WHERE id > 1 UNION VALUES (1) UNION ALL SELECT id FROM Bar";
+        test_query_builder(actual, expected);
+
+        // union node -> order by, offset and limit -> build
+        let actual = table("Foo")
+            .select()
+            .project("name")
+            .union(table("Bar").select().project("name"))
+            .order_by("name DESC")
+            .offset(1)
+            .limit(num(2));
+        let expected =
+            "SELECT name FROM Foo UNION SELECT name FROM Bar ORDER BY name DESC LIMIT 2 OFFSET 1";
+        test_query_builder(actual, expected);
+
+        // operands with their own order by and limit
+        let actual = table("Foo")
+            .select()
+            .order_by("id")
+            .limit(1)
+            .union_all(table("Bar").select().limit(1));
+        let expected =
+            "(SELECT * FROM Foo ORDER BY id LIMIT 1) UNION ALL (SELECT * FROM Bar LIMIT 1)";
+        test_query_builder(actual, expected);
+
+        // union in a derived table
+        let actual = table("Foo")
+            .select()
+            .union(table("Bar").select())
+            .alias_as("Sub")
+            .select();
+        let expected = "SELECT * FROM (SELECT * FROM Foo UNION SELECT * FROM Bar) AS Sub";
+        test_query_builder(actual, expected);
+    }
+
+    #[test]
+    fn union_to_sql() {
+        let query = table("Foo")
+            .select()
+            .limit(1)
+            .union(
+                table("Bar")
+                    .select()
+                    .project("id")
+                    .union_all(QueryNode::from("SELECT id FROM Baz")),
+            )
+            .order_by("id")
+            .build_query()
+            .unwrap();
+
+        let sql = query.to_sql();
+        assert_eq!(
+            sql,
+            r#"(SELECT * FROM "Foo" LIMIT 1) UNION (SELECT "id" AS "id" FROM "Bar" UNION ALL SELECT "id" AS "id" FROM "Baz") ORDER BY "id""#
+        );
+
+        let parsed = parse_query(&sql).unwrap();
+        assert_eq!(translate_query(&parsed, NO_PARAMS), Ok(query));
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     plan::{QueryPlan, ValuesPlan},
     query_builder::{
         ExprList, ExprNode, LimitNode, OffsetNode, OrderByExprList, QueryNode, SourceNode,
-        ValuesOrderByNode,
+        UnionNode, ValuesOrderByNode,
         select::{BuildQuery, BuildQueryPlan},
     },
     result::Result,
@@ -33,6 +33,22 @@ impl<'a> ValuesNode<'a> {
     pub fn alias_as(self, table_alias: &'a str) -> SourceNode<'a> {
         QueryNode::ValuesNode(self).alias_as(table_alias)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::ValuesNode(self).union(query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> UnionNode<'a> {
+        QueryNode::ValuesNode(self).union_all(query)
+    }
+
+    pub fn exists(self) -> ExprNode<'a> {
+        QueryNode::ValuesNode(self).exists()
+    }
+
+    pub fn not_exists(self) -> ExprNode<'a> {
+        QueryNode::ValuesNode(self).not_exists()
+    }
 }
 
 impl ValuesNode<'_> {
@@ -1,6 +1,14 @@
 use {
-    super::{ExprNode, QueryNode, SelectNode, TableAccessNode},
-    crate::ast::Dictionary,
+    super::{ExprNode, QueryBuilderError, QueryNode, SelectNode, TableAccessNode},
+    crate::{
+        ast::{Dictionary, TableAlias, TableFactor},
+        plan::{
+            DerivedSourcePlan, DictionarySourcePlan, SeriesSourcePlan, SourcePlan, TableAliasPlan,
+            TableSourcePlan,
+        },
+        result::Result,
+        translate::alias_or_name,
+    },
 };
 
 #[derive(Clone, Debug)]
@@ -28,6 +36,88 @@ impl<'a> SourceNode<'a> {
     pub fn select(self) -> SelectNode<'a> {
         SelectNode::new(self)
     }
+
+    pub(super) fn build_source_plan(self) -> Result<SourcePlan> {
+        match self {
+            SourceNode::Table {
+                name,
+                alias,
+                access,
+            } => Ok(SourcePlan::Table(TableSourcePlan {
+                name,
+                alias: alias.map(|name| TableAliasPlan {
+                    name,
+                    columns: Vec::new(),
+                }),
+                access: access.build_table_access_plan()?,
+            })),
+            SourceNode::Dictionary { dictionary, alias } => {
+                Ok(SourcePlan::Dictionary(DictionarySourcePlan {
+                    dictionary,
+                    alias: TableAliasPlan {
+                        name: alias,
+                        columns: Vec::new(),
+                    },
+                }))
+            }
+            SourceNode::Series { size, alias } => Ok(SourcePlan::Series(SeriesSourcePlan {
+                alias: TableAliasPlan {
+                    name: alias,
+                    columns: Vec::new(),
+                },
+                size: size.build_expr_plan()?,
+            })),
+            SourceNode::Derived { query, alias } => Ok(SourcePlan::Derived(DerivedSourcePlan {
+                query: Box::new(query.build_query_plan()?),
+                alias: TableAliasPlan {
+                    name: alias,
+                    columns: Vec::new(),
+                },
+            })),
+        }
+    }
+
+    pub(super) fn build_table_factor(self) -> Result<TableFactor> {
+        match self {
+            SourceNode::Table {
+                name,
+                alias,
+                access: TableAccessNode::FullScan,
+            } => Ok(TableFactor::Table {
+                name,
+                alias: alias.map(|name| TableAlias {
+                    name,
+                    columns: Vec::new(),
+                }),
+            }),
+            SourceNode::Table { .. } => Err(QueryBuilderError::IndexByRequiresPlan.into()),
+            SourceNode::Dictionary { dictionary, alias } => Ok(TableFactor::Dictionary {
+                dict: dictionary,
+                alias: alias_or_name(None, alias),
+            }),
+            SourceNode::Series { size, alias } => Ok(TableFactor::Series {
+                alias: alias_or_name(None, alias),
+                size: size.build_expr()?,
+            }),
+            SourceNode::Derived { query, alias } => Ok(TableFactor::Derived {
+                subquery: query.build_query()?,
+                alias: TableAlias {
+                    name: alias,
+                    columns: Vec::new(),
+                },
+            }),
+        }
+    }
+}
+
+impl From<&str> for SourceNode<'_> {
+    fn from(table_name: &str) -> Self {
+        SourceNode::Table {
+            name: table_name.to_owned(),
+            alias: None,
+            access: TableAccessNode::FullScan,
+        }
+    }
 }
 
 pub fn glue_objects() -> SourceNode<'static> {
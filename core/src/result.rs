@@ -1,10 +1,11 @@
 use {serde::Serialize, std::fmt::Debug, thiserror::Error as ThisError};
 
 pub use crate::{
+    backup::BackupError,
     data::{IntervalError, KeyError, SchemaParseError, StringExtError, TableError, ValueError},
     executor::{
         AlterError, DeleteError, EvaluateError, ExecuteError, FetchError, InsertError, QueryError,
-        UpdateError, ValidateError,
+        TriggerError, UpdateError, ValidateError,
     },
     planner::PlannerError,
     query_builder::QueryBuilderError,
@@ -47,6 +48,8 @@ pub enum Error {
     Delete(#[from] DeleteError),
     #[error("update: {0}")]
     Update(#[from] UpdateError),
+    #[error("trigger: {0}")]
+    Trigger(#[from] TriggerError),
     #[error("table: {0}")]
     Table(#[from] TableError),
     #[error("validate: {0}")]
@@ -66,6 +69,9 @@ pub enum Error {
 
     #[error("row-conversion: {0}")]
     RowConversion(#[from] RowConversionError),
+
+    #[error("backup: {0}")]
+    Backup(#[from] BackupError),
 }
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -1,4 +1,12 @@
-use serde::Serialize;
+use {
+    crate::{
+        glue::Glue,
+        store::{GStore, GStoreMut, Planner},
+        translate::ParamLiteral,
+    },
+    serde::Serialize,
+    std::marker::PhantomData,
+};
 
 pub fn uuid_to_string(value: u128) -> String {
     let hex = format!("{value:032x}");
@@ -155,6 +163,59 @@ impl SelectResultExt for crate::result::Result<crate::executor::Payload> {
     }
 }
 
+/// A single statement with its arguments bound, whose rows convert into `T`.
+///
+/// `glue_query!` builds one after checking the SQL against the declared schema,
+/// with `T` set to the row struct it generates, or `()` for statements that
+/// return no rows.
+#[derive(Debug, Clone)]
+pub struct TypedQuery<T> {
+    sql: &'static str,
+    params: Vec<ParamLiteral>,
+    row: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedQuery<T> {
+    pub fn new(sql: &'static str, params: Vec<ParamLiteral>) -> Self {
+        Self {
+            sql,
+            params,
+            row: PhantomData,
+        }
+    }
+
+    pub fn sql(&self) -> &'static str {
+        self.sql
+    }
+
+    pub fn params(&self) -> &[ParamLiteral] {
+        &self.params
+    }
+
+    pub fn execute<S: GStore + GStoreMut + Planner>(
+        self,
+        glue: &mut Glue<S>,
+    ) -> crate::result::Result<Vec<crate::executor::Payload>> {
+        glue.execute_with_params(self.sql, self.params)
+    }
+}
+
+impl<T: FromGlueRow> TypedQuery<T> {
+    pub fn fetch_all<S: GStore + GStoreMut + Planner>(
+        self,
+        glue: &mut Glue<S>,
+    ) -> crate::result::Result<Vec<T>> {
+        self.execute(glue).rows_as::<T>()
+    }
+
+    pub fn fetch_one<S: GStore + GStoreMut + Planner>(
+        self,
+        glue: &mut Glue<S>,
+    ) -> crate::result::Result<T> {
+        self.execute(glue).one_as::<T>()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{FromGlueRow, RowConversionError, SelectExt, uuid_to_string};
@@ -1,32 +1,54 @@
 mod alter_table;
+mod backup;
+mod change_feed;
 mod function;
 mod index;
 mod metadata;
 mod planner;
 mod transaction;
+mod trigger;
 
-pub trait GStore: Store + Index + Metadata + CustomFunction {}
-impl<S: Store + Index + Metadata + CustomFunction> GStore for S {}
+pub trait GStore: Store + Index + Metadata + CustomFunction + Trigger {}
+impl<S: Store + Index + Metadata + CustomFunction + Trigger> GStore for S {}
 
 pub trait GStoreMut:
-    StoreMut + IndexMut + AlterTable + Transaction + CustomFunction + CustomFunctionMut
+    StoreMut
+    + IndexMut
+    + AlterTable
+    + Transaction
+    + CustomFunction
+    + CustomFunctionMut
+    + Trigger
+    + TriggerMut
 {
 }
-impl<S: StoreMut + IndexMut + AlterTable + Transaction + CustomFunction + CustomFunctionMut>
-    GStoreMut for S
+impl<
+    S: StoreMut
+        + IndexMut
+        + AlterTable
+        + Transaction
+        + CustomFunction
+        + CustomFunctionMut
+        + Trigger
+        + TriggerMut,
+> GStoreMut for S
 {
 }
 
 pub use {
     alter_table::{AlterTable, AlterTableError},
+    backup::Backup,
+    change_feed::{Change, ChangeFeed, ChangeIter, ChangeKind},
     function::{CustomFunction, CustomFunctionMut},
     index::{Index, IndexError, IndexMut},
     metadata::{MetaIter, Metadata},
     planner::Planner,
     transaction::Transaction,
+    trigger::{Trigger, TriggerMut},
 };
 
 use crate::{
+    ast::IndexOperator,
     data::{Key, Schema, Value},
     executor::Referencing,
     result::{Error, Result},
@@ -34,6 +56,14 @@ use crate::{
 
 pub type RowIter<'a> = Box<dyn Iterator<Item = Result<(Key, Vec<Value>)>> + 'a>;
 
+/// `column <operator> value` condition taken from a conjunctive `WHERE` clause.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanPredicate {
+    pub column: usize,
+    pub operator: IndexOperator,
+    pub value: Value,
+}
+
 /// By implementing `Store` trait, you can run `SELECT` query.
 pub trait Store {
     fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>>;
@@ -44,6 +74,20 @@ pub trait Store {
 
     fn scan_data<'a>(&'a self, table_name: &str) -> Result<RowIter<'a>>;
 
+    /// Scans a table with the predicates and the needed column indexes of a query.
+    ///
+    /// The executor still applies the whole `WHERE` clause, so a storage may
+    /// ignore any predicate it cannot translate. Columns left out of `columns`
+    /// may be returned as `Value::Null`, but every row keeps the full width.
+    fn scan_filtered_data<'a>(
+        &'a self,
+        table_name: &str,
+        _predicates: &[ScanPredicate],
+        _columns: Option<&[usize]>,
+    ) -> Result<RowIter<'a>> {
+        self.scan_data(table_name)
+    }
+
     fn fetch_referencings(&self, table_name: &str) -> Result<Vec<Referencing>> {
         let schemas = self.fetch_all_schemas()?;
 
@@ -0,0 +1,13 @@
+use {
+    super::{CustomFunction, Store, Trigger},
+    crate::result::Result,
+};
+
+/// By implementing `Backup` trait, you can export a consistent copy of the
+/// storage with [`crate::backup::write_backup`] while writers stay active.
+pub trait Backup {
+    /// Read-only view of the storage fixed at the time it was taken.
+    type Snapshot: Store + CustomFunction + Trigger;
+
+    fn snapshot(&self) -> Result<Self::Snapshot>;
+}
@@ -0,0 +1,52 @@
+use {
+    crate::{
+        data::{Key, Value},
+        result::Result,
+    },
+    serde::{Deserialize, Serialize},
+};
+
+pub type ChangeIter<'a> = Box<dyn Iterator<Item = Result<Change>> + 'a>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChangeKind {
+    Insert,
+    Update,
+    Delete,
+}
+
+/// Row change recorded by a committed transaction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Change {
+    /// Position of the change in the feed, usable as a checkpoint.
+    pub seq: u64,
+    pub txid: u64,
+    pub table_name: String,
+    /// Key of the row, or `None` when the storage generated it for an
+    /// appended row and did not report it.
+    pub key: Option<Key>,
+    pub before: Option<Vec<Value>>,
+    pub after: Option<Vec<Value>>,
+}
+
+impl Change {
+    pub fn kind(&self) -> ChangeKind {
+        match (&self.before, &self.after) {
+            (None, _) => ChangeKind::Insert,
+            (Some(_), Some(_)) => ChangeKind::Update,
+            (Some(_), None) => ChangeKind::Delete,
+        }
+    }
+}
+
+/// By implementing `ChangeFeed` trait, consumers can tail the rows inserted,
+/// updated and deleted by committed transactions.
+pub trait ChangeFeed {
+    /// Scans the changes recorded after `checkpoint` in commit order.
+    /// Pass `0` to read the feed from the beginning, or the `seq` of the last
+    /// change a consumer handled to resume from there.
+    fn scan_changes(&self, checkpoint: u64) -> Result<ChangeIter<'_>>;
+
+    /// Discards the changes up to and including `checkpoint`.
+    fn purge_changes(&mut self, checkpoint: u64) -> Result<()>;
+}
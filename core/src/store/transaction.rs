@@ -15,7 +15,20 @@ pub trait Transaction {
         Ok(())
     }
 
+    /// Checks that a following `commit` of the current transaction can succeed.
+    fn prepare(&mut self) -> Result<()> {
+        Ok(())
+    }
+
     fn commit(&mut self) -> Result<()> {
         Ok(())
     }
+
+    /// Whether committed changes outlive the process.
+    ///
+    /// Storages that coordinate several engines commit the durable ones first,
+    /// so an in-memory engine is still rolled back when a durable commit fails.
+    fn durable(&self) -> bool {
+        true
+    }
 }
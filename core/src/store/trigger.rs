@@ -0,0 +1,29 @@
+use crate::{
+    data::Trigger as StructTrigger,
+    result::{Error, Result},
+};
+
+/// Storages without trigger support report no triggers so that DML keeps working.
+pub trait Trigger {
+    fn fetch_triggers(&self, _table_name: &str) -> Result<Vec<StructTrigger>> {
+        Ok(Vec::new())
+    }
+
+    fn fetch_all_triggers(&self) -> Result<Vec<StructTrigger>> {
+        Ok(Vec::new())
+    }
+}
+
+pub trait TriggerMut {
+    fn insert_trigger(&mut self, _trigger: StructTrigger) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Trigger is not supported".to_owned(),
+        ))
+    }
+
+    fn delete_trigger(&mut self, _table_name: &str, _trigger_name: &str) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Trigger is not supported".to_owned(),
+        ))
+    }
+}
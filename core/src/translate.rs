@@ -16,16 +16,22 @@ pub use self::{
         QueryOption, SelectOption, TransactionOption, TranslateError, UpdateOption,
     },
     expr::{translate_expr, translate_order_by_expr},
-    param::{IntoParamLiteral, ParamLiteral},
+    param::{IntoParamLiteral, ParamLiteral, ParamOf, sql_type, typed_param},
     query::{alias_or_name, translate_query, translate_select_item},
 };
 
 use {
     crate::{
-        ast::{Assignment, Expr, ForeignKey, ReferentialAction, Statement, Variable},
+        ast::{
+            Assignment, Expr, ForeignKey, ReferentialAction, Statement, TriggerEvent,
+            TriggerTiming, Variable,
+        },
+        parse_sql::{ParsedStatement, SqlCreateTrigger},
         result::Result,
     },
-    ddl::{translate_alter_table_operation, translate_operate_function_arg},
+    ddl::{
+        translate_alter_table_operation, translate_operate_function_arg, translate_table_options,
+    },
     sqlparser::ast::{
         Assignment as SqlAssignment, AssignmentTarget as SqlAssignmentTarget,
         CommentDef as SqlCommentDef, CreateFunctionBody as SqlCreateFunctionBody,
@@ -34,6 +40,7 @@ use {
         ObjectName as SqlObjectName, ObjectType as SqlObjectType,
         ReferentialAction as SqlReferentialAction, Statement as SqlStatement,
         TableConstraint as SqlTableConstraint, TableFactor, TableWithJoins,
+        TriggerEvent as SqlTriggerEvent, TriggerPeriod as SqlTriggerPeriod,
     },
     std::num::NonZeroUsize,
 };
@@ -202,6 +209,7 @@ pub fn translate_with_params(
             temporary,
             like,
             clone,
+            with_options,
             ..
         }) => {
             let violation = if *temporary {
@@ -232,6 +240,8 @@ pub fn translate_with_params(
                 .map(translate_foreign_key)
                 .collect::<Result<Vec<_>>>()?;
 
+            let ttl_column = translate_table_options(with_options)?;
+
             Ok(Statement::CreateTable {
                 if_not_exists: *if_not_exists,
                 name,
@@ -249,6 +259,7 @@ pub fn translate_with_params(
                     | SqlCommentDef::WithoutEq(comment)
                     | SqlCommentDef::AfterColumnDefsWithoutEq(comment) => comment.to_owned(),
                 }),
+                ttl_column,
             })
         }
         SqlStatement::AlterTable {
@@ -293,6 +304,16 @@ pub fn translate_with_params(
                 .map(|v| translate_object_name(&v.name))
                 .collect::<Result<Vec<_>>>()?,
         }),
+        SqlStatement::DropTrigger {
+            if_exists,
+            trigger_name,
+            table_name,
+            ..
+        } => Ok(Statement::DropTrigger {
+            if_exists: *if_exists,
+            name: translate_object_name(trigger_name)?,
+            table_name: translate_object_name(table_name)?,
+        }),
         SqlStatement::CreateIndex(SqlCreateIndex {
             name,
             table_name,
@@ -479,6 +500,73 @@ pub fn translate_with_params(
     }
 }
 
+/// Translates a [`ParsedStatement`] into `GlueSQL`'s [`Statement`] using the supplied parameters.
+///
+/// # Errors
+///
+/// Returns an error when the statement uses syntax `GlueSQL` does not support.
+pub fn translate_parsed_with_params(
+    parsed_statement: &ParsedStatement,
+    params: &[ParamLiteral],
+) -> Result<Statement> {
+    match parsed_statement {
+        ParsedStatement::Sql(sql_statement) => translate_with_params(sql_statement, params),
+        ParsedStatement::CreateTrigger(create_trigger) => translate_create_trigger(create_trigger),
+    }
+}
+
+fn translate_create_trigger(
+    SqlCreateTrigger {
+        or_replace,
+        name,
+        period,
+        events,
+        table_name,
+        body,
+    }: &SqlCreateTrigger,
+) -> Result<Statement> {
+    let timing = match period {
+        SqlTriggerPeriod::Before => TriggerTiming::Before,
+        SqlTriggerPeriod::After => TriggerTiming::After,
+        SqlTriggerPeriod::InsteadOf => {
+            return Err(TranslateError::UnsupportedTriggerTiming(period.to_string()).into());
+        }
+    };
+
+    let events = events
+        .iter()
+        .map(|event| match event {
+            SqlTriggerEvent::Insert => Ok(TriggerEvent::Insert),
+            SqlTriggerEvent::Update(columns) if columns.is_empty() => Ok(TriggerEvent::Update),
+            SqlTriggerEvent::Delete => Ok(TriggerEvent::Delete),
+            SqlTriggerEvent::Update(_) | SqlTriggerEvent::Truncate => {
+                Err(TranslateError::UnsupportedTriggerEvent(event.to_string()).into())
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Body statements are stored as SQL text and translated again each time the
+    // trigger fires, with `NEW` and `OLD` bound to the affected rows.
+    let body = body
+        .iter()
+        .map(|sql_statement| match translate(sql_statement)? {
+            Statement::Insert { .. } | Statement::Update { .. } | Statement::Delete { .. } => {
+                Ok(sql_statement.to_string())
+            }
+            _ => Err(TranslateError::UnsupportedTriggerStatement(sql_statement.to_string()).into()),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Statement::CreateTrigger {
+        or_replace: *or_replace,
+        name: translate_object_name(name)?,
+        table_name: translate_object_name(table_name)?,
+        timing,
+        events,
+        body,
+    })
+}
+
 pub(crate) fn bind_placeholder(params: &[ParamLiteral], placeholder: &str) -> Result<Expr> {
     let invalid_placeholder = || TranslateError::InvalidPlaceholder {
         placeholder: placeholder.to_owned(),
@@ -662,6 +750,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn create_trigger() {
+        let translate_sql = |sql: &str| {
+            crate::parse_sql::parse_statements(sql)
+                .and_then(|parsed| translate_parsed_with_params(&parsed[0], NO_PARAMS))
+        };
+
+        assert_eq!(
+            translate_sql(
+                "CREATE OR REPLACE TRIGGER audit BEFORE INSERT OR DELETE ON Foo FOR EACH ROW
+                 BEGIN INSERT INTO Log VALUES (NEW.id); DELETE FROM Log WHERE id = 1; END"
+            ),
+            Ok(Statement::CreateTrigger {
+                or_replace: true,
+                name: "audit".to_owned(),
+                table_name: "Foo".to_owned(),
+                timing: TriggerTiming::Before,
+                events: vec![TriggerEvent::Insert, TriggerEvent::Delete],
+                body: vec![
+                    "INSERT INTO Log VALUES (NEW.id)".to_owned(),
+                    "DELETE FROM Log WHERE id = 1".to_owned(),
+                ],
+            })
+        );
+        assert_eq!(
+            translate_sql("CREATE TRIGGER audit INSTEAD OF INSERT ON Foo FOR EACH ROW BEGIN END"),
+            Err(TranslateError::UnsupportedTriggerTiming("INSTEAD OF".to_owned()).into())
+        );
+        assert_eq!(
+            translate_sql("CREATE TRIGGER audit AFTER UPDATE OF id ON Foo FOR EACH ROW BEGIN END"),
+            Err(TranslateError::UnsupportedTriggerEvent("UPDATE OF id".to_owned()).into())
+        );
+        assert_eq!(
+            translate_sql(
+                "CREATE TRIGGER audit AFTER INSERT ON Foo FOR EACH ROW BEGIN COMMIT; END"
+            ),
+            Err(TranslateError::UnsupportedTriggerStatement("COMMIT".to_owned()).into())
+        );
+        assert_eq!(
+            translate_sql("DROP TRIGGER IF EXISTS audit ON Foo"),
+            Ok(Statement::DropTrigger {
+                if_exists: true,
+                name: "audit".to_owned(),
+                table_name: "Foo".to_owned(),
+            })
+        );
+    }
+
     #[test]
     fn test_tuple_assignment_on_update_not_supported() {
         assert_translate_error(
@@ -770,6 +906,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn create_table_with_options() {
+        let actual = parse(
+            "CREATE TABLE Foo (id INTEGER, expires_at TIMESTAMP) WITH (ttl_column = expires_at)",
+        )
+        .and_then(|parsed| translate(&parsed[0]));
+        assert!(matches!(
+            actual,
+            Ok(Statement::CreateTable { ttl_column: Some(column), .. }) if column == "expires_at"
+        ));
+
+        assert_translate_error(
+            "CREATE TABLE Foo (id INTEGER) WITH (fillfactor = 70)",
+            TranslateError::UnsupportedTableOption("fillfactor = 70".to_owned()),
+        );
+        assert_translate_error(
+            "CREATE TABLE Foo (a TIMESTAMP, b TIMESTAMP) WITH (ttl_column = a, ttl_column = b)",
+            TranslateError::UnsupportedTableOption("ttl_column = b".to_owned()),
+        );
+    }
+
     #[test]
     fn create_index_options_not_supported() {
         let cases = [
@@ -43,6 +43,9 @@ pub fn translate_data_type(sql_data_type: &SqlDataType) -> Result<DataType> {
                 Some("MAP") => Ok(DataType::Map),
                 Some("LIST") => Ok(DataType::List),
                 Some("POINT") => Ok(DataType::Point),
+                Some("LINESTRING") => Ok(DataType::LineString),
+                Some("POLYGON") => Ok(DataType::Polygon),
+                Some("MULTIPOINT") => Ok(DataType::MultiPoint),
                 Some("INET") => Ok(DataType::Inet),
 
                 _ => Err(TranslateError::UnsupportedDataType(sql_data_type.to_string()).into()),
@@ -110,6 +113,9 @@ mod tests {
         test!("MAP" => Ok(DataType::Map));
         test!("LIST" => Ok(DataType::List));
         test!("POINT" => Ok(DataType::Point));
+        test!("LINESTRING" => Ok(DataType::LineString));
+        test!("POLYGON" => Ok(DataType::Polygon));
+        test!("MULTIPOINT" => Ok(DataType::MultiPoint));
         test!("INET" => Ok(DataType::Inet));
     }
 }
@@ -9,8 +9,8 @@ use {
     },
     sqlparser::ast::{
         AlterTableOperation as SqlAlterTableOperation, ColumnDef as SqlColumnDef,
-        ColumnOption as SqlColumnOption, ColumnOptionDef as SqlColumnOptionDef,
-        OperateFunctionArg as SqlOperateFunctionArg,
+        ColumnOption as SqlColumnOption, ColumnOptionDef as SqlColumnOptionDef, Expr as SqlExpr,
+        OperateFunctionArg as SqlOperateFunctionArg, SqlOption,
     },
 };
 
@@ -106,6 +106,25 @@ pub fn translate_column_def(
     })
 }
 
+/// Translates the `WITH (...)` options of `CREATE TABLE` into the table's `ttl_column`.
+///
+/// # Errors
+///
+/// Returns an error when an option other than `ttl_column = <column>` is given.
+pub(crate) fn translate_table_options(with_options: &[SqlOption]) -> Result<Option<String>> {
+    with_options
+        .iter()
+        .try_fold(None, |ttl_column, option| match option {
+            SqlOption::KeyValue {
+                key,
+                value: SqlExpr::Identifier(column),
+            } if key.value.eq_ignore_ascii_case("ttl_column") && ttl_column.is_none() => {
+                Ok(Some(column.value.clone()))
+            }
+            _ => Err(TranslateError::UnsupportedTableOption(option.to_string()).into()),
+        })
+}
+
 /// Translates a [`SqlOperateFunctionArg`] into `GlueSQL`'s [`OperateFunctionArg`] using the supplied parameters.
 ///
 /// # Errors
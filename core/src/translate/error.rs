@@ -302,6 +302,15 @@ pub enum TranslateError {
     #[error("empty function body is not supported")]
     UnsupportedEmptyFunctionBody,
 
+    #[error("unsupported trigger timing: {0}")]
+    UnsupportedTriggerTiming(String),
+
+    #[error("unsupported trigger event: {0}")]
+    UnsupportedTriggerEvent(String),
+
+    #[error("unsupported statement in trigger body: {0}")]
+    UnsupportedTriggerStatement(String),
+
     #[error("unsupported unnamed index")]
     UnsupportedUnnamedIndex,
 
@@ -311,6 +320,9 @@ pub enum TranslateError {
     #[error("unsupported CREATE TABLE option: {0}")]
     UnsupportedCreateTableOption(CreateTableOption),
 
+    #[error("unsupported table option: {0}")]
+    UnsupportedTableOption(String),
+
     #[error("unsupported CREATE INDEX option: {0}")]
     UnsupportedCreateIndexOption(CreateIndexOption),
 
@@ -360,6 +372,18 @@ pub enum TranslateError {
     #[error("qualified wildcard is not supported - COUNT({0})")]
     QualifiedWildcardInCountNotSupported(String),
 
+    #[error("FILTER (WHERE ..) is only supported on aggregate functions: {0}")]
+    UnsupportedFunctionFilter(String),
+
+    #[error("ORDER BY in function arguments is not supported: {0}")]
+    UnsupportedFunctionArgOrderBy(String),
+
+    #[error("WITHIN GROUP (ORDER BY ..) is not supported: {0}")]
+    UnsupportedWithinGroup(String),
+
+    #[error("function {0} requires WITHIN GROUP (ORDER BY <expr>) with a single expression")]
+    WithinGroupRequiresSingleOrderBy(String),
+
     #[error("order by - NULLS (FIRST | LAST) is not supported")]
     OrderByNullsFirstOrLastNotSupported,
 
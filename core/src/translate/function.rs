@@ -1,19 +1,20 @@
 use {
     super::{
         ParamLiteral, TranslateError,
-        expr::translate_expr,
+        expr::{translate_expr, translate_order_by_expr},
         literal::{translate_datetime_field, translate_trim_where_field},
         translate_data_type, translate_object_name,
     },
     crate::{
-        ast::{Aggregate, CountArgExpr, Expr, Function},
+        ast::{Aggregate, AggregateFunction, CountArgExpr, Expr, Function, OrderByExpr},
         result::Result,
     },
     sqlparser::ast::{
         CastFormat as SqlCastFormat, CastKind as SqlCastKind, DataType as SqlDataType,
         DateTimeField as SqlDateTimeField, DuplicateTreatment as SqlDuplicateTreatment,
         Expr as SqlExpr, Function as SqlFunction, FunctionArg as SqlFunctionArg,
-        FunctionArgExpr as SqlFunctionArgExpr, FunctionArguments as SqlFunctionArguments,
+        FunctionArgExpr as SqlFunctionArgExpr, FunctionArgumentClause as SqlFunctionArgumentClause,
+        FunctionArguments as SqlFunctionArguments, OrderByExpr as SqlOrderByExpr,
         TrimWhereField as SqlTrimWhereField,
     },
 };
@@ -172,6 +173,25 @@ fn translate_aggregate_one_arg<T: FnOnce(Expr, bool) -> Aggregate>(
         .map(Expr::Aggregate)
 }
 
+fn translate_aggregate_percentile<T: FnOnce(Expr, OrderByExpr) -> AggregateFunction>(
+    params: &[ParamLiteral],
+    func: T,
+    args: &[&SqlExpr],
+    within_group: &[SqlOrderByExpr],
+    name: String,
+    distinct: bool,
+) -> Result<Expr> {
+    check_len(name.clone(), args.len(), 1)?;
+
+    let fraction = translate_expr(args[0], params)?;
+    let order_by = translate_within_group(params, within_group, name)?;
+
+    Ok(Expr::Aggregate(Box::new(Aggregate::new(
+        func(fraction, order_by),
+        distinct,
+    ))))
+}
+
 fn translate_function_trim<T: FnOnce(Expr, Option<Expr>) -> Function>(
     params: &[ParamLiteral],
     func: T,
@@ -192,6 +212,25 @@ fn translate_function_trim<T: FnOnce(Expr, Option<Expr>) -> Function>(
     Ok(Expr::Function(Box::new(result)))
 }
 
+fn translate_function_regexp<T: FnOnce(Expr, Expr, Option<Expr>) -> Function>(
+    params: &[ParamLiteral],
+    func: T,
+    args: &[&SqlExpr],
+    name: String,
+) -> Result<Expr> {
+    check_len_range(name, args.len(), 2, 3)?;
+
+    let expr = translate_expr(args[0], params)?;
+    let pattern = translate_expr(args[1], params)?;
+    let flags = (args.len() > 2)
+        .then(|| translate_expr(args[2], params))
+        .transpose()?;
+
+    let result = func(expr, pattern, flags);
+
+    Ok(Expr::Function(Box::new(result)))
+}
+
 pub fn translate_function_arg_exprs(
     function_arg_exprs: Vec<&SqlFunctionArgExpr>,
 ) -> Result<Vec<&SqlExpr>> {
@@ -210,10 +249,54 @@ pub(crate) fn translate_function(
     params: &[ParamLiteral],
     sql_function: &SqlFunction,
 ) -> Result<Expr> {
-    let SqlFunction { name, args, .. } = sql_function;
+    let expr = translate_function_call(params, sql_function)?;
+    let Some(filter) = sql_function.filter.as_deref() else {
+        return Ok(expr);
+    };
+
+    let Expr::Aggregate(aggregate) = expr else {
+        let name = translate_object_name(&sql_function.name)?.to_uppercase();
+
+        return Err(TranslateError::UnsupportedFunctionFilter(name).into());
+    };
+    let filter = translate_expr(filter, params)?;
+
+    Ok(Expr::Aggregate(Box::new(
+        aggregate.with_filter(Some(filter)),
+    )))
+}
+
+fn translate_aggregate_order_by(
+    params: &[ParamLiteral],
+    order_by: &[SqlOrderByExpr],
+) -> Result<Vec<OrderByExpr>> {
+    order_by
+        .iter()
+        .map(|order_by| translate_order_by_expr(order_by, params))
+        .collect()
+}
+
+fn translate_within_group(
+    params: &[ParamLiteral],
+    within_group: &[SqlOrderByExpr],
+    name: String,
+) -> Result<OrderByExpr> {
+    match within_group {
+        [order_by] => translate_order_by_expr(order_by, params),
+        _ => Err(TranslateError::WithinGroupRequiresSingleOrderBy(name).into()),
+    }
+}
+
+fn translate_function_call(params: &[ParamLiteral], sql_function: &SqlFunction) -> Result<Expr> {
+    let SqlFunction {
+        name,
+        args,
+        within_group,
+        ..
+    } = sql_function;
     let name = translate_object_name(name)?.to_uppercase();
-    let (args, distinct) = match args {
-        SqlFunctionArguments::None => (Vec::new(), false),
+    let (args, distinct, order_by) = match args {
+        SqlFunctionArguments::None => (Vec::new(), false, [].as_slice()),
         SqlFunctionArguments::Subquery(_) => {
             return Err(TranslateError::UnreachableSubqueryFunctionArgNotSupported.into());
         }
@@ -221,10 +304,32 @@ pub(crate) fn translate_function(
             let distinct = list
                 .duplicate_treatment
                 .is_some_and(|dt| matches!(dt, SqlDuplicateTreatment::Distinct));
-            (list.args.iter().collect(), distinct)
+            let order_by = list
+                .clauses
+                .iter()
+                .find_map(|clause| match clause {
+                    SqlFunctionArgumentClause::OrderBy(order_by) => Some(order_by.as_slice()),
+                    _ => None,
+                })
+                .unwrap_or_default();
+
+            (list.args.iter().collect(), distinct, order_by)
         }
     };
 
+    if !order_by.is_empty() && !matches!(name.as_str(), "STRING_AGG" | "ARRAY_AGG") {
+        return Err(TranslateError::UnsupportedFunctionArgOrderBy(name).into());
+    }
+
+    if !within_group.is_empty()
+        && !matches!(
+            name.as_str(),
+            "PERCENTILE_CONT" | "PERCENTILE_DISC" | "MODE"
+        )
+    {
+        return Err(TranslateError::UnsupportedWithinGroup(name).into());
+    }
+
     let function_arg_exprs = args
         .iter()
         .map(|arg| match arg {
@@ -265,6 +370,69 @@ pub(crate) fn translate_function(
             translate_aggregate_one_arg(params, Aggregate::variance, &args, name, distinct)
         }
         "STDEV" => translate_aggregate_one_arg(params, Aggregate::stdev, &args, name, distinct),
+        "BOOL_AND" => {
+            translate_aggregate_one_arg(params, Aggregate::bool_and, &args, name, distinct)
+        }
+        "BOOL_OR" => translate_aggregate_one_arg(params, Aggregate::bool_or, &args, name, distinct),
+        "MEDIAN" => translate_aggregate_one_arg(params, Aggregate::median, &args, name, distinct),
+        "MODE" if within_group.is_empty() => {
+            translate_aggregate_one_arg(params, Aggregate::mode, &args, name, distinct)
+        }
+        "MODE" => {
+            check_len(name.clone(), args.len(), 0)?;
+
+            let OrderByExpr { expr, .. } = translate_within_group(params, within_group, name)?;
+
+            Ok(Expr::Aggregate(Box::new(Aggregate::mode(expr, distinct))))
+        }
+        "GROUPING" => {
+            check_len_min(name, args.len(), 1)?;
+
+            let exprs = args
+                .into_iter()
+                .map(|arg| translate_expr(arg, params))
+                .collect::<Result<Vec<_>>>()?;
+            let func = AggregateFunction::Grouping(exprs);
+
+            Ok(Expr::Aggregate(Box::new(Aggregate::new(func, distinct))))
+        }
+        "STRING_AGG" => {
+            check_len(name, args.len(), 2)?;
+
+            let func = AggregateFunction::StringAgg {
+                expr: translate_expr(args[0], params)?,
+                separator: translate_expr(args[1], params)?,
+                order_by: translate_aggregate_order_by(params, order_by)?,
+            };
+
+            Ok(Expr::Aggregate(Box::new(Aggregate::new(func, distinct))))
+        }
+        "ARRAY_AGG" => {
+            check_len(name, args.len(), 1)?;
+
+            let func = AggregateFunction::ArrayAgg {
+                expr: translate_expr(args[0], params)?,
+                order_by: translate_aggregate_order_by(params, order_by)?,
+            };
+
+            Ok(Expr::Aggregate(Box::new(Aggregate::new(func, distinct))))
+        }
+        "PERCENTILE_CONT" => translate_aggregate_percentile(
+            params,
+            |fraction, order_by| AggregateFunction::PercentileCont { fraction, order_by },
+            &args,
+            within_group,
+            name,
+            distinct,
+        ),
+        "PERCENTILE_DISC" => translate_aggregate_percentile(
+            params,
+            |fraction, order_by| AggregateFunction::PercentileDisc { fraction, order_by },
+            &args,
+            within_group,
+            name,
+            distinct,
+        ),
         "COALESCE" => {
             let exprs = args
                 .into_iter()
@@ -307,6 +475,63 @@ pub(crate) fn translate_function(
                 start,
             })))
         }
+        "REGEXP_LIKE" => translate_function_regexp(
+            params,
+            |expr, pattern, flags| Function::RegexpLike {
+                expr,
+                pattern,
+                flags,
+            },
+            &args,
+            name,
+        ),
+        "REGEXP_SUBSTR" => translate_function_regexp(
+            params,
+            |expr, pattern, flags| Function::RegexpSubstr {
+                expr,
+                pattern,
+                flags,
+            },
+            &args,
+            name,
+        ),
+        "REGEXP_MATCHES" => translate_function_regexp(
+            params,
+            |expr, pattern, flags| Function::RegexpMatches {
+                expr,
+                pattern,
+                flags,
+            },
+            &args,
+            name,
+        ),
+        "REGEXP_SPLIT_TO_ARRAY" => translate_function_regexp(
+            params,
+            |expr, pattern, flags| Function::RegexpSplitToArray {
+                expr,
+                pattern,
+                flags,
+            },
+            &args,
+            name,
+        ),
+        "REGEXP_REPLACE" => {
+            check_len_range(name, args.len(), 3, 4)?;
+
+            let expr = translate_expr(args[0], params)?;
+            let pattern = translate_expr(args[1], params)?;
+            let replacement = translate_expr(args[2], params)?;
+            let flags = (args.len() > 3)
+                .then(|| translate_expr(args[3], params))
+                .transpose()?;
+
+            Ok(Expr::Function(Box::new(Function::RegexpReplace {
+                expr,
+                pattern,
+                replacement,
+                flags,
+            })))
+        }
         "LOWER" => translate_function_one_arg(params, Function::Lower, &args, name),
         "INITCAP" => translate_function_one_arg(params, Function::Initcap, &args, name),
         "UPPER" => translate_function_one_arg(params, Function::Upper, &args, name),
@@ -441,6 +666,87 @@ pub(crate) fn translate_function(
 
             Ok(Expr::Function(Box::new(Function::LastDay(expr))))
         }
+        "DATE_TRUNC" => {
+            check_len(name, args.len(), 2)?;
+
+            let field = translate_expr(args[0], params)?;
+            let expr = translate_expr(args[1], params)?;
+
+            Ok(Expr::Function(Box::new(Function::DateTrunc {
+                field,
+                expr,
+            })))
+        }
+        "DATE_BIN" => {
+            check_len_range(name, args.len(), 2, 3)?;
+
+            let stride = translate_expr(args[0], params)?;
+            let expr = translate_expr(args[1], params)?;
+            let origin = (args.len() > 2)
+                .then(|| translate_expr(args[2], params))
+                .transpose()?;
+
+            Ok(Expr::Function(Box::new(Function::DateBin {
+                stride,
+                expr,
+                origin,
+            })))
+        }
+        "DATE_DIFF" => {
+            check_len(name, args.len(), 3)?;
+
+            let field = translate_expr(args[0], params)?;
+            let start = translate_expr(args[1], params)?;
+            let end = translate_expr(args[2], params)?;
+
+            Ok(Expr::Function(Box::new(Function::DateDiff {
+                field,
+                start,
+                end,
+            })))
+        }
+        "AGE" => {
+            check_len_range(name, args.len(), 1, 2)?;
+
+            let left = translate_expr(args[0], params)?;
+            let right = (args.len() > 1)
+                .then(|| translate_expr(args[1], params))
+                .transpose()?;
+
+            Ok(Expr::Function(Box::new(Function::Age { left, right })))
+        }
+        "MAKE_DATE" => {
+            check_len(name, args.len(), 3)?;
+
+            let year = translate_expr(args[0], params)?;
+            let month = translate_expr(args[1], params)?;
+            let day = translate_expr(args[2], params)?;
+
+            Ok(Expr::Function(Box::new(Function::MakeDate {
+                year,
+                month,
+                day,
+            })))
+        }
+        "MAKE_TIMESTAMP" => {
+            check_len(name, args.len(), 6)?;
+
+            let year = translate_expr(args[0], params)?;
+            let month = translate_expr(args[1], params)?;
+            let day = translate_expr(args[2], params)?;
+            let hour = translate_expr(args[3], params)?;
+            let minute = translate_expr(args[4], params)?;
+            let second = translate_expr(args[5], params)?;
+
+            Ok(Expr::Function(Box::new(Function::MakeTimestamp {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            })))
+        }
         "LCM" => {
             check_len(name, args.len(), 2)?;
 
@@ -672,6 +978,58 @@ pub(crate) fn translate_function(
                 geometry2,
             })))
         }
+        "ST_CONTAINS" => {
+            check_len(name, args.len(), 2)?;
+
+            let geometry1 = translate_expr(args[0], params)?;
+            let geometry2 = translate_expr(args[1], params)?;
+            Ok(Expr::Function(Box::new(Function::StContains {
+                geometry1,
+                geometry2,
+            })))
+        }
+        "ST_INTERSECTS" => {
+            check_len(name, args.len(), 2)?;
+
+            let geometry1 = translate_expr(args[0], params)?;
+            let geometry2 = translate_expr(args[1], params)?;
+            Ok(Expr::Function(Box::new(Function::StIntersects {
+                geometry1,
+                geometry2,
+            })))
+        }
+        "ST_WITHIN" => {
+            check_len(name, args.len(), 2)?;
+
+            let geometry1 = translate_expr(args[0], params)?;
+            let geometry2 = translate_expr(args[1], params)?;
+            Ok(Expr::Function(Box::new(Function::StWithin {
+                geometry1,
+                geometry2,
+            })))
+        }
+        "ST_AREA" => {
+            check_len(name, args.len(), 1)?;
+
+            let expr = translate_expr(args[0], params)?;
+            Ok(Expr::Function(Box::new(Function::StArea(expr))))
+        }
+        "ST_LENGTH" => {
+            check_len(name, args.len(), 1)?;
+
+            let expr = translate_expr(args[0], params)?;
+            Ok(Expr::Function(Box::new(Function::StLength(expr))))
+        }
+        "ST_DISTANCE_SPHERE" => {
+            check_len(name, args.len(), 2)?;
+
+            let geometry1 = translate_expr(args[0], params)?;
+            let geometry2 = translate_expr(args[1], params)?;
+            Ok(Expr::Function(Box::new(Function::StDistanceSphere {
+                geometry1,
+                geometry2,
+            })))
+        }
         "IS_EMPTY" => {
             check_len(name, args.len(), 1)?;
 
@@ -41,6 +41,10 @@ pub fn translate_binary_operator(
         SqlBinaryOperator::PGBitwiseShiftLeft => Ok(BinaryOperator::BitwiseShiftLeft),
         SqlBinaryOperator::PGBitwiseShiftRight => Ok(BinaryOperator::BitwiseShiftRight),
         SqlBinaryOperator::Arrow => Ok(BinaryOperator::Arrow),
+        SqlBinaryOperator::PGRegexMatch => Ok(BinaryOperator::RegexMatch),
+        SqlBinaryOperator::PGRegexIMatch => Ok(BinaryOperator::RegexIMatch),
+        SqlBinaryOperator::PGRegexNotMatch => Ok(BinaryOperator::RegexNotMatch),
+        SqlBinaryOperator::PGRegexNotIMatch => Ok(BinaryOperator::RegexNotIMatch),
         _ => Err(TranslateError::UnsupportedBinaryOperator(sql_binary_operator.to_string()).into()),
     }
 }
@@ -1,6 +1,6 @@
 use {
     crate::{
-        ast::Expr,
+        ast::{Expr, Literal},
         data::{Interval, Point, Value},
     },
     chrono::{NaiveDate, NaiveDateTime, NaiveTime},
@@ -10,17 +10,44 @@ use {
 };
 
 #[derive(Debug, Clone)]
-pub struct ParamLiteral(Value);
+pub struct ParamLiteral(Expr);
 
 impl ParamLiteral {
     #[must_use]
     pub const fn null() -> Self {
-        Self(Value::Null)
+        Self(Expr::Value(Value::Null))
+    }
+
+    /// Binds `text` without a type, so it is converted by context the same
+    /// way a quoted string written in the SQL text is, e.g. `'1'` compared
+    /// with an `INTEGER` column.
+    #[must_use]
+    pub fn untyped(text: impl Into<String>) -> Self {
+        Self(Expr::Literal(Literal::QuotedString(text.into())))
+    }
+
+    /// Keeps the placeholder `$index` in the statement as the identifier
+    /// `$index`, so a statement can be planned before its parameters are
+    /// bound, e.g. to learn the types they are compared with.
+    #[must_use]
+    pub fn placeholder(index: usize) -> Self {
+        Self(Expr::Identifier(format!("${index}")))
+    }
+
+    /// Index of the placeholder an identifier bound by
+    /// [`ParamLiteral::placeholder`] stands for.
+    #[must_use]
+    pub fn placeholder_index(ident: &str) -> Option<usize> {
+        ident
+            .strip_prefix('$')
+            .filter(|digits| digits.bytes().all(|byte| byte.is_ascii_digit()))?
+            .parse()
+            .ok()
     }
 
     #[must_use]
     pub fn into_expr(self) -> Expr {
-        Expr::Value(self.0)
+        self.0
     }
 }
 
@@ -35,12 +62,18 @@ impl IntoParamLiteral for ParamLiteral {
     }
 }
 
+impl IntoParamLiteral for Value {
+    fn into_param_literal(self) -> ParamLiteral {
+        ParamLiteral(Expr::Value(self))
+    }
+}
+
 macro_rules! impl_into_param_literal {
     ($($rust_ty:ty => $value_variant:ident),+ $(,)?) => {
         $(
             impl IntoParamLiteral for $rust_ty {
                 fn into_param_literal(self) -> ParamLiteral {
-                    ParamLiteral(Value::$value_variant(self))
+                    Value::$value_variant(self).into_param_literal()
                 }
             }
         )+
@@ -75,31 +108,31 @@ impl_into_param_literal!(
 // Types that need conversion
 impl IntoParamLiteral for isize {
     fn into_param_literal(self) -> ParamLiteral {
-        ParamLiteral(Value::I64(self as i64))
+        Value::I64(self as i64).into_param_literal()
     }
 }
 
 impl IntoParamLiteral for usize {
     fn into_param_literal(self) -> ParamLiteral {
-        ParamLiteral(Value::U64(self as u64))
+        Value::U64(self as u64).into_param_literal()
     }
 }
 
 impl IntoParamLiteral for &str {
     fn into_param_literal(self) -> ParamLiteral {
-        ParamLiteral(Value::Str(self.to_owned()))
+        Value::Str(self.to_owned()).into_param_literal()
     }
 }
 
 impl IntoParamLiteral for &[u8] {
     fn into_param_literal(self) -> ParamLiteral {
-        ParamLiteral(Value::Bytea(self.to_vec()))
+        Value::Bytea(self.to_vec()).into_param_literal()
     }
 }
 
 impl IntoParamLiteral for Uuid {
     fn into_param_literal(self) -> ParamLiteral {
-        ParamLiteral(Value::Uuid(self.as_u128()))
+        Value::Uuid(self.as_u128()).into_param_literal()
     }
 }
 
@@ -115,6 +148,65 @@ where
     }
 }
 
+/// SQL types `glue_query!` checks the argument of a parameter against, by
+/// the column or expression the parameter is compared with or assigned to.
+pub mod sql_type {
+    pub struct Boolean;
+    pub struct Integer;
+    pub struct Float;
+    pub struct Decimal;
+    pub struct Text;
+    pub struct Bytea;
+    pub struct Inet;
+    pub struct Date;
+    pub struct Time;
+    pub struct Timestamp;
+    pub struct Interval;
+    pub struct Uuid;
+    pub struct Point;
+}
+
+/// Rust types that can be bound to a parameter of the SQL type `T`, one of
+/// the [`sql_type`] markers.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be bound to a parameter of SQL type `{T}`",
+    label = "the SQL uses this parameter as `{T}`"
+)]
+pub trait ParamOf<T> {}
+
+impl<T> ParamOf<T> for Value {}
+impl<T> ParamOf<T> for ParamLiteral {}
+impl<T, P: ParamOf<T>> ParamOf<T> for Option<P> {}
+impl<T, P: ParamOf<T> + ?Sized> ParamOf<T> for &P {}
+
+macro_rules! impl_param_of {
+    ($($sql_type:ident => $($rust_ty:ty),+);+ $(;)?) => {
+        $($(impl ParamOf<sql_type::$sql_type> for $rust_ty {})+)+
+    };
+}
+
+impl_param_of!(
+    Boolean => bool;
+    Integer => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize;
+    Float => f32, f64, i8, i16, i32, i64, u8, u16, u32, u64;
+    Decimal => Decimal, f32, f64, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128;
+    Text => String, str;
+    Bytea => Vec<u8>, [u8];
+    Inet => IpAddr;
+    Date => NaiveDate;
+    Time => NaiveTime;
+    Timestamp => NaiveDateTime;
+    Interval => Interval;
+    Uuid => Uuid, u128;
+    Point => Point;
+);
+
+/// Binds `param` after checking at compile time that it fits a parameter
+/// of the SQL type `T`.
+pub fn typed_param<T, P: ParamOf<T> + IntoParamLiteral>(param: P) -> ParamLiteral {
+    param.into_param_literal()
+}
+
 #[macro_export]
 macro_rules! params {
     ($($expr:expr),* $(,)?) => {
@@ -130,7 +222,10 @@ macro_rules! params {
 mod tests {
     use {
         super::*,
-        crate::{ast::Expr, data::Point},
+        crate::{
+            ast::{Expr, Literal},
+            data::Point,
+        },
         chrono::{NaiveDate, NaiveTime},
         rust_decimal::Decimal,
         std::{net::IpAddr, str::FromStr},
@@ -145,6 +240,21 @@ mod tests {
         assert!(matches!(converted.into_expr(), Expr::Value(Value::Null)));
     }
 
+    #[test]
+    fn binds_untyped_text() {
+        let expr = ParamLiteral::untyped("42").into_expr();
+        assert_eq!(expr, Expr::Literal(Literal::QuotedString("42".to_owned())));
+    }
+
+    #[test]
+    fn keeps_placeholder() {
+        let expr = ParamLiteral::placeholder(2).into_expr();
+        assert_eq!(expr, Expr::Identifier("$2".to_owned()));
+        assert_eq!(ParamLiteral::placeholder_index("$2"), Some(2));
+        assert_eq!(ParamLiteral::placeholder_index("$+2"), None);
+        assert_eq!(ParamLiteral::placeholder_index("id"), None);
+    }
+
     #[test]
     fn converts_basic_literals() {
         let expr = true.into_param_literal().into_expr();
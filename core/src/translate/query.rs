@@ -15,7 +15,8 @@ use {
         Distinct as SqlDistinct, Expr as SqlExpr, FunctionArg as SqlFunctionArg,
         GroupByExpr as SqlGroupByExpr, Join as SqlJoin, JoinConstraint as SqlJoinConstraint,
         JoinOperator as SqlJoinOperator, Query as SqlQuery, Select as SqlSelect,
-        SelectItem as SqlSelectItem, SetExpr as SqlSetExpr, TableAlias as SqlTableAlias,
+        SelectItem as SqlSelectItem, SetExpr as SqlSetExpr, SetOperator as SqlSetOperator,
+        SetQuantifier as SqlSetQuantifier, TableAlias as SqlTableAlias,
         TableFactor as SqlTableFactor, TableFunctionArgs as SqlTableFunctionArgs,
         TableWithJoins as SqlTableWithJoins,
     },
@@ -93,10 +94,44 @@ fn translate_set_expr(sql_set_expr: &SqlSetExpr, params: &[ParamLiteral]) -> Res
             .collect::<Result<_>>()
             .map(Values)
             .map(SetExpr::Values),
+        SqlSetExpr::SetOperation {
+            op: SqlSetOperator::Union,
+            set_quantifier,
+            left,
+            right,
+        } => {
+            let all = match set_quantifier {
+                SqlSetQuantifier::All => true,
+                SqlSetQuantifier::Distinct | SqlSetQuantifier::None => false,
+                _ => {
+                    return Err(
+                        TranslateError::UnsupportedQuerySetExpr(sql_set_expr.to_string()).into(),
+                    );
+                }
+            };
+
+            Ok(SetExpr::Union {
+                left: translate_set_operand(left, params).map(Box::new)?,
+                right: translate_set_operand(right, params).map(Box::new)?,
+                all,
+            })
+        }
         _ => Err(TranslateError::UnsupportedQuerySetExpr(sql_set_expr.to_string()).into()),
     }
 }
 
+fn translate_set_operand(sql_set_expr: &SqlSetExpr, params: &[ParamLiteral]) -> Result<Query> {
+    match sql_set_expr {
+        SqlSetExpr::Query(query) => translate_query(query, params),
+        _ => Ok(Query {
+            body: translate_set_expr(sql_set_expr, params)?,
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        }),
+    }
+}
+
 fn translate_select(sql_select: &SqlSelect, params: &[ParamLiteral]) -> Result<Select> {
     let SqlSelect {
         projection,
@@ -142,8 +177,10 @@ fn translate_select(sql_select: &SqlSelect, params: &[ParamLiteral]) -> Result<S
         },
     };
 
-    let group_by = match group_by {
-        SqlGroupByExpr::Expressions(group_by, _group_by_with_modifiers) => group_by,
+    let (group_by, grouping_sets) = match group_by {
+        SqlGroupByExpr::Expressions(group_by, _group_by_with_modifiers) => {
+            translate_group_by(group_by, params)?
+        }
         SqlGroupByExpr::All(_group_by_with_modifiers) => {
             return Err(TranslateError::UnsupportedGroupByAll.into());
         }
@@ -162,10 +199,8 @@ fn translate_select(sql_select: &SqlSelect, params: &[ParamLiteral]) -> Result<S
             .as_ref()
             .map(|expr| translate_expr(expr, params))
             .transpose()?,
-        group_by: group_by
-            .iter()
-            .map(|expr| translate_expr(expr, params))
-            .collect::<Result<_>>()?,
+        group_by,
+        grouping_sets,
         having: having
             .as_ref()
             .map(|expr| translate_expr(expr, params))
@@ -173,6 +208,90 @@ fn translate_select(sql_select: &SqlSelect, params: &[ParamLiteral]) -> Result<S
     })
 }
 
+type GroupingSets = Vec<Vec<usize>>;
+
+/// Expands `GROUP BY` items into the distinct grouping expressions plus, when `ROLLUP`,
+/// `CUBE` or `GROUPING SETS` is used, the sets of expression indexes to group by.
+///
+/// Each item contributes a list of sets (`ROLLUP (a, b)` gives `(a, b), (a), ()`, `CUBE`
+/// gives every subset), and the items are combined by their cross product.
+fn translate_group_by(
+    group_by: &[SqlExpr],
+    params: &[ParamLiteral],
+) -> Result<(Vec<Expr>, Option<GroupingSets>)> {
+    let mut exprs: Vec<Expr> = Vec::new();
+    let mut grouping_sets: GroupingSets = vec![Vec::new()];
+
+    for item in group_by {
+        let item_sets: Vec<Vec<&SqlExpr>> = match item {
+            SqlExpr::Rollup(lists) => (0..=lists.len())
+                .rev()
+                .map(|len| lists[..len].iter().flatten().collect())
+                .collect(),
+            SqlExpr::Cube(lists) => {
+                let len = lists.len();
+
+                (0..1_usize << len)
+                    .rev()
+                    .map(|mask| {
+                        lists
+                            .iter()
+                            .enumerate()
+                            .filter(|(i, _)| mask & (1 << (len - 1 - i)) != 0)
+                            .flat_map(|(_, list)| list)
+                            .collect()
+                    })
+                    .collect()
+            }
+            SqlExpr::GroupingSets(lists) => {
+                lists.iter().map(|list| list.iter().collect()).collect()
+            }
+            SqlExpr::Tuple(list) if list.is_empty() => vec![Vec::new()],
+            expr => vec![vec![expr]],
+        };
+
+        let item_sets = item_sets
+            .into_iter()
+            .map(|set| {
+                set.into_iter()
+                    .map(|expr| {
+                        let expr = translate_expr(expr, params)?;
+                        let index = exprs.iter().position(|e| e == &expr).unwrap_or_else(|| {
+                            exprs.push(expr);
+                            exprs.len() - 1
+                        });
+
+                        Ok(index)
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        grouping_sets = grouping_sets
+            .iter()
+            .flat_map(|set| {
+                item_sets.iter().map(move |item_set| {
+                    let mut set = set.clone();
+                    for index in item_set {
+                        if !set.contains(index) {
+                            set.push(*index);
+                        }
+                    }
+
+                    set
+                })
+            })
+            .collect();
+    }
+
+    let grouping_sets = match grouping_sets.as_slice() {
+        [set] if set.len() == exprs.len() => None,
+        _ => Some(grouping_sets),
+    };
+
+    Ok((exprs, grouping_sets))
+}
+
 /// Translates a [`SqlSelectItem`] into `GlueSQL`'s [`SelectItem`].
 ///
 /// # Errors
@@ -273,6 +392,35 @@ fn translate_table_factor(
                         size: translate_table_args(args)?,
                     })
                 }
+                ("GENERATE_SERIES", Some(SqlTableFunctionArgs { args, .. })) => {
+                    let args = args
+                        .iter()
+                        .map(|arg| match arg {
+                            SqlFunctionArg::Named { .. } => {
+                                Err(TranslateError::NamedFunctionArgNotSupported.into())
+                            }
+                            SqlFunctionArg::Unnamed(arg_expr) => Ok(arg_expr),
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    let args = translate_function_arg_exprs(args)?
+                        .into_iter()
+                        .map(|expr| translate_expr(expr, params))
+                        .collect::<Result<Vec<_>>>()?;
+
+                    let mut args = args.into_iter();
+                    match (args.next(), args.next(), args.next(), args.next()) {
+                        (Some(start), Some(end), step, None) => Ok(TableFactor::GenerateSeries {
+                            alias: alias_or_name(alias, object_name),
+                            start,
+                            stop: end,
+                            step,
+                        }),
+                        _ => Err(TranslateError::UnsupportedQueryTableFactor(
+                            sql_table_factor.to_string(),
+                        )
+                        .into()),
+                    }
+                }
                 ("GLUE_OBJECTS", None) => Ok(TableFactor::Dictionary {
                     dict: Dictionary::GlueObjects,
                     alias: alias_or_name(alias, object_name),
@@ -433,6 +581,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn set_operations_other_than_union_rejected() {
+        assert_query_error(
+            "SELECT 1 INTERSECT SELECT 2",
+            TranslateError::UnsupportedQuerySetExpr("SELECT 1 INTERSECT SELECT 2".into()),
+        );
+        assert_query_error(
+            "SELECT 1 EXCEPT SELECT 2",
+            TranslateError::UnsupportedQuerySetExpr("SELECT 1 EXCEPT SELECT 2".into()),
+        );
+    }
+
     #[test]
     fn join_constraint_not_supported() {
         assert_query_error(
@@ -485,6 +645,7 @@ mod tests {
                 },
                 selection: None,
                 group_by: Vec::new(),
+                grouping_sets: None,
                 having: None,
             })),
             order_by: Vec::new(),
@@ -0,0 +1,16 @@
+-- Tables the glue_query! tests are checked against.
+CREATE TABLE Customer (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    email TEXT,
+    joinedAt DATE NOT NULL
+);
+
+CREATE TABLE Item (
+    id INTEGER NOT NULL,
+    customer_id INTEGER NOT NULL,
+    price DECIMAL NOT NULL,
+    tags LIST
+);
+
+CREATE TABLE Event;
@@ -0,0 +1,2 @@
+-- Indexes do not change what glue_query! checks, so they are skipped.
+CREATE INDEX idx_item_customer ON Item (customer_id);
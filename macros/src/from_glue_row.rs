@@ -302,6 +302,9 @@ fn match_expected(
         #gluesql_crate::data::Value::Map(_) => "Map",
         #gluesql_crate::data::Value::List(_) => "List",
         #gluesql_crate::data::Value::Point(_) => "Point",
+        #gluesql_crate::data::Value::LineString(_) => "LineString",
+        #gluesql_crate::data::Value::Polygon(_) => "Polygon",
+        #gluesql_crate::data::Value::MultiPoint(_) => "MultiPoint",
         #gluesql_crate::data::Value::Null => "Null",
     }};
 
@@ -814,9 +817,18 @@ fn match_expected(
     if last_ident_is(base_ty, "Point") {
         return arms_clone!(Point, "Point");
     }
+    if last_ident_is(base_ty, "LineString") {
+        return arms_clone!(LineString, "LineString");
+    }
+    if last_ident_is(base_ty, "Polygon") {
+        return arms_clone!(Polygon, "Polygon");
+    }
+    if last_ident_is(base_ty, "MultiPoint") {
+        return arms_clone!(MultiPoint, "MultiPoint");
+    }
 
     let msg = format!(
-        "Unsupported field type for FromGlueRow: `{}`. Supported: direct matches of Value variants (integers, floats, bool, String, Decimal, Vec<u8>, IpAddr, chrono NaiveDate/NaiveDateTime/NaiveTime, Interval, u128 for Uuid, BTreeMap<String, Value>, Vec<Value>, Point) and Option<T> of those.",
+        "Unsupported field type for FromGlueRow: `{}`. Supported: direct matches of Value variants (integers, floats, bool, String, Decimal, Vec<u8>, IpAddr, chrono NaiveDate/NaiveDateTime/NaiveTime, Interval, u128 for Uuid, BTreeMap<String, Value>, Vec<Value>, Point, LineString, Polygon, MultiPoint) and Option<T> of those.",
         quote! { #base_ty }
     );
     (
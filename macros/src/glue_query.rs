@@ -0,0 +1,567 @@
+use {
+    crate::{from_glue_row::expand_from_glue_row, resolve_gluesql_crate},
+    check::{Checker, Column},
+    gluesql_core::{
+        ast::DataType,
+        error::Error,
+        parse_sql::parse_statements,
+        plan::StatementPlan,
+        translate::{ParamLiteral, TranslateError, translate_parsed_with_params},
+    },
+    proc_macro2::{Span, TokenStream},
+    quote::{quote, quote_spanned},
+    schema::SchemaFile,
+    std::{
+        env,
+        path::{Path, PathBuf},
+    },
+    syn::{
+        Expr, LitStr, Token,
+        parse::{Parse, ParseStream},
+        punctuated::Punctuated,
+        spanned::Spanned,
+    },
+};
+
+mod check;
+mod schema;
+
+const SCHEMA_DIR: &str = "schema";
+
+pub(crate) struct GlueQueryInput {
+    sql: LitStr,
+    args: Vec<Expr>,
+}
+
+impl Parse for GlueQueryInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let sql = input.parse()?;
+        let args = if input.is_empty() {
+            Vec::new()
+        } else {
+            input.parse::<Token![,]>()?;
+            Punctuated::<Expr, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect()
+        };
+
+        Ok(Self { sql, args })
+    }
+}
+
+/// Reads the schema files and expands `glue_query!`.
+///
+/// Schema files are the `.sql` files in `$GLUESQL_SCHEMA_DIR`, or in `schema`
+/// when it is not set, relative to the directory of the calling crate.
+pub(crate) fn expand_glue_query(input: &GlueQueryInput) -> Result<TokenStream, syn::Error> {
+    let error = |message| syn::Error::new(input.sql.span(), message);
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| error("CARGO_MANIFEST_DIR is not set".to_owned()))?;
+    let schema_dir = env::var("GLUESQL_SCHEMA_DIR").unwrap_or_else(|_| SCHEMA_DIR.to_owned());
+    let schema_dir: PathBuf = Path::new(&manifest_dir).join(schema_dir);
+    let files = schema::read_dir(&schema_dir).map_err(error)?;
+
+    expand(input, &files)
+}
+
+fn expand(input: &GlueQueryInput, files: &[SchemaFile]) -> Result<TokenStream, syn::Error> {
+    let GlueQueryInput { sql, args } = input;
+    let error = |message: String| syn::Error::new(sql.span(), message);
+
+    let tables = schema::tables(files).map_err(error)?;
+    let statement = translate(sql, args)?;
+    let checker = Checker::new(&tables, args.len());
+    let output = checker.statement(&statement).map_err(error)?;
+
+    let gluesql_crate = resolve_gluesql_crate()?;
+    let paths = files.iter().map(|file| &file.path);
+    let params = args.iter().zip(checker.params()).map(|(arg, data_type)| {
+        match data_type.as_ref().and_then(sql_type) {
+            Some(sql_type) => quote_spanned! {arg.span()=>
+                #gluesql_crate::translate::typed_param::<
+                    #gluesql_crate::translate::sql_type::#sql_type,
+                    _,
+                >(#arg)
+            },
+            None => {
+                quote! { #gluesql_crate::translate::IntoParamLiteral::into_param_literal(#arg) }
+            }
+        }
+    });
+
+    let (row_struct, row) = match output {
+        Some(Some(columns)) => (row_struct(sql, &columns)?, quote! { Row }),
+        Some(None) => {
+            return Err(error(
+                "the columns of this query depend on schemaless data; select them by name"
+                    .to_owned(),
+            ));
+        }
+        None => (TokenStream::new(), quote! { () }),
+    };
+
+    Ok(quote! {
+        {
+            #(const _: &str = include_str!(#paths);)*
+            #row_struct
+            #gluesql_crate::row_conversion::TypedQuery::<#row>::new(#sql, vec![#(#params),*])
+        }
+    })
+}
+
+/// Translates the single statement in `sql`, checking that the arguments
+/// match the placeholders it uses, which are kept in the statement for the
+/// checker to type.
+fn translate(sql: &LitStr, args: &[Expr]) -> Result<StatementPlan, syn::Error> {
+    let error = |message: String| syn::Error::new(sql.span(), message);
+
+    let mut statements = parse_statements(sql.value()).map_err(|e| error(e.to_string()))?;
+    if statements.len() != 1 {
+        return Err(error(format!(
+            "glue_query! expects one statement, but got {}",
+            statements.len()
+        )));
+    }
+    let parsed = statements.remove(0);
+
+    let params = (1..=args.len())
+        .map(ParamLiteral::placeholder)
+        .collect::<Vec<_>>();
+    let statement = translate_parsed_with_params(&parsed, &params).map_err(|e| match e {
+        Error::Translate(TranslateError::ParameterIndexOutOfRange { index, len }) => error(
+            format!("`${index}` has no argument; glue_query! got {len} arguments"),
+        ),
+        e => error(e.to_string()),
+    })?;
+
+    if let Some(last) = args.last()
+        && translate_parsed_with_params(&parsed, &params[..args.len() - 1]).is_ok()
+    {
+        return Err(syn::Error::new_spanned(
+            last,
+            format!("argument is not used; the SQL has no `${}`", args.len()),
+        ));
+    }
+
+    Ok(statement.into())
+}
+
+fn row_struct(sql: &LitStr, columns: &[Column]) -> Result<TokenStream, syn::Error> {
+    let error = |message: String| syn::Error::new(sql.span(), message);
+    let gluesql_crate = resolve_gluesql_crate()?;
+
+    let mut fields = Vec::<(syn::Ident, TokenStream)>::new();
+    let mut derive_fields = Vec::new();
+    for Column { name, ty } in columns {
+        let ident = field_ident(name).map_err(error)?;
+        if fields.iter().any(|(field, _)| field == &ident) {
+            return Err(error(format!(
+                "column `{name}` appears twice as field `{ident}`; rename one with AS"
+            )));
+        }
+
+        let Some(data_type) = &ty.data_type else {
+            return Err(error(format!(
+                "cannot infer the type of column `{name}`; wrap it in CAST(... AS <type>)"
+            )));
+        };
+        let rust_type = rust_type(&gluesql_crate, data_type);
+        let rust_type = if ty.nullable {
+            quote! { Option<#rust_type> }
+        } else {
+            rust_type
+        };
+
+        derive_fields.push(quote! { #[glue(rename = #name)] #ident: #rust_type });
+        fields.push((ident, rust_type));
+    }
+
+    let from_glue_row = expand_from_glue_row(syn::parse_quote! {
+        struct Row { #(#derive_fields),* }
+    })?;
+    let fields = fields.iter().map(|(ident, ty)| quote! { #ident: #ty });
+
+    Ok(quote! {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Row { #(#fields),* }
+
+        #from_glue_row
+    })
+}
+
+/// Converts a column label such as `userId` into the field name `user_id`.
+fn field_ident(label: &str) -> Result<syn::Ident, String> {
+    let valid = label.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(format!(
+            "column `{label}` is not a valid field name; rename it with AS"
+        ));
+    }
+
+    let chars = label.chars().collect::<Vec<_>>();
+    let mut name = String::with_capacity(label.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(char::is_ascii_lowercase);
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_lower)
+            {
+                name.push('_');
+            }
+        }
+        name.push(c.to_ascii_lowercase());
+    }
+
+    match syn::parse_str::<syn::Ident>(&name) {
+        Ok(ident) => Ok(ident),
+        Err(_) if !matches!(name.as_str(), "_" | "crate" | "self" | "super") => {
+            Ok(syn::Ident::new_raw(&name, Span::call_site()))
+        }
+        Err(_) => Err(format!(
+            "column `{label}` is not a valid field name; rename it with AS"
+        )),
+    }
+}
+
+/// Marker in `gluesql_core::translate::sql_type` the argument of a parameter
+/// used as `data_type` is checked against, if it is checked.
+fn sql_type(data_type: &DataType) -> Option<syn::Ident> {
+    let name = match data_type {
+        DataType::Boolean => "Boolean",
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int
+        | DataType::Int128
+        | DataType::Uint8
+        | DataType::Uint16
+        | DataType::Uint32
+        | DataType::Uint64
+        | DataType::Uint128 => "Integer",
+        DataType::Float32 | DataType::Float => "Float",
+        DataType::Decimal => "Decimal",
+        DataType::Text => "Text",
+        DataType::Bytea => "Bytea",
+        DataType::Inet => "Inet",
+        DataType::Date => "Date",
+        DataType::Time => "Time",
+        DataType::Timestamp => "Timestamp",
+        DataType::Interval => "Interval",
+        DataType::Uuid => "Uuid",
+        DataType::Point => "Point",
+        DataType::Map
+        | DataType::List
+        | DataType::LineString
+        | DataType::Polygon
+        | DataType::MultiPoint => return None,
+    };
+
+    Some(syn::Ident::new(name, Span::call_site()))
+}
+
+fn rust_type(gluesql_crate: &syn::Path, data_type: &DataType) -> TokenStream {
+    match data_type {
+        DataType::Boolean => quote! { bool },
+        DataType::Int8 => quote! { i8 },
+        DataType::Int16 => quote! { i16 },
+        DataType::Int32 => quote! { i32 },
+        DataType::Int => quote! { i64 },
+        DataType::Int128 => quote! { i128 },
+        DataType::Uint8 => quote! { u8 },
+        DataType::Uint16 => quote! { u16 },
+        DataType::Uint32 => quote! { u32 },
+        DataType::Uint64 => quote! { u64 },
+        DataType::Uint128 | DataType::Uuid => quote! { u128 },
+        DataType::Float32 => quote! { f32 },
+        DataType::Float => quote! { f64 },
+        DataType::Text => quote! { String },
+        DataType::Bytea => quote! { ::std::vec::Vec<u8> },
+        DataType::Inet => quote! { ::std::net::IpAddr },
+        DataType::Date => quote! { #gluesql_crate::chrono::NaiveDate },
+        DataType::Timestamp => quote! { #gluesql_crate::chrono::NaiveDateTime },
+        DataType::Time => quote! { #gluesql_crate::chrono::NaiveTime },
+        DataType::Interval => quote! { #gluesql_crate::data::Interval },
+        DataType::Map => {
+            quote! { ::std::collections::BTreeMap<String, #gluesql_crate::data::Value> }
+        }
+        DataType::List => quote! { ::std::vec::Vec<#gluesql_crate::data::Value> },
+        DataType::Decimal => quote! { #gluesql_crate::rust_decimal::Decimal },
+        DataType::Point => quote! { #gluesql_crate::data::Point },
+        DataType::LineString => quote! { #gluesql_crate::data::LineString },
+        DataType::Polygon => quote! { #gluesql_crate::data::Polygon },
+        DataType::MultiPoint => quote! { #gluesql_crate::data::MultiPoint },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{GlueQueryInput, SchemaFile, expand, field_ident},
+        proc_macro2::TokenStream,
+        quote::quote,
+    };
+
+    const SCHEMA: &str = "
+        CREATE TABLE Customer (id INTEGER PRIMARY KEY, name TEXT NOT NULL, email TEXT);
+        CREATE TABLE Item (id INTEGER NOT NULL, customer_id INTEGER NOT NULL, price DECIMAL);
+        CREATE INDEX idx_item ON Item (customer_id);
+        CREATE TABLE Event;
+    ";
+
+    fn test(schema: &str, input: TokenStream) -> Result<String, String> {
+        let input = syn::parse2::<GlueQueryInput>(input).unwrap();
+        let files = [SchemaFile {
+            path: "shop.sql".to_owned(),
+            sql: schema.to_owned(),
+        }];
+
+        expand(&input, &files)
+            .map(|tokens| tokens.to_string())
+            .map_err(|error| error.to_string())
+    }
+
+    fn error(input: TokenStream) -> String {
+        test(SCHEMA, input).unwrap_err()
+    }
+
+    #[test]
+    fn row_struct_fields() {
+        let expanded = test(
+            SCHEMA,
+            quote! {
+                "SELECT c.id, c.name, c.email, i.price AS itemPrice, COUNT(*) AS count
+                FROM Customer c LEFT JOIN Item i ON i.customer_id = c.id
+                WHERE c.id = $1 GROUP BY c.id, c.name, c.email, i.price", id
+            },
+        )
+        .unwrap();
+
+        for field in [
+            "id : i64",
+            "name : String",
+            "email : Option < String >",
+            "item_price : Option < :: gluesql_core :: rust_decimal :: Decimal >",
+            "count : i64",
+            "include_str ! (\"shop.sql\")",
+            "TypedQuery :: < Row >",
+            "typed_param :: < :: gluesql_core :: translate :: sql_type :: Integer , _ , > (id)",
+        ] {
+            assert!(expanded.contains(field), "{field} in {expanded}");
+        }
+
+        let expanded = test(SCHEMA, quote! { "DELETE FROM Item WHERE id = $1", 1 }).unwrap();
+        assert!(expanded.contains("TypedQuery :: < () >"), "{expanded}");
+    }
+
+    #[test]
+    fn unknown_names() {
+        assert_eq!(
+            error(quote! { "SELECT id FROM Nothing" }),
+            "table `Nothing` is not declared in the schema"
+        );
+        assert_eq!(
+            error(quote! { "SELECT age FROM Customer" }),
+            "column `age` does not exist"
+        );
+        assert_eq!(
+            error(quote! { "SELECT c.age FROM Customer c" }),
+            "column `c.age` does not exist"
+        );
+        assert_eq!(
+            error(quote! { "SELECT Item.id FROM Customer" }),
+            "table or alias `Item` is not in scope"
+        );
+        assert_eq!(
+            error(
+                quote! { "SELECT name FROM Customer WHERE EXISTS(SELECT 1 FROM Item WHERE total > 0)" }
+            ),
+            "column `total` does not exist"
+        );
+        assert_eq!(
+            error(quote! { "SELECT c.id FROM Customer c JOIN Item i ON id = i.customer_id" }),
+            "column reference `id` is ambiguous"
+        );
+        assert_eq!(
+            error(quote! { "UPDATE Customer SET age = 1" }),
+            "column `age` does not exist"
+        );
+        assert_eq!(
+            error(quote! { "INSERT INTO Item (id, total) VALUES (1, 2)" }),
+            "column `total` does not exist in table `Item`"
+        );
+    }
+
+    #[test]
+    fn correlated_and_derived_names() {
+        assert!(
+            test(
+                SCHEMA,
+                quote! {
+                    "SELECT s.name, s.n FROM (
+                        SELECT name, (SELECT COUNT(*) FROM Item WHERE customer_id = Customer.id) AS n
+                        FROM Customer
+                    ) AS s"
+                }
+            )
+            .is_ok()
+        );
+        assert_eq!(
+            error(quote! { "SELECT s.email FROM (SELECT name FROM Customer) AS s" }),
+            "column `s.email` does not exist"
+        );
+        assert!(test(SCHEMA, quote! { "SELECT id FROM Event" }).is_err());
+        assert!(
+            test(
+                SCHEMA,
+                quote! { "SELECT CAST(id AS INTEGER) AS id FROM Event WHERE anything = 1" }
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn arguments() {
+        assert_eq!(
+            error(quote! { "SELECT name FROM Customer WHERE id = $2", 1 }),
+            "`$2` has no argument; glue_query! got 1 arguments"
+        );
+        assert_eq!(
+            error(quote! { "SELECT name FROM Customer WHERE id = $1", 1, 2 }),
+            "argument is not used; the SQL has no `$2`"
+        );
+    }
+
+    #[test]
+    fn argument_types() {
+        let sql_types = |input| {
+            let expanded = test(SCHEMA, input).unwrap();
+
+            expanded
+                .split("typed_param")
+                .skip(1)
+                .map(|param| {
+                    let (sql_type, _) = param.split_once(" , _").unwrap();
+
+                    sql_type.rsplit(":: ").next().unwrap().to_owned()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            sql_types(quote! {
+                "SELECT name FROM Customer WHERE $1 = id AND name LIKE $2 AND email IN ($3)
+                LIMIT $4 OFFSET $5", 1, "a", "b", 2, 3
+            }),
+            ["Integer", "Text", "Text", "Integer", "Integer"]
+        );
+        assert_eq!(
+            sql_types(quote! {
+                "UPDATE Item SET price = $1 WHERE id BETWEEN $2 AND $3", 1, 2, 3
+            }),
+            ["Decimal", "Integer", "Integer"]
+        );
+        assert_eq!(
+            sql_types(quote! {
+                "INSERT INTO Item (price, id) VALUES ($1, $2)", 1, 2
+            }),
+            ["Decimal", "Integer"]
+        );
+
+        let expanded = test(
+            SCHEMA,
+            quote! { "SELECT CAST($1 AS INTEGER) AS n FROM Item", "1" },
+        )
+        .unwrap();
+        assert!(
+            expanded.contains("into_param_literal (\"1\")"),
+            "{expanded}"
+        );
+    }
+
+    #[test]
+    fn statements() {
+        assert_eq!(
+            error(quote! { "SELECT 1 AS a; SELECT 2 AS b" }),
+            "glue_query! expects one statement, but got 2"
+        );
+        assert_eq!(
+            error(quote! { "DROP TABLE Item" }),
+            "glue_query! supports SELECT, INSERT, UPDATE and DELETE statements"
+        );
+        assert_eq!(
+            error(quote! { "SELECT id, name FROM Customer UNION SELECT id FROM Item" }),
+            "UNION queries must return the same number of columns, but got 2 and 1"
+        );
+        assert_eq!(
+            error(quote! { "SELECT name FROM Customer WHERE id IN (SELECT id, price FROM Item)" }),
+            "IN subquery must return one column, but returns 2"
+        );
+        assert_eq!(
+            error(quote! { "INSERT INTO Item (id, customer_id) VALUES (1, 2, 3)" }),
+            "INSERT lists 2 columns, but the source returns 3"
+        );
+        assert_eq!(
+            error(quote! { "INSERT INTO Customer VALUES (1, 'a', NULL, 4)" }),
+            "INSERT into `Customer` has 3 columns, but the source returns 4"
+        );
+    }
+
+    #[test]
+    fn row_fields() {
+        assert_eq!(
+            error(quote! { "SELECT id + 1 AS next FROM Customer" }),
+            "cannot infer the type of column `next`; wrap it in CAST(... AS <type>)"
+        );
+        assert_eq!(
+            error(quote! { "SELECT COUNT(*) FROM Customer" }),
+            "column `COUNT(*)` is not a valid field name; rename it with AS"
+        );
+        assert_eq!(
+            error(
+                quote! { "SELECT c.id, i.id FROM Customer c JOIN Item i ON c.id = i.customer_id" }
+            ),
+            "column `id` appears twice as field `id`; rename one with AS"
+        );
+        assert_eq!(
+            error(quote! { "SELECT * FROM Event" }),
+            "the columns of this query depend on schemaless data; select them by name"
+        );
+    }
+
+    #[test]
+    fn schema_files() {
+        assert_eq!(
+            test(
+                "CREATE TABLE A (id INTEGER); DROP TABLE A;",
+                quote! { "SELECT 1 AS a" }
+            )
+            .unwrap_err(),
+            "shop.sql: schema files may only contain CREATE TABLE and CREATE INDEX statements"
+        );
+        assert_eq!(
+            test(
+                "CREATE TABLE A; CREATE TABLE A;",
+                quote! { "SELECT 1 AS a" }
+            )
+            .unwrap_err(),
+            "shop.sql: table `A` is declared twice"
+        );
+    }
+
+    #[test]
+    fn field_names() {
+        let name = |label| field_ident(label).map(|ident| ident.to_string());
+
+        assert_eq!(name("id"), Ok("id".to_owned()));
+        assert_eq!(name("userId"), Ok("user_id".to_owned()));
+        assert_eq!(name("HTTPStatus"), Ok("http_status".to_owned()));
+        assert_eq!(name("Price2Tax"), Ok("price2_tax".to_owned()));
+        assert_eq!(name("type"), Ok("r#type".to_owned()));
+        assert!(name("self").is_err());
+        assert!(name("max price").is_err());
+    }
+}
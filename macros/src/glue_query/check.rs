@@ -0,0 +1,926 @@
+use {
+    super::schema::Tables,
+    gluesql_core::{
+        ast::{BinaryOperator, DataType, Literal, UnaryOperator},
+        plan::{
+            AggregateFunctionPlan, AggregationInputPlan, AggregationPlan, AssignmentPlan,
+            DistinctInputPlan, ExprPlan, FilterInputPlan, FilterPlan, FunctionExprPlan,
+            HashJoinInputPlan, HashJoinPlan, InnerJoinInputPlan, InnerJoinPlan,
+            JoinConditionInputPlan, JoinConditionPlan, LeftOuterJoinInputPlan, LeftOuterJoinPlan,
+            LimitInputPlan, NestedLoopJoinInputPlan, NestedLoopJoinPlan, OffsetInputPlan,
+            OrderByExprPlan, ProjectInputPlan, ProjectPlan, ProjectionPlan, QueryPlan,
+            SelectItemPlan, SourcePlan, StatementPlan, TableAliasPlan, UnionPlan, ValuesPlan,
+        },
+        translate::ParamLiteral,
+    },
+    std::cell::RefCell,
+};
+
+type Result<T> = std::result::Result<T, String>;
+
+/// Type of a value as far as the declared schema tells, with `data_type` left
+/// unknown when it depends on data or on functions the checker does not model.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Ty {
+    pub data_type: Option<DataType>,
+    pub nullable: bool,
+}
+
+impl Ty {
+    fn new(data_type: DataType, nullable: bool) -> Self {
+        Self {
+            data_type: Some(data_type),
+            nullable,
+        }
+    }
+
+    fn unknown(nullable: bool) -> Self {
+        Self {
+            data_type: None,
+            nullable,
+        }
+    }
+
+    fn unify(self, other: &Self) -> Self {
+        Self {
+            data_type: self
+                .data_type
+                .filter(|ty| other.data_type.as_ref() == Some(ty)),
+            nullable: self.nullable || other.nullable,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct Column {
+    pub name: String,
+    pub ty: Ty,
+}
+
+/// Columns a query returns, or `None` when they depend on schemaless data.
+pub(super) type Output = Option<Vec<Column>>;
+
+struct Relation {
+    alias: String,
+    columns: Output,
+    nullable: bool,
+}
+
+impl Relation {
+    fn ty(&self, column: &Column) -> Ty {
+        Ty {
+            nullable: column.ty.nullable || self.nullable,
+            ..column.ty.clone()
+        }
+    }
+}
+
+#[derive(Default)]
+struct Scope<'a> {
+    relations: Vec<Relation>,
+    labels: Vec<Column>,
+    outer: Option<&'a Scope<'a>>,
+}
+
+impl<'a> Scope<'a> {
+    fn new(outer: Option<&'a Scope<'a>>) -> Self {
+        Self {
+            relations: Vec::new(),
+            labels: Vec::new(),
+            outer,
+        }
+    }
+
+    fn column(&self, alias: Option<&str>, name: &str) -> Result<Ty> {
+        if let Some(ty) = self.find(alias, name)? {
+            return Ok(ty);
+        }
+
+        match (self.outer, alias) {
+            (Some(outer), _) => outer.column(alias, name),
+            (None, Some(alias)) => Err(format!("table or alias `{alias}` is not in scope")),
+            (None, None) => Err(format!("column `{name}` does not exist")),
+        }
+    }
+
+    fn find(&self, alias: Option<&str>, name: &str) -> Result<Option<Ty>> {
+        if let Some(alias) = alias {
+            let Some(relation) = self.relations.iter().find(|r| r.alias == alias) else {
+                return Ok(None);
+            };
+
+            return match &relation.columns {
+                Some(columns) => columns
+                    .iter()
+                    .find(|column| column.name == name)
+                    .map(|column| Some(relation.ty(column)))
+                    .ok_or_else(|| format!("column `{alias}.{name}` does not exist")),
+                None => Ok(Some(Ty::unknown(true))),
+            };
+        }
+
+        if let Some(label) = self.labels.iter().find(|label| label.name == name) {
+            return Ok(Some(label.ty.clone()));
+        }
+
+        let mut found = self.relations.iter().filter_map(|relation| {
+            relation
+                .columns
+                .as_ref()?
+                .iter()
+                .find(|column| column.name == name)
+                .map(|column| relation.ty(column))
+        });
+
+        match (found.next(), found.next()) {
+            (Some(_), Some(_)) => Err(format!("column reference `{name}` is ambiguous")),
+            (Some(ty), None) => Ok(Some(ty)),
+            (None, _) if self.relations.iter().any(|r| r.columns.is_none()) => {
+                Ok(Some(Ty::unknown(true)))
+            }
+            (None, _) => Ok(None),
+        }
+    }
+
+    fn relation(&self, alias: &str) -> Result<&Relation> {
+        self.relations
+            .iter()
+            .find(|relation| relation.alias == alias)
+            .ok_or_else(|| format!("table or alias `{alias}` is not in scope"))
+    }
+}
+
+/// Resolves every table and column a statement refers to against the declared
+/// tables, and infers the columns a query returns and the types its
+/// parameters are used as.
+pub(super) struct Checker<'a> {
+    tables: &'a Tables,
+    params: RefCell<Vec<Option<DataType>>>,
+}
+
+impl<'a> Checker<'a> {
+    pub fn new(tables: &'a Tables, param_count: usize) -> Self {
+        Self {
+            tables,
+            params: RefCell::new(vec![None; param_count]),
+        }
+    }
+
+    /// Type of the column or expression each parameter is compared with or
+    /// assigned to, if any.
+    pub fn params(self) -> Vec<Option<DataType>> {
+        self.params.into_inner()
+    }
+
+    /// Records `data_type` as the type of `expr` if it is a parameter whose
+    /// type is not known yet.
+    fn bind(&self, expr: &ExprPlan, data_type: Option<&DataType>) {
+        let index = match expr {
+            ExprPlan::Identifier(name) => ParamLiteral::placeholder_index(name),
+            ExprPlan::Nested(expr) => return self.bind(expr, data_type),
+            _ => None,
+        };
+
+        let Some(index) = index.and_then(|index| index.checked_sub(1)) else {
+            return;
+        };
+        if let Some(param @ None) = self.params.borrow_mut().get_mut(index) {
+            param.clone_from(&data_type.cloned());
+        }
+    }
+
+    /// Returns the output of a query, or `None` for a statement without rows.
+    pub fn statement(&self, statement: &StatementPlan) -> Result<Option<Output>> {
+        match statement {
+            StatementPlan::Query(query) => self.query(query, None).map(Some),
+            StatementPlan::Insert {
+                table_name,
+                columns,
+                source,
+            } => {
+                let defs = self.table(table_name)?;
+                if let Some(defs) = defs
+                    && let Some(column) = columns
+                        .iter()
+                        .find(|column| !defs.iter().any(|def| &&def.name == column))
+                {
+                    return Err(format!(
+                        "column `{column}` does not exist in table `{table_name}`"
+                    ));
+                }
+
+                if let (Some(defs), QueryPlan::Values(ValuesPlan(rows))) = (defs, source) {
+                    for row in rows {
+                        for (i, expr) in row.iter().enumerate() {
+                            let def = match columns.get(i) {
+                                Some(column) => defs.iter().find(|def| &def.name == column),
+                                None if columns.is_empty() => defs.get(i),
+                                None => None,
+                            };
+                            self.bind(expr, def.map(|def| &def.data_type));
+                        }
+                    }
+                }
+
+                let Some(output) = self.query(source, None)? else {
+                    return Ok(None);
+                };
+                if !columns.is_empty() && output.len() != columns.len() {
+                    return Err(format!(
+                        "INSERT lists {} columns, but the source returns {}",
+                        columns.len(),
+                        output.len()
+                    ));
+                }
+                if let Some(defs) = defs
+                    && output.len() > defs.len()
+                {
+                    return Err(format!(
+                        "INSERT into `{table_name}` has {} columns, but the source returns {}",
+                        defs.len(),
+                        output.len()
+                    ));
+                }
+
+                Ok(None)
+            }
+            StatementPlan::Update {
+                table_name,
+                assignments,
+                selection,
+            } => {
+                let scope = self.table_scope(table_name)?;
+                for AssignmentPlan { id, value } in assignments {
+                    let ty = scope.column(None, id)?;
+                    self.bind(value, ty.data_type.as_ref());
+                    self.expr(value, &scope)?;
+                }
+                if let Some(selection) = selection {
+                    self.expr(selection, &scope)?;
+                }
+
+                Ok(None)
+            }
+            StatementPlan::Delete {
+                table_name,
+                selection,
+            } => {
+                let scope = self.table_scope(table_name)?;
+                if let Some(selection) = selection {
+                    self.expr(selection, &scope)?;
+                }
+
+                Ok(None)
+            }
+            _ => {
+                Err("glue_query! supports SELECT, INSERT, UPDATE and DELETE statements".to_owned())
+            }
+        }
+    }
+
+    fn table(&self, name: &str) -> Result<Option<&'a Vec<gluesql_core::ast::ColumnDef>>> {
+        self.tables
+            .get(name)
+            .map(Option::as_ref)
+            .ok_or_else(|| format!("table `{name}` is not declared in the schema"))
+    }
+
+    fn table_columns(&self, name: &str) -> Result<Output> {
+        let columns = self.table(name)?.map(|defs| {
+            defs.iter()
+                .map(|def| Column {
+                    name: def.name.clone(),
+                    ty: Ty::new(def.data_type.clone(), def.nullable),
+                })
+                .collect()
+        });
+
+        Ok(columns)
+    }
+
+    fn table_scope(&self, name: &str) -> Result<Scope<'static>> {
+        let mut scope = Scope::default();
+        scope.relations.push(Relation {
+            alias: name.to_owned(),
+            columns: self.table_columns(name)?,
+            nullable: false,
+        });
+
+        Ok(scope)
+    }
+
+    fn query(&self, query: &QueryPlan, outer: Option<&Scope>) -> Result<Output> {
+        match query {
+            QueryPlan::Project(project) => self.project(project, &[], outer),
+            QueryPlan::SelectOrderBy(order_by) => {
+                self.project(&order_by.input, &order_by.exprs, outer)
+            }
+            QueryPlan::Values(values) => self.values(values, &[], outer),
+            QueryPlan::ValuesOrderBy(order_by) => {
+                self.values(&order_by.input, &order_by.exprs, outer)
+            }
+            QueryPlan::Distinct(distinct) => self.distinct(&distinct.input, outer),
+            QueryPlan::Offset(offset) => {
+                self.count(&offset.count, outer)?;
+                self.offset_input(&offset.input, outer)
+            }
+            QueryPlan::Limit(limit) => {
+                self.count(&limit.count, outer)?;
+                self.limit_input(&limit.input, outer)
+            }
+            QueryPlan::Union(union) => self.union(union, outer),
+        }
+    }
+
+    /// `LIMIT` and `OFFSET` counts, which take integers.
+    fn count(&self, count: &ExprPlan, outer: Option<&Scope>) -> Result<()> {
+        self.bind(count, Some(&DataType::Int));
+        self.expr(count, &Scope::new(outer)).map(|_| ())
+    }
+
+    fn limit_input(&self, input: &LimitInputPlan, outer: Option<&Scope>) -> Result<Output> {
+        match input {
+            LimitInputPlan::Project(project) => self.project(project, &[], outer),
+            LimitInputPlan::SelectOrderBy(order_by) => {
+                self.project(&order_by.input, &order_by.exprs, outer)
+            }
+            LimitInputPlan::Values(values) => self.values(values, &[], outer),
+            LimitInputPlan::ValuesOrderBy(order_by) => {
+                self.values(&order_by.input, &order_by.exprs, outer)
+            }
+            LimitInputPlan::Distinct(distinct) => self.distinct(&distinct.input, outer),
+            LimitInputPlan::Offset(offset) => {
+                self.count(&offset.count, outer)?;
+                self.offset_input(&offset.input, outer)
+            }
+        }
+    }
+
+    fn offset_input(&self, input: &OffsetInputPlan, outer: Option<&Scope>) -> Result<Output> {
+        match input {
+            OffsetInputPlan::Project(project) => self.project(project, &[], outer),
+            OffsetInputPlan::SelectOrderBy(order_by) => {
+                self.project(&order_by.input, &order_by.exprs, outer)
+            }
+            OffsetInputPlan::Values(values) => self.values(values, &[], outer),
+            OffsetInputPlan::ValuesOrderBy(order_by) => {
+                self.values(&order_by.input, &order_by.exprs, outer)
+            }
+            OffsetInputPlan::Distinct(distinct) => self.distinct(&distinct.input, outer),
+        }
+    }
+
+    fn distinct(&self, input: &DistinctInputPlan, outer: Option<&Scope>) -> Result<Output> {
+        match input {
+            DistinctInputPlan::Project(project) => self.project(project, &[], outer),
+            DistinctInputPlan::SelectOrderBy(order_by) => {
+                self.project(&order_by.input, &order_by.exprs, outer)
+            }
+        }
+    }
+
+    fn union(&self, union: &UnionPlan, outer: Option<&Scope>) -> Result<Output> {
+        let left = self.query(&union.left, outer)?;
+        let right = self.query(&union.right, outer)?;
+
+        let output = match (left, right) {
+            (Some(left), Some(right)) if left.len() != right.len() => {
+                return Err(format!(
+                    "UNION queries must return the same number of columns, but got {} and {}",
+                    left.len(),
+                    right.len()
+                ));
+            }
+            (Some(left), Some(right)) => Some(
+                left.into_iter()
+                    .zip(right)
+                    .map(|(left, right)| Column {
+                        name: left.name,
+                        ty: left.ty.unify(&right.ty),
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            _ => None,
+        };
+
+        let scope = Scope {
+            labels: output.clone().unwrap_or_default(),
+            ..Scope::new(outer)
+        };
+        self.order_by(&union.order_by, &scope)?;
+        for count in union.offset.iter().chain(&union.limit) {
+            self.count(count, outer)?;
+        }
+
+        Ok(output)
+    }
+
+    fn values(
+        &self,
+        values: &ValuesPlan,
+        order_by: &[OrderByExprPlan],
+        outer: Option<&Scope>,
+    ) -> Result<Output> {
+        let scope = Scope::new(outer);
+        let mut output: Option<Vec<Column>> = None;
+
+        for row in &values.0 {
+            let row = row
+                .iter()
+                .enumerate()
+                .map(|(i, expr)| {
+                    self.expr(expr, &scope).map(|ty| Column {
+                        name: format!("column{}", i + 1),
+                        ty,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            output = Some(match output {
+                Some(columns) if columns.len() != row.len() => {
+                    return Err("VALUES rows must have the same number of columns".to_owned());
+                }
+                Some(columns) => columns
+                    .into_iter()
+                    .zip(row)
+                    .map(|(column, next)| Column {
+                        ty: column.ty.unify(&next.ty),
+                        ..column
+                    })
+                    .collect(),
+                None => row,
+            });
+        }
+
+        let scope = Scope {
+            labels: output.clone().unwrap_or_default(),
+            ..scope
+        };
+        self.order_by(order_by, &scope)?;
+
+        Ok(output)
+    }
+
+    fn project(
+        &self,
+        project: &ProjectPlan,
+        order_by: &[OrderByExprPlan],
+        outer: Option<&Scope>,
+    ) -> Result<Output> {
+        let mut scope = Scope::new(outer);
+        self.project_input(&project.input, &mut scope)?;
+
+        let output = match &project.projection {
+            ProjectionPlan::SelectItems(items) => {
+                let mut columns = Vec::new();
+                for item in items {
+                    match item {
+                        SelectItemPlan::Expr { expr, label } => columns.push(Column {
+                            name: label.clone(),
+                            ty: self.expr(expr, &scope)?,
+                        }),
+                        SelectItemPlan::QualifiedWildcard(alias) => {
+                            let relation = scope.relation(alias)?;
+                            let Some(relation_columns) = &relation.columns else {
+                                return Ok(None);
+                            };
+
+                            columns.extend(relation_columns.iter().map(|column| Column {
+                                name: column.name.clone(),
+                                ty: relation.ty(column),
+                            }));
+                        }
+                        SelectItemPlan::Wildcard => {
+                            for relation in &scope.relations {
+                                let Some(relation_columns) = &relation.columns else {
+                                    return Ok(None);
+                                };
+
+                                columns.extend(relation_columns.iter().map(|column| Column {
+                                    name: column.name.clone(),
+                                    ty: relation.ty(column),
+                                }));
+                            }
+                        }
+                    }
+                }
+
+                Some(columns)
+            }
+            ProjectionPlan::SchemalessMap => None,
+        };
+
+        scope.labels = output.clone().unwrap_or_default();
+        self.order_by(order_by, &scope)?;
+
+        Ok(output)
+    }
+
+    fn order_by(&self, order_by: &[OrderByExprPlan], scope: &Scope) -> Result<()> {
+        for OrderByExprPlan { expr, .. } in order_by {
+            self.expr(expr, scope)?;
+        }
+
+        Ok(())
+    }
+
+    fn project_input(&self, input: &ProjectInputPlan, scope: &mut Scope) -> Result<()> {
+        match input {
+            ProjectInputPlan::Source(source) => self.source(source, false, scope),
+            ProjectInputPlan::InnerJoin(join) => self.inner_join(join, scope),
+            ProjectInputPlan::LeftOuterJoin(join) => self.left_join(join, scope),
+            ProjectInputPlan::Filter(filter) => self.filter(filter, scope),
+            ProjectInputPlan::Aggregation(aggregation) => self.aggregation(aggregation, scope),
+            ProjectInputPlan::Having(having) => {
+                self.aggregation(&having.input, scope)?;
+                self.expr(&having.expr, scope).map(|_| ())
+            }
+        }
+    }
+
+    fn aggregation(&self, aggregation: &AggregationPlan, scope: &mut Scope) -> Result<()> {
+        match &aggregation.input {
+            AggregationInputPlan::Source(source) => self.source(source, false, scope)?,
+            AggregationInputPlan::InnerJoin(join) => self.inner_join(join, scope)?,
+            AggregationInputPlan::LeftOuterJoin(join) => self.left_join(join, scope)?,
+            AggregationInputPlan::Filter(filter) => self.filter(filter, scope)?,
+        }
+
+        for expr in &aggregation.group_by {
+            self.expr(expr, scope)?;
+        }
+
+        Ok(())
+    }
+
+    fn filter(&self, filter: &FilterPlan, scope: &mut Scope) -> Result<()> {
+        match &filter.input {
+            FilterInputPlan::Source(source) => self.source(source, false, scope)?,
+            FilterInputPlan::InnerJoin(join) => self.inner_join(join, scope)?,
+            FilterInputPlan::LeftOuterJoin(join) => self.left_join(join, scope)?,
+        }
+
+        self.expr(&filter.expr, scope).map(|_| ())
+    }
+
+    fn inner_join(&self, join: &InnerJoinPlan, scope: &mut Scope) -> Result<()> {
+        match &join.input {
+            InnerJoinInputPlan::NestedLoop(join) => self.nested_loop(join, false, scope),
+            InnerJoinInputPlan::Hash(join) => self.hash_join(join, false, scope),
+            InnerJoinInputPlan::Condition(join) => self.join_condition(join, false, scope),
+        }
+    }
+
+    fn left_join(&self, join: &LeftOuterJoinPlan, scope: &mut Scope) -> Result<()> {
+        match &join.input {
+            LeftOuterJoinInputPlan::NestedLoop(join) => self.nested_loop(join, true, scope),
+            LeftOuterJoinInputPlan::Hash(join) => self.hash_join(join, true, scope),
+            LeftOuterJoinInputPlan::Condition(join) => self.join_condition(join, true, scope),
+        }
+    }
+
+    fn join_condition(
+        &self,
+        join: &JoinConditionPlan,
+        nullable: bool,
+        scope: &mut Scope,
+    ) -> Result<()> {
+        match &join.input {
+            JoinConditionInputPlan::NestedLoop(join) => self.nested_loop(join, nullable, scope)?,
+            JoinConditionInputPlan::Hash(join) => self.hash_join(join, nullable, scope)?,
+        }
+
+        self.expr(&join.expr, scope).map(|_| ())
+    }
+
+    fn nested_loop(
+        &self,
+        join: &NestedLoopJoinPlan,
+        nullable: bool,
+        scope: &mut Scope,
+    ) -> Result<()> {
+        match &join.input {
+            NestedLoopJoinInputPlan::Source(source) => self.source(source, false, scope)?,
+            NestedLoopJoinInputPlan::InnerJoin(join) => self.inner_join(join, scope)?,
+            NestedLoopJoinInputPlan::LeftOuterJoin(join) => self.left_join(join, scope)?,
+        }
+
+        self.source(&join.right, nullable, scope)
+    }
+
+    fn hash_join(&self, join: &HashJoinPlan, nullable: bool, scope: &mut Scope) -> Result<()> {
+        match &join.input {
+            HashJoinInputPlan::Source(source) => self.source(source, false, scope)?,
+            HashJoinInputPlan::InnerJoin(join) => self.inner_join(join, scope)?,
+            HashJoinInputPlan::LeftOuterJoin(join) => self.left_join(join, scope)?,
+        }
+        self.source(&join.right, nullable, scope)?;
+
+        for expr in [&join.input_key, &join.right_key]
+            .into_iter()
+            .chain(&join.right_filter)
+        {
+            self.expr(expr, scope)?;
+        }
+
+        Ok(())
+    }
+
+    fn source(&self, source: &SourcePlan, nullable: bool, scope: &mut Scope) -> Result<()> {
+        let outer = Scope::new(scope.outer);
+        let (alias, columns) = match source {
+            SourcePlan::Table(table) => {
+                let alias = table.alias.clone().unwrap_or_else(|| TableAliasPlan {
+                    name: table.name.clone(),
+                    columns: Vec::new(),
+                });
+
+                (alias, self.table_columns(&table.name)?)
+            }
+            SourcePlan::Derived(derived) => (
+                derived.alias.clone(),
+                self.query(&derived.query, scope.outer)?,
+            ),
+            SourcePlan::Series(series) => {
+                self.expr(&series.size, &outer)?;
+                let column = Column {
+                    name: "N".to_owned(),
+                    ty: Ty::new(DataType::Int, false),
+                };
+
+                (series.alias.clone(), Some(vec![column]))
+            }
+            SourcePlan::GenerateSeries(series) => {
+                for expr in [&series.start, &series.stop]
+                    .into_iter()
+                    .chain(&series.step)
+                {
+                    self.expr(expr, &outer)?;
+                }
+                let column = Column {
+                    name: "N".to_owned(),
+                    ty: Ty::unknown(false),
+                };
+
+                (series.alias.clone(), Some(vec![column]))
+            }
+            SourcePlan::Dictionary(dictionary) => (dictionary.alias.clone(), None),
+        };
+
+        let TableAliasPlan {
+            name,
+            columns: renames,
+        } = alias;
+        let columns = match columns {
+            Some(columns) if renames.len() > columns.len() => {
+                return Err(format!(
+                    "`{name}` has {} columns, but {} column aliases were given",
+                    columns.len(),
+                    renames.len()
+                ));
+            }
+            Some(mut columns) => {
+                for (column, rename) in columns.iter_mut().zip(renames) {
+                    column.name = rename;
+                }
+
+                Some(columns)
+            }
+            None => None,
+        };
+
+        scope.relations.push(Relation {
+            alias: name,
+            columns,
+            nullable,
+        });
+
+        Ok(())
+    }
+
+    fn expr(&self, expr: &ExprPlan, scope: &Scope) -> Result<Ty> {
+        let boolean = |nullable| Ty::new(DataType::Boolean, nullable);
+
+        match expr {
+            ExprPlan::Identifier(name) if ParamLiteral::placeholder_index(name).is_some() => {
+                Ok(Ty::unknown(true))
+            }
+            ExprPlan::Identifier(name) => scope.column(None, name),
+            ExprPlan::CompoundIdentifier { alias, ident } => scope.column(Some(alias), ident),
+            ExprPlan::IsNull(expr) | ExprPlan::IsNotNull(expr) => {
+                self.expr(expr, scope)?;
+
+                Ok(boolean(false))
+            }
+            ExprPlan::InList { expr, list, .. } => {
+                let nullable = self.compare(std::iter::once(&**expr).chain(list), scope)?;
+
+                Ok(boolean(nullable))
+            }
+            ExprPlan::InSubquery { expr, subquery, .. } => {
+                let ty = self.single_column(subquery, "IN", scope)?;
+                self.bind(expr, ty.data_type.as_ref());
+                self.expr(expr, scope)?;
+
+                Ok(boolean(true))
+            }
+            ExprPlan::Between {
+                expr, low, high, ..
+            } => {
+                let nullable = self.compare([&**expr, low, high], scope)?;
+
+                Ok(boolean(nullable))
+            }
+            ExprPlan::Like { expr, pattern, .. } | ExprPlan::ILike { expr, pattern, .. } => {
+                self.bind(expr, Some(&DataType::Text));
+                self.bind(pattern, Some(&DataType::Text));
+                let nullable =
+                    self.expr(expr, scope)?.nullable | self.expr(pattern, scope)?.nullable;
+
+                Ok(boolean(nullable))
+            }
+            ExprPlan::BinaryOp { left, op, right } => {
+                let nullable = match op {
+                    BinaryOperator::And | BinaryOperator::Or | BinaryOperator::Xor => {
+                        self.bind(left, Some(&DataType::Boolean));
+                        self.bind(right, Some(&DataType::Boolean));
+
+                        self.expr(left, scope)?.nullable | self.expr(right, scope)?.nullable
+                    }
+                    BinaryOperator::StringConcat => {
+                        self.bind(left, Some(&DataType::Text));
+                        self.bind(right, Some(&DataType::Text));
+
+                        self.expr(left, scope)?.nullable | self.expr(right, scope)?.nullable
+                    }
+                    _ => self.compare([&**left, right], scope)?,
+                };
+
+                Ok(match op {
+                    BinaryOperator::Gt
+                    | BinaryOperator::Lt
+                    | BinaryOperator::GtEq
+                    | BinaryOperator::LtEq
+                    | BinaryOperator::Eq
+                    | BinaryOperator::NotEq
+                    | BinaryOperator::And
+                    | BinaryOperator::Or
+                    | BinaryOperator::Xor => boolean(nullable),
+                    _ => Ty::unknown(nullable),
+                })
+            }
+            ExprPlan::UnaryOp { op, expr } => {
+                let ty = self.expr(expr, scope)?;
+
+                Ok(match op {
+                    UnaryOperator::Not => boolean(ty.nullable),
+                    UnaryOperator::Plus | UnaryOperator::Minus => ty,
+                    UnaryOperator::Factorial | UnaryOperator::BitwiseNot => {
+                        Ty::unknown(ty.nullable)
+                    }
+                })
+            }
+            ExprPlan::Nested(expr) => self.expr(expr, scope),
+            ExprPlan::Literal(Literal::Number(number)) => Ok(if number.is_integer() {
+                Ty::new(DataType::Int, false)
+            } else {
+                Ty::new(DataType::Float, false)
+            }),
+            ExprPlan::Literal(Literal::QuotedString(_)) => Ok(Ty::new(DataType::Text, false)),
+            ExprPlan::Value(value) => Ok(Ty {
+                data_type: value.get_type(),
+                nullable: value.is_null(),
+            }),
+            ExprPlan::TypedString { data_type, .. } => Ok(Ty::new(data_type.clone(), false)),
+            ExprPlan::Function(function) => {
+                let mut nullable = false;
+                for expr in function.as_exprs() {
+                    nullable |= self.expr(expr, scope)?.nullable;
+                }
+
+                Ok(match function.as_ref() {
+                    FunctionExprPlan::Cast { data_type, .. } => {
+                        Ty::new(data_type.clone(), nullable)
+                    }
+                    _ => Ty::unknown(true),
+                })
+            }
+            ExprPlan::Aggregate(aggregate) => {
+                let mut tys = aggregate
+                    .as_exprs()
+                    .map(|expr| self.expr(expr, scope))
+                    .collect::<Result<Vec<_>>>()?;
+                if let Some(filter) = &aggregate.filter {
+                    self.expr(filter, scope)?;
+                }
+
+                Ok(match &aggregate.func {
+                    AggregateFunctionPlan::Count(_) => Ty::new(DataType::Int, false),
+                    AggregateFunctionPlan::Min(_) | AggregateFunctionPlan::Max(_) => Ty {
+                        nullable: true,
+                        ..tys.remove(0)
+                    },
+                    _ => Ty::unknown(true),
+                })
+            }
+            ExprPlan::Exists { subquery, .. } => {
+                self.query(subquery, Some(scope))?;
+
+                Ok(boolean(false))
+            }
+            ExprPlan::Subquery(subquery) => {
+                let ty = self.single_column(subquery, "scalar", scope)?;
+
+                Ok(Ty {
+                    nullable: true,
+                    ..ty
+                })
+            }
+            ExprPlan::Case {
+                operand,
+                when_then,
+                else_result,
+            } => {
+                if let Some(operand) = operand {
+                    self.expr(operand, scope)?;
+                }
+
+                let mut result: Option<Ty> = None;
+                for (when, then) in when_then {
+                    self.expr(when, scope)?;
+                    let then = self.expr(then, scope)?;
+                    result = Some(match result {
+                        Some(result) => result.unify(&then),
+                        None => then,
+                    });
+                }
+
+                let else_result = match else_result {
+                    Some(else_result) => self.expr(else_result, scope)?,
+                    None => Ty {
+                        data_type: result.as_ref().and_then(|ty| ty.data_type.clone()),
+                        nullable: true,
+                    },
+                };
+
+                Ok(match result {
+                    Some(result) => result.unify(&else_result),
+                    None => else_result,
+                })
+            }
+            ExprPlan::ArrayIndex { obj, indexes } => {
+                self.expr(obj, scope)?;
+                for index in indexes {
+                    self.expr(index, scope)?;
+                }
+
+                Ok(Ty::unknown(true))
+            }
+            ExprPlan::Interval { expr, .. } => {
+                let ty = self.expr(expr, scope)?;
+
+                Ok(Ty::new(DataType::Interval, ty.nullable))
+            }
+            ExprPlan::Array { elem } => {
+                for expr in elem {
+                    self.expr(expr, scope)?;
+                }
+
+                Ok(Ty::new(DataType::List, false))
+            }
+        }
+    }
+
+    /// Checks expressions compared with each other, typing a parameter
+    /// among them as the first of them whose type is known. Returns whether
+    /// any of them is nullable.
+    fn compare<'e>(
+        &self,
+        exprs: impl IntoIterator<Item = &'e ExprPlan>,
+        scope: &Scope,
+    ) -> Result<bool> {
+        let exprs = exprs.into_iter().collect::<Vec<_>>();
+        let tys = exprs
+            .iter()
+            .map(|expr| self.expr(expr, scope))
+            .collect::<Result<Vec<_>>>()?;
+
+        let data_type = tys.iter().find_map(|ty| ty.data_type.as_ref());
+        for expr in exprs {
+            self.bind(expr, data_type);
+        }
+
+        Ok(tys.iter().any(|ty| ty.nullable))
+    }
+
+    fn single_column(&self, query: &QueryPlan, kind: &str, scope: &Scope) -> Result<Ty> {
+        match self.query(query, Some(scope))? {
+            Some(mut columns) if columns.len() == 1 => Ok(columns.remove(0).ty),
+            Some(columns) => Err(format!(
+                "{kind} subquery must return one column, but returns {}",
+                columns.len()
+            )),
+            None => Ok(Ty::unknown(true)),
+        }
+    }
+}
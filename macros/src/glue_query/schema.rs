@@ -0,0 +1,82 @@
+use {
+    gluesql_core::{
+        ast::ColumnDef, parse_sql::parse_statements, plan::StatementPlan,
+        translate::translate_parsed_with_params,
+    },
+    std::{collections::HashMap, fs, path::Path},
+};
+
+/// Tables declared by the schema files, with `None` for schemaless tables.
+pub(super) type Tables = HashMap<String, Option<Vec<ColumnDef>>>;
+
+pub(super) struct SchemaFile {
+    pub path: String,
+    pub sql: String,
+}
+
+/// Reads the `.sql` files directly in `dir`, sorted by file name.
+pub(super) fn read_dir(dir: &Path) -> Result<Vec<SchemaFile>, String> {
+    let entries = fs::read_dir(dir).map_err(|error| {
+        format!(
+            "failed to read schema directory `{}`: {error}",
+            dir.display()
+        )
+    })?;
+
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "sql"))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let sql = fs::read_to_string(&path)
+                .map_err(|error| format!("failed to read `{}`: {error}", path.display()))?;
+
+            Ok(SchemaFile {
+                path: path.display().to_string(),
+                sql,
+            })
+        })
+        .collect()
+}
+
+/// Collects the `CREATE TABLE` statements of the schema files.
+///
+/// `CREATE INDEX` statements are skipped, and any other statement is an error
+/// so that a schema file cannot silently declare less than it seems to.
+pub(super) fn tables(files: &[SchemaFile]) -> Result<Tables, String> {
+    let mut tables = Tables::new();
+
+    for SchemaFile { path, sql } in files {
+        let statements = parse_statements(sql).map_err(|error| format!("{path}: {error}"))?;
+
+        for parsed in statements {
+            let statement = translate_parsed_with_params(&parsed, &[])
+                .map_err(|error| format!("{path}: {error}"))?;
+
+            match StatementPlan::from(statement) {
+                StatementPlan::CreateTable {
+                    name,
+                    columns,
+                    source: None,
+                    ..
+                } => {
+                    if tables.insert(name.clone(), columns).is_some() {
+                        return Err(format!("{path}: table `{name}` is declared twice"));
+                    }
+                }
+                StatementPlan::CreateIndex { .. } => {}
+                _ => {
+                    return Err(format!(
+                        "{path}: schema files may only contain CREATE TABLE and CREATE INDEX statements"
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(tables)
+}
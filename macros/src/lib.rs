@@ -8,6 +8,7 @@ use {
 };
 
 mod from_glue_row;
+mod glue_query;
 mod to_glue_row;
 
 fn resolve_gluesql_crate() -> Result<syn::Path, syn::Error> {
@@ -68,6 +69,26 @@ pub fn derive_to_glue_row(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Checks a SQL statement against the `CREATE TABLE` statements in the schema
+/// files at compile time, and returns a `TypedQuery` with the arguments bound
+/// to `$1`, `$2`, ... in order.
+///
+/// A query gets a generated `Row` struct with one field per returned column,
+/// and other statements get `TypedQuery<()>`.
+///
+/// ```ignore
+/// let users = glue_query!("SELECT id, name FROM users WHERE id = $1", id)
+///     .fetch_all(&mut glue)?;
+/// ```
+#[proc_macro]
+pub fn glue_query(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as glue_query::GlueQueryInput);
+    match glue_query::expand_glue_query(&input) {
+        Ok(ts) => TokenStream::from(ts),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 fn parse_glue_rename(attr: &Attribute) -> Option<Result<Option<String>, syn::Error>> {
     if !attr.path().is_ident("glue") {
         return None;
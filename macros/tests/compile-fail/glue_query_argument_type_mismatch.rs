@@ -0,0 +1,5 @@
+use gluesql_macros::glue_query;
+
+fn main() {
+    let _ = glue_query!("SELECT id, name FROM Customer WHERE id = $1", "abc");
+}
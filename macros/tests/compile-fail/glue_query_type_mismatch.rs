@@ -0,0 +1,12 @@
+use {
+    gluesql_core::prelude::Glue, gluesql_macros::glue_query, gluesql_memory_storage::MemoryStorage,
+};
+
+fn main() {
+    let mut glue = Glue::new(MemoryStorage::default());
+    let customer = glue_query!("SELECT id, name FROM Customer WHERE id = $1", 1)
+        .fetch_one(&mut glue)
+        .unwrap();
+
+    let _: String = customer.id;
+}
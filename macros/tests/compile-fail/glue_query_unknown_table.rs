@@ -0,0 +1,5 @@
+use gluesql_macros::glue_query;
+
+fn main() {
+    let _ = glue_query!("SELECT id FROM Order WHERE id = $1", 1);
+}
@@ -0,0 +1,6 @@
+use gluesql_macros::glue_query;
+
+fn main() {
+    let _ = glue_query!("SELECT id FROM Item WHERE id = $1 AND customer_id = $2", 1);
+    let _ = glue_query!("SELECT id FROM Item WHERE id = $1", 1, 2);
+}
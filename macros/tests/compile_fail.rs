@@ -1,5 +1,15 @@
 #[test]
 fn compile_fail() {
+    // trybuild builds each case in its own crate, so glue_query! needs the
+    // absolute path of this crate's schema files.
+    // SAFETY: no other thread of this test binary reads the environment.
+    unsafe {
+        std::env::set_var(
+            "GLUESQL_SCHEMA_DIR",
+            concat!(env!("CARGO_MANIFEST_DIR"), "/schema"),
+        );
+    }
+
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/compile-fail/*.rs");
 }
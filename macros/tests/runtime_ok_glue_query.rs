@@ -0,0 +1,183 @@
+use {
+    chrono::NaiveDate,
+    gluesql_core::{data::Value, executor::Payload, prelude::Glue},
+    gluesql_macros::glue_query,
+    gluesql_memory_storage::MemoryStorage,
+    rust_decimal::Decimal,
+};
+
+fn glue() -> Glue<MemoryStorage> {
+    let mut glue = Glue::new(MemoryStorage::default());
+    glue.execute(include_str!("../schema/shop.sql")).unwrap();
+    glue.execute(
+        "
+        INSERT INTO Customer VALUES
+            (1, 'Alice', 'alice@example.com', '2024-01-02'),
+            (2, 'Bob', NULL, '2024-03-04');
+        INSERT INTO Item VALUES (10, 1, 1.5, '[1, 2]'), (11, 1, 2.25, NULL);
+        ",
+    )
+    .unwrap();
+
+    glue
+}
+
+#[test]
+fn select_with_params() {
+    let mut glue = glue();
+
+    let customers = glue_query!(
+        "SELECT id, name, email, joinedAt FROM Customer WHERE id >= $1 ORDER BY id",
+        1
+    )
+    .fetch_all(&mut glue)
+    .unwrap();
+
+    assert_eq!(customers.len(), 2);
+    let id: i64 = customers[0].id;
+    let name: &String = &customers[0].name;
+    let email: &Option<String> = &customers[0].email;
+    let joined_at: NaiveDate = customers[0].joined_at;
+    assert_eq!(id, 1);
+    assert_eq!(name, "Alice");
+    assert_eq!(email.as_deref(), Some("alice@example.com"));
+    assert_eq!(joined_at, NaiveDate::from_ymd_opt(2024, 1, 2).unwrap());
+    assert_eq!(customers[1].email, None);
+
+    let query = glue_query!(
+        "SELECT name FROM Customer WHERE name = $1",
+        "Bob".to_owned()
+    );
+    assert_eq!(query.sql(), "SELECT name FROM Customer WHERE name = $1");
+    assert_eq!(query.params().len(), 1);
+    assert_eq!(query.fetch_one(&mut glue).unwrap().name, "Bob");
+}
+
+#[test]
+fn select_with_joins_and_aggregates() {
+    let mut glue = glue();
+
+    let rows = glue_query!(
+        "
+        SELECT c.name, i.id AS item_id, i.price, i.tags
+        FROM Customer c
+        LEFT JOIN Item i ON i.customer_id = c.id
+        ORDER BY c.id, i.id
+        "
+    )
+    .fetch_all(&mut glue)
+    .unwrap();
+
+    let item_ids: Vec<Option<i64>> = rows.iter().map(|row| row.item_id).collect();
+    let prices: Vec<Option<Decimal>> = rows.iter().map(|row| row.price).collect();
+    assert_eq!(item_ids, vec![Some(10), Some(11), None]);
+    assert_eq!(
+        prices,
+        vec![Some(Decimal::new(15, 1)), Some(Decimal::new(225, 2)), None]
+    );
+    assert_eq!(rows[0].tags, Some(vec![Value::I64(1), Value::I64(2)]));
+
+    let row = glue_query!(
+        "
+        SELECT
+            COUNT(*) AS count,
+            MAX(price) AS max_price,
+            CAST(SUM(price) AS DECIMAL) AS total,
+            EXISTS(SELECT 1 FROM Customer WHERE email IS NULL) AS has_guest
+        FROM Item
+        "
+    )
+    .fetch_one(&mut glue)
+    .unwrap();
+
+    let count: i64 = row.count;
+    let max_price: Option<Decimal> = row.max_price;
+    let has_guest: bool = row.has_guest;
+    assert_eq!(count, 2);
+    assert_eq!(max_price, Some(Decimal::new(225, 2)));
+    assert_eq!(row.total, Some(Decimal::new(375, 2)));
+    assert!(has_guest);
+}
+
+#[test]
+fn select_with_subqueries_and_union() {
+    let mut glue = glue();
+
+    let rows = glue_query!(
+        "
+        SELECT Summary.name, Summary.item_count
+        FROM (
+            SELECT name, (SELECT COUNT(*) FROM Item WHERE customer_id = Customer.id) AS item_count
+            FROM Customer
+        ) AS Summary
+        WHERE Summary.name IN (SELECT name FROM Customer WHERE email IS NOT NULL)
+        "
+    )
+    .fetch_all(&mut glue)
+    .unwrap();
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].name, "Alice");
+    assert_eq!(rows[0].item_count, Some(2));
+
+    let rows = glue_query!(
+        "
+        SELECT id, 'customer' AS kind FROM Customer
+        UNION ALL
+        SELECT id, 'item' FROM Item
+        ORDER BY id
+        LIMIT $1
+        ",
+        3
+    )
+    .fetch_all(&mut glue)
+    .unwrap();
+
+    let rows: Vec<(i64, String)> = rows.into_iter().map(|row| (row.id, row.kind)).collect();
+    assert_eq!(
+        rows,
+        vec![
+            (1, "customer".to_owned()),
+            (2, "customer".to_owned()),
+            (10, "item".to_owned()),
+        ]
+    );
+
+    let row = glue_query!("SELECT 1 AS one, 1.5 AS half, 'a' AS text, NOT TRUE AS no")
+        .fetch_one(&mut glue)
+        .unwrap();
+    assert_eq!(
+        (row.one, row.half, row.text, row.no),
+        (1, 1.5, "a".to_owned(), false)
+    );
+}
+
+#[test]
+fn statements_without_rows() {
+    let mut glue = glue();
+
+    let payloads = glue_query!(
+        "INSERT INTO Customer (id, name, joinedAt) VALUES ($1, $2, $3)",
+        3_i64,
+        "Carol",
+        NaiveDate::from_ymd_opt(2024, 5, 6).unwrap()
+    )
+    .execute(&mut glue)
+    .unwrap();
+    assert_eq!(payloads, vec![Payload::Insert(1)]);
+
+    let payloads = glue_query!("UPDATE Customer SET email = $1 WHERE id = $2", "c@x", 3)
+        .execute(&mut glue)
+        .unwrap();
+    assert_eq!(payloads, vec![Payload::Update(1)]);
+
+    let payloads = glue_query!("DELETE FROM Customer WHERE email = $1", "c@x")
+        .execute(&mut glue)
+        .unwrap();
+    assert_eq!(payloads, vec![Payload::Delete(1)]);
+
+    let payloads = glue_query!("INSERT INTO Event VALUES ('{\"kind\": \"signup\"}')")
+        .execute(&mut glue)
+        .unwrap();
+    assert_eq!(payloads, vec![Payload::Insert(1)]);
+}
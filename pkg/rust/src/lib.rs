@@ -11,8 +11,8 @@ pub mod core {
 
 pub use gluesql_core::params;
 
-// Re-export the derive macros so users can `use gluesql::{FromGlueRow, ToGlueRow}`.
-pub use gluesql_macros::{FromGlueRow, ToGlueRow};
+// Re-export the macros so users can `use gluesql::{FromGlueRow, ToGlueRow, glue_query}`.
+pub use gluesql_macros::{FromGlueRow, ToGlueRow, glue_query};
 
 #[cfg(feature = "gluesql_memory_storage")]
 pub use gluesql_memory_storage;
@@ -47,6 +47,9 @@ pub use gluesql_mongo_storage;
 #[cfg(feature = "gluesql-composite-storage")]
 pub use gluesql_composite_storage;
 
+#[cfg(feature = "gluesql-change-feed-storage")]
+pub use gluesql_change_feed_storage;
+
 #[cfg(feature = "test-suite")]
 pub use test_suite;
 
@@ -85,4 +88,7 @@ pub mod prelude {
 
     #[cfg(feature = "gluesql-composite-storage")]
     pub use gluesql_composite_storage::CompositeStorage;
+
+    #[cfg(feature = "gluesql-change-feed-storage")]
+    pub use gluesql_change_feed_storage::ChangeFeedStorage;
 }
@@ -0,0 +1,147 @@
+#![cfg(all(feature = "gluesql_sled_storage", feature = "gluesql-redb-storage"))]
+use {
+    gluesql::{
+        core::{
+            backup::{restore_backup, write_backup},
+            store::{Backup, GStore, GStoreMut, Planner},
+        },
+        gluesql_redb_storage::RedbStorage,
+        gluesql_sled_storage::SledStorage,
+        prelude::{Glue, Value::*},
+        test_suite::*,
+    },
+    std::{fs, path::PathBuf},
+};
+
+fn tmp_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("gluesql_backup_{name}"));
+    let _ = fs::remove_dir_all(&path);
+    let _ = fs::remove_file(&path);
+
+    path
+}
+
+fn fill<T: GStore + GStoreMut + Planner>(glue: &mut Glue<T>) {
+    glue.execute(
+        "
+        CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT, price INTEGER NULL);
+        CREATE INDEX idx_price ON Item (price);
+        CREATE TABLE Log (message TEXT);
+        INSERT INTO Item VALUES (1, 'apple', 300), (2, 'banana', NULL), (3, 'cherry', 100);
+        INSERT INTO Log VALUES ('created'), ('filled');
+        ",
+    )
+    .unwrap();
+}
+
+fn write_more<T: GStore + GStoreMut + Planner>(glue: &mut Glue<T>) {
+    glue.execute(
+        "
+        INSERT INTO Item VALUES (4, 'durian', 500);
+        DELETE FROM Item WHERE id = 1;
+        INSERT INTO Log VALUES ('after snapshot');
+        ",
+    )
+    .unwrap();
+}
+
+fn verify<T: GStore + GStoreMut + Planner>(glue: &mut Glue<T>) {
+    assert_eq!(
+        glue.execute("SELECT * FROM Item").unwrap().remove(0),
+        select_with_null!(
+            id     | name                     | price;
+            I64(1)   Str("apple".to_owned())    I64(300);
+            I64(2)   Str("banana".to_owned())   Null;
+            I64(3)   Str("cherry".to_owned())   I64(100)
+        )
+    );
+    assert_eq!(
+        glue.execute("SELECT id FROM Item WHERE price = 300")
+            .unwrap()
+            .remove(0),
+        select!(id I64; 1)
+    );
+    assert_eq!(
+        glue.execute("SELECT * FROM Log").unwrap().remove(0),
+        select!(
+            message
+            Str;
+            "created".to_owned();
+            "filled".to_owned()
+        )
+    );
+    let indexes = glue.storage.fetch_schema("Item").unwrap().unwrap().indexes;
+    assert_eq!(
+        indexes
+            .iter()
+            .map(|index| index.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["idx_price"]
+    );
+
+    glue.execute("INSERT INTO Item VALUES (5, 'elderberry', 200)")
+        .unwrap();
+    assert!(
+        glue.execute("INSERT INTO Item VALUES (5, 'fig', 200)")
+            .is_err(),
+        "primary key should be restored"
+    );
+}
+
+#[test]
+fn sled_to_redb() {
+    let sled_path = tmp_path("sled_to_redb_source");
+    let redb_path = tmp_path("sled_to_redb_target");
+
+    let mut source = Glue::new(SledStorage::new(sled_path.to_str().unwrap()).unwrap());
+    fill(&mut source);
+
+    let snapshot = source.storage.snapshot().unwrap();
+    write_more(&mut source);
+
+    let mut backup = Vec::new();
+    write_backup(&snapshot, &mut backup).unwrap();
+    drop(snapshot);
+
+    let mut target = RedbStorage::new(&redb_path).unwrap();
+    restore_backup(&mut target, backup.as_slice()).unwrap();
+
+    let mut target = Glue::new(target);
+    verify(&mut target);
+}
+
+#[test]
+fn redb_to_sled() {
+    let redb_path = tmp_path("redb_to_sled_source");
+    let sled_path = tmp_path("redb_to_sled_target");
+
+    let mut source = Glue::new(RedbStorage::new(&redb_path).unwrap());
+    fill(&mut source);
+
+    let snapshot = source.storage.snapshot().unwrap();
+    write_more(&mut source);
+
+    let mut backup = Vec::new();
+    write_backup(&snapshot, &mut backup).unwrap();
+    drop(snapshot);
+
+    let mut target = SledStorage::new(sled_path.to_str().unwrap()).unwrap();
+    restore_backup(&mut target, backup.as_slice()).unwrap();
+
+    let mut target = Glue::new(target);
+    verify(&mut target);
+
+    assert_eq!(
+        restore_backup(&mut target.storage, backup.as_slice())
+            .unwrap_err()
+            .to_string(),
+        "backup: table already exists in restore target: Item"
+    );
+    assert_eq!(
+        target
+            .execute("SELECT COUNT(*) FROM Log")
+            .unwrap()
+            .remove(0),
+        select!("COUNT(*)" I64; 2)
+    );
+}
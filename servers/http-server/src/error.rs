@@ -0,0 +1,45 @@
+use {gluesql_core::error::Error, thiserror::Error as ThisError};
+
+/// Error reported to the client as `{"error": message}` with its HTTP status.
+#[derive(ThisError, Debug, Clone, PartialEq, Eq)]
+#[error("{message}")]
+pub struct HttpError {
+    pub status: u16,
+    pub message: String,
+}
+
+impl HttpError {
+    fn new(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(400, message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(404, message)
+    }
+
+    pub fn method_not_allowed(method: &str, path: &str) -> Self {
+        Self::new(405, format!("method {method} is not allowed on {path}"))
+    }
+
+    pub fn unknown_session(id: &str) -> Self {
+        Self::not_found(format!("session \"{id}\" does not exist"))
+    }
+}
+
+impl From<Error> for HttpError {
+    fn from(error: Error) -> Self {
+        let status = match error {
+            Error::StorageMsg(_) => 500,
+            _ => 400,
+        };
+
+        Self::new(status, error.to_string())
+    }
+}
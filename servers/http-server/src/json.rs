@@ -0,0 +1,221 @@
+use {
+    gluesql_core::{
+        data::Value,
+        executor::{Payload, PayloadVariable},
+        translate::{IntoParamLiteral, ParamLiteral},
+    },
+    serde_json::{Map as JsonMap, Value as JsonValue, json},
+    std::io::{self, Read},
+};
+
+/// Converts a request parameter into a [`ParamLiteral`].
+///
+/// Strings, arrays and objects are bound without a type so they convert by
+/// context, e.g. `"2024-01-02"` to a `DATE` or `{"a": 1}` to a `MAP`.
+pub fn to_param(value: JsonValue) -> ParamLiteral {
+    match value {
+        JsonValue::Null => ParamLiteral::null(),
+        JsonValue::Bool(v) => v.into_param_literal(),
+        JsonValue::Number(v) => {
+            if let Some(v) = v.as_i64() {
+                v.into_param_literal()
+            } else if let Some(v) = v.as_u64() {
+                v.into_param_literal()
+            } else {
+                v.as_f64().into_param_literal()
+            }
+        }
+        JsonValue::String(v) => ParamLiteral::untyped(v),
+        v @ (JsonValue::Array(_) | JsonValue::Object(_)) => ParamLiteral::untyped(v.to_string()),
+    }
+}
+
+fn to_json(value: Value) -> JsonValue {
+    value.try_into().unwrap_or(JsonValue::Null)
+}
+
+fn to_row(labels: &[String], row: Vec<Value>) -> JsonValue {
+    labels
+        .iter()
+        .cloned()
+        .zip(row.into_iter().map(to_json))
+        .collect::<JsonMap<_, _>>()
+        .into()
+}
+
+fn to_map_row(row: impl IntoIterator<Item = (String, Value)>) -> JsonValue {
+    row.into_iter()
+        .map(|(key, value)| (key, to_json(value)))
+        .collect::<JsonMap<_, _>>()
+        .into()
+}
+
+/// Converts a payload into `{"type": ...}` with the fields of its variant.
+pub fn from_payload(payload: Payload) -> JsonValue {
+    match payload {
+        Payload::Select { labels, rows } => {
+            let rows = rows
+                .into_iter()
+                .map(|row| to_row(&labels, row))
+                .collect::<Vec<_>>();
+
+            json!({ "type": "SELECT", "labels": labels, "rows": rows })
+        }
+        Payload::SelectMap(rows) => {
+            let rows = rows.into_iter().map(to_map_row).collect::<Vec<_>>();
+
+            json!({ "type": "SELECT", "rows": rows })
+        }
+        payload => header(payload),
+    }
+}
+
+/// Converts a payload without its rows, which [`JsonLines`] streams separately.
+fn header(payload: Payload) -> JsonValue {
+    match payload {
+        Payload::ShowColumns(columns) => {
+            let columns = columns
+                .into_iter()
+                .map(|(name, data_type)| json!({ "name": name, "type": data_type.to_string() }))
+                .collect::<Vec<_>>();
+
+            json!({ "type": "SHOW COLUMNS", "columns": columns })
+        }
+        Payload::Create => json!({ "type": "CREATE TABLE" }),
+        Payload::Insert(n) => json!({ "type": "INSERT", "affected": n }),
+        Payload::Select { labels, .. } => json!({ "type": "SELECT", "labels": labels }),
+        Payload::SelectMap(_) => json!({ "type": "SELECT" }),
+        Payload::Delete(n) => json!({ "type": "DELETE", "affected": n }),
+        Payload::Update(n) => json!({ "type": "UPDATE", "affected": n }),
+        Payload::DropTable(n) => json!({ "type": "DROP TABLE", "affected": n }),
+        Payload::DropFunction => json!({ "type": "DROP FUNCTION" }),
+        Payload::DropTrigger => json!({ "type": "DROP TRIGGER" }),
+        Payload::AlterTable => json!({ "type": "ALTER TABLE" }),
+        Payload::CreateIndex => json!({ "type": "CREATE INDEX" }),
+        Payload::DropIndex => json!({ "type": "DROP INDEX" }),
+        Payload::StartTransaction => json!({ "type": "BEGIN" }),
+        Payload::Commit => json!({ "type": "COMMIT" }),
+        Payload::Rollback => json!({ "type": "ROLLBACK" }),
+        Payload::ShowVariable(PayloadVariable::Tables(tables)) => {
+            json!({ "type": "SHOW TABLES", "tables": tables })
+        }
+        Payload::ShowVariable(PayloadVariable::Functions(functions)) => {
+            json!({ "type": "SHOW FUNCTIONS", "functions": functions })
+        }
+        Payload::ShowVariable(PayloadVariable::Version(version)) => {
+            json!({ "type": "SHOW VERSION", "version": version })
+        }
+    }
+}
+
+/// Splits a payload into its header line followed by one `{"row": ...}` line
+/// per selected row.
+fn lines(payload: Payload) -> Box<dyn Iterator<Item = JsonValue>> {
+    match payload {
+        Payload::Select { labels, rows } => {
+            let header = json!({ "type": "SELECT", "labels": labels });
+            let rows = rows
+                .into_iter()
+                .map(move |row| json!({ "row": to_row(&labels, row) }));
+
+            Box::new(std::iter::once(header).chain(rows))
+        }
+        Payload::SelectMap(rows) => {
+            let header = json!({ "type": "SELECT" });
+            let rows = rows
+                .into_iter()
+                .map(|row| json!({ "row": to_map_row(row) }));
+
+            Box::new(std::iter::once(header).chain(rows))
+        }
+        payload => Box::new(std::iter::once(header(payload))),
+    }
+}
+
+/// Response body which serializes payloads as JSON lines while it is read,
+/// so a large result is never held in memory as a single document.
+pub struct JsonLines {
+    lines: Box<dyn Iterator<Item = JsonValue>>,
+    buffer: Vec<u8>,
+    position: usize,
+}
+
+impl JsonLines {
+    pub fn new(payloads: Vec<Payload>) -> Self {
+        Self {
+            lines: Box::new(payloads.into_iter().flat_map(lines)),
+            buffer: Vec::new(),
+            position: 0,
+        }
+    }
+}
+
+impl Read for JsonLines {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position == self.buffer.len() {
+            let Some(line) = self.lines.next() else {
+                return Ok(0);
+            };
+
+            self.buffer.clear();
+            serde_json::to_writer(&mut self.buffer, &line)?;
+            self.buffer.push(b'\n');
+            self.position = 0;
+        }
+
+        let remaining = &self.buffer[self.position..];
+        let size = remaining.len().min(buf.len());
+        buf[..size].copy_from_slice(&remaining[..size]);
+        self.position += size;
+
+        Ok(size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{JsonLines, from_payload},
+        gluesql_core::{data::Value, executor::Payload},
+        serde_json::{Value as JsonValue, json},
+        std::io::Read,
+    };
+
+    #[test]
+    fn select_payload() {
+        let payload = Payload::Select {
+            labels: vec!["id".to_owned(), "name".to_owned()],
+            rows: vec![
+                vec![Value::I64(1), Value::Str("Glue".to_owned())],
+                vec![Value::I64(2), Value::Null],
+            ],
+        };
+
+        assert_eq!(
+            from_payload(payload.clone()),
+            json!({
+                "type": "SELECT",
+                "labels": ["id", "name"],
+                "rows": [{ "id": 1, "name": "Glue" }, { "id": 2, "name": null }],
+            })
+        );
+
+        let mut lines = String::new();
+        JsonLines::new(vec![payload, Payload::Insert(3)])
+            .read_to_string(&mut lines)
+            .unwrap();
+        let lines = lines
+            .lines()
+            .map(|line| serde_json::from_str::<JsonValue>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                json!({ "type": "SELECT", "labels": ["id", "name"] }),
+                json!({ "row": { "id": 1, "name": "Glue" } }),
+                json!({ "row": { "id": 2, "name": null } }),
+                json!({ "type": "INSERT", "affected": 3 }),
+            ]
+        );
+    }
+}
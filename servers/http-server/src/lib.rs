@@ -0,0 +1,217 @@
+#![deny(clippy::str_to_string)]
+
+mod error;
+mod json;
+mod session;
+
+pub use error::HttpError;
+
+use {
+    gluesql_core::store::{GStore, GStoreMut, Planner},
+    json::JsonLines,
+    serde::Deserialize,
+    serde_json::{Value as JsonValue, json},
+    session::{Session, Sessions},
+    std::{
+        io,
+        net::{SocketAddr, ToSocketAddrs},
+        sync::{Arc, PoisonError},
+        thread,
+        time::Duration,
+    },
+    tiny_http::{Header, Method, Request, Response, Server},
+};
+
+const SESSION_PATH: &str = "/sessions/";
+
+/// Idle time after which a session is closed unless configured otherwise.
+pub const DEFAULT_SESSION_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Serves a `GlueSQL` storage over HTTP with JSON requests and responses.
+///
+/// - `POST /query` executes `{"sql": ..., "params": [...], "session": ...}`
+///   and responds with the JSON of each payload, or with JSON lines when the
+///   request accepts `application/x-ndjson`.
+/// - `POST /sessions` opens a session whose transaction lasts across
+///   requests, and `DELETE /sessions/{id}` closes it. A session left idle
+///   for longer than the session timeout is closed by the next request.
+///
+/// Every request runs on its own thread. A request without a session runs on
+/// its own clone of `storage`, and each session keeps its own clone, so the
+/// clones must share their data, as `SharedMemoryStorage` and `SledStorage` do.
+pub struct HttpServer<T: GStore + GStoreMut + Planner> {
+    server: Server,
+    storage: T,
+    sessions: Arc<Sessions<T>>,
+}
+
+#[derive(Deserialize)]
+struct QueryRequest {
+    sql: String,
+    #[serde(default)]
+    params: Vec<JsonValue>,
+    session: Option<String>,
+}
+
+enum Reply {
+    Json(u16, JsonValue),
+    JsonLines(JsonLines),
+    NoContent,
+}
+
+impl<T> HttpServer<T>
+where
+    T: GStore + GStoreMut + Planner + Clone + Send + 'static,
+{
+    pub fn bind<A: ToSocketAddrs>(addr: A, storage: T) -> io::Result<Self> {
+        let server = Server::http(addr).map_err(io::Error::other)?;
+
+        Ok(Self {
+            server,
+            storage,
+            sessions: Arc::new(Sessions::new(DEFAULT_SESSION_TIMEOUT)),
+        })
+    }
+
+    /// Sets how long a session may stay idle before it is closed and its
+    /// open transaction is rolled back.
+    #[must_use]
+    pub fn session_timeout(mut self, timeout: Duration) -> Self {
+        self.sessions = Arc::new(Sessions::new(timeout));
+        self
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.server
+            .server_addr()
+            .to_ip()
+            .ok_or_else(|| io::Error::other("server is not listening on an IP address"))
+    }
+
+    /// Handles requests until the listener is closed.
+    pub fn run(self) -> io::Result<()> {
+        for request in self.server.incoming_requests() {
+            let storage = self.storage.clone();
+            let sessions = Arc::clone(&self.sessions);
+
+            thread::spawn(move || handle(request, storage, &sessions));
+        }
+
+        Ok(())
+    }
+}
+
+fn handle<T>(mut request: Request, storage: T, sessions: &Sessions<T>)
+where
+    T: GStore + GStoreMut + Planner,
+{
+    let reply = route(&mut request, storage, sessions)
+        .unwrap_or_else(|error| Reply::Json(error.status, json!({ "error": error.message })));
+
+    let result = match reply {
+        Reply::Json(status, body) => request.respond(
+            Response::from_string(body.to_string())
+                .with_status_code(status)
+                .with_header(content_type("application/json")),
+        ),
+        Reply::JsonLines(lines) => request.respond(
+            Response::new(
+                200.into(),
+                vec![content_type("application/x-ndjson")],
+                lines,
+                None,
+                None,
+            )
+            .with_chunked_threshold(0),
+        ),
+        Reply::NoContent => request.respond(Response::empty(204)),
+    };
+
+    if let Err(error) = result {
+        eprintln!("[http-server] failed to respond: {error}");
+    }
+}
+
+fn route<T>(request: &mut Request, storage: T, sessions: &Sessions<T>) -> Result<Reply, HttpError>
+where
+    T: GStore + GStoreMut + Planner,
+{
+    sessions.expire();
+
+    let path = request
+        .url()
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .to_owned();
+
+    match (request.method(), path.as_str()) {
+        (Method::Post, "/query") => query(request, storage, sessions),
+        (Method::Post, "/sessions") => {
+            let id = sessions.open(storage);
+
+            Ok(Reply::Json(201, json!({ "session": id })))
+        }
+        (Method::Delete, path) if path.starts_with(SESSION_PATH) => {
+            sessions.close(&path[SESSION_PATH.len()..])?;
+
+            Ok(Reply::NoContent)
+        }
+        (method, "/query" | "/sessions") => {
+            Err(HttpError::method_not_allowed(method.as_str(), &path))
+        }
+        (method, path) if path.starts_with(SESSION_PATH) => {
+            Err(HttpError::method_not_allowed(method.as_str(), path))
+        }
+        (_, path) => Err(HttpError::not_found(format!("{path} does not exist"))),
+    }
+}
+
+fn query<T>(request: &mut Request, storage: T, sessions: &Sessions<T>) -> Result<Reply, HttpError>
+where
+    T: GStore + GStoreMut + Planner,
+{
+    let streaming = request.headers().iter().any(|header| {
+        header.field.equiv("Accept") && header.value.as_str().contains("application/x-ndjson")
+    });
+
+    let mut body = String::new();
+    request
+        .as_reader()
+        .read_to_string(&mut body)
+        .map_err(|error| HttpError::bad_request(error.to_string()))?;
+    let QueryRequest {
+        sql,
+        params,
+        session,
+    } = serde_json::from_str(&body).map_err(|error| HttpError::bad_request(error.to_string()))?;
+    let params = params.into_iter().map(json::to_param).collect();
+
+    let payloads = if let Some(id) = session {
+        let session = sessions.get(&id)?;
+        let mut session = session.lock().unwrap_or_else(PoisonError::into_inner);
+        if session.is_closed() {
+            return Err(HttpError::unknown_session(&id));
+        }
+
+        session.execute(&sql, params)?
+    } else {
+        let mut session = Session::new(storage);
+        let payloads = session.execute(&sql, params);
+        session.rollback();
+
+        payloads?
+    };
+
+    Ok(if streaming {
+        Reply::JsonLines(JsonLines::new(payloads))
+    } else {
+        let payloads = payloads.into_iter().map(json::from_payload).collect();
+
+        Reply::Json(200, JsonValue::Array(payloads))
+    })
+}
+
+fn content_type(value: &str) -> Header {
+    Header::from_bytes("Content-Type", value).expect("valid content type header")
+}
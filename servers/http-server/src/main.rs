@@ -0,0 +1,62 @@
+use {
+    clap::Parser,
+    gluesql_core::store::{GStore, GStoreMut, Planner},
+    gluesql_http_server::HttpServer,
+    gluesql_shared_memory_storage::SharedMemoryStorage,
+    gluesql_sled_storage::SledStorage,
+    std::{io, path::PathBuf, time::Duration},
+};
+
+#[derive(Parser, Debug)]
+#[clap(name = "gluesql-http-server", about, version)]
+struct Args {
+    /// Address to listen on
+    #[clap(short, long, value_parser, default_value = "127.0.0.1:3000")]
+    addr: String,
+
+    /// Storage to serve, memory or sled
+    #[clap(short, long, value_parser, default_value = "memory")]
+    storage: Storage,
+
+    /// Path of sled storage
+    #[clap(short, long, value_parser, required_if_eq("storage", "sled"))]
+    path: Option<PathBuf>,
+
+    /// Seconds a session may stay idle before it is closed
+    #[clap(long, value_parser, default_value = "300")]
+    session_timeout: u64,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Storage {
+    Memory,
+    Sled,
+}
+
+fn main() -> io::Result<()> {
+    let Args {
+        addr,
+        storage,
+        path,
+        session_timeout,
+    } = Args::parse();
+    let session_timeout = Duration::from_secs(session_timeout);
+
+    match (storage, path) {
+        (Storage::Sled, Some(path)) => {
+            let storage = SledStorage::new(&path).expect("failed to load sled-storage");
+            serve(&addr, storage, session_timeout)
+        }
+        _ => serve(&addr, SharedMemoryStorage::new(), session_timeout),
+    }
+}
+
+fn serve<T>(addr: &str, storage: T, session_timeout: Duration) -> io::Result<()>
+where
+    T: GStore + GStoreMut + Planner + Clone + Send + 'static,
+{
+    let server = HttpServer::bind(addr, storage)?.session_timeout(session_timeout);
+    eprintln!("[http-server] listening on {}", server.local_addr()?);
+
+    server.run()
+}
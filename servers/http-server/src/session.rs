@@ -0,0 +1,172 @@
+use {
+    crate::error::HttpError,
+    gluesql_core::{
+        executor::Payload,
+        prelude::Glue,
+        store::{GStore, GStoreMut, Planner},
+        translate::ParamLiteral,
+    },
+    std::{
+        collections::HashMap,
+        sync::{Arc, Mutex, PoisonError, TryLockError},
+        time::{Duration, Instant},
+    },
+    uuid::Uuid,
+};
+
+/// A `Glue` over its own clone of the storage, which keeps the transaction
+/// opened by `BEGIN` until `COMMIT` or `ROLLBACK`.
+pub struct Session<T: GStore + GStoreMut + Planner> {
+    glue: Glue<T>,
+    in_transaction: bool,
+    last_used: Instant,
+    closed: bool,
+}
+
+impl<T: GStore + GStoreMut + Planner> Session<T> {
+    pub fn new(storage: T) -> Self {
+        Self {
+            glue: Glue::new(storage),
+            in_transaction: false,
+            last_used: Instant::now(),
+            closed: false,
+        }
+    }
+
+    /// Whether the session was closed after a request picked it up, in which
+    /// case the request must not run on it.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Executes the statements in `sql` one by one, stopping at the first
+    /// error. Statements executed before it are not undone.
+    pub fn execute(
+        &mut self,
+        sql: &str,
+        params: Vec<ParamLiteral>,
+    ) -> Result<Vec<Payload>, HttpError> {
+        self.last_used = Instant::now();
+        let result = self.execute_statements(sql, params);
+        self.last_used = Instant::now();
+
+        result
+    }
+
+    fn execute_statements(
+        &mut self,
+        sql: &str,
+        params: Vec<ParamLiteral>,
+    ) -> Result<Vec<Payload>, HttpError> {
+        let statements = self.glue.plan_with_params(sql, params)?;
+        let mut payloads = Vec::with_capacity(statements.len());
+
+        for statement in &statements {
+            let payload = self.glue.execute_stmt(statement)?;
+
+            match payload {
+                Payload::StartTransaction => self.in_transaction = true,
+                Payload::Commit | Payload::Rollback => self.in_transaction = false,
+                _ => {}
+            }
+
+            payloads.push(payload);
+        }
+
+        Ok(payloads)
+    }
+
+    /// Rolls back the transaction left open by the client, if any.
+    pub fn rollback(&mut self) {
+        if self.in_transaction {
+            self.in_transaction = false;
+
+            if let Err(error) = self.glue.execute("ROLLBACK") {
+                eprintln!("[http-server] failed to roll back: {error}");
+            }
+        }
+    }
+
+    fn close(&mut self) {
+        self.rollback();
+        self.closed = true;
+    }
+}
+
+pub type SharedSession<T> = Arc<Mutex<Session<T>>>;
+
+/// Sessions opened with `POST /sessions`, by their id.
+pub struct Sessions<T: GStore + GStoreMut + Planner> {
+    sessions: Mutex<HashMap<String, SharedSession<T>>>,
+    idle_timeout: Duration,
+}
+
+impl<T: GStore + GStoreMut + Planner> Sessions<T> {
+    pub fn new(idle_timeout: Duration) -> Self {
+        Self {
+            sessions: Mutex::new(HashMap::new()),
+            idle_timeout,
+        }
+    }
+
+    pub fn open(&self, storage: T) -> String {
+        let id = Uuid::new_v4().hyphenated().to_string();
+        let session = Arc::new(Mutex::new(Session::new(storage)));
+
+        self.sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(id.clone(), session);
+
+        id
+    }
+
+    pub fn get(&self, id: &str) -> Result<SharedSession<T>, HttpError> {
+        self.sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(id)
+            .cloned()
+            .ok_or_else(|| HttpError::unknown_session(id))
+    }
+
+    /// Removes the session and rolls back its open transaction.
+    pub fn close(&self, id: &str) -> Result<(), HttpError> {
+        let session = self
+            .sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(id)
+            .ok_or_else(|| HttpError::unknown_session(id))?;
+
+        session
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .close();
+
+        Ok(())
+    }
+
+    /// Closes the sessions left idle for longer than the idle timeout and
+    /// rolls back their open transactions. Sessions running a request are
+    /// kept.
+    pub fn expire(&self) {
+        self.sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|_, session| {
+                let mut session = match session.try_lock() {
+                    Ok(session) => session,
+                    Err(TryLockError::Poisoned(error)) => error.into_inner(),
+                    Err(TryLockError::WouldBlock) => return true,
+                };
+
+                if session.last_used.elapsed() < self.idle_timeout {
+                    return true;
+                }
+
+                session.close();
+                false
+            });
+    }
+}
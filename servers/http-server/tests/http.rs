@@ -0,0 +1,297 @@
+use {
+    gluesql_core::store::{GStore, GStoreMut, Planner},
+    gluesql_http_server::HttpServer,
+    gluesql_shared_memory_storage::SharedMemoryStorage,
+    gluesql_sled_storage::SledStorage,
+    serde_json::{Value as JsonValue, json},
+    std::{
+        fs,
+        io::{Read, Write},
+        net::{SocketAddr, TcpStream},
+        thread,
+        time::Duration,
+    },
+};
+
+fn spawn<T>(storage: T) -> SocketAddr
+where
+    T: GStore + GStoreMut + Planner + Clone + Send + 'static,
+{
+    let server = HttpServer::bind("127.0.0.1:0", storage).unwrap();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+
+    addr
+}
+
+struct Response {
+    status: u16,
+    content_type: Option<String>,
+    chunked: bool,
+    body: String,
+}
+
+impl Response {
+    fn json(&self) -> JsonValue {
+        serde_json::from_str(&self.body).unwrap()
+    }
+}
+
+fn request(addr: SocketAddr, method: &str, path: &str, accept: &str, body: &str) -> Response {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(
+        stream,
+        "{method} {path} HTTP/1.1\r\nHost: {addr}\r\nAccept: {accept}\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let mut lines = head.lines();
+    let status = lines
+        .next()
+        .unwrap()
+        .split(' ')
+        .nth(1)
+        .unwrap()
+        .parse()
+        .unwrap();
+    let headers = lines
+        .filter_map(|line| line.split_once(": "))
+        .map(|(name, value)| (name.to_ascii_lowercase(), value.to_owned()))
+        .collect::<Vec<_>>();
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    };
+
+    let chunked = header("transfer-encoding").as_deref() == Some("chunked");
+    let body = if chunked {
+        dechunk(body)
+    } else {
+        body.to_owned()
+    };
+
+    Response {
+        status,
+        content_type: header("content-type"),
+        chunked,
+        body,
+    }
+}
+
+fn dechunk(mut body: &str) -> String {
+    let mut decoded = String::new();
+    loop {
+        let (size, rest) = body.split_once("\r\n").unwrap();
+        let size = usize::from_str_radix(size, 16).unwrap();
+        if size == 0 {
+            return decoded;
+        }
+
+        decoded.push_str(&rest[..size]);
+        body = &rest[size + 2..];
+    }
+}
+
+fn query(addr: SocketAddr, body: &JsonValue) -> Response {
+    request(
+        addr,
+        "POST",
+        "/query",
+        "application/json",
+        &body.to_string(),
+    )
+}
+
+fn open_session(addr: SocketAddr) -> String {
+    let response = request(addr, "POST", "/sessions", "application/json", "");
+    assert_eq!(response.status, 201);
+
+    response.json()["session"].as_str().unwrap().to_owned()
+}
+
+#[test]
+fn query_with_params() {
+    let addr = spawn(SharedMemoryStorage::new());
+
+    let response = query(
+        addr,
+        &json!({
+            "sql": "
+                CREATE TABLE Item (id INTEGER, name TEXT, made DATE, price FLOAT NULL);
+                INSERT INTO Item VALUES ($1, $2, $3, $4), (2, 'SQL', '2024-03-04', NULL);
+            ",
+            "params": [1, "Glue", "2024-01-02", 1.5],
+        }),
+    );
+    assert_eq!(response.status, 200);
+    assert_eq!(response.content_type.as_deref(), Some("application/json"));
+    assert_eq!(
+        response.json(),
+        json!([{ "type": "CREATE TABLE" }, { "type": "INSERT", "affected": 2 }])
+    );
+
+    let response = query(
+        addr,
+        &json!({
+            "sql": "SELECT id, name, made, price FROM Item WHERE made > $1 ORDER BY id",
+            "params": ["2024-01-01"],
+        }),
+    );
+    assert_eq!(
+        response.json(),
+        json!([{
+            "type": "SELECT",
+            "labels": ["id", "name", "made", "price"],
+            "rows": [
+                { "id": 1, "name": "Glue", "made": "2024-01-02", "price": 1.5 },
+                { "id": 2, "name": "SQL", "made": "2024-03-04", "price": null },
+            ],
+        }])
+    );
+
+    let response = query(addr, &json!({ "sql": "SELECT * FROM Nothing" }));
+    assert_eq!(response.status, 400);
+    assert!(response.json()["error"].is_string());
+
+    let response = request(addr, "POST", "/query", "application/json", "{");
+    assert_eq!(response.status, 400);
+    assert_eq!(request(addr, "GET", "/query", "*/*", "").status, 405);
+    assert_eq!(request(addr, "GET", "/nothing", "*/*", "").status, 404);
+}
+
+#[test]
+fn stream_json_lines() {
+    let addr = spawn(SharedMemoryStorage::new());
+    query(
+        addr,
+        &json!({ "sql": "CREATE TABLE Item (id INTEGER); INSERT INTO Item VALUES (1), (2), (3);" }),
+    );
+
+    let body = json!({ "sql": "SELECT id FROM Item ORDER BY id; DELETE FROM Item WHERE id > 1;" });
+    let response = request(
+        addr,
+        "POST",
+        "/query",
+        "application/x-ndjson",
+        &body.to_string(),
+    );
+    assert_eq!(response.status, 200);
+    assert!(response.chunked);
+    assert_eq!(
+        response.content_type.as_deref(),
+        Some("application/x-ndjson")
+    );
+
+    let lines = response
+        .body
+        .lines()
+        .map(|line| serde_json::from_str::<JsonValue>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        vec![
+            json!({ "type": "SELECT", "labels": ["id"] }),
+            json!({ "row": { "id": 1 } }),
+            json!({ "row": { "id": 2 } }),
+            json!({ "row": { "id": 3 } }),
+            json!({ "type": "DELETE", "affected": 2 }),
+        ]
+    );
+}
+
+#[test]
+fn transaction_per_session() {
+    let path = "tmp/transaction_per_session";
+    fs::remove_dir_all(path).unwrap_or(());
+    let addr = spawn(SledStorage::new(path).unwrap());
+
+    query(
+        addr,
+        &json!({ "sql": "CREATE TABLE Item (id INTEGER); INSERT INTO Item VALUES (1);" }),
+    );
+    let count = || {
+        query(
+            addr,
+            &json!({ "sql": "SELECT COUNT(*) AS count FROM Item" }),
+        )
+        .json()[0]["rows"][0]["count"]
+            .clone()
+    };
+
+    let session = open_session(addr);
+    let response = query(
+        addr,
+        &json!({ "sql": "BEGIN; INSERT INTO Item VALUES (2);", "session": session }),
+    );
+    assert_eq!(
+        response.json(),
+        json!([{ "type": "BEGIN" }, { "type": "INSERT", "affected": 1 }])
+    );
+    assert_eq!(count(), json!(1));
+
+    let response = query(
+        addr,
+        &json!({ "sql": "SELECT COUNT(*) AS count FROM Item", "session": session }),
+    );
+    assert_eq!(response.json()[0]["rows"][0]["count"], json!(2));
+
+    query(addr, &json!({ "sql": "COMMIT", "session": session }));
+    assert_eq!(count(), json!(2));
+
+    let response = query(
+        addr,
+        &json!({ "sql": "BEGIN; DELETE FROM Item;", "session": session }),
+    );
+    assert_eq!(response.status, 200);
+    let response = request(addr, "DELETE", &format!("/sessions/{session}"), "*/*", "");
+    assert_eq!(response.status, 204);
+    assert_eq!(count(), json!(2));
+
+    let response = query(addr, &json!({ "sql": "COMMIT", "session": session }));
+    assert_eq!(response.status, 404);
+}
+
+#[test]
+fn idle_session_times_out() {
+    let path = "tmp/idle_session_times_out";
+    fs::remove_dir_all(path).unwrap_or(());
+    let server = HttpServer::bind("127.0.0.1:0", SledStorage::new(path).unwrap())
+        .unwrap()
+        .session_timeout(Duration::from_millis(200));
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+
+    query(
+        addr,
+        &json!({ "sql": "CREATE TABLE Item (id INTEGER); INSERT INTO Item VALUES (1);" }),
+    );
+    let session = open_session(addr);
+    let response = query(
+        addr,
+        &json!({ "sql": "BEGIN; DELETE FROM Item;", "session": session }),
+    );
+    assert_eq!(response.status, 200);
+
+    // the open transaction holds the lock until the session times out
+    let insert = || query(addr, &json!({ "sql": "INSERT INTO Item VALUES (2);" }));
+    assert_eq!(insert().status, 500);
+
+    thread::sleep(Duration::from_millis(300));
+    assert_eq!(insert().status, 200);
+
+    let response = query(
+        addr,
+        &json!({ "sql": "SELECT id FROM Item", "session": session }),
+    );
+    assert_eq!(response.status, 404);
+    assert_eq!(
+        query(addr, &json!({ "sql": "SELECT id FROM Item" })).json()[0]["rows"],
+        json!([{ "id": 1 }, { "id": 2 }])
+    );
+}
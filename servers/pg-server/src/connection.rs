@@ -0,0 +1,848 @@
+use {
+    crate::{
+        describe::{Column, Description, Rows, describe},
+        error::PgError,
+        message::{
+            BackendMessage, FieldDescription, FrontendMessage, Startup, Target, TransactionStatus,
+            read_message, read_startup,
+        },
+        types::{Format, PgType, UNKNOWN_OID, decode_param},
+    },
+    gluesql_core::{
+        data::{Schema, Value},
+        executor::{Payload, PayloadVariable},
+        parse_sql::{ParsedStatement, parse_statements},
+        plan::StatementPlan,
+        planner::fetch_schema_map,
+        prelude::Glue,
+        sqlparser::ast::Statement as SqlStatement,
+        store::{GStore, GStoreMut, Planner},
+        translate::{ParamLiteral, translate_parsed_with_params},
+    },
+    std::{
+        collections::{BTreeMap, BTreeSet, HashMap, VecDeque},
+        io::{self, BufReader, BufWriter, Write},
+        net::TcpStream,
+    },
+};
+
+const PARAMETER_STATUS: [(&str, &str); 8] = [
+    ("server_version", "14.0"),
+    ("server_encoding", "UTF8"),
+    ("client_encoding", "UTF8"),
+    ("DateStyle", "ISO, MDY"),
+    ("IntervalStyle", "postgres"),
+    ("TimeZone", "UTC"),
+    ("integer_datetimes", "on"),
+    ("standard_conforming_strings", "on"),
+];
+
+/// Result columns of a statement or portal once described; `Some(None)`
+/// means it was described as returning no rows.
+type Described = Option<Option<Vec<Column>>>;
+
+#[derive(Debug, Clone)]
+enum Command {
+    Empty,
+    /// `SET` of a session parameter, which drivers send on connect and
+    /// which is acknowledged without effect.
+    Set,
+    Sql(String),
+}
+
+struct Statement {
+    command: Command,
+    param_types: Vec<u32>,
+    columns: Described,
+}
+
+struct Portal {
+    command: Command,
+    params: Vec<ParamLiteral>,
+    result_formats: Vec<i16>,
+    columns: Described,
+    output: Option<Output>,
+}
+
+/// Rows of an executed portal not sent yet.
+struct Output {
+    rows: VecDeque<Vec<Value>>,
+    tag: Tag,
+}
+
+enum Tag {
+    Select,
+    Show,
+    Insert(usize),
+    Update(usize),
+    Delete(usize),
+    Command(&'static str),
+}
+
+impl Tag {
+    fn of(payload: &Payload) -> Self {
+        match payload {
+            Payload::Select { .. } | Payload::SelectMap(_) => Tag::Select,
+            Payload::ShowColumns(_) | Payload::ShowVariable(_) => Tag::Show,
+            Payload::Insert(n) => Tag::Insert(*n),
+            Payload::Update(n) => Tag::Update(*n),
+            Payload::Delete(n) => Tag::Delete(*n),
+            Payload::Create => Tag::Command("CREATE"),
+            Payload::DropTable(_) => Tag::Command("DROP TABLE"),
+            Payload::DropFunction => Tag::Command("DROP FUNCTION"),
+            Payload::DropTrigger => Tag::Command("DROP TRIGGER"),
+            Payload::AlterTable => Tag::Command("ALTER TABLE"),
+            Payload::CreateIndex => Tag::Command("CREATE INDEX"),
+            Payload::DropIndex => Tag::Command("DROP INDEX"),
+            Payload::StartTransaction => Tag::Command("BEGIN"),
+            Payload::Commit => Tag::Command("COMMIT"),
+            Payload::Rollback => Tag::Command("ROLLBACK"),
+        }
+    }
+
+    fn to_command_complete(&self, rows: usize) -> BackendMessage {
+        BackendMessage::CommandComplete(match self {
+            Tag::Select => format!("SELECT {rows}"),
+            Tag::Show => "SHOW".to_owned(),
+            Tag::Insert(n) => format!("INSERT 0 {n}"),
+            Tag::Update(n) => format!("UPDATE {n}"),
+            Tag::Delete(n) => format!("DELETE {n}"),
+            Tag::Command(tag) => (*tag).to_owned(),
+        })
+    }
+}
+
+/// One client session. It owns its `Glue`, so an open transaction belongs
+/// to the connection that started it.
+pub struct Connection<T: GStore + GStoreMut + Planner> {
+    glue: Glue<T>,
+    reader: BufReader<TcpStream>,
+    writer: BufWriter<TcpStream>,
+    process_id: i32,
+    statements: HashMap<String, Statement>,
+    portals: HashMap<String, Portal>,
+    transaction: TransactionStatus,
+    discard_until_sync: bool,
+}
+
+impl<T: GStore + GStoreMut + Planner> Connection<T> {
+    pub fn new(stream: TcpStream, storage: T, process_id: i32) -> io::Result<Self> {
+        Ok(Self {
+            glue: Glue::new(storage),
+            reader: BufReader::new(stream.try_clone()?),
+            writer: BufWriter::new(stream),
+            process_id,
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+            transaction: TransactionStatus::Idle,
+            discard_until_sync: false,
+        })
+    }
+
+    /// Serves the client until it terminates or the stream closes, then rolls
+    /// back a transaction it left open, so that its locks are released on
+    /// every exit path.
+    pub fn run(mut self) -> io::Result<()> {
+        let result = self.serve();
+        if self.transaction == TransactionStatus::Idle {
+            return result;
+        }
+
+        let rollback = self.glue.execute("ROLLBACK").map_err(io::Error::other);
+        result.and(rollback.map(drop))
+    }
+
+    fn serve(&mut self) -> io::Result<()> {
+        if !self.startup()? {
+            return Ok(());
+        }
+
+        while let Some(message) = read_message(&mut self.reader)? {
+            let messages = match message {
+                FrontendMessage::Terminate => return Ok(()),
+                FrontendMessage::Flush => {
+                    self.writer.flush()?;
+                    continue;
+                }
+                FrontendMessage::Sync => {
+                    self.discard_until_sync = false;
+                    self.send(&[BackendMessage::ReadyForQuery(self.transaction)])?;
+                    self.writer.flush()?;
+                    continue;
+                }
+                FrontendMessage::Unsupported(tag) => {
+                    let error = PgError::protocol(format!(
+                        "unsupported frontend message: {}",
+                        char::from(tag)
+                    ));
+                    self.send(&[error_response(error)])?;
+                    return self.writer.flush();
+                }
+                FrontendMessage::Query(sql) => {
+                    let messages = self.simple_query(&sql);
+                    self.send(&messages)?;
+                    self.writer.flush()?;
+                    continue;
+                }
+                _ if self.discard_until_sync => continue,
+                FrontendMessage::Parse {
+                    name,
+                    query,
+                    param_types,
+                } => self.parse(name, query, param_types),
+                FrontendMessage::Bind {
+                    portal,
+                    statement,
+                    param_formats,
+                    params,
+                    result_formats,
+                } => self.bind(portal, &statement, &param_formats, &params, result_formats),
+                FrontendMessage::Describe { target, name } => self.describe(target, &name),
+                FrontendMessage::Execute { portal, max_rows } => self.execute(&portal, max_rows),
+                FrontendMessage::Close { target, name } => {
+                    match target {
+                        Target::Statement => {
+                            self.statements.remove(&name);
+                        }
+                        Target::Portal => {
+                            self.portals.remove(&name);
+                        }
+                    }
+
+                    Ok(vec![BackendMessage::CloseComplete])
+                }
+            };
+
+            match messages {
+                Ok(messages) => self.send(&messages)?,
+                Err(error) => {
+                    self.discard_until_sync = true;
+                    self.send(&[error_response(error)])?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Answers SSL and GSSAPI requests with `N`, then accepts the client
+    /// without authentication. Returns `false` for cancel requests.
+    fn startup(&mut self) -> io::Result<bool> {
+        loop {
+            match read_startup(&mut self.reader)? {
+                Startup::SslRequest | Startup::GssEncRequest => {
+                    self.writer.write_all(b"N")?;
+                    self.writer.flush()?;
+                }
+                Startup::CancelRequest => return Ok(false),
+                Startup::Params(_) => break,
+            }
+        }
+
+        let mut messages = vec![BackendMessage::AuthenticationOk];
+        messages.extend(PARAMETER_STATUS.iter().map(|(name, value)| {
+            BackendMessage::ParameterStatus {
+                name: (*name).to_owned(),
+                value: (*value).to_owned(),
+            }
+        }));
+        messages.push(BackendMessage::BackendKeyData {
+            process_id: self.process_id,
+            secret_key: 0,
+        });
+        messages.push(BackendMessage::ReadyForQuery(self.transaction));
+        self.send(&messages)?;
+        self.writer.flush()?;
+
+        Ok(true)
+    }
+
+    fn send(&mut self, messages: &[BackendMessage]) -> io::Result<()> {
+        messages
+            .iter()
+            .try_for_each(|message| message.write_to(&mut self.writer))
+    }
+
+    fn simple_query(&mut self, sql: &str) -> Vec<BackendMessage> {
+        let mut messages = Vec::new();
+        if let Err(error) = self.run_simple_query(sql, &mut messages) {
+            messages.push(error_response(error));
+        }
+        messages.push(BackendMessage::ReadyForQuery(self.transaction));
+
+        messages
+    }
+
+    fn run_simple_query(
+        &mut self,
+        sql: &str,
+        messages: &mut Vec<BackendMessage>,
+    ) -> Result<(), PgError> {
+        let statements = parse_statements(sql)?;
+        if statements.is_empty() {
+            messages.push(BackendMessage::EmptyQueryResponse);
+            return Ok(());
+        }
+
+        for parsed in &statements {
+            if is_session_setting(parsed) {
+                messages.push(BackendMessage::CommandComplete("SET".to_owned()));
+                continue;
+            }
+
+            let statement = translate_parsed_with_params(parsed, &[])?;
+            let plan = self.glue.storage.plan(statement.into())?;
+            let schemas = fetch_schema_map(&self.glue.storage, &plan)?;
+            let payload = self.execute_plan(&plan)?;
+            let columns = columns_of(&payload, &schemas);
+            let tag = Tag::of(&payload);
+
+            let mut rows = 0;
+            if let Some(columns) = columns {
+                messages.push(row_description(&columns, &[])?);
+                for row in rows_of(payload, &columns) {
+                    messages.push(data_row(&row, &columns, &[])?);
+                    rows += 1;
+                }
+            }
+            messages.push(tag.to_command_complete(rows));
+        }
+
+        Ok(())
+    }
+
+    fn execute_plan(&mut self, plan: &StatementPlan) -> Result<Payload, PgError> {
+        let payload = self.glue.execute_stmt(plan)?;
+        self.track_transaction(&payload);
+
+        Ok(payload)
+    }
+
+    fn track_transaction(&mut self, payload: &Payload) {
+        match payload {
+            Payload::StartTransaction => self.transaction = TransactionStatus::InTransaction,
+            Payload::Commit | Payload::Rollback => self.transaction = TransactionStatus::Idle,
+            _ => {}
+        }
+    }
+
+    fn parse(
+        &mut self,
+        name: String,
+        query: String,
+        mut param_types: Vec<u32>,
+    ) -> Result<Vec<BackendMessage>, PgError> {
+        if !name.is_empty() && self.statements.contains_key(&name) {
+            return Err(PgError::duplicate_statement(&name));
+        }
+
+        let command = match parse_statements(&query)?.as_slice() {
+            [] => Command::Empty,
+            [parsed] if is_session_setting(parsed) => Command::Set,
+            [_] => {
+                let count = param_count(&query).max(param_types.len());
+                param_types.resize(count, 0);
+
+                Command::Sql(query)
+            }
+            _ => {
+                return Err(PgError::syntax(
+                    "cannot insert multiple commands into a prepared statement",
+                ));
+            }
+        };
+
+        self.statements.insert(
+            name,
+            Statement {
+                command,
+                param_types,
+                columns: None,
+            },
+        );
+
+        Ok(vec![BackendMessage::ParseComplete])
+    }
+
+    fn bind(
+        &mut self,
+        portal: String,
+        statement: &str,
+        param_formats: &[i16],
+        params: &[Option<Vec<u8>>],
+        result_formats: Vec<i16>,
+    ) -> Result<Vec<BackendMessage>, PgError> {
+        let Statement {
+            command,
+            param_types,
+            columns,
+        } = self
+            .statements
+            .get(statement)
+            .ok_or_else(|| PgError::unknown_statement(statement))?;
+
+        if params.len() != param_types.len() {
+            return Err(PgError::protocol(format!(
+                "bind message supplies {} parameters, but prepared statement \"{statement}\" requires {}",
+                params.len(),
+                param_types.len()
+            )));
+        }
+
+        let formats = Format::resolve(param_formats, params.len())?;
+        let params = params
+            .iter()
+            .zip(param_types)
+            .zip(formats)
+            .map(|((param, oid), format)| match param {
+                Some(bytes) => decode_param(*oid, format, bytes),
+                None => Ok(ParamLiteral::null()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for code in &result_formats {
+            Format::from_code(*code)?;
+        }
+
+        let portal_state = Portal {
+            command: command.clone(),
+            params,
+            result_formats,
+            columns: columns.clone(),
+            output: None,
+        };
+        self.portals.insert(portal, portal_state);
+
+        Ok(vec![BackendMessage::BindComplete])
+    }
+
+    fn describe(&mut self, target: Target, name: &str) -> Result<Vec<BackendMessage>, PgError> {
+        match target {
+            Target::Statement => {
+                let statement = self
+                    .statements
+                    .get(name)
+                    .ok_or_else(|| PgError::unknown_statement(name))?;
+
+                let (param_types, columns) = if let Some(columns) = statement.columns.clone() {
+                    (statement.param_types.clone(), columns)
+                } else {
+                    let command = statement.command.clone();
+                    let param_types = statement.param_types.clone();
+                    let Description { rows, params } =
+                        self.describe_command(&command, param_types.len())?;
+                    let param_types = param_types
+                        .into_iter()
+                        .zip(params)
+                        .map(|(oid, pg_type)| match (oid, pg_type) {
+                            (0, Some(pg_type)) => pg_type.oid(),
+                            (0, None) => UNKNOWN_OID,
+                            (oid, _) => oid,
+                        })
+                        .collect::<Vec<_>>();
+                    let columns = match rows {
+                        Rows::None => None,
+                        Rows::Columns(columns) => Some(columns),
+                        Rows::Data => {
+                            let params = vec![ParamLiteral::null(); param_types.len()];
+
+                            self.run_command(&command, params)?.0
+                        }
+                    };
+                    if let Some(statement) = self.statements.get_mut(name) {
+                        statement.param_types.clone_from(&param_types);
+                        statement.columns = Some(columns.clone());
+                    }
+
+                    (param_types, columns)
+                };
+
+                Ok(vec![
+                    BackendMessage::ParameterDescription(param_types),
+                    columns.map_or(Ok(BackendMessage::NoData), |columns| {
+                        row_description(&columns, &[])
+                    })?,
+                ])
+            }
+            Target::Portal => {
+                let portal = self
+                    .portals
+                    .get(name)
+                    .ok_or_else(|| PgError::unknown_portal(name))?;
+
+                let columns = if let Some(columns) = portal.columns.clone() {
+                    columns
+                } else {
+                    let command = portal.command.clone();
+                    let params = portal.params.clone();
+                    let (columns, output) =
+                        match self.describe_command(&command, params.len())?.rows {
+                            Rows::None => (None, None),
+                            Rows::Columns(columns) => (Some(columns), None),
+                            Rows::Data => self.run_command(&command, params)?,
+                        };
+                    if let Some(portal) = self.portals.get_mut(name) {
+                        portal.columns = Some(columns.clone());
+                        portal.output = output;
+                    }
+
+                    columns
+                };
+                let result_formats = self
+                    .portals
+                    .get(name)
+                    .map(|portal| portal.result_formats.clone())
+                    .unwrap_or_default();
+
+                Ok(vec![
+                    columns.map_or(Ok(BackendMessage::NoData), |columns| {
+                        row_description(&columns, &result_formats)
+                    })?,
+                ])
+            }
+        }
+    }
+
+    /// Describes a statement from its plan and the schemas of the tables it
+    /// reads, with its parameters left as placeholders, so it is not run.
+    fn describe_command(
+        &mut self,
+        command: &Command,
+        param_count: usize,
+    ) -> Result<Description, PgError> {
+        let Command::Sql(sql) = command else {
+            return Ok(Description {
+                rows: Rows::None,
+                params: vec![None; param_count],
+            });
+        };
+
+        let params = (1..=param_count).map(ParamLiteral::placeholder);
+        let Some(plan) = self.glue.plan_with_params(sql, params)?.into_iter().next() else {
+            return Ok(Description {
+                rows: Rows::None,
+                params: vec![None; param_count],
+            });
+        };
+        let schemas = fetch_schema_map(&self.glue.storage, &plan)?;
+
+        Ok(describe(&plan, &schemas, param_count))
+    }
+
+    /// Runs a query whose columns depend on the data to learn them, keeping
+    /// its rows for the portal. Column types come from the first non-null
+    /// value, then from the schema of the scanned tables, and are text
+    /// otherwise.
+    fn run_command(
+        &mut self,
+        command: &Command,
+        params: Vec<ParamLiteral>,
+    ) -> Result<(Option<Vec<Column>>, Option<Output>), PgError> {
+        let Command::Sql(sql) = command else {
+            return Ok((None, None));
+        };
+
+        let Some(plan) = self.glue.plan_with_params(sql, params)?.into_iter().next() else {
+            return Ok((None, None));
+        };
+        let schemas = fetch_schema_map(&self.glue.storage, &plan)?;
+        let payload = self.execute_plan(&plan)?;
+        let columns = columns_of(&payload, &schemas);
+        let output = columns.as_ref().map(|columns| Output {
+            tag: Tag::of(&payload),
+            rows: rows_of(payload, columns).into(),
+        });
+
+        Ok((columns, output))
+    }
+
+    fn execute(&mut self, name: &str, max_rows: i32) -> Result<Vec<BackendMessage>, PgError> {
+        let portal = self
+            .portals
+            .get(name)
+            .ok_or_else(|| PgError::unknown_portal(name))?;
+
+        let sql = match &portal.command {
+            Command::Empty => return Ok(vec![BackendMessage::EmptyQueryResponse]),
+            Command::Set => return Ok(vec![BackendMessage::CommandComplete("SET".to_owned())]),
+            Command::Sql(sql) => sql.clone(),
+        };
+
+        if portal.output.is_none() {
+            let params = portal.params.clone();
+            let payload = self
+                .glue
+                .execute_with_params(&sql, params)?
+                .into_iter()
+                .next()
+                .ok_or_else(|| PgError::syntax("prepared statement is empty"))?;
+            self.track_transaction(&payload);
+
+            let portal = self
+                .portals
+                .get_mut(name)
+                .ok_or_else(|| PgError::unknown_portal(name))?;
+            let columns = portal
+                .columns
+                .get_or_insert_with(|| columns_of(&payload, &HashMap::new()));
+            let tag = Tag::of(&payload);
+            let rows = match columns {
+                Some(columns) => rows_of(payload, columns).into(),
+                None => VecDeque::new(),
+            };
+            portal.output = Some(Output { rows, tag });
+        }
+
+        let portal = self
+            .portals
+            .get_mut(name)
+            .ok_or_else(|| PgError::unknown_portal(name))?;
+        let columns = portal.columns.clone().flatten().unwrap_or_default();
+        let formats = Format::resolve(&portal.result_formats, columns.len())?;
+        let Some(output) = portal.output.as_mut() else {
+            return Ok(Vec::new());
+        };
+
+        let limit = usize::try_from(max_rows)
+            .ok()
+            .filter(|limit| *limit > 0)
+            .unwrap_or(usize::MAX);
+        let mut messages = Vec::new();
+        while messages.len() < limit {
+            let Some(row) = output.rows.pop_front() else {
+                break;
+            };
+
+            messages.push(data_row(&row, &columns, &formats)?);
+        }
+
+        if output.rows.is_empty() {
+            let rows = messages.len();
+            messages.push(output.tag.to_command_complete(rows));
+        } else {
+            messages.push(BackendMessage::PortalSuspended);
+        }
+
+        Ok(messages)
+    }
+}
+
+fn error_response(error: PgError) -> BackendMessage {
+    BackendMessage::ErrorResponse {
+        code: error.code,
+        message: error.message,
+    }
+}
+
+fn row_description(columns: &[Column], result_formats: &[i16]) -> Result<BackendMessage, PgError> {
+    let formats = Format::resolve(result_formats, columns.len())?;
+    let fields = columns
+        .iter()
+        .zip(formats)
+        .map(|(column, format)| FieldDescription {
+            name: column.name.clone(),
+            type_oid: column.pg_type.oid(),
+            type_size: column.pg_type.size(),
+            format: format.code(),
+        })
+        .collect();
+
+    Ok(BackendMessage::RowDescription(fields))
+}
+
+fn data_row(
+    row: &[Value],
+    columns: &[Column],
+    formats: &[Format],
+) -> Result<BackendMessage, PgError> {
+    let formats = match formats {
+        [] => Format::resolve(&[], columns.len())?,
+        formats => formats.to_vec(),
+    };
+    let values = columns
+        .iter()
+        .zip(formats)
+        .enumerate()
+        .map(|(i, (column, format))| {
+            let value = row.get(i).unwrap_or(&Value::Null);
+
+            column.pg_type.encode(value, format)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(BackendMessage::DataRow(values))
+}
+
+fn columns_of(payload: &Payload, schemas: &HashMap<String, Schema>) -> Option<Vec<Column>> {
+    let text = |name: &str| Column {
+        name: name.to_owned(),
+        pg_type: PgType::Text,
+    };
+
+    match payload {
+        Payload::Select { labels, rows } => Some(
+            labels
+                .iter()
+                .enumerate()
+                .map(|(i, label)| Column {
+                    name: label.clone(),
+                    pg_type: rows
+                        .iter()
+                        .find_map(|row| row.get(i).and_then(PgType::of_value))
+                        .or_else(|| schema_type(schemas, label))
+                        .unwrap_or(PgType::Text),
+                })
+                .collect(),
+        ),
+        Payload::SelectMap(rows) => Some(
+            rows.iter()
+                .flat_map(BTreeMap::keys)
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .map(|key| Column {
+                    name: key.clone(),
+                    pg_type: rows
+                        .iter()
+                        .find_map(|row| row.get(key).and_then(PgType::of_value))
+                        .unwrap_or(PgType::Text),
+                })
+                .collect(),
+        ),
+        Payload::ShowColumns(_) => Some(vec![text("Field"), text("Type")]),
+        Payload::ShowVariable(PayloadVariable::Tables(_)) => Some(vec![text("table")]),
+        Payload::ShowVariable(PayloadVariable::Functions(_)) => Some(vec![text("function")]),
+        Payload::ShowVariable(PayloadVariable::Version(_)) => Some(vec![text("version")]),
+        _ => None,
+    }
+}
+
+/// Type of the column named `label` in the scanned tables, checked in table
+/// name order so the answer does not depend on hash order.
+fn schema_type(schemas: &HashMap<String, Schema>, label: &str) -> Option<PgType> {
+    schemas
+        .iter()
+        .collect::<BTreeMap<_, _>>()
+        .into_values()
+        .filter_map(|schema| schema.column_defs.as_ref())
+        .flatten()
+        .find(|column_def| column_def.name == label)
+        .map(|column_def| PgType::of_data_type(&column_def.data_type))
+}
+
+fn rows_of(payload: Payload, columns: &[Column]) -> Vec<Vec<Value>> {
+    match payload {
+        Payload::Select { rows, .. } => rows,
+        Payload::SelectMap(rows) => rows
+            .into_iter()
+            .map(|mut row| {
+                columns
+                    .iter()
+                    .map(|column| row.remove(&column.name).unwrap_or(Value::Null))
+                    .collect()
+            })
+            .collect(),
+        Payload::ShowColumns(columns) => columns
+            .into_iter()
+            .map(|(name, data_type)| vec![Value::Str(name), Value::Str(data_type.to_string())])
+            .collect(),
+        Payload::ShowVariable(
+            PayloadVariable::Tables(names) | PayloadVariable::Functions(names),
+        ) => names
+            .into_iter()
+            .map(|name| vec![Value::Str(name)])
+            .collect(),
+        Payload::ShowVariable(PayloadVariable::Version(version)) => {
+            vec![vec![Value::Str(version)]]
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn is_session_setting(parsed: &ParsedStatement) -> bool {
+    matches!(
+        parsed,
+        ParsedStatement::Sql(statement) if matches!(
+            **statement,
+            SqlStatement::SetVariable { .. }
+                | SqlStatement::SetTimeZone { .. }
+                | SqlStatement::SetNames { .. }
+                | SqlStatement::SetNamesDefault {}
+                | SqlStatement::SetRole { .. }
+        )
+    )
+}
+
+/// Highest `$n` placeholder in `sql`, skipping quoted text and comments.
+fn param_count(sql: &str) -> usize {
+    let bytes = sql.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    i += 1;
+                }
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !bytes[i..].starts_with(b"*/") {
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'$' => {
+                let digits = bytes[i + 1..]
+                    .iter()
+                    .take_while(|byte| byte.is_ascii_digit())
+                    .count();
+                if let Ok(n) = sql[i + 1..i + 1 + digits].parse::<usize>() {
+                    count = count.max(n);
+                }
+                i += digits;
+            }
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{is_session_setting, param_count},
+        gluesql_core::parse_sql::parse_statements,
+    };
+
+    #[test]
+    fn count_placeholders() {
+        assert_eq!(param_count("SELECT 1"), 0);
+        assert_eq!(
+            param_count("SELECT * FROM Item WHERE id = $2 AND name = $1"),
+            2
+        );
+        assert_eq!(
+            param_count("SELECT '$3', \"$4\" -- $5\n/* $6 */ FROM Item WHERE id = $1"),
+            1
+        );
+    }
+
+    #[test]
+    fn detect_session_setting() {
+        let is_setting = |sql| is_session_setting(&parse_statements(sql).unwrap()[0]);
+
+        assert!(is_setting("SET extra_float_digits = 3"));
+        assert!(is_setting("set application_name = 'psql'"));
+        assert!(is_setting("SET TIME ZONE 'UTC'"));
+        assert!(!is_setting("SELECT * FROM Setting"));
+    }
+}
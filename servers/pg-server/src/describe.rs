@@ -0,0 +1,875 @@
+use {
+    crate::types::PgType,
+    gluesql_core::{
+        ast::{BinaryOperator, DataType, Literal, UnaryOperator, Variable},
+        data::Schema,
+        plan::{
+            AggregateFunctionPlan, AggregationInputPlan, AggregationPlan, DistinctInputPlan,
+            ExprPlan, FilterInputPlan, FilterPlan, FunctionExprPlan, HashJoinInputPlan,
+            HashJoinPlan, InnerJoinInputPlan, InnerJoinPlan, JoinConditionInputPlan,
+            JoinConditionPlan, LeftOuterJoinInputPlan, LeftOuterJoinPlan, LimitInputPlan,
+            NestedLoopJoinInputPlan, NestedLoopJoinPlan, OffsetInputPlan, OrderByExprPlan,
+            ProjectInputPlan, ProjectPlan, ProjectionPlan, QueryPlan, SelectItemPlan, SourcePlan,
+            StatementPlan, TableAliasPlan, UnionPlan, ValuesPlan,
+        },
+        translate::ParamLiteral,
+    },
+    std::collections::HashMap,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    pub name: String,
+    pub pg_type: PgType,
+}
+
+impl Column {
+    fn text(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            pg_type: PgType::Text,
+        }
+    }
+}
+
+/// Rows a statement returns as far as its plan tells.
+pub enum Rows {
+    None,
+    Columns(Vec<Column>),
+    /// The columns depend on the data, as for schemaless tables and
+    /// dictionary views, so the statement has to run to learn them.
+    Data,
+}
+
+/// Columns and parameter types of a statement planned with its parameters
+/// kept as placeholders by [`ParamLiteral::placeholder`].
+pub struct Description {
+    pub rows: Rows,
+    /// Type each parameter is compared with, assigned to or used as, if any.
+    pub params: Vec<Option<PgType>>,
+}
+
+/// Describes `plan` from the schemas of the tables it reads without running
+/// it. Expressions of types the plan does not tell are described as text.
+pub fn describe(
+    plan: &StatementPlan,
+    schemas: &HashMap<String, Schema>,
+    param_count: usize,
+) -> Description {
+    let mut describer = Describer {
+        schemas,
+        params: vec![None; param_count],
+    };
+    let rows = describer.statement(plan);
+    let params = describer
+        .params
+        .iter()
+        .map(|data_type| data_type.as_ref().map(PgType::of_data_type))
+        .collect();
+
+    Description { rows, params }
+}
+
+/// Columns of a relation, `None` when they depend on the data.
+type Columns = Option<Vec<(String, Option<DataType>)>>;
+
+struct Relation {
+    alias: String,
+    columns: Columns,
+}
+
+#[derive(Default)]
+struct Scope<'a> {
+    relations: Vec<Relation>,
+    labels: Vec<(String, Option<DataType>)>,
+    outer: Option<&'a Scope<'a>>,
+}
+
+impl<'a> Scope<'a> {
+    fn new(outer: Option<&'a Scope<'a>>) -> Self {
+        Self {
+            relations: Vec::new(),
+            labels: Vec::new(),
+            outer,
+        }
+    }
+
+    fn column(&self, alias: Option<&str>, name: &str) -> Option<DataType> {
+        let find = |columns: &Columns| {
+            columns
+                .as_ref()?
+                .iter()
+                .find(|(column, _)| column == name)
+                .map(|(_, data_type)| data_type.clone())
+        };
+
+        let found = match alias {
+            Some(alias) => self
+                .relations
+                .iter()
+                .find(|relation| relation.alias == alias)
+                .map(|relation| find(&relation.columns).flatten()),
+            None => self
+                .labels
+                .iter()
+                .find(|(label, _)| label == name)
+                .map(|(_, data_type)| data_type.clone())
+                .or_else(|| {
+                    self.relations
+                        .iter()
+                        .find_map(|relation| find(&relation.columns))
+                }),
+        };
+
+        match found {
+            Some(data_type) => data_type,
+            None => self.outer?.column(alias, name),
+        }
+    }
+}
+
+struct Describer<'a> {
+    schemas: &'a HashMap<String, Schema>,
+    params: Vec<Option<DataType>>,
+}
+
+impl Describer<'_> {
+    fn statement(&mut self, plan: &StatementPlan) -> Rows {
+        let text =
+            |names: &[&str]| Rows::Columns(names.iter().copied().map(Column::text).collect());
+
+        match plan {
+            StatementPlan::Query(query) => match self.query(query, None) {
+                Some(columns) => Rows::Columns(
+                    columns
+                        .into_iter()
+                        .map(|(name, data_type)| Column {
+                            name,
+                            pg_type: data_type
+                                .as_ref()
+                                .map_or(PgType::Text, PgType::of_data_type),
+                        })
+                        .collect(),
+                ),
+                None => Rows::Data,
+            },
+            StatementPlan::ShowColumns { .. } => text(&["Field", "Type"]),
+            StatementPlan::ShowVariable(Variable::Tables) => text(&["table"]),
+            StatementPlan::ShowVariable(Variable::Functions) => text(&["function"]),
+            StatementPlan::ShowVariable(Variable::Version) => text(&["version"]),
+            StatementPlan::ShowIndexes(_) => Rows::Data,
+            StatementPlan::Insert {
+                table_name,
+                columns,
+                source,
+            } => {
+                let defs = self.table_columns(table_name).unwrap_or_default();
+                let targets = if columns.is_empty() {
+                    defs
+                } else {
+                    columns
+                        .iter()
+                        .map(|column| {
+                            let data_type = defs
+                                .iter()
+                                .find(|(name, _)| name == column)
+                                .and_then(|(_, data_type)| data_type.clone());
+
+                            (column.clone(), data_type)
+                        })
+                        .collect()
+                };
+
+                match source {
+                    QueryPlan::Values(ValuesPlan(rows)) => {
+                        let scope = Scope::default();
+                        for row in rows {
+                            for (i, expr) in row.iter().enumerate() {
+                                let expected = targets.get(i).and_then(|(_, ty)| ty.as_ref());
+                                self.expr(expr, &scope, expected);
+                            }
+                        }
+                    }
+                    source => {
+                        self.query(source, None);
+                    }
+                }
+
+                Rows::None
+            }
+            StatementPlan::Update {
+                table_name,
+                assignments,
+                selection,
+            } => {
+                let scope = self.table_scope(table_name);
+                for assignment in assignments {
+                    let expected = scope.column(None, &assignment.id);
+                    self.expr(&assignment.value, &scope, expected.as_ref());
+                }
+                if let Some(selection) = selection {
+                    self.expr(selection, &scope, Some(&DataType::Boolean));
+                }
+
+                Rows::None
+            }
+            StatementPlan::Delete {
+                table_name,
+                selection,
+            } => {
+                let scope = self.table_scope(table_name);
+                if let Some(selection) = selection {
+                    self.expr(selection, &scope, Some(&DataType::Boolean));
+                }
+
+                Rows::None
+            }
+            _ => Rows::None,
+        }
+    }
+
+    fn table_columns(&self, name: &str) -> Columns {
+        let column_defs = self.schemas.get(name)?.column_defs.as_ref()?;
+
+        Some(
+            column_defs
+                .iter()
+                .map(|def| (def.name.clone(), Some(def.data_type.clone())))
+                .collect(),
+        )
+    }
+
+    fn table_scope(&self, name: &str) -> Scope<'static> {
+        Scope {
+            relations: vec![Relation {
+                alias: name.to_owned(),
+                columns: self.table_columns(name),
+            }],
+            ..Scope::default()
+        }
+    }
+
+    fn query(&mut self, query: &QueryPlan, outer: Option<&Scope>) -> Columns {
+        match query {
+            QueryPlan::Project(project) => self.project(project, &[], outer),
+            QueryPlan::SelectOrderBy(order_by) => {
+                self.project(&order_by.input, &order_by.exprs, outer)
+            }
+            QueryPlan::Values(values) => self.values(values, &[], outer),
+            QueryPlan::ValuesOrderBy(order_by) => {
+                self.values(&order_by.input, &order_by.exprs, outer)
+            }
+            QueryPlan::Distinct(distinct) => self.distinct(&distinct.input, outer),
+            QueryPlan::Offset(offset) => {
+                self.count(&offset.count, outer);
+                self.offset_input(&offset.input, outer)
+            }
+            QueryPlan::Limit(limit) => {
+                self.count(&limit.count, outer);
+                self.limit_input(&limit.input, outer)
+            }
+            QueryPlan::Union(union) => self.union(union, outer),
+        }
+    }
+
+    /// `LIMIT` and `OFFSET` counts, which take integers.
+    fn count(&mut self, count: &ExprPlan, outer: Option<&Scope>) {
+        self.expr(count, &Scope::new(outer), Some(&DataType::Int));
+    }
+
+    fn limit_input(&mut self, input: &LimitInputPlan, outer: Option<&Scope>) -> Columns {
+        match input {
+            LimitInputPlan::Project(project) => self.project(project, &[], outer),
+            LimitInputPlan::SelectOrderBy(order_by) => {
+                self.project(&order_by.input, &order_by.exprs, outer)
+            }
+            LimitInputPlan::Values(values) => self.values(values, &[], outer),
+            LimitInputPlan::ValuesOrderBy(order_by) => {
+                self.values(&order_by.input, &order_by.exprs, outer)
+            }
+            LimitInputPlan::Distinct(distinct) => self.distinct(&distinct.input, outer),
+            LimitInputPlan::Offset(offset) => {
+                self.count(&offset.count, outer);
+                self.offset_input(&offset.input, outer)
+            }
+        }
+    }
+
+    fn offset_input(&mut self, input: &OffsetInputPlan, outer: Option<&Scope>) -> Columns {
+        match input {
+            OffsetInputPlan::Project(project) => self.project(project, &[], outer),
+            OffsetInputPlan::SelectOrderBy(order_by) => {
+                self.project(&order_by.input, &order_by.exprs, outer)
+            }
+            OffsetInputPlan::Values(values) => self.values(values, &[], outer),
+            OffsetInputPlan::ValuesOrderBy(order_by) => {
+                self.values(&order_by.input, &order_by.exprs, outer)
+            }
+            OffsetInputPlan::Distinct(distinct) => self.distinct(&distinct.input, outer),
+        }
+    }
+
+    fn distinct(&mut self, input: &DistinctInputPlan, outer: Option<&Scope>) -> Columns {
+        match input {
+            DistinctInputPlan::Project(project) => self.project(project, &[], outer),
+            DistinctInputPlan::SelectOrderBy(order_by) => {
+                self.project(&order_by.input, &order_by.exprs, outer)
+            }
+        }
+    }
+
+    fn union(&mut self, union: &UnionPlan, outer: Option<&Scope>) -> Columns {
+        let left = self.query(&union.left, outer);
+        let right = self.query(&union.right, outer);
+        let columns = left.zip(right).map(|(left, right)| {
+            left.into_iter()
+                .zip(right)
+                .map(|((name, left), (_, right))| (name, unify(left, right.as_ref())))
+                .collect::<Vec<_>>()
+        });
+
+        let scope = Scope {
+            labels: columns.clone().unwrap_or_default(),
+            ..Scope::new(outer)
+        };
+        self.order_by(&union.order_by, &scope);
+        for count in union.offset.iter().chain(&union.limit) {
+            self.count(count, outer);
+        }
+
+        columns
+    }
+
+    fn values(
+        &mut self,
+        values: &ValuesPlan,
+        order_by: &[OrderByExprPlan],
+        outer: Option<&Scope>,
+    ) -> Columns {
+        let scope = Scope::new(outer);
+        let mut columns: Option<Vec<(String, Option<DataType>)>> = None;
+
+        for row in &values.0 {
+            let row = row.iter().enumerate().map(|(i, expr)| {
+                let expected = columns
+                    .as_ref()
+                    .and_then(|columns| columns.get(i))
+                    .and_then(|(_, data_type)| data_type.clone());
+
+                (
+                    format!("column{}", i + 1),
+                    self.expr(expr, &scope, expected.as_ref()),
+                )
+            });
+            let row = row.collect::<Vec<_>>();
+
+            columns = Some(match columns {
+                Some(columns) => columns
+                    .into_iter()
+                    .zip(row)
+                    .map(|((name, left), (_, right))| (name, unify(left, right.as_ref())))
+                    .collect(),
+                None => row,
+            });
+        }
+
+        let scope = Scope {
+            labels: columns.clone().unwrap_or_default(),
+            ..scope
+        };
+        self.order_by(order_by, &scope);
+
+        columns
+    }
+
+    fn project(
+        &mut self,
+        project: &ProjectPlan,
+        order_by: &[OrderByExprPlan],
+        outer: Option<&Scope>,
+    ) -> Columns {
+        let mut scope = Scope::new(outer);
+        self.project_input(&project.input, &mut scope);
+
+        let columns = match &project.projection {
+            ProjectionPlan::SelectItems(items) => {
+                let mut columns = Vec::new();
+                for item in items {
+                    match item {
+                        SelectItemPlan::Expr { expr, label } => {
+                            columns.push((label.clone(), self.expr(expr, &scope, None)));
+                        }
+                        SelectItemPlan::QualifiedWildcard(alias) => {
+                            let relation = scope
+                                .relations
+                                .iter()
+                                .find(|relation| &relation.alias == alias)?;
+
+                            columns.extend(relation.columns.clone()?);
+                        }
+                        SelectItemPlan::Wildcard => {
+                            for relation in &scope.relations {
+                                columns.extend(relation.columns.clone()?);
+                            }
+                        }
+                    }
+                }
+
+                Some(columns)
+            }
+            ProjectionPlan::SchemalessMap => None,
+        };
+
+        scope.labels = columns.clone().unwrap_or_default();
+        self.order_by(order_by, &scope);
+
+        columns
+    }
+
+    fn order_by(&mut self, order_by: &[OrderByExprPlan], scope: &Scope) {
+        for OrderByExprPlan { expr, .. } in order_by {
+            self.expr(expr, scope, None);
+        }
+    }
+
+    fn project_input(&mut self, input: &ProjectInputPlan, scope: &mut Scope) {
+        match input {
+            ProjectInputPlan::Source(source) => self.source(source, scope),
+            ProjectInputPlan::InnerJoin(join) => self.inner_join(join, scope),
+            ProjectInputPlan::LeftOuterJoin(join) => self.left_join(join, scope),
+            ProjectInputPlan::Filter(filter) => self.filter(filter, scope),
+            ProjectInputPlan::Aggregation(aggregation) => self.aggregation(aggregation, scope),
+            ProjectInputPlan::Having(having) => {
+                self.aggregation(&having.input, scope);
+                self.expr(&having.expr, scope, Some(&DataType::Boolean));
+            }
+        }
+    }
+
+    fn aggregation(&mut self, aggregation: &AggregationPlan, scope: &mut Scope) {
+        match &aggregation.input {
+            AggregationInputPlan::Source(source) => self.source(source, scope),
+            AggregationInputPlan::InnerJoin(join) => self.inner_join(join, scope),
+            AggregationInputPlan::LeftOuterJoin(join) => self.left_join(join, scope),
+            AggregationInputPlan::Filter(filter) => self.filter(filter, scope),
+        }
+
+        for expr in &aggregation.group_by {
+            self.expr(expr, scope, None);
+        }
+    }
+
+    fn filter(&mut self, filter: &FilterPlan, scope: &mut Scope) {
+        match &filter.input {
+            FilterInputPlan::Source(source) => self.source(source, scope),
+            FilterInputPlan::InnerJoin(join) => self.inner_join(join, scope),
+            FilterInputPlan::LeftOuterJoin(join) => self.left_join(join, scope),
+        }
+
+        self.expr(&filter.expr, scope, Some(&DataType::Boolean));
+    }
+
+    fn inner_join(&mut self, join: &InnerJoinPlan, scope: &mut Scope) {
+        match &join.input {
+            InnerJoinInputPlan::NestedLoop(join) => self.nested_loop(join, scope),
+            InnerJoinInputPlan::Hash(join) => self.hash_join(join, scope),
+            InnerJoinInputPlan::Condition(join) => self.join_condition(join, scope),
+        }
+    }
+
+    fn left_join(&mut self, join: &LeftOuterJoinPlan, scope: &mut Scope) {
+        match &join.input {
+            LeftOuterJoinInputPlan::NestedLoop(join) => self.nested_loop(join, scope),
+            LeftOuterJoinInputPlan::Hash(join) => self.hash_join(join, scope),
+            LeftOuterJoinInputPlan::Condition(join) => self.join_condition(join, scope),
+        }
+    }
+
+    fn join_condition(&mut self, join: &JoinConditionPlan, scope: &mut Scope) {
+        match &join.input {
+            JoinConditionInputPlan::NestedLoop(join) => self.nested_loop(join, scope),
+            JoinConditionInputPlan::Hash(join) => self.hash_join(join, scope),
+        }
+
+        self.expr(&join.expr, scope, Some(&DataType::Boolean));
+    }
+
+    fn nested_loop(&mut self, join: &NestedLoopJoinPlan, scope: &mut Scope) {
+        match &join.input {
+            NestedLoopJoinInputPlan::Source(source) => self.source(source, scope),
+            NestedLoopJoinInputPlan::InnerJoin(join) => self.inner_join(join, scope),
+            NestedLoopJoinInputPlan::LeftOuterJoin(join) => self.left_join(join, scope),
+        }
+
+        self.source(&join.right, scope);
+    }
+
+    fn hash_join(&mut self, join: &HashJoinPlan, scope: &mut Scope) {
+        match &join.input {
+            HashJoinInputPlan::Source(source) => self.source(source, scope),
+            HashJoinInputPlan::InnerJoin(join) => self.inner_join(join, scope),
+            HashJoinInputPlan::LeftOuterJoin(join) => self.left_join(join, scope),
+        }
+        self.source(&join.right, scope);
+
+        self.compare(&join.input_key, &join.right_key, scope);
+        if let Some(filter) = &join.right_filter {
+            self.expr(filter, scope, Some(&DataType::Boolean));
+        }
+    }
+
+    fn source(&mut self, source: &SourcePlan, scope: &mut Scope) {
+        let (alias, columns) = match source {
+            SourcePlan::Table(table) => {
+                let alias = table.alias.clone().unwrap_or_else(|| TableAliasPlan {
+                    name: table.name.clone(),
+                    columns: Vec::new(),
+                });
+
+                (alias, self.table_columns(&table.name))
+            }
+            SourcePlan::Derived(derived) => (
+                derived.alias.clone(),
+                self.query(&derived.query, scope.outer),
+            ),
+            SourcePlan::Series(series) => {
+                self.expr(&series.size, &Scope::new(scope.outer), Some(&DataType::Int));
+
+                (
+                    series.alias.clone(),
+                    Some(vec![("N".to_owned(), Some(DataType::Int))]),
+                )
+            }
+            SourcePlan::GenerateSeries(series) => {
+                let outer = Scope::new(scope.outer);
+                let start = self.expr(&series.start, &outer, None);
+                let stop = self.expr(&series.stop, &outer, start.as_ref());
+                if let Some(step) = &series.step {
+                    self.expr(step, &outer, None);
+                }
+
+                (
+                    series.alias.clone(),
+                    Some(vec![("N".to_owned(), unify(start, stop.as_ref()))]),
+                )
+            }
+            SourcePlan::Dictionary(dictionary) => (dictionary.alias.clone(), None),
+        };
+
+        let columns = columns.map(|mut columns| {
+            for ((name, _), rename) in columns.iter_mut().zip(&alias.columns) {
+                name.clone_from(rename);
+            }
+
+            columns
+        });
+
+        scope.relations.push(Relation {
+            alias: alias.name,
+            columns,
+        });
+    }
+
+    /// Type of `expr`, recording `expected` as the type of a parameter
+    /// placeholder that is not typed yet.
+    fn expr(
+        &mut self,
+        expr: &ExprPlan,
+        scope: &Scope,
+        expected: Option<&DataType>,
+    ) -> Option<DataType> {
+        let boolean = Some(DataType::Boolean);
+
+        match expr {
+            ExprPlan::Identifier(name) => match ParamLiteral::placeholder_index(name) {
+                Some(index) => {
+                    let param = self.params.get_mut(index.checked_sub(1)?)?;
+                    if param.is_none() {
+                        param.clone_from(&expected.cloned());
+                    }
+
+                    param.clone()
+                }
+                None => scope.column(None, name),
+            },
+            ExprPlan::CompoundIdentifier { alias, ident } => scope.column(Some(alias), ident),
+            ExprPlan::IsNull(expr) | ExprPlan::IsNotNull(expr) => {
+                self.expr(expr, scope, None);
+
+                boolean
+            }
+            ExprPlan::InList { expr, list, .. } => {
+                let mut data_type = self.expr(expr, scope, None);
+                for item in list {
+                    let item = self.expr(item, scope, data_type.as_ref());
+                    data_type = data_type.or(item);
+                }
+                self.expr(expr, scope, data_type.as_ref());
+
+                boolean
+            }
+            ExprPlan::InSubquery { expr, subquery, .. } => {
+                let data_type = self
+                    .query(subquery, Some(scope))
+                    .and_then(|columns| columns.into_iter().next())
+                    .and_then(|(_, data_type)| data_type);
+                self.expr(expr, scope, data_type.as_ref());
+
+                boolean
+            }
+            ExprPlan::Between {
+                expr, low, high, ..
+            } => {
+                let data_type = self.compare(expr, low, scope);
+                self.expr(high, scope, data_type.as_ref());
+                self.expr(expr, scope, data_type.as_ref());
+
+                boolean
+            }
+            ExprPlan::Like { expr, pattern, .. } | ExprPlan::ILike { expr, pattern, .. } => {
+                self.expr(expr, scope, Some(&DataType::Text));
+                self.expr(pattern, scope, Some(&DataType::Text));
+
+                boolean
+            }
+            ExprPlan::BinaryOp { left, op, right } => match op {
+                BinaryOperator::And | BinaryOperator::Or | BinaryOperator::Xor => {
+                    self.expr(left, scope, boolean.as_ref());
+                    self.expr(right, scope, boolean.as_ref());
+
+                    boolean
+                }
+                BinaryOperator::Gt
+                | BinaryOperator::Lt
+                | BinaryOperator::GtEq
+                | BinaryOperator::LtEq
+                | BinaryOperator::Eq
+                | BinaryOperator::NotEq => {
+                    self.compare(left, right, scope);
+
+                    boolean
+                }
+                BinaryOperator::StringConcat => {
+                    self.expr(left, scope, Some(&DataType::Text));
+                    self.expr(right, scope, Some(&DataType::Text));
+
+                    Some(DataType::Text)
+                }
+                BinaryOperator::Plus
+                | BinaryOperator::Minus
+                | BinaryOperator::Multiply
+                | BinaryOperator::Divide
+                | BinaryOperator::Modulo => {
+                    let left = self.expr(left, scope, expected);
+                    let right = self.expr(right, scope, left.as_ref().or(expected));
+
+                    left.filter(|left| right.as_ref() == Some(left) && is_numeric(left))
+                }
+                _ => {
+                    self.expr(left, scope, None);
+                    self.expr(right, scope, None);
+
+                    None
+                }
+            },
+            ExprPlan::UnaryOp { op, expr } => match op {
+                UnaryOperator::Not => {
+                    self.expr(expr, scope, boolean.as_ref());
+
+                    boolean
+                }
+                UnaryOperator::Plus | UnaryOperator::Minus => self.expr(expr, scope, expected),
+                UnaryOperator::Factorial | UnaryOperator::BitwiseNot => {
+                    self.expr(expr, scope, None);
+
+                    None
+                }
+            },
+            ExprPlan::Nested(expr) => self.expr(expr, scope, expected),
+            ExprPlan::Literal(Literal::Number(number)) => Some(if number.is_integer() {
+                DataType::Int
+            } else {
+                DataType::Float
+            }),
+            ExprPlan::Literal(Literal::QuotedString(_)) => Some(DataType::Text),
+            ExprPlan::Value(value) => value.get_type(),
+            ExprPlan::TypedString { data_type, .. } => Some(data_type.clone()),
+            ExprPlan::Function(function) => {
+                for expr in function.as_exprs() {
+                    self.expr(expr, scope, None);
+                }
+
+                function_type(function)
+            }
+            ExprPlan::Aggregate(aggregate) => {
+                let data_type = aggregate
+                    .as_exprs()
+                    .map(|expr| self.expr(expr, scope, None))
+                    .next()
+                    .flatten();
+                if let Some(filter) = &aggregate.filter {
+                    self.expr(filter, scope, boolean.as_ref());
+                }
+
+                match &aggregate.func {
+                    AggregateFunctionPlan::Count(_) => Some(DataType::Int),
+                    AggregateFunctionPlan::Sum(_)
+                    | AggregateFunctionPlan::Min(_)
+                    | AggregateFunctionPlan::Max(_) => data_type,
+                    AggregateFunctionPlan::Avg(_) => Some(DataType::Float),
+                    AggregateFunctionPlan::StringAgg { .. } => Some(DataType::Text),
+                    AggregateFunctionPlan::ArrayAgg { .. } => Some(DataType::List),
+                    AggregateFunctionPlan::BoolAnd(_) | AggregateFunctionPlan::BoolOr(_) => boolean,
+                    _ => None,
+                }
+            }
+            ExprPlan::Exists { subquery, .. } => {
+                self.query(subquery, Some(scope));
+
+                boolean
+            }
+            ExprPlan::Subquery(subquery) => self
+                .query(subquery, Some(scope))
+                .and_then(|columns| columns.into_iter().next())
+                .and_then(|(_, data_type)| data_type),
+            ExprPlan::Case {
+                operand,
+                when_then,
+                else_result,
+            } => {
+                let operand = operand
+                    .as_ref()
+                    .map(|operand| self.expr(operand, scope, None));
+
+                let mut result = None;
+                for (i, (when, then)) in when_then.iter().enumerate() {
+                    match &operand {
+                        Some(operand) => self.expr(when, scope, operand.as_ref()),
+                        None => self.expr(when, scope, boolean.as_ref()),
+                    };
+                    let then = self.expr(then, scope, result.as_ref().or(expected));
+                    result = if i == 0 {
+                        then
+                    } else {
+                        unify(result, then.as_ref())
+                    };
+                }
+
+                match else_result {
+                    Some(else_result) => {
+                        let else_result = self.expr(else_result, scope, result.as_ref());
+
+                        unify(result, else_result.as_ref())
+                    }
+                    None => result,
+                }
+            }
+            ExprPlan::ArrayIndex { obj, indexes } => {
+                self.expr(obj, scope, None);
+                for index in indexes {
+                    self.expr(index, scope, None);
+                }
+
+                None
+            }
+            ExprPlan::Interval { expr, .. } => {
+                self.expr(expr, scope, Some(&DataType::Text));
+
+                Some(DataType::Interval)
+            }
+            ExprPlan::Array { elem } => {
+                for expr in elem {
+                    self.expr(expr, scope, None);
+                }
+
+                Some(DataType::List)
+            }
+        }
+    }
+
+    /// Types two compared expressions, each giving its type to a parameter
+    /// on the other side.
+    fn compare(&mut self, left: &ExprPlan, right: &ExprPlan, scope: &Scope) -> Option<DataType> {
+        let left_type = self.expr(left, scope, None);
+        let right_type = self.expr(right, scope, left_type.as_ref());
+        if left_type.is_some() {
+            return left_type;
+        }
+
+        self.expr(left, scope, right_type.as_ref())
+    }
+}
+
+fn unify(left: Option<DataType>, right: Option<&DataType>) -> Option<DataType> {
+    left.filter(|left| right == Some(left))
+}
+
+fn is_numeric(data_type: &DataType) -> bool {
+    !matches!(
+        data_type,
+        DataType::Boolean
+            | DataType::Text
+            | DataType::Bytea
+            | DataType::Inet
+            | DataType::Date
+            | DataType::Timestamp
+            | DataType::Time
+            | DataType::Interval
+            | DataType::Uuid
+            | DataType::Map
+            | DataType::List
+            | DataType::Point
+            | DataType::LineString
+            | DataType::Polygon
+            | DataType::MultiPoint
+    )
+}
+
+/// Return type of the functions whose result type does not depend on the
+/// values they are given.
+fn function_type(function: &FunctionExprPlan) -> Option<DataType> {
+    Some(match function {
+        FunctionExprPlan::Cast { data_type, .. } => data_type.clone(),
+        FunctionExprPlan::Lower(_)
+        | FunctionExprPlan::Upper(_)
+        | FunctionExprPlan::Initcap(_)
+        | FunctionExprPlan::Left { .. }
+        | FunctionExprPlan::Right { .. }
+        | FunctionExprPlan::Lpad { .. }
+        | FunctionExprPlan::Rpad { .. }
+        | FunctionExprPlan::Replace { .. }
+        | FunctionExprPlan::Concat(_)
+        | FunctionExprPlan::ConcatWs { .. }
+        | FunctionExprPlan::Trim { .. }
+        | FunctionExprPlan::Ltrim { .. }
+        | FunctionExprPlan::Rtrim { .. }
+        | FunctionExprPlan::Reverse(_)
+        | FunctionExprPlan::Repeat { .. }
+        | FunctionExprPlan::Substr { .. }
+        | FunctionExprPlan::Chr(_)
+        | FunctionExprPlan::Md5(_)
+        | FunctionExprPlan::Hex(_) => DataType::Text,
+        FunctionExprPlan::Ceil(_)
+        | FunctionExprPlan::Floor(_)
+        | FunctionExprPlan::Round(_)
+        | FunctionExprPlan::Trunc(_)
+        | FunctionExprPlan::Exp(_)
+        | FunctionExprPlan::Ln(_)
+        | FunctionExprPlan::Log { .. }
+        | FunctionExprPlan::Log2(_)
+        | FunctionExprPlan::Log10(_)
+        | FunctionExprPlan::Power { .. }
+        | FunctionExprPlan::Radians(_)
+        | FunctionExprPlan::Degrees(_)
+        | FunctionExprPlan::Sin(_)
+        | FunctionExprPlan::Cos(_)
+        | FunctionExprPlan::Tan(_)
+        | FunctionExprPlan::Asin(_)
+        | FunctionExprPlan::Acos(_)
+        | FunctionExprPlan::Atan(_) => DataType::Float,
+        FunctionExprPlan::Now() | FunctionExprPlan::CurrentTimestamp() => DataType::Timestamp,
+        FunctionExprPlan::CurrentDate() => DataType::Date,
+        FunctionExprPlan::GenerateUuid() => DataType::Uuid,
+        _ => return None,
+    })
+}
@@ -0,0 +1,67 @@
+use {gluesql_core::error::Error, thiserror::Error as ThisError};
+
+/// Error reported to the client as an `ErrorResponse` with its SQLSTATE code.
+#[derive(ThisError, Debug, Clone, PartialEq, Eq)]
+#[error("{message}")]
+pub struct PgError {
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl PgError {
+    fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn protocol(message: impl Into<String>) -> Self {
+        Self::new("08P01", message)
+    }
+
+    pub fn syntax(message: impl Into<String>) -> Self {
+        Self::new("42601", message)
+    }
+
+    pub fn invalid_param(message: impl Into<String>) -> Self {
+        Self::new("22P02", message)
+    }
+
+    pub fn datatype_mismatch(message: impl Into<String>) -> Self {
+        Self::new("42804", message)
+    }
+
+    pub fn feature_not_supported(message: impl Into<String>) -> Self {
+        Self::new("0A000", message)
+    }
+
+    pub fn unknown_statement(name: &str) -> Self {
+        Self::new(
+            "26000",
+            format!("prepared statement \"{name}\" does not exist"),
+        )
+    }
+
+    pub fn duplicate_statement(name: &str) -> Self {
+        Self::new(
+            "42P05",
+            format!("prepared statement \"{name}\" already exists"),
+        )
+    }
+
+    pub fn unknown_portal(name: &str) -> Self {
+        Self::new("34000", format!("portal \"{name}\" does not exist"))
+    }
+}
+
+impl From<Error> for PgError {
+    fn from(error: Error) -> Self {
+        let code = match error {
+            Error::Parser(_) | Error::Translate(_) => "42601",
+            _ => "XX000",
+        };
+
+        Self::new(code, error.to_string())
+    }
+}
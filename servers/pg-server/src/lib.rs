@@ -0,0 +1,63 @@
+#![deny(clippy::str_to_string)]
+
+mod connection;
+mod describe;
+mod error;
+mod message;
+mod types;
+
+pub use error::PgError;
+
+use {
+    connection::Connection,
+    gluesql_core::store::{GStore, GStoreMut, Planner},
+    std::{
+        io,
+        net::{SocketAddr, TcpListener, ToSocketAddrs},
+        thread,
+    },
+};
+
+/// Serves a `GlueSQL` storage over the Postgres frontend/backend protocol, so
+/// `psql` and Postgres drivers can connect to it.
+///
+/// Every connection runs on its own thread with its own clone of `storage`,
+/// which keeps transaction state per connection. The clones must share their
+/// data, as `SharedMemoryStorage` and `SledStorage` do.
+pub struct PgServer<T> {
+    listener: TcpListener,
+    storage: T,
+}
+
+impl<T> PgServer<T>
+where
+    T: GStore + GStoreMut + Planner + Clone + Send + 'static,
+{
+    pub fn bind<A: ToSocketAddrs>(addr: A, storage: T) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+
+        Ok(Self { listener, storage })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Accepts connections until accepting fails.
+    pub fn run(self) -> io::Result<()> {
+        for (process_id, stream) in (1..).zip(self.listener.incoming()) {
+            let stream = stream?;
+            let storage = self.storage.clone();
+
+            thread::spawn(move || {
+                let result = Connection::new(stream, storage, process_id).and_then(Connection::run);
+
+                if let Err(error) = result {
+                    eprintln!("[pg-server] connection {process_id} closed: {error}");
+                }
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use {
+    clap::Parser,
+    gluesql_core::store::{GStore, GStoreMut, Planner},
+    gluesql_pg_server::PgServer,
+    gluesql_shared_memory_storage::SharedMemoryStorage,
+    gluesql_sled_storage::SledStorage,
+    std::{io, path::PathBuf},
+};
+
+#[derive(Parser, Debug)]
+#[clap(name = "gluesql-pg-server", about, version)]
+struct Args {
+    /// Address to listen on
+    #[clap(short, long, value_parser, default_value = "127.0.0.1:5432")]
+    addr: String,
+
+    /// Storage to serve, memory or sled
+    #[clap(short, long, value_parser, default_value = "memory")]
+    storage: Storage,
+
+    /// Path of sled storage
+    #[clap(short, long, value_parser, required_if_eq("storage", "sled"))]
+    path: Option<PathBuf>,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Storage {
+    Memory,
+    Sled,
+}
+
+fn main() -> io::Result<()> {
+    let Args {
+        addr,
+        storage,
+        path,
+    } = Args::parse();
+
+    match (storage, path) {
+        (Storage::Sled, Some(path)) => {
+            let storage = SledStorage::new(&path).expect("failed to load sled-storage");
+            serve(&addr, storage)
+        }
+        _ => serve(&addr, SharedMemoryStorage::new()),
+    }
+}
+
+fn serve<T>(addr: &str, storage: T) -> io::Result<()>
+where
+    T: GStore + GStoreMut + Planner + Clone + Send + 'static,
+{
+    let server = PgServer::bind(addr, storage)?;
+    eprintln!("[pg-server] listening on {}", server.local_addr()?);
+
+    server.run()
+}
@@ -0,0 +1,486 @@
+use std::io::{self, Read, Write};
+
+const SSL_REQUEST_CODE: i32 = 80_877_103;
+const GSSENC_REQUEST_CODE: i32 = 80_877_104;
+const CANCEL_REQUEST_CODE: i32 = 80_877_102;
+const PROTOCOL_VERSION_3: i32 = 196_608;
+
+/// Upper bound of a single frontend message, to refuse garbage lengths
+/// before allocating for them.
+const MAX_MESSAGE_LEN: usize = 1 << 30;
+
+/// First packet a client sends, before any tagged message.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Startup {
+    SslRequest,
+    GssEncRequest,
+    CancelRequest,
+    Params(Vec<(String, String)>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Statement,
+    Portal,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FrontendMessage {
+    Query(String),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    Describe {
+        target: Target,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    Close {
+        target: Target,
+        name: String,
+    },
+    Sync,
+    Flush,
+    Terminate,
+    Unsupported(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionStatus {
+    Idle,
+    InTransaction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_size: i16,
+    pub format: i16,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BackendMessage {
+    AuthenticationOk,
+    ParameterStatus { name: String, value: String },
+    BackendKeyData { process_id: i32, secret_key: i32 },
+    ReadyForQuery(TransactionStatus),
+    RowDescription(Vec<FieldDescription>),
+    DataRow(Vec<Option<Vec<u8>>>),
+    CommandComplete(String),
+    EmptyQueryResponse,
+    ErrorResponse { code: &'static str, message: String },
+    ParseComplete,
+    BindComplete,
+    CloseComplete,
+    NoData,
+    ParameterDescription(Vec<u32>),
+    PortalSuspended,
+}
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+fn read_i32(reader: &mut impl Read) -> io::Result<i32> {
+    let mut buf = [0; 4];
+    reader.read_exact(&mut buf)?;
+
+    Ok(i32::from_be_bytes(buf))
+}
+
+/// Reads a body whose length field counts itself.
+fn read_body(reader: &mut impl Read) -> io::Result<Vec<u8>> {
+    let len = read_i32(reader)?;
+    let len = usize::try_from(len)
+        .ok()
+        .and_then(|len| len.checked_sub(4))
+        .filter(|len| *len <= MAX_MESSAGE_LEN)
+        .ok_or_else(|| invalid_data(format!("invalid message length: {len}")))?;
+
+    let mut body = vec![0; len];
+    reader.read_exact(&mut body)?;
+
+    Ok(body)
+}
+
+pub fn read_startup(reader: &mut impl Read) -> io::Result<Startup> {
+    let body = read_body(reader)?;
+    let mut body = Body::new(&body);
+
+    match body.i32()? {
+        SSL_REQUEST_CODE => Ok(Startup::SslRequest),
+        GSSENC_REQUEST_CODE => Ok(Startup::GssEncRequest),
+        CANCEL_REQUEST_CODE => Ok(Startup::CancelRequest),
+        PROTOCOL_VERSION_3 => {
+            let mut params = Vec::new();
+            loop {
+                let name = body.cstr()?;
+                if name.is_empty() {
+                    break;
+                }
+
+                params.push((name, body.cstr()?));
+            }
+
+            Ok(Startup::Params(params))
+        }
+        version => Err(invalid_data(format!(
+            "unsupported protocol version: {}.{}",
+            version >> 16,
+            version & 0xffff
+        ))),
+    }
+}
+
+/// Reads the next tagged message, or `None` once the client closed the
+/// connection.
+pub fn read_message(reader: &mut impl Read) -> io::Result<Option<FrontendMessage>> {
+    let mut tag = [0; 1];
+    match reader.read_exact(&mut tag) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+
+    let body = read_body(reader)?;
+    let mut body = Body::new(&body);
+
+    let message = match tag[0] {
+        b'Q' => FrontendMessage::Query(body.cstr()?),
+        b'P' => {
+            let name = body.cstr()?;
+            let query = body.cstr()?;
+            let param_types = (0..body.i16()?)
+                .map(|_| body.i32().map(|oid| oid as u32))
+                .collect::<io::Result<_>>()?;
+
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            }
+        }
+        b'B' => {
+            let portal = body.cstr()?;
+            let statement = body.cstr()?;
+            let param_formats = (0..body.i16()?)
+                .map(|_| body.i16())
+                .collect::<io::Result<_>>()?;
+            let params = (0..body.i16()?)
+                .map(|_| match body.i32()? {
+                    -1 => Ok(None),
+                    len => body.bytes(len).map(|bytes| Some(bytes.to_vec())),
+                })
+                .collect::<io::Result<_>>()?;
+            let result_formats = (0..body.i16()?)
+                .map(|_| body.i16())
+                .collect::<io::Result<_>>()?;
+
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            }
+        }
+        b'D' => FrontendMessage::Describe {
+            target: body.target()?,
+            name: body.cstr()?,
+        },
+        b'E' => FrontendMessage::Execute {
+            portal: body.cstr()?,
+            max_rows: body.i32()?,
+        },
+        b'C' => FrontendMessage::Close {
+            target: body.target()?,
+            name: body.cstr()?,
+        },
+        b'S' => FrontendMessage::Sync,
+        b'H' => FrontendMessage::Flush,
+        b'X' => FrontendMessage::Terminate,
+        tag => FrontendMessage::Unsupported(tag),
+    };
+
+    Ok(Some(message))
+}
+
+struct Body<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Body<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn bytes(&mut self, len: i32) -> io::Result<&'a [u8]> {
+        let end = usize::try_from(len)
+            .ok()
+            .and_then(|len| self.pos.checked_add(len))
+            .filter(|end| *end <= self.buf.len())
+            .ok_or_else(|| invalid_data("message is shorter than its fields"))?;
+        let bytes = &self.buf[self.pos..end];
+        self.pos = end;
+
+        Ok(bytes)
+    }
+
+    fn i16(&mut self) -> io::Result<i16> {
+        let bytes = self.bytes(2)?;
+
+        Ok(i16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn i32(&mut self) -> io::Result<i32> {
+        let bytes = self.bytes(4)?;
+
+        Ok(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn cstr(&mut self) -> io::Result<String> {
+        let rest = &self.buf[self.pos..];
+        let len = rest
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or_else(|| invalid_data("string is not null-terminated"))?;
+        let text = String::from_utf8(rest[..len].to_vec())
+            .map_err(|_| invalid_data("string is not valid UTF-8"))?;
+        self.pos += len + 1;
+
+        Ok(text)
+    }
+
+    fn target(&mut self) -> io::Result<Target> {
+        match self.bytes(1)? {
+            b"S" => Ok(Target::Statement),
+            b"P" => Ok(Target::Portal),
+            target => Err(invalid_data(format!(
+                "invalid describe target: {}",
+                char::from(target[0])
+            ))),
+        }
+    }
+}
+
+impl BackendMessage {
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        let mut body = Vec::new();
+        let tag = self.encode(&mut body);
+        let len = i32::try_from(body.len() + 4)
+            .map_err(|_| invalid_data("message is too large to send"))?;
+
+        writer.write_all(&[tag])?;
+        writer.write_all(&len.to_be_bytes())?;
+        writer.write_all(&body)
+    }
+
+    fn encode(&self, body: &mut Vec<u8>) -> u8 {
+        fn put_cstr(body: &mut Vec<u8>, text: &str) {
+            body.extend_from_slice(text.as_bytes());
+            body.push(0);
+        }
+
+        match self {
+            BackendMessage::AuthenticationOk => {
+                body.extend_from_slice(&0_i32.to_be_bytes());
+                b'R'
+            }
+            BackendMessage::ParameterStatus { name, value } => {
+                put_cstr(body, name);
+                put_cstr(body, value);
+                b'S'
+            }
+            BackendMessage::BackendKeyData {
+                process_id,
+                secret_key,
+            } => {
+                body.extend_from_slice(&process_id.to_be_bytes());
+                body.extend_from_slice(&secret_key.to_be_bytes());
+                b'K'
+            }
+            BackendMessage::ReadyForQuery(status) => {
+                body.push(match status {
+                    TransactionStatus::Idle => b'I',
+                    TransactionStatus::InTransaction => b'T',
+                });
+                b'Z'
+            }
+            BackendMessage::RowDescription(fields) => {
+                body.extend_from_slice(&(fields.len() as i16).to_be_bytes());
+                for field in fields {
+                    put_cstr(body, &field.name);
+                    body.extend_from_slice(&0_i32.to_be_bytes());
+                    body.extend_from_slice(&0_i16.to_be_bytes());
+                    body.extend_from_slice(&field.type_oid.to_be_bytes());
+                    body.extend_from_slice(&field.type_size.to_be_bytes());
+                    body.extend_from_slice(&(-1_i32).to_be_bytes());
+                    body.extend_from_slice(&field.format.to_be_bytes());
+                }
+                b'T'
+            }
+            BackendMessage::DataRow(values) => {
+                body.extend_from_slice(&(values.len() as i16).to_be_bytes());
+                for value in values {
+                    match value {
+                        Some(value) => {
+                            body.extend_from_slice(&(value.len() as i32).to_be_bytes());
+                            body.extend_from_slice(value);
+                        }
+                        None => body.extend_from_slice(&(-1_i32).to_be_bytes()),
+                    }
+                }
+                b'D'
+            }
+            BackendMessage::CommandComplete(tag) => {
+                put_cstr(body, tag);
+                b'C'
+            }
+            BackendMessage::EmptyQueryResponse => b'I',
+            BackendMessage::ErrorResponse { code, message } => {
+                for (field, value) in [(b'S', "ERROR"), (b'V', "ERROR"), (b'C', code)] {
+                    body.push(field);
+                    put_cstr(body, value);
+                }
+                body.push(b'M');
+                put_cstr(body, message);
+                body.push(0);
+                b'E'
+            }
+            BackendMessage::ParseComplete => b'1',
+            BackendMessage::BindComplete => b'2',
+            BackendMessage::CloseComplete => b'3',
+            BackendMessage::NoData => b'n',
+            BackendMessage::ParameterDescription(types) => {
+                body.extend_from_slice(&(types.len() as i16).to_be_bytes());
+                for oid in types {
+                    body.extend_from_slice(&oid.to_be_bytes());
+                }
+                b't'
+            }
+            BackendMessage::PortalSuspended => b's',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{
+            BackendMessage, FrontendMessage, Startup, Target, TransactionStatus, read_message,
+            read_startup,
+        },
+        std::io::Cursor,
+    };
+
+    fn frame(tag: u8, body: &[u8]) -> Vec<u8> {
+        let mut message = vec![tag];
+        message.extend_from_slice(&(body.len() as i32 + 4).to_be_bytes());
+        message.extend_from_slice(body);
+        message
+    }
+
+    #[test]
+    fn read_startup_packets() {
+        let mut packet = 8_i32.to_be_bytes().to_vec();
+        packet.extend_from_slice(&80_877_103_i32.to_be_bytes());
+        assert_eq!(
+            read_startup(&mut Cursor::new(packet)).unwrap(),
+            Startup::SslRequest
+        );
+
+        let mut body = 196_608_i32.to_be_bytes().to_vec();
+        body.extend_from_slice(b"user\0glue\0database\0main\0\0");
+        let mut packet = (body.len() as i32 + 4).to_be_bytes().to_vec();
+        packet.extend_from_slice(&body);
+        assert_eq!(
+            read_startup(&mut Cursor::new(packet)).unwrap(),
+            Startup::Params(vec![
+                ("user".to_owned(), "glue".to_owned()),
+                ("database".to_owned(), "main".to_owned()),
+            ])
+        );
+    }
+
+    #[test]
+    fn read_frontend_messages() {
+        let mut bind = b"portal\0stmt\0".to_vec();
+        bind.extend_from_slice(&1_i16.to_be_bytes());
+        bind.extend_from_slice(&1_i16.to_be_bytes());
+        bind.extend_from_slice(&2_i16.to_be_bytes());
+        bind.extend_from_slice(&8_i32.to_be_bytes());
+        bind.extend_from_slice(&7_i64.to_be_bytes());
+        bind.extend_from_slice(&(-1_i32).to_be_bytes());
+        bind.extend_from_slice(&0_i16.to_be_bytes());
+
+        let mut stream = frame(b'Q', b"SELECT 1;\0");
+        stream.extend(frame(b'B', &bind));
+        stream.extend(frame(b'D', b"Sstmt\0"));
+        stream.extend(frame(b'S', b""));
+        let mut stream = Cursor::new(stream);
+
+        assert_eq!(
+            read_message(&mut stream).unwrap(),
+            Some(FrontendMessage::Query("SELECT 1;".to_owned()))
+        );
+        assert_eq!(
+            read_message(&mut stream).unwrap(),
+            Some(FrontendMessage::Bind {
+                portal: "portal".to_owned(),
+                statement: "stmt".to_owned(),
+                param_formats: vec![1],
+                params: vec![Some(7_i64.to_be_bytes().to_vec()), None],
+                result_formats: Vec::new(),
+            })
+        );
+        assert_eq!(
+            read_message(&mut stream).unwrap(),
+            Some(FrontendMessage::Describe {
+                target: Target::Statement,
+                name: "stmt".to_owned(),
+            })
+        );
+        assert_eq!(
+            read_message(&mut stream).unwrap(),
+            Some(FrontendMessage::Sync)
+        );
+        assert_eq!(read_message(&mut stream).unwrap(), None);
+
+        let truncated = frame(b'E', b"portal\0");
+        assert!(read_message(&mut Cursor::new(truncated)).is_err());
+    }
+
+    #[test]
+    fn write_backend_messages() {
+        let mut buf = Vec::new();
+        BackendMessage::ReadyForQuery(TransactionStatus::InTransaction)
+            .write_to(&mut buf)
+            .unwrap();
+        BackendMessage::DataRow(vec![Some(b"1".to_vec()), None])
+            .write_to(&mut buf)
+            .unwrap();
+
+        let mut expected = frame(b'Z', b"T");
+        let mut row = 2_i16.to_be_bytes().to_vec();
+        row.extend_from_slice(&1_i32.to_be_bytes());
+        row.push(b'1');
+        row.extend_from_slice(&(-1_i32).to_be_bytes());
+        expected.extend(frame(b'D', &row));
+
+        assert_eq!(buf, expected);
+    }
+}
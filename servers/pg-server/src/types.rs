@@ -0,0 +1,648 @@
+use {
+    crate::error::PgError,
+    gluesql_core::{
+        ast::DataType,
+        chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike},
+        data::Value,
+        translate::{IntoParamLiteral, ParamLiteral},
+    },
+    rust_decimal::Decimal,
+    std::{
+        net::{IpAddr, Ipv4Addr, Ipv6Addr},
+        str::FromStr,
+    },
+};
+
+const BOOL_OID: u32 = 16;
+const BYTEA_OID: u32 = 17;
+const NAME_OID: u32 = 19;
+const INT8_OID: u32 = 20;
+const INT2_OID: u32 = 21;
+const INT4_OID: u32 = 23;
+const TEXT_OID: u32 = 25;
+const JSON_OID: u32 = 114;
+const CIDR_OID: u32 = 650;
+const FLOAT4_OID: u32 = 700;
+const FLOAT8_OID: u32 = 701;
+/// Type of a parameter whose type the statement does not tell.
+pub const UNKNOWN_OID: u32 = 705;
+const INET_OID: u32 = 869;
+const BPCHAR_OID: u32 = 1042;
+const VARCHAR_OID: u32 = 1043;
+const DATE_OID: u32 = 1082;
+const TIME_OID: u32 = 1083;
+const TIMESTAMP_OID: u32 = 1114;
+const TIMESTAMPTZ_OID: u32 = 1184;
+const NUMERIC_OID: u32 = 1700;
+const UUID_OID: u32 = 2950;
+
+const NUMERIC_NEGATIVE: u16 = 0x4000;
+const NUMERIC_NAN: u16 = 0xC000;
+const PGSQL_AF_INET: u8 = 2;
+const PGSQL_AF_INET6: u8 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Binary,
+}
+
+impl Format {
+    pub fn from_code(code: i16) -> Result<Self, PgError> {
+        match code {
+            0 => Ok(Format::Text),
+            1 => Ok(Format::Binary),
+            code => Err(PgError::protocol(format!("invalid format code: {code}"))),
+        }
+    }
+
+    pub fn code(self) -> i16 {
+        match self {
+            Format::Text => 0,
+            Format::Binary => 1,
+        }
+    }
+
+    /// Expands the format codes of a Bind message to one format per item;
+    /// none means text for all, a single code applies to all.
+    pub fn resolve(codes: &[i16], len: usize) -> Result<Vec<Self>, PgError> {
+        match codes {
+            [] => Ok(vec![Format::Text; len]),
+            [code] => Ok(vec![Format::from_code(*code)?; len]),
+            codes if codes.len() == len => codes.iter().copied().map(Format::from_code).collect(),
+            codes => Err(PgError::protocol(format!(
+                "expected {len} format codes, got {}",
+                codes.len()
+            ))),
+        }
+    }
+}
+
+/// Postgres type a `GlueSQL` value is sent as. Values without a Postgres
+/// counterpart, such as intervals and geometries, are sent as text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgType {
+    Bool,
+    Int2,
+    Int4,
+    Int8,
+    Float4,
+    Float8,
+    Numeric,
+    Text,
+    Bytea,
+    Date,
+    Time,
+    Timestamp,
+    Uuid,
+    Inet,
+    Json,
+}
+
+impl PgType {
+    pub fn oid(self) -> u32 {
+        match self {
+            PgType::Bool => BOOL_OID,
+            PgType::Int2 => INT2_OID,
+            PgType::Int4 => INT4_OID,
+            PgType::Int8 => INT8_OID,
+            PgType::Float4 => FLOAT4_OID,
+            PgType::Float8 => FLOAT8_OID,
+            PgType::Numeric => NUMERIC_OID,
+            PgType::Text => TEXT_OID,
+            PgType::Bytea => BYTEA_OID,
+            PgType::Date => DATE_OID,
+            PgType::Time => TIME_OID,
+            PgType::Timestamp => TIMESTAMP_OID,
+            PgType::Uuid => UUID_OID,
+            PgType::Inet => INET_OID,
+            PgType::Json => JSON_OID,
+        }
+    }
+
+    /// `typlen` of the type, `-1` for variable length types.
+    pub fn size(self) -> i16 {
+        match self {
+            PgType::Bool => 1,
+            PgType::Int2 => 2,
+            PgType::Int4 | PgType::Float4 | PgType::Date => 4,
+            PgType::Int8 | PgType::Float8 | PgType::Time | PgType::Timestamp => 8,
+            PgType::Uuid => 16,
+            PgType::Numeric | PgType::Text | PgType::Bytea | PgType::Inet | PgType::Json => -1,
+        }
+    }
+
+    pub fn from_oid(oid: u32) -> Option<Self> {
+        Some(match oid {
+            BOOL_OID => PgType::Bool,
+            INT2_OID => PgType::Int2,
+            INT4_OID => PgType::Int4,
+            INT8_OID => PgType::Int8,
+            FLOAT4_OID => PgType::Float4,
+            FLOAT8_OID => PgType::Float8,
+            NUMERIC_OID => PgType::Numeric,
+            TEXT_OID | VARCHAR_OID | BPCHAR_OID | NAME_OID => PgType::Text,
+            BYTEA_OID => PgType::Bytea,
+            DATE_OID => PgType::Date,
+            TIME_OID => PgType::Time,
+            TIMESTAMP_OID | TIMESTAMPTZ_OID => PgType::Timestamp,
+            UUID_OID => PgType::Uuid,
+            INET_OID | CIDR_OID => PgType::Inet,
+            JSON_OID => PgType::Json,
+            _ => return None,
+        })
+    }
+
+    pub fn of_value(value: &Value) -> Option<Self> {
+        Some(match value {
+            Value::Null => return None,
+            Value::Bool(_) => PgType::Bool,
+            Value::I8(_) | Value::I16(_) | Value::U8(_) => PgType::Int2,
+            Value::I32(_) | Value::U16(_) => PgType::Int4,
+            Value::I64(_) | Value::U32(_) => PgType::Int8,
+            Value::I128(_) | Value::U64(_) | Value::U128(_) | Value::Decimal(_) => PgType::Numeric,
+            Value::F32(_) => PgType::Float4,
+            Value::F64(_) => PgType::Float8,
+            Value::Str(_)
+            | Value::Interval(_)
+            | Value::Point(_)
+            | Value::LineString(_)
+            | Value::Polygon(_)
+            | Value::MultiPoint(_) => PgType::Text,
+            Value::Bytea(_) => PgType::Bytea,
+            Value::Inet(_) => PgType::Inet,
+            Value::Date(_) => PgType::Date,
+            Value::Timestamp(_) => PgType::Timestamp,
+            Value::Time(_) => PgType::Time,
+            Value::Uuid(_) => PgType::Uuid,
+            Value::Map(_) | Value::List(_) => PgType::Json,
+        })
+    }
+
+    pub fn of_data_type(data_type: &DataType) -> Self {
+        match data_type {
+            DataType::Boolean => PgType::Bool,
+            DataType::Int8 | DataType::Int16 | DataType::Uint8 => PgType::Int2,
+            DataType::Int32 | DataType::Uint16 => PgType::Int4,
+            DataType::Int | DataType::Uint32 => PgType::Int8,
+            DataType::Int128 | DataType::Uint64 | DataType::Uint128 | DataType::Decimal => {
+                PgType::Numeric
+            }
+            DataType::Float32 => PgType::Float4,
+            DataType::Float => PgType::Float8,
+            DataType::Text
+            | DataType::Interval
+            | DataType::Point
+            | DataType::LineString
+            | DataType::Polygon
+            | DataType::MultiPoint => PgType::Text,
+            DataType::Bytea => PgType::Bytea,
+            DataType::Inet => PgType::Inet,
+            DataType::Date => PgType::Date,
+            DataType::Timestamp => PgType::Timestamp,
+            DataType::Time => PgType::Time,
+            DataType::Uuid => PgType::Uuid,
+            DataType::Map | DataType::List => PgType::Json,
+        }
+    }
+
+    /// Type a value is cast to before its binary encoding, when the value
+    /// does not already match the described column type.
+    fn data_type(self) -> Option<DataType> {
+        Some(match self {
+            PgType::Bool => DataType::Boolean,
+            PgType::Int2 => DataType::Int16,
+            PgType::Int4 => DataType::Int32,
+            PgType::Int8 => DataType::Int,
+            PgType::Float4 => DataType::Float32,
+            PgType::Float8 => DataType::Float,
+            PgType::Numeric => DataType::Decimal,
+            PgType::Bytea => DataType::Bytea,
+            PgType::Date => DataType::Date,
+            PgType::Time => DataType::Time,
+            PgType::Timestamp => DataType::Timestamp,
+            PgType::Uuid => DataType::Uuid,
+            PgType::Inet => DataType::Inet,
+            PgType::Text | PgType::Json => return None,
+        })
+    }
+
+    /// Encodes `value` for a column described as `self`.
+    pub fn encode(self, value: &Value, format: Format) -> Result<Option<Vec<u8>>, PgError> {
+        if matches!(value, Value::Null) {
+            return Ok(None);
+        }
+
+        match format {
+            Format::Text => Ok(Some(to_text(value).into_bytes())),
+            Format::Binary => {
+                if let Some(bytes) = self.encode_binary(value) {
+                    return Ok(Some(bytes));
+                }
+
+                self.data_type()
+                    .and_then(|data_type| value.cast(&data_type).ok())
+                    .and_then(|value| self.encode_binary(&value))
+                    .map(Some)
+                    .ok_or_else(|| {
+                        PgError::datatype_mismatch(format!(
+                            "cannot send {value:?} as {self:?} in binary format"
+                        ))
+                    })
+            }
+        }
+    }
+
+    fn encode_binary(self, value: &Value) -> Option<Vec<u8>> {
+        Some(match (self, value) {
+            (PgType::Text | PgType::Json, value) => to_text(value).into_bytes(),
+            (PgType::Bool, Value::Bool(v)) => vec![u8::from(*v)],
+            (PgType::Int2, Value::I16(v)) => v.to_be_bytes().to_vec(),
+            (PgType::Int4, Value::I32(v)) => v.to_be_bytes().to_vec(),
+            (PgType::Int8, Value::I64(v)) => v.to_be_bytes().to_vec(),
+            (PgType::Float4, Value::F32(v)) => v.to_be_bytes().to_vec(),
+            (PgType::Float8, Value::F64(v)) => v.to_be_bytes().to_vec(),
+            (PgType::Numeric, Value::Decimal(v)) => encode_numeric(*v),
+            (PgType::Bytea, Value::Bytea(v)) => v.clone(),
+            (PgType::Date, Value::Date(v)) => {
+                let days = v.signed_duration_since(pg_epoch().date()).num_days();
+
+                i32::try_from(days).ok()?.to_be_bytes().to_vec()
+            }
+            (PgType::Time, Value::Time(v)) => {
+                let micros = i64::from(v.num_seconds_from_midnight()) * 1_000_000
+                    + i64::from(v.nanosecond() / 1_000);
+
+                micros.to_be_bytes().to_vec()
+            }
+            (PgType::Timestamp, Value::Timestamp(v)) => v
+                .signed_duration_since(pg_epoch())
+                .num_microseconds()?
+                .to_be_bytes()
+                .to_vec(),
+            (PgType::Uuid, Value::Uuid(v)) => v.to_be_bytes().to_vec(),
+            (PgType::Inet, Value::Inet(v)) => encode_inet(*v),
+            _ => return None,
+        })
+    }
+}
+
+fn pg_epoch() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2000, 1, 1)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .expect("2000-01-01 is a valid date")
+}
+
+/// Text form of a value, as Postgres prints it where the two differ.
+pub fn to_text(value: &Value) -> String {
+    fn float(value: f64) -> String {
+        match value {
+            f64::INFINITY => "Infinity".to_owned(),
+            f64::NEG_INFINITY => "-Infinity".to_owned(),
+            value => value.to_string(),
+        }
+    }
+
+    match value {
+        Value::Bool(v) => (if *v { "t" } else { "f" }).to_owned(),
+        Value::Bytea(v) => format!("\\x{}", hex::encode(v)),
+        Value::F32(v) if v.is_infinite() => float(f64::from(*v)),
+        Value::F64(v) => float(*v),
+        value => String::from(value),
+    }
+}
+
+/// Decodes a bound parameter of the type declared in the Parse message.
+/// Text parameters of undeclared or loosely typed kinds are bound untyped,
+/// so `GlueSQL` converts them by context like a quoted literal.
+pub fn decode_param(oid: u32, format: Format, bytes: &[u8]) -> Result<ParamLiteral, PgError> {
+    let invalid = |what: &str| {
+        PgError::invalid_param(format!(
+            "invalid {what} parameter: {}",
+            String::from_utf8_lossy(bytes)
+        ))
+    };
+    let pg_type = PgType::from_oid(oid);
+
+    if format == Format::Text {
+        let text = std::str::from_utf8(bytes).map_err(|_| invalid("UTF-8"))?;
+        let value = match pg_type {
+            Some(PgType::Bool) => Value::Bool(parse_bool(text).ok_or_else(|| invalid("boolean"))?),
+            Some(PgType::Int2) => Value::I16(text.trim().parse().map_err(|_| invalid("int2"))?),
+            Some(PgType::Int4) => Value::I32(text.trim().parse().map_err(|_| invalid("int4"))?),
+            Some(PgType::Int8) => Value::I64(text.trim().parse().map_err(|_| invalid("int8"))?),
+            Some(PgType::Float4) => {
+                Value::F32(parse_float(text).ok_or_else(|| invalid("float4"))? as f32)
+            }
+            Some(PgType::Float8) => Value::F64(parse_float(text).ok_or_else(|| invalid("float8"))?),
+            Some(PgType::Text) => Value::Str(text.to_owned()),
+            Some(PgType::Bytea) => Value::Bytea(match text.strip_prefix("\\x") {
+                Some(hex) => hex::decode(hex).map_err(|_| invalid("bytea"))?,
+                None => bytes.to_vec(),
+            }),
+            _ => return Ok(ParamLiteral::untyped(text)),
+        };
+
+        return Ok(value.into_param_literal());
+    }
+
+    let fixed = |len: usize, what: &str| {
+        if bytes.len() == len {
+            Ok(bytes)
+        } else {
+            Err(invalid(what))
+        }
+    };
+    let value = match pg_type {
+        Some(PgType::Bool) => Value::Bool(fixed(1, "boolean")?[0] != 0),
+        Some(PgType::Int2) => Value::I16(i16::from_be_bytes(array(fixed(2, "int2")?))),
+        Some(PgType::Int4) => Value::I32(i32::from_be_bytes(array(fixed(4, "int4")?))),
+        Some(PgType::Int8) => Value::I64(i64::from_be_bytes(array(fixed(8, "int8")?))),
+        Some(PgType::Float4) => Value::F32(f32::from_be_bytes(array(fixed(4, "float4")?))),
+        Some(PgType::Float8) => Value::F64(f64::from_be_bytes(array(fixed(8, "float8")?))),
+        Some(PgType::Numeric) => {
+            Value::Decimal(decode_numeric(bytes).ok_or_else(|| invalid("numeric"))?)
+        }
+        Some(PgType::Text) => Value::Str(
+            std::str::from_utf8(bytes)
+                .map_err(|_| invalid("text"))?
+                .to_owned(),
+        ),
+        Some(PgType::Bytea) => Value::Bytea(bytes.to_vec()),
+        Some(PgType::Date) => {
+            let days = i32::from_be_bytes(array(fixed(4, "date")?));
+            let date = pg_epoch()
+                .date()
+                .checked_add_signed(gluesql_core::chrono::Duration::days(i64::from(days)))
+                .ok_or_else(|| invalid("date"))?;
+
+            Value::Date(date)
+        }
+        Some(PgType::Time) => {
+            let micros = i64::from_be_bytes(array(fixed(8, "time")?));
+            let secs = u32::try_from(micros / 1_000_000).map_err(|_| invalid("time"))?;
+            let nanos = u32::try_from(micros % 1_000_000 * 1_000).map_err(|_| invalid("time"))?;
+            let time = NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos)
+                .ok_or_else(|| invalid("time"))?;
+
+            Value::Time(time)
+        }
+        Some(PgType::Timestamp) => {
+            let micros = i64::from_be_bytes(array(fixed(8, "timestamp")?));
+            let timestamp = pg_epoch()
+                .checked_add_signed(gluesql_core::chrono::Duration::microseconds(micros))
+                .ok_or_else(|| invalid("timestamp"))?;
+
+            Value::Timestamp(timestamp)
+        }
+        Some(PgType::Uuid) => Value::Uuid(u128::from_be_bytes(array(fixed(16, "uuid")?))),
+        Some(PgType::Inet) => Value::Inet(decode_inet(bytes).ok_or_else(|| invalid("inet"))?),
+        Some(PgType::Json) | None if matches!(oid, 0 | UNKNOWN_OID | JSON_OID) => {
+            let text = std::str::from_utf8(bytes).map_err(|_| invalid("UTF-8"))?;
+
+            return Ok(ParamLiteral::untyped(text));
+        }
+        Some(PgType::Json) | None => {
+            return Err(PgError::feature_not_supported(format!(
+                "binary parameters of type {oid} are not supported"
+            )));
+        }
+    };
+
+    Ok(value.into_param_literal())
+}
+
+fn array<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    bytes.try_into().expect("length is checked by the caller")
+}
+
+fn parse_bool(text: &str) -> Option<bool> {
+    match text.trim().to_ascii_lowercase().as_str() {
+        "t" | "true" | "y" | "yes" | "on" | "1" => Some(true),
+        "f" | "false" | "n" | "no" | "off" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_float(text: &str) -> Option<f64> {
+    match text.trim().to_ascii_lowercase().as_str() {
+        "infinity" | "inf" => Some(f64::INFINITY),
+        "-infinity" | "-inf" => Some(f64::NEG_INFINITY),
+        text => text.parse().ok(),
+    }
+}
+
+/// Encodes a decimal as base-10000 digits with a weight, the binary layout
+/// of Postgres `numeric`.
+fn encode_numeric(value: Decimal) -> Vec<u8> {
+    let scale = value.scale();
+    let text = value.abs().to_string();
+    let (int_part, frac_part) = text.split_once('.').unwrap_or((&text, ""));
+
+    let int_pad = (4 - int_part.len() % 4) % 4;
+    let int_part = format!("{}{int_part}", "0".repeat(int_pad));
+    let frac_pad = (4 - frac_part.len() % 4) % 4;
+    let frac_part = format!("{frac_part}{}", "0".repeat(frac_pad));
+
+    let group = |digits: &str| -> Vec<i16> {
+        digits
+            .as_bytes()
+            .chunks(4)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0_i16, |acc, digit| acc * 10 + i16::from(digit - b'0'))
+            })
+            .collect()
+    };
+    let int_groups = group(&int_part);
+    let mut weight = int_groups.len() as i16 - 1;
+    let mut digits = int_groups;
+    digits.extend(group(&frac_part));
+
+    let leading = digits.iter().take_while(|digit| **digit == 0).count();
+    digits.drain(..leading);
+    weight -= leading as i16;
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        weight = 0;
+    }
+
+    let sign = if value.is_sign_negative() && !value.is_zero() {
+        NUMERIC_NEGATIVE
+    } else {
+        0
+    };
+
+    let mut bytes = Vec::with_capacity(8 + digits.len() * 2);
+    bytes.extend_from_slice(&(digits.len() as i16).to_be_bytes());
+    bytes.extend_from_slice(&weight.to_be_bytes());
+    bytes.extend_from_slice(&sign.to_be_bytes());
+    bytes.extend_from_slice(&(scale as u16).to_be_bytes());
+    for digit in digits {
+        bytes.extend_from_slice(&digit.to_be_bytes());
+    }
+
+    bytes
+}
+
+fn decode_numeric(bytes: &[u8]) -> Option<Decimal> {
+    let header = |index: usize| bytes.get(index..index + 2).map(|b| [b[0], b[1]]);
+    let ndigits = usize::try_from(i16::from_be_bytes(header(0)?)).ok()?;
+    let weight = i16::from_be_bytes(header(2)?);
+    let sign = u16::from_be_bytes(header(4)?);
+    let scale = u16::from_be_bytes(header(6)?);
+    if sign == NUMERIC_NAN || bytes.len() != 8 + ndigits * 2 {
+        return None;
+    }
+
+    let mut text = (0..ndigits)
+        .map(|index| header(8 + index * 2).map(|digit| format!("{:04}", i16::from_be_bytes(digit))))
+        .collect::<Option<String>>()?;
+
+    // digits are base 10000, so the decimal point sits 4 * (weight + 1)
+    // characters into the padded digit string
+    let point = 4 * (i32::from(weight) + 1);
+    let text = if point <= 0 {
+        format!("0.{}{text}", "0".repeat(point.unsigned_abs() as usize))
+    } else {
+        let point = point as usize;
+        if text.len() < point {
+            text.push_str(&"0".repeat(point - text.len()));
+        }
+        let (int_part, frac_part) = text.split_at(point);
+
+        format!("{int_part}.{frac_part}0")
+    };
+
+    let mut value = Decimal::from_str(&text).ok()?;
+    value.rescale(u32::from(scale));
+    value.set_sign_negative(sign == NUMERIC_NEGATIVE);
+
+    Some(value)
+}
+
+fn encode_inet(addr: IpAddr) -> Vec<u8> {
+    let (family, bits, octets) = match addr {
+        IpAddr::V4(addr) => (PGSQL_AF_INET, 32, addr.octets().to_vec()),
+        IpAddr::V6(addr) => (PGSQL_AF_INET6, 128, addr.octets().to_vec()),
+    };
+
+    let mut bytes = vec![family, bits, 0, octets.len() as u8];
+    bytes.extend(octets);
+    bytes
+}
+
+fn decode_inet(bytes: &[u8]) -> Option<IpAddr> {
+    match bytes {
+        [PGSQL_AF_INET, _, _, 4, octets @ ..] => {
+            let octets: [u8; 4] = octets.try_into().ok()?;
+
+            Some(IpAddr::V4(Ipv4Addr::from(octets)))
+        }
+        [PGSQL_AF_INET6, _, _, 16, octets @ ..] => {
+            let octets: [u8; 16] = octets.try_into().ok()?;
+
+            Some(IpAddr::V6(Ipv6Addr::from(octets)))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{Format, PgType, decode_numeric, decode_param, encode_numeric, to_text},
+        gluesql_core::{
+            ast::{Expr, Literal},
+            chrono::NaiveDate,
+            data::Value,
+        },
+        rust_decimal::Decimal,
+        std::str::FromStr,
+    };
+
+    #[test]
+    fn numeric_round_trip() {
+        for text in [
+            "0",
+            "1",
+            "-1",
+            "12345.678",
+            "0.0012",
+            "10000",
+            "-98765432.10",
+        ] {
+            let value = Decimal::from_str(text).unwrap();
+            let decoded = decode_numeric(&encode_numeric(value)).unwrap();
+
+            assert_eq!(decoded, value, "{text}");
+            assert_eq!(decoded.scale(), value.scale(), "{text}");
+        }
+
+        // 12345.678 is [1, 2345, 6780] with weight 1 and scale 3
+        let bytes = encode_numeric(Decimal::from_str("12345.678").unwrap());
+        assert_eq!(
+            bytes,
+            [0, 3, 0, 1, 0, 0, 0, 3, 0, 1, 0x09, 0x29, 0x1A, 0x7C]
+        );
+    }
+
+    #[test]
+    fn encode_values() {
+        let date = NaiveDate::from_ymd_opt(2000, 1, 2).unwrap();
+        assert_eq!(
+            PgType::Date
+                .encode(&Value::Date(date), Format::Binary)
+                .unwrap(),
+            Some(1_i32.to_be_bytes().to_vec())
+        );
+        assert_eq!(
+            PgType::Int8.encode(&Value::I32(7), Format::Binary).unwrap(),
+            Some(7_i64.to_be_bytes().to_vec())
+        );
+        assert_eq!(
+            PgType::Int8.encode(&Value::Null, Format::Binary).unwrap(),
+            None
+        );
+        assert!(
+            PgType::Int8
+                .encode(&Value::Str("glue".to_owned()), Format::Binary)
+                .is_err()
+        );
+
+        assert_eq!(to_text(&Value::Bool(true)), "t");
+        assert_eq!(to_text(&Value::Bytea(vec![0x12, 0xAB])), "\\x12ab");
+        assert_eq!(to_text(&Value::F64(f64::NEG_INFINITY)), "-Infinity");
+    }
+
+    #[test]
+    fn decode_params() {
+        let expr =
+            |oid, format, bytes: &[u8]| decode_param(oid, format, bytes).unwrap().into_expr();
+
+        assert_eq!(expr(20, Format::Text, b"42"), Expr::Value(Value::I64(42)));
+        assert_eq!(
+            expr(20, Format::Binary, &42_i64.to_be_bytes()),
+            Expr::Value(Value::I64(42))
+        );
+        assert_eq!(
+            expr(0, Format::Text, b"42"),
+            Expr::Literal(Literal::QuotedString("42".to_owned()))
+        );
+        assert_eq!(
+            expr(1082, Format::Binary, &(-1_i32).to_be_bytes()),
+            Expr::Value(Value::Date(NaiveDate::from_ymd_opt(1999, 12, 31).unwrap()))
+        );
+        assert_eq!(
+            expr(17, Format::Text, b"\\x0102"),
+            Expr::Value(Value::Bytea(vec![1, 2]))
+        );
+
+        assert!(decode_param(23, Format::Text, b"glue").is_err());
+        assert!(decode_param(23, Format::Binary, &[0, 1]).is_err());
+        assert!(decode_param(600, Format::Binary, &[0; 16]).is_err());
+    }
+}
@@ -0,0 +1,293 @@
+use {
+    gluesql_pg_server::PgServer,
+    gluesql_shared_memory_storage::SharedMemoryStorage,
+    gluesql_sled_storage::SledStorage,
+    postgres::{Client, NoTls, SimpleQueryMessage, types::Type},
+    std::{fs, thread, time::Duration},
+};
+
+fn spawn<T>(storage: T) -> String
+where
+    T: gluesql_core::store::GStore
+        + gluesql_core::store::GStoreMut
+        + gluesql_core::store::Planner
+        + Clone
+        + Send
+        + 'static,
+{
+    let server = PgServer::bind("127.0.0.1:0", storage).unwrap();
+    let port = server.local_addr().unwrap().port();
+    thread::spawn(move || server.run());
+
+    format!("host=127.0.0.1 port={port} user=glue")
+}
+
+#[test]
+fn simple_query() {
+    let mut client = Client::connect(&spawn(SharedMemoryStorage::new()), NoTls).unwrap();
+
+    client
+        .batch_execute(
+            "
+            SET application_name = 'test';
+            CREATE TABLE Item (id INTEGER, name TEXT, price FLOAT NULL);
+            INSERT INTO Item VALUES (1, 'Glue', 1.5), (2, 'SQL', NULL);
+            ",
+        )
+        .unwrap();
+
+    let messages = client
+        .simple_query(
+            "SELECT id, name, price FROM Item ORDER BY id; DELETE FROM Item WHERE id = 2;",
+        )
+        .unwrap();
+    let rows = messages
+        .iter()
+        .filter_map(|message| match message {
+            SimpleQueryMessage::Row(row) => Some((
+                row.get("id").map(str::to_owned),
+                row.get("name").map(str::to_owned),
+                row.get("price").map(str::to_owned),
+            )),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        vec![
+            (
+                Some("1".to_owned()),
+                Some("Glue".to_owned()),
+                Some("1.5".to_owned())
+            ),
+            (Some("2".to_owned()), Some("SQL".to_owned()), None),
+        ]
+    );
+    assert!(matches!(
+        messages.last(),
+        Some(SimpleQueryMessage::CommandComplete(1))
+    ));
+
+    let error = client.simple_query("SELECT * FROM Nothing;").unwrap_err();
+    assert!(error.as_db_error().is_some());
+    assert!(matches!(
+        client.simple_query("").unwrap().as_slice(),
+        [SimpleQueryMessage::CommandComplete(0)]
+    ));
+}
+
+#[test]
+fn extended_query() {
+    let mut client = Client::connect(&spawn(SharedMemoryStorage::new()), NoTls).unwrap();
+    client
+        .batch_execute(
+            "
+            CREATE TABLE Item (id INTEGER, name TEXT, ok BOOLEAN, made DATE);
+            INSERT INTO Item VALUES
+                (1, 'Glue', TRUE, '2024-01-02'),
+                (2, 'SQL', FALSE, '2024-03-04');
+            ",
+        )
+        .unwrap();
+
+    let insert = client
+        .prepare_typed(
+            "INSERT INTO Item VALUES ($1, $2, $3, $4)",
+            &[Type::INT8, Type::TEXT, Type::BOOL, Type::UNKNOWN],
+        )
+        .unwrap();
+    let inserted = client
+        .execute(&insert, &[&3_i64, &"Rust", &true, &"2024-05-06"])
+        .unwrap();
+    assert_eq!(inserted, 1);
+
+    let rows = client
+        .query(
+            "SELECT id, name, ok, made FROM Item WHERE id > CAST($1 AS INTEGER) ORDER BY id",
+            &[&"1"],
+        )
+        .unwrap();
+    let columns = rows[0]
+        .columns()
+        .iter()
+        .map(|column| column.type_().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        columns,
+        vec![Type::INT8, Type::TEXT, Type::BOOL, Type::DATE]
+    );
+    let rows = rows
+        .iter()
+        .map(|row| {
+            (
+                row.get::<_, i64>(0),
+                row.get::<_, String>(1),
+                row.get::<_, bool>(2),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        vec![(2, "SQL".to_owned(), false), (3, "Rust".to_owned(), true)]
+    );
+
+    let statement = client
+        .prepare_typed(
+            "SELECT name FROM Item WHERE id = $1 OR ok = $2",
+            &[Type::INT8, Type::BOOL],
+        )
+        .unwrap();
+    let names = client
+        .query(&statement, &[&2_i64, &false])
+        .unwrap()
+        .iter()
+        .map(|row| row.get::<_, String>("name"))
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["SQL".to_owned()]);
+
+    let statement = client
+        .prepare_typed("SELECT name FROM Item WHERE made = $1", &[Type::UNKNOWN])
+        .unwrap();
+    let rows = client.query(&statement, &[&"2024-05-06"]).unwrap();
+    assert_eq!(rows.len(), 1);
+
+    let error = client
+        .prepare("SELECT 1; SELECT 2")
+        .err()
+        .and_then(|error| {
+            error
+                .as_db_error()
+                .map(|error| error.code().code().to_owned())
+        });
+    assert_eq!(error.as_deref(), Some("42601"));
+    assert_eq!(
+        client
+            .query_one("SELECT COUNT(*) FROM Item", &[])
+            .unwrap()
+            .get::<_, i64>(0),
+        3
+    );
+}
+
+#[test]
+fn describe_without_running() {
+    let mut client = Client::connect(&spawn(SharedMemoryStorage::new()), NoTls).unwrap();
+    client
+        .batch_execute(
+            "
+            CREATE TABLE Item (id INTEGER, name TEXT, price FLOAT NULL);
+            INSERT INTO Item VALUES (1, 'Glue', 1.5), (2, 'SQL', NULL);
+            ",
+        )
+        .unwrap();
+
+    let statement = client
+        .prepare("SELECT name FROM Item WHERE id = $1 OR name LIKE $2 LIMIT $3 OFFSET $4")
+        .unwrap();
+    assert_eq!(
+        statement.params(),
+        &[Type::INT8, Type::TEXT, Type::INT8, Type::INT8]
+    );
+    let statement = client.prepare("SELECT CAST($1 AS INTEGER) AS n").unwrap();
+    assert_eq!(statement.params(), &[Type::UNKNOWN]);
+    let statement = client
+        .prepare("UPDATE Item SET price = $1 WHERE id = $2")
+        .unwrap();
+    assert_eq!(statement.params(), &[Type::FLOAT8, Type::INT8]);
+
+    let rows = client
+        .query("SELECT * FROM Item LIMIT $1", &[&1_i64])
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+    let name = client
+        .query_one("SELECT name FROM Item WHERE id = $1", &[&2_i64])
+        .unwrap()
+        .get::<_, String>(0);
+    assert_eq!(name, "SQL");
+
+    let statement = client
+        .prepare(
+            "SELECT id * 2 AS double, UPPER(name) AS upper, price > 1 AS high,
+                AVG(price) AS average, COUNT(*) AS count
+            FROM Item GROUP BY id, name, price",
+        )
+        .unwrap();
+    let columns = statement
+        .columns()
+        .iter()
+        .map(|column| column.type_().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        columns,
+        vec![Type::INT8, Type::TEXT, Type::BOOL, Type::FLOAT8, Type::INT8]
+    );
+
+    let statement = client
+        .prepare("SELECT id / (id - id) AS n FROM Item")
+        .unwrap();
+    assert_eq!(statement.columns()[0].type_(), &Type::INT8);
+    assert!(client.query(&statement, &[]).is_err());
+}
+
+#[test]
+fn transaction_per_connection() {
+    let path = "tmp/transaction_per_connection";
+    fs::remove_dir_all(path).unwrap_or(());
+    let config = spawn(SledStorage::new(path).unwrap());
+
+    let mut writer = Client::connect(&config, NoTls).unwrap();
+    let mut reader = Client::connect(&config, NoTls).unwrap();
+    writer
+        .batch_execute("CREATE TABLE Item (id INTEGER); INSERT INTO Item VALUES (1);")
+        .unwrap();
+
+    let count = |client: &mut Client| -> i64 {
+        client
+            .query_one("SELECT COUNT(*) FROM Item", &[])
+            .unwrap()
+            .get(0)
+    };
+
+    let mut transaction = writer.transaction().unwrap();
+    transaction
+        .execute("INSERT INTO Item VALUES (CAST($1 AS INTEGER))", &[&"2"])
+        .unwrap();
+    assert_eq!(count(&mut reader), 1);
+    assert!(reader.execute("INSERT INTO Item VALUES (3)", &[]).is_err());
+
+    transaction.commit().unwrap();
+    assert_eq!(count(&mut reader), 2);
+}
+
+#[test]
+fn disconnect_rolls_back_transaction() {
+    let path = "tmp/disconnect_rolls_back_transaction";
+    fs::remove_dir_all(path).unwrap_or(());
+    let config = spawn(SledStorage::new(path).unwrap());
+
+    let mut writer = Client::connect(&config, NoTls).unwrap();
+    writer
+        .batch_execute("CREATE TABLE Item (id INTEGER); BEGIN; INSERT INTO Item VALUES (1);")
+        .unwrap();
+    drop(writer);
+
+    // the server rolls back once it sees the disconnect, which releases the lock
+    let mut client = Client::connect(&config, NoTls).unwrap();
+    let inserted = (0..100).any(|_| {
+        let inserted = client.execute("INSERT INTO Item VALUES (2)", &[]).is_ok();
+        if !inserted {
+            thread::sleep(Duration::from_millis(20));
+        }
+
+        inserted
+    });
+    assert!(inserted);
+
+    let ids = client
+        .query("SELECT id FROM Item", &[])
+        .unwrap()
+        .iter()
+        .map(|row| row.get::<_, i64>(0))
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![2]);
+}
@@ -0,0 +1,94 @@
+#![deny(clippy::str_to_string)]
+
+mod store;
+mod store_mut;
+mod transaction;
+
+use gluesql_core::{
+    error::Result,
+    store::{Change, ChangeFeed, ChangeIter},
+};
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    Idle,
+    Transaction {
+        /// Whether the wrapped storage started a transaction of its own,
+        /// which has to be committed or rolled back along with this one.
+        inner: bool,
+    },
+}
+
+/// Wraps any storage and records the rows changed by committed transactions
+/// as a [`ChangeFeed`].
+///
+/// Changes are kept in memory. Rows appended to tables without a primary key
+/// are recorded without a key because the wrapped storage generates it.
+pub struct ChangeFeedStorage<T> {
+    pub storage: T,
+    state: State,
+    pending: Vec<Change>,
+    changes: Vec<Change>,
+    last_seq: u64,
+    last_txid: u64,
+}
+
+impl<T> ChangeFeedStorage<T> {
+    pub fn new(storage: T) -> Self {
+        Self {
+            storage,
+            state: State::Idle,
+            pending: Vec::new(),
+            changes: Vec::new(),
+            last_seq: 0,
+            last_txid: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.storage
+    }
+
+    fn record(&mut self, changes: impl IntoIterator<Item = Change>) {
+        self.pending.extend(changes);
+
+        if matches!(self.state, State::Idle) {
+            self.publish();
+        }
+    }
+
+    fn publish(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+
+        self.last_txid += 1;
+
+        for mut change in self.pending.drain(..) {
+            self.last_seq += 1;
+            change.seq = self.last_seq;
+            change.txid = self.last_txid;
+
+            self.changes.push(change);
+        }
+    }
+}
+
+impl<T> ChangeFeed for ChangeFeedStorage<T> {
+    fn scan_changes(&self, checkpoint: u64) -> Result<ChangeIter<'_>> {
+        let start = self
+            .changes
+            .partition_point(|change| change.seq <= checkpoint);
+
+        Ok(Box::new(self.changes[start..].iter().cloned().map(Ok)))
+    }
+
+    fn purge_changes(&mut self, checkpoint: u64) -> Result<()> {
+        let end = self
+            .changes
+            .partition_point(|change| change.seq <= checkpoint);
+        self.changes.drain(..end);
+
+        Ok(())
+    }
+}
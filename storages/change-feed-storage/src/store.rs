@@ -0,0 +1,157 @@
+use {
+    super::ChangeFeedStorage,
+    gluesql_core::{
+        ast::{ColumnDef, IndexOperator, OrderByExpr},
+        data::{
+            CustomFunction as StructCustomFunction, Key, Schema, Trigger as StructTrigger, Value,
+        },
+        error::Result,
+        executor::Referencing,
+        plan::StatementPlan,
+        store::{
+            AlterTable, CustomFunction, CustomFunctionMut, Index, IndexMut, MetaIter, Metadata,
+            Planner, RowIter, ScanPredicate, Store, StoreMut, Trigger, TriggerMut,
+        },
+    },
+};
+
+impl<T: Store> Store for ChangeFeedStorage<T> {
+    fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        self.storage.fetch_schema(table_name)
+    }
+
+    fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        self.storage.fetch_all_schemas()
+    }
+
+    fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<Vec<Value>>> {
+        self.storage.fetch_data(table_name, key)
+    }
+
+    fn scan_data<'a>(&'a self, table_name: &str) -> Result<RowIter<'a>> {
+        self.storage.scan_data(table_name)
+    }
+
+    fn scan_filtered_data<'a>(
+        &'a self,
+        table_name: &str,
+        predicates: &[ScanPredicate],
+        columns: Option<&[usize]>,
+    ) -> Result<RowIter<'a>> {
+        self.storage
+            .scan_filtered_data(table_name, predicates, columns)
+    }
+
+    fn fetch_referencings(&self, table_name: &str) -> Result<Vec<Referencing>> {
+        self.storage.fetch_referencings(table_name)
+    }
+}
+
+impl<T: Index> Index for ChangeFeedStorage<T> {
+    fn scan_indexed_data<'a>(
+        &'a self,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
+    ) -> Result<RowIter<'a>> {
+        self.storage
+            .scan_indexed_data(table_name, index_name, asc, cmp_value)
+    }
+}
+
+impl<T: IndexMut> IndexMut for ChangeFeedStorage<T> {
+    fn create_index(
+        &mut self,
+        table_name: &str,
+        index_name: &str,
+        column: &OrderByExpr,
+    ) -> Result<()> {
+        self.storage.create_index(table_name, index_name, column)
+    }
+
+    fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        self.storage.drop_index(table_name, index_name)
+    }
+}
+
+/// Schema changes are not part of the feed, so `ALTER TABLE` goes straight to
+/// the wrapped storage.
+impl<T: AlterTable> AlterTable for ChangeFeedStorage<T>
+where
+    Self: StoreMut,
+{
+    fn rename_schema(&mut self, table_name: &str, new_table_name: &str) -> Result<()> {
+        self.storage.rename_schema(table_name, new_table_name)
+    }
+
+    fn rename_column(
+        &mut self,
+        table_name: &str,
+        old_column_name: &str,
+        new_column_name: &str,
+    ) -> Result<()> {
+        self.storage
+            .rename_column(table_name, old_column_name, new_column_name)
+    }
+
+    fn add_column(&mut self, table_name: &str, column_def: &ColumnDef) -> Result<()> {
+        self.storage.add_column(table_name, column_def)
+    }
+
+    fn drop_column(&mut self, table_name: &str, column_name: &str, if_exists: bool) -> Result<()> {
+        self.storage.drop_column(table_name, column_name, if_exists)
+    }
+}
+
+impl<T: Metadata> Metadata for ChangeFeedStorage<T> {
+    fn scan_table_meta(&self) -> Result<MetaIter> {
+        self.storage.scan_table_meta()
+    }
+}
+
+impl<T: CustomFunction> CustomFunction for ChangeFeedStorage<T> {
+    fn fetch_function<'a>(&'a self, func_name: &str) -> Result<Option<&'a StructCustomFunction>> {
+        self.storage.fetch_function(func_name)
+    }
+
+    fn fetch_all_functions(&self) -> Result<Vec<&StructCustomFunction>> {
+        self.storage.fetch_all_functions()
+    }
+}
+
+impl<T: CustomFunctionMut> CustomFunctionMut for ChangeFeedStorage<T> {
+    fn insert_function(&mut self, func: StructCustomFunction) -> Result<()> {
+        self.storage.insert_function(func)
+    }
+
+    fn delete_function(&mut self, func_name: &str) -> Result<()> {
+        self.storage.delete_function(func_name)
+    }
+}
+
+impl<T: Trigger> Trigger for ChangeFeedStorage<T> {
+    fn fetch_triggers(&self, table_name: &str) -> Result<Vec<StructTrigger>> {
+        self.storage.fetch_triggers(table_name)
+    }
+
+    fn fetch_all_triggers(&self) -> Result<Vec<StructTrigger>> {
+        self.storage.fetch_all_triggers()
+    }
+}
+
+impl<T: TriggerMut> TriggerMut for ChangeFeedStorage<T> {
+    fn insert_trigger(&mut self, trigger: StructTrigger) -> Result<()> {
+        self.storage.insert_trigger(trigger)
+    }
+
+    fn delete_trigger(&mut self, table_name: &str, trigger_name: &str) -> Result<()> {
+        self.storage.delete_trigger(table_name, trigger_name)
+    }
+}
+
+impl<T: Planner> Planner for ChangeFeedStorage<T> {
+    fn plan(&self, statement: StatementPlan) -> Result<StatementPlan> {
+        self.storage.plan(statement)
+    }
+}
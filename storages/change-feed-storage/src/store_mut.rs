@@ -0,0 +1,87 @@
+use {
+    super::ChangeFeedStorage,
+    gluesql_core::{
+        data::{Key, Schema, Value},
+        error::Result,
+        store::{Change, Store, StoreMut},
+    },
+};
+
+fn change(
+    table_name: &str,
+    key: Option<Key>,
+    before: Option<Vec<Value>>,
+    after: Option<Vec<Value>>,
+) -> Change {
+    Change {
+        seq: 0,
+        txid: 0,
+        table_name: table_name.to_owned(),
+        key,
+        before,
+        after,
+    }
+}
+
+impl<T: Store + StoreMut> StoreMut for ChangeFeedStorage<T> {
+    fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        self.storage.insert_schema(schema)
+    }
+
+    fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        self.storage.delete_schema(table_name)
+    }
+
+    fn append_data(&mut self, table_name: &str, rows: Vec<Vec<Value>>) -> Result<()> {
+        let changes = rows
+            .iter()
+            .map(|row| change(table_name, None, None, Some(row.clone())))
+            .collect::<Vec<_>>();
+
+        self.storage.append_data(table_name, rows)?;
+        self.record(changes);
+
+        Ok(())
+    }
+
+    fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, Vec<Value>)>) -> Result<()> {
+        let changes = rows
+            .iter()
+            .map(|(key, row)| {
+                let before = self.storage.fetch_data(table_name, key)?;
+
+                Ok(change(
+                    table_name,
+                    Some(key.clone()),
+                    before,
+                    Some(row.clone()),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.storage.insert_data(table_name, rows)?;
+        self.record(changes);
+
+        Ok(())
+    }
+
+    fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        let changes = keys
+            .iter()
+            .filter_map(|key| {
+                self.storage
+                    .fetch_data(table_name, key)
+                    .map(|before| {
+                        before
+                            .map(|before| change(table_name, Some(key.clone()), Some(before), None))
+                    })
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.storage.delete_data(table_name, keys)?;
+        self.record(changes);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use {
+    super::{ChangeFeedStorage, State},
+    gluesql_core::{
+        error::{Error, Result},
+        store::Transaction,
+    },
+};
+
+impl<T: Transaction> Transaction for ChangeFeedStorage<T> {
+    fn begin(&mut self, autocommit: bool) -> Result<bool> {
+        match (self.state, autocommit) {
+            (State::Transaction { .. }, true) => Ok(false),
+            (State::Transaction { .. }, false) => Err(Error::StorageMsg(
+                "[ChangeFeedStorage] nested transaction is not supported".to_owned(),
+            )),
+            (State::Idle, autocommit) => {
+                let started = self.storage.begin(autocommit)?;
+                self.state = State::Transaction {
+                    inner: started || !autocommit,
+                };
+
+                Ok(autocommit)
+            }
+        }
+    }
+
+    fn rollback(&mut self) -> Result<()> {
+        let State::Transaction { inner } = std::mem::replace(&mut self.state, State::Idle) else {
+            return Ok(());
+        };
+
+        if inner {
+            self.pending.clear();
+
+            self.storage.rollback()
+        } else {
+            // Without a transaction of the wrapped storage nothing is undone,
+            // so the changes it already applied still belong to the feed.
+            self.publish();
+
+            Ok(())
+        }
+    }
+
+    fn prepare(&mut self) -> Result<()> {
+        match self.state {
+            State::Transaction { inner: true } => self.storage.prepare(),
+            _ => Ok(()),
+        }
+    }
+
+    fn commit(&mut self) -> Result<()> {
+        let State::Transaction { inner } = std::mem::replace(&mut self.state, State::Idle) else {
+            return Ok(());
+        };
+
+        if inner && let Err(error) = self.storage.commit() {
+            self.pending.clear();
+
+            return Err(error);
+        }
+
+        self.publish();
+
+        Ok(())
+    }
+
+    fn durable(&self) -> bool {
+        self.storage.durable()
+    }
+}
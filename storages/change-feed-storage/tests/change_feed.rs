@@ -0,0 +1,117 @@
+use {
+    gluesql_change_feed_storage::ChangeFeedStorage,
+    gluesql_core::{
+        data::Key,
+        prelude::{Glue, Value::*},
+        store::{Change, ChangeFeed, ChangeKind},
+    },
+    gluesql_memory_storage::MemoryStorage,
+    gluesql_sled_storage::SledStorage,
+    std::fs,
+};
+
+fn changes<T: ChangeFeed>(storage: &T, checkpoint: u64) -> Vec<Change> {
+    storage
+        .scan_changes(checkpoint)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap()
+}
+
+fn summary(changes: &[Change]) -> Vec<(u64, ChangeKind, Option<Key>)> {
+    changes
+        .iter()
+        .map(|change| (change.txid, change.kind(), change.key.clone()))
+        .collect()
+}
+
+#[test]
+fn memory_changes() {
+    let mut glue = Glue::new(ChangeFeedStorage::new(MemoryStorage::default()));
+
+    glue.execute(
+        "
+        CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT);
+        INSERT INTO Item VALUES (1, 'apple'), (2, 'banana');
+        UPDATE Item SET name = 'cherry' WHERE id = 2;
+        DELETE FROM Item WHERE id = 1;
+        CREATE TABLE Log (message TEXT);
+        INSERT INTO Log VALUES ('hello');
+        ",
+    )
+    .unwrap();
+
+    let feed = changes(&glue.storage, 0);
+    assert_eq!(
+        summary(&feed),
+        vec![
+            (1, ChangeKind::Insert, Some(Key::I64(1))),
+            (1, ChangeKind::Insert, Some(Key::I64(2))),
+            (2, ChangeKind::Update, Some(Key::I64(2))),
+            (3, ChangeKind::Delete, Some(Key::I64(1))),
+            (4, ChangeKind::Insert, None),
+        ]
+    );
+    assert_eq!(
+        (&feed[2].before, &feed[2].after),
+        (
+            &Some(vec![I64(2), Str("banana".to_owned())]),
+            &Some(vec![I64(2), Str("cherry".to_owned())])
+        )
+    );
+    assert_eq!(feed[3].after, None);
+    assert_eq!(feed[4].table_name, "Log");
+
+    let checkpoint = feed[2].seq;
+    assert_eq!(changes(&glue.storage, checkpoint), feed[3..].to_vec());
+
+    glue.storage.purge_changes(checkpoint).unwrap();
+    assert_eq!(changes(&glue.storage, 0), feed[3..].to_vec());
+}
+
+#[test]
+fn sled_wrapped_transaction() {
+    let path = "tmp/change_feed_sled_wrapped";
+    fs::remove_dir_all(path).unwrap_or(());
+
+    let storage = SledStorage::new(path).unwrap();
+    let mut glue = Glue::new(ChangeFeedStorage::new(storage));
+
+    glue.execute("CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT);")
+        .unwrap();
+
+    glue.execute(
+        "
+        BEGIN;
+        INSERT INTO Item VALUES (1, 'apple');
+        UPDATE Item SET name = 'banana' WHERE id = 1;
+        ROLLBACK;
+        ",
+    )
+    .unwrap();
+    assert_eq!(changes(&glue.storage, 0), Vec::new());
+
+    glue.execute(
+        "
+        BEGIN;
+        INSERT INTO Item VALUES (1, 'apple'), (2, 'banana');
+        DELETE FROM Item WHERE id = 2;
+        COMMIT;
+        ",
+    )
+    .unwrap();
+    assert_eq!(
+        changes(&glue.storage, 0)
+            .iter()
+            .map(|change| (change.txid, change.kind()))
+            .collect::<Vec<_>>(),
+        vec![
+            (1, ChangeKind::Insert),
+            (1, ChangeKind::Insert),
+            (1, ChangeKind::Delete),
+        ]
+    );
+
+    assert!(glue.execute("INSERT INTO Item VALUES (1, 'dup')").is_err());
+    assert_eq!(changes(&glue.storage, 3), Vec::new());
+}
@@ -0,0 +1,26 @@
+use {
+    gluesql_change_feed_storage::ChangeFeedStorage, gluesql_core::prelude::Glue,
+    gluesql_memory_storage::MemoryStorage, test_suite::*,
+};
+
+struct ChangeFeedTester {
+    glue: Glue<ChangeFeedStorage<MemoryStorage>>,
+}
+
+impl Tester<ChangeFeedStorage<MemoryStorage>> for ChangeFeedTester {
+    fn new(_: &str) -> Self {
+        let storage = ChangeFeedStorage::new(MemoryStorage::default());
+        let glue = Glue::new(storage);
+
+        Self { glue }
+    }
+
+    fn get_glue(&mut self) -> &mut Glue<ChangeFeedStorage<MemoryStorage>> {
+        &mut self.glue
+    }
+}
+
+generate_store_tests!(test, ChangeFeedTester);
+generate_alter_table_tests!(test, ChangeFeedTester);
+generate_metadata_table_tests!(test, ChangeFeedTester);
+generate_custom_function_tests!(test, ChangeFeedTester);
@@ -3,14 +3,16 @@
 mod store;
 mod store_mut;
 mod transaction;
+mod undo;
 
 use {
     gluesql_core::{
         data::Schema,
         error::{Error, Result},
-        store::{GStore, GStoreMut, Metadata, Planner, Store},
+        store::{GStore, GStoreMut, Metadata, Planner},
     },
     std::collections::HashMap,
+    undo::Undo,
 };
 
 pub trait IStorage: GStore + GStoreMut {}
@@ -21,6 +23,10 @@ impl<T: GStore + GStoreMut> IStorage for T {}
 pub struct CompositeStorage {
     pub storages: HashMap<String, Box<dyn IStorage>>,
     pub default_engine: Option<String>,
+    in_transaction: bool,
+    /// Writes of the open transaction, kept for the engines that commit
+    /// before another one and may have to be undone.
+    undo: HashMap<String, Vec<Undo>>,
 }
 
 impl CompositeStorage {
@@ -55,10 +61,18 @@ impl CompositeStorage {
         self.default_engine = None;
     }
 
+    /// Picks the engine named by the table schema, then the engine already
+    /// holding the table, e.g. a JSON file created outside `GlueSQL`, and
+    /// finally the default engine.
     fn fetch_engine(&self, table_name: &str) -> Result<String> {
-        self.fetch_schema(table_name)?
-            .and_then(|Schema { engine, .. }| engine)
-            .or_else(|| self.default_engine.clone())
+        for (name, storage) in &self.storages {
+            if let Some(Schema { engine, .. }) = storage.fetch_schema(table_name)? {
+                return Ok(engine.unwrap_or_else(|| name.clone()));
+            }
+        }
+
+        self.default_engine
+            .clone()
             .ok_or_else(|| Error::StorageMsg(format!("engine not found for table: {table_name}")))
     }
 
@@ -72,10 +86,25 @@ impl CompositeStorage {
         })
     }
 
-    fn fetch_storage_mut(&mut self, table_name: &str) -> Result<&mut dyn IStorage> {
-        let engine = self.fetch_engine(table_name)?;
+    /// Records what `undo` needs to reverse a write to `engine`, if the open
+    /// transaction keeps an undo log for it.
+    fn record(
+        &mut self,
+        engine: &str,
+        undo: impl FnOnce(&dyn IStorage) -> Result<Undo>,
+    ) -> Result<()> {
+        let (Some(log), Some(storage)) = (self.undo.get_mut(engine), self.storages.get(engine))
+        else {
+            return Ok(());
+        };
+
+        log.push(undo(storage.as_ref())?);
+
+        Ok(())
+    }
 
-        match self.storages.get_mut(&engine) {
+    fn fetch_storage_mut(&mut self, engine: &str, table_name: &str) -> Result<&mut dyn IStorage> {
+        match self.storages.get_mut(engine) {
             Some(storage) => Ok(storage.as_mut()),
             None => Err(Error::StorageMsg(format!(
                 "[fetch_storage_mut] storage not found for table: {table_name}"
@@ -90,4 +119,6 @@ impl gluesql_core::store::IndexMut for CompositeStorage {}
 impl Metadata for CompositeStorage {}
 impl gluesql_core::store::CustomFunction for CompositeStorage {}
 impl gluesql_core::store::CustomFunctionMut for CompositeStorage {}
+impl gluesql_core::store::Trigger for CompositeStorage {}
+impl gluesql_core::store::TriggerMut for CompositeStorage {}
 impl Planner for CompositeStorage {}
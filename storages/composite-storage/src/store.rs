@@ -3,7 +3,7 @@ use {
     gluesql_core::{
         data::{Key, Schema, Value},
         error::Result,
-        store::{RowIter, Store},
+        store::{RowIter, ScanPredicate, Store},
     },
 };
 
@@ -41,4 +41,14 @@ impl Store for CompositeStorage {
     fn scan_data<'a>(&'a self, table_name: &str) -> Result<RowIter<'a>> {
         self.fetch_storage(table_name)?.scan_data(table_name)
     }
+
+    fn scan_filtered_data<'a>(
+        &'a self,
+        table_name: &str,
+        predicates: &[ScanPredicate],
+        columns: Option<&[usize]>,
+    ) -> Result<RowIter<'a>> {
+        self.fetch_storage(table_name)?
+            .scan_filtered_data(table_name, predicates, columns)
+    }
 }
@@ -1,5 +1,5 @@
 use {
-    super::CompositeStorage,
+    super::{CompositeStorage, Undo},
     gluesql_core::{
         data::{Key, Schema, Value},
         error::{Error, Result},
@@ -9,46 +9,63 @@ use {
 
 impl StoreMut for CompositeStorage {
     fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
-        let storage = schema
+        let Some(engine) = schema
             .engine
-            .as_ref()
-            .or(self.default_engine.as_ref())
-            .and_then(|engine| self.storages.get_mut(engine));
-
-        match (storage, schema.engine.is_some()) {
-            (Some(storage), true) => storage.insert_schema(schema),
-            (Some(storage), false) => {
-                let schema = Schema {
-                    engine: self.default_engine.clone(),
-                    ..schema.clone()
-                };
-
-                storage.insert_schema(&schema)
-            }
-            (None, _) => Err(Error::StorageMsg(format!(
+            .clone()
+            .or_else(|| self.default_engine.clone())
+            .filter(|engine| self.storages.contains_key(engine))
+        else {
+            return Err(Error::StorageMsg(format!(
                 "storage not found for table: {}",
                 schema.table_name
-            ))),
-        }
+            )));
+        };
+
+        self.record(&engine, |storage| Undo::schema(storage, &schema.table_name))?;
+
+        let schema = Schema {
+            engine: schema
+                .engine
+                .clone()
+                .or_else(|| self.default_engine.clone()),
+            ..schema.clone()
+        };
+        self.fetch_storage_mut(&engine, &schema.table_name)?
+            .insert_schema(&schema)
     }
 
     fn delete_schema(&mut self, table_name: &str) -> Result<()> {
-        self.fetch_storage_mut(table_name)?
+        let engine = self.fetch_engine(table_name)?;
+        self.record(&engine, |storage| Undo::table(storage, table_name))?;
+        self.record(&engine, |storage| Undo::schema(storage, table_name))?;
+
+        self.fetch_storage_mut(&engine, table_name)?
             .delete_schema(table_name)
     }
 
     fn append_data(&mut self, table_name: &str, rows: Vec<Vec<Value>>) -> Result<()> {
-        self.fetch_storage_mut(table_name)?
+        let engine = self.fetch_engine(table_name)?;
+        self.record(&engine, |storage| Undo::append(storage, table_name))?;
+
+        self.fetch_storage_mut(&engine, table_name)?
             .append_data(table_name, rows)
     }
 
     fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, Vec<Value>)>) -> Result<()> {
-        self.fetch_storage_mut(table_name)?
+        let engine = self.fetch_engine(table_name)?;
+        self.record(&engine, |storage| {
+            Undo::rows(storage, table_name, rows.iter().map(|(key, _)| key))
+        })?;
+
+        self.fetch_storage_mut(&engine, table_name)?
             .insert_data(table_name, rows)
     }
 
     fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
-        self.fetch_storage_mut(table_name)?
+        let engine = self.fetch_engine(table_name)?;
+        self.record(&engine, |storage| Undo::rows(storage, table_name, &keys))?;
+
+        self.fetch_storage_mut(&engine, table_name)?
             .delete_data(table_name, keys)
     }
 }
@@ -1,27 +1,94 @@
 use {
-    super::CompositeStorage,
+    super::{CompositeStorage, IStorage, undo},
     gluesql_core::{
         error::{Error, Result},
         store::Transaction,
     },
 };
 
+impl CompositeStorage {
+    /// Engines in commit order: durable engines first, then by name.
+    fn engines_mut(&mut self) -> Vec<(&String, &mut Box<dyn IStorage>)> {
+        let mut engines = self.storages.iter_mut().collect::<Vec<_>>();
+        engines.sort_by(|(a, a_storage), (b, b_storage)| {
+            b_storage
+                .durable()
+                .cmp(&a_storage.durable())
+                .then_with(|| a.cmp(b))
+        });
+
+        engines
+    }
+}
+
+fn engine_error(engine: &str, action: &str, error: Error) -> Error {
+    let error = match error {
+        Error::StorageMsg(message) => message,
+        error => error.to_string(),
+    };
+
+    Error::StorageMsg(format!(
+        "[CompositeStorage] engine {engine} {action}: {error}"
+    ))
+}
+
+fn rollback_all<'a>(engines: impl IntoIterator<Item = (&'a String, &'a mut Box<dyn IStorage>)>) {
+    // The error that aborted the transaction is what the caller needs to see,
+    // so a failing rollback must not replace it.
+    for (_, storage) in engines {
+        let _ = storage.rollback();
+    }
+}
+
 impl Transaction for CompositeStorage {
     fn begin(&mut self, autocommit: bool) -> Result<bool> {
-        if autocommit {
-            for storage in self.storages.values_mut() {
-                storage.begin(autocommit)?;
+        match (self.in_transaction, autocommit) {
+            (true, true) => return Ok(false),
+            (true, false) => {
+                return Err(Error::StorageMsg(
+                    "[CompositeStorage] nested transaction is not supported".to_owned(),
+                ));
+            }
+            (false, true) => {
+                for storage in self.storages.values_mut() {
+                    storage.begin(autocommit)?;
+                }
+
+                return Ok(true);
             }
+            (false, false) => {}
+        }
+
+        let mut engines = self.engines_mut();
 
-            return Ok(true);
+        for i in 0..engines.len() {
+            let (engine, storage) = &mut engines[i];
+
+            if let Err(error) = storage.begin(false) {
+                let error = engine_error(engine, "cannot join the transaction", error);
+                rollback_all(engines.into_iter().take(i));
+
+                return Err(error);
+            }
         }
 
-        Err(Error::StorageMsg(
-            "[CompositeStorage] Transaction::begin is not supported".to_owned(),
-        ))
+        // The last engine to commit never has to be undone.
+        let undo = engines
+            .iter()
+            .rev()
+            .skip(1)
+            .map(|(engine, _)| ((*engine).clone(), Vec::new()))
+            .collect();
+        self.undo = undo;
+        self.in_transaction = true;
+
+        Ok(false)
     }
 
     fn rollback(&mut self) -> Result<()> {
+        self.in_transaction = false;
+        self.undo.clear();
+
         for storage in self.storages.values_mut() {
             storage.rollback()?;
         }
@@ -30,10 +97,65 @@ impl Transaction for CompositeStorage {
     }
 
     fn commit(&mut self) -> Result<()> {
-        for storage in self.storages.values_mut() {
-            storage.commit()?;
+        if !std::mem::take(&mut self.in_transaction) {
+            for storage in self.storages.values_mut() {
+                storage.commit()?;
+            }
+
+            return Ok(());
+        }
+
+        let mut engines = self.engines_mut();
+
+        for (engine, storage) in &mut engines {
+            if let Err(error) = storage.prepare() {
+                let error = engine_error(engine, "failed to prepare commit", error);
+                rollback_all(engines);
+
+                return Err(error);
+            }
+        }
+
+        // Every engine has prepared, but a commit can still fail. Engines that
+        // already committed are then undone from their undo logs, which is
+        // best-effort: writers outside this storage may have touched the same
+        // rows since. Committing in-memory engines last keeps them out of it.
+        let mut undo = std::mem::take(&mut self.undo);
+        let mut engines = self.engines_mut().into_iter();
+        let mut committed = Vec::new();
+
+        while let Some((engine, storage)) = engines.next() {
+            if let Err(error) = storage.commit() {
+                let engine = engine.clone();
+                rollback_all(std::iter::once((&engine, storage)).chain(engines));
+
+                let mut failed = Vec::new();
+                for name in committed.into_iter().rev() {
+                    let log = undo.remove(&name).unwrap_or_default();
+                    let storage = self.storages.get_mut(&name).map(Box::as_mut);
+
+                    if storage.is_none_or(|storage| undo::undo_all(storage, log).is_err()) {
+                        failed.push(name);
+                    }
+                }
+
+                let action = if failed.is_empty() {
+                    "failed to commit".to_owned()
+                } else {
+                    failed.reverse();
+                    format!("failed to commit after {} committed", failed.join(", "))
+                };
+
+                return Err(engine_error(&engine, &action, error));
+            }
+
+            committed.push(engine.clone());
         }
 
         Ok(())
     }
+
+    fn durable(&self) -> bool {
+        self.storages.values().any(|storage| storage.durable())
+    }
 }
@@ -0,0 +1,138 @@
+use {
+    super::IStorage,
+    gluesql_core::{
+        data::{Key, Schema, Value},
+        error::Result,
+    },
+    std::collections::HashSet,
+};
+
+/// State a write of a transaction replaced, recorded before the write so that
+/// the write can be undone after its engine committed.
+pub(crate) enum Undo {
+    /// Schema of the table before the write; `None` if it did not exist.
+    Schema {
+        table_name: String,
+        schema: Option<Schema>,
+    },
+    /// Rows before the write, `None` for keys that did not exist.
+    Rows {
+        table_name: String,
+        rows: Vec<(Key, Option<Vec<Value>>)>,
+    },
+    /// Keys of the table before an append, which generates the new keys.
+    Append {
+        table_name: String,
+        keys: HashSet<Key>,
+    },
+}
+
+impl Undo {
+    pub(crate) fn schema(storage: &dyn IStorage, table_name: &str) -> Result<Self> {
+        Ok(Self::Schema {
+            table_name: table_name.to_owned(),
+            schema: storage.fetch_schema(table_name)?,
+        })
+    }
+
+    pub(crate) fn rows<'a>(
+        storage: &dyn IStorage,
+        table_name: &str,
+        keys: impl IntoIterator<Item = &'a Key>,
+    ) -> Result<Self> {
+        let rows = keys
+            .into_iter()
+            .map(|key| Ok((key.clone(), storage.fetch_data(table_name, key)?)))
+            .collect::<Result<_>>()?;
+
+        Ok(Self::Rows {
+            table_name: table_name.to_owned(),
+            rows,
+        })
+    }
+
+    /// Every row of the table, to restore it after it is dropped.
+    pub(crate) fn table(storage: &dyn IStorage, table_name: &str) -> Result<Self> {
+        let rows = storage
+            .scan_data(table_name)?
+            .map(|row| row.map(|(key, row)| (key, Some(row))))
+            .collect::<Result<_>>()?;
+
+        Ok(Self::Rows {
+            table_name: table_name.to_owned(),
+            rows,
+        })
+    }
+
+    pub(crate) fn append(storage: &dyn IStorage, table_name: &str) -> Result<Self> {
+        Ok(Self::Append {
+            table_name: table_name.to_owned(),
+            keys: scan_keys(storage, table_name)?,
+        })
+    }
+
+    fn apply(self, storage: &mut dyn IStorage) -> Result<()> {
+        match self {
+            Self::Schema {
+                schema: Some(schema),
+                ..
+            } => storage.insert_schema(&schema),
+            Self::Schema {
+                table_name,
+                schema: None,
+            } => storage.delete_schema(&table_name),
+            Self::Rows { table_name, rows } => {
+                let (rows, deleted): (Vec<_>, Vec<_>) =
+                    rows.into_iter().partition(|(_, row)| row.is_some());
+                let rows = rows
+                    .into_iter()
+                    .filter_map(|(key, row)| Some((key, row?)))
+                    .collect();
+                let deleted = deleted.into_iter().map(|(key, _)| key).collect();
+
+                storage.delete_data(&table_name, deleted)?;
+                storage.insert_data(&table_name, rows)
+            }
+            Self::Append { table_name, keys } => {
+                let appended = scan_keys(storage, &table_name)?
+                    .into_iter()
+                    .filter(|key| !keys.contains(key))
+                    .collect();
+
+                storage.delete_data(&table_name, appended)
+            }
+        }
+    }
+}
+
+fn scan_keys(storage: &dyn IStorage, table_name: &str) -> Result<HashSet<Key>> {
+    if storage.fetch_schema(table_name)?.is_none() {
+        return Ok(HashSet::new());
+    }
+
+    storage
+        .scan_data(table_name)?
+        .map(|row| row.map(|(key, _)| key))
+        .collect()
+}
+
+/// Undoes the writes of a committed transaction, latest first, in a
+/// transaction of their own.
+pub(crate) fn undo_all(storage: &mut dyn IStorage, undo: Vec<Undo>) -> Result<()> {
+    let autocommit = storage.begin(true)?;
+    let result = undo
+        .into_iter()
+        .rev()
+        .try_for_each(|undo| undo.apply(storage));
+
+    match (result, autocommit) {
+        (Ok(()), true) => storage.commit(),
+        (Ok(()), false) => Ok(()),
+        (Err(error), true) => {
+            storage.rollback()?;
+
+            Err(error)
+        }
+        (Err(error), false) => Err(error),
+    }
+}
@@ -1,12 +1,20 @@
 use {
     gluesql_composite_storage::CompositeStorage,
-    gluesql_core::prelude::{Error, Glue, Value::I64},
+    gluesql_core::prelude::{Glue, Payload, Value::I64},
     gluesql_memory_storage::MemoryStorage,
     gluesql_sled_storage::SledStorage,
     std::fs,
     test_suite::*,
 };
 
+fn count(glue: &mut Glue<CompositeStorage>, table_name: &str) -> Payload {
+    glue.execute(format!("SELECT COUNT(*) AS cnt FROM {table_name};"))
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap()
+}
+
 #[test]
 fn memory_and_sled() {
     let memory_storage = MemoryStorage::default();
@@ -51,8 +59,57 @@ fn memory_and_sled() {
         )
     );
 
+    glue.execute("BEGIN;").unwrap();
+    glue.execute("INSERT INTO Foo VALUES (6);").unwrap();
+    glue.execute("DELETE FROM Bar WHERE foo_id = 3;").unwrap();
+    glue.execute("ROLLBACK;").unwrap();
+
+    assert_eq!(count(&mut glue, "Foo"), select!(cnt I64; 5));
+    assert_eq!(count(&mut glue, "Bar"), select!(cnt I64; 5));
+
+    glue.execute("BEGIN;").unwrap();
+    glue.execute("INSERT INTO Foo VALUES (6);").unwrap();
+    glue.execute("DELETE FROM Bar WHERE foo_id = 3;").unwrap();
+    glue.execute("COMMIT;").unwrap();
+
+    assert_eq!(count(&mut glue, "Foo"), select!(cnt I64; 6));
+    assert_eq!(count(&mut glue, "Bar"), select!(cnt I64; 2));
+}
+
+#[test]
+fn table_without_engine() {
+    let mut memory_glue = Glue::new(MemoryStorage::default());
+    memory_glue
+        .execute("CREATE TABLE Foo (foo_id INTEGER); INSERT INTO Foo VALUES (1), (2);")
+        .unwrap();
+
+    let sled_storage = {
+        let path = "data/table_without_engine";
+        fs::remove_dir_all(path).unwrap_or(());
+
+        SledStorage::new(path).unwrap()
+    };
+
+    let mut storage = CompositeStorage::new();
+    storage.push("MEMORY", memory_glue.storage);
+    storage.push("SLED", sled_storage);
+    storage.set_default("SLED");
+
+    let mut glue = Glue::new(storage);
+
+    glue.execute("CREATE TABLE Bar (foo_id INTEGER);").unwrap();
+    glue.execute("INSERT INTO Bar VALUES (2), (3);").unwrap();
+
     assert_eq!(
-        glue.execute("BEGIN;").unwrap_err(),
-        Error::StorageMsg("[CompositeStorage] Transaction::begin is not supported".to_owned()),
+        glue.execute("SELECT Foo.foo_id FROM Foo JOIN Bar ON Foo.foo_id = Bar.foo_id;")
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap(),
+        select!(
+            foo_id
+            I64;
+            2
+        )
     );
 }
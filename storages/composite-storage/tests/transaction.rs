@@ -0,0 +1,216 @@
+use {
+    gluesql_composite_storage::CompositeStorage,
+    gluesql_core::{
+        data::{Key, Schema, Value},
+        error::Result,
+        prelude::{Error, Glue, Payload, Value::I64},
+        store::{
+            AlterTable, CustomFunction, CustomFunctionMut, Index, IndexMut, Metadata, RowIter,
+            Store, StoreMut, Transaction, Trigger, TriggerMut,
+        },
+    },
+    gluesql_sled_storage::SledStorage,
+    std::{fs, thread::sleep, time::Duration},
+    test_suite::*,
+};
+
+/// Sled storage whose `commit` of an explicit transaction fails after
+/// `prepare` succeeded.
+struct CommitFailure(SledStorage, bool);
+
+impl Store for CommitFailure {
+    fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        self.0.fetch_schema(table_name)
+    }
+
+    fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        self.0.fetch_all_schemas()
+    }
+
+    fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<Vec<Value>>> {
+        self.0.fetch_data(table_name, key)
+    }
+
+    fn scan_data<'a>(&'a self, table_name: &str) -> Result<RowIter<'a>> {
+        self.0.scan_data(table_name)
+    }
+}
+
+impl StoreMut for CommitFailure {
+    fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        self.0.insert_schema(schema)
+    }
+
+    fn append_data(&mut self, table_name: &str, rows: Vec<Vec<Value>>) -> Result<()> {
+        self.0.append_data(table_name, rows)
+    }
+}
+
+impl Transaction for CommitFailure {
+    fn begin(&mut self, autocommit: bool) -> Result<bool> {
+        self.1 = !autocommit;
+
+        self.0.begin(autocommit)
+    }
+
+    fn rollback(&mut self) -> Result<()> {
+        self.0.rollback()
+    }
+
+    fn commit(&mut self) -> Result<()> {
+        if self.1 {
+            return Err(Error::StorageMsg("disk is full".to_owned()));
+        }
+
+        self.0.commit()
+    }
+}
+
+impl Index for CommitFailure {}
+impl IndexMut for CommitFailure {}
+impl AlterTable for CommitFailure {}
+impl Metadata for CommitFailure {}
+impl CustomFunction for CommitFailure {}
+impl CustomFunctionMut for CommitFailure {}
+impl Trigger for CommitFailure {}
+impl TriggerMut for CommitFailure {}
+
+fn sled_storage(path: &str) -> SledStorage {
+    fs::remove_dir_all(path).unwrap_or(());
+
+    SledStorage::new(path).unwrap()
+}
+
+fn count(glue: &mut Glue<CompositeStorage>, table_name: &str) -> Payload {
+    glue.execute(format!("SELECT COUNT(*) AS cnt FROM {table_name};"))
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap()
+}
+
+#[test]
+fn transaction() {
+    let mut storage = CompositeStorage::new();
+    storage.push("DISK", sled_storage("data/composite_transaction_disk"));
+    storage.push("LOG", sled_storage("data/composite_transaction_log"));
+
+    let mut glue = Glue::new(storage);
+
+    glue.execute("CREATE TABLE Foo (id INTEGER) ENGINE = DISK;")
+        .unwrap();
+    glue.execute("CREATE TABLE Bar (id INTEGER) ENGINE = LOG;")
+        .unwrap();
+
+    glue.execute("BEGIN;").unwrap();
+    glue.execute("INSERT INTO Foo VALUES (1), (2);").unwrap();
+    glue.execute("INSERT INTO Bar VALUES (10);").unwrap();
+    assert_eq!(count(&mut glue, "Foo"), select!(cnt I64; 2));
+    glue.execute("ROLLBACK;").unwrap();
+
+    assert_eq!(count(&mut glue, "Foo"), select!(cnt I64; 0));
+    assert_eq!(count(&mut glue, "Bar"), select!(cnt I64; 0));
+
+    glue.execute("BEGIN;").unwrap();
+    assert_eq!(
+        glue.execute("BEGIN;").unwrap_err(),
+        Error::StorageMsg("[CompositeStorage] nested transaction is not supported".to_owned()),
+    );
+    glue.execute("INSERT INTO Foo VALUES (1), (2);").unwrap();
+    glue.execute("INSERT INTO Bar VALUES (10);").unwrap();
+    glue.execute("COMMIT;").unwrap();
+
+    assert_eq!(count(&mut glue, "Foo"), select!(cnt I64; 2));
+    assert_eq!(count(&mut glue, "Bar"), select!(cnt I64; 1));
+}
+
+#[test]
+fn transaction_prepare_failure() {
+    let mut log_storage = sled_storage("data/composite_transaction_prepare_log");
+    log_storage.set_transaction_timeout(Some(1000));
+
+    let mut storage = CompositeStorage::new();
+    storage.push(
+        "DISK",
+        sled_storage("data/composite_transaction_prepare_disk"),
+    );
+    storage.push("LOG", log_storage);
+
+    let mut glue = Glue::new(storage);
+
+    glue.execute("CREATE TABLE Foo (id INTEGER) ENGINE = DISK;")
+        .unwrap();
+    glue.execute("CREATE TABLE Bar (id INTEGER) ENGINE = LOG;")
+        .unwrap();
+
+    glue.execute("BEGIN;").unwrap();
+    glue.execute("INSERT INTO Foo VALUES (1), (2);").unwrap();
+    glue.execute("INSERT INTO Bar VALUES (10);").unwrap();
+    sleep(Duration::from_millis(1100));
+
+    assert_eq!(
+        glue.execute("COMMIT;").unwrap_err(),
+        Error::StorageMsg(
+            "[CompositeStorage] engine LOG failed to prepare commit: fetch failed - expired transaction has used (timeout)"
+                .to_owned()
+        ),
+    );
+
+    assert_eq!(count(&mut glue, "Foo"), select!(cnt I64; 0));
+    assert_eq!(count(&mut glue, "Bar"), select!(cnt I64; 0));
+}
+
+#[test]
+fn transaction_commit_failure() {
+    let mut storage = CompositeStorage::new();
+    storage.push(
+        "DISK",
+        sled_storage("data/composite_transaction_commit_disk"),
+    );
+    storage.push(
+        "LOG",
+        CommitFailure(sled_storage("data/composite_transaction_commit_log"), false),
+    );
+
+    let mut glue = Glue::new(storage);
+
+    glue.execute("CREATE TABLE Foo (id INTEGER) ENGINE = DISK;")
+        .unwrap();
+    glue.execute("CREATE TABLE Bar (id INTEGER) ENGINE = LOG;")
+        .unwrap();
+
+    glue.execute("INSERT INTO Foo VALUES (1), (2);").unwrap();
+
+    glue.execute("BEGIN;").unwrap();
+    glue.execute("INSERT INTO Foo VALUES (3);").unwrap();
+    glue.execute("UPDATE Foo SET id = 20 WHERE id = 2;")
+        .unwrap();
+    glue.execute("DELETE FROM Foo WHERE id = 1;").unwrap();
+    glue.execute("CREATE TABLE Baz (id INTEGER) ENGINE = DISK;")
+        .unwrap();
+    glue.execute("INSERT INTO Bar VALUES (10);").unwrap();
+
+    assert_eq!(
+        glue.execute("COMMIT;").unwrap_err(),
+        Error::StorageMsg(
+            "[CompositeStorage] engine LOG failed to commit: disk is full".to_owned()
+        ),
+    );
+
+    // DISK committed before LOG failed, so its writes are undone.
+    assert_eq!(
+        glue.execute("SELECT id FROM Foo ORDER BY id;")
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap(),
+        select!(id I64; 1; 2)
+    );
+    assert_eq!(count(&mut glue, "Bar"), select!(cnt I64; 0));
+    assert_eq!(
+        glue.execute("SELECT * FROM Baz;").unwrap_err(),
+        Error::Fetch(gluesql_core::error::FetchError::TableNotFound(
+            "Baz".to_owned()
+        )),
+    );
+}
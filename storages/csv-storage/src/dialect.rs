@@ -0,0 +1,334 @@
+use {
+    crate::{
+        CsvStorage,
+        error::{CsvStorageError, ResultExt},
+    },
+    csv::{Reader, ReaderBuilder, StringRecordsIntoIter, Writer, WriterBuilder},
+    encoding_rs::{Encoding, UTF_8},
+    flate2::{Compression, read::MultiGzDecoder, write::GzEncoder},
+    gluesql_core::{ast::DataType, data::Value, error::Result},
+    std::{
+        fs::{self, File, OpenOptions},
+        io::{Cursor, Read, Write},
+        path::{Path, PathBuf},
+    },
+};
+
+pub(crate) type DataRecords = StringRecordsIntoIter<Box<dyn Read>>;
+
+/// Column types tried, in order, when inferring the type of a column.
+const INFERABLE_TYPES: [DataType; 4] = [
+    DataType::Int,
+    DataType::Float,
+    DataType::Date,
+    DataType::Boolean,
+];
+
+#[derive(Clone, Debug)]
+pub(crate) struct Dialect {
+    pub delimiter: u8,
+    pub quote: u8,
+    pub escape: Option<u8>,
+    pub has_headers: bool,
+    pub null_token: String,
+    pub encoding: &'static Encoding,
+    pub gzip: bool,
+    pub infer_types: Option<usize>,
+}
+
+impl Default for Dialect {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            escape: None,
+            has_headers: true,
+            null_token: "NULL".to_owned(),
+            encoding: UTF_8,
+            gzip: false,
+            infer_types: None,
+        }
+    }
+}
+
+/// Builds a [`CsvStorage`] that reads and writes a specific CSV dialect.
+///
+/// ```no_run
+/// use gluesql_csv_storage::CsvStorage;
+///
+/// let storage = CsvStorage::builder("data/drops")
+///     .delimiter(b'\t')
+///     .null_token("")
+///     .gzip(true)
+///     .infer_types(100)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct CsvStorageBuilder {
+    path: PathBuf,
+    dialect: Dialect,
+    encoding: Option<String>,
+}
+
+impl CsvStorageBuilder {
+    pub(crate) fn new<T: AsRef<Path>>(path: T) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            dialect: Dialect::default(),
+            encoding: None,
+        }
+    }
+
+    /// Field delimiter, `,` by default.
+    #[must_use]
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.dialect.delimiter = delimiter;
+        self
+    }
+
+    /// Quote character, `"` by default.
+    #[must_use]
+    pub fn quote(mut self, quote: u8) -> Self {
+        self.dialect.quote = quote;
+        self
+    }
+
+    /// Escape character for quotes inside quoted fields. Quotes are escaped
+    /// by doubling them when no escape character is set.
+    #[must_use]
+    pub fn escape(mut self, escape: u8) -> Self {
+        self.dialect.escape = Some(escape);
+        self
+    }
+
+    /// Whether the first row of each data file holds the column names.
+    /// Files without a header row name their columns `column1`, `column2`
+    /// and so on, except for schemaless tables written by `GlueSQL`, whose
+    /// map keys are kept in the header row of the types file.
+    #[must_use]
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.dialect.has_headers = has_headers;
+        self
+    }
+
+    /// Field value read and written as `NULL`, `NULL` by default.
+    #[must_use]
+    pub fn null_token<T: Into<String>>(mut self, null_token: T) -> Self {
+        self.dialect.null_token = null_token.into();
+        self
+    }
+
+    /// Character encoding label of the data files, such as `euc-kr` or
+    /// `windows-1252`. Files are UTF-8 by default.
+    #[must_use]
+    pub fn encoding<T: Into<String>>(mut self, label: T) -> Self {
+        self.encoding = Some(label.into());
+        self
+    }
+
+    /// Reads and writes gzip compressed `{table}.csv.gz` data files.
+    #[must_use]
+    pub fn gzip(mut self, gzip: bool) -> Self {
+        self.dialect.gzip = gzip;
+        self
+    }
+
+    /// Infers `INT`, `FLOAT`, `DATE` and `BOOLEAN` columns of tables without
+    /// a schema file from their first `sample_rows` rows, instead of reading
+    /// every column as `TEXT`.
+    #[must_use]
+    pub fn infer_types(mut self, sample_rows: usize) -> Self {
+        self.dialect.infer_types = Some(sample_rows);
+        self
+    }
+
+    pub fn build(self) -> Result<CsvStorage> {
+        let Self {
+            path,
+            mut dialect,
+            encoding,
+        } = self;
+
+        if let Some(label) = encoding {
+            dialect.encoding = Encoding::for_label(label.as_bytes())
+                .ok_or(CsvStorageError::UnsupportedEncoding(label))?;
+        }
+
+        fs::create_dir_all(&path).map_storage_err()?;
+
+        Ok(CsvStorage { path, dialect })
+    }
+}
+
+impl CsvStorage {
+    pub(crate) fn data_extension(&self) -> &'static str {
+        if self.dialect.gzip { "csv.gz" } else { "csv" }
+    }
+
+    pub(crate) fn data_reader(&self, path: &Path) -> Result<Reader<Box<dyn Read>>> {
+        let Dialect {
+            delimiter,
+            quote,
+            escape,
+            encoding,
+            gzip,
+            ..
+        } = &self.dialect;
+
+        let file = File::open(path).map_storage_err()?;
+        let mut reader: Box<dyn Read> = if *gzip {
+            Box::new(MultiGzDecoder::new(file))
+        } else {
+            Box::new(file)
+        };
+
+        if *encoding != UTF_8 {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).map_storage_err()?;
+
+            let (text, _) = encoding.decode_with_bom_removal(&bytes);
+            reader = Box::new(Cursor::new(text.into_owned().into_bytes()));
+        }
+
+        Ok(ReaderBuilder::new()
+            .delimiter(*delimiter)
+            .quote(*quote)
+            .escape(*escape)
+            .has_headers(false)
+            .from_reader(reader))
+    }
+
+    /// Reads the column names of a data file and returns the records that
+    /// follow them.
+    pub(crate) fn read_data(&self, path: &Path) -> Result<(Vec<String>, DataRecords)> {
+        let mut records = self.data_reader(path)?.into_records();
+        let columns = if self.dialect.has_headers {
+            records
+                .next()
+                .transpose()
+                .map_storage_err()?
+                .map(|header| header.iter().map(ToString::to_string).collect())
+                .unwrap_or_default()
+        } else {
+            let width = self
+                .data_reader(path)?
+                .records()
+                .next()
+                .transpose()
+                .map_storage_err()?
+                .map_or(0, |record| record.len());
+
+            (1..=width).map(|i| format!("column{i}")).collect()
+        };
+
+        Ok((columns, records))
+    }
+
+    /// Writes CSV records to a data file in the storage's dialect, either
+    /// replacing its contents or appending to them.
+    pub(crate) fn write_data<F>(&self, path: &Path, append: bool, write_records: F) -> Result<()>
+    where
+        F: FnOnce(&mut Writer<Vec<u8>>) -> Result<()>,
+    {
+        let Dialect {
+            delimiter,
+            quote,
+            escape,
+            encoding,
+            gzip,
+            ..
+        } = &self.dialect;
+
+        let mut builder = WriterBuilder::new();
+        builder.delimiter(*delimiter).quote(*quote);
+        if let Some(escape) = escape {
+            builder.double_quote(false).escape(*escape);
+        }
+
+        let mut writer = builder.from_writer(Vec::new());
+        write_records(&mut writer)?;
+        let mut bytes = writer.into_inner().map_storage_err()?;
+
+        if *encoding != UTF_8 {
+            let text = String::from_utf8(bytes).map_storage_err()?;
+            bytes = encoding.encode(&text).0.into_owned();
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(path)
+            .map_storage_err()?;
+
+        if *gzip {
+            let mut encoder = GzEncoder::new(file, Compression::default());
+            encoder.write_all(&bytes).map_storage_err()?;
+            encoder.finish().map_storage_err()?;
+        } else {
+            file.write_all(&bytes).map_storage_err()?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn write_header(
+        &self,
+        writer: &mut Writer<Vec<u8>>,
+        columns: &[String],
+    ) -> Result<()> {
+        if !self.dialect.has_headers {
+            return Ok(());
+        }
+
+        writer.write_record(columns).map_storage_err()
+    }
+
+    pub(crate) fn to_field(&self, value: Value) -> String {
+        match value {
+            Value::Null => self.dialect.null_token.clone(),
+            value => String::from(value),
+        }
+    }
+
+    /// Chooses a column type that every sampled non-null field casts to,
+    /// falling back to `TEXT`.
+    pub(crate) fn infer_data_types(&self, columns: usize, path: &Path) -> Result<Vec<DataType>> {
+        let Some(sample_rows) = self.dialect.infer_types else {
+            return Ok(vec![DataType::Text; columns]);
+        };
+
+        let (_, records) = self.read_data(path)?;
+        let records = records
+            .take(sample_rows)
+            .collect::<Result<Vec<_>, _>>()
+            .map_storage_err()?;
+
+        let data_types = (0..columns)
+            .map(|index| {
+                let fields = records
+                    .iter()
+                    .filter_map(|record| record.get(index))
+                    .filter(|field| *field != self.dialect.null_token)
+                    .collect::<Vec<_>>();
+
+                if fields.is_empty() {
+                    return DataType::Text;
+                }
+
+                INFERABLE_TYPES
+                    .into_iter()
+                    .find(|data_type| {
+                        fields
+                            .iter()
+                            .all(|field| Value::Str((*field).to_owned()).cast(data_type).is_ok())
+                    })
+                    .unwrap_or(DataType::Text)
+            })
+            .collect();
+
+        Ok(data_types)
+    }
+}
@@ -40,4 +40,7 @@ pub enum CsvStorageError {
 
     #[error("unexpected non-map row for schemaless table")]
     UnexpectedNonMapRowForSchemalessTable,
+
+    #[error("unsupported encoding: {0}")]
+    UnsupportedEncoding(String),
 }
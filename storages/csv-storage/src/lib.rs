@@ -1,8 +1,12 @@
+mod dialect;
 pub mod error;
 mod store;
 mod store_mut;
 
+pub use dialect::CsvStorageBuilder;
+
 use {
+    dialect::Dialect,
     error::{CsvStorageError, ResultExt},
     gluesql_core::{
         ast::{ColumnDef, ColumnUniqueOption, DataType},
@@ -11,13 +15,13 @@ use {
         parse_sql::parse_data_type,
         store::{
             AlterTable, CustomFunction, CustomFunctionMut, Index, IndexMut, Metadata, Planner,
-            Transaction,
+            Transaction, Trigger, TriggerMut,
         },
         translate::translate_data_type,
     },
     std::{
         collections::BTreeMap,
-        fs::{self, File},
+        fs::File,
         io::Read,
         path::{Path, PathBuf},
     },
@@ -27,15 +31,16 @@ type RowIter = Box<dyn Iterator<Item = Result<(Key, Vec<Value>)>>>;
 
 pub struct CsvStorage {
     pub path: PathBuf,
+    dialect: Dialect,
 }
 
 impl CsvStorage {
     pub fn new<T: AsRef<Path>>(path: T) -> Result<Self> {
-        let path = path.as_ref();
-        fs::create_dir_all(path).map_storage_err()?;
-        let path = PathBuf::from(path);
+        Self::builder(path).build()
+    }
 
-        Ok(Self { path })
+    pub fn builder<T: AsRef<Path>>(path: T) -> CsvStorageBuilder {
+        CsvStorageBuilder::new(path)
     }
 
     fn fetch_schema(&self, table_name: &str) -> Result<Option<(Schema, bool)>> {
@@ -46,22 +51,25 @@ impl CsvStorage {
 
             let column_defs = match (types_path.exists(), data_path.exists()) {
                 (false, false) => return Ok(None),
-                (false, true) => Some(
-                    csv::Reader::from_path(data_path)
-                        .map_storage_err()?
-                        .headers()
-                        .map_storage_err()?
-                        .into_iter()
-                        .map(|header| ColumnDef {
-                            name: header.to_string(),
-                            data_type: DataType::Text,
-                            unique: None,
-                            default: None,
-                            nullable: true,
-                            comment: None,
-                        })
-                        .collect::<Vec<_>>(),
-                ),
+                (false, true) => {
+                    let (columns, _) = self.read_data(&data_path)?;
+                    let data_types = self.infer_data_types(columns.len(), &data_path)?;
+
+                    Some(
+                        columns
+                            .into_iter()
+                            .zip(data_types)
+                            .map(|(name, data_type)| ColumnDef {
+                                name,
+                                data_type,
+                                unique: None,
+                                default: None,
+                                nullable: true,
+                                comment: None,
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                }
                 (true, _) => None,
             };
 
@@ -72,6 +80,7 @@ impl CsvStorage {
                 engine: None,
                 foreign_keys: Vec::new(),
                 comment: None,
+                ttl_column: None,
             };
 
             return Ok(Some((schema, true)));
@@ -102,11 +111,11 @@ impl CsvStorage {
     }
 
     fn data_path(&self, table_name: &str) -> PathBuf {
-        self.path_by(table_name, "csv")
+        self.path_by(table_name, self.data_extension())
     }
 
     fn tmp_data_path(&self, table_name: &str) -> PathBuf {
-        self.path_by(table_name, "tmp.csv")
+        self.path_by(table_name, &format!("tmp.{}", self.data_extension()))
     }
 
     fn types_path(&self, table_name: &str) -> PathBuf {
@@ -124,15 +133,7 @@ impl CsvStorage {
             (Some(v), true) => v,
         };
 
-        let mut data_rdr = csv::Reader::from_path(data_path).map_storage_err()?;
-        let mut fetch_data_header_columns = || -> Result<Vec<String>> {
-            Ok(data_rdr
-                .headers()
-                .map_storage_err()?
-                .into_iter()
-                .map(ToString::to_string)
-                .collect::<Vec<_>>())
-        };
+        let (data_columns, data_rdr) = self.read_data(&data_path)?;
 
         if let Schema {
             column_defs: Some(column_defs),
@@ -144,50 +145,52 @@ impl CsvStorage {
                 .map(|column_def| column_def.name.clone())
                 .collect::<Vec<_>>();
 
-            let rows = data_rdr
-                .into_records()
-                .enumerate()
-                .map(move |(index, record)| {
-                    let mut key: Option<Key> = None;
-
-                    let values = record
-                        .map_storage_err()?
-                        .into_iter()
-                        .zip(column_defs.iter())
-                        .map(|(value, column_def)| {
-                            let value = match value {
-                                "NULL" => Value::Null,
-                                _ => Value::Str(value.to_owned()),
-                            };
-
-                            let value = match &column_def.data_type {
-                                DataType::Text => value,
-                                data_type => value.cast(data_type)?,
-                            };
-
-                            if column_def.unique == Some(ColumnUniqueOption { is_primary: true }) {
-                                key = Key::try_from(&value).map(Some)?;
-                            }
-
-                            Ok(value)
-                        })
-                        .collect::<Result<Vec<Value>>>()?;
+            let null_token = self.dialect.null_token.clone();
+            let rows = data_rdr.enumerate().map(move |(index, record)| {
+                let mut key: Option<Key> = None;
+
+                let values = record
+                    .map_storage_err()?
+                    .into_iter()
+                    .zip(column_defs.iter())
+                    .map(|(value, column_def)| {
+                        let value = if value == null_token {
+                            Value::Null
+                        } else {
+                            Value::Str(value.to_owned())
+                        };
+
+                        let value = match &column_def.data_type {
+                            DataType::Text => value,
+                            data_type => value.cast(data_type)?,
+                        };
+
+                        if column_def.unique == Some(ColumnUniqueOption { is_primary: true }) {
+                            key = Key::try_from(&value).map(Some)?;
+                        }
+
+                        Ok(value)
+                    })
+                    .collect::<Result<Vec<Value>>>()?;
 
-                    let key = key.unwrap_or(Key::U64(index as u64));
-                    let row = values;
+                let key = key.unwrap_or(Key::U64(index as u64));
+                let row = values;
 
-                    Ok((key, row))
-                });
+                Ok((key, row))
+            });
 
             Ok((Some(columns), Box::new(rows)))
         } else if self.types_path(table_name).exists() {
             let types_path = self.types_path(table_name);
-            let types_rdr = csv::Reader::from_path(types_path)
+            let mut types_rdr = csv::Reader::from_path(types_path).map_storage_err()?;
+            let columns = types_rdr
+                .headers()
                 .map_storage_err()?
-                .into_records();
+                .into_iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
 
-            let columns = fetch_data_header_columns()?;
-            let rows = data_rdr.into_records().zip(types_rdr).enumerate().map(
+            let rows = data_rdr.zip(types_rdr.into_records()).enumerate().map(
                 move |(index, (record, types))| {
                     let key = Key::U64(index as u64);
                     let record = record.map_storage_err()?;
@@ -225,24 +228,30 @@ impl CsvStorage {
 
             Ok((None, Box::new(rows)))
         } else {
-            let columns = fetch_data_header_columns()?;
+            let columns = data_columns;
             let rows = {
                 let columns = columns.clone();
+                let null_token = self.dialect.null_token.clone();
 
-                data_rdr
-                    .into_records()
-                    .enumerate()
-                    .map(move |(index, record)| {
-                        let key = Key::U64(index as u64);
-                        let row = record
-                            .map_storage_err()?
-                            .into_iter()
-                            .zip(columns.iter())
-                            .map(|(value, column)| (column.clone(), Value::Str(value.to_owned())))
-                            .collect::<BTreeMap<String, Value>>();
+                data_rdr.enumerate().map(move |(index, record)| {
+                    let key = Key::U64(index as u64);
+                    let row = record
+                        .map_storage_err()?
+                        .into_iter()
+                        .zip(columns.iter())
+                        .map(|(value, column)| {
+                            let value = if value == null_token {
+                                Value::Null
+                            } else {
+                                Value::Str(value.to_owned())
+                            };
 
-                        Ok((key, vec![Value::Map(row)]))
-                    })
+                            (column.clone(), value)
+                        })
+                        .collect::<BTreeMap<String, Value>>();
+
+                    Ok((key, vec![Value::Map(row)]))
+                })
             };
 
             Ok((generated.then_some(columns), Box::new(rows)))
@@ -253,6 +262,8 @@ impl CsvStorage {
 impl AlterTable for CsvStorage {}
 impl CustomFunction for CsvStorage {}
 impl CustomFunctionMut for CsvStorage {}
+impl Trigger for CsvStorage {}
+impl TriggerMut for CsvStorage {}
 impl Index for CsvStorage {}
 impl IndexMut for CsvStorage {}
 impl Transaction for CsvStorage {}
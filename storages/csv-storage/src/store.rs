@@ -8,7 +8,7 @@ use {
         error::Result,
         store::{RowIter, Store},
     },
-    std::{ffi::OsStr, fs},
+    std::{ffi::OsStr, fs, path::Path},
 };
 
 impl Store for CsvStorage {
@@ -19,18 +19,20 @@ impl Store for CsvStorage {
 
     fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
         let paths = fs::read_dir(&self.path).map_storage_err()?;
+        let data_suffix = format!(".{}", self.data_extension());
         let mut schemas = paths
             .map(|result| {
                 let path = result.map_storage_err()?.path();
-                let extension = path.extension().and_then(OsStr::to_str);
-                if extension != Some("csv") || path.to_string_lossy().ends_with(".types.csv") {
-                    return Ok(None);
-                }
-
-                let table_name = path
-                    .file_stem()
+                let file_name = path
+                    .file_name()
                     .and_then(OsStr::to_str)
                     .map_storage_err(CsvStorageError::FileNotFound)?;
+                let Some(table_name) = file_name.strip_suffix(&data_suffix) else {
+                    return Ok(None);
+                };
+                if Path::new(table_name).extension() == Some(OsStr::new("types")) {
+                    return Ok(None);
+                }
 
                 self.fetch_schema(table_name)?
                     .map(|(schema, _)| schema)
@@ -12,7 +12,7 @@ use {
     std::{
         cmp::Ordering,
         collections::BTreeSet,
-        fs::{File, OpenOptions, remove_file, rename},
+        fs::{File, remove_file, rename},
         io::Write,
         iter::Peekable,
         vec::IntoIter,
@@ -32,15 +32,11 @@ impl StoreMut for CsvStorage {
 
         let columns = column_defs
             .iter()
-            .map(|column_def| column_def.name.as_str())
-            .collect::<Vec<&str>>();
+            .map(|column_def| column_def.name.clone())
+            .collect::<Vec<_>>();
         let data_path = self.data_path(schema.table_name.as_str());
 
-        File::create(data_path)
-            .map_storage_err()
-            .map(Writer::from_writer)?
-            .write_record(&columns)
-            .map_storage_err()
+        self.write_data(&data_path, false, |wtr| self.write_header(wtr, &columns))
     }
 
     fn delete_schema(&mut self, table_name: &str) -> Result<()> {
@@ -67,19 +63,16 @@ impl StoreMut for CsvStorage {
 
         if columns.is_some() {
             let data_path = self.data_path(table_name);
-            let mut wtr = OpenOptions::new()
-                .append(true)
-                .open(data_path)
-                .map_storage_err()
-                .map(Writer::from_writer)?;
 
-            for row in rows {
-                let row = convert(row);
+            self.write_data(&data_path, true, |wtr| {
+                for row in rows {
+                    let row = self.convert(row);
 
-                wtr.write_record(&row).map_storage_err()?;
-            }
+                    wtr.write_record(&row).map_storage_err()?;
+                }
 
-            Ok(())
+                Ok(())
+            })
         } else {
             let rows = prev_rows
                 .map(|item| item.map(|(_, row)| row))
@@ -124,18 +117,19 @@ impl CsvStorage {
         rows: T,
     ) -> Result<()> {
         let tmp_data_path = self.tmp_data_path(table_name);
-        let mut data_wtr = File::create(&tmp_data_path)
-            .map_storage_err()
-            .map(Writer::from_writer)?;
 
         if let Some(columns) = columns {
-            data_wtr.write_record(&columns).map_storage_err()?;
+            self.write_data(&tmp_data_path, false, |data_wtr| {
+                self.write_header(data_wtr, &columns)?;
 
-            for row in rows {
-                let row = convert(row?);
+                for row in rows {
+                    let row = self.convert(row?);
 
-                data_wtr.write_record(&row).map_storage_err()?;
-            }
+                    data_wtr.write_record(&row).map_storage_err()?;
+                }
+
+                Ok(())
+            })?;
         } else {
             let tmp_types_path = self.tmp_types_path(table_name);
             let mut types_wtr = File::create(&tmp_types_path)
@@ -158,37 +152,54 @@ impl CsvStorage {
                 columns.extend(row.keys().cloned());
             }
 
-            data_wtr.write_record(&columns).map_storage_err()?;
+            let columns = columns.into_iter().collect::<Vec<_>>();
             types_wtr.write_record(&columns).map_storage_err()?;
 
-            for row in &rows {
+            let mut data_rows = Vec::with_capacity(rows.len());
+            for row in rows {
                 let (row, data_types): (Vec<_>, Vec<_>) = columns
                     .iter()
                     .map(|key| {
-                        row.get(key.as_str())
-                            .map_or(("NULL".to_owned(), String::new()), |value| {
+                        row.get(key.as_str()).map_or(
+                            (self.dialect.null_token.clone(), String::new()),
+                            |value| {
                                 let data_type = value
                                     .get_type()
                                     .map_or("NULL".to_owned(), |t| t.to_string());
 
-                                (String::from(value), data_type)
-                            })
+                                (self.to_field(value.clone()), data_type)
+                            },
+                        )
                     })
                     .unzip();
 
-                data_wtr.write_record(&row).map_storage_err()?;
                 types_wtr.write_record(&data_types).map_storage_err()?;
+                data_rows.push(row);
             }
 
+            self.write_data(&tmp_data_path, false, |data_wtr| {
+                self.write_header(data_wtr, &columns)?;
+
+                for row in &data_rows {
+                    data_wtr.write_record(row).map_storage_err()?;
+                }
+
+                Ok(())
+            })?;
+
+            types_wtr.flush().map_storage_err()?;
             rename(tmp_types_path, self.types_path(table_name)).map_storage_err()?;
         }
 
         rename(tmp_data_path, self.data_path(table_name)).map_storage_err()
     }
-}
 
-fn convert(data_row: Vec<Value>) -> Vec<String> {
-    data_row.into_iter().map(String::from).collect()
+    fn convert(&self, data_row: Vec<Value>) -> Vec<String> {
+        data_row
+            .into_iter()
+            .map(|value| self.to_field(value))
+            .collect()
+    }
 }
 
 struct SortMerge<T: Iterator<Item = Result<(Key, Vec<Value>)>>> {
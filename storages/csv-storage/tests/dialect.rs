@@ -0,0 +1,218 @@
+use {
+    flate2::read::MultiGzDecoder,
+    gluesql_core::prelude::{
+        Glue, Payload, PayloadVariable,
+        Value::{Bool, Date, F64, I64, Null, Str},
+    },
+    gluesql_csv_storage::CsvStorage,
+    std::{
+        fs::{self, File, remove_dir_all},
+        io::Read,
+    },
+    test_suite::*,
+};
+
+fn new_path(namespace: &str) -> String {
+    let path = format!("tmp/{namespace}");
+    remove_dir_all(&path).unwrap_or(());
+    fs::create_dir_all(&path).unwrap();
+
+    path
+}
+
+#[test]
+fn tsv_with_custom_null_token() {
+    let path = new_path("csv_dialect_tsv");
+    fs::write(
+        format!("{path}/Partner.csv"),
+        "id\tname\tnote\n1\tAcme, Inc.\t\\N\n2\t'Tab\tCo'\tnew\n",
+    )
+    .unwrap();
+
+    let storage = CsvStorage::builder(&path)
+        .delimiter(b'\t')
+        .quote(b'\'')
+        .null_token("\\N")
+        .build()
+        .unwrap();
+    let mut glue = Glue::new(storage);
+
+    assert_eq!(
+        glue.execute("SELECT * FROM Partner").unwrap().remove(0),
+        select_with_null!(
+            id                 | name                     | note;
+            Str("1".to_owned())  Str("Acme, Inc.".to_owned())  Null;
+            Str("2".to_owned())  Str("Tab\tCo".to_owned())     Str("new".to_owned())
+        )
+    );
+
+    glue.execute("DELETE FROM Partner WHERE id = '2'").unwrap();
+    glue.execute("INSERT INTO Partner VALUES ('3', 'Pipe|Co', NULL)")
+        .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(format!("{path}/Partner.csv")).unwrap(),
+        "id\tname\tnote\n1\tAcme, Inc.\t\\N\n3\tPipe|Co\t\\N\n"
+    );
+}
+
+#[test]
+fn without_headers() {
+    let path = new_path("csv_dialect_no_headers");
+    fs::write(format!("{path}/Log.csv"), "a|1\nb|2\n").unwrap();
+
+    let storage = CsvStorage::builder(&path)
+        .delimiter(b'|')
+        .has_headers(false)
+        .build()
+        .unwrap();
+    let mut glue = Glue::new(storage);
+
+    assert_eq!(
+        glue.execute("SELECT column1, column2 FROM Log")
+            .unwrap()
+            .remove(0),
+        select!(
+            column1         | column2
+            Str             | Str;
+            "a".to_owned()    "1".to_owned();
+            "b".to_owned()    "2".to_owned()
+        )
+    );
+
+    glue.execute("CREATE TABLE Item (id INTEGER, name TEXT)")
+        .unwrap();
+    glue.execute("INSERT INTO Item VALUES (1, 'x'), (2, 'y')")
+        .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(format!("{path}/Item.csv")).unwrap(),
+        "1|x\n2|y\n"
+    );
+    assert_eq!(
+        glue.execute("SELECT name FROM Item WHERE id = 2")
+            .unwrap()
+            .remove(0),
+        select!(name Str; "y".to_owned())
+    );
+}
+
+#[test]
+fn gzip_and_encoding() {
+    let path = new_path("csv_dialect_gzip");
+    let storage = CsvStorage::builder(&path)
+        .gzip(true)
+        .encoding("windows-1252")
+        .build()
+        .unwrap();
+    let mut glue = Glue::new(storage);
+
+    glue.execute("CREATE TABLE Cafe (id INTEGER, name TEXT)")
+        .unwrap();
+    glue.execute("INSERT INTO Cafe VALUES (1, 'Café')").unwrap();
+    glue.execute("INSERT INTO Cafe VALUES (2, 'Crème')")
+        .unwrap();
+
+    let mut bytes = Vec::new();
+    MultiGzDecoder::new(File::open(format!("{path}/Cafe.csv.gz")).unwrap())
+        .read_to_end(&mut bytes)
+        .unwrap();
+    assert_eq!(bytes, b"id,name\n1,Caf\xe9\n2,Cr\xe8me\n");
+
+    assert_eq!(
+        glue.execute("SELECT * FROM Cafe").unwrap().remove(0),
+        select!(
+            id  | name
+            I64 | Str;
+            1     "Café".to_owned();
+            2     "Crème".to_owned()
+        )
+    );
+    assert_eq!(
+        glue.execute("SHOW TABLES").unwrap().remove(0),
+        Payload::ShowVariable(PayloadVariable::Tables(vec!["Cafe".to_owned()]))
+    );
+
+    assert!(
+        CsvStorage::builder(&path)
+            .encoding("no-such-encoding")
+            .build()
+            .is_err()
+    );
+}
+
+#[test]
+fn infer_types() {
+    let path = new_path("csv_dialect_infer_types");
+    fs::write(
+        format!("{path}/Feed.csv"),
+        "id,price,day,active,name\n1,1.5,2024-01-02,true,a\n2,NULL,2024-02-03,FALSE,b\n3,3,2024-03-04,true,4\n",
+    )
+    .unwrap();
+
+    let storage = CsvStorage::builder(&path).infer_types(10).build().unwrap();
+    let mut glue = Glue::new(storage);
+
+    let date = |s: &str| Date(s.parse().unwrap());
+    assert_eq!(
+        glue.execute("SELECT * FROM Feed WHERE id > 1")
+            .unwrap()
+            .remove(0),
+        select_with_null!(
+            id     | price    | day                | active      | name;
+            I64(2)   Null       date("2024-02-03")   Bool(false)   Str("b".to_owned());
+            I64(3)   F64(3.0)   date("2024-03-04")   Bool(true)    Str("4".to_owned())
+        )
+    );
+}
+
+#[test]
+fn schemaless_without_headers() {
+    let path = new_path("csv_dialect_schemaless_no_headers");
+    let storage = CsvStorage::builder(&path)
+        .has_headers(false)
+        .null_token("")
+        .build()
+        .unwrap();
+    let mut glue = Glue::new(storage);
+
+    glue.execute("CREATE TABLE Log").unwrap();
+    glue.execute(r#"INSERT INTO Log VALUES ('{"id": 1, "name": "a"}')"#)
+        .unwrap();
+    glue.execute(r#"INSERT INTO Log VALUES ('{"id": 2, "memo": null}')"#)
+        .unwrap();
+
+    // The data file has no header row, so the map keys live in the header
+    // of the types file.
+    assert_eq!(
+        fs::read_to_string(format!("{path}/Log.csv")).unwrap(),
+        "1,,a\n2,,\n"
+    );
+    assert_eq!(
+        fs::read_to_string(format!("{path}/Log.types.csv")).unwrap(),
+        "id,memo,name\nINT,,TEXT\nINT,NULL,\n"
+    );
+    assert_eq!(
+        glue.execute("SELECT id, name, memo FROM Log")
+            .unwrap()
+            .remove(0),
+        select_with_null!(
+            id     | name                 | memo;
+            I64(1)   Str("a".to_owned())    Null;
+            I64(2)   Null                   Null
+        )
+    );
+
+    // Without a types file the fields are read by position.
+    fs::remove_file(format!("{path}/Log.types.csv")).unwrap();
+    assert_eq!(
+        glue.execute("SELECT column1, column3 FROM Log")
+            .unwrap()
+            .remove(0),
+        select_with_null!(
+            column1              | column3;
+            Str("1".to_owned())    Str("a".to_owned());
+            Str("2".to_owned())    Null
+        )
+    );
+}
@@ -12,7 +12,7 @@ use {
         error::{Error, Result},
         store::{
             AlterTable, CustomFunction, CustomFunctionMut, Index, IndexMut, Metadata, Planner,
-            Transaction,
+            Transaction, Trigger, TriggerMut,
         },
     },
     hex::ToHex,
@@ -84,4 +84,6 @@ impl Transaction for FileStorage {}
 impl Metadata for FileStorage {}
 impl CustomFunction for FileStorage {}
 impl CustomFunctionMut for FileStorage {}
+impl Trigger for FileStorage {}
+impl TriggerMut for FileStorage {}
 impl Planner for FileStorage {}
@@ -10,7 +10,7 @@ use {
         error::{Error, Result},
         store::{
             AlterTable, CustomFunction, CustomFunctionMut, Index, IndexMut, Metadata, Planner,
-            Store, StoreMut, Transaction,
+            Store, StoreMut, Transaction, Trigger, TriggerMut,
         },
     },
     gluesql_csv_storage::CsvStorage,
@@ -162,4 +162,6 @@ impl Transaction for GitStorage {}
 impl Metadata for GitStorage {}
 impl CustomFunction for GitStorage {}
 impl CustomFunctionMut for GitStorage {}
+impl Trigger for GitStorage {}
+impl TriggerMut for GitStorage {}
 impl Planner for GitStorage {}
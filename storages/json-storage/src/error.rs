@@ -46,4 +46,13 @@ pub enum JsonStorageError {
 
     #[error("json array type is required")]
     JsonArrayTypeRequired,
+
+    #[error("nested transaction is not supported")]
+    NestedTransactionNotSupported,
+
+    #[error("invalid journal manifest")]
+    InvalidJournal,
+
+    #[error("invalid index file content: {0}")]
+    InvalidIndexContent(String),
 }
@@ -1,7 +1,9 @@
 use {
     super::JsonStorage,
-    gluesql_core::store::{CustomFunction, CustomFunctionMut},
+    gluesql_core::store::{CustomFunction, CustomFunctionMut, Trigger, TriggerMut},
 };
 
 impl CustomFunction for JsonStorage {}
 impl CustomFunctionMut for JsonStorage {}
+impl Trigger for JsonStorage {}
+impl TriggerMut for JsonStorage {}
@@ -1,7 +1,560 @@
 use {
-    super::JsonStorage,
-    gluesql_core::store::{Index, IndexMut},
+    super::{JsonStorage, to_row},
+    crate::error::{JsonStorageError, OptionExt, ResultExt},
+    gluesql_core::{
+        ast::{IndexOperator, OrderByExpr},
+        chrono::Utc,
+        data::{
+            BoundingBox, Key, Schema, SchemaIndex, SchemaIndexOrd, Value, value::BTreeMapJsonExt,
+        },
+        error::{IndexError, Result},
+        executor::{RowContext, evaluate_stateless},
+        plan::plan_scalar_expr,
+        store::{Index, IndexMut, RowIter, Store},
+    },
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::BTreeMap,
+        fs::{self, File},
+        io::{BufRead, BufReader, Read, Seek, SeekFrom},
+        path::{Path, PathBuf},
+        time::{SystemTime, UNIX_EPOCH},
+    },
 };
 
-impl Index for JsonStorage {}
-impl IndexMut for JsonStorage {}
+/// Bytes read from the end of a sidecar file to find its footer.
+const FOOTER_TAIL: u64 = 256;
+
+/// One line of a sidecar file.
+///
+/// An entry holds the hex encoded index key, the position of the row in the
+/// table's data file and, for `*.jsonl` files, the byte offset of its line.
+/// Every write ends the file with a footer naming the
+/// version of the data file the entries describe, so a sidecar whose data
+/// file was edited outside `GlueSQL` is detected and ignored.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum IndexLine {
+    Entry(String, usize, Option<u64>),
+    Footer(Footer),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Footer {
+    rows: usize,
+    len: u64,
+    secs: u64,
+    nanos: u32,
+}
+
+impl Footer {
+    fn matches(&self, version: Option<(u64, SystemTime)>) -> bool {
+        version.is_some_and(|version| *self == Footer::new(self.rows, version))
+    }
+
+    fn new(rows: usize, (len, modified): (u64, SystemTime)) -> Self {
+        let modified = modified.duration_since(UNIX_EPOCH).unwrap_or_default();
+
+        Self {
+            rows,
+            len,
+            secs: modified.as_secs(),
+            nanos: modified.subsec_nanos(),
+        }
+    }
+}
+
+/// Index keys of an appended batch of rows, and the footer of the sidecar
+/// they extend, or `None` when the sidecar has to be rebuilt.
+pub(crate) type IndexAppend = Option<(Footer, IndexKeys)>;
+
+/// Index keys of a sequence of rows, one per row.
+pub(crate) type IndexKeys = Vec<Vec<u8>>;
+
+/// Byte offsets of the rows of a `*.jsonl` data file, or `None` for a
+/// `*.json` file, whose rows can only be read by parsing the whole file.
+pub(crate) type RowOffsets = Option<Vec<u64>>;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct IndexEntry {
+    key: Vec<u8>,
+    position: usize,
+    offset: Option<u64>,
+}
+
+/// Entries of a sidecar, sorted by key.
+type IndexEntries = Vec<IndexEntry>;
+
+impl JsonStorage {
+    pub(crate) fn index_path(&self, table_name: &str, index_name: &str) -> PathBuf {
+        self.path.join(format!("{table_name}.{index_name}.idx"))
+    }
+
+    /// Length and modification time of the table's data file, as seen by
+    /// the running transaction.
+    fn data_version(&self, table_name: &str) -> Option<(u64, SystemTime)> {
+        let path = self
+            .resolve(&self.json_path(table_name))
+            .or_else(|| self.resolve(&self.jsonl_path(table_name)))?;
+        let metadata = fs::metadata(path).ok()?;
+
+        Some((metadata.len(), metadata.modified().ok()?))
+    }
+
+    /// Rewrites the sidecar files of every index on the table from the keys
+    /// returned by `table_index_keys` for the rows just written.
+    pub(crate) fn write_indexes(
+        &mut self,
+        schema: &Schema,
+        keys: Vec<IndexKeys>,
+        offsets: &RowOffsets,
+    ) -> Result<()> {
+        for (index, keys) in schema.indexes.iter().zip(keys) {
+            self.write_index(schema, index, keys, offsets)?;
+        }
+
+        Ok(())
+    }
+
+    /// Computes the index keys of `rows` before they are appended to the
+    /// table's data file, for each sidecar that is still current.
+    pub(crate) fn prepare_index_append(
+        &self,
+        schema: &Schema,
+        rows: &[Vec<Value>],
+    ) -> Result<Vec<IndexAppend>> {
+        let version = self.data_version(&schema.table_name);
+
+        schema
+            .indexes
+            .iter()
+            .map(|index| {
+                let index_path = self.index_path(&schema.table_name, &index.name);
+                let Some(footer) = self
+                    .resolve(&index_path)
+                    .and_then(|path| read_footer(&path))
+                    .filter(|footer| footer.matches(version))
+                else {
+                    return Ok(None);
+                };
+
+                let keys = index_keys(schema, index, rows.iter().map(Ok))?;
+
+                Ok(Some((footer, keys)))
+            })
+            .collect()
+    }
+
+    /// Appends the entries prepared by `prepare_index_append` to the sidecar
+    /// files, rebuilding the ones that were not current. `offsets` are the
+    /// line offsets within the appended contents.
+    pub(crate) fn append_indexes(
+        &mut self,
+        schema: &Schema,
+        appends: Vec<IndexAppend>,
+        offsets: &[u64],
+    ) -> Result<()> {
+        let version = self
+            .data_version(&schema.table_name)
+            .map_storage_err(JsonStorageError::FileNotFound)?;
+
+        for (index, append) in schema.indexes.iter().zip(appends) {
+            let Some((footer, keys)) = append else {
+                self.rebuild_index(schema, index)?;
+                continue;
+            };
+
+            let rows = footer.rows + keys.len();
+            let entries = keys
+                .into_iter()
+                .zip(offsets)
+                .enumerate()
+                .map(|(i, (key, offset))| IndexEntry {
+                    key,
+                    position: footer.rows + i,
+                    offset: Some(footer.len + offset),
+                });
+            let contents = serialize_index(entries, Footer::new(rows, version))?;
+
+            let index_path = self.index_path(&schema.table_name, &index.name);
+            self.append_file(&index_path, &contents)?;
+        }
+
+        Ok(())
+    }
+
+    fn rebuild_index(&mut self, schema: &Schema, index: &SchemaIndex) -> Result<()> {
+        let rows = self
+            .scan_data(&schema.table_name)?
+            .0
+            .map(|item| Ok(item?.1));
+        let keys = index_keys(schema, index, rows)?;
+        let offsets = self.row_offsets(&schema.table_name)?;
+
+        self.write_index(schema, index, keys, &offsets)
+    }
+
+    fn write_index(
+        &mut self,
+        schema: &Schema,
+        index: &SchemaIndex,
+        keys: IndexKeys,
+        offsets: &RowOffsets,
+    ) -> Result<()> {
+        let version = self
+            .data_version(&schema.table_name)
+            .map_storage_err(JsonStorageError::FileNotFound)?;
+        let footer = Footer::new(keys.len(), version);
+        let entries = sorted_entries(keys, offsets);
+
+        let contents = serialize_index(entries, footer)?;
+        let index_path = self.index_path(&schema.table_name, &index.name);
+        self.write_file(&index_path, &contents)
+    }
+
+    /// Computes the entries of an index from the rows of the data file, for
+    /// sidecars that are missing or out of date.
+    fn scan_index(&self, table_name: &str, index_name: &str) -> Result<IndexEntries> {
+        let (rows, schema) = self.scan_data(table_name)?;
+        let index = schema
+            .indexes
+            .iter()
+            .find(|index| index.name == index_name)
+            .ok_or_else(|| IndexError::IndexNameDoesNotExist(index_name.to_owned()))?;
+
+        let keys = index_keys(&schema, index, rows.map(|item| Ok(item?.1)))?;
+        let offsets = self.row_offsets(table_name)?;
+
+        Ok(sorted_entries(keys, &offsets))
+    }
+
+    /// Reads the byte offsets of the rows of a `*.jsonl` data file.
+    fn row_offsets(&self, table_name: &str) -> Result<RowOffsets> {
+        if self.file_exists(&self.json_path(table_name)) {
+            return Ok(None);
+        }
+
+        let jsonl_path = self
+            .resolve(&self.jsonl_path(table_name))
+            .map_storage_err(JsonStorageError::FileNotFound)?;
+        let contents = fs::read(jsonl_path).map_storage_err()?;
+
+        Ok(Some(line_offsets(&contents)))
+    }
+
+    /// Reads the rows of `entries` from a `*.jsonl` data file by their byte
+    /// offsets, without parsing the rows in between.
+    fn read_rows(
+        &self,
+        table_name: &str,
+        entries: &[&IndexEntry],
+    ) -> Result<Vec<(Key, Vec<Value>)>> {
+        let schema = self
+            .fetch_schema(table_name)?
+            .map_storage_err(JsonStorageError::TableDoesNotExist)?;
+        let jsonl_path = self
+            .resolve(&self.jsonl_path(table_name))
+            .map_storage_err(JsonStorageError::FileNotFound)?;
+        let mut reader = BufReader::new(File::open(jsonl_path).map_storage_err()?);
+        let mut line = String::new();
+
+        entries
+            .iter()
+            .map(|entry| {
+                let offset = entry
+                    .offset
+                    .ok_or(IndexError::ConflictOnEmptyIndexValueScan)?;
+                reader.seek(SeekFrom::Start(offset)).map_storage_err()?;
+                line.clear();
+                reader.read_line(&mut line).map_storage_err()?;
+
+                let json = BTreeMap::parse_json_object(line.trim_end())?;
+
+                to_row(&schema, entry.position, json)
+            })
+            .collect()
+    }
+
+    /// Reads the entries of a sidecar sorted by key, or `None` when the
+    /// sidecar is missing or does not match the current data file.
+    fn read_index(&self, table_name: &str, index_name: &str) -> Result<Option<IndexEntries>> {
+        let index_path = self.index_path(table_name, index_name);
+        let Some(index_path) = self.resolve(&index_path) else {
+            return Ok(None);
+        };
+        let invalid =
+            || JsonStorageError::InvalidIndexContent(format!("{table_name}.{index_name}"));
+
+        let contents = fs::read_to_string(index_path).map_storage_err()?;
+        let mut entries = Vec::new();
+        let mut footer = None;
+        for line in contents.lines() {
+            match serde_json::from_str(line) {
+                Ok(IndexLine::Entry(key, position, offset)) => {
+                    let key = hex::decode(key).map_err(|_| invalid()).map_storage_err()?;
+
+                    entries.push(IndexEntry {
+                        key,
+                        position,
+                        offset,
+                    });
+                    footer = None;
+                }
+                Ok(IndexLine::Footer(line)) => footer = Some(line),
+                Err(_) => return Ok(None),
+            }
+        }
+
+        let version = self.data_version(table_name);
+        if !footer.is_some_and(|footer| footer.matches(version) && footer.rows == entries.len()) {
+            return Ok(None);
+        }
+
+        entries.sort();
+
+        Ok(Some(entries))
+    }
+}
+
+/// Index keys of `rows` for every index on the table, computed before the
+/// rows are serialized into the data file.
+pub(crate) fn table_index_keys(schema: &Schema, rows: &[Vec<Value>]) -> Result<Vec<IndexKeys>> {
+    schema
+        .indexes
+        .iter()
+        .map(|index| index_keys(schema, index, rows.iter().map(Ok)))
+        .collect()
+}
+
+fn index_keys<T, R>(schema: &Schema, index: &SchemaIndex, rows: T) -> Result<IndexKeys>
+where
+    T: Iterator<Item = Result<R>>,
+    R: AsRef<[Value]>,
+{
+    let columns = schema
+        .column_defs
+        .as_ref()
+        .map(|column_defs| {
+            column_defs
+                .iter()
+                .map(|column_def| column_def.name.clone())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let expr = plan_scalar_expr(index.expr.clone());
+
+    rows.map(|row| {
+        let row = row?;
+        let context = Some(RowContext::RefVecData {
+            columns: &columns,
+            values: row.as_ref(),
+        });
+        let value: Value = evaluate_stateless(context, &expr)?.try_into()?;
+
+        build_index_key(&value)
+    })
+    .collect()
+}
+
+fn sorted_entries(keys: IndexKeys, offsets: &RowOffsets) -> IndexEntries {
+    let mut entries = keys
+        .into_iter()
+        .enumerate()
+        .map(|(position, key)| IndexEntry {
+            key,
+            position,
+            offset: offsets
+                .as_ref()
+                .and_then(|offsets| offsets.get(position).copied()),
+        })
+        .collect::<Vec<_>>();
+    entries.sort();
+
+    entries
+}
+
+/// Byte offsets at which the lines of `contents` start.
+pub(crate) fn line_offsets(contents: &[u8]) -> Vec<u64> {
+    let ends = contents
+        .iter()
+        .enumerate()
+        .filter(|(_, byte)| **byte == b'\n')
+        .map(|(i, _)| i as u64 + 1);
+
+    std::iter::once(0)
+        .chain(ends)
+        .filter(|offset| *offset < contents.len() as u64)
+        .collect()
+}
+
+fn serialize_index(
+    entries: impl IntoIterator<Item = IndexEntry>,
+    footer: Footer,
+) -> Result<Vec<u8>> {
+    let mut contents = Vec::new();
+    let lines = entries
+        .into_iter()
+        .map(|entry| IndexLine::Entry(hex::encode(entry.key), entry.position, entry.offset))
+        .chain(std::iter::once(IndexLine::Footer(footer)));
+
+    for line in lines {
+        serde_json::to_writer(&mut contents, &line).map_storage_err()?;
+        contents.push(b'\n');
+    }
+
+    Ok(contents)
+}
+
+/// Reads the last line of a sidecar without reading its entries.
+fn read_footer(path: &Path) -> Option<Footer> {
+    let mut file = File::open(path).ok()?;
+    let len = file.metadata().ok()?.len();
+    file.seek(SeekFrom::Start(len.saturating_sub(FOOTER_TAIL)))
+        .ok()?;
+
+    let mut tail = Vec::new();
+    file.read_to_end(&mut tail).ok()?;
+    let tail = String::from_utf8_lossy(&tail);
+    let line = tail.trim_end().rsplit('\n').next()?;
+
+    match serde_json::from_str(line).ok()? {
+        IndexLine::Footer(footer) => Some(footer),
+        IndexLine::Entry(..) => None,
+    }
+}
+
+fn build_index_key(value: &Value) -> Result<Vec<u8>> {
+    match value.to_spatial_cmp_be_bytes() {
+        Some(bytes) => Ok(bytes),
+        None => value.to_cmp_be_bytes(),
+    }
+}
+
+impl Index for JsonStorage {
+    fn scan_indexed_data<'a>(
+        &'a self,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
+    ) -> Result<RowIter<'a>> {
+        let entries = match self.read_index(table_name, index_name)? {
+            Some(entries) => entries,
+            None => self.scan_index(table_name, index_name)?,
+        };
+        let entries: Vec<&IndexEntry> = match cmp_value {
+            None => entries.iter().collect(),
+            Some((IndexOperator::Intersects, value)) => {
+                let Some(area) = value.bounding_box() else {
+                    return Ok(Box::new(std::iter::empty()));
+                };
+
+                entries
+                    .iter()
+                    .filter(|entry| {
+                        entry
+                            .key
+                            .get(1..33)
+                            .and_then(BoundingBox::from_cmp_be_bytes)
+                            .is_some_and(|bbox| bbox.intersects(&area))
+                    })
+                    .collect()
+            }
+            Some((op, value)) => {
+                let key = build_index_key(&value)?;
+                let lower = entries.partition_point(|entry| entry.key < key);
+                let upper = entries.partition_point(|entry| entry.key <= key);
+
+                let entries = match op {
+                    IndexOperator::Eq => &entries[lower..upper],
+                    IndexOperator::Gt => &entries[upper..],
+                    IndexOperator::GtEq => &entries[lower..],
+                    IndexOperator::Lt => &entries[..lower],
+                    IndexOperator::LtEq => &entries[..upper],
+                    IndexOperator::Intersects => &[],
+                };
+
+                entries.iter().collect()
+            }
+        };
+
+        if entries.is_empty() {
+            return Ok(Box::new(std::iter::empty()));
+        }
+
+        let mut rows = if entries.iter().all(|entry| entry.offset.is_some()) {
+            self.read_rows(table_name, &entries)?
+        } else {
+            let mut rows = self
+                .scan_data(table_name)?
+                .0
+                .map(|item| item.map(Some))
+                .collect::<Result<Vec<_>>>()?;
+
+            entries
+                .into_iter()
+                .map(|entry| {
+                    rows.get_mut(entry.position)
+                        .and_then(Option::take)
+                        .ok_or_else(|| IndexError::ConflictOnEmptyIndexValueScan.into())
+                })
+                .collect::<Result<Vec<_>>>()?
+        };
+
+        if asc == Some(false) {
+            rows.reverse();
+        }
+
+        Ok(Box::new(rows.into_iter().map(Ok)))
+    }
+}
+
+impl IndexMut for JsonStorage {
+    fn create_index(
+        &mut self,
+        table_name: &str,
+        index_name: &str,
+        column: &OrderByExpr,
+    ) -> Result<()> {
+        let mut schema = self
+            .fetch_schema(table_name)?
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+
+        if schema.indexes.iter().any(|index| index.name == index_name) {
+            return Err(IndexError::IndexNameAlreadyExists(index_name.to_owned()).into());
+        }
+
+        let index = SchemaIndex {
+            name: index_name.to_owned(),
+            expr: column.expr.clone(),
+            order: SchemaIndexOrd::Both,
+            created: Utc::now().naive_utc(),
+        };
+        schema.indexes.push(index.clone());
+
+        let schema_path = self.schema_path(table_name);
+        self.write_file(&schema_path, schema.to_ddl().as_bytes())?;
+        self.rebuild_index(&schema, &index)
+    }
+
+    fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        let mut schema = self
+            .fetch_schema(table_name)?
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+
+        let Some(position) = schema
+            .indexes
+            .iter()
+            .position(|index| index.name == index_name)
+        else {
+            return Err(IndexError::IndexNameDoesNotExist(index_name.to_owned()).into());
+        };
+        schema.indexes.remove(position);
+
+        let schema_path = self.schema_path(table_name);
+        self.write_file(&schema_path, schema.to_ddl().as_bytes())?;
+
+        let index_path = self.index_path(table_name, index_name);
+        self.remove_file(&index_path)
+    }
+}
@@ -2,6 +2,7 @@ mod alter_table;
 pub mod error;
 mod function;
 mod index;
+mod planner;
 mod store;
 mod store_mut;
 mod transaction;
@@ -12,7 +13,7 @@ use {
         ast::ColumnUniqueOption,
         data::{Key, Schema, Value, value::BTreeMapJsonExt},
         error::{Error, Result},
-        store::{Metadata, Planner, Store},
+        store::{Metadata, Store},
     },
     iter_enum::Iterator,
     serde_json::Value as JsonValue,
@@ -22,6 +23,7 @@ use {
         io::{self, BufRead},
         path::{Path, PathBuf},
     },
+    transaction::Journal,
 };
 
 type RowIter = Box<dyn Iterator<Item = Result<(Key, Vec<Value>)>>>;
@@ -29,14 +31,19 @@ type RowIter = Box<dyn Iterator<Item = Result<(Key, Vec<Value>)>>>;
 #[derive(Clone, Debug)]
 pub struct JsonStorage {
     pub path: PathBuf,
+    journal: Option<Journal>,
 }
 
 impl JsonStorage {
     pub fn new<T: AsRef<Path>>(path: T) -> Result<Self> {
         let path = path.as_ref();
         fs::create_dir_all(path).map_storage_err()?;
+        Journal::recover(path)?;
 
-        Ok(Self { path: path.into() })
+        Ok(Self {
+            path: path.into(),
+            journal: None,
+        })
     }
 
     fn jsonl_path(&self, table_name: &str) -> PathBuf {
@@ -68,8 +75,10 @@ impl JsonStorage {
 
         let schema = <Self as Store>::fetch_schema(self, table_name)?
             .map_storage_err(JsonStorageError::TableDoesNotExist)?;
-        let json_path = self.json_path(table_name);
-        let jsons = if let Ok(json_file_str) = fs::read_to_string(json_path) {
+        let json_path = self.resolve(&self.json_path(table_name));
+        let jsons = if let Some(json_file_str) =
+            json_path.and_then(|path| fs::read_to_string(path).ok())
+        {
             let value = serde_json::from_str(&json_file_str).map_err(|_| {
                 Error::StorageMsg(
                     JsonStorageError::InvalidJsonContent(format!("{table_name}.json")).to_string(),
@@ -94,7 +103,9 @@ impl JsonStorage {
 
             Extension::Json(jsons.into_iter().map(Ok))
         } else {
-            let jsonl_path = self.jsonl_path(table_name);
+            let jsonl_path = self
+                .resolve(&self.jsonl_path(table_name))
+                .map_storage_err(JsonStorageError::FileNotFound)?;
             let lines = read_lines(jsonl_path).map_storage_err()?;
             let jsons = lines.map(|line| BTreeMap::parse_json_object(&line.map_storage_err()?));
 
@@ -102,49 +113,59 @@ impl JsonStorage {
         };
 
         let schema2 = schema.clone();
-        let rows = jsons.enumerate().map(move |(index, json)| -> Result<_> {
-            let json = json?;
-            let get_index_key = || index.try_into().map(Key::I64).map_storage_err();
-
-            let Some(column_defs) = &schema2.column_defs else {
-                let key = get_index_key()?;
-                let row = vec![Value::Map(json)];
-
-                return Ok((key, row));
-            };
-
-            let mut key: Option<Key> = None;
-            let mut values = Vec::with_capacity(column_defs.len());
-            for column_def in column_defs {
-                let value = json.get(&column_def.name).map_storage_err(
-                    JsonStorageError::ColumnDoesNotExist(column_def.name.clone()),
-                )?;
-
-                if column_def.unique == Some(ColumnUniqueOption { is_primary: true }) {
-                    let value = value.cast(&column_def.data_type)?;
-                    key = Some(value.try_into().map_storage_err()?);
-                }
-
-                let value = match value.get_type() {
-                    Some(data_type) if data_type != column_def.data_type => {
-                        value.cast(&column_def.data_type)?
-                    }
-                    Some(_) | None => value.clone(),
-                };
-
-                values.push(value);
-            }
+        let rows = jsons
+            .enumerate()
+            .map(move |(index, json)| to_row(&schema2, index, json?));
 
-            let key = match key {
-                Some(key) => key,
-                None => get_index_key()?,
-            };
+        Ok((Box::new(rows), schema))
+    }
+}
 
-            Ok((key, values))
-        });
+/// Converts the JSON object at `index` of a data file into a keyed row.
+fn to_row(
+    schema: &Schema,
+    index: usize,
+    json: BTreeMap<String, Value>,
+) -> Result<(Key, Vec<Value>)> {
+    let get_index_key = || index.try_into().map(Key::I64).map_storage_err();
+
+    let Some(column_defs) = &schema.column_defs else {
+        let key = get_index_key()?;
+        let row = vec![Value::Map(json)];
+
+        return Ok((key, row));
+    };
+
+    let mut key: Option<Key> = None;
+    let mut values = Vec::with_capacity(column_defs.len());
+    for column_def in column_defs {
+        let value =
+            json.get(&column_def.name)
+                .map_storage_err(JsonStorageError::ColumnDoesNotExist(
+                    column_def.name.clone(),
+                ))?;
+
+        if column_def.unique == Some(ColumnUniqueOption { is_primary: true }) {
+            let value = value.cast(&column_def.data_type)?;
+            key = Some(value.try_into().map_storage_err()?);
+        }
 
-        Ok((Box::new(rows), schema))
+        let value = match value.get_type() {
+            Some(data_type) if data_type != column_def.data_type => {
+                value.cast(&column_def.data_type)?
+            }
+            Some(_) | None => value.clone(),
+        };
+
+        values.push(value);
     }
+
+    let key = match key {
+        Some(key) => key,
+        None => get_index_key()?,
+    };
+
+    Ok((key, values))
 }
 
 fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
@@ -156,4 +177,3 @@ where
 }
 
 impl Metadata for JsonStorage {}
-impl Planner for JsonStorage {}
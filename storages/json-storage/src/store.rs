@@ -8,18 +8,14 @@ use {
         error::{Error, Result},
         store::{RowIter, Store},
     },
-    std::{
-        ffi::OsStr,
-        fs::{self, File},
-        io::Read,
-    },
+    std::{ffi::OsStr, fs::File, io::Read},
 };
 
 impl Store for JsonStorage {
     fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
         match (
-            self.jsonl_path(table_name).exists(),
-            self.json_path(table_name).exists(),
+            self.file_exists(&self.jsonl_path(table_name)),
+            self.file_exists(&self.json_path(table_name)),
         ) {
             (true, true) => {
                 return Err(Error::StorageMsg(
@@ -30,39 +26,47 @@ impl Store for JsonStorage {
             _ => {}
         }
 
-        let schema_path = self.schema_path(table_name);
-        let (column_defs, foreign_keys, comment) = if schema_path.exists() {
-            let mut file = File::open(&schema_path).map_storage_err()?;
-            let mut ddl = String::new();
-            file.read_to_string(&mut ddl).map_storage_err()?;
+        let schema_path = self.resolve(&self.schema_path(table_name));
+        let (column_defs, indexes, foreign_keys, comment, ttl_column) =
+            if let Some(schema_path) = schema_path {
+                let mut file = File::open(&schema_path).map_storage_err()?;
+                let mut ddl = String::new();
+                file.read_to_string(&mut ddl).map_storage_err()?;
 
-            let schema = Schema::from_ddl(&ddl)?;
-            if schema.table_name != table_name {
-                return Err(Error::StorageMsg(
-                    JsonStorageError::TableNameDoesNotMatchWithFile.to_string(),
-                ));
-            }
+                let schema = Schema::from_ddl(&ddl)?;
+                if schema.table_name != table_name {
+                    return Err(Error::StorageMsg(
+                        JsonStorageError::TableNameDoesNotMatchWithFile.to_string(),
+                    ));
+                }
 
-            (schema.column_defs, schema.foreign_keys, schema.comment)
-        } else {
-            (None, Vec::new(), None)
-        };
+                (
+                    schema.column_defs,
+                    schema.indexes,
+                    schema.foreign_keys,
+                    schema.comment,
+                    schema.ttl_column,
+                )
+            } else {
+                (None, Vec::new(), Vec::new(), None, None)
+            };
 
         Ok(Some(Schema {
             table_name: table_name.to_owned(),
             column_defs,
-            indexes: vec![],
+            indexes,
             engine: None,
             foreign_keys,
             comment,
+            ttl_column,
         }))
     }
 
     fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
-        let paths = fs::read_dir(&self.path).map_storage_err()?;
+        let paths = self.file_paths()?;
         let mut schemas = paths
-            .map(|result| {
-                let path = result.map_storage_err()?.path();
+            .into_iter()
+            .map(|path| {
                 let extension = path.extension().and_then(OsStr::to_str);
                 if extension != Some("jsonl") && extension != Some("json") {
                     return Ok(None);
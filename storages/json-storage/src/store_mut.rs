@@ -2,6 +2,7 @@ use {
     crate::{
         JsonStorage,
         error::{JsonStorageError, OptionExt, ResultExt},
+        index::{line_offsets, table_index_keys},
     },
     gluesql_core::{
         data::{Key, Schema, Value},
@@ -9,42 +10,45 @@ use {
         store::{Store, StoreMut},
     },
     serde_json::{Map, Value as JsonValue, to_string_pretty},
-    std::{
-        cmp::Ordering,
-        fs::{File, OpenOptions, remove_file},
-        io::Write as IoWrite,
-        iter::Peekable,
-        vec::IntoIter,
-    },
+    std::{cmp::Ordering, io::Write as IoWrite, iter::Peekable, vec::IntoIter},
 };
 
 impl StoreMut for JsonStorage {
     fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
         let data_path = self.jsonl_path(schema.table_name.as_str());
-        File::create(data_path).map_storage_err()?;
+        self.write_file(&data_path, &[])?;
 
         let schema_path = self.schema_path(schema.table_name.as_str());
         let ddl = schema.to_ddl();
-        let mut file = File::create(schema_path).map_storage_err()?;
-
-        file.write_all(ddl.as_bytes()).map_storage_err()?;
 
-        Ok(())
+        self.write_file(&schema_path, ddl.as_bytes())
     }
 
     fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        let indexes = self
+            .fetch_schema(table_name)
+            .ok()
+            .flatten()
+            .map(|schema| schema.indexes)
+            .unwrap_or_default();
+
         let json_path = self.json_path(table_name);
         let jsonl_path = self.jsonl_path(table_name);
 
-        match (json_path.exists(), jsonl_path.exists()) {
-            (true, false) => remove_file(json_path).map_storage_err()?,
-            (false, true) => remove_file(jsonl_path).map_storage_err()?,
+        match (self.file_exists(&json_path), self.file_exists(&jsonl_path)) {
+            (true, false) => self.remove_file(&json_path)?,
+            (false, true) => self.remove_file(&jsonl_path)?,
             _ => {}
         }
 
         let schema_path = self.schema_path(table_name);
-        if schema_path.exists() {
-            remove_file(schema_path).map_storage_err()?;
+        if self.file_exists(&schema_path) {
+            self.remove_file(&schema_path)?;
+        }
+
+        for index in indexes {
+            let index_path = self.index_path(table_name, &index.name);
+            self.remove_file(&index_path)?;
         }
 
         Ok(())
@@ -52,7 +56,7 @@ impl StoreMut for JsonStorage {
 
     fn append_data(&mut self, table_name: &str, rows: Vec<Vec<Value>>) -> Result<()> {
         let json_path = self.json_path(table_name);
-        if json_path.exists() {
+        if self.file_exists(&json_path) {
             let (prev_rows, schema) = self.scan_data(table_name)?;
 
             let rows = prev_rows
@@ -60,21 +64,17 @@ impl StoreMut for JsonStorage {
                 .chain(rows.into_iter().map(Ok))
                 .collect::<Result<Vec<_>>>()?;
 
-            let file = File::create(&json_path).map_storage_err()?;
-
-            Self::write(&schema, rows, file, true)
-        } else {
-            let schema = self
-                .fetch_schema(table_name)?
-                .map_storage_err(JsonStorageError::TableDoesNotExist)?;
+            return self.rewrite(&schema, rows);
+        }
 
-            let file = OpenOptions::new()
-                .append(true)
-                .open(self.jsonl_path(&schema.table_name))
-                .map_storage_err()?;
+        let schema = self
+            .fetch_schema(table_name)?
+            .map_storage_err(JsonStorageError::TableDoesNotExist)?;
+        let appends = self.prepare_index_append(&schema, &rows)?;
 
-            Self::write(&schema, rows, file, false)
-        }
+        let contents = Self::serialize(&schema, rows, false)?;
+        self.append_file(&self.jsonl_path(&schema.table_name), &contents)?;
+        self.append_indexes(&schema, appends, &line_offsets(&contents))
     }
 
     fn insert_data(&mut self, table_name: &str, mut rows: Vec<(Key, Vec<Value>)>) -> Result<()> {
@@ -154,19 +154,22 @@ where
 impl JsonStorage {
     fn rewrite(&mut self, schema: &Schema, rows: Vec<Vec<Value>>) -> Result<()> {
         let json_path = self.json_path(&schema.table_name);
-        let (path, is_json) = if json_path.exists() {
+        let (path, is_json) = if self.file_exists(&json_path) {
             (json_path, true)
         } else {
             let jsonl_path = self.jsonl_path(&schema.table_name);
 
             (jsonl_path, false)
         };
-        let file = File::create(path).map_storage_err()?;
 
-        Self::write(schema, rows, file, is_json)
+        let keys = table_index_keys(schema, &rows)?;
+        let contents = Self::serialize(schema, rows, is_json)?;
+        let offsets = (!is_json).then(|| line_offsets(&contents));
+        self.write_file(&path, &contents)?;
+        self.write_indexes(schema, keys, &offsets)
     }
 
-    fn write(schema: &Schema, rows: Vec<Vec<Value>>, mut file: File, is_json: bool) -> Result<()> {
+    fn serialize(schema: &Schema, rows: Vec<Vec<Value>>, is_json: bool) -> Result<Vec<u8>> {
         let rows = if let Some(column_defs) = &schema.column_defs {
             // Schema table: zip labels with values
             let labels = column_defs
@@ -205,15 +208,16 @@ impl JsonStorage {
                 .collect::<Result<Vec<_>>>()?
         };
 
+        let mut contents = Vec::new();
         if is_json {
             let json_str = to_string_pretty(&JsonValue::Array(rows)).map_storage_err()?;
-            file.write_all(json_str.as_bytes()).map_storage_err()?;
+            contents.extend(json_str.as_bytes());
         } else {
             for row in rows {
-                writeln!(file, "{row}").map_storage_err()?;
+                writeln!(contents, "{row}").map_storage_err()?;
             }
         }
 
-        Ok(())
+        Ok(contents)
     }
 }
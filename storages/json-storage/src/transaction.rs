@@ -1,3 +1,262 @@
-use {super::JsonStorage, gluesql_core::store::Transaction};
+use {
+    super::JsonStorage,
+    crate::error::{JsonStorageError, OptionExt, ResultExt},
+    gluesql_core::{error::Result, store::Transaction},
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::BTreeMap,
+        ffi::OsStr,
+        fs::{self, OpenOptions},
+        io::Write,
+        path::{Path, PathBuf},
+    },
+};
 
-impl Transaction for JsonStorage {}
+const JOURNAL_DIR: &str = ".journal";
+const MANIFEST_FILE: &str = "COMMIT";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+enum Change {
+    Write,
+    Remove,
+}
+
+/// Files changed by the running transaction, keyed by file name.
+///
+/// Written files are staged under the `.journal` directory and only renamed
+/// over their targets on commit, so readers outside the transaction never
+/// observe a partially applied statement.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Journal {
+    changes: BTreeMap<String, Change>,
+}
+
+impl Journal {
+    /// Finishes a commit interrupted after its manifest was written and
+    /// discards the staged files of any transaction that never committed.
+    pub(crate) fn recover(path: &Path) -> Result<()> {
+        let journal_dir = path.join(JOURNAL_DIR);
+        if !journal_dir.exists() {
+            return Ok(());
+        }
+
+        let manifest_path = journal_dir.join(MANIFEST_FILE);
+        if manifest_path.exists() {
+            let manifest = fs::read_to_string(manifest_path).map_storage_err()?;
+            let changes = serde_json::from_str(&manifest)
+                .map_err(|_| JsonStorageError::InvalidJournal)
+                .map_storage_err()?;
+
+            Self::apply(path, &changes)?;
+        }
+
+        fs::remove_dir_all(journal_dir).map_storage_err()
+    }
+
+    fn commit(self, path: &Path) -> Result<()> {
+        let journal_dir = path.join(JOURNAL_DIR);
+        if self.changes.is_empty() {
+            return remove_dir_if_exists(&journal_dir);
+        }
+
+        let manifest = serde_json::to_string_pretty(&self.changes).map_storage_err()?;
+        let manifest_path = journal_dir.join(MANIFEST_FILE);
+        write_atomic(&manifest_path, manifest.as_bytes())?;
+
+        Self::apply(path, &self.changes)?;
+
+        fs::remove_dir_all(journal_dir).map_storage_err()
+    }
+
+    fn apply(path: &Path, changes: &BTreeMap<String, Change>) -> Result<()> {
+        let journal_dir = path.join(JOURNAL_DIR);
+
+        for (file_name, change) in changes {
+            let target = path.join(file_name);
+
+            match change {
+                Change::Write => {
+                    let staged = journal_dir.join(file_name);
+                    if staged.exists() {
+                        fs::rename(staged, target).map_storage_err()?;
+                    }
+                }
+                Change::Remove => {
+                    if target.exists() {
+                        fs::remove_file(target).map_storage_err()?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl JsonStorage {
+    fn journal_dir(&self) -> PathBuf {
+        self.path.join(JOURNAL_DIR)
+    }
+
+    /// Returns where the current contents of `path` live, looking at the
+    /// files staged by the running transaction first.
+    pub(crate) fn resolve(&self, path: &Path) -> Option<PathBuf> {
+        let change = self.journal.as_ref().and_then(|journal| {
+            let file_name = path.file_name().and_then(OsStr::to_str)?;
+
+            journal.changes.get(file_name)
+        });
+
+        match change {
+            Some(Change::Write) => Some(self.journal_dir().join(path.file_name()?)),
+            Some(Change::Remove) => None,
+            None => path.exists().then(|| path.to_path_buf()),
+        }
+    }
+
+    pub(crate) fn file_exists(&self, path: &Path) -> bool {
+        self.resolve(path).is_some()
+    }
+
+    /// Lists the files of the storage directory as seen by the running
+    /// transaction.
+    pub(crate) fn file_paths(&self) -> Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(&self.path).map_storage_err()? {
+            let path = entry.map_storage_err()?.path();
+
+            if path.is_file() {
+                paths.push(path);
+            }
+        }
+
+        if let Some(journal) = &self.journal {
+            paths.retain(|path| {
+                let file_name = path.file_name().and_then(OsStr::to_str);
+
+                file_name.and_then(|name| journal.changes.get(name)) != Some(&Change::Remove)
+            });
+            paths.extend(
+                journal
+                    .changes
+                    .iter()
+                    .filter(|(_, change)| **change == Change::Write)
+                    .map(|(file_name, _)| self.path.join(file_name)),
+            );
+            paths.sort();
+            paths.dedup();
+        }
+
+        Ok(paths)
+    }
+
+    pub(crate) fn write_file(&mut self, path: &Path, contents: &[u8]) -> Result<()> {
+        match self.stage(path, Change::Write)? {
+            Some(staged) => fs::write(staged, contents).map_storage_err(),
+            None => write_atomic(path, contents),
+        }
+    }
+
+    pub(crate) fn append_file(&mut self, path: &Path, contents: &[u8]) -> Result<()> {
+        let current = self.resolve(path);
+        let target = match self.stage(path, Change::Write)? {
+            Some(staged) => {
+                if let Some(current) = current.filter(|current| current != &staged) {
+                    fs::copy(current, &staged).map_storage_err()?;
+                }
+
+                staged
+            }
+            None => path.to_path_buf(),
+        };
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(target)
+            .map_storage_err()?
+            .write_all(contents)
+            .map_storage_err()
+    }
+
+    pub(crate) fn remove_file(&mut self, path: &Path) -> Result<()> {
+        match self.stage(path, Change::Remove)? {
+            Some(staged) => remove_file_if_exists(&staged),
+            None => remove_file_if_exists(path),
+        }
+    }
+
+    /// Records `change` in the running transaction and returns the staged
+    /// path, or `None` when no transaction is running.
+    fn stage(&mut self, path: &Path, change: Change) -> Result<Option<PathBuf>> {
+        let journal_dir = self.journal_dir();
+        let Some(journal) = self.journal.as_mut() else {
+            return Ok(None);
+        };
+
+        let file_name = path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .map_storage_err(JsonStorageError::FileNotFound)?;
+
+        fs::create_dir_all(&journal_dir).map_storage_err()?;
+        journal.changes.insert(file_name.to_owned(), change);
+
+        Ok(Some(journal_dir.join(file_name)))
+    }
+}
+
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+
+    fs::write(&temp_path, contents).map_storage_err()?;
+    fs::rename(temp_path, path).map_storage_err()
+}
+
+fn remove_file_if_exists(path: &Path) -> Result<()> {
+    if path.exists() {
+        fs::remove_file(path).map_storage_err()?;
+    }
+
+    Ok(())
+}
+
+fn remove_dir_if_exists(path: &Path) -> Result<()> {
+    if path.exists() {
+        fs::remove_dir_all(path).map_storage_err()?;
+    }
+
+    Ok(())
+}
+
+impl Transaction for JsonStorage {
+    fn begin(&mut self, autocommit: bool) -> Result<bool> {
+        match (&self.journal, autocommit) {
+            (Some(_), true) => Ok(false),
+            (Some(_), false) => {
+                Err(JsonStorageError::NestedTransactionNotSupported).map_storage_err()
+            }
+            (None, _) => {
+                self.journal = Some(Journal::default());
+
+                Ok(autocommit)
+            }
+        }
+    }
+
+    fn rollback(&mut self) -> Result<()> {
+        if self.journal.take().is_some() {
+            remove_dir_if_exists(&self.journal_dir())?;
+        }
+
+        Ok(())
+    }
+
+    fn commit(&mut self) -> Result<()> {
+        match self.journal.take() {
+            Some(journal) => journal.commit(&self.path),
+            None => Ok(()),
+        }
+    }
+}
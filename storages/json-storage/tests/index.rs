@@ -0,0 +1,119 @@
+use {
+    gluesql_core::prelude::{Glue, Value::*},
+    gluesql_json_storage::JsonStorage,
+    std::fs::{self, File, remove_dir_all},
+    test_suite::{concat_with, row, select, stringify_label},
+};
+
+fn new_path(namespace: &str) -> String {
+    let path = format!("tmp/{namespace}");
+    remove_dir_all(&path).unwrap_or(());
+
+    path
+}
+
+#[test]
+fn append_extends_sidecar() {
+    let path = new_path("json_index_append");
+    let mut glue = Glue::new(JsonStorage::new(&path).unwrap());
+    let index_path = glue.storage.path.join("Item.idx_id.idx");
+
+    glue.execute("CREATE TABLE Item (id INTEGER);").unwrap();
+    glue.execute("CREATE INDEX idx_id ON Item (id);").unwrap();
+    glue.execute("INSERT INTO Item VALUES (3);").unwrap();
+    glue.execute("INSERT INTO Item VALUES (1), (2);").unwrap();
+
+    // one footer from CREATE INDEX, then the entries and footer of each INSERT
+    assert_eq!(fs::read_to_string(&index_path).unwrap().lines().count(), 6);
+    assert_eq!(
+        glue.execute("SELECT id FROM Item WHERE id > 1 ORDER BY id;")
+            .unwrap()
+            .remove(0),
+        select!(id I64; 2; 3)
+    );
+
+    glue.execute("DELETE FROM Item WHERE id = 2;").unwrap();
+    assert_eq!(fs::read_to_string(&index_path).unwrap().lines().count(), 3);
+    assert_eq!(
+        glue.execute("SELECT id FROM Item ORDER BY id DESC;")
+            .unwrap()
+            .remove(0),
+        select!(id I64; 3; 1)
+    );
+}
+
+#[test]
+fn edited_data_file_ignores_sidecar() {
+    let path = new_path("json_index_edited");
+    let mut glue = Glue::new(JsonStorage::new(&path).unwrap());
+    let data_path = glue.storage.path.join("Item.jsonl");
+
+    glue.execute("CREATE TABLE Item (id INTEGER);").unwrap();
+    glue.execute("CREATE INDEX idx_id ON Item (id);").unwrap();
+    glue.execute("INSERT INTO Item VALUES (1), (2), (3);")
+        .unwrap();
+
+    fs::write(&data_path, "{\"id\":9}\n{\"id\":7}\n{\"id\":8}\n").unwrap();
+
+    assert_eq!(
+        glue.execute("SELECT id FROM Item WHERE id >= 8 ORDER BY id;")
+            .unwrap()
+            .remove(0),
+        select!(id I64; 8; 9)
+    );
+
+    glue.execute("INSERT INTO Item VALUES (6);").unwrap();
+    assert_eq!(
+        glue.execute("SELECT id FROM Item WHERE id < 8 ORDER BY id;")
+            .unwrap()
+            .remove(0),
+        select!(id I64; 6; 7)
+    );
+}
+
+#[test]
+fn indexed_scan_reads_only_matching_rows() {
+    let path = new_path("json_index_offsets");
+    let mut glue = Glue::new(JsonStorage::new(&path).unwrap());
+    let data_path = glue.storage.path.join("Item.jsonl");
+
+    glue.execute("CREATE TABLE Item (id INTEGER, name TEXT);")
+        .unwrap();
+    glue.execute("CREATE INDEX idx_id ON Item (id);").unwrap();
+    glue.execute("INSERT INTO Item VALUES (3, 'c'), (1, 'a');")
+        .unwrap();
+    glue.execute("INSERT INTO Item VALUES (2, 'b');").unwrap();
+
+    // Break the row with id 1 without changing the length or modification
+    // time of the data file, so the sidecar still matches it.
+    let modified = fs::metadata(&data_path).unwrap().modified().unwrap();
+    let contents = fs::read_to_string(&data_path)
+        .unwrap()
+        .replace(r#"{"id":1,"name":"a"}"#, "not a json object!!");
+    fs::write(&data_path, contents).unwrap();
+    File::options()
+        .write(true)
+        .open(&data_path)
+        .unwrap()
+        .set_modified(modified)
+        .unwrap();
+
+    assert!(glue.execute("SELECT id FROM Item;").is_err());
+    assert_eq!(
+        glue.execute("SELECT id, name FROM Item WHERE id >= 2;")
+            .unwrap()
+            .remove(0),
+        select!(
+            id  | name
+            I64 | Str;
+            2     "b".to_owned();
+            3     "c".to_owned()
+        )
+    );
+    assert_eq!(
+        glue.execute("SELECT name FROM Item WHERE id = 3;")
+            .unwrap()
+            .remove(0),
+        select!(name Str; "c".to_owned())
+    );
+}
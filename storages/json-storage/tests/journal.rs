@@ -0,0 +1,73 @@
+use {
+    gluesql_core::prelude::{Glue, Value::*},
+    gluesql_json_storage::JsonStorage,
+    std::fs::{self, remove_dir_all},
+    test_suite::{concat_with, row, select, stringify_label},
+};
+
+fn new_path(namespace: &str) -> String {
+    let path = format!("tmp/{namespace}");
+    remove_dir_all(&path).unwrap_or(());
+
+    path
+}
+
+#[test]
+fn transaction_stages_writes_until_commit() {
+    let path = new_path("json_journal_commit");
+    let mut glue = Glue::new(JsonStorage::new(&path).unwrap());
+    let data_path = glue.storage.path.join("Item.jsonl");
+    let journal_path = glue.storage.path.join(".journal");
+
+    glue.execute("CREATE TABLE Item (id INTEGER, name TEXT);")
+        .unwrap();
+    glue.execute("INSERT INTO Item VALUES (1, 'a');").unwrap();
+    assert!(!journal_path.exists());
+
+    glue.execute("BEGIN;").unwrap();
+    glue.execute("INSERT INTO Item VALUES (2, 'b');").unwrap();
+    glue.execute("CREATE TABLE Tag (name TEXT);").unwrap();
+
+    assert_eq!(fs::read_to_string(&data_path).unwrap().lines().count(), 1);
+    assert!(!glue.storage.path.join("Tag.jsonl").exists());
+    assert_eq!(
+        glue.execute("SELECT id FROM Item;").unwrap().remove(0),
+        select!(id I64; 1; 2)
+    );
+
+    glue.execute("COMMIT;").unwrap();
+
+    assert_eq!(fs::read_to_string(&data_path).unwrap().lines().count(), 2);
+    assert!(glue.storage.path.join("Tag.jsonl").exists());
+    assert!(!journal_path.exists());
+}
+
+#[test]
+fn recover_replays_committed_journal() {
+    let path = new_path("json_journal_recover");
+    let mut glue = Glue::new(JsonStorage::new(&path).unwrap());
+    glue.execute("CREATE TABLE Item (id INTEGER);").unwrap();
+    glue.execute("INSERT INTO Item VALUES (1);").unwrap();
+
+    let journal_path = glue.storage.path.join(".journal");
+    fs::create_dir_all(&journal_path).unwrap();
+    fs::write(journal_path.join("Item.jsonl"), "{\"id\":1}\n{\"id\":2}\n").unwrap();
+    fs::write(journal_path.join("COMMIT"), r#"{"Item.jsonl":"Write"}"#).unwrap();
+
+    let mut glue = Glue::new(JsonStorage::new(&path).unwrap());
+    assert!(!journal_path.exists());
+    assert_eq!(
+        glue.execute("SELECT id FROM Item;").unwrap().remove(0),
+        select!(id I64; 1; 2)
+    );
+
+    fs::create_dir_all(&journal_path).unwrap();
+    fs::write(journal_path.join("Item.jsonl"), "{\"id\":3}\n").unwrap();
+
+    let mut glue = Glue::new(JsonStorage::new(&path).unwrap());
+    assert!(!journal_path.exists());
+    assert_eq!(
+        glue.execute("SELECT id FROM Item;").unwrap().remove(0),
+        select!(id I64; 1; 2)
+    );
+}
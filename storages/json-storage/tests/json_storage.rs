@@ -27,3 +27,6 @@ impl Tester<JsonStorage> for JsonTester {
 
 generate_store_tests!(test, JsonTester);
 generate_alter_table_tests!(test, JsonTester);
+generate_transaction_tests!(test, JsonTester);
+generate_index_tests!(test, JsonTester);
+generate_transaction_index_tests!(test, JsonTester);
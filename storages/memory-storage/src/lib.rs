@@ -8,9 +8,14 @@ mod transaction;
 use {
     gluesql_core::{
         chrono::Utc,
-        data::{CustomFunction as StructCustomFunction, Key, Schema, Value},
+        data::{
+            CustomFunction as StructCustomFunction, Key, Schema, Trigger as StructTrigger, Value,
+        },
         error::Result,
-        store::{CustomFunction, CustomFunctionMut, Planner, RowIter, Store, StoreMut},
+        store::{
+            CustomFunction, CustomFunctionMut, Planner, RowIter, Store, StoreMut, Trigger,
+            TriggerMut,
+        },
     },
     serde::{Deserialize, Serialize},
     std::collections::{BTreeMap, HashMap},
@@ -28,9 +33,24 @@ pub struct MemoryStorage {
     pub items: HashMap<String, Item>,
     pub metadata: HashMap<String, BTreeMap<String, Value>>,
     pub functions: HashMap<String, StructCustomFunction>,
+    pub triggers: HashMap<String, Vec<StructTrigger>>,
+    /// State the open transaction began from, restored by `ROLLBACK`.
+    #[serde(skip)]
+    snapshot: Option<Box<MemoryStorage>>,
 }
 
 impl MemoryStorage {
+    /// Whether an explicit transaction is open.
+    pub fn in_transaction(&self) -> bool {
+        self.snapshot.is_some()
+    }
+
+    /// State as of the last commit, which is `self` unless a transaction is
+    /// open.
+    pub fn committed(&self) -> &Self {
+        self.snapshot.as_deref().unwrap_or(self)
+    }
+
     pub fn scan_data(&self, table_name: &str) -> Vec<(Key, Vec<Value>)> {
         match self.items.get(table_name) {
             Some(item) => item.rows.clone().into_iter().collect(),
@@ -60,6 +80,33 @@ impl CustomFunctionMut for MemoryStorage {
     }
 }
 
+impl Trigger for MemoryStorage {
+    fn fetch_triggers(&self, table_name: &str) -> Result<Vec<StructTrigger>> {
+        Ok(self.triggers.get(table_name).cloned().unwrap_or_default())
+    }
+
+    fn fetch_all_triggers(&self) -> Result<Vec<StructTrigger>> {
+        Ok(self.triggers.values().flatten().cloned().collect())
+    }
+}
+
+impl TriggerMut for MemoryStorage {
+    fn insert_trigger(&mut self, trigger: StructTrigger) -> Result<()> {
+        self.triggers
+            .entry(trigger.table_name.clone())
+            .or_default()
+            .push(trigger);
+        Ok(())
+    }
+
+    fn delete_trigger(&mut self, table_name: &str, trigger_name: &str) -> Result<()> {
+        if let Some(triggers) = self.triggers.get_mut(table_name) {
+            triggers.retain(|trigger| trigger.name != trigger_name);
+        }
+        Ok(())
+    }
+}
+
 impl Store for MemoryStorage {
     fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
         let mut schemas = self
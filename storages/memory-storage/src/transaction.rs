@@ -6,22 +6,39 @@ use {
     },
 };
 
+/// `BEGIN` copies the whole storage, and `ROLLBACK` restores that copy.
 impl Transaction for MemoryStorage {
     fn begin(&mut self, autocommit: bool) -> Result<bool> {
         if autocommit {
             return Ok(false);
         }
 
-        Err(Error::StorageMsg(
-            "[MemoryStorage] transaction is not supported".to_owned(),
-        ))
+        if self.in_transaction() {
+            return Err(Error::StorageMsg(
+                "[MemoryStorage] nested transaction is not supported".to_owned(),
+            ));
+        }
+
+        self.snapshot = Some(Box::new(self.clone()));
+
+        Ok(false)
     }
 
     fn rollback(&mut self) -> Result<()> {
+        if let Some(snapshot) = self.snapshot.take() {
+            *self = *snapshot;
+        }
+
         Ok(())
     }
 
     fn commit(&mut self) -> Result<()> {
+        self.snapshot = None;
+
         Ok(())
     }
+
+    fn durable(&self) -> bool {
+        false
+    }
 }
@@ -25,6 +25,12 @@ generate_metadata_table_tests!(test, MemoryTester);
 
 generate_custom_function_tests!(test, MemoryTester);
 
+generate_trigger_tests!(test, MemoryTester);
+
+generate_ttl_tests!(test, MemoryTester);
+
+generate_transaction_tests!(test, MemoryTester);
+
 macro_rules! exec {
     ($glue: ident $sql: literal) => {
         $glue.execute($sql).unwrap();
@@ -79,15 +85,23 @@ fn memory_storage_index() {
 
 #[test]
 fn memory_storage_transaction() {
-    use gluesql_core::prelude::{Error, Glue, Payload};
+    use gluesql_core::prelude::{Error, Glue, Payload, Value};
 
     let storage = MemoryStorage::default();
     let mut glue = Glue::new(storage);
 
     exec!(glue "CREATE TABLE TxTest (id INTEGER);");
-    test!(glue "BEGIN", Err(Error::StorageMsg("[MemoryStorage] transaction is not supported".to_owned())));
+    test!(glue "BEGIN", Ok(vec![Payload::StartTransaction]));
+    test!(glue "BEGIN", Err(Error::StorageMsg("[MemoryStorage] nested transaction is not supported".to_owned())));
+    exec!(glue "INSERT INTO TxTest VALUES (1);");
+    test!(glue "ROLLBACK", Ok(vec![Payload::Rollback]));
+    test!(glue "SELECT * FROM TxTest", Ok(vec![Payload::Select { labels: vec!["id".to_owned()], rows: Vec::new() }]));
+
+    exec!(glue "BEGIN;");
+    exec!(glue "INSERT INTO TxTest VALUES (2);");
     test!(glue "COMMIT", Ok(vec![Payload::Commit]));
     test!(glue "ROLLBACK", Ok(vec![Payload::Rollback]));
+    test!(glue "SELECT * FROM TxTest", Ok(vec![Payload::Select { labels: vec!["id".to_owned()], rows: vec![vec![Value::I64(2)]] }]));
 }
 
 #[test]
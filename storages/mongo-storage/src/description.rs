@@ -7,6 +7,8 @@ use {
 pub struct TableDescription {
     pub foreign_keys: Vec<ForeignKey>,
     pub comment: Option<String>,
+    #[serde(default)]
+    pub ttl_column: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
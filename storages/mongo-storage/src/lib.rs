@@ -1,5 +1,6 @@
 mod description;
 pub mod error;
+mod planner;
 pub mod row;
 mod store;
 mod store_mut;
@@ -10,8 +11,8 @@ use {
     gluesql_core::{
         error::Result,
         store::{
-            AlterTable, CustomFunction, CustomFunctionMut, Index, IndexMut, Metadata, Planner,
-            Transaction,
+            AlterTable, CustomFunction, CustomFunctionMut, Index, IndexMut, Metadata, Transaction,
+            Trigger, TriggerMut,
         },
     },
     mongodb::sync::{Client, Database},
@@ -38,7 +39,8 @@ impl Metadata for MongoStorage {}
 impl AlterTable for MongoStorage {}
 impl CustomFunction for MongoStorage {}
 impl CustomFunctionMut for MongoStorage {}
+impl Trigger for MongoStorage {}
+impl TriggerMut for MongoStorage {}
 impl Index for MongoStorage {}
 impl IndexMut for MongoStorage {}
 impl Transaction for MongoStorage {}
-impl Planner for MongoStorage {}
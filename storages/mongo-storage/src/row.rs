@@ -15,6 +15,14 @@ pub trait IntoRow {
         data_types: impl Iterator<Item = &'a DataType>,
         is_primary: bool,
     ) -> Result<(Key, Vec<Value>)>;
+
+    /// Builds a row from a projected document, reading `Value::Null` for
+    /// columns whose field is missing.
+    fn into_projected_row<'a>(
+        self,
+        columns: impl Iterator<Item = (&'a str, &'a DataType)>,
+        is_primary: bool,
+    ) -> Result<(Key, Vec<Value>)>;
 }
 
 impl IntoRow for Document {
@@ -23,16 +31,7 @@ impl IntoRow for Document {
         data_types: impl Iterator<Item = &'a DataType>,
         has_primary: bool,
     ) -> Result<(Key, Vec<Value>)> {
-        let key = if has_primary {
-            self.get_binary_generic("_id").map_storage_err()?.to_owned()
-        } else {
-            self.get_object_id("_id")
-                .map_storage_err()?
-                .bytes()
-                .to_vec()
-        };
-        let key = Key::Bytea(key);
-
+        let key = document_key(&self, has_primary)?;
         let row = self
             .into_iter()
             .skip(1)
@@ -42,4 +41,30 @@ impl IntoRow for Document {
 
         Ok((key, row))
     }
+
+    fn into_projected_row<'a>(
+        mut self,
+        columns: impl Iterator<Item = (&'a str, &'a DataType)>,
+        has_primary: bool,
+    ) -> Result<(Key, Vec<Value>)> {
+        let key = document_key(&self, has_primary)?;
+        let row = columns
+            .map(|(name, data_type)| match self.remove(name) {
+                Some(bson) => bson.into_value(data_type).map_storage_err(),
+                None => Ok(Value::Null),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((key, row))
+    }
+}
+
+fn document_key(doc: &Document, has_primary: bool) -> Result<Key> {
+    let key = if has_primary {
+        doc.get_binary_generic("_id").map_storage_err()?.to_owned()
+    } else {
+        doc.get_object_id("_id").map_storage_err()?.bytes().to_vec()
+    };
+
+    Ok(Key::Bytea(key))
 }
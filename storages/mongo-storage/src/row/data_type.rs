@@ -63,9 +63,13 @@ impl From<&DataType> for BsonType {
                 BsonType::Decimal128
             }
             DataType::Float32 | DataType::Float => BsonType::Double,
-            DataType::Text | DataType::Timestamp | DataType::Inet | DataType::Interval => {
-                BsonType::String
-            }
+            DataType::Text
+            | DataType::Timestamp
+            | DataType::Inet
+            | DataType::Interval
+            | DataType::LineString
+            | DataType::Polygon
+            | DataType::MultiPoint => BsonType::String,
             DataType::Bytea | DataType::Uuid => BsonType::Binary,
             DataType::Date | DataType::Time => BsonType::Date,
             DataType::Map | DataType::Point => BsonType::Object,
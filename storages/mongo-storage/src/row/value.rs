@@ -3,7 +3,7 @@ use {
     gluesql_core::{
         ast::{Expr, ToSql},
         chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc},
-        data::{Interval, Point, Value},
+        data::{Interval, LineString, MultiPoint, Point, Polygon, Value},
         parse_sql::parse_interval,
         prelude::DataType,
         translate::translate_expr,
@@ -59,6 +59,17 @@ impl IntoValue for Bson {
                 NaiveDateTime::parse_from_str(&string, "%Y-%m-%d %H:%M:%S%.f")
                     .map_err(|_| MongoStorageError::UnsupportedBsonType)?,
             ),
+            (Bson::String(string), DataType::LineString) => Value::LineString(
+                LineString::from_wkt(&string)
+                    .map_err(|_| MongoStorageError::UnsupportedBsonType)?,
+            ),
+            (Bson::String(string), DataType::Polygon) => Value::Polygon(
+                Polygon::from_wkt(&string).map_err(|_| MongoStorageError::UnsupportedBsonType)?,
+            ),
+            (Bson::String(string), DataType::MultiPoint) => Value::MultiPoint(
+                MultiPoint::from_wkt(&string)
+                    .map_err(|_| MongoStorageError::UnsupportedBsonType)?,
+            ),
             (Bson::String(string), DataType::Interval) => {
                 let interval =
                     parse_interval(string).map_err(|_| MongoStorageError::UnsupportedBsonType)?;
@@ -240,6 +251,9 @@ impl IntoBson for Value {
                 Ok(Bson::DateTime(datetime))
             }
             Value::Point(Point { x, y }) => Ok(Bson::Document(doc! {  "x": x, "y": y })),
+            Value::LineString(val) => Ok(Bson::String(val.to_string())),
+            Value::Polygon(val) => Ok(Bson::String(val.to_string())),
+            Value::MultiPoint(val) => Ok(Bson::String(val.to_string())),
             Value::Inet(val) => Ok(Bson::String(val.to_string())),
             Value::I16(val) => Ok(Bson::Int32(val.into())),
             Value::I128(val) => Ok(Bson::Decimal128(Decimal128::from_bytes(val.to_be_bytes()))),
@@ -3,21 +3,25 @@ use {
         MongoStorage,
         description::{ColumnDescription, TableDescription},
         error::{MongoStorageError, OptionExt, ResultExt},
-        row::{IntoRow, key::KeyIntoBson, value::IntoValue},
+        row::{
+            IntoRow,
+            key::KeyIntoBson,
+            value::{IntoBson, IntoValue},
+        },
         utils::get_primary_key,
     },
     gluesql_core::{
-        ast::{ColumnDef, ColumnUniqueOption},
+        ast::{ColumnDef, ColumnUniqueOption, IndexOperator},
         data::{Key, Schema},
         error::Result,
         parse_sql::parse_data_type,
-        prelude::{Error, Value},
-        store::{RowIter, Store},
+        prelude::{DataType, Error, Value},
+        store::{RowIter, ScanPredicate, Store},
         translate::translate_data_type,
     },
     mongodb::{
         IndexModel,
-        bson::{Document, doc, document::ValueAccessError},
+        bson::{Bson, Document, doc, document::ValueAccessError},
         options::{FindOptions, ListIndexesOptions},
     },
     serde_json::from_str,
@@ -130,6 +134,109 @@ impl Store for MongoStorage {
 
         Ok(Box::new(row_iter))
     }
+
+    fn scan_filtered_data<'a>(
+        &'a self,
+        table_name: &str,
+        predicates: &[ScanPredicate],
+        columns: Option<&[usize]>,
+    ) -> Result<RowIter<'a>> {
+        let Some(column_defs) = self.get_column_defs(table_name)? else {
+            return self.scan_data(table_name);
+        };
+
+        let mut filter = Document::new();
+        for predicate in predicates {
+            let Some(column_def) = column_defs.get(predicate.column) else {
+                continue;
+            };
+            let Some(bson) = comparable_bson(&column_def.data_type, &predicate.value) else {
+                continue;
+            };
+
+            let operator = match predicate.operator {
+                IndexOperator::Gt => "$gt",
+                IndexOperator::Lt => "$lt",
+                IndexOperator::GtEq => "$gte",
+                IndexOperator::LtEq => "$lte",
+                IndexOperator::Eq => "$eq",
+                IndexOperator::Intersects => continue,
+            };
+
+            if let Bson::Document(conditions) = filter
+                .entry(column_def.name.clone())
+                .or_insert_with(|| Bson::Document(Document::new()))
+            {
+                conditions.insert(operator, bson);
+            }
+        }
+
+        let primary_key = get_primary_key(&column_defs);
+        let has_primary = primary_key.is_some();
+        let projection = columns.map(|columns| {
+            columns
+                .iter()
+                .filter_map(|&index| column_defs.get(index))
+                .fold(doc! { "_id": 1 }, |mut projection, column_def| {
+                    projection.insert(column_def.name.clone(), 1);
+                    projection
+                })
+        });
+
+        let options = FindOptions::builder()
+            .projection(projection)
+            .sort(primary_key.map(|primary_key| doc! { primary_key.name.clone(): 1 }))
+            .build();
+
+        let cursor = self
+            .db
+            .collection::<Document>(table_name)
+            .find(filter, options)
+            .map_storage_err()?;
+
+        let row_iter = cursor.map(move |doc| {
+            let columns = column_defs
+                .iter()
+                .map(|column_def| (column_def.name.as_str(), &column_def.data_type));
+
+            doc.map_storage_err()?
+                .into_projected_row(columns, has_primary)
+        });
+
+        Ok(Box::new(row_iter))
+    }
+}
+
+/// Converts a predicate value into BSON only when Mongo orders it against
+/// the stored values of the column the same way the executor does.
+fn comparable_bson(data_type: &DataType, value: &Value) -> Option<Bson> {
+    let comparable = match data_type {
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int
+        | DataType::Uint8
+        | DataType::Uint16
+        | DataType::Uint32
+        | DataType::Float32
+        | DataType::Float => matches!(
+            value,
+            Value::I8(_)
+                | Value::I16(_)
+                | Value::I32(_)
+                | Value::I64(_)
+                | Value::U8(_)
+                | Value::U16(_)
+                | Value::U32(_)
+                | Value::F32(_)
+                | Value::F64(_)
+        ),
+        DataType::Text => matches!(value, Value::Str(_)),
+        DataType::Boolean => matches!(value, Value::Bool(_)),
+        _ => false,
+    };
+
+    comparable.then(|| value.clone().into_bson().ok()).flatten()
 }
 
 impl MongoStorage {
@@ -256,6 +363,7 @@ impl MongoStorage {
             let TableDescription {
                 foreign_keys,
                 comment,
+                ttl_column,
             } = from_str::<TableDescription>(table_description).map_storage_err()?;
 
             let schema = Schema {
@@ -265,6 +373,7 @@ impl MongoStorage {
                 engine: None,
                 foreign_keys,
                 comment,
+                ttl_column,
             };
 
             Ok::<_, Error>(schema)
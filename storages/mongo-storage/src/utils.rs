@@ -25,6 +25,7 @@ impl Validator {
         column_types: Document,
         foreign_keys: Vec<ForeignKey>,
         comment: Option<String>,
+        ttl_column: Option<String>,
     ) -> Result<Self> {
         let mut required = vec!["_id".to_owned()];
         required.extend(labels);
@@ -39,6 +40,7 @@ impl Validator {
             &(TableDescription {
                 foreign_keys,
                 comment,
+                ttl_column,
             }),
         )
         .map_storage_err()?;
@@ -0,0 +1,51 @@
+#![cfg(feature = "test-mongo")]
+
+use {
+    gluesql_core::{
+        ast::IndexOperator,
+        prelude::{Glue, Value::*},
+        store::{ScanPredicate, Store},
+    },
+    gluesql_mongo_storage::MongoStorage,
+    test_suite::{row, select, stringify_label},
+};
+
+#[test]
+fn mongo_pushdown() {
+    let conn_str = "mongodb://localhost:27017";
+    let storage = MongoStorage::new(conn_str, "mongo_pushdown").expect("MongoStorage::new");
+    storage.drop_database().expect("database dropped");
+    let mut glue = Glue::new(storage);
+
+    glue.execute("CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT, price FLOAT);")
+        .unwrap();
+    glue.execute("INSERT INTO Item VALUES (1, 'a', 1.5), (2, 'b', 2.5), (3, 'c', 3.5);")
+        .unwrap();
+
+    let predicates = [
+        ScanPredicate {
+            column: 2,
+            operator: IndexOperator::Gt,
+            value: I64(2),
+        },
+        ScanPredicate {
+            column: 1,
+            operator: IndexOperator::LtEq,
+            value: Str("b".to_owned()),
+        },
+    ];
+    let rows = glue
+        .storage
+        .scan_filtered_data("Item", &predicates, Some(&[0, 2]))
+        .unwrap()
+        .map(|row| row.unwrap().1)
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![vec![I64(2), Null, F64(2.5)]]);
+
+    assert_eq!(
+        glue.execute("SELECT name FROM Item WHERE price >= 2 AND id < 3")
+            .unwrap()
+            .remove(0),
+        select!(name Str; "b".to_owned())
+    );
+}
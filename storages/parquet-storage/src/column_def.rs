@@ -37,6 +37,9 @@ static PARQUET_TO_GLUESQL_DATA_TYPE_MAPPING: LazyLock<HashMap<&'static str, Data
         m.insert("List", DataType::List);
         m.insert("Decimal", DataType::Decimal);
         m.insert("Point", DataType::Point);
+        m.insert("LineString", DataType::LineString);
+        m.insert("Polygon", DataType::Polygon);
+        m.insert("MultiPoint", DataType::MultiPoint);
 
         m
     });
@@ -0,0 +1,226 @@
+use {
+    crate::{
+        ParquetStorage,
+        error::{OptionExt, ParquetStorageError, ResultExt},
+        value::ParquetField,
+    },
+    gluesql_core::{
+        data::{Key, Schema, Value},
+        error::Result,
+    },
+    parquet::{
+        file::{reader::FileReader, serialized_reader::SerializedFileReader},
+        record::{Field, Row, reader::RowIter as ParquetRowIter},
+    },
+    std::{
+        collections::{BTreeMap, btree_map},
+        ffi::OsStr,
+        fs::{self, File},
+        iter::Peekable,
+        path::{Path, PathBuf},
+    },
+};
+
+/// Name of the hidden trailing column that stores each delta row's key.
+pub(crate) const KEY_COLUMN_NAME: &str = "__gluesql_key";
+
+const ROWS_EXTENSION: &str = "parquet";
+const TOMBSTONE_EXTENSION: &str = "tombstone";
+
+/// Latest delta state per key; `None` marks a deleted row.
+pub(crate) type Delta = BTreeMap<Key, Option<Vec<Value>>>;
+
+pub(crate) enum Segment {
+    Rows(PathBuf),
+    Tombstone(PathBuf),
+}
+
+impl ParquetStorage {
+    pub(crate) fn delta_path(&self, table_name: &str) -> PathBuf {
+        self.path_by(table_name, "delta")
+    }
+
+    /// Lists the delta segments of a table in the order they were written.
+    pub(crate) fn segments(&self, table_name: &str) -> Result<Vec<(u64, Segment)>> {
+        let delta_path = self.delta_path(table_name);
+        if !delta_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut segments = Vec::new();
+        for entry in fs::read_dir(delta_path).map_storage_err()? {
+            let path = entry.map_storage_err()?.path();
+            let seq = path
+                .file_stem()
+                .and_then(OsStr::to_str)
+                .and_then(|stem| stem.parse::<u64>().ok())
+                .map_storage_err(ParquetStorageError::InvalidDeltaSegment(
+                    path.display().to_string(),
+                ))?;
+
+            let segment = match path.extension().and_then(OsStr::to_str) {
+                Some(ROWS_EXTENSION) => Segment::Rows(path),
+                Some(TOMBSTONE_EXTENSION) => Segment::Tombstone(path),
+                _ => {
+                    return Err(ParquetStorageError::InvalidDeltaSegment(
+                        path.display().to_string(),
+                    )
+                    .into());
+                }
+            };
+
+            segments.push((seq, segment));
+        }
+
+        segments.sort_by_key(|(seq, _)| *seq);
+        Ok(segments)
+    }
+
+    pub(crate) fn next_segment_path(&self, table_name: &str, tombstone: bool) -> Result<PathBuf> {
+        let seq = self
+            .segments(table_name)?
+            .last()
+            .map_or(0, |(seq, _)| seq + 1);
+        let extension = if tombstone {
+            TOMBSTONE_EXTENSION
+        } else {
+            ROWS_EXTENSION
+        };
+
+        let delta_path = self.delta_path(table_name);
+        fs::create_dir_all(&delta_path).map_storage_err()?;
+
+        Ok(delta_path.join(format!("{seq:010}.{extension}")))
+    }
+
+    /// Counts every row ever written to the base file and the row segments.
+    ///
+    /// Generated keys continue from this count, so they never collide with
+    /// the positional keys of the base file or with earlier segments.
+    pub(crate) fn count_written_rows(&self, table_name: &str) -> Result<u64> {
+        let mut count = Self::count_file_rows(&self.data_path(table_name))?;
+
+        for (_, segment) in self.segments(table_name)? {
+            if let Segment::Rows(path) = segment {
+                count += Self::count_file_rows(&path)?;
+            }
+        }
+
+        Ok(count)
+    }
+
+    fn count_file_rows(path: &Path) -> Result<u64> {
+        let file = File::open(path).map_storage_err()?;
+        let reader = SerializedFileReader::new(file).map_storage_err()?;
+
+        u64::try_from(reader.metadata().file_metadata().num_rows()).map_storage_err()
+    }
+
+    pub(crate) fn read_delta(&self, schema: &Schema) -> Result<Delta> {
+        let mut delta = Delta::new();
+
+        for (_, segment) in self.segments(&schema.table_name)? {
+            match segment {
+                Segment::Rows(path) => {
+                    let file = File::open(path).map_storage_err()?;
+                    let reader = SerializedFileReader::new(file).map_storage_err()?;
+
+                    for record in ParquetRowIter::from_file_into(Box::new(reader)) {
+                        let (key, row) =
+                            Self::convert_segment_row(schema, &record.map_storage_err()?)?;
+                        delta.insert(key, Some(row));
+                    }
+                }
+                Segment::Tombstone(path) => {
+                    let keys: Vec<Key> = bincode::deserialize(&fs::read(path).map_storage_err()?)
+                        .map_storage_err()?;
+
+                    for key in keys {
+                        delta.insert(key, None);
+                    }
+                }
+            }
+        }
+
+        Ok(delta)
+    }
+
+    fn convert_segment_row(schema: &Schema, record: &Row) -> Result<(Key, Vec<Value>)> {
+        let tmp_schema;
+        let value_schema = if schema.column_defs.is_some() {
+            schema
+        } else {
+            tmp_schema = Self::generate_temp_schema();
+            &tmp_schema
+        };
+
+        let mut key = None;
+        let mut row = Vec::new();
+
+        for (idx, (name, field)) in record.get_column_iter().enumerate() {
+            if name == KEY_COLUMN_NAME {
+                if let Field::Bytes(bytes) = field {
+                    key = Some(bincode::deserialize(bytes.data()).map_storage_err()?);
+                }
+
+                continue;
+            }
+
+            row.push(ParquetField(field.clone()).to_value(value_schema, idx)?);
+        }
+
+        let key = key.map_storage_err(ParquetStorageError::MissingDeltaKey)?;
+
+        Ok((key, row))
+    }
+}
+
+/// Merges the key-ordered base rows with the delta, letting the delta
+/// replace or drop base rows that share a key.
+pub(crate) struct DeltaMerge<T: Iterator<Item = Result<(Key, Vec<Value>)>>> {
+    base_rows: Peekable<T>,
+    delta: Peekable<btree_map::IntoIter<Key, Option<Vec<Value>>>>,
+}
+
+impl<T> DeltaMerge<T>
+where
+    T: Iterator<Item = Result<(Key, Vec<Value>)>>,
+{
+    pub(crate) fn new(base_rows: T, delta: Delta) -> Self {
+        Self {
+            base_rows: base_rows.peekable(),
+            delta: delta.into_iter().peekable(),
+        }
+    }
+}
+
+impl<T> Iterator for DeltaMerge<T>
+where
+    T: Iterator<Item = Result<(Key, Vec<Value>)>>,
+{
+    type Item = Result<(Key, Vec<Value>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (key, row) = match (self.base_rows.peek(), self.delta.peek()) {
+                (Some(Ok((base_key, _))), Some((delta_key, _))) if base_key < delta_key => {
+                    return self.base_rows.next();
+                }
+                (Some(Ok((base_key, _))), Some((delta_key, _))) => {
+                    if base_key == delta_key {
+                        self.base_rows.next();
+                    }
+
+                    self.delta.next()?
+                }
+                (Some(_), _) => return self.base_rows.next(),
+                (None, Some(_)) => self.delta.next()?,
+                (None, None) => return None,
+            };
+
+            if let Some(row) = row {
+                return Some(Ok((key, row)));
+            }
+        }
+    }
+}
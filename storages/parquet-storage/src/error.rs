@@ -50,4 +50,13 @@ pub enum ParquetStorageError {
 
     #[error("Unreachable gluesql value type for parquet writer")]
     UnreachableGlueSqlValueTypeForParquetWriter,
+
+    #[error("invalid delta segment file: {0}")]
+    InvalidDeltaSegment(String),
+
+    #[error("delta segment row is missing its key")]
+    MissingDeltaKey,
+
+    #[error("no column found for parquet field at position {0}")]
+    ColumnNotFound(usize),
 }
@@ -1,7 +1,9 @@
 use {
     super::ParquetStorage,
-    gluesql_core::store::{CustomFunction, CustomFunctionMut},
+    gluesql_core::store::{CustomFunction, CustomFunctionMut, Trigger, TriggerMut},
 };
 
 impl CustomFunctionMut for ParquetStorage {}
+impl Trigger for ParquetStorage {}
+impl TriggerMut for ParquetStorage {}
 impl CustomFunction for ParquetStorage {}
@@ -1,35 +1,48 @@
 use {
+    delta::DeltaMerge,
     error::{OptionExt, ParquetStorageError, ResultExt},
     gluesql_core::{
         ast::{ColumnDef, ColumnUniqueOption},
         data::Schema,
         error::Result,
         prelude::{DataType, Key, Value},
-        store::{Metadata, Planner, Store},
+        store::{Metadata, ScanPredicate, Store},
     },
     parquet::{
-        file::serialized_reader::SerializedFileReader,
+        errors::Result as ParquetResult,
+        file::{
+            metadata::{ColumnChunkMetaData, ParquetMetaData},
+            reader::FileReader,
+            serialized_reader::{ReadOptionsBuilder, SerializedFileReader},
+        },
         record::{Row, reader::RowIter as ParquetRowIter},
+        schema::types::Type as SchemaType,
     },
     std::{
         collections::BTreeMap,
         fs::{self, File},
+        iter,
         path::{Path, PathBuf},
+        sync::Arc,
     },
     value::ParquetField,
 };
 
 mod alter_table;
 mod column_def;
+mod delta;
 pub mod error;
 mod function;
 mod index;
+mod planner;
+mod statistics;
 mod store;
 mod store_mut;
 mod transaction;
 mod value;
 
-type RowIter = Box<dyn Iterator<Item = Result<(Key, Vec<Value>)>>>;
+type RowIter = RowIterOf<'static>;
+type RowIterOf<'a> = Box<dyn Iterator<Item = Result<(Key, Vec<Value>)>> + 'a>;
 
 #[derive(Debug, Clone)]
 pub struct ParquetStorage {
@@ -62,36 +75,241 @@ impl ParquetStorage {
     }
 
     fn scan_data(&self, table_name: &str) -> Result<(RowIter, Schema)> {
-        let fetched_schema = <Self as Store>::fetch_schema(self, table_name)?.map_storage_err(
-            ParquetStorageError::TableDoesNotExist(table_name.to_owned()),
-        )?;
-        let file = File::open(self.data_path(table_name)).map_storage_err()?;
+        self.scan_columns(table_name, &[], None)
+    }
+
+    /// Scans a table, reading only `columns` (and the primary key) from the
+    /// base file. The other columns of base rows are returned as `Value::Null`,
+    /// and row groups whose statistics rule out one of `predicates` are skipped.
+    fn scan_columns(
+        &self,
+        table_name: &str,
+        predicates: &[ScanPredicate],
+        columns: Option<&[usize]>,
+    ) -> Result<(RowIter, Schema)> {
+        let fetched_schema = self.fetch_existing_schema(table_name)?;
+        let data_path = self.data_path(table_name);
+        let file = File::open(&data_path).map_storage_err()?;
 
         let parquet_reader = SerializedFileReader::new(file).map_storage_err()?;
-        let row_iter = ParquetRowIter::from_file_into(Box::new(parquet_reader));
-        let scan_schema = fetched_schema.clone();
-        let primary_key_index = scan_schema.column_defs.as_ref().and_then(|column_defs| {
+        let projection = columns
+            .map(|columns| {
+                let root = parquet_reader.metadata().file_metadata().schema();
+
+                Self::projection(&fetched_schema, root, columns)
+            })
+            .transpose()?
+            .flatten();
+        let (projection, column_indexes) = projection.unzip();
+        let row_groups =
+            Self::matching_row_groups(&fetched_schema, parquet_reader.metadata(), predicates)?;
+
+        let base_rows = if row_groups.len() == parquet_reader.num_row_groups() {
+            let row_iter = ParquetRowIter::from_file_into(Box::new(parquet_reader))
+                .project(projection)
+                .map_storage_err()?;
+
+            Self::convert_rows(fetched_schema.clone(), row_iter, 0, column_indexes)
+        } else {
+            let schema = fetched_schema.clone();
+
+            Box::new(row_groups.into_iter().flat_map(move |(index, key_offset)| {
+                Self::read_row_group(
+                    &data_path,
+                    index,
+                    key_offset,
+                    &schema,
+                    projection.clone(),
+                    column_indexes.clone(),
+                )
+                .unwrap_or_else(|error| Box::new(iter::once(Err(error))))
+            }))
+        };
+        let delta = self.read_delta(&fetched_schema)?;
+        let rows = Box::new(DeltaMerge::new(base_rows, delta));
+
+        Ok((rows, fetched_schema))
+    }
+
+    /// Lists the row groups, with the key offset of their first row, whose
+    /// column statistics do not rule out any of `predicates`.
+    fn matching_row_groups(
+        schema: &Schema,
+        metadata: &ParquetMetaData,
+        predicates: &[ScanPredicate],
+    ) -> Result<Vec<(usize, u64)>> {
+        let column_defs = schema.column_defs.as_deref().unwrap_or_default();
+        let predicates = predicates
+            .iter()
+            .filter_map(|predicate| {
+                let column_def = column_defs.get(predicate.column)?;
+
+                // Statistics are only comparable with values of the column type.
+                let comparable = matches!(
+                    (&column_def.data_type, &predicate.value),
+                    (
+                        DataType::Int8
+                            | DataType::Int16
+                            | DataType::Int32
+                            | DataType::Int
+                            | DataType::Uint8
+                            | DataType::Uint16,
+                        Value::I8(_)
+                            | Value::I16(_)
+                            | Value::I32(_)
+                            | Value::I64(_)
+                            | Value::U8(_)
+                            | Value::U16(_),
+                    ) | (DataType::Boolean, Value::Bool(_))
+                        | (DataType::Float32, Value::F32(_))
+                        | (DataType::Float, Value::F64(_))
+                        | (DataType::Text, Value::Str(_))
+                );
+                let key = Key::try_from(&predicate.value)
+                    .ok()
+                    .filter(|_| comparable)?;
+
+                Some((column_def.name.as_str(), &predicate.operator, key))
+            })
+            .collect::<Vec<_>>();
+
+        let mut key_offset = 0;
+        let mut row_groups = Vec::new();
+
+        for (index, row_group) in metadata.row_groups().iter().enumerate() {
+            let may_match = predicates.iter().all(|(name, operator, key)| {
+                row_group
+                    .columns()
+                    .iter()
+                    .find(|column| matches!(column.column_path().parts(), [part] if part == name))
+                    .and_then(ColumnChunkMetaData::statistics)
+                    .is_none_or(|statistics| statistics::may_match(statistics, operator, key))
+            });
+
+            if may_match {
+                row_groups.push((index, key_offset));
+            }
+
+            key_offset += u64::try_from(row_group.num_rows()).map_storage_err()?;
+        }
+
+        Ok(row_groups)
+    }
+
+    fn read_row_group(
+        path: &Path,
+        index: usize,
+        key_offset: u64,
+        schema: &Schema,
+        projection: Option<SchemaType>,
+        column_indexes: Option<Vec<usize>>,
+    ) -> Result<RowIter> {
+        let file = File::open(path).map_storage_err()?;
+        let options = ReadOptionsBuilder::new()
+            .with_predicate(Box::new(move |_, row_group| row_group == index))
+            .build();
+        let reader = SerializedFileReader::new_with_options(file, options).map_storage_err()?;
+        let row_iter = ParquetRowIter::from_file_into(Box::new(reader))
+            .project(projection)
+            .map_storage_err()?;
+
+        Ok(Self::convert_rows(
+            schema.clone(),
+            row_iter,
+            key_offset,
+            column_indexes,
+        ))
+    }
+
+    /// Builds the parquet projection of `columns` plus the primary key, which
+    /// keys the rows, together with the schema index of each projected field.
+    fn projection(
+        schema: &Schema,
+        root: &SchemaType,
+        columns: &[usize],
+    ) -> Result<Option<(SchemaType, Vec<usize>)>> {
+        let Some(column_defs) = schema.column_defs.as_ref() else {
+            return Ok(None);
+        };
+
+        let mut column_indexes = columns
+            .iter()
+            .copied()
+            .chain(Self::primary_key_index(schema))
+            .filter(|index| *index < column_defs.len())
+            .collect::<Vec<_>>();
+        column_indexes.sort_unstable();
+        column_indexes.dedup();
+
+        if column_indexes.is_empty() {
+            column_indexes.push(0);
+        }
+
+        let fields = root.get_fields();
+        let mut projected = column_indexes
+            .iter()
+            .filter_map(|index| fields.get(*index).map(Arc::clone))
+            .collect::<Vec<_>>();
+        let projection = SchemaType::group_type_builder(root.name())
+            .with_fields(&mut projected)
+            .build()
+            .map_storage_err()?;
+
+        Ok(Some((projection, column_indexes)))
+    }
+
+    fn fetch_existing_schema(&self, table_name: &str) -> Result<Schema> {
+        <Self as Store>::fetch_schema(self, table_name)?.map_storage_err(
+            ParquetStorageError::TableDoesNotExist(table_name.to_owned()),
+        )
+    }
+
+    fn primary_key_index(schema: &Schema) -> Option<usize> {
+        schema.column_defs.as_ref().and_then(|column_defs| {
             column_defs.iter().position(|column_def| {
                 column_def.unique == Some(ColumnUniqueOption { is_primary: true })
             })
-        });
+        })
+    }
 
-        let rows: RowIter = if scan_schema.column_defs.is_some() {
-            let mut key_counter = 0_u64;
+    /// Converts parquet records into keyed rows.
+    ///
+    /// Rows without a primary key get positional `Key::U64` keys counted from
+    /// `key_offset`, so a row group can be converted on its own. Records read
+    /// through a projection map their fields with `column_indexes`.
+    fn convert_rows<'a, I>(
+        scan_schema: Schema,
+        row_iter: I,
+        key_offset: u64,
+        column_indexes: Option<Vec<usize>>,
+    ) -> RowIterOf<'a>
+    where
+        I: Iterator<Item = ParquetResult<Row>> + 'a,
+    {
+        let primary_key_index = Self::primary_key_index(&scan_schema);
+
+        if let Some(column_defs) = scan_schema.column_defs.as_ref() {
+            let width = column_defs.len();
+            let mut key_counter = key_offset;
 
             Box::new(row_iter.map(move |record| {
                 record.map_storage_err().and_then(|record: Row| {
-                    let mut row = Vec::new();
+                    let mut row = vec![Value::Null; width];
                     let mut key = None;
 
-                    for (idx, (_, field)) in record.get_column_iter().enumerate() {
+                    for (position, (_, field)) in record.get_column_iter().enumerate() {
+                        let idx = column_indexes
+                            .as_ref()
+                            .map_or(Some(position), |indexes| indexes.get(position).copied())
+                            .filter(|idx| *idx < width)
+                            .map_storage_err(ParquetStorageError::ColumnNotFound(position))?;
                         let value = ParquetField(field.clone()).to_value(&scan_schema, idx)?;
 
                         if primary_key_index == Some(idx) {
                             key = Key::try_from(&value).ok();
                         }
 
-                        row.push(value);
+                        row[idx] = value;
                     }
 
                     let generated_key = key.unwrap_or_else(|| {
@@ -105,7 +323,7 @@ impl ParquetStorage {
             }))
         } else {
             let tmp_schema = Self::generate_temp_schema();
-            let mut key_counter = 0_u64;
+            let mut key_counter = key_offset;
 
             Box::new(row_iter.flat_map(move |record| {
                 let rows = record.map_storage_err().and_then(|record: Row| {
@@ -131,9 +349,7 @@ impl ParquetStorage {
                     Err(error) => vec![Err(error)].into_iter(),
                 }
             }))
-        };
-
-        Ok((rows, fetched_schema))
+        }
     }
 
     fn generate_temp_schema() -> Schema {
@@ -151,12 +367,12 @@ impl ParquetStorage {
             engine: None,
             foreign_keys: Vec::new(),
             comment: None,
+            ttl_column: None,
         }
     }
 }
 
 impl Metadata for ParquetStorage {}
-impl Planner for ParquetStorage {}
 
 #[cfg(test)]
 mod tests {
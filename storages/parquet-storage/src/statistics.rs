@@ -0,0 +1,68 @@
+use {
+    gluesql_core::{ast::IndexOperator, data::Key},
+    parquet::{data_type::ByteArray, file::statistics::Statistics},
+};
+
+/// Returns `false` only when the column chunk statistics prove that no value
+/// in the row group can equal `key`.
+pub(crate) fn may_contain_key(statistics: &Statistics, key: &Key) -> bool {
+    may_match(statistics, &IndexOperator::Eq, key)
+}
+
+/// Returns `false` only when the column chunk statistics prove that no value
+/// in the row group satisfies `value <operator> key`.
+pub(crate) fn may_match(statistics: &Statistics, operator: &IndexOperator, key: &Key) -> bool {
+    macro_rules! in_range {
+        ($statistics: expr, $value: expr) => {
+            !$statistics.has_min_max_set()
+                || match operator {
+                    IndexOperator::Eq => $statistics.min() <= $value && $value <= $statistics.max(),
+                    IndexOperator::Gt => $statistics.max() > $value,
+                    IndexOperator::GtEq => $statistics.max() >= $value,
+                    IndexOperator::Lt => $statistics.min() < $value,
+                    IndexOperator::LtEq => $statistics.min() <= $value,
+                    IndexOperator::Intersects => true,
+                }
+        };
+    }
+
+    match (statistics, key) {
+        (Statistics::Boolean(statistics), Key::Bool(value)) => in_range!(statistics, value),
+        (Statistics::Int32(statistics), key) => match key_to_i64(key) {
+            Some(value) => match i32::try_from(value) {
+                Ok(value) => in_range!(statistics, &value),
+                // The key lies outside every `i32`, so only the side it is on matters.
+                Err(_) => match operator {
+                    IndexOperator::Gt | IndexOperator::GtEq => value < 0,
+                    IndexOperator::Lt | IndexOperator::LtEq => value > 0,
+                    IndexOperator::Eq => false,
+                    IndexOperator::Intersects => true,
+                },
+            },
+            None => true,
+        },
+        (Statistics::Int64(statistics), key) => {
+            key_to_i64(key).is_none_or(|value| in_range!(statistics, &value))
+        }
+        (Statistics::Float(statistics), Key::F32(value)) => in_range!(statistics, &value.0),
+        (Statistics::Double(statistics), Key::F64(value)) => in_range!(statistics, &value.0),
+        (Statistics::ByteArray(statistics), Key::Str(value)) => {
+            in_range!(statistics, &ByteArray::from(value.as_str()))
+        }
+        _ => true,
+    }
+}
+
+/// Unsigned 32 and 64 bit columns are stored as wrapped signed integers, so
+/// their statistics are not comparable with the key and are left out.
+fn key_to_i64(key: &Key) -> Option<i64> {
+    match key {
+        Key::I8(value) => Some(i64::from(*value)),
+        Key::I16(value) => Some(i64::from(*value)),
+        Key::I32(value) => Some(i64::from(*value)),
+        Key::I64(value) => Some(*value),
+        Key::U8(value) => Some(i64::from(*value)),
+        Key::U16(value) => Some(i64::from(*value)),
+        _ => None,
+    }
+}
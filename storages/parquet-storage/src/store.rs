@@ -3,13 +3,14 @@ use {
         ParquetStorage,
         column_def::ParquetSchemaType,
         error::{OptionExt, ParquetStorageError, ResultExt},
+        statistics::may_contain_key,
     },
     gluesql_core::{
         ast::{ColumnDef, ForeignKey},
         data::{Key, Schema},
         error::{Error, Result},
         prelude::Value,
-        store::{RowIter, Store},
+        store::{RowIter, ScanPredicate, Store},
     },
     parquet::file::{reader::FileReader, serialized_reader::SerializedFileReader},
     serde_json::from_str,
@@ -36,12 +37,15 @@ impl Store for ParquetStorage {
         let mut is_schemaless = false;
         let mut foreign_keys = Vec::new();
         let mut comment = None;
+        let mut ttl_column = None;
         if let Some(metadata) = key_value_file_metadata {
             for kv in metadata {
                 if kv.key == "schemaless" {
                     is_schemaless = matches!(kv.value.as_deref(), Some("true"));
                 } else if kv.key == "comment" {
                     comment.clone_from(&kv.value);
+                } else if kv.key == "ttl_column" {
+                    ttl_column.clone_from(&kv.value);
                 } else if kv.key.starts_with("foreign_key") {
                     let fk = kv
                         .value
@@ -81,6 +85,7 @@ impl Store for ParquetStorage {
             engine: None,
             foreign_keys,
             comment,
+            ttl_column,
         }))
     }
 
@@ -89,6 +94,9 @@ impl Store for ParquetStorage {
         let mut schemas = paths
             .map(|result| {
                 let path = result.map_storage_err()?.path();
+                if path.is_dir() {
+                    return Ok(None);
+                }
 
                 let table_name = path
                     .file_stem()
@@ -109,13 +117,46 @@ impl Store for ParquetStorage {
     }
 
     fn fetch_data(&self, table_name: &str, target: &Key) -> Result<Option<Vec<Value>>> {
-        for item in self.scan_data(table_name)?.0 {
-            let (key, row) = item?;
+        let schema = self.fetch_existing_schema(table_name)?;
+
+        if let Some(row) = self.read_delta(&schema)?.remove(target) {
+            return Ok(row);
+        }
+
+        let file = File::open(self.data_path(table_name)).map_storage_err()?;
+        let reader = SerializedFileReader::new(file).map_storage_err()?;
+        let primary_key_index = Self::primary_key_index(&schema);
+        let mut key_offset = 0;
+
+        for (idx, row_group) in reader.metadata().row_groups().iter().enumerate() {
+            let num_rows = u64::try_from(row_group.num_rows()).map_storage_err()?;
+            let may_contain = match (primary_key_index, target) {
+                (Some(column_index), _) => row_group
+                    .column(column_index)
+                    .statistics()
+                    .is_none_or(|statistics| may_contain_key(statistics, target)),
+                (None, Key::U64(position)) => {
+                    (key_offset..key_offset + num_rows).contains(position)
+                }
+                (None, _) => false,
+            };
+
+            if may_contain {
+                let row_group_reader = reader.get_row_group(idx).map_storage_err()?;
+                let row_iter = row_group_reader.get_row_iter(None).map_storage_err()?;
 
-            if &key == target {
-                return Ok(Some(row));
+                for item in Self::convert_rows(schema.clone(), row_iter, key_offset, None) {
+                    let (key, row) = item?;
+
+                    if &key == target {
+                        return Ok(Some(row));
+                    }
+                }
             }
+
+            key_offset += num_rows;
         }
+
         Ok(None)
     }
 
@@ -123,4 +164,14 @@ impl Store for ParquetStorage {
         let rows = self.scan_data(table_name)?.0;
         Ok(Box::new(rows))
     }
+
+    fn scan_filtered_data<'a>(
+        &'a self,
+        table_name: &str,
+        predicates: &[ScanPredicate],
+        columns: Option<&[usize]>,
+    ) -> Result<RowIter<'a>> {
+        let rows = self.scan_columns(table_name, predicates, columns)?.0;
+        Ok(Box::new(rows))
+    }
 }
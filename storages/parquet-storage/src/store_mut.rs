@@ -1,5 +1,5 @@
 use {
-    crate::{ParquetStorage, ParquetStorageError, error::ResultExt},
+    crate::{ParquetStorage, ParquetStorageError, delta::KEY_COLUMN_NAME, error::ResultExt},
     gluesql_core::{
         ast::{ColumnDef, ToSql},
         chrono::{NaiveDate, Timelike},
@@ -9,7 +9,7 @@ use {
         store::StoreMut,
     },
     parquet::{
-        basic::{ConvertedType, Type},
+        basic::{ConvertedType, Repetition, Type},
         column::writer::{ColumnWriter, ColumnWriterImpl},
         data_type::{
             BoolType, ByteArray, ByteArrayType, DoubleType, FixedLenByteArray,
@@ -23,13 +23,11 @@ use {
         schema::types::Type as SchemaType,
     },
     std::{
-        cmp::Ordering,
         collections::HashMap,
         convert::TryFrom,
-        fs::{File, remove_file},
-        iter::Peekable,
+        fs::{self, File, remove_file},
+        path::Path,
         sync::{Arc, LazyLock},
-        vec::IntoIter,
     },
 };
 
@@ -61,21 +59,31 @@ static GLUESQL_TO_PARQUET_DATA_TYPE_MAPPING: LazyLock<HashMap<DataType, &'static
         m.insert(DataType::List, "List");
         m.insert(DataType::Decimal, "Decimal");
         m.insert(DataType::Point, "Point");
+        m.insert(DataType::LineString, "LineString");
+        m.insert(DataType::Polygon, "Polygon");
+        m.insert(DataType::MultiPoint, "MultiPoint");
 
         m
     });
 
+/// Rows per row group, so that statistics can skip parts of a large file.
+const ROW_GROUP_SIZE: usize = 8192;
+
 const DEF_PRESENT: [i16; 1] = [1];
 const DEF_NULL: [i16; 1] = [0];
 
 impl StoreMut for ParquetStorage {
     fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        self.remove_delta(&schema.table_name)?;
+
         let data_path = self.data_path(schema.table_name.as_str());
         let file = File::create(data_path).map_storage_err()?;
         Self::write(schema, &[], file)
     }
 
     fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        self.remove_delta(table_name)?;
+
         let schema_path = self.data_path(table_name);
         if schema_path.exists() {
             remove_file(schema_path).map_storage_err()?;
@@ -84,108 +92,144 @@ impl StoreMut for ParquetStorage {
     }
 
     fn append_data(&mut self, table_name: &str, rows: Vec<Vec<Value>>) -> Result<()> {
-        let schema_path = self.data_path(table_name);
-        let (prev_rows, schema) = self.scan_data(table_name)?;
+        let key_offset = self.count_written_rows(table_name)?;
+        let rows = (key_offset..).map(Key::U64).zip(rows).collect::<Vec<_>>();
 
-        let rows = prev_rows
-            .map(|item| Ok(item?.1))
-            .chain(rows.into_iter().map(Ok))
-            .collect::<Result<Vec<_>>>()?;
+        self.write_segment(table_name, rows)
+    }
 
-        let file = File::create(schema_path).map_storage_err()?;
-        Self::write(&schema, &rows, file)
+    fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, Vec<Value>)>) -> Result<()> {
+        self.write_segment(table_name, rows)
     }
 
-    fn insert_data(&mut self, table_name: &str, mut rows: Vec<(Key, Vec<Value>)>) -> Result<()> {
-        let (prev_rows, schema) = self.scan_data(table_name)?;
+    fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        self.fetch_existing_schema(table_name)?;
 
-        rows.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+        let path = self.next_segment_path(table_name, true)?;
+        let keys = bincode::serialize(&keys).map_storage_err()?;
 
-        let sort_merge = SortMerge::new(prev_rows, rows.into_iter());
-        let merged = sort_merge.collect::<Result<Vec<_>>>()?;
-        self.rewrite(&schema, &merged)
+        fs::write(path, keys).map_storage_err()
     }
+}
 
-    fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
-        let (prev_rows, schema) = self.scan_data(table_name)?;
-        let rows = prev_rows
-            .filter_map(|result| {
-                result
-                    .map(|(key, data_row)| {
-                        let preservable = !keys.iter().any(|target_key| target_key == &key);
-
-                        preservable.then_some(data_row)
-                    })
-                    .transpose()
-            })
-            .collect::<Result<Vec<_>>>()?;
+impl ParquetStorage {
+    /// Compacts the delta segments of a table into its base file.
+    ///
+    /// Writes only ever append segments, so a table that sees many updates or
+    /// deletes should be vacuumed from time to time to keep reads fast.
+    /// Generated keys of tables without a primary key are renumbered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table does not exist or its files cannot be
+    /// read or rewritten.
+    pub fn vacuum(&mut self, table_name: &str) -> Result<()> {
+        if self.segments(table_name)?.is_empty() {
+            return Ok(());
+        }
+
+        let (rows, schema) = self.scan_data(table_name)?;
+        let rows = rows.map(|item| Ok(item?.1)).collect::<Result<Vec<_>>>()?;
+
+        let data_path = self.data_path(table_name);
+        let mut compacted_path = data_path.clone();
+        compacted_path.set_extension("parquet.compacting");
+
+        let file = File::create(&compacted_path).map_storage_err()?;
+        Self::write(&schema, &rows, file)?;
+        fs::rename(compacted_path, data_path).map_storage_err()?;
 
-        self.rewrite(&schema, &rows)
+        self.remove_delta(table_name)
     }
-}
 
-struct SortMerge<T: Iterator<Item = Result<(Key, Vec<Value>)>>> {
-    left_rows: Peekable<T>,
-    right_rows: Peekable<IntoIter<(Key, Vec<Value>)>>,
-}
+    /// Writes `rows` to a standalone parquet file at `path`, replacing it when
+    /// it exists.
+    ///
+    /// The file carries `schema` in its metadata, so a storage rooted at its
+    /// directory reads it back as a table. No other file is created.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema has a type parquet cannot store or the
+    /// file cannot be written.
+    pub fn write_file<T: AsRef<Path>>(path: T, schema: &Schema, rows: &[Vec<Value>]) -> Result<()> {
+        let file = File::create(path).map_storage_err()?;
 
-impl<T> SortMerge<T>
-where
-    T: Iterator<Item = Result<(Key, Vec<Value>)>>,
-{
-    fn new(left_rows: T, right_rows: IntoIter<(Key, Vec<Value>)>) -> Self {
-        let left_rows = left_rows.peekable();
-        let right_rows = right_rows.peekable();
-
-        Self {
-            left_rows,
-            right_rows,
-        }
+        Self::write(schema, rows, file)
     }
-}
-impl<T> Iterator for SortMerge<T>
-where
-    T: Iterator<Item = Result<(Key, Vec<Value>)>>,
-{
-    type Item = Result<Vec<Value>>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let left = self.left_rows.peek();
-        let right = self.right_rows.peek();
-
-        match (left, right) {
-            (Some(Ok((left_key, _))), Some((right_key, _))) => match left_key.cmp(right_key) {
-                Ordering::Less => self.left_rows.next(),
-                Ordering::Greater => self.right_rows.next().map(Ok),
-                Ordering::Equal => {
-                    self.left_rows.next();
-                    self.right_rows.next().map(Ok)
-                }
-            }
-            .map(|item| Ok(item?.1)),
-            (Some(_), _) => self.left_rows.next().map(|item| Ok(item?.1)),
-            (None, Some(_)) => self.right_rows.next().map(|item| Ok(item.1)),
-            (None, None) => None,
+
+    fn remove_delta(&self, table_name: &str) -> Result<()> {
+        let delta_path = self.delta_path(table_name);
+        if delta_path.exists() {
+            fs::remove_dir_all(delta_path).map_storage_err()?;
         }
+
+        Ok(())
     }
-}
 
-impl ParquetStorage {
-    fn rewrite(&mut self, schema: &Schema, rows: &[Vec<Value>]) -> Result<()> {
-        let parquet_path = self.data_path(&schema.table_name);
-        let file = File::create(parquet_path).map_storage_err()?;
-        Self::write(schema, rows, file)
+    fn write_segment(&self, table_name: &str, rows: Vec<(Key, Vec<Value>)>) -> Result<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let schema = self.fetch_existing_schema(table_name)?;
+        let rows = rows
+            .into_iter()
+            .map(|(key, mut row)| {
+                let key = bincode::serialize(&key).map_storage_err()?;
+                row.push(Value::Bytea(key));
+
+                Ok(row)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let schema_type = Self::convert_to_parquet_schema(&schema)?;
+        let mut fields = schema_type.get_fields().to_vec();
+        let key_field = SchemaType::primitive_type_builder(KEY_COLUMN_NAME, Type::BYTE_ARRAY)
+            .with_repetition(Repetition::REQUIRED)
+            .build()
+            .map_storage_err()?;
+        fields.push(Arc::new(key_field));
+
+        let schema_type = SchemaType::group_type_builder("schema")
+            .with_fields(&mut fields)
+            .build()
+            .map(Arc::new)
+            .map_storage_err()?;
+
+        let path = self.next_segment_path(table_name, false)?;
+        let file = File::create(path).map_storage_err()?;
+        let props = Arc::new(WriterProperties::builder().build());
+
+        Self::write_rows(&schema_type, props, &rows, file)
     }
 
     fn write(schema: &Schema, rows: &[Vec<Value>], file: File) -> Result<()> {
         let schema_type = Self::convert_to_parquet_schema(schema)?;
         let props = Self::build_writer_properties(schema)?;
+
+        Self::write_rows(&schema_type, props, rows, file)
+    }
+
+    fn write_rows(
+        schema_type: &Arc<SchemaType>,
+        props: Arc<WriterProperties>,
+        rows: &[Vec<Value>],
+        file: File,
+    ) -> Result<()> {
         let mut file_writer =
-            SerializedFileWriter::new(file, Arc::clone(&schema_type), props).map_storage_err()?;
+            SerializedFileWriter::new(file, Arc::clone(schema_type), props).map_storage_err()?;
 
+        for row_group in rows
+            .chunks(ROW_GROUP_SIZE)
+            .chain(rows.is_empty().then_some(rows))
         {
             let mut row_group_writer = file_writer.next_row_group().map_storage_err()?;
-            Self::write_row_group(&schema_type, rows, &mut row_group_writer)?;
+            Self::write_row_group(schema_type, row_group, &mut row_group_writer)?;
             row_group_writer.close().map_storage_err()?;
         }
 
@@ -499,6 +543,15 @@ impl ParquetStorage {
             Value::Point(val) => {
                 Self::write_serialized_byte_array(writer, val)?;
             }
+            Value::LineString(val) => {
+                Self::write_serialized_byte_array(writer, val)?;
+            }
+            Value::Polygon(val) => {
+                Self::write_serialized_byte_array(writer, val)?;
+            }
+            Value::MultiPoint(val) => {
+                Self::write_serialized_byte_array(writer, val)?;
+            }
             Value::Inet(val) => {
                 Self::write_serialized_byte_array(writer, val)?;
             }
@@ -668,6 +721,13 @@ impl ParquetStorage {
             });
         }
 
+        if schema.ttl_column.is_some() {
+            metadata.push(KeyValue {
+                key: "ttl_column".to_owned(),
+                value: schema.ttl_column.clone(),
+            });
+        }
+
         Ok(Some(metadata))
     }
 
@@ -689,6 +749,9 @@ impl ParquetStorage {
             DataType::Uuid => (Type::FIXED_LEN_BYTE_ARRAY, None),
             DataType::Time => (Type::INT64, None),
             DataType::Point
+            | DataType::LineString
+            | DataType::Polygon
+            | DataType::MultiPoint
             | DataType::Inet
             | DataType::Uint128
             | DataType::Int128
@@ -748,6 +811,7 @@ mod tests {
             engine: None,
             foreign_keys: vec![],
             comment: None,
+            ttl_column: None,
         }
     }
 
@@ -112,6 +112,18 @@ impl ParquetField {
                             let point = bincode::deserialize(v.data()).map_storage_err()?;
                             return Ok(Value::Point(point));
                         }
+                        DataType::LineString => {
+                            let line_string = bincode::deserialize(v.data()).map_storage_err()?;
+                            return Ok(Value::LineString(line_string));
+                        }
+                        DataType::Polygon => {
+                            let polygon = bincode::deserialize(v.data()).map_storage_err()?;
+                            return Ok(Value::Polygon(polygon));
+                        }
+                        DataType::MultiPoint => {
+                            let multi_point = bincode::deserialize(v.data()).map_storage_err()?;
+                            return Ok(Value::MultiPoint(multi_point));
+                        }
                         _ => {}
                     }
                 }
@@ -0,0 +1,100 @@
+use {
+    gluesql_core::{
+        data::Key,
+        prelude::{Glue, Value::*},
+        store::Store,
+    },
+    gluesql_parquet_storage::ParquetStorage,
+    std::fs::{self, remove_dir_all},
+    test_suite::{concat_with, row, select, stringify_label},
+};
+
+fn new_glue(namespace: &str) -> Glue<ParquetStorage> {
+    let path = format!("tmp/{namespace}");
+    remove_dir_all(&path).unwrap_or(());
+
+    Glue::new(ParquetStorage::new(&path).unwrap())
+}
+
+#[test]
+fn writes_append_delta_segments() {
+    let mut glue = new_glue("parquet_delta_segments");
+    let base_path = glue.storage.path.join("Item.parquet");
+    let delta_path = glue.storage.path.join("Item.delta");
+
+    glue.execute("CREATE TABLE Item (id INTEGER, name TEXT);")
+        .unwrap();
+    let base_len = fs::metadata(&base_path).unwrap().len();
+
+    glue.execute("INSERT INTO Item VALUES (1, 'a'), (2, 'b'), (3, 'c');")
+        .unwrap();
+    glue.execute("UPDATE Item SET name = 'B' WHERE id = 2;")
+        .unwrap();
+    glue.execute("DELETE FROM Item WHERE id = 1;").unwrap();
+    glue.execute("INSERT INTO Item VALUES (4, 'd');").unwrap();
+
+    assert_eq!(fs::metadata(&base_path).unwrap().len(), base_len);
+    assert_eq!(fs::read_dir(&delta_path).unwrap().count(), 4);
+
+    let expected = select!(
+        id  | name
+        I64 | Str;
+        2     "B".to_owned();
+        3     "c".to_owned();
+        4     "d".to_owned()
+    );
+    assert_eq!(
+        glue.execute("SELECT * FROM Item;").unwrap().remove(0),
+        expected
+    );
+
+    glue.storage.vacuum("Item").unwrap();
+
+    assert!(!delta_path.exists());
+    assert_eq!(
+        glue.execute("SELECT * FROM Item;").unwrap().remove(0),
+        expected
+    );
+
+    glue.execute("DELETE FROM Item WHERE id = 3;").unwrap();
+    assert_eq!(
+        glue.execute("SELECT id FROM Item;").unwrap().remove(0),
+        select!(id I64; 2; 4)
+    );
+}
+
+#[test]
+fn fetch_data_skips_row_groups() {
+    let mut glue = new_glue("parquet_delta_fetch_data");
+
+    glue.execute("CREATE TABLE Item (id INTEGER PRIMARY KEY, num INTEGER);")
+        .unwrap();
+    glue.execute("INSERT INTO Item SELECT N, N * 2 FROM SERIES(20000);")
+        .unwrap();
+    glue.storage.vacuum("Item").unwrap();
+    glue.execute("UPDATE Item SET num = 0 WHERE id = 5;")
+        .unwrap();
+
+    assert_eq!(
+        glue.storage.fetch_data("Item", &Key::I64(15000)).unwrap(),
+        Some(vec![I64(15000), I64(30000)])
+    );
+    assert_eq!(
+        glue.storage.fetch_data("Item", &Key::I64(5)).unwrap(),
+        Some(vec![I64(5), I64(0)])
+    );
+    assert_eq!(
+        glue.storage.fetch_data("Item", &Key::I64(20001)).unwrap(),
+        None
+    );
+
+    glue.execute("CREATE TABLE Log (num INTEGER);").unwrap();
+    glue.execute("INSERT INTO Log SELECT N FROM SERIES(10000);")
+        .unwrap();
+    glue.storage.vacuum("Log").unwrap();
+
+    assert_eq!(
+        glue.storage.fetch_data("Log", &Key::U64(9000)).unwrap(),
+        Some(vec![I64(9001)])
+    );
+}
@@ -0,0 +1,146 @@
+use {
+    gluesql_core::{
+        ast::IndexOperator,
+        data::Key,
+        prelude::{Glue, Value::*},
+        store::{ScanPredicate, Store},
+    },
+    gluesql_parquet_storage::ParquetStorage,
+    std::fs::remove_dir_all,
+    test_suite::{concat_with, row, select, stringify_label},
+};
+
+fn new_glue(namespace: &str) -> Glue<ParquetStorage> {
+    let path = format!("tmp/{namespace}");
+    remove_dir_all(&path).unwrap_or(());
+
+    Glue::new(ParquetStorage::new(&path).unwrap())
+}
+
+#[test]
+fn scan_filtered_data_prunes_columns() {
+    let mut glue = new_glue("parquet_pushdown");
+
+    glue.execute("CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT, price INTEGER);")
+        .unwrap();
+    glue.execute("INSERT INTO Item VALUES (1, 'a', 10), (2, 'b', 20), (3, 'c', 30);")
+        .unwrap();
+    glue.storage.vacuum("Item").unwrap();
+    glue.execute("UPDATE Item SET price = 25 WHERE id = 2;")
+        .unwrap();
+
+    let rows = glue
+        .storage
+        .scan_filtered_data("Item", &[], Some(&[2]))
+        .unwrap()
+        .map(|row| row.unwrap().1)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        vec![
+            vec![I64(1), Null, I64(10)],
+            vec![I64(2), Str("b".to_owned()), I64(25)],
+            vec![I64(3), Null, I64(30)],
+        ]
+    );
+
+    assert_eq!(
+        glue.execute("SELECT name FROM Item WHERE price > 15 ORDER BY price DESC;")
+            .unwrap()
+            .remove(0),
+        select!(name Str; "c".to_owned(); "b".to_owned())
+    );
+    assert_eq!(
+        glue.execute("SELECT COUNT(*) AS cnt, SUM(price) AS total FROM Item;")
+            .unwrap()
+            .remove(0),
+        select!(
+            cnt | total
+            I64 | I64;
+            3     65
+        )
+    );
+}
+
+#[test]
+fn wildcard_aggregate_reads_every_column() {
+    let mut glue = Glue::new(ParquetStorage::new("./tests/samples/").unwrap());
+
+    assert_eq!(
+        glue.execute("SELECT COUNT(DISTINCT *) AS c FROM alltypes_plain WHERE bool_col = TRUE;")
+            .unwrap()
+            .remove(0),
+        select!(c I64; 4)
+    );
+}
+
+#[test]
+fn scan_filtered_data_skips_row_groups() {
+    let mut glue = new_glue("parquet_pushdown_row_groups");
+
+    glue.execute("CREATE TABLE Item (id INTEGER PRIMARY KEY, num INTEGER);")
+        .unwrap();
+    glue.execute("INSERT INTO Item SELECT N, N * 2 FROM SERIES(20000);")
+        .unwrap();
+    glue.storage.vacuum("Item").unwrap();
+    glue.execute("UPDATE Item SET num = 50000 WHERE id = 5;")
+        .unwrap();
+
+    let predicate = |column, operator, value| ScanPredicate {
+        column,
+        operator,
+        value: I64(value),
+    };
+
+    // Rows are written in row groups of 8192, so only the last one is read.
+    let keys = glue
+        .storage
+        .scan_filtered_data("Item", &[predicate(1, IndexOperator::Gt, 39990)], None)
+        .unwrap()
+        .map(|row| row.unwrap().0)
+        .collect::<Vec<_>>();
+    assert_eq!(keys.len(), 20000 - 16384 + 1);
+    assert_eq!(keys.first(), Some(&Key::I64(5)));
+    assert_eq!(keys.get(1), Some(&Key::I64(16385)));
+
+    assert_eq!(
+        glue.execute("SELECT id FROM Item WHERE num > 39994 ORDER BY id;")
+            .unwrap()
+            .remove(0),
+        select!(id I64; 5; 19998; 19999; 20000)
+    );
+    assert_eq!(
+        glue.execute("SELECT COUNT(*) AS cnt FROM Item WHERE id = 100 AND num = 200;")
+            .unwrap()
+            .remove(0),
+        select!(cnt I64; 1)
+    );
+
+    glue.execute("CREATE TABLE Log (num INTEGER);").unwrap();
+    glue.execute("INSERT INTO Log SELECT N FROM SERIES(10000);")
+        .unwrap();
+    glue.storage.vacuum("Log").unwrap();
+
+    let rows = glue
+        .storage
+        .scan_filtered_data("Log", &[predicate(0, IndexOperator::LtEq, 10)], None)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect::<Vec<_>>();
+    assert_eq!(rows.len(), 8192);
+
+    let rows = glue
+        .storage
+        .scan_filtered_data("Log", &[predicate(0, IndexOperator::GtEq, 9000)], None)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect::<Vec<_>>();
+    assert_eq!(rows.len(), 10000 - 8192);
+    assert_eq!(rows[0], (Key::U64(8192), vec![I64(8193)]));
+    assert_eq!(
+        glue.execute("SELECT num FROM Log WHERE num >= 9999;")
+            .unwrap()
+            .remove(0),
+        select!(num I64; 9999; 10000)
+    );
+}
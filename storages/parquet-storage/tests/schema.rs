@@ -8,7 +8,7 @@ use {
     },
     gluesql_parquet_storage::ParquetStorage,
     parquet::data_type::ByteArray,
-    std::fs,
+    std::{fs, path::Path},
     test_suite::{concat_with, concat_with_null, row, select, select_with_null, stringify_label},
 };
 
@@ -21,6 +21,13 @@ impl Drop for FileGuard {
         if let Err(err) = fs::remove_file(&self.path) {
             eprintln!("Failed to remove file: {err:?}");
         }
+
+        let delta_path = Path::new(&self.path).with_extension("delta");
+        if delta_path.exists()
+            && let Err(err) = fs::remove_dir_all(delta_path)
+        {
+            eprintln!("Failed to remove delta directory: {err:?}");
+        }
     }
 }
 
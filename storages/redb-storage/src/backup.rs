@@ -0,0 +1,124 @@
+use {
+    super::{
+        RedbStorage,
+        core::{SCHEMA_TABLE, StorageCore},
+        error::StorageError,
+    },
+    bincode::deserialize,
+    gluesql_core::{
+        data::{CustomFunction as StructCustomFunction, Key, Schema, Value},
+        error::Result,
+        store::{Backup, CustomFunction, RowIter, Store, Trigger},
+    },
+    redb::{ReadOnlyTable, ReadTransaction, ReadableTable, TableDefinition, TableError},
+};
+
+type StorageResult<T> = std::result::Result<T, StorageError>;
+
+/// Point-in-time view of a [`RedbStorage`].
+///
+/// The snapshot holds a redb read transaction, so commits made after it was
+/// taken stay hidden. Pages it still references are not reclaimed until the
+/// snapshot is dropped.
+pub struct RedbSnapshot(ReadTransaction);
+
+impl Backup for RedbStorage {
+    type Snapshot = RedbSnapshot;
+
+    fn snapshot(&self) -> Result<RedbSnapshot> {
+        self.0.begin_read().map(RedbSnapshot).map_err(Into::into)
+    }
+}
+
+impl RedbSnapshot {
+    /// Opens a table of the snapshot, treating a table that was never
+    /// written as empty.
+    fn open_table<K: redb::Key + 'static, V: redb::Value + 'static>(
+        &self,
+        table_def: TableDefinition<'_, K, V>,
+    ) -> StorageResult<Option<ReadOnlyTable<K, V>>> {
+        match self.0.open_table(table_def) {
+            Ok(table) => Ok(Some(table)),
+            Err(TableError::TableDoesNotExist(_)) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+impl Store for RedbSnapshot {
+    fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let Some(table) = self.open_table(SCHEMA_TABLE)? else {
+            return Ok(Vec::new());
+        };
+
+        table
+            .iter()
+            .map_err(StorageError::from)?
+            .map(|entry| {
+                let value = entry.map_err(StorageError::from)?.1.value();
+
+                deserialize(&value).map_err(|e| StorageError::from(e).into())
+            })
+            .collect()
+    }
+
+    fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let Some(table) = self.open_table(SCHEMA_TABLE)? else {
+            return Ok(None);
+        };
+
+        table
+            .get(table_name)
+            .map_err(StorageError::from)?
+            .map(|v| deserialize(&v.value()).map_err(|e| StorageError::from(e).into()))
+            .transpose()
+    }
+
+    fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<Vec<Value>>> {
+        let Some(table) = self.open_table(StorageCore::data_table_def(table_name)?)? else {
+            return Ok(None);
+        };
+
+        let key = key.to_cmp_be_bytes()?;
+        let row = table
+            .get(key.as_slice())
+            .map_err(StorageError::from)?
+            .map(|v| deserialize(&v.value()).map_err(StorageError::from))
+            .transpose()?
+            .map(|(_, row): (Key, Vec<Value>)| row);
+
+        Ok(row)
+    }
+
+    fn scan_data<'a>(&'a self, table_name: &str) -> Result<RowIter<'a>> {
+        let Some(table) = self.open_table(StorageCore::data_table_def(table_name)?)? else {
+            return Ok(Box::new(std::iter::empty()));
+        };
+
+        let rows =
+            table
+                .range::<&[u8]>(..)
+                .map_err(StorageError::from)?
+                .map(|entry| -> Result<_> {
+                    let value = entry.map_err(StorageError::from)?.1.value();
+                    let (key, row): (Key, Vec<Value>) =
+                        deserialize(&value).map_err(StorageError::from)?;
+
+                    Ok((key, row))
+                });
+
+        Ok(Box::new(rows))
+    }
+}
+
+impl Trigger for RedbSnapshot {}
+
+impl CustomFunction for RedbSnapshot {
+    fn fetch_function<'a>(&'a self, _func_name: &str) -> Result<Option<&'a StructCustomFunction>> {
+        Ok(None)
+    }
+
+    fn fetch_all_functions(&self) -> Result<Vec<&StructCustomFunction>> {
+        Ok(Vec::new())
+    }
+}
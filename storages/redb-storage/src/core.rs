@@ -1,21 +1,42 @@
 use {
     super::{
         error::StorageError,
+        index_sync::{
+            INDEX_TABLE_PREFIX, IndexSync, PlannedIndex, build_index_key, index_table_def,
+            index_table_name,
+        },
         migration::{ensure_storage_format_version_supported, initialize_storage_format_version},
     },
     bincode::{deserialize, serialize},
-    gluesql_core::data::{Key, Schema, Value},
-    redb::{Builder, Database, ReadableTable, TableDefinition, WriteTransaction},
-    std::path::Path,
+    gluesql_core::{
+        ast::{IndexOperator, OrderByExpr},
+        chrono::Utc,
+        data::{BoundingBox, Key, Schema, SchemaIndex, SchemaIndexOrd, Value},
+        error::IndexError,
+    },
+    redb::{
+        AccessGuard, Builder, Database, MultimapValue, ReadTransaction, ReadableMultimapTable,
+        ReadableTable, TableDefinition, TableError, WriteTransaction,
+    },
+    std::{ops::Bound, path::Path},
     uuid::Uuid,
 };
 
 pub(super) const SCHEMA_TABLE_NAME: &str = "__SCHEMA__";
 pub(super) const STORAGE_META_TABLE_NAME: &str = "__GLUESQL_META__";
-const SCHEMA_TABLE: TableDefinition<&str, Vec<u8>> = TableDefinition::new(SCHEMA_TABLE_NAME);
+pub(super) const SCHEMA_TABLE: TableDefinition<&str, Vec<u8>> =
+    TableDefinition::new(SCHEMA_TABLE_NAME);
 
 type Result<T> = std::result::Result<T, StorageError>;
 type RedbRowIter<'a> = Box<dyn Iterator<Item = Result<(Key, Vec<Value>)>> + 'a>;
+type IndexEntry<'a> = std::result::Result<
+    (
+        AccessGuard<'a, &'static [u8]>,
+        MultimapValue<'a, &'static [u8]>,
+    ),
+    redb::StorageError,
+>;
+pub(super) type DataTableDef<'a> = TableDefinition<'a, &'static [u8], Vec<u8>>;
 
 pub enum TransactionState {
     None,
@@ -60,14 +81,20 @@ impl StorageCore {
         })
     }
 
-    fn data_table_def(table_name: &str) -> Result<TableDefinition<'_, &'static [u8], Vec<u8>>> {
-        if matches!(table_name, SCHEMA_TABLE_NAME | STORAGE_META_TABLE_NAME) {
+    pub(super) fn data_table_def(table_name: &str) -> Result<DataTableDef<'_>> {
+        if matches!(table_name, SCHEMA_TABLE_NAME | STORAGE_META_TABLE_NAME)
+            || table_name.starts_with(INDEX_TABLE_PREFIX)
+        {
             return Err(StorageError::ReservedTableName(table_name.to_owned()));
         }
 
         Ok(TableDefinition::new(table_name))
     }
 
+    pub(super) fn begin_read(&self) -> Result<ReadTransaction> {
+        self.db.begin_read().map_err(Into::into)
+    }
+
     fn txn(&self) -> Result<&WriteTransaction> {
         match &self.state {
             TransactionState::Active { txn, .. } => Ok(txn),
@@ -190,25 +217,39 @@ impl StorageCore {
 
     pub fn delete_schema(&mut self, table_name: &str) -> Result<()> {
         let table_def = Self::data_table_def(table_name)?;
+        let schema = self.fetch_schema(table_name)?;
         let txn = self.txn_mut()?;
         let mut table = txn.open_table(SCHEMA_TABLE)?;
         table.remove(table_name)?;
         txn.delete_table(table_def)?;
 
+        for index in schema.iter().flat_map(|schema| &schema.indexes) {
+            let index_table_name = index_table_name(table_name, &index.name);
+            txn.delete_multimap_table(index_table_def(&index_table_name))?;
+        }
+
         Ok(())
     }
 
     pub fn append_data(&mut self, table_name: &str, rows: Vec<Vec<Value>>) -> Result<()> {
         let table_def = Self::data_table_def(table_name)?;
-        let txn = self.txn_mut()?;
+        let schema = self.fetch_schema(table_name)?;
+        let txn = self.txn()?;
+        let index_sync = schema
+            .as_ref()
+            .map(|schema| IndexSync::from_schema(txn, schema));
         let mut table = txn.open_table(table_def)?;
 
         for row in rows {
             let key = Key::Uuid(Uuid::now_v7().as_u128());
-            let value = serialize(&(&key, row))?;
+            let value = serialize(&(&key, &row))?;
             let table_key = key.to_cmp_be_bytes()?;
             let table_key = table_key.as_slice();
             table.insert(table_key, value)?;
+
+            if let Some(index_sync) = &index_sync {
+                index_sync.insert(table_key, &row)?;
+            }
         }
 
         Ok(())
@@ -216,14 +257,27 @@ impl StorageCore {
 
     pub fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, Vec<Value>)>) -> Result<()> {
         let table_def = Self::data_table_def(table_name)?;
-        let txn = self.txn_mut()?;
+        let schema = self.fetch_schema(table_name)?;
+        let txn = self.txn()?;
+        let index_sync = schema
+            .as_ref()
+            .map(|schema| IndexSync::from_schema(txn, schema));
         let mut table = txn.open_table(table_def)?;
 
         for (key, row) in rows {
-            let value = serialize(&(&key, row))?;
+            let value = serialize(&(&key, &row))?;
             let table_key = key.to_cmp_be_bytes()?;
             let table_key = table_key.as_slice();
-            table.insert(table_key, value)?;
+            let old_value = table.insert(table_key, value)?.map(|v| v.value());
+
+            if let Some(index_sync) = &index_sync {
+                if let Some(old_value) = old_value {
+                    let (_, old_row): (Key, Vec<Value>) = deserialize(&old_value)?;
+                    index_sync.delete(table_key, &old_row)?;
+                }
+
+                index_sync.insert(table_key, &row)?;
+            }
         }
 
         Ok(())
@@ -231,19 +285,138 @@ impl StorageCore {
 
     pub fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
         let table_def = Self::data_table_def(table_name)?;
-        let txn = self.txn_mut()?;
+        let schema = self.fetch_schema(table_name)?;
+        let txn = self.txn()?;
+        let index_sync = schema
+            .as_ref()
+            .map(|schema| IndexSync::from_schema(txn, schema));
         let mut table = txn.open_table(table_def)?;
 
         for key in keys {
             let table_key = key.to_cmp_be_bytes()?;
             let table_key = table_key.as_slice();
-            table.remove(table_key)?;
+            let old_value = table.remove(table_key)?.map(|v| v.value());
+
+            if let (Some(index_sync), Some(old_value)) = (&index_sync, old_value) {
+                let (_, old_row): (Key, Vec<Value>) = deserialize(&old_value)?;
+                index_sync.delete(table_key, &old_row)?;
+            }
         }
 
         Ok(())
     }
 }
 
+// Index
+impl StorageCore {
+    pub fn scan_indexed_data(
+        &self,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
+    ) -> Result<Vec<(Key, Vec<Value>)>> {
+        let table_def = Self::data_table_def(table_name)?;
+        let index_table_name = index_table_name(table_name, index_name);
+        let index_def = index_table_def(&index_table_name);
+
+        let rows = if let TransactionState::Active { autocommit, txn } = &self.state
+            && !autocommit
+        {
+            let index_table = txn.open_multimap_table(index_def)?;
+            let table = txn.open_table(table_def)?;
+
+            scan_index(&index_table, &table, cmp_value)?
+        } else {
+            let read_txn = self.db.begin_read()?;
+            let index_table = match read_txn.open_multimap_table(index_def) {
+                Ok(index_table) => index_table,
+                // indexes created on an empty table by earlier versions have no table
+                Err(TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+                Err(error) => return Err(error.into()),
+            };
+            let table = read_txn.open_table(table_def)?;
+
+            scan_index(&index_table, &table, cmp_value)?
+        };
+
+        Ok(match asc {
+            Some(true) | None => rows,
+            Some(false) => rows.into_iter().rev().collect(),
+        })
+    }
+}
+
+// IndexMut
+impl StorageCore {
+    pub fn create_index(
+        &mut self,
+        table_name: &str,
+        index_name: &str,
+        column: &OrderByExpr,
+    ) -> Result<()> {
+        let table_def = Self::data_table_def(table_name)?;
+        let mut schema = self
+            .fetch_schema(table_name)?
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+
+        if schema.indexes.iter().any(|index| index.name == index_name) {
+            return Err(IndexError::IndexNameAlreadyExists(index_name.to_owned()).into());
+        }
+
+        let index = SchemaIndex {
+            name: index_name.to_owned(),
+            expr: column.expr.clone(),
+            order: SchemaIndexOrd::Both,
+            created: Utc::now().naive_utc(),
+        };
+        let planned_index = PlannedIndex::new(index.clone());
+        schema.indexes.push(index);
+
+        let txn = self.txn()?;
+        let index_table_name = index_table_name(table_name, index_name);
+        txn.open_multimap_table(index_table_def(&index_table_name))?;
+
+        let index_sync = IndexSync::from_schema(txn, &schema);
+        let table = txn.open_table(table_def)?;
+
+        for entry in table.iter()? {
+            let (table_key, value) = entry?;
+            let (_, row): (Key, Vec<Value>) = deserialize(&value.value())?;
+
+            index_sync.insert_index(&planned_index, table_key.value(), &row)?;
+        }
+
+        txn.open_table(SCHEMA_TABLE)?
+            .insert(table_name, serialize(&schema)?)?;
+
+        Ok(())
+    }
+
+    pub fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        let mut schema = self
+            .fetch_schema(table_name)?
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+
+        let Some(position) = schema
+            .indexes
+            .iter()
+            .position(|index| index.name == index_name)
+        else {
+            return Err(IndexError::IndexNameDoesNotExist(index_name.to_owned()).into());
+        };
+        schema.indexes.remove(position);
+
+        let txn = self.txn()?;
+        let index_table_name = index_table_name(table_name, index_name);
+        txn.delete_multimap_table(index_table_def(&index_table_name))?;
+        txn.open_table(SCHEMA_TABLE)?
+            .insert(table_name, serialize(&schema)?)?;
+
+        Ok(())
+    }
+}
+
 // Transaction
 impl StorageCore {
     pub fn begin(&mut self, autocommit: bool) -> Result<bool> {
@@ -280,3 +453,89 @@ impl StorageCore {
         Ok(())
     }
 }
+
+fn scan_index(
+    index_table: &impl ReadableMultimapTable<&'static [u8], &'static [u8]>,
+    table: &impl ReadableTable<&'static [u8], Vec<u8>>,
+    cmp_value: Option<(&IndexOperator, Value)>,
+) -> Result<Vec<(Key, Vec<Value>)>> {
+    index_data_keys(index_table, cmp_value)?
+        .into_iter()
+        .map(|data_key| {
+            let value = table
+                .get(data_key.as_slice())?
+                .ok_or(IndexError::ConflictOnEmptyIndexValueScan)?
+                .value();
+
+            deserialize(&value).map_err(Into::into)
+        })
+        .collect()
+}
+
+fn index_data_keys(
+    index_table: &impl ReadableMultimapTable<&'static [u8], &'static [u8]>,
+    cmp_value: Option<(&IndexOperator, Value)>,
+) -> Result<Vec<Vec<u8>>> {
+    let Some((op, value)) = cmp_value else {
+        return collect_data_keys(index_table.iter()?, |_| true);
+    };
+
+    let key = build_index_key(&value)?;
+    let key = key.as_slice();
+    let range = match op {
+        IndexOperator::Eq => (Bound::Included(key), Bound::Included(key)),
+        IndexOperator::Gt => (Bound::Excluded(key), Bound::Unbounded),
+        IndexOperator::GtEq => (Bound::Included(key), Bound::Unbounded),
+        IndexOperator::Lt => (Bound::Unbounded, Bound::Excluded(key)),
+        IndexOperator::LtEq => (Bound::Unbounded, Bound::Included(key)),
+        IndexOperator::Intersects => {
+            let Some(area) = value.bounding_box() else {
+                return Ok(Vec::new());
+            };
+
+            // Spatial keys start with the box's `min_x`, so every candidate
+            // sorts before the first box starting right of the searched area.
+            let lower = [0_u8];
+            let upper = [&[0], &area.to_cmp_be_bytes()[8..16]].concat();
+            let entries = index_table
+                .range::<&[u8]>(&lower[..]..)?
+                .take_while(|entry| {
+                    entry.as_ref().map_or(true, |(index_key, _)| {
+                        let index_key = index_key.value();
+
+                        index_key[..index_key.len().min(upper.len())] <= *upper
+                    })
+                });
+
+            return collect_data_keys(entries, |index_key| {
+                index_key
+                    .get(1..33)
+                    .and_then(BoundingBox::from_cmp_be_bytes)
+                    .is_some_and(|bbox| bbox.intersects(&area))
+            });
+        }
+    };
+
+    collect_data_keys(index_table.range::<&[u8]>(range)?, |_| true)
+}
+
+fn collect_data_keys<'a>(
+    entries: impl Iterator<Item = IndexEntry<'a>>,
+    filter: impl Fn(&[u8]) -> bool,
+) -> Result<Vec<Vec<u8>>> {
+    let mut data_keys = Vec::new();
+
+    for entry in entries {
+        let (index_key, values) = entry?;
+
+        if !filter(index_key.value()) {
+            continue;
+        }
+
+        for data_key in values {
+            data_keys.push(data_key?.value().to_vec());
+        }
+    }
+
+    Ok(data_keys)
+}
@@ -1,4 +1,7 @@
-use {gluesql_core::error::Error, thiserror::Error as ThisError};
+use {
+    gluesql_core::error::{Error, IndexError},
+    thiserror::Error as ThisError,
+};
 
 #[derive(ThisError, Debug)]
 pub enum StorageError {
@@ -43,7 +46,10 @@ pub enum StorageError {
 
 impl From<StorageError> for Error {
     fn from(e: StorageError) -> Error {
-        Error::StorageMsg(e.to_string())
+        match e {
+            StorageError::Glue(e) => e,
+            e => Error::StorageMsg(e.to_string()),
+        }
     }
 }
 
@@ -52,3 +58,9 @@ impl From<redb::TransactionError> for StorageError {
         StorageError::RedbTransaction(Box::new(e))
     }
 }
+
+impl From<IndexError> for StorageError {
+    fn from(e: IndexError) -> StorageError {
+        StorageError::Glue(e.into())
+    }
+}
@@ -0,0 +1,134 @@
+use {
+    super::error::StorageError,
+    gluesql_core::{
+        data::{Schema, SchemaIndex, Value},
+        executor::{RowContext, evaluate_stateless},
+        plan::{ExprPlan, plan_scalar_expr},
+    },
+    redb::{MultimapTableDefinition, WriteTransaction},
+};
+
+type Result<T> = std::result::Result<T, StorageError>;
+
+pub(super) const INDEX_TABLE_PREFIX: &str = "__INDEX__/";
+
+pub(super) fn index_table_name(table_name: &str, index_name: &str) -> String {
+    format!("{INDEX_TABLE_PREFIX}{table_name}/{index_name}")
+}
+
+pub(super) fn index_table_def(
+    table_name: &str,
+) -> MultimapTableDefinition<'_, &'static [u8], &'static [u8]> {
+    MultimapTableDefinition::new(table_name)
+}
+
+pub(super) fn build_index_key(value: &Value) -> Result<Vec<u8>> {
+    match value.to_spatial_cmp_be_bytes() {
+        Some(bytes) => Ok(bytes),
+        None => value.to_cmp_be_bytes().map_err(Into::into),
+    }
+}
+
+pub(super) struct PlannedIndex {
+    name: String,
+    expr: ExprPlan,
+}
+
+impl PlannedIndex {
+    pub(super) fn new(index: SchemaIndex) -> Self {
+        let SchemaIndex { name, expr, .. } = index;
+
+        Self {
+            name,
+            expr: plan_scalar_expr(expr),
+        }
+    }
+}
+
+pub(super) struct IndexSync<'a> {
+    txn: &'a WriteTransaction,
+    table_name: &'a str,
+    columns: Option<Vec<String>>,
+    indexes: Vec<PlannedIndex>,
+}
+
+impl<'a> IndexSync<'a> {
+    pub(super) fn from_schema(txn: &'a WriteTransaction, schema: &'a Schema) -> Self {
+        let Schema {
+            table_name,
+            column_defs,
+            indexes,
+            ..
+        } = schema;
+
+        let columns = column_defs.as_ref().map(|column_defs| {
+            column_defs
+                .iter()
+                .map(|column_def| column_def.name.clone())
+                .collect::<Vec<_>>()
+        });
+
+        let indexes = indexes.iter().cloned().map(PlannedIndex::new).collect();
+
+        Self {
+            txn,
+            table_name,
+            columns,
+            indexes,
+        }
+    }
+
+    pub(super) fn insert(&self, data_key: &[u8], row: &[Value]) -> Result<()> {
+        for index in &self.indexes {
+            self.insert_index(index, data_key, row)?;
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn insert_index(
+        &self,
+        index: &PlannedIndex,
+        data_key: &[u8],
+        row: &[Value],
+    ) -> Result<()> {
+        let index_key = self.evaluate_index_key(&index.expr, row)?;
+        let table_name = index_table_name(self.table_name, &index.name);
+        let mut table = self.txn.open_multimap_table(index_table_def(&table_name))?;
+        table.insert(index_key.as_slice(), data_key)?;
+
+        Ok(())
+    }
+
+    pub(super) fn delete(&self, data_key: &[u8], row: &[Value]) -> Result<()> {
+        for index in &self.indexes {
+            self.delete_index(index, data_key, row)?;
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn delete_index(
+        &self,
+        index: &PlannedIndex,
+        data_key: &[u8],
+        row: &[Value],
+    ) -> Result<()> {
+        let index_key = self.evaluate_index_key(&index.expr, row)?;
+        let table_name = index_table_name(self.table_name, &index.name);
+        let mut table = self.txn.open_multimap_table(index_table_def(&table_name))?;
+        table.remove(index_key.as_slice(), data_key)?;
+
+        Ok(())
+    }
+
+    fn evaluate_index_key(&self, index_expr: &ExprPlan, row: &[Value]) -> Result<Vec<u8>> {
+        let context = Some(RowContext::RefVecData {
+            columns: self.columns.as_deref().unwrap_or(&[]),
+            values: row,
+        });
+        let value: Value = evaluate_stateless(context, index_expr)?.try_into()?;
+
+        build_index_key(&value)
+    }
+}
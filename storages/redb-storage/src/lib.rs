@@ -1,19 +1,24 @@
 #![deny(clippy::str_to_string)]
 
+mod backup;
 mod core;
 mod error;
+mod index_sync;
 mod migration;
+mod planner;
 
+pub use backup::RedbSnapshot;
 pub use migration::{MigrationReport, REDB_STORAGE_FORMAT_VERSION, migrate_to_latest};
 
 use {
     core::StorageCore,
     gluesql_core::{
+        ast::{IndexOperator, OrderByExpr},
         data::{Key, Schema, Value},
         error::Result,
         store::{
-            AlterTable, CustomFunction, CustomFunctionMut, Index, IndexMut, Metadata, Planner,
-            RowIter, Store, StoreMut, Transaction,
+            AlterTable, CustomFunction, CustomFunctionMut, Index, IndexMut, Metadata, RowIter,
+            Store, StoreMut, Transaction, Trigger, TriggerMut,
         },
     },
     redb::Database,
@@ -88,10 +93,44 @@ impl Transaction for RedbStorage {
     }
 }
 
+impl Index for RedbStorage {
+    fn scan_indexed_data<'a>(
+        &'a self,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
+    ) -> Result<RowIter<'a>> {
+        let rows = self
+            .0
+            .scan_indexed_data(table_name, index_name, asc, cmp_value)?;
+
+        Ok(Box::new(rows.into_iter().map(Ok)))
+    }
+}
+
+impl IndexMut for RedbStorage {
+    fn create_index(
+        &mut self,
+        table_name: &str,
+        index_name: &str,
+        column: &OrderByExpr,
+    ) -> Result<()> {
+        self.0
+            .create_index(table_name, index_name, column)
+            .map_err(Into::into)
+    }
+
+    fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        self.0
+            .drop_index(table_name, index_name)
+            .map_err(Into::into)
+    }
+}
+
 impl AlterTable for RedbStorage {}
-impl Index for RedbStorage {}
-impl IndexMut for RedbStorage {}
 impl Metadata for RedbStorage {}
 impl CustomFunction for RedbStorage {}
 impl CustomFunctionMut for RedbStorage {}
-impl Planner for RedbStorage {}
+impl Trigger for RedbStorage {}
+impl TriggerMut for RedbStorage {}
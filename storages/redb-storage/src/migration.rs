@@ -1,5 +1,6 @@
 mod v1_to_v2;
 mod v2_to_v3;
+mod v3_to_v4;
 
 use {
     crate::{
@@ -16,10 +17,12 @@ use {
 /// This is stored in the `__GLUESQL_META__` table and is independent of both
 /// the redb crate version and redb's internal file format version. Storage
 /// format v3 keeps the v2 row serialization and requires redb file format v3.
-pub const REDB_STORAGE_FORMAT_VERSION: u32 = 3;
+/// Storage format v4 adds `ttl_column` to the serialized table schemas.
+pub const REDB_STORAGE_FORMAT_VERSION: u32 = 4;
 
 const V1_REDB_STORAGE_FORMAT_VERSION: u32 = 1;
 const V2_REDB_STORAGE_FORMAT_VERSION: u32 = 2;
+const V3_REDB_STORAGE_FORMAT_VERSION: u32 = 3;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct MigrationReport {
@@ -40,6 +43,7 @@ enum DetectedStorageFormatVersion {
     V1,
     V2,
     V3,
+    V4,
     UnsupportedNewer(u32),
     Unsupported(u32),
 }
@@ -62,7 +66,11 @@ pub(super) fn ensure_storage_format_version_supported(db: &Database) -> StorageR
             found: V2_REDB_STORAGE_FORMAT_VERSION,
             expected: REDB_STORAGE_FORMAT_VERSION,
         }),
-        DetectedStorageFormatVersion::V3 => Ok(()),
+        DetectedStorageFormatVersion::V3 => Err(StorageError::MigrationRequired {
+            found: V3_REDB_STORAGE_FORMAT_VERSION,
+            expected: REDB_STORAGE_FORMAT_VERSION,
+        }),
+        DetectedStorageFormatVersion::V4 => Ok(()),
         DetectedStorageFormatVersion::UnsupportedNewer(version) => {
             Err(StorageError::UnsupportedNewerFormatVersion(version))
         }
@@ -110,32 +118,42 @@ fn migrate_database_to_latest(db: &mut Database) -> StorageResult<MigrationRepor
             // Phase 2: upgrade redb internal file format v2 → v3.
             v2_to_v3::migrate(db)?;
 
-            // Phase 3: record GlueSQL storage version 3.
+            // Phase 3: rewrite schemas with `ttl_column` and record GlueSQL storage version 4.
             let txn = db.begin_write()?;
+            v3_to_v4::migrate_schemas(&txn)?;
             write_storage_format_version(&txn, REDB_STORAGE_FORMAT_VERSION)?;
             txn.commit()?;
 
             Ok(report)
         }
         DetectedStorageFormatVersion::V2 => {
-            // GlueSQL row format is unchanged; only the redb file format needs upgrading.
-            let read_txn = db.begin_read()?;
-            let unchanged_tables = list_user_table_names_from_read(&read_txn)?.len();
-            drop(read_txn);
-
+            // GlueSQL row format is unchanged; the redb file format and schemas need upgrading.
             v2_to_v3::migrate(db)?;
 
             let txn = db.begin_write()?;
+            let migrated_tables = v3_to_v4::migrate_schemas(&txn)?;
             write_storage_format_version(&txn, REDB_STORAGE_FORMAT_VERSION)?;
             txn.commit()?;
 
             Ok(MigrationReport {
-                migrated_tables: 0,
-                unchanged_tables,
+                migrated_tables,
+                unchanged_tables: 0,
                 rewritten_rows: 0,
             })
         }
         DetectedStorageFormatVersion::V3 => {
+            let txn = db.begin_write()?;
+            let migrated_tables = v3_to_v4::migrate_schemas(&txn)?;
+            write_storage_format_version(&txn, REDB_STORAGE_FORMAT_VERSION)?;
+            txn.commit()?;
+
+            Ok(MigrationReport {
+                migrated_tables,
+                unchanged_tables: 0,
+                rewritten_rows: 0,
+            })
+        }
+        DetectedStorageFormatVersion::V4 => {
             let read_txn = db.begin_read()?;
             let unchanged_tables = list_user_table_names_from_read(&read_txn)?.len();
 
@@ -165,7 +183,8 @@ fn detect_storage_format_version(db: &Database) -> StorageResult<DetectedStorage
 
             Ok(match version {
                 V2_REDB_STORAGE_FORMAT_VERSION => DetectedStorageFormatVersion::V2,
-                REDB_STORAGE_FORMAT_VERSION => DetectedStorageFormatVersion::V3,
+                V3_REDB_STORAGE_FORMAT_VERSION => DetectedStorageFormatVersion::V3,
+                REDB_STORAGE_FORMAT_VERSION => DetectedStorageFormatVersion::V4,
                 version if version > REDB_STORAGE_FORMAT_VERSION => {
                     DetectedStorageFormatVersion::UnsupportedNewer(version)
                 }
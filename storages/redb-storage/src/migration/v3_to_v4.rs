@@ -0,0 +1,66 @@
+use {
+    super::SCHEMA_TABLE,
+    crate::error::StorageError,
+    bincode::{deserialize, serialize},
+    gluesql_core::{
+        ast::{ColumnDef, ForeignKey},
+        data::{Schema, SchemaIndex},
+    },
+    redb::{ReadableTable, WriteTransaction},
+    serde::Deserialize,
+};
+
+type Result<T> = std::result::Result<T, StorageError>;
+
+/// `Schema` as serialized by storage format v3, before `ttl_column` was added.
+#[derive(Debug, Deserialize)]
+struct V3Schema {
+    table_name: String,
+    column_defs: Option<Vec<ColumnDef>>,
+    indexes: Vec<SchemaIndex>,
+    engine: Option<String>,
+    foreign_keys: Vec<ForeignKey>,
+    comment: Option<String>,
+}
+
+impl From<V3Schema> for Schema {
+    fn from(schema: V3Schema) -> Self {
+        let V3Schema {
+            table_name,
+            column_defs,
+            indexes,
+            engine,
+            foreign_keys,
+            comment,
+        } = schema;
+
+        Schema {
+            table_name,
+            column_defs,
+            indexes,
+            engine,
+            foreign_keys,
+            comment,
+            ttl_column: None,
+        }
+    }
+}
+
+pub(super) fn migrate_schemas(txn: &WriteTransaction) -> Result<usize> {
+    let mut table = txn.open_table(SCHEMA_TABLE)?;
+    let schemas = table
+        .iter()?
+        .map(|entry| {
+            let (table_name, value) = entry?;
+            let schema: Schema = deserialize::<V3Schema>(&value.value())?.into();
+
+            Ok((table_name.value().to_owned(), serialize(&schema)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    for (table_name, schema) in &schemas {
+        table.insert(table_name.as_str(), schema)?;
+    }
+
+    Ok(schemas.len())
+}
@@ -0,0 +1,36 @@
+use {
+    gluesql_core::prelude::{Glue, Payload, Value},
+    gluesql_redb_storage::RedbStorage,
+    std::fs::{create_dir, remove_file},
+};
+
+#[test]
+fn empty_table_index() {
+    let _ = create_dir("tmp");
+    let path = "tmp/redb_empty_table_index";
+    let _ = remove_file(path);
+
+    let storage = RedbStorage::new(path).unwrap();
+    let mut glue = Glue::new(storage);
+
+    glue.execute("CREATE TABLE T (id INTEGER); CREATE INDEX idx_id ON T (id);")
+        .unwrap();
+
+    let select = "SELECT * FROM T WHERE id = 1";
+    assert_eq!(
+        glue.execute(select),
+        Ok(vec![Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: Vec::new(),
+        }])
+    );
+
+    glue.execute("INSERT INTO T VALUES (1);").unwrap();
+    assert_eq!(
+        glue.execute(select),
+        Ok(vec![Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![vec![Value::I64(1)]],
+        }])
+    );
+}
@@ -1,7 +1,8 @@
 use {
     bincode::{deserialize, serialize},
     gluesql_core::{
-        data::{Key, Schema, Value},
+        ast::{ColumnDef, ForeignKey},
+        data::{Key, Schema, SchemaIndex, Value},
         error::Error,
         store::{Store, StoreMut, Transaction},
     },
@@ -23,6 +24,17 @@ const SCHEMA_TABLE_WITH_BINARY_KEY: TableDefinition<&[u8], Vec<u8>> =
     TableDefinition::new("__SCHEMA__");
 const STORAGE_META_VERSION_KEY: &str = "storage_format_version";
 
+/// Table schema as serialized before storage format v4 added `ttl_column`.
+#[derive(Serialize)]
+struct V3Schema {
+    table_name: String,
+    column_defs: Option<Vec<ColumnDef>>,
+    indexes: Vec<SchemaIndex>,
+    engine: Option<String>,
+    foreign_keys: Vec<ForeignKey>,
+    comment: Option<String>,
+}
+
 #[derive(Serialize)]
 enum V1DataRow {
     Vec(Vec<Value>),
@@ -49,7 +61,7 @@ fn write_v1_storage(path: &str, table_name: &str, rows: Vec<(Key, V1DataRow)>) {
     let db = Database::create(path).expect("create database");
     let txn = db.begin_write().expect("begin write transaction");
 
-    let schema = Schema {
+    let schema = V3Schema {
         table_name: table_name.to_owned(),
         column_defs: None,
         indexes: Vec::new(),
@@ -198,7 +210,7 @@ fn v1_storage_requires_migration() {
     assert_eq!(
         err,
         Error::StorageMsg(
-            "[RedbStorage] migration required (found v1, expected v3); migrate redb-storage data to the latest format before opening"
+            "[RedbStorage] migration required (found v1, expected v4); migrate redb-storage data to the latest format before opening"
                 .to_owned(),
         ),
     );
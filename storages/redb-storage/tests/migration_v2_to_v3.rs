@@ -1,11 +1,13 @@
 use {
     bincode::{deserialize, serialize},
     gluesql_core::{
-        data::{Key, Schema, Value},
+        ast::{ColumnDef, ForeignKey},
+        data::{Key, SchemaIndex, Value},
         error::Error,
     },
     gluesql_redb_storage::{REDB_STORAGE_FORMAT_VERSION, RedbStorage, migrate_to_latest},
     redb::{Database, ReadableTable, TableDefinition},
+    serde::Serialize,
     std::fs::{create_dir, remove_dir_all, remove_file},
     uuid::Uuid,
 };
@@ -15,6 +17,17 @@ const META_TABLE: TableDefinition<&str, u32> = TableDefinition::new("__GLUESQL_M
 const STORAGE_META_VERSION_KEY: &str = "storage_format_version";
 const V2_FORMAT_VERSION: u32 = 2;
 
+/// Table schema as serialized before storage format v4 added `ttl_column`.
+#[derive(Serialize)]
+struct V3Schema {
+    table_name: String,
+    column_defs: Option<Vec<ColumnDef>>,
+    indexes: Vec<SchemaIndex>,
+    engine: Option<String>,
+    foreign_keys: Vec<ForeignKey>,
+    comment: Option<String>,
+}
+
 fn test_path(name: &str) -> String {
     format!("tmp/{name}-{}", Uuid::now_v7())
 }
@@ -37,7 +50,7 @@ fn write_v2_storage(path: &str, table_name: &str, rows: Vec<(Key, Vec<Value>)>)
     let db = Database::create(path).expect("create v2 database");
     let txn = db.begin_write().expect("begin write transaction");
 
-    let schema = Schema {
+    let schema = V3Schema {
         table_name: table_name.to_owned(),
         column_defs: None,
         indexes: Vec::new(),
@@ -120,7 +133,7 @@ fn v2_storage_requires_migration() {
     assert_eq!(
         err,
         Error::StorageMsg(
-            "[RedbStorage] migration required (found v2, expected v3); migrate redb-storage data to the latest format before opening"
+            "[RedbStorage] migration required (found v2, expected v4); migrate redb-storage data to the latest format before opening"
                 .to_owned(),
         ),
     );
@@ -141,8 +154,8 @@ fn v2_to_v3_migration_keeps_rows_and_upgrades_format() {
     );
 
     let report = migrate_to_latest(&path).expect("migrate v2 to v3");
-    assert_eq!(report.migrated_tables, 0);
-    assert_eq!(report.unchanged_tables, 1);
+    assert_eq!(report.migrated_tables, 1);
+    assert_eq!(report.unchanged_tables, 0);
     assert_eq!(report.rewritten_rows, 0);
     assert_eq!(
         read_storage_format_version(&path),
@@ -174,8 +187,8 @@ fn v2_to_v3_migration_is_idempotent() {
     write_v2_storage(&path, "Foo", vec![]);
 
     let first = migrate_to_latest(&path).expect("first migration");
-    assert_eq!(first.migrated_tables, 0);
-    assert_eq!(first.unchanged_tables, 1);
+    assert_eq!(first.migrated_tables, 1);
+    assert_eq!(first.unchanged_tables, 0);
 
     let second = migrate_to_latest(&path).expect("second migration");
     assert_eq!(second.migrated_tables, 0);
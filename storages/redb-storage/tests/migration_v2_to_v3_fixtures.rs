@@ -44,8 +44,8 @@ fn migrate_v2_mixed_schema_schemaless_fixture() {
     let (path, _guard) = fixture_to_tmp("mixed_schema_schemaless");
 
     let first = migrate_to_latest(&path).expect("migrate fixture");
-    assert_eq!(first.migrated_tables, 0);
-    assert_eq!(first.unchanged_tables, 2);
+    assert_eq!(first.migrated_tables, 2);
+    assert_eq!(first.unchanged_tables, 0);
     assert_eq!(first.rewritten_rows, 0);
 
     let second = migrate_to_latest(&path).expect("migrate fixture twice");
@@ -0,0 +1,139 @@
+use {
+    bincode::serialize,
+    gluesql_core::{
+        ast::{ColumnDef, ForeignKey},
+        data::{Key, SchemaIndex, Value},
+        error::Error,
+        prelude::{DataType, Glue, Payload},
+    },
+    gluesql_redb_storage::{REDB_STORAGE_FORMAT_VERSION, RedbStorage, migrate_to_latest},
+    redb::{Database, TableDefinition},
+    serde::Serialize,
+    std::fs::{create_dir, remove_file},
+    uuid::Uuid,
+};
+
+const SCHEMA_TABLE: TableDefinition<&str, Vec<u8>> = TableDefinition::new("__SCHEMA__");
+const META_TABLE: TableDefinition<&str, u32> = TableDefinition::new("__GLUESQL_META__");
+const STORAGE_META_VERSION_KEY: &str = "storage_format_version";
+const V3_FORMAT_VERSION: u32 = 3;
+
+/// Table schema as serialized before storage format v4 added `ttl_column`.
+#[derive(Serialize)]
+struct V3Schema {
+    table_name: String,
+    column_defs: Option<Vec<ColumnDef>>,
+    indexes: Vec<SchemaIndex>,
+    engine: Option<String>,
+    foreign_keys: Vec<ForeignKey>,
+    comment: Option<String>,
+}
+
+fn test_path(name: &str) -> String {
+    format!("tmp/{name}-{}", Uuid::now_v7())
+}
+
+/// Creates a database in `GlueSQL` format v3 (redb internal file format v3).
+fn write_v3_storage(path: &str) {
+    let _ = create_dir("tmp");
+
+    let mut db = Database::create(path).expect("create database");
+    db.upgrade().expect("upgrade redb file format");
+    let txn = db.begin_write().expect("begin write transaction");
+
+    let schema = V3Schema {
+        table_name: "Foo".to_owned(),
+        column_defs: Some(vec![ColumnDef {
+            name: "id".to_owned(),
+            data_type: DataType::Int,
+            nullable: false,
+            default: None,
+            unique: None,
+            comment: None,
+        }]),
+        indexes: Vec::new(),
+        engine: None,
+        foreign_keys: Vec::new(),
+        comment: Some("legacy".to_owned()),
+    };
+    let mut schema_table = txn.open_table(SCHEMA_TABLE).expect("open schema table");
+    schema_table
+        .insert("Foo", serialize(&schema).expect("serialize schema"))
+        .expect("insert schema");
+    drop(schema_table);
+
+    let table_def = TableDefinition::<&[u8], Vec<u8>>::new("Foo");
+    let mut table = txn.open_table(table_def).expect("open data table");
+    let key = Key::I64(1);
+    let table_key = key.to_cmp_be_bytes().expect("table key bytes");
+    let payload = serialize(&(&key, vec![Value::I32(1)])).expect("serialize row");
+    table
+        .insert(table_key.as_slice(), payload)
+        .expect("insert row");
+    drop(table);
+
+    let mut meta = txn.open_table(META_TABLE).expect("open metadata table");
+    meta.insert(STORAGE_META_VERSION_KEY, &V3_FORMAT_VERSION)
+        .expect("insert format version");
+    drop(meta);
+
+    txn.commit().expect("commit");
+}
+
+#[test]
+fn v3_storage_requires_migration() {
+    let path = test_path("redb-v3-requires-migration");
+    write_v3_storage(&path);
+
+    let err = match RedbStorage::new(&path) {
+        Ok(_storage) => panic!("migration should be required"),
+        Err(err) => err,
+    };
+    assert_eq!(
+        err,
+        Error::StorageMsg(
+            "[RedbStorage] migration required (found v3, expected v4); migrate redb-storage data to the latest format before opening"
+                .to_owned(),
+        ),
+    );
+
+    remove_file(&path).expect("remove database");
+}
+
+#[test]
+fn v3_to_v4_migration_rewrites_schemas() {
+    let path = test_path("redb-v3-to-v4");
+    write_v3_storage(&path);
+
+    let report = migrate_to_latest(&path).expect("migrate v3 to v4");
+    assert_eq!(report.migrated_tables, 1);
+    assert_eq!(report.unchanged_tables, 0);
+    assert_eq!(report.rewritten_rows, 0);
+
+    let second = migrate_to_latest(&path).expect("migrate twice");
+    assert_eq!(second.migrated_tables, 0);
+    assert_eq!(second.unchanged_tables, 1);
+    assert_eq!(REDB_STORAGE_FORMAT_VERSION, 4);
+
+    let storage = RedbStorage::new(&path).expect("open migrated storage");
+    let mut glue = Glue::new(storage);
+
+    let actual = glue
+        .execute("SELECT id FROM Foo")
+        .expect("select migrated rows");
+    assert_eq!(
+        actual,
+        vec![Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![vec![Value::I32(1)]],
+        }],
+    );
+
+    glue.execute(
+        "CREATE TABLE Session (id INTEGER, expires_at TIMESTAMP) WITH (ttl_column = expires_at)",
+    )
+    .expect("create ttl table after migration");
+
+    drop(glue);
+    remove_file(&path).expect("remove database");
+}
@@ -28,3 +28,5 @@ impl Tester<RedbStorage> for RedbTester {
 
 generate_store_tests!(test, RedbTester);
 generate_transaction_tests!(test, RedbTester);
+generate_index_tests!(test, RedbTester);
+generate_transaction_index_tests!(test, RedbTester);
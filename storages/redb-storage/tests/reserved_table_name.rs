@@ -31,4 +31,13 @@ fn reserved_table_name() {
         ))
         .map(|payload| vec![payload])
     );
+
+    let result = glue.execute(r#"CREATE TABLE "__INDEX__/Foo/idx" (id INTEGER);"#);
+    assert_eq!(
+        result,
+        Err(Error::StorageMsg(
+            "cannot create table with reserved name: __INDEX__/Foo/idx".to_owned(),
+        ))
+        .map(|payload| vec![payload])
+    );
 }
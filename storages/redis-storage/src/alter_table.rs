@@ -18,6 +18,7 @@ impl AlterTable for RedisStorage {
 
             new_table_name.clone_into(&mut schema.table_name);
             self.redis_store_schema(&schema)?;
+            let ttl_index = schema.ttl_index();
 
             let redis_key_iter: Vec<String> = self.redis_execute_scan(table_name)?;
 
@@ -27,6 +28,14 @@ impl AlterTable for RedisStorage {
                     let new_key = Self::redis_generate_key(&self.namespace, new_table_name, &key)?;
 
                     self.redis_execute_set(&new_key, &value)?;
+                    if ttl_index.is_some() {
+                        let row: Vec<Value> = serde_json::from_str(&value).map_err(|e| {
+                            Error::StorageMsg(format!(
+                                "[RedisStorage] failed to deserialize value={value} error={e}"
+                            ))
+                        })?;
+                        self.redis_expire_row(&new_key, &row, ttl_index)?;
+                    }
                     self.redis_execute_del(&redis_key)?;
                 }
             }
@@ -76,6 +85,7 @@ impl AlterTable for RedisStorage {
 
     fn add_column(&mut self, table_name: &str, column_def: &ColumnDef) -> Result<()> {
         if let Some(mut schema) = self.fetch_schema(table_name)? {
+            let ttl_index = schema.ttl_index();
             let column_defs = schema
                 .column_defs
                 .as_mut()
@@ -164,6 +174,7 @@ impl AlterTable for RedisStorage {
                             ))
                         })?;
                 };
+                self.redis_expire_row(&key, &row, ttl_index)?;
             }
 
             column_defs.push(column_def.clone());
@@ -178,6 +189,7 @@ impl AlterTable for RedisStorage {
 
     fn drop_column(&mut self, table_name: &str, column_name: &str, if_exists: bool) -> Result<()> {
         if let Some(mut schema) = self.fetch_schema(table_name)? {
+            let ttl_index = schema.ttl_index();
             let column_defs = schema
                 .column_defs
                 .as_mut()
@@ -190,6 +202,13 @@ impl AlterTable for RedisStorage {
             match column_index {
                 Some(column_index) => {
                     column_defs.remove(column_index);
+                    let ttl_index = ttl_index.map(|ttl_index| {
+                        if ttl_index > column_index {
+                            ttl_index - 1
+                        } else {
+                            ttl_index
+                        }
+                    });
 
                     let key_iter = self.redis_execute_scan(table_name)?;
                     for key in key_iter {
@@ -213,6 +232,7 @@ impl AlterTable for RedisStorage {
                                 ))
                             })?;
                             self.redis_execute_set(&key, &new_value)?;
+                            self.redis_expire_row(&key, &row, ttl_index)?;
                         }
                     }
                 }
@@ -5,11 +5,12 @@ mod index;
 mod metadata;
 mod mutex;
 mod transaction;
+mod trigger;
 
 use mutex::MutexExt;
 use {
     gluesql_core::{
-        chrono::Utc,
+        chrono::{NaiveDateTime, Utc},
         data::{CustomFunction as StructCustomFunction, Key, Schema, Value},
         error::{Error, Result},
         store::{CustomFunction, CustomFunctionMut, Planner, RowIter, Store, StoreMut},
@@ -133,6 +134,37 @@ impl RedisStorage {
         Ok(())
     }
 
+    fn redis_execute_pexpireat(&mut self, key: &str, expires_at: NaiveDateTime) -> Result<()> {
+        let mut conn = self.conn.lock_err()?;
+        let expires_at = expires_at.and_utc().timestamp_millis();
+        redis::cmd("PEXPIREAT")
+            .arg(key)
+            .arg(expires_at)
+            .query::<()>(&mut *conn)
+            .map_err(|e| {
+                Error::StorageMsg(format!(
+                    "[RedisStorage] failed to execute PEXPIREAT: key={key} expires_at={expires_at} error={e}"
+                ))
+            })?;
+
+        Ok(())
+    }
+
+    /// Maps the `ttl_column` of a row onto the native expiry of its key.
+    ///
+    /// `SET` discards any previous expiry, so this runs after every row write.
+    pub fn redis_expire_row(
+        &mut self,
+        key: &str,
+        row: &[Value],
+        ttl_index: Option<usize>,
+    ) -> Result<()> {
+        match ttl_index.and_then(|ttl_index| row.get(ttl_index)) {
+            Some(Value::Timestamp(expires_at)) => self.redis_execute_pexpireat(key, *expires_at),
+            _ => Ok(()),
+        }
+    }
+
     pub fn redis_execute_del(&mut self, key: &str) -> Result<()> {
         let mut conn = self.conn.lock_err()?;
         redis::cmd("DEL")
@@ -437,6 +469,10 @@ impl StoreMut for RedisStorage {
     }
 
     fn append_data(&mut self, table_name: &str, rows: Vec<Vec<Value>>) -> Result<()> {
+        let ttl_index = self
+            .fetch_schema(table_name)?
+            .and_then(|schema| schema.ttl_index());
+
         for row in rows {
             // Even multiple clients can get an unique value with INCR command.
             // and a shared key "globalkey"
@@ -458,12 +494,17 @@ impl StoreMut for RedisStorage {
             })?;
 
             self.redis_execute_set(&redis_key, &value)?;
+            self.redis_expire_row(&redis_key, &row, ttl_index)?;
         }
 
         Ok(())
     }
 
     fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, Vec<Value>)>) -> Result<()> {
+        let ttl_index = self
+            .fetch_schema(table_name)?
+            .and_then(|schema| schema.ttl_index());
+
         for (key, row) in rows {
             let redis_key = Self::redis_generate_key(&self.namespace, table_name, &key)?;
             let value = serde_json::to_string(&row).map_err(|e| {
@@ -472,6 +513,7 @@ impl StoreMut for RedisStorage {
                 ))
             })?;
             self.redis_execute_set(&redis_key, &value)?;
+            self.redis_expire_row(&redis_key, &row, ttl_index)?;
         }
 
         Ok(())
@@ -0,0 +1,105 @@
+use {
+    crate::{RedisStorage, mutex::MutexExt},
+    gluesql_core::{
+        data::Trigger as StructTrigger,
+        error::{Error, Result},
+        store::{Trigger, TriggerMut},
+    },
+    redis::Commands,
+};
+
+impl RedisStorage {
+    /// Make a key to store all triggers of a table, in the order they were created.
+    fn redis_generate_trigger_key(namespace: &str, table_name: &str) -> String {
+        format!("#trigger#{namespace}#{table_name}#")
+    }
+
+    fn redis_generate_scan_trigger_key(namespace: &str) -> String {
+        format!("#trigger#{namespace}#*")
+    }
+
+    fn redis_fetch_triggers(&self, redis_key: &str) -> Result<Vec<StructTrigger>> {
+        let value = {
+            let mut conn = self.conn.lock_err()?;
+            redis::cmd("GET")
+                .arg(redis_key)
+                .query::<Option<String>>(&mut *conn)
+                .map_err(|e| {
+                    Error::StorageMsg(format!(
+                        "[RedisStorage] failed to execute GET: key={redis_key} error={e}"
+                    ))
+                })?
+        };
+
+        value.map_or(Ok(Vec::new()), |value| {
+            serde_json::from_str(&value).map_err(|e| {
+                Error::StorageMsg(format!(
+                    "[RedisStorage] failed to deserialize triggers={value} error={e}"
+                ))
+            })
+        })
+    }
+
+    fn redis_store_triggers(&mut self, table_name: &str, triggers: &[StructTrigger]) -> Result<()> {
+        let redis_key = Self::redis_generate_trigger_key(&self.namespace, table_name);
+        if triggers.is_empty() {
+            return self.redis_execute_del(&redis_key);
+        }
+
+        let value = serde_json::to_string(triggers).map_err(|e| {
+            Error::StorageMsg(format!(
+                "[RedisStorage] failed to serialize triggers={triggers:?} error={e}"
+            ))
+        })?;
+
+        self.redis_execute_set(&redis_key, &value)
+    }
+}
+
+impl Trigger for RedisStorage {
+    fn fetch_triggers(&self, table_name: &str) -> Result<Vec<StructTrigger>> {
+        let redis_key = Self::redis_generate_trigger_key(&self.namespace, table_name);
+
+        self.redis_fetch_triggers(&redis_key)
+    }
+
+    fn fetch_all_triggers(&self) -> Result<Vec<StructTrigger>> {
+        let scan_key = Self::redis_generate_scan_trigger_key(&self.namespace);
+        let mut redis_keys: Vec<String> = {
+            let mut conn = self.conn.lock_err()?;
+            conn.scan_match(&scan_key)
+                .map(Iterator::collect::<Vec<String>>)
+                .map_err(|e| {
+                    Error::StorageMsg(format!(
+                        "[RedisStorage] failed to scan triggers: namespace={} error={}",
+                        self.namespace, e
+                    ))
+                })?
+        };
+        redis_keys.sort();
+
+        let mut triggers = Vec::new();
+        for redis_key in redis_keys {
+            triggers.extend(self.redis_fetch_triggers(&redis_key)?);
+        }
+
+        Ok(triggers)
+    }
+}
+
+impl TriggerMut for RedisStorage {
+    fn insert_trigger(&mut self, trigger: StructTrigger) -> Result<()> {
+        let mut triggers = self.fetch_triggers(&trigger.table_name)?;
+        let table_name = trigger.table_name.clone();
+        triggers.push(trigger);
+
+        self.redis_store_triggers(&table_name, &triggers)
+    }
+
+    fn delete_trigger(&mut self, table_name: &str, trigger_name: &str) -> Result<()> {
+        let mut triggers = self.fetch_triggers(table_name)?;
+        triggers.retain(|trigger| trigger.name != trigger_name);
+
+        self.redis_store_triggers(table_name, &triggers)
+    }
+}
@@ -32,6 +32,7 @@ impl Tester<RedisStorage> for RedisStorageTester {
             format!("{namespace}#*"),
             format!("#schema#{namespace}#*"),
             format!("#metadata#{namespace}#*"),
+            format!("#trigger#{namespace}#*"),
         ] {
             let key_iter: Vec<String> = {
                 let mut conn = storage.conn.lock().unwrap();
@@ -61,6 +62,7 @@ impl Tester<RedisStorage> for RedisStorageTester {
 generate_store_tests!(test, RedisStorageTester);
 generate_alter_table_tests!(test, RedisStorageTester);
 generate_metadata_table_tests!(test, RedisStorageTester);
+generate_trigger_tests!(test, RedisStorageTester);
 
 macro_rules! exec {
     ($glue: ident $sql: literal) => {
@@ -8,15 +8,20 @@ use {
     gluesql_core::{
         data::{Key, Schema, Value},
         error::{Error, Result},
-        store::{Metadata, Planner, RowIter, Store, StoreMut},
+        store::{Metadata, Planner, RowIter, Store, StoreMut, Transaction},
     },
     gluesql_memory_storage::MemoryStorage,
     std::sync::{Arc, RwLock},
 };
 
-#[derive(Clone, Debug)]
+/// A `MemoryStorage` shared by every clone of the handle.
+///
+/// One handle at a time may open a transaction. Until it ends, the other
+/// handles read the last committed data and cannot write.
+#[derive(Debug)]
 pub struct SharedMemoryStorage {
     pub database: Arc<RwLock<MemoryStorage>>,
+    in_transaction: bool,
 }
 
 impl SharedMemoryStorage {
@@ -24,7 +29,54 @@ impl SharedMemoryStorage {
         let database = MemoryStorage::default();
         let database = Arc::new(RwLock::new(database));
 
-        Self { database }
+        Self {
+            database,
+            in_transaction: false,
+        }
+    }
+
+    /// Runs `read` on the data this handle sees: its own transaction, or the
+    /// last commit.
+    fn read<R>(&self, read: impl FnOnce(&MemoryStorage) -> R) -> Result<R> {
+        let database = self.database.read().map_err(lock_error)?;
+        let database = if self.in_transaction {
+            &database
+        } else {
+            database.committed()
+        };
+
+        Ok(read(database))
+    }
+
+    /// Runs `write` unless another handle holds the open transaction.
+    fn write<R>(&self, write: impl FnOnce(&mut MemoryStorage) -> Result<R>) -> Result<R> {
+        let mut database = self.database.write().map_err(lock_error)?;
+        if database.in_transaction() && !self.in_transaction {
+            return Err(locked_error());
+        }
+
+        write(&mut database)
+    }
+}
+
+/// A clone is a new handle, which does not share the transaction.
+impl Clone for SharedMemoryStorage {
+    fn clone(&self) -> Self {
+        Self {
+            database: Arc::clone(&self.database),
+            in_transaction: false,
+        }
+    }
+}
+
+/// Rolls back the transaction a dropped handle left open.
+impl Drop for SharedMemoryStorage {
+    fn drop(&mut self) {
+        if self.in_transaction
+            && let Ok(mut database) = self.database.write()
+        {
+            let _ = database.rollback();
+        }
     }
 }
 
@@ -37,35 +89,30 @@ impl Default for SharedMemoryStorage {
 impl From<MemoryStorage> for SharedMemoryStorage {
     fn from(storage: MemoryStorage) -> Self {
         let database = Arc::new(RwLock::new(storage));
-        Self { database }
+
+        Self {
+            database,
+            in_transaction: false,
+        }
     }
 }
 
 impl Store for SharedMemoryStorage {
     fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
-        let database = self.database.read().map_err(lock_error)?;
-
-        database.fetch_all_schemas()
+        self.read(MemoryStorage::fetch_all_schemas)?
     }
 
     fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
-        let database = self.database.read().map_err(lock_error)?;
-
-        database.fetch_schema(table_name)
+        self.read(|database| database.fetch_schema(table_name))?
     }
 
     fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<Vec<Value>>> {
-        let database = self.database.read().map_err(lock_error)?;
-
-        database.fetch_data(table_name, key)
+        self.read(|database| database.fetch_data(table_name, key))?
     }
 
     fn scan_data<'a>(&'a self, table_name: &str) -> Result<RowIter<'a>> {
         let rows = self
-            .database
-            .read()
-            .map_err(lock_error)?
-            .scan_data(table_name)
+            .read(|database| database.scan_data(table_name))?
             .into_iter()
             .map(Ok);
 
@@ -75,33 +122,23 @@ impl Store for SharedMemoryStorage {
 
 impl StoreMut for SharedMemoryStorage {
     fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
-        let mut database = self.database.write().map_err(lock_error)?;
-
-        database.insert_schema(schema)
+        self.write(|database| database.insert_schema(schema))
     }
 
     fn delete_schema(&mut self, table_name: &str) -> Result<()> {
-        let mut database = self.database.write().map_err(lock_error)?;
-
-        database.delete_schema(table_name)
+        self.write(|database| database.delete_schema(table_name))
     }
 
     fn append_data(&mut self, table_name: &str, rows: Vec<Vec<Value>>) -> Result<()> {
-        let mut database = self.database.write().map_err(lock_error)?;
-
-        database.append_data(table_name, rows)
+        self.write(|database| database.append_data(table_name, rows))
     }
 
     fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, Vec<Value>)>) -> Result<()> {
-        let mut database = self.database.write().map_err(lock_error)?;
-
-        database.insert_data(table_name, rows)
+        self.write(|database| database.insert_data(table_name, rows))
     }
 
     fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
-        let mut database = self.database.write().map_err(lock_error)?;
-
-        database.delete_data(table_name, keys)
+        self.write(|database| database.delete_data(table_name, keys))
     }
 }
 
@@ -109,7 +146,13 @@ impl Metadata for SharedMemoryStorage {}
 impl Planner for SharedMemoryStorage {}
 impl gluesql_core::store::CustomFunction for SharedMemoryStorage {}
 impl gluesql_core::store::CustomFunctionMut for SharedMemoryStorage {}
+impl gluesql_core::store::Trigger for SharedMemoryStorage {}
+impl gluesql_core::store::TriggerMut for SharedMemoryStorage {}
 
 fn lock_error<T>(_: std::sync::PoisonError<T>) -> Error {
     Error::StorageMsg("[Shared MemoryStorage] lock poisoned".to_owned())
 }
+
+fn locked_error() -> Error {
+    Error::StorageMsg("[Shared MemoryStorage] database is locked by another transaction".to_owned())
+}
@@ -1,5 +1,5 @@
 use {
-    super::SharedMemoryStorage,
+    super::{SharedMemoryStorage, lock_error, locked_error},
     gluesql_core::{
         error::{Error, Result},
         store::Transaction,
@@ -12,20 +12,40 @@ impl Transaction for SharedMemoryStorage {
             return Ok(false);
         }
 
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] transaction is not supported".to_owned(),
-        ))
+        if self.in_transaction {
+            return Err(Error::StorageMsg(
+                "[Shared MemoryStorage] nested transaction is not supported".to_owned(),
+            ));
+        }
+
+        let mut database = self.database.write().map_err(lock_error)?;
+        if database.in_transaction() {
+            return Err(locked_error());
+        }
+
+        database.begin(false)?;
+        self.in_transaction = true;
+
+        Ok(false)
     }
 
     fn rollback(&mut self) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] transaction is not supported".to_owned(),
-        ))
+        if !std::mem::take(&mut self.in_transaction) {
+            return Ok(());
+        }
+
+        self.database.write().map_err(lock_error)?.rollback()
     }
 
     fn commit(&mut self) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] transaction is not supported".to_owned(),
-        ))
+        if !std::mem::take(&mut self.in_transaction) {
+            return Ok(());
+        }
+
+        self.database.write().map_err(lock_error)?.commit()
+    }
+
+    fn durable(&self) -> bool {
+        false
     }
 }
@@ -23,6 +23,8 @@ generate_store_tests!(test, SharedMemoryTester);
 
 generate_alter_table_tests!(test, SharedMemoryTester);
 
+generate_transaction_tests!(test, SharedMemoryTester);
+
 macro_rules! exec {
     ($glue: ident $sql: literal) => {
         $glue.execute($sql).unwrap();
@@ -79,15 +81,47 @@ fn shared_memory_storage_index() {
 
 #[test]
 fn shared_memory_storage_transaction() {
-    use gluesql_core::{error::Error, prelude::Glue};
+    use gluesql_core::{
+        error::Error,
+        prelude::{Glue, Payload, Value},
+    };
 
     let storage = SharedMemoryStorage::new();
+
+    let mut other = Glue::new(storage.clone());
     let mut glue = Glue::new(storage);
+    let rows = |ids: &[i64]| {
+        Ok(vec![Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: ids.iter().map(|id| vec![Value::I64(*id)]).collect(),
+        }])
+    };
 
     exec!(glue "CREATE TABLE TxTest (id INTEGER);");
-    test!(glue "BEGIN", Err(Error::StorageMsg("[Shared MemoryStorage] transaction is not supported".to_owned())));
-    test!(glue "COMMIT", Err(Error::StorageMsg("[Shared MemoryStorage] transaction is not supported".to_owned())));
-    test!(glue "ROLLBACK", Err(Error::StorageMsg("[Shared MemoryStorage] transaction is not supported".to_owned())));
+    exec!(glue "BEGIN;");
+    test!(glue "BEGIN", Err(Error::StorageMsg("[Shared MemoryStorage] nested transaction is not supported".to_owned())));
+    exec!(glue "INSERT INTO TxTest VALUES (1);");
+
+    // other handles read the last commit and cannot write or begin
+    test!(glue "SELECT * FROM TxTest", rows(&[1]));
+    test!(other "SELECT * FROM TxTest", rows(&[]));
+    test!(other "INSERT INTO TxTest VALUES (2);", Err(Error::StorageMsg("[Shared MemoryStorage] database is locked by another transaction".to_owned())));
+    test!(other "BEGIN", Err(Error::StorageMsg("[Shared MemoryStorage] database is locked by another transaction".to_owned())));
+
+    exec!(glue "ROLLBACK;");
+    test!(other "SELECT * FROM TxTest", rows(&[]));
+
+    exec!(glue "BEGIN;");
+    exec!(glue "INSERT INTO TxTest VALUES (3);");
+    exec!(glue "COMMIT;");
+    exec!(other "INSERT INTO TxTest VALUES (4);");
+    test!(other "SELECT * FROM TxTest", rows(&[3, 4]));
+
+    // dropping a handle rolls back its open transaction
+    exec!(glue "BEGIN;");
+    exec!(glue "DELETE FROM TxTest;");
+    drop(glue);
+    test!(other "SELECT * FROM TxTest", rows(&[3, 4]));
 }
 
 #[test]
@@ -50,6 +50,7 @@ impl AlterTable for SledStorage {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
                 ..
             } = old_schema
                 .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()).into())
@@ -62,6 +63,7 @@ impl AlterTable for SledStorage {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
             };
 
             bincode::serialize(&old_snapshot)
@@ -159,6 +161,7 @@ impl AlterTable for SledStorage {
                 engine,
                 foreign_keys,
                 comment: schema_comment,
+                ttl_column,
                 ..
             } = snapshot
                 .get(txid, None)
@@ -211,6 +214,7 @@ impl AlterTable for SledStorage {
                 engine,
                 foreign_keys,
                 comment: schema_comment,
+                ttl_column,
             };
             let (snapshot, _) = snapshot.update(txid, schema);
             let value = bincode::serialize(&snapshot)
@@ -264,6 +268,7 @@ impl AlterTable for SledStorage {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
             } = schema_snapshot
                 .get(txid, None)
                 .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()).into())
@@ -352,6 +357,7 @@ impl AlterTable for SledStorage {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
             };
             let (schema_snapshot, _) = schema_snapshot.update(txid, schema);
             let schema_value = bincode::serialize(&schema_snapshot)
@@ -404,6 +410,7 @@ impl AlterTable for SledStorage {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
             } = schema_snapshot
                 .get(txid, None)
                 .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()).into())
@@ -473,6 +480,7 @@ impl AlterTable for SledStorage {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
             };
             let (schema_snapshot, _) = schema_snapshot.update(txid, schema);
             let schema_value = bincode::serialize(&schema_snapshot)
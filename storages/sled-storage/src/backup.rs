@@ -0,0 +1,75 @@
+use {
+    super::{SledStorage, lock},
+    gluesql_core::{
+        data::{CustomFunction as StructCustomFunction, Key, Schema, Value},
+        error::Result,
+        store::{Backup, CustomFunction, RowIter, Store, Trigger},
+    },
+};
+
+/// Point-in-time view of a [`SledStorage`].
+///
+/// The snapshot registers its own txid and fixes the lock holder seen at
+/// creation, so rows written by transactions that commit later stay hidden.
+/// Registered txids hold back garbage collection until the snapshot is
+/// dropped.
+pub struct SledSnapshot {
+    storage: SledStorage,
+    txid: u64,
+    lock_txid: Option<u64>,
+}
+
+impl Backup for SledStorage {
+    type Snapshot = SledSnapshot;
+
+    fn snapshot(&self) -> Result<SledSnapshot> {
+        let (txid, created_at) = lock::register(&self.tree, self.id_offset)?;
+        let lock_txid = lock::fetch(&self.tree, txid, created_at, None)?;
+
+        Ok(SledSnapshot {
+            storage: self.clone(),
+            txid,
+            lock_txid,
+        })
+    }
+}
+
+impl Drop for SledSnapshot {
+    fn drop(&mut self) {
+        let _ = lock::unregister(&self.storage.tree, self.txid);
+    }
+}
+
+impl Store for SledSnapshot {
+    fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        self.storage.read_all_schemas(self.txid, self.lock_txid)
+    }
+
+    fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        self.storage
+            .read_schema(table_name, self.txid, self.lock_txid)
+    }
+
+    fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<Vec<Value>>> {
+        self.storage
+            .read_data(table_name, key, self.txid, self.lock_txid)
+    }
+
+    fn scan_data<'a>(&'a self, table_name: &str) -> Result<RowIter<'a>> {
+        Ok(self
+            .storage
+            .read_rows(table_name, self.txid, self.lock_txid))
+    }
+}
+
+impl Trigger for SledSnapshot {}
+
+impl CustomFunction for SledSnapshot {
+    fn fetch_function<'a>(&'a self, _func_name: &str) -> Result<Option<&'a StructCustomFunction>> {
+        Ok(None)
+    }
+
+    fn fetch_all_functions(&self) -> Result<Vec<&StructCustomFunction>> {
+        Ok(Vec::new())
+    }
+}
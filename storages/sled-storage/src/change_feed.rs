@@ -0,0 +1,199 @@
+use {
+    super::{SledStorage, err_into, fetch_schema, key},
+    gluesql_core::{
+        ast::ColumnUniqueOption,
+        data::{Key, Value},
+        error::{Error, IndexError, Result},
+        store::{Change, ChangeFeed, ChangeIter},
+    },
+    sled::{
+        Batch, IVec,
+        transaction::{
+            ConflictableTransactionError, ConflictableTransactionResult, TransactionalTree,
+        },
+    },
+};
+
+const CHANGE_FEED_KEY: &str = "change_feed";
+
+impl SledStorage {
+    /// Starts or stops recording committed row changes. The setting is stored
+    /// in the database, so it applies to every handle opened on it.
+    pub fn set_change_feed(&self, enabled: bool) -> Result<()> {
+        if enabled {
+            self.tree.insert(CHANGE_FEED_KEY, &[1]).map_err(err_into)?;
+        } else {
+            self.tree.remove(CHANGE_FEED_KEY).map_err(err_into)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn fetch_temp_changes(&self, txid: u64) -> Result<Vec<(IVec, IVec)>> {
+        self.tree
+            .scan_prefix(key::temp_change_prefix(txid))
+            .map(|item| item.map_err(err_into))
+            .collect()
+    }
+}
+
+/// Records the row changes of a transaction next to its data writes. They stay
+/// under `temp_change/{txid}` until [`publish`] moves them into the feed at
+/// commit.
+///
+/// Rows of a table with a primary key are recorded with the key value of that
+/// column, and other rows with the `Key::Bytea` row id that scans return.
+pub(super) struct ChangeSync<'a> {
+    tree: &'a TransactionalTree,
+    txid: u64,
+    table_name: &'a str,
+    enabled: bool,
+    primary_key: Option<usize>,
+}
+
+impl<'a> ChangeSync<'a> {
+    pub(super) fn new(
+        tree: &'a TransactionalTree,
+        txid: u64,
+        table_name: &'a str,
+    ) -> ConflictableTransactionResult<Self, Error> {
+        let enabled = tree.get(CHANGE_FEED_KEY)?.is_some();
+        let primary_key = if enabled {
+            let schema = fetch_schema(tree, table_name)
+                .map(|(_, snapshot)| snapshot)?
+                .and_then(|snapshot| snapshot.extract(txid, None))
+                .ok_or_else(|| IndexError::ConflictTableNotFound(table_name.to_owned()))
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            schema.column_defs.and_then(|column_defs| {
+                column_defs.iter().position(|column_def| {
+                    column_def.unique == Some(ColumnUniqueOption { is_primary: true })
+                })
+            })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            tree,
+            txid,
+            table_name,
+            enabled,
+            primary_key,
+        })
+    }
+
+    pub(super) fn insert(
+        &self,
+        data_key: &IVec,
+        row: &[Value],
+    ) -> ConflictableTransactionResult<(), Error> {
+        self.record(data_key, None, Some(row))
+    }
+
+    pub(super) fn update(
+        &self,
+        data_key: &IVec,
+        old_row: &[Value],
+        new_row: &[Value],
+    ) -> ConflictableTransactionResult<(), Error> {
+        self.record(data_key, Some(old_row), Some(new_row))
+    }
+
+    pub(super) fn delete(
+        &self,
+        data_key: &IVec,
+        old_row: &[Value],
+    ) -> ConflictableTransactionResult<(), Error> {
+        self.record(data_key, Some(old_row), None)
+    }
+
+    fn record(
+        &self,
+        data_key: &IVec,
+        before: Option<&[Value]>,
+        after: Option<&[Value]>,
+    ) -> ConflictableTransactionResult<(), Error> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let primary_key = self
+            .primary_key
+            .and_then(|index| before.or(after)?.get(index));
+        let key = if let Some(value) = primary_key {
+            Key::try_from(value).map_err(ConflictableTransactionError::Abort)?
+        } else {
+            let prefix_len = key::data_prefix(self.table_name).len();
+
+            Key::Bytea(data_key[prefix_len..].to_vec())
+        };
+        let change = Change {
+            seq: 0,
+            txid: self.txid,
+            table_name: self.table_name.to_owned(),
+            key: Some(key),
+            before: before.map(<[Value]>::to_vec),
+            after: after.map(<[Value]>::to_vec),
+        };
+        let change = bincode::serialize(&change)
+            .map_err(err_into)
+            .map_err(ConflictableTransactionError::Abort)?;
+
+        let temp_key = key::temp_change(self.txid, self.tree.generate_id()?);
+        self.tree.insert(temp_key, change)?;
+
+        Ok(())
+    }
+}
+
+/// Moves the recorded changes of a committing transaction into the feed,
+/// numbering them in commit order.
+pub(super) fn publish(
+    tree: &TransactionalTree,
+    temp_changes: &[(IVec, IVec)],
+) -> ConflictableTransactionResult<(), Error> {
+    for (temp_key, change) in temp_changes {
+        let mut change: Change = bincode::deserialize(change)
+            .map_err(err_into)
+            .map_err(ConflictableTransactionError::Abort)?;
+        change.seq = tree.generate_id()?;
+
+        let value = bincode::serialize(&change)
+            .map_err(err_into)
+            .map_err(ConflictableTransactionError::Abort)?;
+
+        tree.remove(temp_key)?;
+        tree.insert(key::change(change.seq), value)?;
+    }
+
+    Ok(())
+}
+
+impl ChangeFeed for SledStorage {
+    fn scan_changes(&self, checkpoint: u64) -> Result<ChangeIter<'_>> {
+        let changes = self
+            .tree
+            .range(key::change(checkpoint.saturating_add(1))..=key::change(u64::MAX))
+            .map(|item| {
+                let (_, value) = item.map_err(err_into)?;
+
+                bincode::deserialize(&value).map_err(err_into)
+            });
+
+        Ok(Box::new(changes))
+    }
+
+    fn purge_changes(&mut self, checkpoint: u64) -> Result<()> {
+        let mut batch = Batch::default();
+
+        for item in self.tree.range(key::change(0)..=key::change(checkpoint)) {
+            let (key, _) = item.map_err(err_into)?;
+
+            batch.remove(key);
+        }
+
+        self.tree.apply_batch(batch).map_err(err_into)
+    }
+}
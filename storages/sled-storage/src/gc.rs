@@ -4,13 +4,59 @@ use {
         lock::{Lock, TxData, get_txdata_key},
     },
     gluesql_core::{
-        data::{Schema, Value},
+        chrono::Utc,
+        data::{Key, Schema, Value},
         error::Result,
+        store::{Store, StoreMut, Transaction},
     },
     std::time::{SystemTime, UNIX_EPOCH},
 };
 
 impl SledStorage {
+    /// Deletes every row past its table's `ttl_column` and returns how many were removed.
+    ///
+    /// The rows are deleted in one transaction, so the `gc` run by its commit
+    /// drops the row snapshots and their index entries from the tree.
+    pub fn purge_expired(&mut self) -> Result<usize> {
+        self.begin(false)?;
+
+        let purged = self.delete_expired();
+        let purged = match purged {
+            Ok(purged) => purged,
+            Err(error) => {
+                self.rollback()?;
+
+                return Err(error);
+            }
+        };
+
+        self.commit().map(|()| purged)
+    }
+
+    fn delete_expired(&mut self) -> Result<usize> {
+        let now = Utc::now().naive_utc();
+        let mut purged = 0;
+
+        for schema in self.fetch_all_schemas()? {
+            let Some(expiry) = schema.row_expiry(now) else {
+                continue;
+            };
+
+            let keys = self
+                .scan_data(&schema.table_name)?
+                .filter_map(|row| match row {
+                    Ok((key, values)) => expiry.is_expired(&values).then_some(Ok(key)),
+                    Err(error) => Some(Err(error)),
+                })
+                .collect::<Result<Vec<Key>>>()?;
+
+            purged += keys.len();
+            self.delete_data(&schema.table_name, keys)?;
+        }
+
+        Ok(purged)
+    }
+
     pub fn gc(&self) -> Result<()> {
         let mut lock: Lock = self
             .tree
@@ -6,7 +6,7 @@ use {
     },
     gluesql_core::{
         ast::IndexOperator,
-        data::{Key, Value},
+        data::{BoundingBox, Key, Value},
         error::{Error, IndexError, Result},
         store::{Index, RowIter},
     },
@@ -25,11 +25,12 @@ impl Index for SledStorage {
     ) -> Result<RowIter<'a>> {
         let data_keys = {
             #[derive(Iterator, DoubleEndedIterator)]
-            enum DataIds<I1, I2, I3, I4> {
+            enum DataIds<I1, I2, I3, I4, I5> {
                 Empty(I1),
                 Once(I2),
                 Range(I3),
                 Full(I4),
+                Filtered(I5),
             }
 
             let map = |item: std::result::Result<_, _>| item.map(|(_, v)| v);
@@ -76,6 +77,33 @@ impl Index for SledStorage {
                         IndexOperator::LtEq => {
                             DataIds::Range(self.tree.range(lower()..=key).map(map))
                         }
+                        IndexOperator::Intersects => {
+                            let Some(area) = value.bounding_box() else {
+                                return Ok(Box::new(empty()));
+                            };
+
+                            // Spatial keys start with the box's `min_x`, so every
+                            // candidate sorts before the first box starting right
+                            // of the searched area.
+                            let start = lower().len() + 1;
+                            let spatial_lower = [lower(), vec![0]].concat();
+                            let max_x = &area.to_cmp_be_bytes()[8..16];
+                            let spatial_upper = incr([spatial_lower.as_slice(), max_x].concat());
+                            let overlaps = move |item: &sled::Result<(IVec, IVec)>| {
+                                item.as_ref().map_or(true, |(key, _)| {
+                                    key.get(start..start + 32)
+                                        .and_then(BoundingBox::from_cmp_be_bytes)
+                                        .is_some_and(|bbox| bbox.intersects(&area))
+                                })
+                            };
+
+                            DataIds::Filtered(
+                                self.tree
+                                    .range(spatial_lower..spatial_upper)
+                                    .filter(overlaps)
+                                    .map(map),
+                            )
+                        }
                     }
                 }
             }
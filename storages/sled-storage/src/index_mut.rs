@@ -67,6 +67,7 @@ impl IndexMut for SledStorage {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
                 ..
             } = schema
                 .ok_or_else(|| IndexError::ConflictTableNotFound(table_name.to_owned()).into())
@@ -99,6 +100,7 @@ impl IndexMut for SledStorage {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
             };
 
             let index_sync = IndexSync::from_schema(tree, txid, &schema);
@@ -157,6 +159,7 @@ impl IndexMut for SledStorage {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
                 ..
             } = schema
                 .ok_or_else(|| IndexError::ConflictTableNotFound(table_name.to_owned()).into())
@@ -181,6 +184,7 @@ impl IndexMut for SledStorage {
                 engine,
                 foreign_keys,
                 comment,
+                ttl_column,
             };
 
             let index_sync = IndexSync::from_schema(tree, txid, &schema);
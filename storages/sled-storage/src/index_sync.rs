@@ -302,8 +302,13 @@ pub fn build_index_key_prefix(table_name: &str, index_name: &str) -> Vec<u8> {
 }
 
 pub fn build_index_key(table_name: &str, index_name: &str, value: &Value) -> Result<Vec<u8>> {
+    let value_bytes = match value.to_spatial_cmp_be_bytes() {
+        Some(bytes) => bytes,
+        None => value.to_cmp_be_bytes()?,
+    };
+
     Ok(build_index_key_prefix(table_name, index_name)
         .into_iter()
-        .chain(value.to_cmp_be_bytes()?)
+        .chain(value_bytes)
         .collect::<Vec<_>>())
 }
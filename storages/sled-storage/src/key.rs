@@ -3,6 +3,8 @@ use sled::IVec;
 const TEMP_DATA: &str = "temp_data/";
 const TEMP_SCHEMA: &str = "temp_schema/";
 const TEMP_INDEX: &str = "temp_index/";
+const TEMP_CHANGE: &str = "temp_change/";
+const CHANGE: &str = "change/";
 
 pub fn data_prefix(table_name: &str) -> String {
     format!("data/{table_name}/")
@@ -36,6 +38,20 @@ pub fn temp_index_prefix(txid: u64) -> IVec {
     prefix!(txid, TEMP_INDEX).collect::<IVec>()
 }
 
+pub fn temp_change_prefix(txid: u64) -> IVec {
+    prefix!(txid, TEMP_CHANGE).collect::<IVec>()
+}
+
+pub fn temp_change(txid: u64, id: u64) -> IVec {
+    prefix!(txid, TEMP_CHANGE)
+        .chain(id.to_be_bytes().iter().copied())
+        .collect::<IVec>()
+}
+
+pub fn change(seq: u64) -> IVec {
+    prefix!(seq, CHANGE).collect::<IVec>()
+}
+
 pub fn temp_data(txid: u64, data_key: &IVec) -> IVec {
     prefix!(txid, TEMP_DATA)
         .chain(data_key.iter().copied())
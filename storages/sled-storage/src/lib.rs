@@ -1,6 +1,8 @@
 #![deny(clippy::str_to_string)]
 
 mod alter_table;
+mod backup;
+mod change_feed;
 mod error;
 mod gc;
 mod index;
@@ -17,6 +19,7 @@ mod store_mut;
 mod transaction;
 
 // re-export
+pub use backup::SledSnapshot;
 pub use migration::{MigrationReport, SLED_STORAGE_FORMAT_VERSION, migrate_to_latest};
 pub use sled;
 
@@ -175,3 +178,5 @@ fn fetch_schema(
 impl Metadata for SledStorage {}
 impl gluesql_core::store::CustomFunction for SledStorage {}
 impl gluesql_core::store::CustomFunctionMut for SledStorage {}
+impl gluesql_core::store::Trigger for SledStorage {}
+impl gluesql_core::store::TriggerMut for SledStorage {}
@@ -1,4 +1,5 @@
 mod v1_to_v2;
+mod v2_to_v3;
 
 use {
     crate::{Snapshot, err_into, open::open_with_lock_wait},
@@ -10,7 +11,7 @@ use {
     std::path::Path,
 };
 
-pub const SLED_STORAGE_FORMAT_VERSION: u32 = 2;
+pub const SLED_STORAGE_FORMAT_VERSION: u32 = 3;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct MigrationReport {
@@ -20,6 +21,7 @@ pub struct MigrationReport {
 }
 
 const V1_SLED_STORAGE_FORMAT_VERSION: u32 = 1;
+const V2_SLED_STORAGE_FORMAT_VERSION: u32 = 2;
 const SCHEMA_PREFIX: &str = "schema/";
 const STORAGE_FORMAT_VERSION_KEY: &str = "__GLUESQL_STORAGE_FORMAT_VERSION__";
 
@@ -27,6 +29,7 @@ const STORAGE_FORMAT_VERSION_KEY: &str = "__GLUESQL_STORAGE_FORMAT_VERSION__";
 enum DetectedStorageFormatVersion {
     V1,
     V2,
+    V3,
     UnsupportedNewer(u32),
     Unsupported(u32),
 }
@@ -36,7 +39,10 @@ pub(super) fn ensure_storage_format_version_supported(tree: &Db) -> Result<()> {
         DetectedStorageFormatVersion::V1 => Err(Error::StorageMsg(format!(
             "[SledStorage] migration required (found v{V1_SLED_STORAGE_FORMAT_VERSION}, expected v{SLED_STORAGE_FORMAT_VERSION}); migrate sled-storage data to the latest format before opening",
         ))),
-        DetectedStorageFormatVersion::V2 => Ok(()),
+        DetectedStorageFormatVersion::V2 => Err(Error::StorageMsg(format!(
+            "[SledStorage] migration required (found v{V2_SLED_STORAGE_FORMAT_VERSION}, expected v{SLED_STORAGE_FORMAT_VERSION}); migrate sled-storage data to the latest format before opening",
+        ))),
+        DetectedStorageFormatVersion::V3 => Ok(()),
         DetectedStorageFormatVersion::UnsupportedNewer(version) => Err(Error::StorageMsg(format!(
             "[SledStorage] unsupported newer format version v{version}"
         ))),
@@ -92,8 +98,9 @@ pub fn migrate_to_latest<P: AsRef<Path>>(path: P) -> Result<MigrationReport> {
 fn migrate_tree_to_latest(tree: &Db) -> Result<MigrationReport> {
     match detect_storage_format_version(tree)? {
         DetectedStorageFormatVersion::V1 => {
-            let migrated_tables = list_alive_table_names(tree)?.len();
             let rewritten_rows = v1_to_v2::migrate_tree(tree)?;
+            v2_to_v3::migrate_tree(tree)?;
+            let migrated_tables = list_alive_table_names(tree)?.len();
 
             write_storage_format_version(tree, SLED_STORAGE_FORMAT_VERSION)?;
 
@@ -104,6 +111,18 @@ fn migrate_tree_to_latest(tree: &Db) -> Result<MigrationReport> {
             })
         }
         DetectedStorageFormatVersion::V2 => {
+            v2_to_v3::migrate_tree(tree)?;
+            let migrated_tables = list_alive_table_names(tree)?.len();
+
+            write_storage_format_version(tree, SLED_STORAGE_FORMAT_VERSION)?;
+
+            Ok(MigrationReport {
+                migrated_tables,
+                unchanged_tables: 0,
+                rewritten_rows: 0,
+            })
+        }
+        DetectedStorageFormatVersion::V3 => {
             let unchanged_tables = list_alive_table_names(tree)?.len();
 
             Ok(MigrationReport {
@@ -133,7 +152,8 @@ fn detect_storage_format_version(tree: &Db) -> Result<DetectedStorageFormatVersi
     let version = parse_storage_format_version(&value)?;
 
     Ok(match version {
-        SLED_STORAGE_FORMAT_VERSION => DetectedStorageFormatVersion::V2,
+        V2_SLED_STORAGE_FORMAT_VERSION => DetectedStorageFormatVersion::V2,
+        SLED_STORAGE_FORMAT_VERSION => DetectedStorageFormatVersion::V3,
         version if version > SLED_STORAGE_FORMAT_VERSION => {
             DetectedStorageFormatVersion::UnsupportedNewer(version)
         }
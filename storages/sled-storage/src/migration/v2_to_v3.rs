@@ -0,0 +1,106 @@
+use {
+    super::SCHEMA_PREFIX,
+    crate::err_into,
+    gluesql_core::{
+        ast::{ColumnDef, ForeignKey},
+        data::{Schema, SchemaIndex},
+        error::{Error, Result},
+    },
+    serde::{Deserialize, Serialize},
+    sled::{Db, IVec},
+};
+
+/// `Schema` as serialized by storage format v2, before `ttl_column` was added.
+#[derive(Debug, Clone, Deserialize)]
+struct V2Schema {
+    table_name: String,
+    column_defs: Option<Vec<ColumnDef>>,
+    indexes: Vec<SchemaIndex>,
+    engine: Option<String>,
+    foreign_keys: Vec<ForeignKey>,
+    comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SnapshotItem<T> {
+    data: T,
+    created_by: u64,
+    deleted_by: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Snapshot<T>(Vec<SnapshotItem<T>>);
+
+pub fn migrate_tree(tree: &Db) -> Result<usize> {
+    let rewritten_schemas = tree
+        .scan_prefix(SCHEMA_PREFIX)
+        .map(|item| {
+            let (schema_key, schema_value) = item.map_err(err_into)?;
+            let snapshot = bincode::deserialize::<Snapshot<V2Schema>>(&schema_value)
+                .map_err(|parse_err| {
+                    Error::StorageMsg(format!(
+                        "[SledStorage] failed to parse v2 schema snapshot during migration: {parse_err}",
+                    ))
+                })?;
+            let snapshot = bincode::serialize(&snapshot.map_data(Schema::from)).map_err(err_into)?;
+
+            Ok((schema_key, snapshot))
+        })
+        .collect::<Result<Vec<(IVec, Vec<u8>)>>>()?;
+    let rewritten_schemas_len = rewritten_schemas.len();
+
+    for (schema_key, snapshot) in rewritten_schemas {
+        tree.insert(schema_key, snapshot).map_err(err_into)?;
+    }
+
+    if rewritten_schemas_len > 0 {
+        tree.flush().map_err(err_into)?;
+    }
+
+    Ok(rewritten_schemas_len)
+}
+
+impl From<V2Schema> for Schema {
+    fn from(schema: V2Schema) -> Self {
+        let V2Schema {
+            table_name,
+            column_defs,
+            indexes,
+            engine,
+            foreign_keys,
+            comment,
+        } = schema;
+
+        Schema {
+            table_name,
+            column_defs,
+            indexes,
+            engine,
+            foreign_keys,
+            comment,
+            ttl_column: None,
+        }
+    }
+}
+
+impl<T> Snapshot<T> {
+    fn map_data<U>(self, mapper: impl Fn(T) -> U) -> Snapshot<U> {
+        let items = self
+            .0
+            .into_iter()
+            .map(
+                |SnapshotItem {
+                     data,
+                     created_by,
+                     deleted_by,
+                 }| SnapshotItem {
+                    data: mapper(data),
+                    created_by,
+                    deleted_by,
+                },
+            )
+            .collect();
+
+        Snapshot(items)
+    }
+}
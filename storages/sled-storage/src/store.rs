@@ -10,18 +10,12 @@ use {
 
 impl SledStorage {
     const SCHEMA_PREFIX: &'static str = "schema/";
-}
-
-impl Store for SledStorage {
-    fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
-        let (txid, created_at) = match self.state {
-            State::Transaction {
-                txid, created_at, ..
-            } => (txid, created_at),
-            State::Idle => lock::register(&self.tree, self.id_offset)?,
-        };
-        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
 
+    pub(crate) fn read_all_schemas(
+        &self,
+        txid: u64,
+        lock_txid: Option<u64>,
+    ) -> Result<Vec<Schema>> {
         self.tree
             .scan_prefix(SledStorage::SCHEMA_PREFIX)
             .map(move |item| {
@@ -35,16 +29,12 @@ impl Store for SledStorage {
             .collect::<Result<Vec<_>>>()
     }
 
-    fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
-        let (txid, created_at, temp) = match self.state {
-            State::Transaction {
-                txid, created_at, ..
-            } => (txid, created_at, false),
-            State::Idle => lock::register(&self.tree, self.id_offset)
-                .map(|(txid, created_at)| (txid, created_at, true))?,
-        };
-        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
-
+    pub(crate) fn read_schema(
+        &self,
+        table_name: &str,
+        txid: u64,
+        lock_txid: Option<u64>,
+    ) -> Result<Option<Schema>> {
         let key = format!("schema/{table_name}");
         let schema = self
             .tree
@@ -55,26 +45,16 @@ impl Store for SledStorage {
             .map_err(err_into)?
             .and_then(|snapshot: Snapshot<Schema>| snapshot.extract(txid, lock_txid));
 
-        if temp {
-            lock::unregister(&self.tree, txid)?;
-        }
-
         Ok(schema)
     }
 
-    fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<Vec<Value>>> {
-        let (txid, created_at) = match self.state {
-            State::Transaction {
-                txid, created_at, ..
-            } => (txid, created_at),
-            State::Idle => {
-                return Err(Error::StorageMsg(
-                    "conflict - fetch_data failed, lock does not exist".to_owned(),
-                ));
-            }
-        };
-        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
-
+    pub(crate) fn read_data(
+        &self,
+        table_name: &str,
+        key: &Key,
+        txid: u64,
+        lock_txid: Option<u64>,
+    ) -> Result<Option<Vec<Value>>> {
         let key = key
             .to_cmp_be_bytes()
             .map(|key| key::data(table_name, key))?;
@@ -90,19 +70,12 @@ impl Store for SledStorage {
         Ok(row)
     }
 
-    fn scan_data<'a>(&'a self, table_name: &str) -> Result<RowIter<'a>> {
-        let (txid, created_at) = match self.state {
-            State::Transaction {
-                txid, created_at, ..
-            } => (txid, created_at),
-            State::Idle => {
-                return Err(Error::StorageMsg(
-                    "conflict - scan_data failed, lock does not exist".to_owned(),
-                ));
-            }
-        };
-        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
-
+    pub(crate) fn read_rows(
+        &self,
+        table_name: &str,
+        txid: u64,
+        lock_txid: Option<u64>,
+    ) -> RowIter<'static> {
         let prefix = key::data_prefix(table_name);
         let prefix_len = prefix.len();
         let result_set = self
@@ -120,6 +93,70 @@ impl Store for SledStorage {
             })
             .filter_map(Result::transpose);
 
-        Ok(Box::new(result_set))
+        Box::new(result_set)
+    }
+}
+
+impl Store for SledStorage {
+    fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let (txid, created_at) = match self.state {
+            State::Transaction {
+                txid, created_at, ..
+            } => (txid, created_at),
+            State::Idle => lock::register(&self.tree, self.id_offset)?,
+        };
+        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+
+        self.read_all_schemas(txid, lock_txid)
+    }
+
+    fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let (txid, created_at, temp) = match self.state {
+            State::Transaction {
+                txid, created_at, ..
+            } => (txid, created_at, false),
+            State::Idle => lock::register(&self.tree, self.id_offset)
+                .map(|(txid, created_at)| (txid, created_at, true))?,
+        };
+        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+        let schema = self.read_schema(table_name, txid, lock_txid)?;
+
+        if temp {
+            lock::unregister(&self.tree, txid)?;
+        }
+
+        Ok(schema)
+    }
+
+    fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<Vec<Value>>> {
+        let (txid, created_at) = match self.state {
+            State::Transaction {
+                txid, created_at, ..
+            } => (txid, created_at),
+            State::Idle => {
+                return Err(Error::StorageMsg(
+                    "conflict - fetch_data failed, lock does not exist".to_owned(),
+                ));
+            }
+        };
+        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+
+        self.read_data(table_name, key, txid, lock_txid)
+    }
+
+    fn scan_data<'a>(&'a self, table_name: &str) -> Result<RowIter<'a>> {
+        let (txid, created_at) = match self.state {
+            State::Transaction {
+                txid, created_at, ..
+            } => (txid, created_at),
+            State::Idle => {
+                return Err(Error::StorageMsg(
+                    "conflict - scan_data failed, lock does not exist".to_owned(),
+                ));
+            }
+        };
+        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+
+        Ok(self.read_rows(table_name, txid, lock_txid))
     }
 }
@@ -1,6 +1,8 @@
 use {
     super::{
-        SledStorage, Snapshot, err_into,
+        SledStorage, Snapshot,
+        change_feed::ChangeSync,
+        err_into,
         index_sync::IndexSync,
         key,
         lock::{self, LockAcquired},
@@ -159,6 +161,7 @@ impl StoreMut for SledStorage {
             };
 
             let index_sync = IndexSync::new(tree, txid, table_name)?;
+            let change_sync = ChangeSync::new(tree, txid, table_name)?;
 
             for row in tx_rows {
                 let id = id_offset + tree.generate_id()?;
@@ -166,6 +169,7 @@ impl StoreMut for SledStorage {
                 let key = key::data(table_name, id.to_vec());
 
                 index_sync.insert(&key, row)?;
+                change_sync.insert(&key, row)?;
 
                 let snapshot = Snapshot::new(txid, row.clone());
                 let snapshot = bincode::serialize(&snapshot)
@@ -210,6 +214,7 @@ impl StoreMut for SledStorage {
             };
 
             let index_sync = IndexSync::new(tree, txid, table_name)?;
+            let change_sync = ChangeSync::new(tree, txid, table_name)?;
 
             for (key, new_row) in tx_rows {
                 let key = key
@@ -222,16 +227,22 @@ impl StoreMut for SledStorage {
                         .map_err(err_into)
                         .map_err(ConflictableTransactionError::Abort)?;
 
+                    let visible_row = snapshot.get(txid, Some(txid));
                     let (snapshot, old_row) = snapshot.update(txid, new_row.clone());
                     let Some(old_row) = old_row else {
                         continue;
                     };
 
                     index_sync.update(&key, &old_row, new_row)?;
+                    match visible_row {
+                        Some(visible_row) => change_sync.update(&key, &visible_row, new_row)?,
+                        None => change_sync.insert(&key, new_row)?,
+                    }
 
                     snapshot
                 } else {
                     index_sync.insert(&key, new_row)?;
+                    change_sync.insert(&key, new_row)?;
 
                     Snapshot::new(txid, new_row.clone())
                 };
@@ -278,6 +289,7 @@ impl StoreMut for SledStorage {
             };
 
             let index_sync = IndexSync::new(tree, txid, table_name)?;
+            let change_sync = ChangeSync::new(tree, txid, table_name)?;
 
             for key in tx_keys {
                 let key = key
@@ -304,6 +316,7 @@ impl StoreMut for SledStorage {
                     .map(|snapshot| tree.insert(&key, snapshot))??;
 
                 index_sync.delete(&key, &row)?;
+                change_sync.delete(&key, &row)?;
 
                 if !autocommit {
                     let temp_key = key::temp_data(txid, &key);
@@ -1,6 +1,6 @@
 use {
     super::{
-        SledStorage, Snapshot, State, err_into, key,
+        SledStorage, Snapshot, State, change_feed, err_into, key,
         lock::{self, Lock},
         tx_err_into,
     },
@@ -85,6 +85,19 @@ impl Transaction for SledStorage {
         Ok(())
     }
 
+    fn prepare(&mut self) -> Result<()> {
+        let (txid, created_at) = match self.state {
+            State::Transaction {
+                txid, created_at, ..
+            } => (txid, created_at),
+            State::Idle => {
+                return Err(Error::StorageMsg("no transaction to prepare".to_owned()));
+            }
+        };
+
+        lock::fetch(&self.tree, txid, created_at, self.tx_timeout).map(|_| ())
+    }
+
     fn commit(&mut self) -> Result<()> {
         let (txid, created_at) = match self.state {
             State::Transaction {
@@ -97,8 +110,13 @@ impl Transaction for SledStorage {
 
         lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
 
+        let temp_changes = self.fetch_temp_changes(txid)?;
+
         self.tree
-            .transaction(move |tree| lock::release(tree, txid))
+            .transaction(move |tree| {
+                change_feed::publish(tree, &temp_changes)?;
+                lock::release(tree, txid)
+            })
             .map_err(tx_err_into)?;
 
         self.state = State::Idle;
@@ -168,12 +186,17 @@ impl SledStorage {
         let data_items = fetch_items(key::temp_data_prefix(txid))?;
         let schema_items = fetch_items(key::temp_schema_prefix(txid))?;
         let index_items = fetch_items(key::temp_index_prefix(txid))?;
+        let change_items = fetch_items(key::temp_change_prefix(txid))?;
 
         self.tree
             .transaction(move |tree| {
                 rollback_items::<Vec<Value>>(tree, txid, &data_items)?;
                 rollback_items::<Schema>(tree, txid, &schema_items)?;
 
+                for (temp_key, _) in &change_items {
+                    tree.remove(temp_key)?;
+                }
+
                 for (temp_key, value_key) in &index_items {
                     tree.remove(temp_key)?;
 
@@ -0,0 +1,130 @@
+use {
+    gluesql_core::{
+        data::Key,
+        prelude::{Glue, Value::*},
+        store::{Change, ChangeFeed, ChangeKind, Store},
+    },
+    gluesql_sled_storage::SledStorage,
+    std::fs,
+};
+
+fn changes(storage: &SledStorage, checkpoint: u64) -> Vec<Change> {
+    storage
+        .scan_changes(checkpoint)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap()
+}
+
+#[test]
+fn sled_change_feed() {
+    let path = "tmp/sled_change_feed";
+    fs::remove_dir_all(path).unwrap_or(());
+
+    let storage = SledStorage::new(path).unwrap();
+    let mut glue = Glue::new(storage.clone());
+
+    glue.execute(
+        "
+        CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT);
+        INSERT INTO Item VALUES (1, 'ignored');
+        DELETE FROM Item;
+        ",
+    )
+    .unwrap();
+    assert_eq!(changes(&storage, 0), Vec::new());
+
+    storage.set_change_feed(true).unwrap();
+
+    glue.execute(
+        "
+        BEGIN;
+        INSERT INTO Item VALUES (1, 'apple'), (2, 'banana');
+        ROLLBACK;
+
+        BEGIN;
+        INSERT INTO Item VALUES (1, 'apple'), (2, 'banana');
+        UPDATE Item SET name = 'cherry' WHERE id = 2;
+        COMMIT;
+
+        DELETE FROM Item WHERE id = 1;
+        ",
+    )
+    .unwrap();
+
+    let feed = changes(&storage, 0);
+    let (one, two) = (Key::I64(1), Key::I64(2));
+    assert_eq!(
+        feed.iter()
+            .map(|change| (change.kind(), change.key.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (ChangeKind::Insert, Some(one.clone())),
+            (ChangeKind::Insert, Some(two.clone())),
+            (ChangeKind::Update, Some(two)),
+            (ChangeKind::Delete, Some(one)),
+        ]
+    );
+    assert_eq!(feed[0].txid, feed[2].txid);
+    assert!(feed[3].txid > feed[2].txid);
+    assert!(feed.windows(2).all(|w| w[0].seq < w[1].seq));
+    assert_eq!(
+        (&feed[2].before, &feed[2].after),
+        (
+            &Some(vec![I64(2), Str("banana".to_owned())]),
+            &Some(vec![I64(2), Str("cherry".to_owned())])
+        )
+    );
+    assert_eq!(feed[3].after, None);
+
+    assert_eq!(changes(&storage, feed[1].seq), feed[2..].to_vec());
+
+    drop(glue);
+    drop(storage);
+    let mut storage = SledStorage::new(path).unwrap();
+    assert_eq!(changes(&storage, feed[1].seq), feed[2..].to_vec());
+
+    storage.purge_changes(feed[2].seq).unwrap();
+    assert_eq!(changes(&storage, 0), feed[3..].to_vec());
+
+    storage.set_change_feed(false).unwrap();
+    let mut glue = Glue::new(storage.clone());
+    glue.execute("DELETE FROM Item").unwrap();
+    assert_eq!(changes(&storage, 0), feed[3..].to_vec());
+}
+
+#[test]
+fn sled_change_feed_without_primary_key() {
+    let path = "tmp/sled_change_feed_without_primary_key";
+    fs::remove_dir_all(path).unwrap_or(());
+
+    let storage = SledStorage::new(path).unwrap();
+    storage.set_change_feed(true).unwrap();
+
+    let mut glue = Glue::new(storage.clone());
+    glue.execute("CREATE TABLE Log (id INTEGER); INSERT INTO Log VALUES (1);")
+        .unwrap();
+
+    glue.execute("BEGIN;").unwrap();
+    let (key, _) = glue
+        .storage
+        .scan_data("Log")
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    assert!(matches!(key, Key::Bytea(_)));
+    glue.execute("COMMIT;").unwrap();
+
+    glue.execute("DELETE FROM Log;").unwrap();
+    assert_eq!(
+        changes(&storage, 0)
+            .iter()
+            .map(|change| (change.kind(), change.key.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (ChangeKind::Insert, Some(key.clone())),
+            (ChangeKind::Delete, Some(key)),
+        ]
+    );
+}
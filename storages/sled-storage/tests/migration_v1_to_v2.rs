@@ -1,7 +1,7 @@
 use {
     gluesql_core::{
-        ast::ColumnDef,
-        data::{Key, Schema, Value},
+        ast::{ColumnDef, ForeignKey},
+        data::{Key, SchemaIndex, Value},
         error::Error,
         prelude::DataType,
     },
@@ -49,6 +49,17 @@ enum V1DataRow {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct V1VecDataRow(Vec<Value>);
 
+/// Table schema as serialized before storage format v3 added `ttl_column`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct V2Schema {
+    table_name: String,
+    column_defs: Option<Vec<ColumnDef>>,
+    indexes: Vec<SchemaIndex>,
+    engine: Option<String>,
+    foreign_keys: Vec<ForeignKey>,
+    comment: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct V2SnapshotItem<T> {
     data: T,
@@ -95,7 +106,7 @@ fn open_migrated_sled(path: &Path) -> Db {
         .tree
 }
 
-fn write_schema_snapshot(tree: &Db, schema: Schema) {
+fn write_schema_snapshot(tree: &Db, schema: V2Schema) {
     let schema_key = format!("schema/{}", schema.table_name);
     let snapshot = V1Snapshot::new(1, schema);
     let value = bincode::serialize(&snapshot).expect("serialize schema snapshot");
@@ -132,7 +143,7 @@ fn setup_v1_storage(path: &Path) -> BTreeMap<String, Value> {
     let _ = remove_dir_all(path);
     let tree = create_sled(path);
 
-    let user_schema = Schema {
+    let user_schema = V2Schema {
         table_name: "User".to_owned(),
         column_defs: Some(vec![
             ColumnDef {
@@ -159,7 +170,7 @@ fn setup_v1_storage(path: &Path) -> BTreeMap<String, Value> {
     };
     write_schema_snapshot(&tree, user_schema);
 
-    let logs_schema = Schema {
+    let logs_schema = V2Schema {
         table_name: "Logs".to_owned(),
         column_defs: None,
         indexes: Vec::new(),
@@ -222,7 +233,7 @@ fn opening_v1_storage_requires_migration() {
 
     let actual = SledStorage::new(&path).map(|_| ());
     let expected = Err(Error::StorageMsg(
-        "[SledStorage] migration required (found v1, expected v2); migrate sled-storage data to the latest format before opening".to_owned(),
+        "[SledStorage] migration required (found v1, expected v3); migrate sled-storage data to the latest format before opening".to_owned(),
     ));
     assert_eq!(actual, expected);
 
@@ -287,7 +298,7 @@ fn migrate_rejects_unsupported_newer_version() {
 
     let actual = migrate_to_latest(&path);
     let expected = Err(Error::StorageMsg(
-        "[SledStorage] unsupported newer format version v3".to_owned(),
+        "[SledStorage] unsupported newer format version v4".to_owned(),
     ));
     assert_eq!(actual, expected);
 
@@ -328,7 +339,7 @@ fn opening_storage_rejects_unsupported_versions() {
     write_storage_format_version(&path, &(SLED_STORAGE_FORMAT_VERSION + 1).to_be_bytes());
     let actual = SledStorage::new(&path).map(|_| ());
     let expected = Err(Error::StorageMsg(
-        "[SledStorage] unsupported newer format version v3".to_owned(),
+        "[SledStorage] unsupported newer format version v4".to_owned(),
     ));
     assert_eq!(actual, expected);
 
@@ -387,7 +398,7 @@ fn migrate_v1_to_v2_supports_all_v1_snapshot_shapes() {
 
     write_schema_snapshot(
         &tree,
-        Schema {
+        V2Schema {
             table_name: "Mixed".to_owned(),
             column_defs: None,
             indexes: Vec::new(),
@@ -498,7 +509,7 @@ fn migrate_v1_storage_without_data_rows() {
 
     write_schema_snapshot(
         &tree,
-        Schema {
+        V2Schema {
             table_name: "OnlySchema".to_owned(),
             column_defs: Some(vec![ColumnDef {
                 name: "id".to_owned(),
@@ -543,7 +554,7 @@ fn migrate_rejects_invalid_v1_row_snapshot_payload() {
     let tree = create_sled(&path);
     write_schema_snapshot(
         &tree,
-        Schema {
+        V2Schema {
             table_name: "Broken".to_owned(),
             column_defs: None,
             indexes: Vec::new(),
@@ -0,0 +1,156 @@
+use {
+    gluesql_core::{
+        ast::{ColumnDef, ForeignKey},
+        data::{Key, SchemaIndex, Value},
+        error::Error,
+        prelude::{DataType, Glue, Payload},
+    },
+    gluesql_sled_storage::{MigrationReport, SledStorage, migrate_to_latest},
+    serde::Serialize,
+    std::{
+        fs::{create_dir_all, remove_dir_all},
+        path::PathBuf,
+        time::{SystemTime, UNIX_EPOCH},
+    },
+};
+
+const STORAGE_FORMAT_VERSION_KEY: &str = "__GLUESQL_STORAGE_FORMAT_VERSION__";
+const V2_FORMAT_VERSION: u32 = 2;
+
+/// Table schema as serialized before storage format v3 added `ttl_column`.
+#[derive(Serialize)]
+struct V2Schema {
+    table_name: String,
+    column_defs: Option<Vec<ColumnDef>>,
+    indexes: Vec<SchemaIndex>,
+    engine: Option<String>,
+    foreign_keys: Vec<ForeignKey>,
+    comment: Option<String>,
+}
+
+#[derive(Serialize)]
+struct V2SnapshotItem<T> {
+    data: T,
+    created_by: u64,
+    deleted_by: Option<u64>,
+}
+
+fn test_path(name: &str) -> PathBuf {
+    let suffix = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time")
+        .as_nanos();
+
+    let path = PathBuf::from("./tmp");
+    create_dir_all(&path).expect("create tmp directory");
+
+    path.join(format!("{name}-{suffix}"))
+}
+
+fn snapshot<T>(data: T) -> Vec<V2SnapshotItem<T>> {
+    vec![V2SnapshotItem {
+        data,
+        created_by: 1,
+        deleted_by: None,
+    }]
+}
+
+fn setup_v2_storage(path: &PathBuf) {
+    let tree = sled::Config::new()
+        .path(path)
+        .flush_every_ms(None)
+        .open()
+        .expect("create sled");
+
+    let schema = V2Schema {
+        table_name: "Foo".to_owned(),
+        column_defs: Some(vec![ColumnDef {
+            name: "id".to_owned(),
+            data_type: DataType::Int,
+            nullable: false,
+            default: None,
+            unique: None,
+            comment: None,
+        }]),
+        indexes: Vec::new(),
+        engine: None,
+        foreign_keys: Vec::new(),
+        comment: Some("legacy".to_owned()),
+    };
+    let value = bincode::serialize(&snapshot(schema)).expect("serialize schema snapshot");
+    tree.insert("schema/Foo", value)
+        .expect("insert schema snapshot");
+
+    let data_key = "data/Foo/"
+        .bytes()
+        .chain(Key::I64(1).to_cmp_be_bytes().expect("key bytes"))
+        .collect::<Vec<_>>();
+    let value = bincode::serialize(&snapshot(vec![Value::I32(1)])).expect("serialize row");
+    tree.insert(data_key, value).expect("insert row snapshot");
+
+    tree.insert(STORAGE_FORMAT_VERSION_KEY, &V2_FORMAT_VERSION.to_be_bytes())
+        .expect("insert version");
+    tree.flush().expect("flush sled");
+}
+
+#[test]
+fn opening_v2_storage_requires_migration() {
+    let path = test_path("sled-v2-requires-migration");
+    setup_v2_storage(&path);
+
+    let actual = SledStorage::new(&path).map(|_| ());
+    let expected = Err(Error::StorageMsg(
+        "[SledStorage] migration required (found v2, expected v3); migrate sled-storage data to the latest format before opening".to_owned(),
+    ));
+    assert_eq!(actual, expected);
+
+    remove_dir_all(path).expect("cleanup");
+}
+
+#[test]
+fn migrate_v2_to_v3_rewrites_schemas() {
+    let path = test_path("sled-migration-v2-to-v3");
+    setup_v2_storage(&path);
+
+    let report = migrate_to_latest(&path).expect("migrate v2 to v3");
+    assert_eq!(
+        report,
+        MigrationReport {
+            migrated_tables: 1,
+            unchanged_tables: 0,
+            rewritten_rows: 0,
+        }
+    );
+
+    let report = migrate_to_latest(&path).expect("idempotent migration");
+    assert_eq!(
+        report,
+        MigrationReport {
+            migrated_tables: 0,
+            unchanged_tables: 1,
+            rewritten_rows: 0,
+        }
+    );
+
+    let storage = SledStorage::new(&path).expect("open migrated storage");
+    let mut glue = Glue::new(storage);
+
+    let actual = glue
+        .execute("SELECT id FROM Foo")
+        .expect("select migrated rows");
+    assert_eq!(
+        actual,
+        vec![Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![vec![Value::I32(1)]],
+        }],
+    );
+
+    glue.execute(
+        "CREATE TABLE Session (id INTEGER, expires_at TIMESTAMP) WITH (ttl_column = expires_at)",
+    )
+    .expect("create ttl table after migration");
+
+    drop(glue);
+    remove_dir_all(path).expect("cleanup");
+}
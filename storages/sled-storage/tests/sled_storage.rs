@@ -90,3 +90,42 @@ generate_alter_table_index_tests!(test, SledTester);
 generate_transaction_alter_table_tests!(test, SledTester);
 generate_transaction_index_tests!(test, SledTester);
 generate_metadata_index_tests!(test, SledTester);
+generate_ttl_tests!(test, SledTester);
+
+#[test]
+fn sled_purge_expired() {
+    use gluesql_core::prelude::*;
+
+    let mut tester = SledTester::new("purge_expired");
+    let glue = tester.get_glue();
+
+    glue.execute(
+        "CREATE TABLE Session (id INTEGER PRIMARY KEY, expires_at TIMESTAMP NULL)
+         WITH (ttl_column = expires_at);",
+    )
+    .unwrap();
+    glue.execute("CREATE INDEX idx_expires ON Session (expires_at);")
+        .unwrap();
+    glue.execute(
+        "INSERT INTO Session VALUES
+            (1, '2000-01-01 00:00:00'),
+            (2, '2999-01-01 00:00:00'),
+            (3, NULL);",
+    )
+    .unwrap();
+
+    let stored_rows = |storage: &SledStorage| storage.tree.scan_prefix("data/Session/").count();
+    assert_eq!(stored_rows(&glue.storage), 3);
+
+    assert_eq!(glue.storage.purge_expired(), Ok(1));
+    assert_eq!(stored_rows(&glue.storage), 2);
+    assert_eq!(glue.storage.purge_expired(), Ok(0));
+
+    assert_eq!(
+        glue.execute("SELECT id FROM Session;"),
+        Ok(vec![Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![vec![Value::I64(2)], vec![Value::I64(3)]],
+        }])
+    );
+}
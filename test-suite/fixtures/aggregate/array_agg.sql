@@ -0,0 +1,54 @@
+CREATE TABLE Employee (
+    id INTEGER,
+    dept TEXT,
+    name TEXT,
+    salary INTEGER NULL,
+    remote BOOLEAN
+);
+-- @expect: ok
+
+INSERT INTO Employee (id, dept, name, salary, remote) VALUES
+    (1, 'eng', 'Alice',  100,  TRUE),
+    (2, 'eng',   'Bob',  200, FALSE),
+    (3, 'eng', 'Carol',  200,  TRUE),
+    (4, 'ops',  'Dave', NULL,  TRUE),
+    (5, 'ops',  'Erin',   50,  TRUE),
+    (6,  'hr', 'Frank', NULL, FALSE);
+-- @expect: ok
+
+-- @name: ARRAY_AGG keeps NULL values
+SELECT ARRAY_AGG(salary ORDER BY id) AS salaries FROM Employee
+-- @expect:
+-- | salaries: List             |
+-- | -------------------------- |
+-- | [100,200,200,null,50,null] |
+
+-- @name: ARRAY_AGG per group
+SELECT dept, ARRAY_AGG(name ORDER BY id DESC) AS names FROM Employee GROUP BY dept
+-- @expect:
+-- | dept: Str | names: List             |
+-- | --------- | ----------------------- |
+-- | "eng"     | ["Carol","Bob","Alice"] |
+-- | "ops"     | ["Erin","Dave"]         |
+-- | "hr"      | ["Frank"]               |
+
+-- @name: ARRAY_AGG with DISTINCT
+SELECT ARRAY_AGG(DISTINCT dept ORDER BY dept) AS depts FROM Employee
+-- @expect:
+-- | depts: List        |
+-- | ------------------ |
+-- | ["eng","hr","ops"] |
+
+-- @name: ARRAY_AGG with FILTER
+SELECT ARRAY_AGG(name) FILTER (WHERE salary > 100) AS names FROM Employee
+-- @expect:
+-- | names: List     |
+-- | --------------- |
+-- | ["Bob","Carol"] |
+
+-- @name: ARRAY_AGG of no rows is NULL
+SELECT ARRAY_AGG(name) FILTER (WHERE id > 100) AS names FROM Employee
+-- @expect:
+-- | names |
+-- | ----- |
+-- | NULL  |
@@ -0,0 +1,50 @@
+CREATE TABLE Employee (
+    id INTEGER,
+    dept TEXT,
+    name TEXT,
+    salary INTEGER NULL,
+    remote BOOLEAN
+);
+-- @expect: ok
+
+INSERT INTO Employee (id, dept, name, salary, remote) VALUES
+    (1, 'eng', 'Alice',  100,  TRUE),
+    (2, 'eng',   'Bob',  200, FALSE),
+    (3, 'eng', 'Carol',  200,  TRUE),
+    (4, 'ops',  'Dave', NULL,  TRUE),
+    (5, 'ops',  'Erin',   50,  TRUE),
+    (6,  'hr', 'Frank', NULL, FALSE);
+-- @expect: ok
+
+-- @name: BOOL_AND and BOOL_OR
+SELECT BOOL_AND(remote) AS every, BOOL_OR(remote) AS some FROM Employee
+-- @expect:
+-- | every: Bool | some: Bool |
+-- | ----------- | ---------- |
+-- | false       | true       |
+
+-- @name: BOOL_AND and BOOL_OR per group
+SELECT dept, BOOL_AND(remote) AS every, BOOL_OR(remote) AS some FROM Employee GROUP BY dept
+-- @expect:
+-- | dept: Str | every: Bool | some: Bool |
+-- | --------- | ----------- | ---------- |
+-- | "eng"     | false       | true       |
+-- | "ops"     | true        | true       |
+-- | "hr"      | false       | false      |
+
+-- @name: BOOL_AND with FILTER
+SELECT BOOL_AND(remote) FILTER (WHERE dept = 'ops') AS every FROM Employee
+-- @expect:
+-- | every: Bool |
+-- | ----------- |
+-- | true        |
+
+-- @name: BOOL_AND requires boolean values
+SELECT BOOL_AND(id) FROM Employee;
+-- @expect: error Query.AggregateRequiresBooleanValue
+-- @json: "BOOL_AND"
+
+-- @name: BOOL_OR requires boolean values
+SELECT BOOL_OR(name) FROM Employee;
+-- @expect: error Query.AggregateRequiresBooleanValue
+-- @json: "BOOL_OR"
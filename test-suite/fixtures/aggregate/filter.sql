@@ -0,0 +1,52 @@
+CREATE TABLE Employee (
+    id INTEGER,
+    dept TEXT,
+    name TEXT,
+    salary INTEGER NULL,
+    remote BOOLEAN
+);
+-- @expect: ok
+
+INSERT INTO Employee (id, dept, name, salary, remote) VALUES
+    (1, 'eng', 'Alice',  100,  TRUE),
+    (2, 'eng',   'Bob',  200, FALSE),
+    (3, 'eng', 'Carol',  200,  TRUE),
+    (4, 'ops',  'Dave', NULL,  TRUE),
+    (5, 'ops',  'Erin',   50,  TRUE),
+    (6,  'hr', 'Frank', NULL, FALSE);
+-- @expect: ok
+
+-- @name: FILTER restricts the rows an aggregate sees
+SELECT COUNT(*) FILTER (WHERE remote) AS remote, COUNT(*) AS total FROM Employee
+-- @expect:
+-- | remote: I64 | total: I64 |
+-- | ----------- | ---------- |
+-- | 4           | 6          |
+
+-- @name: FILTER per group
+SELECT dept, SUM(salary) FILTER (WHERE remote) AS remote_salary FROM Employee GROUP BY dept
+-- @expect:
+-- | dept: Str | remote_salary: I64 |
+-- | --------- | ------------------ |
+-- | "eng"     | 300                |
+-- | "ops"     | 50                 |
+-- | "hr"      | NULL               |
+
+-- @name: FILTER with DISTINCT
+SELECT COUNT(DISTINCT salary) FILTER (WHERE remote) AS salaries FROM Employee
+-- @expect:
+-- | salaries: I64 |
+-- | ------------- |
+-- | 3             |
+
+-- @name: the same aggregate with different FILTERs
+SELECT SUM(salary) FILTER (WHERE dept = 'eng') AS eng, SUM(salary) FILTER (WHERE dept = 'ops') AS ops FROM Employee
+-- @expect:
+-- | eng: I64 | ops: I64 |
+-- | -------- | -------- |
+-- | 500      | 50       |
+
+-- @name: FILTER is rejected on non-aggregate functions
+SELECT LOWER(name) FILTER (WHERE id > 1) FROM Employee;
+-- @expect: error Translate.UnsupportedFunctionFilter
+-- @json: "LOWER"
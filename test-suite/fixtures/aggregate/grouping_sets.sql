@@ -0,0 +1,95 @@
+CREATE TABLE Sales (
+    id INTEGER,
+    region TEXT,
+    product TEXT,
+    amount INTEGER
+);
+-- @expect: ok
+
+INSERT INTO Sales (id, region, product, amount) VALUES
+    (1, 'east', 'apple', 10),
+    (2, 'east',  'pear', 20),
+    (3, 'west', 'apple', 30),
+    (4, 'west',  'pear', 40),
+    (5, 'west',  'pear',  5);
+-- @expect: ok
+
+-- @name: ROLLUP adds subtotal rows and a grand total
+SELECT region, product, SUM(amount) AS total FROM Sales GROUP BY ROLLUP (region, product)
+-- @expect:
+-- | region: Str | product: Str | total: I64 |
+-- | ----------- | ------------ | ---------- |
+-- | "east"      | "apple"      | 10         |
+-- | "east"      | "pear"       | 20         |
+-- | "west"      | "apple"      | 30         |
+-- | "west"      | "pear"       | 45         |
+-- | "east"      | NULL         | 30         |
+-- | "west"      | NULL         | 75         |
+-- | NULL        | NULL         | 105        |
+
+-- @name: CUBE groups by every subset and GROUPING tells the rolled-up columns apart
+SELECT region, product, GROUPING(region, product) AS level, SUM(amount) AS total FROM Sales GROUP BY CUBE (region, product)
+-- @expect:
+-- | region: Str | product: Str | level: I64 | total: I64 |
+-- | ----------- | ------------ | ---------- | ---------- |
+-- | "east"      | "apple"      | 0          | 10         |
+-- | "east"      | "pear"       | 0          | 20         |
+-- | "west"      | "apple"      | 0          | 30         |
+-- | "west"      | "pear"       | 0          | 45         |
+-- | "east"      | NULL         | 1          | 30         |
+-- | "west"      | NULL         | 1          | 75         |
+-- | NULL        | "apple"      | 2          | 40         |
+-- | NULL        | "pear"       | 2          | 65         |
+-- | NULL        | NULL         | 3          | 105        |
+
+-- @name: GROUPING SETS lists the groupings explicitly
+SELECT region, product, COUNT(*) AS cnt FROM Sales GROUP BY GROUPING SETS ((region), (product), ())
+-- @expect:
+-- | region: Str | product: Str | cnt: I64 |
+-- | ----------- | ------------ | -------- |
+-- | "east"      | NULL         | 2        |
+-- | "west"      | NULL         | 3        |
+-- | NULL        | "apple"      | 2        |
+-- | NULL        | "pear"       | 3        |
+-- | NULL        | NULL         | 5        |
+
+-- @name: plain GROUP BY expressions combine with ROLLUP
+SELECT region, product, SUM(amount) AS total FROM Sales GROUP BY region, ROLLUP (product)
+-- @expect:
+-- | region: Str | product: Str | total: I64 |
+-- | ----------- | ------------ | ---------- |
+-- | "east"      | "apple"      | 10         |
+-- | "east"      | "pear"       | 20         |
+-- | "west"      | "apple"      | 30         |
+-- | "west"      | "pear"       | 45         |
+-- | "east"      | NULL         | 30         |
+-- | "west"      | NULL         | 75         |
+
+-- @name: HAVING can keep only the subtotal rows
+SELECT region, SUM(amount) AS total FROM Sales GROUP BY ROLLUP (region, product) HAVING GROUPING(product) = 1
+-- @expect:
+-- | region: Str | total: I64 |
+-- | ----------- | ---------- |
+-- | "east"      | 30         |
+-- | "west"      | 75         |
+-- | NULL        | 105        |
+
+-- @name: ORDER BY sees rolled-up columns as NULL
+SELECT region, SUM(amount) AS total FROM Sales GROUP BY ROLLUP (region) ORDER BY total DESC
+-- @expect:
+-- | region: Str | total: I64 |
+-- | ----------- | ---------- |
+-- | NULL        | 105        |
+-- | "west"      | 75         |
+-- | "east"      | 30         |
+
+-- @name: the grand total row is produced for an empty input
+SELECT region, COUNT(*) AS cnt FROM Sales WHERE amount > 100 GROUP BY ROLLUP (region)
+-- @expect:
+-- | region | cnt: I64 |
+-- | ------ | -------- |
+-- | NULL   | 0        |
+
+-- @name: GROUPING arguments must be GROUP BY expressions
+SELECT region, GROUPING(product) FROM Sales GROUP BY ROLLUP (region);
+-- @expect: error Query.GroupingArgumentNotInGroupBy
@@ -0,0 +1,47 @@
+CREATE TABLE Employee (
+    id INTEGER,
+    dept TEXT,
+    name TEXT,
+    salary INTEGER NULL,
+    remote BOOLEAN
+);
+-- @expect: ok
+
+INSERT INTO Employee (id, dept, name, salary, remote) VALUES
+    (1, 'eng', 'Alice',  100,  TRUE),
+    (2, 'eng',   'Bob',  200, FALSE),
+    (3, 'eng', 'Carol',  200,  TRUE),
+    (4, 'ops',  'Dave', NULL,  TRUE),
+    (5, 'ops',  'Erin',   50,  TRUE),
+    (6,  'hr', 'Frank', NULL, FALSE);
+-- @expect: ok
+
+-- @name: MEDIAN averages the two middle values
+SELECT MEDIAN(salary) AS median FROM Employee
+-- @expect:
+-- | median: F64 |
+-- | ----------- |
+-- | 150.0       |
+
+-- @name: MEDIAN per group
+SELECT dept, MEDIAN(salary) AS median FROM Employee GROUP BY dept
+-- @expect:
+-- | dept: Str | median: F64 |
+-- | --------- | ----------- |
+-- | "eng"     | 200.0       |
+-- | "ops"     | 50.0        |
+-- | "hr"      | NULL        |
+
+-- @name: MEDIAN with DISTINCT
+SELECT MEDIAN(DISTINCT salary) AS median FROM Employee
+-- @expect:
+-- | median: F64 |
+-- | ----------- |
+-- | 100.0       |
+
+-- @name: MEDIAN with FILTER
+SELECT MEDIAN(salary) FILTER (WHERE remote) AS median FROM Employee
+-- @expect:
+-- | median: F64 |
+-- | ----------- |
+-- | 100.0       |
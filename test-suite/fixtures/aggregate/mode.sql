@@ -0,0 +1,47 @@
+CREATE TABLE Employee (
+    id INTEGER,
+    dept TEXT,
+    name TEXT,
+    salary INTEGER NULL,
+    remote BOOLEAN
+);
+-- @expect: ok
+
+INSERT INTO Employee (id, dept, name, salary, remote) VALUES
+    (1, 'eng', 'Alice',  100,  TRUE),
+    (2, 'eng',   'Bob',  200, FALSE),
+    (3, 'eng', 'Carol',  200,  TRUE),
+    (4, 'ops',  'Dave', NULL,  TRUE),
+    (5, 'ops',  'Erin',   50,  TRUE),
+    (6,  'hr', 'Frank', NULL, FALSE);
+-- @expect: ok
+
+-- @name: MODE returns the most frequent value
+SELECT MODE(salary) AS mode FROM Employee
+-- @expect:
+-- | mode: I64 |
+-- | --------- |
+-- | 200       |
+
+-- @name: MODE WITHIN GROUP syntax
+SELECT MODE() WITHIN GROUP (ORDER BY dept) AS mode FROM Employee
+-- @expect:
+-- | mode: Str |
+-- | --------- |
+-- | "eng"     |
+
+-- @name: MODE breaks ties with the smallest value
+SELECT MODE(name) AS mode FROM Employee
+-- @expect:
+-- | mode: Str |
+-- | --------- |
+-- | "Alice"   |
+
+-- @name: MODE per group with FILTER
+SELECT dept, MODE(salary) FILTER (WHERE remote) AS mode FROM Employee GROUP BY dept
+-- @expect:
+-- | dept: Str | mode: I64 |
+-- | --------- | --------- |
+-- | "eng"     | 100       |
+-- | "ops"     | 50        |
+-- | "hr"      | NULL      |
@@ -0,0 +1,62 @@
+CREATE TABLE Employee (
+    id INTEGER,
+    dept TEXT,
+    name TEXT,
+    salary INTEGER NULL,
+    remote BOOLEAN
+);
+-- @expect: ok
+
+INSERT INTO Employee (id, dept, name, salary, remote) VALUES
+    (1, 'eng', 'Alice',  100,  TRUE),
+    (2, 'eng',   'Bob',  200, FALSE),
+    (3, 'eng', 'Carol',  200,  TRUE),
+    (4, 'ops',  'Dave', NULL,  TRUE),
+    (5, 'ops',  'Erin',   50,  TRUE),
+    (6,  'hr', 'Frank', NULL, FALSE);
+-- @expect: ok
+
+-- @name: PERCENTILE_CONT interpolates
+SELECT PERCENTILE_CONT(0.25) WITHIN GROUP (ORDER BY salary) AS p FROM Employee
+-- @expect:
+-- | p: F64 |
+-- | ------ |
+-- | 87.5   |
+
+-- @name: PERCENTILE_CONT with descending order
+SELECT PERCENTILE_CONT(0.25) WITHIN GROUP (ORDER BY salary DESC) AS p FROM Employee
+-- @expect:
+-- | p: F64 |
+-- | ------ |
+-- | 200.0  |
+
+-- @name: PERCENTILE_DISC picks an input value
+SELECT PERCENTILE_DISC(0.25) WITHIN GROUP (ORDER BY salary) AS p25, PERCENTILE_DISC(0.5) WITHIN GROUP (ORDER BY salary) AS p50 FROM Employee
+-- @expect:
+-- | p25: I64 | p50: I64 |
+-- | -------- | -------- |
+-- | 50       | 100      |
+
+-- @name: PERCENTILE_DISC per group with FILTER
+SELECT dept, PERCENTILE_DISC(1) WITHIN GROUP (ORDER BY salary) FILTER (WHERE remote) AS p FROM Employee GROUP BY dept
+-- @expect:
+-- | dept: Str | p: I64 |
+-- | --------- | ------ |
+-- | "eng"     | 200    |
+-- | "ops"     | 50     |
+-- | "hr"      | NULL   |
+
+-- @name: percentile fraction must be within 0 and 1
+SELECT PERCENTILE_CONT(1.5) WITHIN GROUP (ORDER BY salary) FROM Employee;
+-- @expect: error Query.PercentileFractionOutOfRange
+-- @json: "1.5"
+
+-- @name: PERCENTILE_CONT requires WITHIN GROUP
+SELECT PERCENTILE_CONT(0.5) FROM Employee;
+-- @expect: error Translate.WithinGroupRequiresSingleOrderBy
+-- @json: "PERCENTILE_CONT"
+
+-- @name: WITHIN GROUP is rejected on other functions
+SELECT SUM(id) WITHIN GROUP (ORDER BY id) FROM Employee;
+-- @expect: error Translate.UnsupportedWithinGroup
+-- @json: "SUM"
@@ -0,0 +1,62 @@
+CREATE TABLE Employee (
+    id INTEGER,
+    dept TEXT,
+    name TEXT,
+    salary INTEGER NULL,
+    remote BOOLEAN
+);
+-- @expect: ok
+
+INSERT INTO Employee (id, dept, name, salary, remote) VALUES
+    (1, 'eng', 'Alice',  100,  TRUE),
+    (2, 'eng',   'Bob',  200, FALSE),
+    (3, 'eng', 'Carol',  200,  TRUE),
+    (4, 'ops',  'Dave', NULL,  TRUE),
+    (5, 'ops',  'Erin',   50,  TRUE),
+    (6,  'hr', 'Frank', NULL, FALSE);
+-- @expect: ok
+
+-- @name: STRING_AGG joins values in input order
+SELECT STRING_AGG(name, ', ') AS names FROM Employee
+-- @expect:
+-- | names: Str                             |
+-- | -------------------------------------- |
+-- | "Alice, Bob, Carol, Dave, Erin, Frank" |
+
+-- @name: STRING_AGG honours ORDER BY inside the call
+SELECT dept, STRING_AGG(name, '/' ORDER BY id DESC) AS names FROM Employee GROUP BY dept
+-- @expect:
+-- | dept: Str | names: Str        |
+-- | --------- | ----------------- |
+-- | "eng"     | "Carol/Bob/Alice" |
+-- | "ops"     | "Erin/Dave"       |
+-- | "hr"      | "Frank"           |
+
+-- @name: STRING_AGG with DISTINCT
+SELECT STRING_AGG(DISTINCT dept, ',' ORDER BY dept) AS depts FROM Employee
+-- @expect:
+-- | depts: Str   |
+-- | ------------ |
+-- | "eng,hr,ops" |
+
+-- @name: STRING_AGG with FILTER
+SELECT STRING_AGG(name, ',') FILTER (WHERE remote) AS names FROM Employee
+-- @expect:
+-- | names: Str              |
+-- | ----------------------- |
+-- | "Alice,Carol,Dave,Erin" |
+
+-- @name: STRING_AGG requires string values
+SELECT STRING_AGG(id, ',') FROM Employee;
+-- @expect: error Query.AggregateRequiresStringValue
+-- @json: "STRING_AGG"
+
+-- @name: STRING_AGG requires a separator
+SELECT STRING_AGG(name) FROM Employee;
+-- @expect: error Translate.FunctionArgsLengthNotMatching
+-- @json: {"name": "STRING_AGG", "expected": 2, "found": 1}
+
+-- @name: ORDER BY inside other function calls is rejected
+SELECT SUM(id ORDER BY id) FROM Employee;
+-- @expect: error Translate.UnsupportedFunctionArgOrderBy
+-- @json: "SUM"
@@ -0,0 +1,90 @@
+CREATE TABLE Shape (
+    id INTEGER,
+    route LINESTRING,
+    area POLYGON,
+    spots MULTIPOINT
+)
+-- @expect: payload Create
+
+INSERT INTO Shape VALUES (
+    1,
+    'LINESTRING(0 0, 3 4)',
+    'POLYGON((0 0, 4 0, 4 4, 0 4, 0 0))',
+    'MULTIPOINT((1 1), (2 2))'
+)
+-- @expect: payload Insert
+-- @json: 1
+
+INSERT INTO Shape VALUES (
+    2,
+    CAST('LINESTRING (1.5 2, -1 0.5, 0 0)' AS LINESTRING),
+    CAST('POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 4 2, 4 4, 2 4, 2 2))' AS POLYGON),
+    CAST('MULTIPOINT (0 0, 1 2)' AS MULTIPOINT)
+)
+-- @expect: payload Insert
+-- @json: 1
+
+SELECT id, route, area, spots FROM Shape
+-- @expect:
+-- | id: I64 | route: LineString                | area: Polygon                                                       | spots: MultiPoint      |
+-- | ------- | -------------------------------- | ------------------------------------------------------------------- | ---------------------- |
+-- | 1       | "LINESTRING(0 0, 3 4)"           | "POLYGON((0 0, 4 0, 4 4, 0 4, 0 0))"                                | "MULTIPOINT(1 1, 2 2)" |
+-- | 2       | "LINESTRING(1.5 2, -1 0.5, 0 0)" | "POLYGON((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 4 2, 4 4, 2 4, 2 2))" | "MULTIPOINT(0 0, 1 2)" |
+
+SELECT id FROM Shape WHERE route = CAST('LINESTRING(0 0, 3 4)' AS LINESTRING)
+-- @expect:
+-- | id: I64 |
+-- | ------- |
+-- | 1       |
+
+UPDATE Shape SET spots = 'MULTIPOINT(5 5)' WHERE id = 1
+-- @expect: payload Update
+-- @json: 1
+
+SELECT spots FROM Shape WHERE id = 1
+-- @expect:
+-- | spots: MultiPoint |
+-- | ----------------- |
+-- | "MULTIPOINT(5 5)" |
+
+SELECT CAST(X'0101000000000000000000F03F0000000000000040' AS POINT) AS pt
+-- @expect:
+-- | pt: Point    |
+-- | ------------ |
+-- | "POINT(1 2)" |
+
+SELECT CAST(X'01020000000200000000000000000000000000000000000000000000000000F03F000000000000F03F' AS LINESTRING) AS line
+-- @expect:
+-- | line: LineString       |
+-- | ---------------------- |
+-- | "LINESTRING(0 0, 1 1)" |
+
+SELECT CAST(X'000000000200000002000000000000000000000000000000003FF00000000000003FF0000000000000' AS LINESTRING) AS line
+-- @expect:
+-- | line: LineString       |
+-- | ---------------------- |
+-- | "LINESTRING(0 0, 1 1)" |
+
+INSERT INTO Shape VALUES (3, 'LINESTRING(0 0)', NULL, NULL)
+-- @expect: error Evaluate.TextParseFailed
+-- @json:
+-- {
+--   "data_type": "LineString",
+--   "literal": "LINESTRING(0 0)"
+-- }
+
+INSERT INTO Shape VALUES (3, NULL, 'POLYGON((0 0, 1 0, 1 1, 0 1))', NULL)
+-- @expect: error Evaluate.TextParseFailed
+-- @json:
+-- {
+--   "data_type": "Polygon",
+--   "literal": "POLYGON((0 0, 1 0, 1 1, 0 1))"
+-- }
+
+SELECT CAST('POINT(1 2)' AS MULTIPOINT) AS spots
+-- @expect: error Evaluate.TextParseFailed
+-- @json:
+-- {
+--   "data_type": "MultiPoint",
+--   "literal": "POINT(1 2)"
+-- }
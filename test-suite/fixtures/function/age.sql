@@ -0,0 +1,33 @@
+-- @name: AGE returns completed months between two timestamps
+SELECT
+    AGE(DATE '2024-05-17', DATE '1990-02-20') AS a,
+    AGE(TIMESTAMP '2024-05-17 10:00:00', TIMESTAMP '2024-02-17 12:00:00') AS b
+-- @expect:
+-- | a: Interval            | b: Interval |
+-- | ---------------------- | ----------- |
+-- | "'34-2' YEAR TO MONTH" | "'2' MONTH" |
+
+-- @name: AGE is negative when the first argument is earlier
+SELECT AGE(DATE '2023-01-01', DATE '2024-01-01') AS a
+-- @expect:
+-- | a: Interval |
+-- | ----------- |
+-- | "'-1' YEAR" |
+
+-- @name: AGE with a single argument measures from the current date
+SELECT AGE(NOW()) < INTERVAL '1' MONTH AS recent
+-- @expect:
+-- | recent: Bool |
+-- | ------------ |
+-- | true         |
+
+-- @name: AGE returns NULL for NULL input
+SELECT AGE(NULL, DATE '2024-01-01') AS a
+-- @expect:
+-- | a    |
+-- | ---- |
+-- | NULL |
+
+SELECT AGE('2024-01-01') AS a
+-- @expect: error Evaluate.FunctionRequiresDateOrDateTimeValue
+-- @json: "AGE"
@@ -0,0 +1,37 @@
+-- @name: DATE_BIN aligns to the Unix epoch by default
+SELECT DATE_BIN(INTERVAL '15' MINUTE, TIMESTAMP '2024-05-17 13:47:21') AS b
+-- @expect:
+-- | b: Timestamp          |
+-- | --------------------- |
+-- | "2024-05-17 13:45:00" |
+
+-- @name: DATE_BIN aligns to the given origin
+SELECT DATE_BIN(INTERVAL '1' DAY, TIMESTAMP '2024-05-17 05:30:00', TIMESTAMP '2024-01-01 06:00:00') AS b
+-- @expect:
+-- | b: Timestamp          |
+-- | --------------------- |
+-- | "2024-05-16 06:00:00" |
+
+-- @name: DATE_BIN accepts dates
+SELECT DATE_BIN(INTERVAL '7' DAY, DATE '2024-05-17', DATE '2024-05-01') AS b
+-- @expect:
+-- | b: Timestamp          |
+-- | --------------------- |
+-- | "2024-05-15 00:00:00" |
+
+-- @name: DATE_BIN returns NULL for NULL input
+SELECT DATE_BIN(INTERVAL '1' HOUR, NULL) AS b
+-- @expect:
+-- | b    |
+-- | ---- |
+-- | NULL |
+
+SELECT DATE_BIN(INTERVAL '1' MONTH, TIMESTAMP '2024-05-17 13:47:21') AS b
+-- @expect: error Evaluate.DateBinRequiresPositiveTimeInterval
+
+SELECT DATE_BIN(INTERVAL '-1' HOUR, TIMESTAMP '2024-05-17 13:47:21') AS b
+-- @expect: error Evaluate.DateBinRequiresPositiveTimeInterval
+
+SELECT DATE_BIN(60, TIMESTAMP '2024-05-17 13:47:21') AS b
+-- @expect: error Evaluate.FunctionRequiresIntervalValue
+-- @json: "DATE_BIN"
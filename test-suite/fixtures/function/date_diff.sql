@@ -0,0 +1,39 @@
+-- @name: DATE_DIFF counts calendar boundaries
+SELECT
+    DATE_DIFF('year', DATE '2023-12-31', DATE '2024-01-01') AS y,
+    DATE_DIFF('quarter', DATE '2024-03-31', DATE '2024-04-01') AS q,
+    DATE_DIFF('month', DATE '2024-01-31', DATE '2024-03-01') AS m
+-- @expect:
+-- | y: I64 | q: I64 | m: I64 |
+-- | ------ | ------ | ------ |
+-- | 1      | 1      | 2      |
+
+-- @name: DATE_DIFF counts weeks starting on Monday
+SELECT
+    DATE_DIFF('week', DATE '2024-05-12', DATE '2024-05-13') AS w,
+    DATE_DIFF('day', TIMESTAMP '2024-05-01 23:00:00', TIMESTAMP '2024-05-03 01:00:00') AS d
+-- @expect:
+-- | w: I64 | d: I64 |
+-- | ------ | ------ |
+-- | 1      | 2      |
+
+-- @name: DATE_DIFF supports time units
+SELECT
+    DATE_DIFF('hour', TIMESTAMP '2024-05-01 10:59:00', TIMESTAMP '2024-05-01 12:01:00') AS h,
+    DATE_DIFF('minute', TIMESTAMP '2024-05-01 10:59:59', TIMESTAMP '2024-05-01 11:00:00') AS mi,
+    DATE_DIFF('second', TIMESTAMP '2024-05-01 11:00:00', TIMESTAMP '2024-05-01 10:59:00') AS s
+-- @expect:
+-- | h: I64 | mi: I64 | s: I64 |
+-- | ------ | ------- | ------ |
+-- | 2      | 1       | -60    |
+
+-- @name: DATE_DIFF returns NULL for NULL input
+SELECT DATE_DIFF('day', NULL, DATE '2024-05-01') AS d
+-- @expect:
+-- | d    |
+-- | ---- |
+-- | NULL |
+
+SELECT DATE_DIFF('decade', DATE '2024-05-01', DATE '2024-05-01') AS d
+-- @expect: error Evaluate.UnsupportedDateTimeUnit
+-- @json: "decade"
@@ -0,0 +1,59 @@
+-- @name: DATE_TRUNC keeps timestamps as timestamps
+SELECT
+    DATE_TRUNC('year', TIMESTAMP '2024-05-17 13:45:21') AS y,
+    DATE_TRUNC('quarter', TIMESTAMP '2024-05-17 13:45:21') AS q,
+    DATE_TRUNC('month', TIMESTAMP '2024-05-17 13:45:21') AS m
+-- @expect:
+-- | y: Timestamp          | q: Timestamp          | m: Timestamp          |
+-- | --------------------- | --------------------- | --------------------- |
+-- | "2024-01-01 00:00:00" | "2024-04-01 00:00:00" | "2024-05-01 00:00:00" |
+
+-- @name: DATE_TRUNC rounds down to the Monday of the week
+SELECT
+    DATE_TRUNC('WEEK', TIMESTAMP '2024-05-17 13:45:21') AS w,
+    DATE_TRUNC('Day', TIMESTAMP '2024-05-17 13:45:21') AS d
+-- @expect:
+-- | w: Timestamp          | d: Timestamp          |
+-- | --------------------- | --------------------- |
+-- | "2024-05-13 00:00:00" | "2024-05-17 00:00:00" |
+
+-- @name: DATE_TRUNC supports time units
+SELECT
+    DATE_TRUNC('hour', TIMESTAMP '2024-05-17 13:45:21.5') AS h,
+    DATE_TRUNC('minute', TIMESTAMP '2024-05-17 13:45:21.5') AS mi,
+    DATE_TRUNC('second', TIMESTAMP '2024-05-17 13:45:21.5') AS s
+-- @expect:
+-- | h: Timestamp          | mi: Timestamp         | s: Timestamp          |
+-- | --------------------- | --------------------- | --------------------- |
+-- | "2024-05-17 13:00:00" | "2024-05-17 13:45:00" | "2024-05-17 13:45:21" |
+
+-- @name: DATE_TRUNC keeps dates as dates
+SELECT DATE_TRUNC('month', DATE '2024-05-17') AS m
+-- @expect:
+-- | m: Date      |
+-- | ------------ |
+-- | "2024-05-01" |
+
+-- @name: DATE_TRUNC returns NULL for NULL input
+SELECT DATE_TRUNC('month', NULL) AS m
+-- @expect:
+-- | m    |
+-- | ---- |
+-- | NULL |
+
+SELECT DATE_TRUNC('fortnight', DATE '2024-05-17') AS m
+-- @expect: error Evaluate.UnsupportedDateTimeUnit
+-- @json: "fortnight"
+
+SELECT DATE_TRUNC('month', 'foo') AS m
+-- @expect: error Evaluate.FunctionRequiresDateOrDateTimeValue
+-- @json: "DATE_TRUNC"
+
+SELECT DATE_TRUNC('month') AS m
+-- @expect: error Translate.FunctionArgsLengthNotMatching
+-- @json:
+-- {
+--   "expected": 2,
+--   "found": 1,
+--   "name": "DATE_TRUNC"
+-- }
@@ -0,0 +1,21 @@
+SELECT ST_AREA(CAST('POLYGON((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 2, 1 1))' AS POLYGON)) AS area
+-- @expect:
+-- | area: F64 |
+-- | --------- |
+-- | 15        |
+
+SELECT ST_AREA(CAST('LINESTRING(0 0, 1 1)' AS LINESTRING)) AS area
+-- @expect:
+-- | area: F64 |
+-- | --------- |
+-- | 0         |
+
+SELECT ST_AREA(NULL) AS area
+-- @expect:
+-- | area |
+-- | ---- |
+-- | NULL |
+
+SELECT ST_AREA(1) AS area
+-- @expect: error Evaluate.FunctionRequiresGeometryValue
+-- @json: "ST_AREA"
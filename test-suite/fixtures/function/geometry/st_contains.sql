@@ -0,0 +1,52 @@
+CREATE TABLE Zone (name TEXT, area POLYGON)
+-- @expect: payload Create
+
+INSERT INTO Zone VALUES
+    ('square', 'POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))'),
+    ('donut', 'POLYGON((0 0, 10 0, 10 10, 0 10, 0 0), (4 4, 6 4, 6 6, 4 6, 4 4))'),
+    ('far', 'POLYGON((20 20, 30 20, 30 30, 20 30, 20 20))')
+-- @expect: payload Insert
+-- @json: 3
+
+SELECT name FROM Zone WHERE ST_CONTAINS(area, POINT(5, 5))
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "square"  |
+
+SELECT name FROM Zone WHERE ST_CONTAINS(area, POINT(0, 5))
+-- @expect:
+-- | name |
+-- | ---- |
+
+SELECT name FROM Zone WHERE ST_CONTAINS(area, CAST('LINESTRING(1 1, 9 9)' AS LINESTRING))
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "square"  |
+
+SELECT name FROM Zone WHERE ST_CONTAINS(area, CAST('POLYGON((1 1, 3 1, 3 3, 1 3, 1 1))' AS POLYGON))
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "square"  |
+-- | "donut"   |
+
+SELECT ST_CONTAINS(area, NULL) AS contained FROM Zone WHERE name = 'far'
+-- @expect:
+-- | contained |
+-- | --------- |
+-- | NULL      |
+
+SELECT ST_CONTAINS(area, 1) AS contained FROM Zone
+-- @expect: error Evaluate.FunctionRequiresGeometryValue
+-- @json: "ST_CONTAINS"
+
+SELECT ST_CONTAINS(area) AS contained FROM Zone
+-- @expect: error Translate.FunctionArgsLengthNotMatching
+-- @json:
+-- {
+--   "expected": 2,
+--   "found": 1,
+--   "name": "ST_CONTAINS"
+-- }
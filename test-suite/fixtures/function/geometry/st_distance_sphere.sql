@@ -0,0 +1,32 @@
+CREATE TABLE City (name TEXT, location POINT)
+-- @expect: payload Create
+
+INSERT INTO City VALUES
+    ('London', POINT(-0.1278, 51.5074)),
+    ('Paris', POINT(2.3522, 48.8566))
+-- @expect: payload Insert
+-- @json: 2
+
+SELECT ST_DISTANCE_SPHERE(POINT(0, 0), POINT(0, 1)) AS meters
+-- @expect:
+-- | meters: F64       |
+-- | ----------------- |
+-- | 111195.0802335329 |
+
+SELECT a.name AS source, b.name AS target, ST_DISTANCE_SPHERE(a.location, b.location) AS meters
+FROM City a JOIN City b ON a.name <> b.name
+-- @expect:
+-- | source: Str | target: Str | meters: F64       |
+-- | ----------- | ----------- | ----------------- |
+-- | "London"    | "Paris"     | 343556.5348808832 |
+-- | "Paris"     | "London"    | 343556.5348808832 |
+
+SELECT ST_DISTANCE_SPHERE(location, NULL) AS meters FROM City WHERE name = 'Paris'
+-- @expect:
+-- | meters |
+-- | ------ |
+-- | NULL   |
+
+SELECT ST_DISTANCE_SPHERE(location, CAST('LINESTRING(0 0, 1 1)' AS LINESTRING)) AS meters FROM City
+-- @expect: error Evaluate.FunctionRequiresPointValue
+-- @json: "ST_DISTANCE_SPHERE"
@@ -0,0 +1,34 @@
+CREATE TABLE Route (name TEXT, path LINESTRING)
+-- @expect: payload Create
+
+INSERT INTO Route VALUES
+    ('diagonal', 'LINESTRING(0 0, 10 10)'),
+    ('horizontal', 'LINESTRING(0 5, 10 5)'),
+    ('far', 'LINESTRING(20 0, 30 0)')
+-- @expect: payload Insert
+-- @json: 3
+
+SELECT name, ST_INTERSECTS(path, CAST('LINESTRING(0 10, 10 0)' AS LINESTRING)) AS crosses FROM Route
+-- @expect:
+-- | name: Str    | crosses: Bool |
+-- | ------------ | ------------- |
+-- | "diagonal"   | true          |
+-- | "horizontal" | true          |
+-- | "far"        | false         |
+
+SELECT name FROM Route WHERE ST_INTERSECTS(path, CAST('POLYGON((8 8, 12 8, 12 12, 8 12, 8 8))' AS POLYGON))
+-- @expect:
+-- | name: Str  |
+-- | ---------- |
+-- | "diagonal" |
+
+SELECT name FROM Route WHERE ST_INTERSECTS(CAST('MULTIPOINT(10 5, 25 0)' AS MULTIPOINT), path)
+-- @expect:
+-- | name: Str    |
+-- | ------------ |
+-- | "horizontal" |
+-- | "far"        |
+
+SELECT ST_INTERSECTS(path, 'LINESTRING(0 0, 1 1)') AS crosses FROM Route
+-- @expect: error Evaluate.FunctionRequiresGeometryValue
+-- @json: "ST_INTERSECTS"
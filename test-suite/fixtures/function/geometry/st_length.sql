@@ -0,0 +1,21 @@
+SELECT ST_LENGTH(CAST('LINESTRING(0 0, 3 4, 3 10)' AS LINESTRING)) AS length
+-- @expect:
+-- | length: F64 |
+-- | ----------- |
+-- | 11          |
+
+SELECT ST_LENGTH(POINT(1, 1)) AS length
+-- @expect:
+-- | length: F64 |
+-- | ----------- |
+-- | 0           |
+
+SELECT ST_LENGTH(NULL) AS length
+-- @expect:
+-- | length |
+-- | ------ |
+-- | NULL   |
+
+SELECT ST_LENGTH('LINESTRING(0 0, 3 4)') AS length
+-- @expect: error Evaluate.FunctionRequiresGeometryValue
+-- @json: "ST_LENGTH"
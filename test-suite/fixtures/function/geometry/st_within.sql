@@ -0,0 +1,27 @@
+CREATE TABLE Spot (name TEXT, location POINT)
+-- @expect: payload Create
+
+INSERT INTO Spot VALUES
+    ('inside', POINT(2, 2)),
+    ('edge', POINT(0, 2)),
+    ('outside', POINT(20, 20))
+-- @expect: payload Insert
+-- @json: 3
+
+SELECT name, ST_WITHIN(location, CAST('POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))' AS POLYGON)) AS within FROM Spot
+-- @expect:
+-- | name: Str | within: Bool |
+-- | --------- | ------------ |
+-- | "inside"  | true         |
+-- | "edge"    | false        |
+-- | "outside" | false        |
+
+SELECT ST_WITHIN(CAST('LINESTRING(1 1, 2 2)' AS LINESTRING), CAST('POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))' AS POLYGON)) AS within
+-- @expect:
+-- | within: Bool |
+-- | ------------ |
+-- | true         |
+
+SELECT ST_WITHIN(location, 'inside') AS within FROM Spot
+-- @expect: error Evaluate.FunctionRequiresGeometryValue
+-- @json: "ST_WITHIN"
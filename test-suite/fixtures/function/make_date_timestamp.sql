@@ -0,0 +1,34 @@
+-- @name: MAKE_DATE builds a date from its parts
+SELECT MAKE_DATE(2024, 2, 29) AS d
+-- @expect:
+-- | d: Date      |
+-- | ------------ |
+-- | "2024-02-29" |
+
+-- @name: MAKE_TIMESTAMP builds a timestamp with fractional seconds
+SELECT
+    MAKE_TIMESTAMP(2024, 5, 17, 13, 45, 21) AS t1,
+    MAKE_TIMESTAMP(2024, 5, 17, 13, 45, 21.25) AS t2
+-- @expect:
+-- | t1: Timestamp         | t2: Timestamp             |
+-- | --------------------- | ------------------------- |
+-- | "2024-05-17 13:45:21" | "2024-05-17 13:45:21.250" |
+
+-- @name: MAKE_DATE returns NULL for NULL input
+SELECT MAKE_DATE(2024, NULL, 1) AS d
+-- @expect:
+-- | d    |
+-- | ---- |
+-- | NULL |
+
+SELECT MAKE_DATE(2023, 2, 29) AS d
+-- @expect: error Evaluate.InvalidDateTimeParts
+-- @json: "2023-2-29"
+
+SELECT MAKE_TIMESTAMP(2024, 5, 17, 24, 0, 0) AS t
+-- @expect: error Evaluate.InvalidDateTimeParts
+-- @json: "2024-5-17 24:0:0"
+
+SELECT MAKE_DATE('2024', 1, 1) AS d
+-- @expect: error Evaluate.FunctionRequiresIntegerValue
+-- @json: "MAKE_DATE"
@@ -0,0 +1,161 @@
+CREATE TABLE Log (id INTEGER, line TEXT)
+-- @expect: ok
+
+INSERT INTO Log VALUES
+    (1, 'GET /index.html 200'),
+    (2, 'POST /api/users 201'),
+    (3, 'get /favicon.ico 404'),
+    (4, NULL);
+-- @expect: ok
+
+-- @name: REGEXP_LIKE matches case-sensitively by default
+SELECT id, REGEXP_LIKE(line, '^GET') AS is_get FROM Log
+-- @expect:
+-- | id: I64 | is_get: Bool |
+-- | ------- | ------------ |
+-- | 1       | true         |
+-- | 2       | false        |
+-- | 3       | false        |
+-- | 4       | NULL         |
+
+-- @name: REGEXP_LIKE with the i flag ignores case
+SELECT id FROM Log WHERE REGEXP_LIKE(line, '^get', 'i')
+-- @expect:
+-- | id: I64 |
+-- | ------- |
+-- | 1       |
+-- | 3       |
+
+-- @name: ~ operator
+SELECT id FROM Log WHERE line ~ '\s2\d\d$'
+-- @expect:
+-- | id: I64 |
+-- | ------- |
+-- | 1       |
+-- | 2       |
+
+-- @name: ~* operator
+SELECT id FROM Log WHERE line ~* '^get'
+-- @expect:
+-- | id: I64 |
+-- | ------- |
+-- | 1       |
+-- | 3       |
+
+-- @name: !~ operator
+SELECT id FROM Log WHERE line !~ '^GET'
+-- @expect:
+-- | id: I64 |
+-- | ------- |
+-- | 2       |
+-- | 3       |
+
+-- @name: !~* operator
+SELECT id FROM Log WHERE line !~* '^get'
+-- @expect:
+-- | id: I64 |
+-- | ------- |
+-- | 2       |
+
+-- @name: REGEXP_SUBSTR returns the first match
+SELECT id, REGEXP_SUBSTR(line, '\d{3}$') AS status FROM Log
+-- @expect:
+-- | id: I64 | status: Str |
+-- | ------- | ----------- |
+-- | 1       | "200"       |
+-- | 2       | "201"       |
+-- | 3       | "404"       |
+-- | 4       | NULL        |
+
+-- @name: REGEXP_SUBSTR returns NULL without a match
+SELECT REGEXP_SUBSTR('no digits', '\d+') AS found
+-- @expect:
+-- | found |
+-- | ----- |
+-- | NULL  |
+
+-- @name: REGEXP_REPLACE replaces the first match unless the g flag is set
+SELECT REGEXP_REPLACE('a-b-c', '-', '+') AS first, REGEXP_REPLACE('a-b-c', '-', '+', 'g') AS every
+-- @expect:
+-- | first: Str | every: Str |
+-- | ---------- | ---------- |
+-- | "a+b-c"    | "a+b+c"    |
+
+-- @name: REGEXP_REPLACE supports back references
+SELECT REGEXP_REPLACE('2024-01-31', '(\d+)-(\d+)-(\d+)', '\3/\2/\1') AS swapped
+-- @expect:
+-- | swapped: Str |
+-- | ------------ |
+-- | "31/01/2024" |
+
+-- @name: REGEXP_REPLACE keeps literal dollar signs
+SELECT REGEXP_REPLACE('cost: 5', '\d', '$\&') AS price
+-- @expect:
+-- | price: Str |
+-- | ---------- |
+-- | "cost: $5" |
+
+-- @name: REGEXP_MATCHES returns the capture groups of the first match
+SELECT REGEXP_MATCHES(line, '^(\w+) (\S+)') AS captures FROM Log WHERE id = 2
+-- @expect:
+-- | captures: List        |
+-- | --------------------- |
+-- | ["POST","/api/users"] |
+
+-- @name: REGEXP_MATCHES returns the whole match when there are no groups
+SELECT REGEXP_MATCHES('a1b2', '\d') AS digits
+-- @expect:
+-- | digits: List |
+-- | ------------ |
+-- | ["1"]        |
+
+-- @name: REGEXP_MATCHES with the g flag returns every match
+SELECT REGEXP_MATCHES('a1b2', '([a-z])(\d)', 'g') AS pairs
+-- @expect:
+-- | pairs: List           |
+-- | --------------------- |
+-- | [["a","1"],["b","2"]] |
+
+-- @name: REGEXP_MATCHES returns NULL without a match
+SELECT REGEXP_MATCHES('abc', '\d') AS digits
+-- @expect:
+-- | digits |
+-- | ------ |
+-- | NULL   |
+
+-- @name: REGEXP_SPLIT_TO_ARRAY
+SELECT REGEXP_SPLIT_TO_ARRAY('a, b,c', ',\s*') AS items
+-- @expect:
+-- | items: List   |
+-- | ------------- |
+-- | ["a","b","c"] |
+
+-- @name: invalid pattern
+SELECT REGEXP_LIKE('abc', '(') AS invalid
+-- @expect: error StringExt.InvalidRegexPattern
+-- @json: "("
+
+-- @name: invalid flag
+SELECT REGEXP_LIKE('abc', 'a', 'q') AS invalid
+-- @expect: error StringExt.InvalidRegexFlag
+-- @json: "q"
+
+-- @name: pattern must be a string
+SELECT REGEXP_LIKE('abc', 1) AS invalid
+-- @expect: error Evaluate.FunctionRequiresStringValue
+-- @json: "REGEXP_LIKE"
+
+-- @name: ~ requires string operands
+SELECT id FROM Log WHERE id ~ '1'
+-- @expect: error Evaluate.UnsupportedBinaryOperation
+
+-- @name: REGEXP_REPLACE argument count
+SELECT REGEXP_REPLACE('abc', 'a') AS invalid
+-- @expect: error Translate.FunctionArgsLengthNotWithinRange
+-- @json:
+-- {
+--   "expected_maximum": 4,
+--   "expected_minimum": 3,
+--   "found": 2,
+--   "name": "REGEXP_REPLACE"
+-- }
@@ -0,0 +1,97 @@
+-- @name: GENERATE_SERIES counts integers with a default step of one
+SELECT * FROM GENERATE_SERIES(1, 3)
+-- @expect:
+-- | N: I64 |
+-- | ------ |
+-- | 1      |
+-- | 2      |
+-- | 3      |
+
+-- @name: GENERATE_SERIES accepts a negative step
+SELECT * FROM GENERATE_SERIES(10, 1, -4) AS S
+-- @expect:
+-- | N: I64 |
+-- | ------ |
+-- | 10     |
+-- | 6      |
+-- | 2      |
+
+-- @name: GENERATE_SERIES yields no rows when the bounds are reversed
+SELECT * FROM GENERATE_SERIES(3, 1)
+-- @expect:
+-- | N   |
+-- | --- |
+
+-- @name: GENERATE_SERIES produces dates for day and month steps
+SELECT * FROM GENERATE_SERIES(DATE '2024-01-31', DATE '2024-05-01', INTERVAL '1' MONTH)
+-- @expect:
+-- | N: Date      |
+-- | ------------ |
+-- | "2024-01-31" |
+-- | "2024-02-29" |
+-- | "2024-03-31" |
+-- | "2024-04-30" |
+
+-- @name: GENERATE_SERIES produces timestamps for sub-day steps
+SELECT * FROM GENERATE_SERIES(DATE '2024-01-01', TIMESTAMP '2024-01-01 01:00:00', INTERVAL '30' MINUTE)
+-- @expect:
+-- | N: Timestamp          |
+-- | --------------------- |
+-- | "2024-01-01 00:00:00" |
+-- | "2024-01-01 00:30:00" |
+-- | "2024-01-01 01:00:00" |
+
+-- @name: GENERATE_SERIES walks backwards through timestamps
+SELECT * FROM GENERATE_SERIES(TIMESTAMP '2024-01-03 12:00:00', TIMESTAMP '2024-01-01 00:00:00', INTERVAL '-1' DAY)
+-- @expect:
+-- | N: Timestamp          |
+-- | --------------------- |
+-- | "2024-01-03 12:00:00" |
+-- | "2024-01-02 12:00:00" |
+-- | "2024-01-01 12:00:00" |
+
+-- @name: GENERATE_SERIES yields no rows for NULL arguments
+SELECT * FROM GENERATE_SERIES(DATE '2024-01-01', NULL, INTERVAL '1' DAY)
+-- @expect:
+-- | N   |
+-- | --- |
+
+CREATE TABLE Sales (day DATE, amount INTEGER)
+-- @expect: ok
+
+INSERT INTO Sales VALUES (DATE '2024-03-01', 10), (DATE '2024-03-03', 20), (DATE '2024-03-03', 5)
+-- @expect: ok
+
+-- @name: GENERATE_SERIES fills gaps in a daily report
+SELECT D.N AS day, SUM(Sales.amount) AS total
+FROM GENERATE_SERIES(DATE '2024-03-01', DATE '2024-03-04', INTERVAL '1' DAY) AS D
+LEFT JOIN Sales ON Sales.day = D.N
+GROUP BY D.N
+ORDER BY D.N
+-- @expect:
+-- | day: Date    | total: I64 |
+-- | ------------ | ---------- |
+-- | "2024-03-01" | 10         |
+-- | "2024-03-02" | NULL       |
+-- | "2024-03-03" | 25         |
+-- | "2024-03-04" | NULL       |
+
+SELECT * FROM GENERATE_SERIES(1, 3, 0)
+-- @expect: error Query.GenerateSeriesZeroStep
+
+SELECT * FROM GENERATE_SERIES(DATE '2024-01-01', DATE '2024-01-03', INTERVAL '0' DAY)
+-- @expect: error Query.GenerateSeriesZeroStep
+
+SELECT * FROM GENERATE_SERIES(DATE '2024-01-01', DATE '2024-01-03')
+-- @expect: error Query.GenerateSeriesIntervalStepRequired
+
+SELECT * FROM GENERATE_SERIES(DATE '2024-01-01', DATE '2024-01-03', 1)
+-- @expect: error Query.GenerateSeriesIntervalStepRequired
+
+SELECT * FROM GENERATE_SERIES('a', 'b')
+-- @expect: error Query.GenerateSeriesUnsupportedBounds
+-- @json: "Str(\"a\")"
+
+SELECT * FROM GENERATE_SERIES(1)
+-- @expect: error Translate.UnsupportedQueryTableFactor
+-- @json: "GENERATE_SERIES(1)"
@@ -0,0 +1,86 @@
+CREATE TABLE Place (
+    name TEXT,
+    location POINT NULL,
+    area POLYGON NULL
+);
+-- @expect: ok
+
+INSERT INTO Place VALUES
+    ('home', POINT(1, 1), 'POLYGON((0 0, 4 0, 4 4, 0 4, 0 0))'),
+    ('shed', POINT(3, 1), 'POLYGON((0 0, 4 0, 0 4, 0 0))'),
+    ('office', POINT(12, 5), 'POLYGON((10 0, 20 0, 20 10, 10 10, 10 0))'),
+    ('cabin', POINT(-30, 40), 'POLYGON((-35 35, -25 35, -25 45, -35 45, -35 35))'),
+    ('unknown', NULL, NULL);
+-- @expect: ok
+
+CREATE INDEX idx_location ON Place (location);
+-- @expect: payload CreateIndex
+
+CREATE INDEX idx_area ON Place (area);
+-- @expect: payload CreateIndex
+
+SELECT name FROM Place WHERE ST_WITHIN(location, CAST('POLYGON((0 0, 15 0, 15 15, 0 15, 0 0))' AS POLYGON));
+-- @expect-index: idx_location && CAST('POLYGON((0 0, 15 0, 15 15, 0 15, 0 0))' AS POLYGON)
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "home"    |
+-- | "shed"    |
+-- | "office"  |
+
+SELECT name FROM Place WHERE ST_INTERSECTS(POINT(3, 3), area);
+-- @expect-index: idx_area && POINT(3, 3)
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "home"    |
+
+SELECT name FROM Place WHERE ST_CONTAINS(area, POINT(1, 1)) AND name <> 'home';
+-- @expect-index: idx_area && POINT(1, 1)
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "shed"    |
+
+SELECT name FROM Place WHERE ST_CONTAINS(area, CAST('LINESTRING(-40 30, -20 50)' AS LINESTRING));
+-- @expect-index: idx_area && CAST('LINESTRING(-40 30, -20 50)' AS LINESTRING)
+-- @expect:
+-- | name |
+-- | ---- |
+
+SELECT name FROM Place WHERE location = POINT(3, 1);
+-- @expect-index: idx_location = POINT(3, 1)
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "shed"    |
+
+SELECT name FROM Place WHERE location IS NULL;
+-- @expect-index: idx_location = NULL
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "unknown" |
+
+UPDATE Place SET location = POINT(-31, 41) WHERE name = 'office';
+-- @expect: payload Update
+-- @json: 1
+
+DELETE FROM Place WHERE name = 'home';
+-- @expect: payload Delete
+-- @json: 1
+
+SELECT name FROM Place WHERE ST_WITHIN(location, CAST('POLYGON((-35 35, -25 35, -25 45, -35 45, -35 35))' AS POLYGON));
+-- @expect-index: idx_location && CAST('POLYGON((-35 35, -25 35, -25 45, -35 45, -35 35))' AS POLYGON)
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "office"  |
+-- | "cabin"   |
+
+SELECT name FROM Place WHERE ST_INTERSECTS(area, POINT(1, 1));
+-- @expect-index: idx_area && POINT(1, 1)
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "shed"    |
@@ -43,9 +43,9 @@ SELECT 1 ^ 2 FROM Test;
 -- @expect: error Translate.UnsupportedBinaryOperator
 -- @json: "^"
 
-SELECT * FROM Test UNION SELECT * FROM Test;
+SELECT * FROM Test INTERSECT SELECT * FROM Test;
 -- @expect: error Translate.UnsupportedQuerySetExpr
--- @json: "SELECT * FROM Test UNION SELECT * FROM Test"
+-- @json: "SELECT * FROM Test INTERSECT SELECT * FROM Test"
 
 SELECT * FROM Test WHERE noname = 1;
 -- @expect: error Evaluate.IdentifierNotFound
@@ -0,0 +1,198 @@
+CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT, price INTEGER);
+-- @expect: payload Create
+
+CREATE TABLE Audit (item_id INTEGER, action TEXT, old_price INTEGER NULL, new_price INTEGER NULL);
+-- @expect: payload Create
+
+CREATE TABLE Counter (name TEXT PRIMARY KEY, n INTEGER);
+-- @expect: payload Create
+
+INSERT INTO Counter VALUES ('Item', 0);
+-- @expect: payload Insert
+-- @json: 1
+
+-- @name: create trigger on missing table
+CREATE TRIGGER missing AFTER INSERT ON Missing FOR EACH ROW
+BEGIN
+    DELETE FROM Audit;
+END
+-- @expect: error Alter.TableNotFound
+-- @json: "Missing"
+
+CREATE TRIGGER item_truncate AFTER TRUNCATE ON Item FOR EACH ROW
+BEGIN
+    DELETE FROM Audit;
+END
+-- @expect: error Translate.UnsupportedTriggerEvent
+-- @json: "TRUNCATE"
+
+CREATE TRIGGER item_select AFTER INSERT ON Item FOR EACH ROW
+BEGIN
+    SELECT * FROM Audit;
+END
+-- @expect: error Translate.UnsupportedTriggerStatement
+-- @json: "SELECT * FROM Audit"
+
+-- @name: create row triggers
+CREATE TRIGGER item_insert AFTER INSERT ON Item FOR EACH ROW
+BEGIN
+    INSERT INTO Audit VALUES (NEW.id, 'insert', NULL, NEW.price);
+    UPDATE Counter SET n = n + 1 WHERE name = 'Item';
+END;
+-- @expect: payload Create
+
+CREATE TRIGGER item_update AFTER UPDATE ON Item FOR EACH ROW
+BEGIN
+    INSERT INTO Audit VALUES (NEW.id, 'update', OLD.price, NEW.price)
+END;
+-- @expect: payload Create
+
+CREATE TRIGGER item_delete BEFORE DELETE ON Item FOR EACH ROW
+BEGIN
+    INSERT INTO Audit VALUES (OLD.id, 'delete', OLD.price, NULL);
+    UPDATE Counter SET n = n - 1 WHERE name = 'Item';
+END;
+-- @expect: payload Create
+
+CREATE TRIGGER item_insert AFTER INSERT ON Item FOR EACH ROW
+BEGIN
+    DELETE FROM Audit;
+END
+-- @expect: error Alter.TriggerAlreadyExists
+-- @json: "item_insert"
+
+INSERT INTO Item VALUES (1, 'apple', 100), (2, 'banana', 200), (3, 'cherry', 300);
+-- @expect: payload Insert
+-- @json: 3
+
+UPDATE Item SET price = price + 50 WHERE id = 2;
+-- @expect: payload Update
+-- @json: 1
+
+DELETE FROM Item WHERE id = 3;
+-- @expect: payload Delete
+-- @json: 1
+
+SELECT * FROM Audit
+-- @expect:
+-- | item_id: I64 | action: Str | old_price: I64 | new_price: I64 |
+-- | ------------ | ----------- | -------------- | -------------- |
+-- | 1            | "insert"    | NULL           | 100            |
+-- | 2            | "insert"    | NULL           | 200            |
+-- | 3            | "insert"    | NULL           | 300            |
+-- | 2            | "update"    | 200            | 250            |
+-- | 3            | "delete"    | 300            | NULL           |
+
+SELECT n FROM Counter
+-- @expect:
+-- | n: I64 |
+-- | ------ |
+-- | 2      |
+
+SELECT OBJECT_NAME, OBJECT_TYPE FROM GLUE_OBJECTS WHERE OBJECT_TYPE = 'TRIGGER';
+-- @expect:
+-- | OBJECT_NAME: Str | OBJECT_TYPE: Str |
+-- | ---------------- | ---------------- |
+-- | "item_insert"    | "TRIGGER"        |
+-- | "item_update"    | "TRIGGER"        |
+-- | "item_delete"    | "TRIGGER"        |
+
+-- @name: trigger referencing a missing column
+CREATE OR REPLACE TRIGGER item_update AFTER UPDATE ON Item FOR EACH ROW
+BEGIN
+    INSERT INTO Audit VALUES (NEW.id, 'update', OLD.cost, NEW.price)
+END;
+-- @expect: payload Create
+
+UPDATE Item SET price = 0;
+-- @expect: error Trigger.ColumnNotFound
+-- @json:
+-- {
+--   "column": "cost",
+--   "row": "OLD",
+--   "trigger_name": "item_update"
+-- }
+
+-- @name: drop trigger
+DROP TRIGGER item_update ON Item;
+-- @expect: payload DropTrigger
+
+DROP TRIGGER item_update ON Item;
+-- @expect: error Alter.TriggerNotFound
+-- @json: "item_update"
+
+DROP TRIGGER IF EXISTS item_update ON Item;
+-- @expect: payload DropTrigger
+
+UPDATE Item SET price = 0;
+-- @expect: payload Update
+-- @json: 2
+
+DROP TABLE Item;
+-- @expect: payload DropTable
+-- @json: 1
+
+SELECT COUNT(*) AS n FROM GLUE_OBJECTS WHERE OBJECT_TYPE = 'TRIGGER';
+-- @expect:
+-- | n: I64 |
+-- | ------ |
+-- | 0      |
+
+-- @name: trigger bodies are planned
+CREATE TABLE Stock (id INTEGER PRIMARY KEY, name TEXT);
+-- @expect: payload Create
+
+CREATE TABLE StockCount (n INTEGER);
+-- @expect: payload Create
+
+CREATE TABLE StockLog (stock_id INTEGER, text TEXT);
+-- @expect: payload Create
+
+CREATE TABLE Note;
+-- @expect: payload Create
+
+INSERT INTO Note VALUES ('{"stock_id": 2, "text": "fresh"}');
+-- @expect: payload Insert
+-- @json: 1
+
+CREATE TRIGGER stock_count AFTER INSERT ON Stock FOR EACH ROW
+BEGIN
+    DELETE FROM StockCount;
+    INSERT INTO StockCount SELECT COUNT(*) FROM Stock;
+    INSERT INTO StockLog SELECT stock_id, text FROM Note WHERE stock_id = NEW.id;
+END;
+-- @expect: payload Create
+
+INSERT INTO Stock VALUES (1, 'apple'), (2, 'banana');
+-- @expect: payload Insert
+-- @json: 2
+
+SELECT n FROM StockCount
+-- @expect:
+-- | n: I64 |
+-- | ------ |
+-- | 2      |
+
+SELECT * FROM StockLog
+-- @expect:
+-- | stock_id: I64 | text: Str |
+-- | ------------- | --------- |
+-- | 2             | "fresh"   |
+
+-- @name: recursive trigger
+CREATE TABLE Chain (id INTEGER);
+-- @expect: payload Create
+
+CREATE TRIGGER chain_next AFTER INSERT ON Chain FOR EACH ROW
+BEGIN
+    INSERT INTO Chain VALUES (NEW.id + 1);
+END;
+-- @expect: payload Create
+
+INSERT INTO Chain VALUES (1);
+-- @expect: error Trigger.DepthLimitExceeded
+-- @json:
+-- {
+--   "limit": 32,
+--   "trigger_name": "chain_next"
+-- }
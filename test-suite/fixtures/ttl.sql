@@ -0,0 +1,83 @@
+CREATE TABLE Session (
+    id INTEGER PRIMARY KEY,
+    name TEXT,
+    expires_at TIMESTAMP NULL
+) WITH (ttl_column = expires_at);
+-- @expect: payload Create
+
+INSERT INTO Session VALUES
+    (1, 'expired', '2000-01-01 00:00:00'),
+    (2, 'live', '2999-01-01 00:00:00'),
+    (3, 'forever', NULL);
+-- @expect: payload Insert
+-- @json: 3
+
+-- @name: expired rows are hidden from queries
+SELECT id, name FROM Session;
+-- @expect:
+-- | id: I64 | name: Str |
+-- | ------- | --------- |
+-- | 2       | "live"    |
+-- | 3       | "forever" |
+
+SELECT id FROM Session WHERE id = 1;
+-- @expect:
+-- | id  |
+-- | --- |
+
+SELECT COUNT(*) AS n FROM Session;
+-- @expect:
+-- | n: I64 |
+-- | ------ |
+-- | 2      |
+
+-- @name: expired rows are skipped by UPDATE and DELETE
+UPDATE Session SET name = 'touched';
+-- @expect: payload Update
+-- @json: 2
+
+DELETE FROM Session WHERE name = 'expired';
+-- @expect: payload Delete
+-- @json: 0
+
+-- @name: an expired primary key can be reused
+INSERT INTO Session VALUES (1, 'renewed', '2999-01-01 00:00:00');
+-- @expect: payload Insert
+-- @json: 1
+
+UPDATE Session SET expires_at = '2000-01-01 00:00:00' WHERE id = 3;
+-- @expect: payload Update
+-- @json: 1
+
+SELECT id, name FROM Session;
+-- @expect:
+-- | id: I64 | name: Str |
+-- | ------- | --------- |
+-- | 1       | "renewed" |
+-- | 2       | "touched" |
+
+-- @name: ttl column cannot be dropped or renamed
+ALTER TABLE Session DROP COLUMN expires_at;
+-- @expect: error Alter.CannotAlterTtlColumn
+-- @json: "expires_at"
+
+ALTER TABLE Session RENAME COLUMN expires_at TO ends_at;
+-- @expect: error Alter.CannotAlterTtlColumn
+-- @json: "expires_at"
+
+-- @name: invalid ttl options
+CREATE TABLE Missing (id INTEGER) WITH (ttl_column = expires_at);
+-- @expect: error Alter.TtlColumnNotFound
+-- @json: "expires_at"
+
+CREATE TABLE WrongType (id INTEGER, expires_at DATE) WITH (ttl_column = expires_at);
+-- @expect: error Alter.UnsupportedTtlColumnType
+-- @json:
+-- {
+--   "column": "expires_at",
+--   "data_type": "Date"
+-- }
+
+CREATE TABLE Unknown (id INTEGER) WITH (fillfactor = 70);
+-- @expect: error Translate.UnsupportedTableOption
+-- @json: "fillfactor = 70"
@@ -0,0 +1,84 @@
+CREATE TABLE Fruit (id INTEGER, name TEXT)
+-- @expect: ok
+
+CREATE TABLE Vegetable (id INTEGER, name TEXT)
+-- @expect: ok
+
+INSERT INTO Fruit VALUES (1, 'Apple'), (2, 'Banana'), (3, 'Cherry')
+-- @expect: ok
+
+INSERT INTO Vegetable VALUES (3, 'Cherry'), (4, 'Daikon')
+-- @expect: ok
+
+-- @name: UNION removes duplicate rows
+SELECT id, name FROM Fruit UNION SELECT id, name FROM Vegetable ORDER BY id
+-- @expect:
+-- | id: I64 | name: Str |
+-- | ------- | --------- |
+-- | 1       | "Apple"   |
+-- | 2       | "Banana"  |
+-- | 3       | "Cherry"  |
+-- | 4       | "Daikon"  |
+
+-- @name: UNION ALL keeps duplicate rows
+SELECT name FROM Fruit WHERE id > 2 UNION ALL SELECT name FROM Vegetable ORDER BY name
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "Cherry"  |
+-- | "Cherry"  |
+-- | "Daikon"  |
+
+-- @name: UNION labels rows after the left query
+SELECT id AS key FROM Fruit WHERE id = 1 UNION SELECT id FROM Vegetable ORDER BY key DESC
+-- @expect:
+-- | key: I64 |
+-- | -------- |
+-- | 4        |
+-- | 3        |
+-- | 1        |
+
+-- @name: ORDER BY, OFFSET and LIMIT apply to the combined rows
+SELECT name FROM Fruit UNION SELECT name FROM Vegetable ORDER BY name DESC OFFSET 1 LIMIT 2
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "Cherry"  |
+-- | "Banana"  |
+
+-- @name: Parenthesized operands keep their own ORDER BY and LIMIT
+(SELECT name FROM Fruit ORDER BY id DESC LIMIT 1) UNION ALL (SELECT name FROM Vegetable ORDER BY id LIMIT 1)
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "Cherry"  |
+-- | "Cherry"  |
+
+-- @name: UNION chains with VALUES
+SELECT id FROM Fruit WHERE id = 1 UNION VALUES (5) UNION ALL VALUES (5)
+-- @expect:
+-- | id: I64 |
+-- | ------- |
+-- | 1       |
+-- | 5       |
+-- | 5       |
+
+-- @name: UNION in a derived table
+SELECT COUNT(*) AS count FROM (SELECT name FROM Fruit UNION SELECT name FROM Vegetable) AS Food
+-- @expect:
+-- | count: I64 |
+-- | ---------- |
+-- | 4          |
+
+-- @name: UNION in an IN subquery
+SELECT name FROM Fruit WHERE id IN (SELECT id FROM Vegetable UNION SELECT 1) ORDER BY id
+-- @expect:
+-- | name: Str |
+-- | --------- |
+-- | "Apple"   |
+-- | "Cherry"  |
+
+-- @name: UNION queries must have the same number of columns
+SELECT id, name FROM Fruit UNION SELECT id FROM Vegetable
+-- @expect: error Query.UnionColumnCountMismatch
+-- @json: [2, 1]
@@ -3,7 +3,7 @@ use {
     chrono::{NaiveDate, NaiveDateTime, NaiveTime},
     gluesql_core::{
         ast::{DataType, IndexOperator},
-        data::{Interval, Point},
+        data::{Interval, LineString, MultiPoint, Point, Polygon},
         executor::Payload,
         plan::{IndexPredicatePlan, TableAccessPlan},
         prelude::Value,
@@ -492,6 +492,7 @@ fn parse_index(value: &str) -> TableAccessPlan {
                 ">=" => IndexOperator::GtEq,
                 "<=" => IndexOperator::LtEq,
                 "=" => IndexOperator::Eq,
+                "&&" => IndexOperator::Intersects,
                 operator => panic!("unsupported index operator: {operator}"),
             };
             let expression = parts
@@ -807,6 +808,9 @@ fn parse_column_type(type_: &str) -> Option<DataType> {
         "Map" => DataType::Map,
         "List" => DataType::List,
         "Point" => DataType::Point,
+        "LineString" => DataType::LineString,
+        "Polygon" => DataType::Polygon,
+        "MultiPoint" => DataType::MultiPoint,
         _ => return None,
     })
 }
@@ -889,6 +893,15 @@ fn parse_typed_value(value: &str, type_: &DataType) -> Value {
         DataType::Point => {
             Value::Point(Point::from_wkt(unquote(value)).expect("expected a Point WKT value"))
         }
+        DataType::LineString => Value::LineString(
+            LineString::from_wkt(unquote(value)).expect("expected a LineString WKT value"),
+        ),
+        DataType::Polygon => {
+            Value::Polygon(Polygon::from_wkt(unquote(value)).expect("expected a Polygon WKT value"))
+        }
+        DataType::MultiPoint => Value::MultiPoint(
+            MultiPoint::from_wkt(unquote(value)).expect("expected a MultiPoint WKT value"),
+        ),
     };
 
     value.validate_type(type_).unwrap();
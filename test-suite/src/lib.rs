@@ -171,6 +171,14 @@ macro_rules! generate_store_tests {
         sql_case!(aggregate::variance);
         sql_case!(aggregate::error);
         sql_case!(aggregate::expr);
+        sql_case!(aggregate::string_agg);
+        sql_case!(aggregate::array_agg);
+        sql_case!(aggregate::bool);
+        sql_case!(aggregate::median);
+        sql_case!(aggregate::percentile);
+        sql_case!(aggregate::mode);
+        sql_case!(aggregate::filter);
+        sql_case!(aggregate::grouping_sets);
         sql_case!(project);
 
         // expression tests
@@ -226,6 +234,11 @@ macro_rules! generate_store_tests {
         sql_case!(function::floor);
         sql_case!(function::format);
         sql_case!(function::last_day);
+        sql_case!(function::date_trunc);
+        sql_case!(function::date_bin);
+        sql_case!(function::date_diff);
+        sql_case!(function::age);
+        sql_case!(function::make_date_timestamp);
         sql_case!(function::exp_log::ln);
         sql_case!(function::exp_log::log);
         sql_case!(function::exp_log::log2);
@@ -248,6 +261,12 @@ macro_rules! generate_store_tests {
         sql_case!(function::geometry::get_x);
         sql_case!(function::geometry::get_y);
         sql_case!(function::geometry::calc_distance);
+        sql_case!(function::geometry::st_contains);
+        sql_case!(function::geometry::st_intersects);
+        sql_case!(function::geometry::st_within);
+        sql_case!(function::geometry::st_area);
+        sql_case!(function::geometry::st_length);
+        sql_case!(function::geometry::st_distance_sphere);
         sql_case!(function::add_month);
         sql_case!(function::slice);
         sql_case!(function::entries);
@@ -262,6 +281,7 @@ macro_rules! generate_store_tests {
         sql_case!(primary_key);
         sql_case!(foreign_key);
         sql_case!(series);
+        sql_case!(generate_series);
         sql_case!(nullable);
         sql_case!(nullable::text);
         sql_case!(nullable::implicit_insert);
@@ -270,6 +290,7 @@ macro_rules! generate_store_tests {
         sql_case!(data_type::sql_types);
         sql_case!(show_columns);
         sql_case!(distinct);
+        sql_case!(union);
         sql_case!(data_type::int8);
         sql_case!(data_type::int16);
         sql_case!(data_type::int32);
@@ -290,6 +311,7 @@ macro_rules! generate_store_tests {
         sql_case!(data_type::bytea);
         sql_case!(data_type::inet);
         sql_case!(data_type::point);
+        sql_case!(data_type::geometry);
         sql_case!(data_type::null);
         sql_case!(synthesize);
         sql_case!(validate::unique);
@@ -314,6 +336,7 @@ macro_rules! generate_store_tests {
         sql_case!(column_alias);
         sql_case!(function::splice);
         sql_case!(function::dedup);
+        sql_case!(function::regexp);
 
         // query-builder
         rust_case!(query_builder::basic);
@@ -389,6 +412,38 @@ macro_rules! generate_custom_function_tests {
     };
 }
 
+#[macro_export]
+macro_rules! generate_trigger_tests {
+    ($test: meta, $storage: ident) => {
+        $crate::generate_trigger_tests!(@sql_case $test, $storage, $);
+    };
+    (@sql_case $test: meta, $storage: ident, $d: tt) => {
+        macro_rules! sql_case {
+            ($d($d module:ident)::+) => {
+                $crate::declare_sql_case!($test, $storage, $d($d module)::+);
+            };
+        }
+
+        sql_case!(trigger);
+    };
+}
+
+#[macro_export]
+macro_rules! generate_ttl_tests {
+    ($test: meta, $storage: ident) => {
+        $crate::generate_ttl_tests!(@sql_case $test, $storage, $);
+    };
+    (@sql_case $test: meta, $storage: ident, $d: tt) => {
+        macro_rules! sql_case {
+            ($d($d module:ident)::+) => {
+                $crate::declare_sql_case!($test, $storage, $d($d module)::+);
+            };
+        }
+
+        sql_case!(ttl);
+    };
+}
+
 #[macro_export]
 macro_rules! generate_index_tests {
     ($test: meta, $storage: ident) => {
@@ -410,6 +465,7 @@ macro_rules! generate_index_tests {
         sql_case!(index::order_by);
         sql_case!(index::order_by::multi);
         sql_case!(index::showindexes);
+        sql_case!(index::spatial);
         sql_case!(dictionary_index);
     };
 }
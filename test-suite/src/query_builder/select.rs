@@ -240,4 +240,93 @@ test_case!(select, {
         500 3 "Orange juice".to_owned() 60
     ));
     assert_eq!(actual, expected, "distinct * (all columns)");
+
+    // join a derived table
+    let actual = table("Category")
+        .select()
+        .join(
+            table("Item")
+                .select()
+                .group_by("category_id")
+                .project("category_id, MAX(price) AS max_price")
+                .alias_as("Top"),
+        )
+        .on("Top.category_id = Category.id")
+        .project("Category.name AS name, Top.max_price AS max_price")
+        .order_by("max_price DESC")
+        .execute(glue);
+    let expected = Ok(select!(
+        name                | max_price
+        Str                 | I64;
+        "Meat".to_owned()     90;
+        "Drink".to_owned()    60;
+        "Fruit".to_owned()    40
+    ));
+    assert_eq!(actual, expected, "join a derived table");
+
+    // exists and in subquery
+    let actual = table("Category")
+        .select()
+        .filter(
+            table("Item")
+                .select()
+                .filter("Item.category_id = Category.id AND price > 50")
+                .exists(),
+        )
+        .filter(
+            col("id").not_in_subquery(
+                table("Item")
+                    .select()
+                    .filter("price < 30")
+                    .project("category_id"),
+            ),
+        )
+        .project("name")
+        .execute(glue);
+    let expected = Ok(select!(
+        name
+        Str;
+        "Meat".to_owned()
+    ));
+    assert_eq!(actual, expected, "exists and in subquery");
+
+    // case
+    let actual = table("Item")
+        .select()
+        .filter(col("id").lte(300))
+        .project("name")
+        .project(
+            case()
+                .when(col("price").gte(50))
+                .then(text("high"))
+                .or_else(text("low"))
+                .alias_as("band"),
+        )
+        .execute(glue);
+    let expected = Ok(select!(
+        name                    | band
+        Str                     | Str;
+        "Pineapple".to_owned()    "low".to_owned();
+        "Pork belly".to_owned()   "high".to_owned();
+        "Strawberry".to_owned()   "low".to_owned()
+    ));
+    assert_eq!(actual, expected, "case");
+
+    // union
+    let actual = table("Category")
+        .select()
+        .project("name")
+        .union_all(table("Item").select().filter("price > 50").project("name"))
+        .union(values(vec!["'Meat'"]))
+        .order_by("name")
+        .limit(3)
+        .execute(glue);
+    let expected = Ok(select!(
+        name
+        Str;
+        "Drink".to_owned();
+        "Fruit".to_owned();
+        "Meat".to_owned()
+    ));
+    assert_eq!(actual, expected, "union");
 });
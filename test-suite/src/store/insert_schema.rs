@@ -24,6 +24,7 @@ test_case!(insert_schema, {
         engine: None,
         foreign_keys: Vec::new(),
         comment: Some("this is comment for table".to_owned()),
+        ttl_column: None,
     };
 
     storage.begin(true).unwrap();
@@ -58,6 +59,7 @@ test_case!(insert_schema, {
         engine: None,
         foreign_keys: Vec::new(),
         comment: Some("this is comment for schemaless table".to_owned()),
+        ttl_column: None,
     };
     storage.insert_schema(&schema).unwrap();
 